@@ -1,10 +1,16 @@
 /// Cache System with a max size
 pub mod cache;
+/// Open Vector Tile Encoding
+pub mod open_vector_tile;
 /// Priority Queue
 pub mod priority_queue;
 /// Tile Structure
 pub mod tile;
+/// Vector Tile Encoding
+pub mod vector_tile;
 
 pub use cache::*;
+pub use open_vector_tile::*;
 pub use priority_queue::*;
 pub use tile::*;
+pub use vector_tile::*;
@@ -0,0 +1,1451 @@
+use alloc::collections::BTreeMap;
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use libm::round;
+use pbf::{zagzig, zigzag, ProtoRead, ProtoWrite, Protobuf};
+
+use super::{HasLayer, Layer, Tile, TileProjection};
+use crate::geometry::{
+    BBox3D, MValue, PrimitiveValue, Value, ValuePrimitiveType, ValueType, VectorFeature,
+    VectorGeometry, VectorPoint,
+};
+
+/// The extents an Open Vector Tile layer may use. The layer stores the position in this list.
+pub const OVT_EXTENTS: [u32; 6] = [512, 1_024, 2_048, 4_096, 8_192, 16_384];
+
+/// Open Vector Tile column cache columns. The tag of each entry in the column cache message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OColumnName {
+    /// UTF-8 strings
+    String = 1,
+    /// Unsigned integers
+    Unsigned = 2,
+    /// Signed integers
+    Signed = 3,
+    /// 32 bit floats
+    Float = 4,
+    /// 64 bit floats
+    Double = 5,
+    /// Woven and delta encoded 2D points
+    Points = 6,
+    /// Woven and delta encoded 3D points
+    Points3D = 7,
+    /// Delta encoded indices
+    Indices = 8,
+    /// Shapes and the values stored against them
+    Shapes = 9,
+    /// Quantized bounding boxes
+    BBox = 10,
+}
+impl OColumnName {
+    /// Get the column of a column cache tag
+    pub fn from_tag(tag: u64) -> Option<Self> {
+        match tag {
+            1 => Some(OColumnName::String),
+            2 => Some(OColumnName::Unsigned),
+            3 => Some(OColumnName::Signed),
+            4 => Some(OColumnName::Float),
+            5 => Some(OColumnName::Double),
+            6 => Some(OColumnName::Points),
+            7 => Some(OColumnName::Points3D),
+            8 => Some(OColumnName::Indices),
+            9 => Some(OColumnName::Shapes),
+            10 => Some(OColumnName::BBox),
+            _ => None,
+        }
+    }
+}
+
+/// Open Vector Tile feature geometry types
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OFeatureType {
+    /// (Multi)Point
+    Points = 1,
+    /// (Multi)LineString
+    Lines = 2,
+    /// (Multi)Polygon
+    Polygons = 3,
+    /// 3D (Multi)Point
+    Points3D = 4,
+    /// 3D (Multi)LineString
+    Lines3D = 5,
+    /// 3D (Multi)Polygon
+    Polygons3D = 6,
+}
+impl OFeatureType {
+    /// Get the feature type of a stored type. Returns `None` if the type is unknown
+    pub fn from_u64(value: u64) -> Option<Self> {
+        match value {
+            1 => Some(OFeatureType::Points),
+            2 => Some(OFeatureType::Lines),
+            3 => Some(OFeatureType::Polygons),
+            4 => Some(OFeatureType::Points3D),
+            5 => Some(OFeatureType::Lines3D),
+            6 => Some(OFeatureType::Polygons3D),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the points of the geometry have a z value
+    pub fn is_3d(&self) -> bool {
+        matches!(self, OFeatureType::Points3D | OFeatureType::Lines3D | OFeatureType::Polygons3D)
+    }
+}
+
+/// Feature flag: the feature has an id
+pub const OFLAG_ID: u8 = 1;
+/// Feature flag: the feature has a bounding box
+pub const OFLAG_BBOX: u8 = 1 << 1;
+/// Feature flag: every line stores its offset
+pub const OFLAG_OFFSETS: u8 = 1 << 2;
+/// Feature flag: the polygon stores triangle indices
+pub const OFLAG_INDICES: u8 = 1 << 3;
+/// Feature flag: the polygon stores tessellation points
+pub const OFLAG_TESSELLATION: u8 = 1 << 4;
+/// Feature flag: every point stores an M-value
+pub const OFLAG_M_VALUES: u8 = 1 << 5;
+/// Feature flag: the geometry is a single point, line or polygon
+pub const OFLAG_SINGLE: u8 = 1 << 6;
+
+/// Interleave the bits of two 16 bit numbers
+pub fn weave_2d(a: u16, b: u16) -> u32 {
+    let (a, b) = (a as u32, b as u32);
+    let mut result = 0;
+    for i in 0..16 {
+        result |= ((a >> i) & 1) << (i * 2);
+        result |= ((b >> i) & 1) << (i * 2 + 1);
+    }
+    result
+}
+
+/// Split a number into the two 16 bit numbers woven by [`weave_2d`]
+pub fn unweave_2d(num: u32) -> (u16, u16) {
+    let (mut a, mut b) = (0_u16, 0_u16);
+    for i in 0..16 {
+        a |= (((num >> (i * 2)) & 1) as u16) << i;
+        b |= (((num >> (i * 2 + 1)) & 1) as u16) << i;
+    }
+    (a, b)
+}
+
+/// Interleave the bits of three 16 bit numbers
+pub fn weave_3d(a: u16, b: u16, c: u16) -> u64 {
+    let (a, b, c) = (a as u64, b as u64, c as u64);
+    let mut result = 0;
+    for i in 0..16 {
+        result |= ((a >> i) & 1) << (i * 3);
+        result |= ((b >> i) & 1) << (i * 3 + 1);
+        result |= ((c >> i) & 1) << (i * 3 + 2);
+    }
+    result
+}
+
+/// Split a number into the three 16 bit numbers woven by [`weave_3d`]
+pub fn unweave_3d(num: u64) -> (u16, u16, u16) {
+    let (mut a, mut b, mut c) = (0_u16, 0_u16, 0_u16);
+    for i in 0..16 {
+        a |= (((num >> (i * 3)) & 1) as u16) << i;
+        b |= (((num >> (i * 3 + 1)) & 1) as u16) << i;
+        c |= (((num >> (i * 3 + 2)) & 1) as u16) << i;
+    }
+    (a, b, c)
+}
+
+/// zigzag encode a 32 bit number, truncated to the 16 bits that are woven
+fn zigzag_16(num: i32) -> u16 {
+    zigzag(num as i64) as u16
+}
+
+/// Decode a zigzag encoded 16 bit number
+fn zagzig_16(num: u16) -> i32 {
+    zagzig(num as u64) as i32
+}
+
+/// Weave a single 2D point, used by features that only store one point
+pub fn weave_point(x: i32, y: i32) -> u32 {
+    weave_2d(zigzag_16(x), zigzag_16(y))
+}
+
+/// Unweave a single 2D point stored by [`weave_point`]
+pub fn unweave_point(num: u32) -> (i32, i32) {
+    let (a, b) = unweave_2d(num);
+    (zagzig_16(a), zagzig_16(b))
+}
+
+/// Weave a single 3D point, used by features that only store one point
+pub fn weave_point_3d(x: i32, y: i32, z: i32) -> u64 {
+    weave_3d(zigzag_16(x), zigzag_16(y), zigzag_16(z))
+}
+
+/// Unweave a single 3D point stored by [`weave_point_3d`]
+pub fn unweave_point_3d(num: u64) -> (i32, i32, i32) {
+    let (a, b, c) = unweave_3d(num);
+    (zagzig_16(a), zagzig_16(b), zagzig_16(c))
+}
+
+/// Delta encode the points and weave each delta into a single number
+fn weave_and_delta_encode(points: &[(i32, i32, i32)], is_3d: bool) -> Vec<u64> {
+    let mut prev = (0_i32, 0_i32, 0_i32);
+    points
+        .iter()
+        .map(|&(x, y, z)| {
+            let (dx, dy, dz) =
+                (x.wrapping_sub(prev.0), y.wrapping_sub(prev.1), z.wrapping_sub(prev.2));
+            prev = (x, y, z);
+            if is_3d {
+                weave_point_3d(dx, dy, dz)
+            } else {
+                weave_point(dx, dy) as u64
+            }
+        })
+        .collect()
+}
+
+/// Decode points stored by `weave_and_delta_encode`
+fn unweave_and_delta_decode(nums: &[u64], is_3d: bool) -> Vec<(i32, i32, i32)> {
+    let mut prev = (0_i32, 0_i32, 0_i32);
+    nums.iter()
+        .map(|&num| {
+            let (dx, dy, dz) =
+                if is_3d { unweave_point_3d(num) } else { with_z(unweave_point(num as u32)) };
+            prev = (prev.0.wrapping_add(dx), prev.1.wrapping_add(dy), prev.2.wrapping_add(dz));
+            prev
+        })
+        .collect()
+}
+
+fn with_z((x, y): (i32, i32)) -> (i32, i32, i32) {
+    (x, y, 0)
+}
+
+/// zigzag delta encode a list of indices
+fn delta_encode(indices: &[u32]) -> Vec<u32> {
+    let mut prev = 0_i64;
+    indices
+        .iter()
+        .map(|&num| {
+            let delta = zigzag(num as i64 - prev) as u32;
+            prev = num as i64;
+            delta
+        })
+        .collect()
+}
+
+/// Decode a list of indices stored by `delta_encode`
+fn delta_decode(deltas: &[u32]) -> Vec<u32> {
+    let mut prev = 0_i64;
+    deltas
+        .iter()
+        .map(|&delta| {
+            prev += zagzig(delta as u64);
+            prev as u32
+        })
+        .collect()
+}
+
+/// Encode a line offset as thousandths
+pub fn encode_offset(offset: f64) -> u32 {
+    round(offset * 1_000.) as u32
+}
+
+/// Decode a line offset stored by [`encode_offset`]
+pub fn decode_offset(offset: u32) -> f64 {
+    offset as f64 / 1_000.
+}
+
+/// Quantize a longitude-latitude bounding box into 24 bit integers. 3D boxes append near and
+/// far as little endian 32 bit floats.
+fn quantize_bbox(bbox: &BBox3D, is_3d: bool) -> Vec<u8> {
+    let mut buffer = Vec::with_capacity(if is_3d { 20 } else { 12 });
+    let lon = |lon: f64| round((lon + 180.) * 16_777_215. / 360.) as i32;
+    let lat = |lat: f64| ((lat + 90.) * 16_777_215. / 180.) as i32;
+    for value in [lon(bbox.left), lat(bbox.bottom), lon(bbox.right), lat(bbox.top)] {
+        buffer.extend_from_slice(&value.to_be_bytes()[1..]);
+    }
+    if is_3d {
+        buffer.extend_from_slice(&(bbox.near as f32).to_le_bytes());
+        buffer.extend_from_slice(&(bbox.far as f32).to_le_bytes());
+    }
+    buffer
+}
+
+/// Decode a bounding box stored by `quantize_bbox`. Returns `None` if the length is invalid
+fn dequantize_bbox(buffer: &[u8]) -> Option<BBox3D> {
+    if buffer.len() != 12 && buffer.len() != 20 {
+        return None;
+    }
+    let unpack = |i: usize| i32::from_be_bytes([0, buffer[i], buffer[i + 1], buffer[i + 2]]);
+    let lon = |i: usize| unpack(i) as f64 * 360. / 16_777_215. - 180.;
+    let lat = |i: usize| unpack(i) as f64 * 180. / 16_777_215. - 90.;
+    let float = |i: usize| {
+        f32::from_le_bytes([buffer[i], buffer[i + 1], buffer[i + 2], buffer[i + 3]]) as f64
+    };
+    let (near, far) = if buffer.len() == 20 { (float(12), float(16)) } else { (0., 0.) };
+    Some(BBox3D::new(lon(0), lat(3), lon(6), lat(9), near, far))
+}
+
+/// The type of a primitive property value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OPrimitiveShape {
+    /// UTF-8 string
+    String = 0,
+    /// Unsigned 64 bit integer
+    U64 = 1,
+    /// Signed 64 bit integer
+    I64 = 2,
+    /// 32 bit float
+    F32 = 3,
+    /// 64 bit float
+    F64 = 4,
+    /// Boolean
+    Bool = 5,
+    /// Null
+    Null = 6,
+}
+impl OPrimitiveShape {
+    fn from_code(code: usize) -> Option<Self> {
+        match code {
+            0 => Some(OPrimitiveShape::String),
+            1 => Some(OPrimitiveShape::U64),
+            2 => Some(OPrimitiveShape::I64),
+            3 => Some(OPrimitiveShape::F32),
+            4 => Some(OPrimitiveShape::F64),
+            5 => Some(OPrimitiveShape::Bool),
+            6 => Some(OPrimitiveShape::Null),
+            _ => None,
+        }
+    }
+
+    fn from_value(value: &PrimitiveValue) -> Self {
+        match value {
+            PrimitiveValue::String(_) => OPrimitiveShape::String,
+            PrimitiveValue::U64(_) => OPrimitiveShape::U64,
+            PrimitiveValue::I64(_) => OPrimitiveShape::I64,
+            PrimitiveValue::F32(_) => OPrimitiveShape::F32,
+            PrimitiveValue::F64(_) => OPrimitiveShape::F64,
+            PrimitiveValue::Bool(_) => OPrimitiveShape::Bool,
+            PrimitiveValue::Null => OPrimitiveShape::Null,
+        }
+    }
+
+    fn is_number(&self) -> bool {
+        matches!(
+            self,
+            OPrimitiveShape::U64
+                | OPrimitiveShape::I64
+                | OPrimitiveShape::F32
+                | OPrimitiveShape::F64
+        )
+    }
+
+    /// Null takes the other type and numbers widen to fit both. Other conflicts keep the
+    /// current type.
+    fn merge(&mut self, other: Self) {
+        let widens = self.is_number() && other.is_number() && other as u8 > *self as u8;
+        if *self == OPrimitiveShape::Null || widens {
+            *self = other;
+        }
+    }
+}
+
+/// The type of an array element: a primitive or an object of primitives
+#[derive(Debug, Clone, PartialEq)]
+pub enum OPrimitiveShapeType {
+    /// A primitive
+    Primitive(OPrimitiveShape),
+    /// An object of primitives
+    NestedPrimitive(BTreeMap<String, OPrimitiveShape>),
+}
+impl OPrimitiveShapeType {
+    fn from_value(value: &ValuePrimitiveType) -> Self {
+        match value {
+            ValuePrimitiveType::Primitive(p) => {
+                OPrimitiveShapeType::Primitive(OPrimitiveShape::from_value(p))
+            }
+            ValuePrimitiveType::NestedPrimitive(nested) => OPrimitiveShapeType::NestedPrimitive(
+                nested.iter().map(|(k, v)| (k.clone(), OPrimitiveShape::from_value(v))).collect(),
+            ),
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        match (self, other) {
+            (OPrimitiveShapeType::Primitive(a), OPrimitiveShapeType::Primitive(b)) => a.merge(*b),
+            (OPrimitiveShapeType::NestedPrimitive(a), OPrimitiveShapeType::NestedPrimitive(b)) => {
+                for (key, shape) in b {
+                    a.entry(key.clone()).and_modify(|s| s.merge(*shape)).or_insert(*shape);
+                }
+            }
+            (this @ OPrimitiveShapeType::Primitive(OPrimitiveShape::Null), other) => {
+                *this = other.clone()
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The type of a property value
+#[derive(Debug, Clone, PartialEq)]
+pub enum OShapeType {
+    /// A primitive
+    Primitive(OPrimitiveShape),
+    /// An array whose elements share a type
+    Array(OPrimitiveShapeType),
+    /// A nested object
+    Nested(OShape),
+}
+impl OShapeType {
+    fn from_value(value: &ValueType) -> Self {
+        match value {
+            ValueType::Primitive(p) => OShapeType::Primitive(OPrimitiveShape::from_value(p)),
+            ValueType::Array(values) => {
+                let mut shape = OPrimitiveShapeType::Primitive(OPrimitiveShape::Null);
+                values.iter().for_each(|v| shape.merge(&OPrimitiveShapeType::from_value(v)));
+                OShapeType::Array(shape)
+            }
+            ValueType::Nested(nested) => {
+                let mut shape = OShape::new();
+                merge_shape(&mut shape, nested);
+                OShapeType::Nested(shape)
+            }
+        }
+    }
+
+    fn merge(&mut self, other: &Self) {
+        match (self, other) {
+            (OShapeType::Primitive(a), OShapeType::Primitive(b)) => a.merge(*b),
+            (OShapeType::Array(a), OShapeType::Array(b)) => a.merge(b),
+            (OShapeType::Nested(a), OShapeType::Nested(b)) => {
+                for (key, shape) in b {
+                    match a.get_mut(key) {
+                        Some(s) => s.merge(shape),
+                        None => _ = a.insert(key.clone(), shape.clone()),
+                    }
+                }
+            }
+            (this @ OShapeType::Primitive(OPrimitiveShape::Null), other) => *this = other.clone(),
+            _ => {}
+        }
+    }
+}
+
+/// The shape of a properties object. Every feature of a layer is stored against one shape.
+pub type OShape = BTreeMap<String, OShapeType>;
+
+/// Merge the shape of `value` into `shape`
+fn merge_shape(shape: &mut OShape, value: &Value) {
+    for (key, value) in value {
+        let other = OShapeType::from_value(value);
+        match shape.get_mut(key) {
+            Some(s) => s.merge(&other),
+            None => _ = shape.insert(key.clone(), other),
+        }
+    }
+}
+
+/// Shape definitions stored in the lowest two bits of a shape entry
+const SHAPE_ARRAY: usize = 0;
+const SHAPE_OBJECT: usize = 1;
+const SHAPE_PRIMITIVE: usize = 2;
+
+/// Nested objects deeper than this are rejected when decoding
+const MAX_SHAPE_DEPTH: usize = 32;
+
+fn shape_pair(definition: usize, count_or_col: usize) -> usize {
+    (count_or_col << 2) + definition
+}
+
+/// Deduplicating column cache writer. Every column maps a value to its index, in the order the
+/// values were first added.
+#[derive(Debug, Default)]
+struct ColumnCacheWriter {
+    string: BTreeMap<String, usize>,
+    unsigned: BTreeMap<u64, usize>,
+    signed: BTreeMap<i64, usize>,
+    float: BTreeMap<u32, usize>,
+    double: BTreeMap<u64, usize>,
+    points: BTreeMap<Vec<u64>, usize>,
+    points_3d: BTreeMap<Vec<u64>, usize>,
+    indices: BTreeMap<Vec<u32>, usize>,
+    shapes: BTreeMap<Vec<usize>, usize>,
+    bbox: BTreeMap<Vec<u8>, usize>,
+}
+impl ColumnCacheWriter {
+    fn add<T: Ord>(column: &mut BTreeMap<T, usize>, value: T) -> usize {
+        let next = column.len();
+        *column.entry(value).or_insert(next)
+    }
+
+    fn add_string(&mut self, value: &str) -> usize {
+        match self.string.get(value) {
+            Some(index) => *index,
+            None => Self::add(&mut self.string, value.into()),
+        }
+    }
+
+    fn add_points(&mut self, points: &[(i32, i32, i32)], is_3d: bool) -> usize {
+        let encoded = weave_and_delta_encode(points, is_3d);
+        if is_3d {
+            Self::add(&mut self.points_3d, encoded)
+        } else {
+            Self::add(&mut self.points, encoded)
+        }
+    }
+
+    fn add_indices(&mut self, indices: &[u32]) -> usize {
+        Self::add(&mut self.indices, delta_encode(indices))
+    }
+
+    /// Store a shape and return its index in the shapes column
+    fn add_shape(&mut self, shape: &OShape) -> usize {
+        let mut store = vec![];
+        self.encode_shape(shape, &mut store);
+        Self::add(&mut self.shapes, store)
+    }
+
+    /// Store a value against its shape and return its index in the shapes column
+    fn add_value(&mut self, value: &Value, shape: &OShape) -> usize {
+        let mut store = vec![];
+        self.encode_value(value, shape, &mut store);
+        Self::add(&mut self.shapes, store)
+    }
+
+    fn encode_shape(&mut self, shape: &OShape, store: &mut Vec<usize>) {
+        store.push(shape_pair(SHAPE_OBJECT, shape.len()));
+        for (key, shape_type) in shape {
+            store.push(self.add_string(key));
+            match shape_type {
+                OShapeType::Primitive(p) => store.push(shape_pair(SHAPE_PRIMITIVE, *p as usize)),
+                OShapeType::Array(element) => {
+                    store.push(shape_pair(SHAPE_ARRAY, 0));
+                    match element {
+                        OPrimitiveShapeType::Primitive(p) => {
+                            store.push(shape_pair(SHAPE_PRIMITIVE, *p as usize))
+                        }
+                        OPrimitiveShapeType::NestedPrimitive(nested) => {
+                            store.push(shape_pair(SHAPE_OBJECT, nested.len()));
+                            for (key, p) in nested {
+                                store.push(self.add_string(key));
+                                store.push(shape_pair(SHAPE_PRIMITIVE, *p as usize));
+                            }
+                        }
+                    }
+                }
+                OShapeType::Nested(nested) => self.encode_shape(nested, store),
+            }
+        }
+    }
+
+    /// Store every key of the shape. Missing or mismatched values are stored as the default of
+    /// their shape.
+    fn encode_value(&mut self, value: &Value, shape: &OShape, store: &mut Vec<usize>) {
+        for (key, shape_type) in shape {
+            match (value.get(key), shape_type) {
+                (Some(ValueType::Primitive(v)), OShapeType::Primitive(s)) => {
+                    self.encode_primitive(Some(v), *s, store)
+                }
+                (_, OShapeType::Primitive(s)) => self.encode_primitive(None, *s, store),
+                (Some(ValueType::Array(values)), OShapeType::Array(s)) => {
+                    store.push(values.len());
+                    for v in values {
+                        match (v, s) {
+                            (
+                                ValuePrimitiveType::Primitive(v),
+                                OPrimitiveShapeType::Primitive(s),
+                            ) => self.encode_primitive(Some(v), *s, store),
+                            (_, OPrimitiveShapeType::Primitive(s)) => {
+                                self.encode_primitive(None, *s, store)
+                            }
+                            (
+                                ValuePrimitiveType::NestedPrimitive(v),
+                                OPrimitiveShapeType::NestedPrimitive(s),
+                            ) => {
+                                for (key, s) in s {
+                                    self.encode_primitive(v.get(key), *s, store);
+                                }
+                            }
+                            (_, OPrimitiveShapeType::NestedPrimitive(s)) => {
+                                for s in s.values() {
+                                    self.encode_primitive(None, *s, store);
+                                }
+                            }
+                        }
+                    }
+                }
+                (_, OShapeType::Array(_)) => store.push(0),
+                (Some(ValueType::Nested(v)), OShapeType::Nested(s)) => {
+                    self.encode_value(v, s, store)
+                }
+                (_, OShapeType::Nested(s)) => self.encode_value(&Value::new(), s, store),
+            }
+        }
+    }
+
+    /// Store a primitive in the column of its shape. Numbers are widened to the shape.
+    fn encode_primitive(
+        &mut self,
+        value: Option<&PrimitiveValue>,
+        shape: OPrimitiveShape,
+        store: &mut Vec<usize>,
+    ) {
+        use PrimitiveValue as V;
+        let index = match shape {
+            OPrimitiveShape::String => match value {
+                Some(V::String(s)) => self.add_string(s),
+                _ => self.add_string(""),
+            },
+            OPrimitiveShape::U64 => {
+                let value = if let Some(V::U64(u)) = value { *u } else { 0 };
+                Self::add(&mut self.unsigned, value)
+            }
+            OPrimitiveShape::I64 => {
+                let value = match value {
+                    Some(V::U64(u)) => *u as i64,
+                    Some(V::I64(i)) => *i,
+                    _ => 0,
+                };
+                Self::add(&mut self.signed, value)
+            }
+            OPrimitiveShape::F32 => {
+                let value = match value {
+                    Some(V::U64(u)) => *u as f32,
+                    Some(V::I64(i)) => *i as f32,
+                    Some(V::F32(f)) => *f,
+                    _ => 0.,
+                };
+                Self::add(&mut self.float, value.to_bits())
+            }
+            OPrimitiveShape::F64 => {
+                let value = match value {
+                    Some(V::U64(u)) => *u as f64,
+                    Some(V::I64(i)) => *i as f64,
+                    Some(V::F32(f)) => *f as f64,
+                    Some(V::F64(f)) => *f,
+                    _ => 0.,
+                };
+                Self::add(&mut self.double, value.to_bits())
+            }
+            OPrimitiveShape::Bool => {
+                let value = matches!(value, Some(V::Bool(true))) as u64;
+                Self::add(&mut self.unsigned, value)
+            }
+            OPrimitiveShape::Null => return,
+        };
+        store.push(index);
+    }
+}
+impl ProtoWrite for ColumnCacheWriter {
+    fn write(&self, pbf: &mut Protobuf) {
+        /// The values of a column in the order of their index
+        fn sorted<T>(column: &BTreeMap<T, usize>) -> Vec<&T> {
+            let mut values: Vec<(&T, &usize)> = column.iter().collect();
+            values.sort_by_key(|(_, i)| **i);
+            values.into_iter().map(|(v, _)| v).collect()
+        }
+        for s in sorted(&self.string) {
+            pbf.write_string_field(OColumnName::String as u64, s);
+        }
+        for u in sorted(&self.unsigned) {
+            pbf.write_varint_field(OColumnName::Unsigned as u64, *u);
+        }
+        for i in sorted(&self.signed) {
+            pbf.write_s_varint_field(OColumnName::Signed as u64, *i);
+        }
+        for f in sorted(&self.float) {
+            pbf.write_varint_field(OColumnName::Float as u64, *f);
+        }
+        for d in sorted(&self.double) {
+            pbf.write_varint_field(OColumnName::Double as u64, *d);
+        }
+        for p in sorted(&self.points) {
+            pbf.write_packed_varint(OColumnName::Points as u64, p);
+        }
+        for p in sorted(&self.points_3d) {
+            pbf.write_packed_varint(OColumnName::Points3D as u64, p);
+        }
+        for i in sorted(&self.indices) {
+            pbf.write_packed_varint(OColumnName::Indices as u64, i);
+        }
+        for s in sorted(&self.shapes) {
+            pbf.write_packed_varint(OColumnName::Shapes as u64, s);
+        }
+        for b in sorted(&self.bbox) {
+            pbf.write_packed_varint(OColumnName::BBox as u64, b);
+        }
+    }
+}
+
+/// # Open Vector Tile Column Cache
+///
+/// ## Description
+/// The decoded column cache of an Open Vector Tile. Layers and features store indexes into these
+/// columns. Every getter returns `None` if the index is out of range or the stored data doesn't
+/// match what was asked for.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OColumnCacheReader {
+    string: Vec<String>,
+    unsigned: Vec<u64>,
+    signed: Vec<i64>,
+    float: Vec<f32>,
+    double: Vec<f64>,
+    points: Vec<Vec<u64>>,
+    points_3d: Vec<Vec<u64>>,
+    indices: Vec<Vec<u32>>,
+    shapes: Vec<Vec<usize>>,
+    bbox: Vec<Vec<u8>>,
+}
+impl OColumnCacheReader {
+    /// Get a string
+    pub fn get_string(&self, index: usize) -> Option<&String> {
+        self.string.get(index)
+    }
+
+    /// Get a list of points as `(x, y, z)`. 2D points have a z of 0
+    pub fn get_points(&self, index: usize, is_3d: bool) -> Option<Vec<(i32, i32, i32)>> {
+        let column = if is_3d { &self.points_3d } else { &self.points };
+        column.get(index).map(|nums| unweave_and_delta_decode(nums, is_3d))
+    }
+
+    /// Get a list of indices
+    pub fn get_indices(&self, index: usize) -> Option<Vec<u32>> {
+        self.indices.get(index).map(|deltas| delta_decode(deltas))
+    }
+
+    /// Get a bounding box
+    pub fn get_bbox(&self, index: usize) -> Option<BBox3D> {
+        dequantize_bbox(self.bbox.get(index)?)
+    }
+
+    /// Decode the shape stored at `index` of the shapes column
+    pub fn get_shape(&self, index: usize) -> Option<OShape> {
+        let store = self.shapes.get(index)?;
+        let mut cursor = 0;
+        let count = self.shape_object(store, &mut cursor)?;
+        self.decode_shape(store, &mut cursor, count, 0)
+    }
+
+    /// Decode the value stored at `index` of the shapes column against `shape`
+    pub fn get_value(&self, index: usize, shape: &OShape) -> Option<Value> {
+        let store = self.shapes.get(index)?;
+        self.decode_value(store, &mut 0, shape)
+    }
+
+    /// Read an object shape entry and return its key count
+    fn shape_object(&self, store: &[usize], cursor: &mut usize) -> Option<usize> {
+        let code = *store.get(*cursor)?;
+        *cursor += 1;
+        (code & 0b11 == SHAPE_OBJECT).then_some(code >> 2)
+    }
+
+    fn decode_shape(
+        &self,
+        store: &[usize],
+        cursor: &mut usize,
+        count: usize,
+        depth: usize,
+    ) -> Option<OShape> {
+        if depth > MAX_SHAPE_DEPTH {
+            return None;
+        }
+        let mut shape = OShape::new();
+        for _ in 0..count {
+            let key = self.get_string(*store.get(*cursor)?)?.clone();
+            let code = *store.get(*cursor + 1)?;
+            *cursor += 2;
+            let shape_type = match code & 0b11 {
+                SHAPE_PRIMITIVE => OShapeType::Primitive(OPrimitiveShape::from_code(code >> 2)?),
+                SHAPE_ARRAY => {
+                    let code = *store.get(*cursor)?;
+                    *cursor += 1;
+                    match code & 0b11 {
+                        SHAPE_PRIMITIVE => OShapeType::Array(OPrimitiveShapeType::Primitive(
+                            OPrimitiveShape::from_code(code >> 2)?,
+                        )),
+                        SHAPE_OBJECT => {
+                            let mut nested = BTreeMap::new();
+                            for _ in 0..code >> 2 {
+                                let key = self.get_string(*store.get(*cursor)?)?.clone();
+                                let code = *store.get(*cursor + 1)?;
+                                *cursor += 2;
+                                if code & 0b11 != SHAPE_PRIMITIVE {
+                                    return None;
+                                }
+                                nested.insert(key, OPrimitiveShape::from_code(code >> 2)?);
+                            }
+                            OShapeType::Array(OPrimitiveShapeType::NestedPrimitive(nested))
+                        }
+                        _ => return None,
+                    }
+                }
+                SHAPE_OBJECT => {
+                    OShapeType::Nested(self.decode_shape(store, cursor, code >> 2, depth + 1)?)
+                }
+                _ => return None,
+            };
+            shape.insert(key, shape_type);
+        }
+        Some(shape)
+    }
+
+    fn decode_value(&self, store: &[usize], cursor: &mut usize, shape: &OShape) -> Option<Value> {
+        let mut value = Value::new();
+        for (key, shape_type) in shape {
+            let value_type = match shape_type {
+                OShapeType::Primitive(s) => {
+                    ValueType::Primitive(self.decode_primitive(store, cursor, *s)?)
+                }
+                OShapeType::Array(s) => {
+                    let len = *store.get(*cursor)?;
+                    *cursor += 1;
+                    // every element takes at least one entry unless it's null
+                    let mut values = Vec::with_capacity(len.min(store.len()));
+                    for _ in 0..len {
+                        values.push(match s {
+                            OPrimitiveShapeType::Primitive(s) => ValuePrimitiveType::Primitive(
+                                self.decode_primitive(store, cursor, *s)?,
+                            ),
+                            OPrimitiveShapeType::NestedPrimitive(s) => {
+                                let mut nested = BTreeMap::new();
+                                for (key, s) in s {
+                                    nested.insert(
+                                        key.clone(),
+                                        self.decode_primitive(store, cursor, *s)?,
+                                    );
+                                }
+                                ValuePrimitiveType::NestedPrimitive(nested)
+                            }
+                        });
+                    }
+                    ValueType::Array(values)
+                }
+                OShapeType::Nested(s) => ValueType::Nested(self.decode_value(store, cursor, s)?),
+            };
+            value.insert(key.clone(), value_type);
+        }
+        Some(value)
+    }
+
+    fn decode_primitive(
+        &self,
+        store: &[usize],
+        cursor: &mut usize,
+        shape: OPrimitiveShape,
+    ) -> Option<PrimitiveValue> {
+        if shape == OPrimitiveShape::Null {
+            return Some(PrimitiveValue::Null);
+        }
+        let index = *store.get(*cursor)?;
+        *cursor += 1;
+        Some(match shape {
+            OPrimitiveShape::String => PrimitiveValue::String(self.string.get(index)?.clone()),
+            OPrimitiveShape::U64 => PrimitiveValue::U64(*self.unsigned.get(index)?),
+            OPrimitiveShape::I64 => PrimitiveValue::I64(*self.signed.get(index)?),
+            OPrimitiveShape::F32 => PrimitiveValue::F32(*self.float.get(index)?),
+            OPrimitiveShape::F64 => PrimitiveValue::F64(*self.double.get(index)?),
+            OPrimitiveShape::Bool => PrimitiveValue::Bool(*self.unsigned.get(index)? == 1),
+            OPrimitiveShape::Null => PrimitiveValue::Null,
+        })
+    }
+}
+impl ProtoRead for OColumnCacheReader {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match OColumnName::from_tag(tag) {
+            Some(OColumnName::String) => self.string.push(pb.read_string()),
+            Some(OColumnName::Unsigned) => self.unsigned.push(pb.read_varint()),
+            Some(OColumnName::Signed) => self.signed.push(zagzig(pb.read_varint())),
+            Some(OColumnName::Float) => self.float.push(pb.read_varint()),
+            Some(OColumnName::Double) => self.double.push(pb.read_varint()),
+            Some(OColumnName::Points) => self.points.push(pb.read_packed()),
+            Some(OColumnName::Points3D) => self.points_3d.push(pb.read_packed()),
+            Some(OColumnName::Indices) => self.indices.push(pb.read_packed()),
+            Some(OColumnName::Shapes) => self.shapes.push(pb.read_packed()),
+            Some(OColumnName::BBox) => self.bbox.push(pb.read_packed()),
+            None => {}
+        }
+    }
+}
+
+/// # Open Vector Tile Encoding
+///
+/// ## Description
+/// Serialize a tile into the [Open Vector Tile](https://github.com/Open-S2/open-vector-tile)
+/// format. Unlike MVT, OVT keeps 3D coordinates, M-values, line offsets, polygon indices and
+/// lon-lat bounding boxes.
+///
+/// - Tile: `layers = 4` (bytes), then `columns = 5`
+/// - Layer: `version = 1`, `name = 2` (string index), `extent = 3` (position in
+///   [`OVT_EXTENTS`]), `features = 4`, `shape = 5`, `m_shape = 6` (shape indexes)
+/// - Feature: raw varints `type`, `flags`, `[id]`, `properties`, `geometry`, `[indices]`,
+///   `[bbox]`
+///
+/// Strings, numbers, points, indices, shapes and bounding boxes are deduplicated into a column
+/// cache shared by every layer. Each layer stores its features' properties against one shape
+/// merged from all of them: missing keys are written as the default of their type, numbers widen
+/// to fit every feature (`u64` -> `i64` -> `f32` -> `f64`), and values whose type conflicts with
+/// the shape are written as its default. M-values are stored the same way against an `m_shape`.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::data_structures::Tile;
+/// use gistools::geometry::{
+///     CellId, Properties, VectorFeature, VectorGeometry, VectorGeometryType, VectorPoint,
+///     VectorPointGeometry,
+/// };
+///
+/// let mut tile: Tile<()> = Tile::new(CellId::from_face(0));
+/// let geometry = VectorGeometry::Point(VectorPointGeometry {
+///     _type: VectorGeometryType::Point,
+///     is_3d: true,
+///     coordinates: VectorPoint::new(0.5, 0.5, Some(0.25), None),
+///     ..Default::default()
+/// });
+/// tile.add_feature(VectorFeature::new_wm(Some(1), Properties::new(), geometry, None), None);
+///
+/// let ovt: Vec<u8> = tile.encode_ovt(4_096);
+/// ```
+impl<M: HasLayer + Clone> Tile<M> {
+    /// Encode the tile as an Open Vector Tile. Feature coordinates are expected to be relative
+    /// to the tile (see [`Tile::transform`]) and are scaled to `extent`, which is rounded up to
+    /// the next of [`OVT_EXTENTS`] (at most 16,384). Z values are scaled to `extent` as well.
+    pub fn encode_ovt(&self, extent: u32) -> Vec<u8> {
+        let extent_code =
+            OVT_EXTENTS.iter().position(|e| *e >= extent).unwrap_or(OVT_EXTENTS.len() - 1);
+        let project = TileProjection::new(self, OVT_EXTENTS[extent_code]);
+
+        let mut cache = ColumnCacheWriter::default();
+        let mut pbf = Protobuf::new();
+        for layer in self.layers.values() {
+            if let Some(layer) = write_layer(layer, extent_code, &project, &mut cache) {
+                pbf.write_bytes_field(4, &layer);
+            }
+        }
+        pbf.write_message(5, &cache);
+        pbf.take()
+    }
+}
+
+/// Write a layer. Returns `None` if none of its features have a geometry
+fn write_layer<M>(
+    layer: &Layer<M>,
+    extent_code: usize,
+    project: &TileProjection,
+    cache: &mut ColumnCacheWriter,
+) -> Option<Vec<u8>> {
+    let mut shape = OShape::new();
+    let mut m_shape: Option<OShape> = None;
+    for feature in &layer.features {
+        merge_shape(&mut shape, &feature.properties);
+        for_each_point(&feature.geometry, &mut |point| {
+            if let Some(m) = &point.m {
+                merge_shape(m_shape.get_or_insert_default(), m);
+            }
+        });
+    }
+
+    let mut features: Vec<(OFeatureType, Vec<u8>)> = layer
+        .features
+        .iter()
+        .filter_map(|feature| write_feature(feature, project, &shape, m_shape.as_ref(), cache))
+        .collect();
+    if features.is_empty() {
+        return None;
+    }
+    features.sort_by_key(|(_type, _)| *_type);
+
+    let mut pbf = Protobuf::new();
+    pbf.write_varint_field(1, 1);
+    pbf.write_varint_field(2, cache.add_string(&layer.name));
+    pbf.write_varint_field(3, extent_code);
+    pbf.write_varint_field(5, cache.add_shape(&shape));
+    if let Some(m_shape) = &m_shape {
+        pbf.write_varint_field(6, cache.add_shape(m_shape));
+    }
+    for (_, feature) in features {
+        pbf.write_bytes_field(4, &feature);
+    }
+    Some(pbf.take())
+}
+
+/// A line of projected points and its offset
+struct OLine<'a> {
+    offset: f64,
+    points: Vec<(i32, i32, i32)>,
+    m_values: Vec<Option<&'a MValue>>,
+}
+
+/// A geometry projected into the integer coordinates of the tile
+enum OGeometry<'a> {
+    Points(OLine<'a>),
+    Lines(Vec<OLine<'a>>),
+    Polygons(Vec<Vec<OLine<'a>>>),
+}
+
+/// Project a line into the integer coordinates of the tile. Z values are scaled to the extent.
+fn project_line<'a>(
+    project: &TileProjection,
+    points: &'a [VectorPoint],
+    offset: Option<f64>,
+) -> OLine<'a> {
+    let mut line = OLine { offset: offset.unwrap_or(0.), points: vec![], m_values: vec![] };
+    for point in points {
+        let (x, y) = project.project(point);
+        let z = round(point.z.unwrap_or(0.) * project.extent);
+        line.points.push((x as i32, y as i32, z as i32));
+        line.m_values.push(point.m.as_ref());
+    }
+    line
+}
+
+/// Project a list of lines, pairing each with its offset
+fn project_lines<'a>(
+    project: &TileProjection,
+    lines: &'a [Vec<VectorPoint>],
+    offsets: Option<&Vec<f64>>,
+) -> Vec<OLine<'a>> {
+    lines
+        .iter()
+        .enumerate()
+        .map(|(i, line)| project_line(project, line, offsets.and_then(|o| o.get(i).copied())))
+        .collect()
+}
+
+/// Write a feature. Returns `None` if the feature has no points
+fn write_feature<M>(
+    feature: &VectorFeature<M>,
+    project: &TileProjection,
+    shape: &OShape,
+    m_shape: Option<&OShape>,
+    cache: &mut ColumnCacheWriter,
+) -> Option<(OFeatureType, Vec<u8>)> {
+    let mut is_3d = false;
+    for_each_point(&feature.geometry, &mut |p| is_3d |= p.z.is_some());
+    let (geometry, bbox, indices) = match &feature.geometry {
+        VectorGeometry::Point(g) => {
+            is_3d |= g.is_3d;
+            (
+                OGeometry::Points(project_line(
+                    project,
+                    core::slice::from_ref(&g.coordinates),
+                    None,
+                )),
+                g.bbox,
+                None,
+            )
+        }
+        VectorGeometry::MultiPoint(g) => {
+            is_3d |= g.is_3d;
+            (OGeometry::Points(project_line(project, &g.coordinates, None)), g.bbox, None)
+        }
+        VectorGeometry::LineString(g) => {
+            is_3d |= g.is_3d;
+            (OGeometry::Lines(vec![project_line(project, &g.coordinates, g.offset)]), g.bbox, None)
+        }
+        VectorGeometry::MultiLineString(g) => {
+            is_3d |= g.is_3d;
+            (
+                OGeometry::Lines(project_lines(project, &g.coordinates, g.offset.as_ref())),
+                g.bbox,
+                None,
+            )
+        }
+        VectorGeometry::Polygon(g) => {
+            is_3d |= g.is_3d;
+            let polygon = project_lines(project, &g.coordinates, g.offset.as_ref());
+            (OGeometry::Polygons(vec![polygon]), g.bbox, g.indices.as_ref())
+        }
+        VectorGeometry::MultiPolygon(g) => {
+            is_3d |= g.is_3d;
+            let polygons = g
+                .coordinates
+                .iter()
+                .enumerate()
+                .map(|(i, p)| project_lines(project, p, g.offset.as_ref().and_then(|o| o.get(i))))
+                .collect();
+            (OGeometry::Polygons(polygons), g.bbox, g.indices.as_ref())
+        }
+    };
+
+    let all_lines: Vec<&OLine> = match &geometry {
+        OGeometry::Points(points) => vec![points],
+        OGeometry::Lines(lines) => lines.iter().collect(),
+        OGeometry::Polygons(polygons) => polygons.iter().flatten().collect(),
+    };
+    if all_lines.iter().all(|l| l.points.is_empty()) {
+        return None;
+    }
+    let m_shape =
+        m_shape.filter(|_| all_lines.iter().any(|l| l.m_values.iter().any(Option::is_some)));
+    let has_offsets = all_lines.iter().any(|l| l.offset != 0.);
+    let indices = indices.filter(|i| !i.is_empty());
+    // single points are woven into the feature itself, which leaves no room for an M-value. A
+    // woven 3D point doesn't fit the 32 bits some readers decode it into, so those use the
+    // points column instead.
+    let single = match &geometry {
+        OGeometry::Points(points) => points.points.len() == 1 && m_shape.is_none() && !is_3d,
+        OGeometry::Lines(lines) => lines.len() == 1,
+        OGeometry::Polygons(polygons) => polygons.len() == 1,
+    };
+    let _type = match (&geometry, is_3d) {
+        (OGeometry::Points(_), false) => OFeatureType::Points,
+        (OGeometry::Lines(_), false) => OFeatureType::Lines,
+        (OGeometry::Polygons(_), false) => OFeatureType::Polygons,
+        (OGeometry::Points(_), true) => OFeatureType::Points3D,
+        (OGeometry::Lines(_), true) => OFeatureType::Lines3D,
+        (OGeometry::Polygons(_), true) => OFeatureType::Polygons3D,
+    };
+
+    let mut flags = 0;
+    if feature.id.is_some() {
+        flags |= OFLAG_ID;
+    }
+    if bbox.is_some() {
+        flags |= OFLAG_BBOX;
+    }
+    if has_offsets {
+        flags |= OFLAG_OFFSETS;
+    }
+    if indices.is_some() {
+        flags |= OFLAG_INDICES;
+    }
+    if m_shape.is_some() {
+        flags |= OFLAG_M_VALUES;
+    }
+    if single {
+        flags |= OFLAG_SINGLE;
+    }
+
+    let mut pbf = Protobuf::new();
+    pbf.write_varint(_type as u8);
+    pbf.write_varint(flags);
+    if let Some(id) = feature.id {
+        pbf.write_varint(id);
+    }
+    pbf.write_varint(cache.add_value(&feature.properties, shape));
+
+    // every line stores [offset], its points and an M-value per point
+    let mut write_line = |line: &OLine, geometry: &mut Vec<u32>| {
+        if has_offsets {
+            geometry.push(encode_offset(line.offset));
+        }
+        geometry.push(cache.add_points(&line.points, is_3d) as u32);
+        if let Some(m_shape) = m_shape {
+            for m in &line.m_values {
+                let empty = MValue::new();
+                geometry.push(cache.add_value(m.unwrap_or(&empty), m_shape) as u32);
+            }
+        }
+    };
+    let mut geometry_indices: Vec<u32> = vec![];
+    match &geometry {
+        OGeometry::Points(points) if single => {
+            let (x, y, _) = points.points[0];
+            pbf.write_varint(weave_point(x, y));
+        }
+        OGeometry::Points(points) => write_line(points, &mut geometry_indices),
+        OGeometry::Lines(lines) => {
+            if !single {
+                geometry_indices.push(lines.len() as u32);
+            }
+            lines.iter().for_each(|line| write_line(line, &mut geometry_indices));
+        }
+        OGeometry::Polygons(polygons) => {
+            if !single {
+                geometry_indices.push(polygons.len() as u32);
+            }
+            for polygon in polygons {
+                geometry_indices.push(polygon.len() as u32);
+                polygon.iter().for_each(|line| write_line(line, &mut geometry_indices));
+            }
+        }
+    }
+    if !(single && matches!(geometry, OGeometry::Points(_))) {
+        pbf.write_varint(cache.add_indices(&geometry_indices));
+    }
+    if let Some(indices) = indices {
+        pbf.write_varint(cache.add_indices(indices));
+    }
+    if let Some(bbox) = bbox {
+        pbf.write_varint(ColumnCacheWriter::add(&mut cache.bbox, quantize_bbox(&bbox, is_3d)));
+    }
+
+    Some((_type, pbf.take()))
+}
+
+/// Call `f` on every point of the geometry
+fn for_each_point<'a>(geometry: &'a VectorGeometry, f: &mut impl FnMut(&'a VectorPoint)) {
+    match geometry {
+        VectorGeometry::Point(g) => f(&g.coordinates),
+        VectorGeometry::MultiPoint(g) | VectorGeometry::LineString(g) => {
+            g.coordinates.iter().for_each(f)
+        }
+        VectorGeometry::MultiLineString(g) | VectorGeometry::Polygon(g) => {
+            g.coordinates.iter().flatten().for_each(f)
+        }
+        VectorGeometry::MultiPolygon(g) => g.coordinates.iter().flatten().flatten().for_each(f),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{
+        CellId, Properties, VectorGeometryType, VectorLineStringGeometry, VectorPointGeometry,
+        VectorPolygonGeometry,
+    };
+    use alloc::string::ToString;
+
+    #[derive(Debug, Default)]
+    struct TestTile {
+        layers: Vec<Vec<u8>>,
+        cache: OColumnCacheReader,
+    }
+    impl ProtoRead for TestTile {
+        fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+            match tag {
+                4 => self.layers.push(pb.read_bytes()),
+                5 => pb.read_message(&mut self.cache),
+                _ => panic!("unknown tile tag {tag}"),
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct TestLayer {
+        version: u8,
+        name: usize,
+        extent: usize,
+        features: Vec<Vec<u8>>,
+        shape: usize,
+        m_shape: Option<usize>,
+    }
+    impl ProtoRead for TestLayer {
+        fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+            match tag {
+                1 => self.version = pb.read_varint(),
+                2 => self.name = pb.read_varint(),
+                3 => self.extent = pb.read_varint(),
+                4 => self.features.push(pb.read_bytes()),
+                5 => self.shape = pb.read_varint(),
+                6 => self.m_shape = Some(pb.read_varint()),
+                _ => panic!("unknown layer tag {tag}"),
+            }
+        }
+    }
+
+    fn decode(data: Vec<u8>) -> (OColumnCacheReader, Vec<TestLayer>) {
+        let mut pb = Protobuf::from(data);
+        let mut tile = TestTile::default();
+        pb.read_fields(&mut tile, None);
+        let layers = tile
+            .layers
+            .into_iter()
+            .map(|bytes| {
+                let mut pb = Protobuf::from(bytes);
+                let mut layer = TestLayer::default();
+                pb.read_fields(&mut layer, None);
+                layer
+            })
+            .collect();
+        (tile.cache, layers)
+    }
+
+    /// Read every raw varint of a feature
+    fn feature_varints(bytes: &[u8]) -> Vec<u64> {
+        let mut pb = Protobuf::from(bytes.to_vec());
+        let mut varints = vec![];
+        while pb.get_pos() < bytes.len() {
+            varints.push(pb.read_varint());
+        }
+        varints
+    }
+
+    fn primitive(value: PrimitiveValue) -> ValueType {
+        ValueType::Primitive(value)
+    }
+
+    fn properties(pairs: &[(&str, ValueType)]) -> Properties {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+    }
+
+    #[test]
+    fn test_weave() {
+        assert_eq!(weave_2d(0b11, 0b01), 0b0111);
+        assert_eq!(unweave_2d(weave_2d(12_345, 54_321)), (12_345, 54_321));
+        assert_eq!(unweave_3d(weave_3d(1, 65_535, 300)), (1, 65_535, 300));
+        assert_eq!(unweave_point(weave_point(-4_096, 8_191)), (-4_096, 8_191));
+        assert_eq!(unweave_point_3d(weave_point_3d(-1, 2, -3)), (-1, 2, -3));
+
+        let points = vec![(0, 0, 0), (4_096, -10, 5), (100, 200, -300)];
+        assert_eq!(unweave_and_delta_decode(&weave_and_delta_encode(&points, true), true), points);
+        let points = vec![(1, 2, 0), (-3, 4, 0)];
+        assert_eq!(
+            unweave_and_delta_decode(&weave_and_delta_encode(&points, false), false),
+            points
+        );
+        assert_eq!(delta_decode(&delta_encode(&[5, 1, 9, 9])), vec![5, 1, 9, 9]);
+        assert_eq!(decode_offset(encode_offset(1.25)), 1.25);
+    }
+
+    #[test]
+    fn test_bbox() {
+        let bbox = BBox3D::new(-180., -90., 180., 90., 0., 0.);
+        let quantized = quantize_bbox(&bbox, false);
+        assert_eq!(quantized.len(), 12);
+        assert_eq!(dequantize_bbox(&quantized), Some(bbox));
+
+        let bbox = BBox3D::new(0., 0., 0., 0., -1.5, 2.5);
+        let quantized = quantize_bbox(&bbox, true);
+        assert_eq!(quantized.len(), 20);
+        let decoded = dequantize_bbox(&quantized).unwrap();
+        assert!((decoded.left - 0.).abs() < 1e-4 && (decoded.bottom - 0.).abs() < 1e-4);
+        assert_eq!((decoded.near, decoded.far), (-1.5, 2.5));
+        assert_eq!(dequantize_bbox(&[0; 5]), None);
+    }
+
+    #[test]
+    fn test_shapes() {
+        let a = properties(&[
+            ("count", primitive(PrimitiveValue::U64(3))),
+            ("name", primitive(PrimitiveValue::String("a".into()))),
+            (
+                "list",
+                ValueType::Array(vec![ValuePrimitiveType::Primitive(PrimitiveValue::I64(-1))]),
+            ),
+        ]);
+        let b = properties(&[
+            ("count", primitive(PrimitiveValue::F32(1.5))),
+            (
+                "nested",
+                ValueType::Nested(properties(&[("on", primitive(PrimitiveValue::Bool(true)))])),
+            ),
+        ]);
+        let mut shape = OShape::new();
+        merge_shape(&mut shape, &a);
+        merge_shape(&mut shape, &b);
+        assert_eq!(shape.get("count"), Some(&OShapeType::Primitive(OPrimitiveShape::F32)));
+
+        let mut cache = ColumnCacheWriter::default();
+        let shape_index = cache.add_shape(&shape);
+        let a_index = cache.add_value(&a, &shape);
+        let b_index = cache.add_value(&b, &shape);
+        let mut pb = Protobuf::new();
+        pb.write_message(5, &cache);
+        let mut pb = Protobuf::from(pb.take());
+        let mut reader = OColumnCacheReader::default();
+        pb.read_field();
+        pb.read_message(&mut reader);
+
+        assert_eq!(reader.get_shape(shape_index), Some(shape.clone()));
+        let mut expected_a = a.clone();
+        expected_a.insert("count".into(), primitive(PrimitiveValue::F32(3.)));
+        expected_a.insert(
+            "nested".into(),
+            ValueType::Nested(properties(&[("on", primitive(PrimitiveValue::Bool(false)))])),
+        );
+        assert_eq!(reader.get_value(a_index, &shape), Some(expected_a));
+        let mut expected_b = b.clone();
+        expected_b.insert("name".into(), primitive(PrimitiveValue::String("".into())));
+        expected_b.insert("list".into(), ValueType::Array(vec![]));
+        assert_eq!(reader.get_value(b_index, &shape), Some(expected_b));
+        // indexes past the end of the columns decode to nothing
+        assert_eq!(reader.get_shape(100), None);
+        assert_eq!(reader.get_value(shape_index, &shape), None);
+    }
+
+    #[test]
+    fn test_encode_ovt() {
+        let mut tile: Tile<()> = Tile::new(CellId::from_face(0));
+        let point = VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            coordinates: VectorPoint::new(0.5, 0.25, None, None),
+            bbox: Some(BBox3D::new(-10., -20., 30., 40., 0., 0.)),
+            ..Default::default()
+        });
+        let props = properties(&[("name", primitive(PrimitiveValue::String("a".into())))]);
+        tile.add_feature(VectorFeature::new_wm(Some(3), props, point, None), None);
+        let m = properties(&[("speed", primitive(PrimitiveValue::U64(5)))]);
+        let line = VectorGeometry::LineString(VectorLineStringGeometry {
+            _type: VectorGeometryType::LineString,
+            is_3d: true,
+            offset: Some(1.25),
+            coordinates: vec![
+                VectorPoint::new(0., 0., Some(0.5), Some(m.clone())),
+                VectorPoint::new(1., 1., Some(0.75), None),
+            ],
+            ..Default::default()
+        });
+        tile.add_feature(VectorFeature::new_wm(None, Properties::new(), line, None), None);
+        let polygon = VectorGeometry::Polygon(VectorPolygonGeometry {
+            _type: VectorGeometryType::Polygon,
+            indices: Some(vec![0, 1, 2]),
+            coordinates: vec![vec![
+                VectorPoint::new(0., 0., None, None),
+                VectorPoint::new(1., 0., None, None),
+                VectorPoint::new(1., 1., None, None),
+                VectorPoint::new(0., 0., None, None),
+            ]],
+            ..Default::default()
+        });
+        tile.add_feature(
+            VectorFeature::new_wm(None, Properties::new(), polygon, None),
+            Some("water".into()),
+        );
+
+        let (cache, layers) = decode(tile.encode_ovt(4_000));
+        assert_eq!(layers.len(), 2);
+        let layer = &layers[0];
+        assert_eq!(layer.version, 1);
+        assert_eq!(cache.get_string(layer.name).unwrap(), "default");
+        // 4,000 is rounded up to 4,096
+        assert_eq!(OVT_EXTENTS[layer.extent], 4_096);
+        let shape = cache.get_shape(layer.shape).unwrap();
+        let m_shape = cache.get_shape(layer.m_shape.unwrap()).unwrap();
+        assert_eq!(layer.features.len(), 2);
+
+        // features are sorted by type, so the 2D point comes first
+        let point = feature_varints(&layer.features[0]);
+        assert_eq!(point[0], OFeatureType::Points as u64);
+        assert_eq!(point[1], (OFLAG_ID | OFLAG_BBOX | OFLAG_SINGLE) as u64);
+        assert_eq!(point[2], 3);
+        assert_eq!(
+            cache.get_value(point[3] as usize, &shape).unwrap().get("name"),
+            Some(&primitive(PrimitiveValue::String("a".into())))
+        );
+        assert_eq!(unweave_point(point[4] as u32), (2_048, 1_024));
+        let bbox = cache.get_bbox(point[5] as usize).unwrap();
+        assert!((bbox.left + 10.).abs() < 1e-4 && (bbox.top - 40.).abs() < 1e-4);
+
+        let line = feature_varints(&layer.features[1]);
+        assert_eq!(line[0], OFeatureType::Lines3D as u64);
+        assert_eq!(line[1], (OFLAG_OFFSETS | OFLAG_M_VALUES | OFLAG_SINGLE) as u64);
+        let geometry = cache.get_indices(line[3] as usize).unwrap();
+        assert_eq!(geometry.len(), 4);
+        assert_eq!(decode_offset(geometry[0]), 1.25);
+        assert_eq!(
+            cache.get_points(geometry[1] as usize, true).unwrap(),
+            vec![(0, 0, 2_048), (4_096, 4_096, 3_072)]
+        );
+        assert_eq!(cache.get_value(geometry[2] as usize, &m_shape), Some(m));
+        assert_eq!(
+            cache.get_value(geometry[3] as usize, &m_shape),
+            Some(properties(&[("speed", primitive(PrimitiveValue::U64(0)))]))
+        );
+
+        let layer = &layers[1];
+        assert_eq!(cache.get_string(layer.name).unwrap(), "water");
+        assert_eq!(layer.m_shape, None);
+        let polygon = feature_varints(&layer.features[0]);
+        assert_eq!(polygon[0], OFeatureType::Polygons as u64);
+        assert_eq!(polygon[1], (OFLAG_INDICES | OFLAG_SINGLE) as u64);
+        let geometry = cache.get_indices(polygon[3] as usize).unwrap();
+        // one ring, then the ring's points
+        assert_eq!(geometry[0], 1);
+        assert_eq!(
+            cache.get_points(geometry[1] as usize, false).unwrap(),
+            vec![(0, 0, 0), (4_096, 0, 0), (4_096, 4_096, 0), (0, 0, 0)]
+        );
+        assert_eq!(cache.get_indices(polygon[4] as usize), Some(vec![0, 1, 2]));
+    }
+}
@@ -4,12 +4,9 @@ use alloc::string::ToString;
 use alloc::vec;
 use alloc::vec::Vec;
 
-use libm::round;
-
 use crate::geometry::{
-    convert, CellId, ConvertFeature, ConvertVectorFeatureS2, ConvertVectorFeatureWM, Face,
-    JSONCollection, Projection, SimplifyVectorGeometry, TileChildren, VectorFeature,
-    VectorGeometry, VectorPoint,
+    convert, CellId, Face, JSONCollection, Projection, SimplifyVectorGeometry, TileChildren,
+    VectorFeature, VectorGeometry, VectorPoint,
 };
 
 /// If a user creates metadata for a VectorFeature, it needs to define a get_layer function
@@ -71,7 +68,8 @@ impl<M: HasLayer + Clone> Tile<M> {
         if self.transformed {
             return;
         }
-        let (zoom, i, j) = self.id.to_zoom_ij(None);
+        let zoom = self.id.level();
+        let (_face, i, j) = self.id.to_zoom_ij(Some(zoom));
 
         for layer in self.layers.values_mut() {
             for feature in layer.features.iter_mut() {
@@ -139,14 +137,7 @@ impl<M: HasLayer + Clone> Default for TileStore<M> {
         }
     }
 }
-impl<
-        M: HasLayer
-            + Clone
-            + ConvertFeature<M>
-            + ConvertVectorFeatureWM<M>
-            + ConvertVectorFeatureS2<M>,
-    > TileStore<M>
-{
+impl<M: HasLayer + Clone> TileStore<M> {
     /// Create a new TileStore
     pub fn new(data: JSONCollection<M>, options: TileStoreOptions) -> Self {
         let mut tile_store = Self {
@@ -301,7 +292,43 @@ pub trait TransformVectorPoint {
 impl TransformVectorPoint for VectorPoint {
     /// Transform the point from the 0->1 coordinate system to a tile coordinate system
     fn transform(&mut self, zoom: f64, ti: f64, tj: f64) {
-        self.x = round(self.x * zoom - ti);
-        self.y = round(self.y * zoom - tj);
+        self.x = self.x * zoom - ti;
+        self.y = self.y * zoom - tj;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{VectorGeometryType, VectorMultiPointGeometry};
+    use s2json::Properties;
+
+    #[test]
+    fn test_tile_store_transform() {
+        let mut store: TileStore<()> = TileStore::default();
+        let geometry = VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+            _type: VectorGeometryType::MultiPoint,
+            coordinates: vec![
+                VectorPoint::new(0.6, 0.7, None, None),
+                VectorPoint::new(0.8125, 0.90625, None, None),
+            ],
+            ..Default::default()
+        });
+        store.add_feature(VectorFeature::new_wm(None, Properties::new(), geometry, None));
+
+        // requesting a zoom 2 tile splits and transforms its zoom 1 parent
+        assert!(store.get_tile(CellId::from_face_ij(0, 2, 2, Some(2))).is_some());
+        let tile = store.get_tile(CellId::from_face_ij(0, 1, 1, Some(1))).unwrap();
+        assert!(tile.transformed);
+        let VectorGeometry::MultiPoint(points) = &tile.layers["default"].features[0].geometry
+        else {
+            panic!("expected a multi point");
+        };
+        // coordinates are relative to the zoom 1 tile and keep their sub-tile precision
+        let coordinates: Vec<(f64, f64)> = points.coordinates.iter().map(|p| (p.x, p.y)).collect();
+        assert_eq!(coordinates.len(), 2);
+        assert!((coordinates[0].0 - 0.2).abs() < 1e-12);
+        assert!((coordinates[0].1 - 0.4).abs() < 1e-12);
+        assert_eq!(coordinates[1], (0.625, 0.8125));
     }
 }
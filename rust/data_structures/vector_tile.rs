@@ -0,0 +1,624 @@
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use libm::round;
+use pbf::{zigzag, ProtoWrite, Protobuf};
+
+use super::{HasLayer, Layer, Tile};
+use crate::geometry::{PrimitiveValue, Properties, ValueType, VectorGeometry, VectorPoint};
+
+/// The default extent of a vector tile
+pub const DEFAULT_EXTENT: u32 = 4_096;
+
+/// MoveTo geometry command
+pub const COMMAND_MOVE_TO: u32 = 1;
+/// LineTo geometry command
+pub const COMMAND_LINE_TO: u32 = 2;
+/// ClosePath geometry command
+pub const COMMAND_CLOSE_PATH: u32 = 7;
+
+/// Vector tile geometry types
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum VectorTileGeomType {
+    /// Unknown geometry type
+    #[default]
+    Unknown = 0,
+    /// (Multi)Point
+    Points = 1,
+    /// (Multi)LineString
+    Lines = 2,
+    /// (Multi)Polygon
+    Polygons = 3,
+}
+impl From<u8> for VectorTileGeomType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => VectorTileGeomType::Points,
+            2 => VectorTileGeomType::Lines,
+            3 => VectorTileGeomType::Polygons,
+            _ => VectorTileGeomType::Unknown,
+        }
+    }
+}
+
+/// Build a geometry command integer from a command id and its repeat count
+pub fn command_encode(cmd: u32, count: u32) -> u32 {
+    (cmd & 0x7) | (count << 3)
+}
+
+/// # Vector Tile Encoding
+///
+/// ## Description
+/// Serialize a tile into the
+/// [Mapbox Vector Tile 2.1](https://github.com/mapbox/vector-tile-spec/tree/master/2.1) format:
+///
+/// - Tile: `layers = 3`
+/// - Layer: `name = 1`, `features = 2`, `keys = 3`, `values = 4`, `extent = 5`, `version = 15`
+/// - Feature: `id = 1`, `tags = 2`, `type = 3`, `geometry = 4`
+///
+/// Keys and values are deduplicated per layer. Nested properties are flattened using a `.`
+/// separator (`{ a: { b: 1 } }` becomes `{ "a.b": 1 }`) and arrays are stored as JSON strings.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::data_structures::Tile;
+/// use gistools::geometry::{
+///     CellId, Properties, VectorFeature, VectorGeometry, VectorGeometryType, VectorPoint,
+///     VectorPointGeometry,
+/// };
+///
+/// let mut tile: Tile<()> = Tile::new(CellId::from_face(0));
+/// let geometry = VectorGeometry::Point(VectorPointGeometry {
+///     _type: VectorGeometryType::Point,
+///     coordinates: VectorPoint::new(0.5, 0.5, None, None),
+///     ..Default::default()
+/// });
+/// tile.add_feature(VectorFeature::new_wm(Some(1), Properties::new(), geometry, None), None);
+///
+/// let mvt: Vec<u8> = tile.encode_mvt(4_096);
+/// ```
+impl<M: HasLayer + Clone> Tile<M> {
+    /// Encode the tile as a Mapbox Vector Tile 2.1 protobuf. Feature coordinates are expected to
+    /// be relative to the tile (see [`Tile::transform`]) and are scaled to `extent`.
+    /// Z and M values are dropped.
+    pub fn encode_mvt(&self, extent: u32) -> Vec<u8> {
+        let project = TileProjection::new(self, extent);
+
+        let mut pbf = Protobuf::new();
+        for layer in self.layers.values() {
+            let layer = LayerWriter::new(layer, &project, extent);
+            if !layer.features.is_empty() {
+                pbf.write_message(3, &layer);
+            }
+        }
+        pbf.take()
+    }
+}
+
+/// Projects a feature point into the integer coordinates of the tile
+pub(super) struct TileProjection {
+    pub(super) extent: f64,
+    scale: f64,
+    ti: f64,
+    tj: f64,
+}
+impl TileProjection {
+    pub(super) fn new<M>(tile: &Tile<M>, extent: u32) -> Self {
+        // if the tile hasn't been transformed, move the features into the tile's coordinate space
+        let zoom = tile.id.level();
+        let (_face, i, j) = tile.id.to_zoom_ij(Some(zoom));
+        let (scale, ti, tj) = if tile.transformed {
+            (1., 0., 0.)
+        } else {
+            ((1_u64 << zoom) as f64, i as f64, j as f64)
+        };
+        TileProjection { extent: extent as f64, scale, ti, tj }
+    }
+
+    pub(super) fn project(&self, point: &VectorPoint) -> (i64, i64) {
+        (
+            round((point.x * self.scale - self.ti) * self.extent) as i64,
+            round((point.y * self.scale - self.tj) * self.extent) as i64,
+        )
+    }
+}
+
+/// A hashable representation of a vector tile value used for deduplication
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum ValueKey {
+    String(String),
+    Float(u32),
+    Double(u64),
+    UInt(u64),
+    SInt(i64),
+    Bool(bool),
+}
+impl ProtoWrite for ValueKey {
+    fn write(&self, pbf: &mut Protobuf) {
+        match self {
+            ValueKey::String(s) => pbf.write_string_field(1, s),
+            ValueKey::Float(f) => pbf.write_fixed_field(2, f32::from_bits(*f)),
+            ValueKey::Double(d) => pbf.write_fixed_field(3, f64::from_bits(*d)),
+            ValueKey::UInt(u) => pbf.write_varint_field(5, *u),
+            ValueKey::SInt(i) => pbf.write_s_varint_field(6, *i),
+            ValueKey::Bool(b) => pbf.write_varint_field(7, *b),
+        }
+    }
+}
+impl ValueKey {
+    fn from_primitive(value: &PrimitiveValue) -> Option<Self> {
+        match value {
+            PrimitiveValue::String(s) => Some(ValueKey::String(s.clone())),
+            PrimitiveValue::U64(u) => Some(ValueKey::UInt(*u)),
+            PrimitiveValue::I64(i) => Some(ValueKey::SInt(*i)),
+            PrimitiveValue::F32(f) => Some(ValueKey::Float(f.to_bits())),
+            PrimitiveValue::F64(d) => Some(ValueKey::Double(d.to_bits())),
+            PrimitiveValue::Bool(b) => Some(ValueKey::Bool(*b)),
+            PrimitiveValue::Null => None,
+        }
+    }
+}
+
+/// Flatten nested properties into `(key, value)` pairs. Nested keys are joined with a `.` and
+/// arrays are stored as JSON strings. Null values are skipped.
+fn flatten_properties(prefix: &str, properties: &Properties, out: &mut Vec<(String, ValueKey)>) {
+    for (key, value) in properties {
+        let key = if prefix.is_empty() { key.clone() } else { prefix.to_string() + "." + key };
+        match value {
+            ValueType::Primitive(p) => {
+                if let Some(v) = ValueKey::from_primitive(p) {
+                    out.push((key, v));
+                }
+            }
+            ValueType::Array(arr) => {
+                let json = serde_json::to_string(arr).unwrap_or_default();
+                out.push((key, ValueKey::String(json)));
+            }
+            ValueType::Nested(nested) => flatten_properties(&key, nested, out),
+        }
+    }
+}
+
+/// Writes a vector tile layer, deduplicating its keys and values
+struct LayerWriter<'a> {
+    name: &'a str,
+    extent: u32,
+    keys: BTreeMap<String, u32>,
+    values: BTreeMap<ValueKey, u32>,
+    features: Vec<FeatureWriter>,
+}
+impl<'a> LayerWriter<'a> {
+    fn new<M>(layer: &'a Layer<M>, project: &TileProjection, extent: u32) -> Self {
+        let mut writer = LayerWriter {
+            name: &layer.name,
+            extent,
+            keys: BTreeMap::new(),
+            values: BTreeMap::new(),
+            features: vec![],
+        };
+        for feature in &layer.features {
+            let mut geometry = GeometryWriter::new(project);
+            let _type = geometry.write_geometry(&feature.geometry);
+            if geometry.commands.is_empty() {
+                continue;
+            }
+            let tags = writer.tags(&feature.properties);
+            writer.features.push(FeatureWriter {
+                id: feature.id,
+                tags,
+                _type,
+                geometry: geometry.commands,
+            });
+        }
+        writer
+    }
+
+    /// Convert properties into key/value index pairs
+    fn tags(&mut self, properties: &Properties) -> Vec<u32> {
+        let mut pairs = vec![];
+        flatten_properties("", properties, &mut pairs);
+        let mut tags = Vec::with_capacity(pairs.len() * 2);
+        for (key, value) in pairs {
+            let next_key = self.keys.len() as u32;
+            tags.push(*self.keys.entry(key).or_insert(next_key));
+            let next_value = self.values.len() as u32;
+            tags.push(*self.values.entry(value).or_insert(next_value));
+        }
+        tags
+    }
+}
+impl ProtoWrite for LayerWriter<'_> {
+    fn write(&self, pbf: &mut Protobuf) {
+        pbf.write_varint_field(15, 2);
+        pbf.write_string_field(1, self.name);
+        for feature in &self.features {
+            pbf.write_message(2, feature);
+        }
+        let mut keys: Vec<(&String, &u32)> = self.keys.iter().collect();
+        keys.sort_by_key(|(_, i)| **i);
+        for (key, _) in keys {
+            pbf.write_string_field(3, key);
+        }
+        let mut values: Vec<(&ValueKey, &u32)> = self.values.iter().collect();
+        values.sort_by_key(|(_, i)| **i);
+        for (value, _) in values {
+            pbf.write_message(4, value);
+        }
+        pbf.write_varint_field(5, self.extent);
+    }
+}
+
+/// Writes a vector tile feature
+struct FeatureWriter {
+    id: Option<u64>,
+    tags: Vec<u32>,
+    _type: VectorTileGeomType,
+    geometry: Vec<u32>,
+}
+impl ProtoWrite for FeatureWriter {
+    fn write(&self, pbf: &mut Protobuf) {
+        if let Some(id) = self.id {
+            pbf.write_varint_field(1, id);
+        }
+        if !self.tags.is_empty() {
+            pbf.write_packed_varint(2, &self.tags);
+        }
+        pbf.write_varint_field(3, self._type as u8);
+        pbf.write_packed_varint(4, &self.geometry);
+    }
+}
+
+/// Encodes a geometry into zigzag delta encoded commands
+struct GeometryWriter<'a> {
+    project: &'a TileProjection,
+    cursor: (i64, i64),
+    commands: Vec<u32>,
+}
+impl<'a> GeometryWriter<'a> {
+    fn new(project: &'a TileProjection) -> Self {
+        Self { project, cursor: (0, 0), commands: vec![] }
+    }
+
+    /// Write the geometry and return its vector tile geometry type
+    fn write_geometry(&mut self, geometry: &VectorGeometry) -> VectorTileGeomType {
+        match geometry {
+            VectorGeometry::Point(g) => {
+                self.write_points(core::slice::from_ref(&g.coordinates));
+                VectorTileGeomType::Points
+            }
+            VectorGeometry::MultiPoint(g) => {
+                self.write_points(&g.coordinates);
+                VectorTileGeomType::Points
+            }
+            VectorGeometry::LineString(g) => {
+                self.write_line(&g.coordinates);
+                VectorTileGeomType::Lines
+            }
+            VectorGeometry::MultiLineString(g) => {
+                g.coordinates.iter().for_each(|line| self.write_line(line));
+                VectorTileGeomType::Lines
+            }
+            VectorGeometry::Polygon(g) => {
+                self.write_polygon(&g.coordinates);
+                VectorTileGeomType::Polygons
+            }
+            VectorGeometry::MultiPolygon(g) => {
+                g.coordinates.iter().for_each(|polygon| self.write_polygon(polygon));
+                VectorTileGeomType::Polygons
+            }
+        }
+    }
+
+    fn write_points(&mut self, points: &[VectorPoint]) {
+        if points.is_empty() {
+            return;
+        }
+        self.commands.push(command_encode(COMMAND_MOVE_TO, points.len() as u32));
+        for point in points {
+            self.write_point(self.project.project(point));
+        }
+    }
+
+    fn write_line(&mut self, line: &[VectorPoint]) {
+        let line = self.project_line(line, false);
+        if line.len() < 2 {
+            return;
+        }
+        self.write_path(&line, false);
+    }
+
+    fn write_polygon(&mut self, polygon: &[Vec<VectorPoint>]) {
+        for (i, ring) in polygon.iter().enumerate() {
+            let mut ring = self.project_line(ring, true);
+            if ring.len() < 3 {
+                // a degenerate outer ring invalidates the whole polygon
+                if i == 0 {
+                    return;
+                }
+                continue;
+            }
+            // outer rings are clockwise (positive area) and holes are counter-clockwise
+            let area = ring_area(&ring);
+            if area == 0 {
+                continue;
+            }
+            if (i == 0) != (area > 0) {
+                ring.reverse();
+            }
+            self.write_path(&ring, true);
+        }
+    }
+
+    /// Project a line into tile coordinates removing consecutive duplicate points. If `closed`,
+    /// the closing point of the ring is also removed as it's implied by ClosePath.
+    fn project_line(&self, line: &[VectorPoint], closed: bool) -> Vec<(i64, i64)> {
+        let mut res: Vec<(i64, i64)> = Vec::with_capacity(line.len());
+        for point in line {
+            let projected = self.project.project(point);
+            if res.last() != Some(&projected) {
+                res.push(projected);
+            }
+        }
+        if closed && res.len() > 1 && res[0] == res[res.len() - 1] {
+            res.pop();
+        }
+        res
+    }
+
+    fn write_path(&mut self, path: &[(i64, i64)], close: bool) {
+        self.commands.push(command_encode(COMMAND_MOVE_TO, 1));
+        self.write_point(path[0]);
+        self.commands.push(command_encode(COMMAND_LINE_TO, path.len() as u32 - 1));
+        for point in &path[1..] {
+            self.write_point(*point);
+        }
+        if close {
+            self.commands.push(command_encode(COMMAND_CLOSE_PATH, 1));
+        }
+    }
+
+    fn write_point(&mut self, (x, y): (i64, i64)) {
+        self.commands.push(zigzag(x - self.cursor.0) as u32);
+        self.commands.push(zigzag(y - self.cursor.1) as u32);
+        self.cursor = (x, y);
+    }
+}
+
+/// Twice the signed area of a ring. Positive areas are clockwise in tile coordinates (y down)
+fn ring_area(ring: &[(i64, i64)]) -> i64 {
+    let mut sum = 0;
+    for i in 0..ring.len() {
+        let a = ring[i];
+        let b = ring[(i + 1) % ring.len()];
+        sum += a.0 * b.1 - b.0 * a.1;
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{
+        CellId, VectorFeature, VectorGeometryType, VectorLineStringGeometry,
+        VectorMultiPointGeometry, VectorPointGeometry, VectorPolygonGeometry,
+    };
+    use pbf::ProtoRead;
+
+    #[derive(Debug, Default)]
+    struct TestTile {
+        layers: Vec<TestLayer>,
+    }
+    impl ProtoRead for TestTile {
+        fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+            if tag == 3 {
+                let mut layer = TestLayer::default();
+                pb.read_message(&mut layer);
+                self.layers.push(layer);
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct TestLayer {
+        version: u32,
+        name: String,
+        features: Vec<TestFeature>,
+        keys: Vec<String>,
+        values: Vec<TestValue>,
+        extent: u32,
+    }
+    impl ProtoRead for TestLayer {
+        fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+            match tag {
+                15 => self.version = pb.read_varint(),
+                1 => self.name = pb.read_string(),
+                2 => {
+                    let mut feature = TestFeature::default();
+                    pb.read_message(&mut feature);
+                    self.features.push(feature);
+                }
+                3 => self.keys.push(pb.read_string()),
+                4 => {
+                    let mut value = TestValue::default();
+                    pb.read_message(&mut value);
+                    self.values.push(value);
+                }
+                5 => self.extent = pb.read_varint(),
+                _ => panic!("unknown layer tag {tag}"),
+            }
+        }
+    }
+
+    #[derive(Debug, Default, PartialEq)]
+    enum TestValue {
+        #[default]
+        None,
+        String(String),
+        Double(f64),
+        UInt(u64),
+        SInt(i64),
+        Bool(bool),
+    }
+    impl ProtoRead for TestValue {
+        fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+            *self = match tag {
+                1 => TestValue::String(pb.read_string()),
+                3 => TestValue::Double(pb.read_fixed()),
+                5 => TestValue::UInt(pb.read_varint()),
+                6 => TestValue::SInt(pb.read_s_varint()),
+                7 => TestValue::Bool(pb.read_varint()),
+                _ => panic!("unknown value tag {tag}"),
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct TestFeature {
+        id: Option<u64>,
+        tags: Vec<u32>,
+        _type: u8,
+        geometry: Vec<u32>,
+    }
+    impl ProtoRead for TestFeature {
+        fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+            match tag {
+                1 => self.id = Some(pb.read_varint()),
+                2 => self.tags = pb.read_packed(),
+                3 => self._type = pb.read_varint(),
+                4 => self.geometry = pb.read_packed(),
+                _ => panic!("unknown feature tag {tag}"),
+            }
+        }
+    }
+
+    fn decode(data: Vec<u8>) -> TestTile {
+        let mut pb = Protobuf::from(data);
+        let mut tile = TestTile::default();
+        pb.read_fields(&mut tile, None);
+        tile
+    }
+
+    fn point(x: f64, y: f64, z: Option<f64>) -> VectorPoint {
+        VectorPoint::new(x, y, z, None)
+    }
+
+    fn properties(pairs: &[(&str, ValueType)]) -> Properties {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+    }
+
+    fn test_tile() -> Tile<()> {
+        let mut tile: Tile<()> = Tile::new(CellId::from_face(0));
+        let point_geometry = VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            coordinates: point(0.5, 0.5, Some(10.)),
+            ..Default::default()
+        });
+        let props = properties(&[
+            ("name", ValueType::Primitive(PrimitiveValue::String("a".into()))),
+            ("count", ValueType::Primitive(PrimitiveValue::U64(3))),
+            ("empty", ValueType::Primitive(PrimitiveValue::Null)),
+            (
+                "nested",
+                ValueType::Nested(properties(&[(
+                    "neg",
+                    ValueType::Primitive(PrimitiveValue::I64(-2)),
+                )])),
+            ),
+        ]);
+        tile.add_feature(VectorFeature::new_wm(Some(7), props, point_geometry, None), None);
+        let line_geometry = VectorGeometry::LineString(VectorLineStringGeometry {
+            _type: VectorGeometryType::LineString,
+            coordinates: vec![point(0., 0., None), point(0., 0., None), point(0.25, 0.5, None)],
+            ..Default::default()
+        });
+        let props =
+            properties(&[("name", ValueType::Primitive(PrimitiveValue::String("a".into())))]);
+        tile.add_feature(VectorFeature::new_wm(None, props, line_geometry, None), None);
+        // outer ring is counter-clockwise and the hole clockwise, both need to be rewound
+        let polygon_geometry = VectorGeometry::Polygon(VectorPolygonGeometry {
+            _type: VectorGeometryType::Polygon,
+            coordinates: vec![
+                vec![
+                    point(0., 0., None),
+                    point(0., 1., None),
+                    point(1., 1., None),
+                    point(1., 0., None),
+                    point(0., 0., None),
+                ],
+                vec![
+                    point(0.25, 0.25, None),
+                    point(0.75, 0.25, None),
+                    point(0.75, 0.75, None),
+                    point(0.25, 0.25, None),
+                ],
+            ],
+            ..Default::default()
+        });
+        tile.add_feature(
+            VectorFeature::new_wm(Some(9), Properties::new(), polygon_geometry, None),
+            Some("water".into()),
+        );
+        tile
+    }
+
+    #[test]
+    fn test_encode_mvt() {
+        let tile = decode(test_tile().encode_mvt(DEFAULT_EXTENT));
+        assert_eq!(tile.layers.len(), 2);
+
+        let layer = &tile.layers[0];
+        assert_eq!(layer.version, 2);
+        assert_eq!(layer.name, "default");
+        assert_eq!(layer.extent, 4_096);
+        assert_eq!(layer.keys, vec!["count", "name", "nested.neg"]);
+        assert_eq!(
+            layer.values,
+            vec![TestValue::UInt(3), TestValue::String("a".into()), TestValue::SInt(-2)]
+        );
+        assert_eq!(layer.features.len(), 2);
+        let point = &layer.features[0];
+        assert_eq!(point.id, Some(7));
+        assert_eq!(point.tags, vec![0, 0, 1, 1, 2, 2]);
+        assert_eq!(point._type, VectorTileGeomType::Points as u8);
+        assert_eq!(point.geometry, vec![9, 4_096, 4_096]);
+        let line = &layer.features[1];
+        assert_eq!(line.id, None);
+        assert_eq!(line.tags, vec![1, 1]);
+        assert_eq!(line._type, VectorTileGeomType::Lines as u8);
+        // duplicate points are removed
+        assert_eq!(line.geometry, vec![9, 0, 0, 10, 2_048, 4_096]);
+
+        let layer = &tile.layers[1];
+        assert_eq!(layer.name, "water");
+        let polygon = &layer.features[0];
+        assert_eq!(polygon._type, VectorTileGeomType::Polygons as u8);
+        assert_eq!(
+            polygon.geometry,
+            vec![
+                // outer ring: (4096,0) -> (4096,4096) -> (0,4096) -> (0,0)
+                9, 8_192, 0, 26, 0, 8_192, 8_191, 0, 0, 8_191, 15,
+                // hole: (3072,3072) -> (3072,1024) -> (1024,1024)
+                9, 6_144, 6_144, 18, 0, 4_095, 4_095, 0, 15,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_encode_transformed() {
+        let mut tile: Tile<()> = Tile::new(CellId::from_face_ij(0, 1, 1, Some(1)));
+        let geometry = VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+            _type: VectorGeometryType::MultiPoint,
+            coordinates: vec![point(0.75, 0.75, None), point(1., 1., None)],
+            ..Default::default()
+        });
+        tile.add_feature(VectorFeature::new_wm(None, Properties::new(), geometry, None), None);
+        let untransformed = tile.encode_mvt(512);
+        tile.transform(0., None);
+        let transformed = tile.encode_mvt(512);
+        assert_eq!(untransformed, transformed);
+        let layer = &decode(transformed).layers[0];
+        assert_eq!(layer.features[0].geometry, vec![17, 512, 512, 512, 512]);
+    }
+}
@@ -39,8 +39,8 @@ impl<M: HasLayer + Clone> Tile<M> {
  */
 pub fn split_tile<M: HasLayer + Clone>(tile: &mut Tile<M>, buffer: Option<f64>) -> TileChildren<M> {
     let buffer = buffer.unwrap_or(0.0625);
-    let face = tile.id.face();
-    let (zoom, i, j) = tile.id.to_zoom_ij(None);
+    let zoom = tile.id.level();
+    let (face, i, j) = tile.id.to_zoom_ij(Some(zoom));
     let [bl_id, br_id, tl_id, tr_id] = S2CellId::children_ij(face, zoom, i, j);
     let mut children = TileChildren {
         bottom_left: Tile::new(bl_id),
@@ -7,9 +7,7 @@ use crate::geometry::{
 };
 
 /// Given an input data, convert it to a vector of VectorFeature
-pub fn convert<
-    M: Clone + ConvertVectorFeatureWM<M> + ConvertFeature<M> + ConvertVectorFeatureS2<M>,
->(
+pub fn convert<M: Clone>(
     projection: Projection,
     data: &JSONCollection<M>,
     tolerance: Option<f64>,
@@ -50,7 +48,7 @@ pub fn convert<
 }
 
 /// Convert a GeoJSON Feature to the appropriate VectorFeature
-fn convert_feature<M: Clone + ConvertFeature<M> + ConvertVectorFeatureS2<M>>(
+fn convert_feature<M: Clone>(
     projection: Projection,
     data: &Feature<M>,
     tolerance: Option<f64>,
@@ -68,7 +66,7 @@ fn convert_feature<M: Clone + ConvertFeature<M> + ConvertVectorFeatureS2<M>>(
 }
 
 /// Convert a GeoJSON VectorFeature to the appropriate VectorFeature
-fn convert_vector_feature<M: Clone + ConvertVectorFeatureWM<M>>(
+fn convert_vector_feature<M: Clone>(
     projection: Projection,
     data: &VectorFeature<M>,
     tolerance: Option<f64>,
@@ -1,7 +1,9 @@
 mod clip;
 mod convert;
+mod ring;
 mod simplify;
 
 pub use clip::*;
 pub use convert::*;
+pub use ring::*;
 pub use simplify::*;
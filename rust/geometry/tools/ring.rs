@@ -0,0 +1,38 @@
+use crate::geometry::{VectorLineString, VectorPoint};
+
+/// Ray casting point in ring test. The ring may be open or closed and its winding order doesn't
+/// matter. Points on the boundary may be inside or outside.
+pub fn point_in_ring(point: &VectorPoint, ring: &VectorLineString) -> bool {
+    let mut inside = false;
+    let Some(mut j) = ring.len().checked_sub(1) else { return false };
+    for i in 0..ring.len() {
+        let (a, b) = (&ring[i], &ring[j]);
+        if (a.y > point.y) != (b.y > point.y)
+            && point.x < (b.x - a.x) * (point.y - a.y) / (b.y - a.y) + a.x
+        {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_point_in_ring() {
+        let ring: VectorLineString = [(0., 0.), (4., 0.), (4., 4.), (0., 4.), (0., 0.)]
+            .iter()
+            .map(|(x, y)| VectorPoint::new(*x, *y, None, None))
+            .collect();
+        assert!(point_in_ring(&VectorPoint::new(2., 2., None, None), &ring));
+        assert!(!point_in_ring(&VectorPoint::new(5., 2., None, None), &ring));
+        let mut reversed = ring.clone();
+        reversed.reverse();
+        assert!(point_in_ring(&VectorPoint::new(1., 3., None, None), &reversed));
+        assert!(!point_in_ring(&VectorPoint::new(1., 3., None, None), &vec![]));
+    }
+}
@@ -181,24 +181,22 @@ fn simplify_line(
     ring
 }
 
-/// In place adjust the ring if necessary
+/// In place adjust the ring if necessary.
+/// The signed area is computed in a y-down (tile) coordinate system, so `clockwise` set to true
+/// results in a counter-clockwise ring when the y axis points up (e.g. lon-lat coordinates).
 pub fn rewind(ring: &mut VectorLineString, clockwise: bool) {
     let len = ring.len();
+    if len < 4 {
+        return;
+    }
     let mut area: f64 = 0.;
-    let mut i = 0;
-    let mut j = len - 2;
-    while i < len {
+    let mut j = len - 1;
+    for i in 0..len {
         area += (ring[i].x - ring[j].x) * (ring[i].y + ring[j].y);
         j = i;
-        i += 2;
     }
-    i = 0;
     if (area > 0.) == clockwise {
-        let len_half = len / 2;
-        while i < len_half {
-            ring.swap(i, len - i - 1);
-            i += 2;
-        }
+        ring.reverse();
     }
 }
 
@@ -222,19 +220,49 @@ mod tests {
             VectorPoint::new(1., 0., None, None),
         ];
 
+        let original = ring.clone();
+
+        // already wound correctly
         rewind(&mut ring, false);
+        assert_eq!(ring, original);
 
+        rewind(&mut ring, true);
         assert_eq!(
             ring,
             vec![
                 VectorPoint::new(1., 0., None, None),
-                VectorPoint::new(0., 1., None, None),
                 VectorPoint::new(1., 1., None, None),
+                VectorPoint::new(0., 1., None, None),
                 VectorPoint::new(0., 0., None, None),
             ]
         );
     }
 
+    #[test]
+    fn test_rewind_closed_ring() {
+        // every vertex counts towards the area, not only the even ones
+        let square = vec![
+            VectorPoint::new(0., 0., None, None),
+            VectorPoint::new(1., 0., None, None),
+            VectorPoint::new(1., 1., None, None),
+            VectorPoint::new(0., 1., None, None),
+            VectorPoint::new(0., 0., None, None),
+        ];
+        let mut ring = square.clone();
+        rewind(&mut ring, true);
+        assert_eq!(ring, square);
+
+        rewind(&mut ring, false);
+        let mut reversed = square.clone();
+        reversed.reverse();
+        assert_eq!(ring, reversed);
+
+        // too short to be a ring
+        let mut line = square[..3].to_vec();
+        rewind(&mut line, false);
+        assert_eq!(line, square[..3]);
+    }
+
     #[test]
     fn test_line_string() {
         let mut line_string_geo = VectorGeometry::LineString(VectorLineStringGeometry {
@@ -26,3 +26,5 @@ pub mod readers;
 pub mod space;
 /// Utility Tools
 pub mod util;
+/// GIS Writers
+pub mod writers;
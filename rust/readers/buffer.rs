@@ -157,6 +157,120 @@ impl<'a> From<&'a [u8]> for BufferReader<'a> {
     }
 }
 
+/// A buffer reader that owns its data, e.g. a file extracted from an archive. Reads behave
+/// exactly like a [`BufferReader`] over the same bytes.
+#[derive(Default, Debug)]
+pub struct OwnedBufferReader {
+    /// The buffer
+    pub buffer: Vec<u8>,
+    cursor: usize,
+}
+impl OwnedBufferReader {
+    /// Creates a new owned buffer reader
+    pub fn new(buffer: Vec<u8>) -> Self {
+        Self { buffer, cursor: 0 }
+    }
+
+    /// Run a read on a [`BufferReader`] borrowing the data, keeping its cursor
+    fn read<R>(&mut self, f: impl FnOnce(&mut BufferReader<'_>) -> R) -> R {
+        let mut reader = BufferReader { buffer: &self.buffer, cursor: self.cursor };
+        let res = f(&mut reader);
+        self.cursor = reader.cursor;
+        res
+    }
+}
+impl Reader for OwnedBufferReader {
+    fn len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    // GETTERS
+
+    fn uint64_be(&mut self, byte_offset: Option<usize>) -> u64 {
+        self.read(|r| r.uint64_be(byte_offset))
+    }
+    fn uint64_le(&mut self, byte_offset: Option<usize>) -> u64 {
+        self.read(|r| r.uint64_le(byte_offset))
+    }
+    fn int64_be(&mut self, byte_offset: Option<usize>) -> i64 {
+        self.read(|r| r.int64_be(byte_offset))
+    }
+    fn int64_le(&mut self, byte_offset: Option<usize>) -> i64 {
+        self.read(|r| r.int64_le(byte_offset))
+    }
+    fn f64_be(&mut self, byte_offset: Option<usize>) -> f64 {
+        self.read(|r| r.f64_be(byte_offset))
+    }
+    fn f64_le(&mut self, byte_offset: Option<usize>) -> f64 {
+        self.read(|r| r.f64_le(byte_offset))
+    }
+    fn uint32_be(&mut self, byte_offset: Option<usize>) -> u32 {
+        self.read(|r| r.uint32_be(byte_offset))
+    }
+    fn uint32_le(&mut self, byte_offset: Option<usize>) -> u32 {
+        self.read(|r| r.uint32_le(byte_offset))
+    }
+    fn int32_be(&mut self, byte_offset: Option<usize>) -> i32 {
+        self.read(|r| r.int32_be(byte_offset))
+    }
+    fn int32_le(&mut self, byte_offset: Option<usize>) -> i32 {
+        self.read(|r| r.int32_le(byte_offset))
+    }
+    fn f32_be(&mut self, byte_offset: Option<usize>) -> f32 {
+        self.read(|r| r.f32_be(byte_offset))
+    }
+    fn f32_le(&mut self, byte_offset: Option<usize>) -> f32 {
+        self.read(|r| r.f32_le(byte_offset))
+    }
+    fn uint16_be(&mut self, byte_offset: Option<usize>) -> u16 {
+        self.read(|r| r.uint16_be(byte_offset))
+    }
+    fn uint16_le(&mut self, byte_offset: Option<usize>) -> u16 {
+        self.read(|r| r.uint16_le(byte_offset))
+    }
+    fn int16_be(&mut self, byte_offset: Option<usize>) -> i16 {
+        self.read(|r| r.int16_be(byte_offset))
+    }
+    fn int16_le(&mut self, byte_offset: Option<usize>) -> i16 {
+        self.read(|r| r.int16_le(byte_offset))
+    }
+    fn f16_be(&mut self, byte_offset: Option<usize>) -> f32 {
+        self.read(|r| r.f16_be(byte_offset))
+    }
+    fn f16_le(&mut self, byte_offset: Option<usize>) -> f32 {
+        self.read(|r| r.f16_le(byte_offset))
+    }
+    fn uint8(&mut self, byte_offset: Option<usize>) -> u8 {
+        self.read(|r| r.uint8(byte_offset))
+    }
+    fn int8(&mut self, byte_offset: Option<usize>) -> i8 {
+        self.read(|r| r.int8(byte_offset))
+    }
+
+    // Methods
+
+    fn tell(&mut self) -> usize {
+        self.cursor
+    }
+    fn seek(&mut self, pos: usize) {
+        self.cursor = pos;
+    }
+    fn slice(&mut self, begin: Option<usize>, end: Option<usize>) -> Vec<u8> {
+        self.read(|r| r.slice(begin, end))
+    }
+    fn seek_slice(&mut self, size: usize) -> Vec<u8> {
+        self.read(|r| r.seek_slice(size))
+    }
+    fn parse_string(&mut self, byte_offset: Option<usize>, byte_length: Option<usize>) -> String {
+        self.read(|r| r.parse_string(byte_offset, byte_length))
+    }
+}
+impl From<Vec<u8>> for OwnedBufferReader {
+    fn from(buffer: Vec<u8>) -> Self {
+        OwnedBufferReader::new(buffer)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,442 @@
+use crate::{
+    geometry::{
+        VectorFeature, VectorGeometry, VectorGeometryType, VectorPoint, VectorPointGeometry,
+    },
+    readers::{parse_wkt_geometry, FeatureIterator, Reader},
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use s2json::{PrimitiveValue, Properties, ValueType};
+
+/// The number of bytes read from the reader at a time
+const CHUNK_SIZE: usize = 65_536;
+
+/// User defined options on how to read a CSV file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CSVReaderOptions {
+    /// The ASCII character separating the values [Default: b',']
+    pub delimiter: Option<u8>,
+    /// The ASCII character quoting values [Default: b'"']
+    pub quote: Option<u8>,
+    /// The column of the longitude [Default: "lon"]
+    pub lon_key: Option<String>,
+    /// The column of the latitude [Default: "lat"]
+    pub lat_key: Option<String>,
+    /// The column of the height stored as each point's z value [Default: None]
+    pub height_key: Option<String>,
+    /// A column storing the geometry as WKT or EWKT (e.g. `POINT (1 2)`), used instead of the
+    /// longitude and latitude columns [Default: None]
+    pub geometry_key: Option<String>,
+    /// If true, integers, decimals and booleans are converted from strings [Default: true]
+    pub infer_types: Option<bool>,
+}
+
+/// # CSV Reader
+///
+/// ## Description
+/// Streams RFC 4180 CSV through the [`Reader`] trait, reading the data in chunks. Quoted values
+/// may contain delimiters, escaped (doubled) quotes and line breaks, and lines may end with
+/// `\n`, `\r\n` or `\r`. The first record is the header naming the columns.
+///
+/// Implements the [`FeatureIterator`], yielding a feature for each record. The Point is read
+/// from the longitude and latitude columns (which may be decimal degrees or
+/// degrees-minutes-seconds such as `23°30'N`), or any geometry from a WKT column. The other
+/// non-empty values are stored in the properties, converting integers, decimals and booleans
+/// unless [`CSVReaderOptions::infer_types`] is false. Records without valid coordinates are
+/// skipped.
+///
+/// NOTE: Unquoted values are trimmed. Invalid UTF-8 is replaced.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, CSVReader, CSVReaderOptions, FeatureIterator};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/csv/fixtures/basic3D.csv");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let options = CSVReaderOptions {
+///     lon_key: Some("Longitude".into()),
+///     lat_key: Some("Latitude".into()),
+///     height_key: Some("height".into()),
+///     ..Default::default()
+/// };
+/// let mut reader = CSVReader::new(BufferReader::from(&data[..]), Some(options));
+/// assert_eq!(reader.fields, vec!["Latitude", "Longitude", "height", "name"]);
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://www.rfc-editor.org/rfc/rfc4180>
+/// - <https://en.wikipedia.org/wiki/Comma-separated_values>
+#[derive(Debug)]
+pub struct CSVReader<T: Reader> {
+    reader: T,
+    /// The column names of the header
+    pub fields: Vec<String>,
+    delimiter: u8,
+    quote: u8,
+    lon_key: String,
+    lat_key: String,
+    height_key: Option<String>,
+    geometry_key: Option<String>,
+    infer_types: bool,
+    buffer: Vec<u8>,
+    /// The position of the next record in the buffer
+    pos: usize,
+    /// The offset of the next chunk in the reader
+    offset: usize,
+    chunk_size: usize,
+}
+impl<T: Reader> CSVReader<T> {
+    /// Create a new CSV reader, reading the header
+    pub fn new(reader: T, options: Option<CSVReaderOptions>) -> Self {
+        let options = options.unwrap_or_default();
+        let mut csv = CSVReader {
+            reader,
+            fields: vec![],
+            delimiter: options.delimiter.unwrap_or(b','),
+            quote: options.quote.unwrap_or(b'"'),
+            lon_key: options.lon_key.unwrap_or("lon".into()),
+            lat_key: options.lat_key.unwrap_or("lat".into()),
+            height_key: options.height_key,
+            geometry_key: options.geometry_key,
+            infer_types: options.infer_types.unwrap_or(true),
+            buffer: vec![],
+            pos: 0,
+            offset: 0,
+            chunk_size: CHUNK_SIZE,
+        };
+        csv.fields = csv.next_record().unwrap_or_default();
+        csv
+    }
+
+    /// Read the next non-empty record
+    pub fn next_record(&mut self) -> Option<Vec<String>> {
+        loop {
+            let eof = self.offset >= self.reader.len();
+            let data = &self.buffer[self.pos..];
+            match parse_record(data, self.delimiter, self.quote, eof) {
+                Some((record, used)) => {
+                    self.pos += used;
+                    if record.len() > 1 || !record[0].is_empty() {
+                        return Some(record);
+                    }
+                }
+                None if eof => return None,
+                None => self.read_chunk(),
+            }
+        }
+    }
+
+    /// Append the next chunk of the reader to the buffer, dropping the records already read
+    fn read_chunk(&mut self) {
+        self.buffer.drain(..self.pos);
+        self.pos = 0;
+        let end = (self.offset + self.chunk_size).min(self.reader.len());
+        self.buffer.extend(self.reader.slice(Some(self.offset), Some(end)));
+        if self.offset == 0 && self.buffer.starts_with(b"\xEF\xBB\xBF") {
+            self.pos = 3;
+        }
+        self.offset = end;
+    }
+
+    /// Build a point feature from a record
+    fn to_feature(&self, record: Vec<String>) -> Option<VectorFeature> {
+        let (mut x, mut y, mut z, mut geometry) = (None, None, None, None);
+        let mut properties = Properties::new();
+        for (field, value) in self.fields.iter().zip(record) {
+            if field.is_empty() || value.is_empty() {
+                continue;
+            }
+            if Some(field) == self.geometry_key.as_ref() {
+                geometry = parse_wkt_geometry(&value).ok().flatten();
+            } else if *field == self.lon_key {
+                x = parse_coordinate(&value);
+            } else if *field == self.lat_key {
+                y = parse_coordinate(&value);
+            } else if Some(field) == self.height_key.as_ref() {
+                z = value.parse().ok();
+            } else {
+                let value = if self.infer_types {
+                    infer_value(&value)
+                } else {
+                    PrimitiveValue::String(value)
+                };
+                properties.insert(field.clone(), ValueType::Primitive(value));
+            }
+        }
+        let geometry = match self.geometry_key {
+            Some(_) => geometry?,
+            None => VectorGeometry::Point(VectorPointGeometry {
+                _type: VectorGeometryType::Point,
+                is_3d: z.is_some(),
+                coordinates: VectorPoint::new(x?, y?, z, None),
+                ..Default::default()
+            }),
+        };
+
+        Some(VectorFeature::new_wm(None, properties, geometry, None))
+    }
+}
+
+impl<T: Reader> Iterator for CSVReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let record = self.next_record()?;
+            if let Some(feature) = self.to_feature(record) {
+                return Some(feature);
+            }
+        }
+    }
+}
+impl<T: Reader> FeatureIterator for CSVReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Parse CSV data into records keyed by the columns of the header. Empty values are left out
+pub fn parse_csv_as_record(source: &str) -> Vec<BTreeMap<String, String>> {
+    let mut data = source.strip_prefix('\u{feff}').unwrap_or(source).as_bytes();
+    let mut records = vec![];
+    let mut header: Option<Vec<String>> = None;
+    while let Some((record, used)) = parse_record(data, b',', b'"', true) {
+        data = &data[used..];
+        if record.len() == 1 && record[0].is_empty() {
+            continue;
+        }
+        let Some(header) = &header else {
+            header = Some(record);
+            continue;
+        };
+        let values = header.iter().zip(record).filter(|(_, value)| !value.is_empty());
+        records.push(values.map(|(key, value)| (key.clone(), value)).collect());
+    }
+    records
+}
+
+/// Parse the record at the start of the data, returning its values and the number of bytes
+/// used. Returns `None` if the data ends before the record does (unless at the end of the file)
+/// or if there's no data left
+fn parse_record(data: &[u8], delimiter: u8, quote: u8, eof: bool) -> Option<(Vec<String>, usize)> {
+    if data.is_empty() {
+        return None;
+    }
+    let mut record = vec![];
+    let mut value = vec![];
+    let (mut quoted, mut in_quotes, mut at_start) = (false, false, true);
+    let mut finish = |value: &mut Vec<u8>, quoted: bool| {
+        let string = String::from_utf8_lossy(value);
+        record.push(if quoted { string.into_owned() } else { string.trim().to_string() });
+        value.clear();
+    };
+    let mut i = 0;
+    while i < data.len() {
+        let byte = data[i];
+        i += 1;
+        if in_quotes {
+            if byte != quote {
+                value.push(byte);
+            } else if i == data.len() && !eof {
+                // the next byte could be an escaped quote
+                return None;
+            } else if data.get(i) == Some(&quote) {
+                value.push(quote);
+                i += 1;
+            } else {
+                in_quotes = false;
+            }
+        } else if byte == delimiter {
+            finish(&mut value, quoted);
+            (quoted, at_start) = (false, true);
+        } else if byte == b'\n' || byte == b'\r' {
+            if byte == b'\r' && i == data.len() && !eof {
+                return None;
+            }
+            if byte == b'\r' && data.get(i) == Some(&b'\n') {
+                i += 1;
+            }
+            finish(&mut value, quoted);
+            return Some((record, i));
+        } else if at_start && byte == quote {
+            (quoted, in_quotes, at_start) = (true, true, false);
+            value.clear();
+        } else {
+            at_start &= byte == b' ' || byte == b'\t';
+            if !quoted {
+                value.push(byte);
+            }
+        }
+    }
+    if !eof {
+        return None;
+    }
+    finish(&mut value, quoted);
+    Some((record, data.len()))
+}
+
+/// Convert a value to an integer, decimal or boolean when it is one. Integers with leading
+/// zeros (e.g. postal codes) are kept as strings
+pub(crate) fn infer_value(text: &str) -> PrimitiveValue {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+    let is_number = digits.bytes().all(|b| b.is_ascii_digit() || b == b'.');
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    if is_number && !leading_zero {
+        if let Ok(value) = text.parse::<i64>() {
+            return PrimitiveValue::I64(value);
+        }
+        if let Ok(value) = text.parse::<f64>() {
+            return PrimitiveValue::F64(value);
+        }
+    }
+    match text {
+        "true" => PrimitiveValue::Bool(true),
+        "false" => PrimitiveValue::Bool(false),
+        _ => PrimitiveValue::String(text.into()),
+    }
+}
+
+/// Parse a longitude or latitude in decimal degrees or degrees-minutes-seconds with an optional
+/// hemisphere, e.g. `-12.5`, `23°N` or `12°30'15"W`
+fn parse_coordinate(value: &str) -> Option<f64> {
+    if let Ok(value) = value.parse::<f64>() {
+        return value.is_finite().then_some(value);
+    }
+    let value = value.trim();
+    let hemisphere = |c: char| matches!(c.to_ascii_uppercase(), 'N' | 'S' | 'E' | 'W');
+    let (sign, value) = match value.chars().last().filter(|c| hemisphere(*c)) {
+        Some(c) => (c, &value[..value.len() - 1]),
+        None => match value.chars().next().filter(|c| hemisphere(*c)) {
+            Some(c) => (c, &value[1..]),
+            None => ('N', value),
+        },
+    };
+    let parts = value.split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'));
+    let mut degrees = None;
+    for (part, scale) in parts.filter(|p| !p.is_empty()).zip([1., 60., 3600.]) {
+        degrees = Some(degrees.unwrap_or(0.) + part.parse::<f64>().ok()?.abs() / scale);
+    }
+    let degrees = degrees?;
+    let negative = value.trim_start().starts_with('-') || matches!(sign, 'S' | 's' | 'W' | 'w');
+    Some(if negative { -degrees } else { degrees })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::BufferReader;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/csv/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn primitive(properties: &Properties, key: &str) -> PrimitiveValue {
+        match properties.get(key) {
+            Some(ValueType::Primitive(value)) => value.clone(),
+            _ => panic!("missing {key}"),
+        }
+    }
+
+    fn point(x: f64, y: f64, z: Option<f64>) -> VectorGeometry {
+        VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            is_3d: z.is_some(),
+            coordinates: VectorPoint::new(x, y, z, None),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_basic() {
+        let data = fixture("basic.csv");
+        let reader = CSVReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(reader.fields, vec!["lat", "lon", "name"]);
+        let features: Vec<VectorFeature> = reader.collect();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[0].geometry, point(2., 1., None));
+        assert_eq!(primitive(&features[0].properties, "name"), PrimitiveValue::I64(3));
+        assert_eq!(features[1].geometry, point(1.1, 3.2, None));
+        assert_eq!(
+            features[1].properties.get("name"),
+            Some(&ValueType::Primitive(PrimitiveValue::String("a".into())))
+        );
+
+        let data = fixture("basic3D.csv");
+        let options = CSVReaderOptions {
+            lon_key: Some("Longitude".into()),
+            lat_key: Some("Latitude".into()),
+            height_key: Some("height".into()),
+            infer_types: Some(false),
+            ..Default::default()
+        };
+        let features: Vec<VectorFeature> =
+            CSVReader::new(BufferReader::from(&data[..]), Some(options)).collect();
+        assert_eq!(features[1].geometry, point(1.1, 3.2, Some(-2.2)));
+        assert_eq!(
+            features[0].properties.get("name"),
+            Some(&ValueType::Primitive(PrimitiveValue::String("3".into())))
+        );
+    }
+
+    #[test]
+    fn test_degrees() {
+        let data = fixture("degrees.csv");
+        let mut reader = CSVReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(reader.next().unwrap().geometry, point(-26., 23., None));
+        assert_eq!(parse_coordinate("12°30'36\"S"), Some(-12.51));
+        assert_eq!(parse_coordinate("W 45° 30′"), Some(-45.5));
+        assert_eq!(parse_coordinate("north"), None);
+    }
+
+    #[test]
+    fn test_quoted() {
+        let data = fixture("quoted.csv");
+        let options = CSVReaderOptions {
+            delimiter: Some(b';'),
+            geometry_key: Some("wkt".into()),
+            ..Default::default()
+        };
+        // read in small chunks so records and escapes cross the chunk boundaries
+        for chunk_size in [1, 2, 3, 7, CHUNK_SIZE] {
+            let mut reader = CSVReader::new(BufferReader::from(&data[..]), Some(options.clone()));
+            assert_eq!(reader.fields, vec!["id", "name", "wkt", "note", "active", "zip"]);
+            reader.chunk_size = chunk_size;
+            let features: Vec<VectorFeature> = reader.collect();
+            assert_eq!(features.len(), 2);
+
+            let props = &features[0].properties;
+            assert_eq!(features[0].geometry, point(2.35, 48.85, Some(35.)));
+            assert_eq!(primitive(props, "id"), PrimitiveValue::I64(1));
+            let string = |v: &str| Some(ValueType::Primitive(PrimitiveValue::String(v.into())));
+            assert_eq!(props.get("name").cloned(), string("Café; \"Le Zinc\""));
+            assert_eq!(props.get("note").cloned(), string("multi\r\nline"));
+            assert_eq!(primitive(props, "active"), PrimitiveValue::Bool(true));
+            assert_eq!(props.get("zip").cloned(), string("07501"));
+
+            assert_eq!(features[1].geometry, point(-0.12, 51.5, None));
+            assert_eq!(features[1].properties.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_parse_csv_as_record() {
+        let records = parse_csv_as_record("a,b,c\n1,\"x,y\",\n\n2,,3");
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0]["b"], "x,y");
+        assert!(!records[0].contains_key("c"));
+        assert_eq!(records[1]["a"], "2");
+        assert_eq!(records[1]["c"], "3");
+    }
+}
@@ -0,0 +1,179 @@
+use super::{FGBColumn, FGBColumnType, FGBGeometryType, FGBHeader, FlatTable};
+use crate::{
+    geometry::{PrimitiveValue, Properties, ValueType, VectorFeature, VectorGeometry, VectorPoint},
+    readers::wkt::geometry::{
+        line_string_geometry, m_value, multi_line_string_geometry, multi_point_geometry,
+        multi_polygon_geometry, point_geometry, polygon_geometry,
+    },
+};
+
+use alloc::{string::String, vec::Vec};
+
+/// Parse a feature's flatbuffer (without its size prefix). The feature's own columns are used
+/// to decode its properties if it has them, otherwise the header's columns are used.
+pub fn fgb_feature(buf: &[u8], header: &FGBHeader, id: u64) -> Option<VectorFeature> {
+    let feature = FlatTable::root(buf)?;
+    let geometry = fgb_geometry(&feature.table(0)?, header.geometry_type)?;
+    let columns: Vec<FGBColumn> = feature.tables(2).iter().map(FGBColumn::from).collect();
+    let columns = if columns.is_empty() { &header.columns } else { &columns };
+    let properties = fgb_properties(feature.bytes(1).unwrap_or_default(), columns);
+
+    Some(VectorFeature::new_wm(Some(id), properties, geometry, None))
+}
+
+/// Decode a geometry table. The header's geometry type is used unless it's
+/// [`FGBGeometryType::Unknown`], in which case the geometry stores its own type.
+///
+/// NOTE: Geometry collections and curved geometries are not supported. T and TM values are
+/// ignored and M values are stored in each point's M-value.
+pub fn fgb_geometry(
+    geometry: &FlatTable<'_>,
+    geometry_type: FGBGeometryType,
+) -> Option<VectorGeometry> {
+    let geometry_type = match geometry_type {
+        FGBGeometryType::Unknown => geometry.u8(6, 0).into(),
+        geometry_type => geometry_type,
+    };
+    match geometry_type {
+        FGBGeometryType::Point => Some(point_geometry(points(geometry).into_iter().next()?)),
+        FGBGeometryType::MultiPoint => Some(multi_point_geometry(non_empty(points(geometry))?)),
+        FGBGeometryType::LineString => Some(line_string_geometry(non_empty(points(geometry))?)),
+        FGBGeometryType::MultiLineString => {
+            Some(multi_line_string_geometry(non_empty(lines(geometry))?))
+        }
+        FGBGeometryType::Polygon => Some(polygon_geometry(non_empty(lines(geometry))?)),
+        FGBGeometryType::MultiPolygon => {
+            let parts = geometry.tables(7);
+            let polygons = if parts.is_empty() {
+                // a single polygon may be stored without parts
+                Vec::from([non_empty(lines(geometry))?])
+            } else {
+                parts.iter().map(lines).filter(|p| !p.is_empty()).collect()
+            };
+            Some(multi_polygon_geometry(non_empty(polygons)?))
+        }
+        _ => None,
+    }
+}
+
+/// `None` if the collection is empty
+fn non_empty<T>(values: Vec<T>) -> Option<Vec<T>> {
+    (!values.is_empty()).then_some(values)
+}
+
+/// Read the geometry's points with their z and m values if present
+fn points(geometry: &FlatTable<'_>) -> Vec<VectorPoint> {
+    let xy = geometry.f64s(1);
+    let z = geometry.f64s(2);
+    let m = geometry.f64s(3);
+    xy.as_chunks()
+        .0
+        .iter()
+        .enumerate()
+        .map(|(i, [x, y])| {
+            VectorPoint::new(*x, *y, z.get(i).copied(), m.get(i).copied().map(m_value))
+        })
+        .collect()
+}
+
+/// Split the geometry's points into lines (or rings) by their `ends`
+fn lines(geometry: &FlatTable<'_>) -> Vec<Vec<VectorPoint>> {
+    let points = points(geometry);
+    let ends = geometry.u32s(0);
+    if ends.is_empty() {
+        return non_empty(points).map(|p| Vec::from([p])).unwrap_or_default();
+    }
+    let mut start = 0;
+    ends.iter()
+        .filter_map(|end| {
+            let end = (*end as usize).min(points.len());
+            let line = points.get(start..end).map(<[VectorPoint]>::to_vec);
+            start = end;
+            line.filter(|l| !l.is_empty())
+        })
+        .collect()
+}
+
+/// Decode the properties of a feature. Each value is prefixed by the u16 index of its column.
+/// Missing values are omitted and binary values are skipped. Decoding stops at the first unknown
+/// column or truncated value.
+pub fn fgb_properties(data: &[u8], columns: &[FGBColumn]) -> Properties {
+    let mut properties = Properties::new();
+    let mut cursor = Cursor { data, pos: 0 };
+    while let Some(index) = cursor.array().map(u16::from_le_bytes) {
+        let Some(column) = columns.get(index as usize) else {
+            break;
+        };
+        let value = match column.column_type {
+            FGBColumnType::Byte => {
+                cursor.array().map(|b| PrimitiveValue::I64(i8::from_le_bytes(b) as i64))
+            }
+            FGBColumnType::UByte => {
+                cursor.array().map(|b| PrimitiveValue::U64(u8::from_le_bytes(b) as u64))
+            }
+            FGBColumnType::Bool => cursor.array().map(|[b]| PrimitiveValue::Bool(b != 0)),
+            FGBColumnType::Short => {
+                cursor.array().map(|b| PrimitiveValue::I64(i16::from_le_bytes(b) as i64))
+            }
+            FGBColumnType::UShort => {
+                cursor.array().map(|b| PrimitiveValue::U64(u16::from_le_bytes(b) as u64))
+            }
+            FGBColumnType::Int => {
+                cursor.array().map(|b| PrimitiveValue::I64(i32::from_le_bytes(b) as i64))
+            }
+            FGBColumnType::UInt => {
+                cursor.array().map(|b| PrimitiveValue::U64(u32::from_le_bytes(b) as u64))
+            }
+            FGBColumnType::Long => {
+                cursor.array().map(|b| PrimitiveValue::I64(i64::from_le_bytes(b)))
+            }
+            FGBColumnType::ULong => {
+                cursor.array().map(|b| PrimitiveValue::U64(u64::from_le_bytes(b)))
+            }
+            FGBColumnType::Float => {
+                cursor.array().map(|b| PrimitiveValue::F32(f32::from_le_bytes(b)))
+            }
+            FGBColumnType::Double => {
+                cursor.array().map(|b| PrimitiveValue::F64(f64::from_le_bytes(b)))
+            }
+            FGBColumnType::String
+            | FGBColumnType::Json
+            | FGBColumnType::DateTime
+            | FGBColumnType::Binary => {
+                let len = cursor.array().map(u32::from_le_bytes);
+                let Some(bytes) = len.and_then(|len| cursor.take(len as usize)) else {
+                    break;
+                };
+                if column.column_type == FGBColumnType::Binary {
+                    continue;
+                }
+                Some(PrimitiveValue::String(String::from_utf8_lossy(bytes).into()))
+            }
+        };
+        let Some(value) = value else {
+            break;
+        };
+        properties.insert(column.name.clone(), ValueType::Primitive(value));
+    }
+
+    properties
+}
+
+/// Reads the encoded properties in order
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+impl<'a> Cursor<'a> {
+    /// Take the next `len` bytes if they exist
+    fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let bytes = self.data.get(self.pos..self.pos.checked_add(len)?)?;
+        self.pos += len;
+        Some(bytes)
+    }
+
+    /// Take the next `N` bytes if they exist
+    fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.take(N)?.try_into().ok()
+    }
+}
@@ -0,0 +1,139 @@
+use alloc::vec::Vec;
+
+/// Read `N` little-endian bytes at the position if they are within the buffer
+fn bytes<const N: usize>(buf: &[u8], pos: usize) -> Option<[u8; N]> {
+    buf.get(pos..pos.checked_add(N)?)?.try_into().ok()
+}
+
+/// Read the unsigned 32 bit offset at the position and resolve it
+fn follow(buf: &[u8], pos: usize) -> Option<usize> {
+    pos.checked_add(u32::from_le_bytes(bytes(buf, pos)?) as usize)
+}
+
+/// # FlatBuffers Table
+///
+/// ## Description
+/// A read-only view of a FlatBuffers table. Only what the FlatGeobuf schemas need is supported:
+/// scalars, strings, scalar vectors, sub-tables and vectors of tables. Every access is bounds
+/// checked, a missing or out-of-bounds field reads as absent (or its default).
+///
+/// ## Links
+/// - <https://flatbuffers.dev/internals/>
+#[derive(Debug, Clone, Copy)]
+pub struct FlatTable<'a> {
+    buf: &'a [u8],
+    /// The position of the table
+    pos: usize,
+    /// The position of the table's vtable
+    vtable: usize,
+    /// The size of the vtable in bytes
+    vtable_size: usize,
+}
+impl<'a> FlatTable<'a> {
+    /// Access the root table of a buffer
+    pub fn root(buf: &'a [u8]) -> Option<Self> {
+        Self::new(buf, follow(buf, 0)?)
+    }
+
+    /// Access the table at the position
+    fn new(buf: &'a [u8], pos: usize) -> Option<Self> {
+        let soffset = i32::from_le_bytes(bytes(buf, pos)?) as i64;
+        let vtable = usize::try_from(pos as i64 - soffset).ok()?;
+        let vtable_size = u16::from_le_bytes(bytes(buf, vtable)?) as usize;
+        Some(Self { buf, pos, vtable, vtable_size })
+    }
+
+    /// The position of the field in the buffer if it's present
+    fn field(&self, index: usize) -> Option<usize> {
+        let entry = 4 + 2 * index;
+        if entry + 2 > self.vtable_size {
+            return None;
+        }
+        match u16::from_le_bytes(bytes(self.buf, self.vtable + entry)?) {
+            0 => None,
+            offset => Some(self.pos + offset as usize),
+        }
+    }
+
+    /// Read an unsigned 8 bit field
+    pub fn u8(&self, index: usize, default: u8) -> u8 {
+        self.field(index).and_then(|p| bytes(self.buf, p)).map(u8::from_le_bytes).unwrap_or(default)
+    }
+
+    /// Read a boolean field
+    pub fn bool(&self, index: usize, default: bool) -> bool {
+        self.u8(index, default as u8) != 0
+    }
+
+    /// Read an unsigned 16 bit field
+    pub fn u16(&self, index: usize, default: u16) -> u16 {
+        self.field(index)
+            .and_then(|p| bytes(self.buf, p))
+            .map(u16::from_le_bytes)
+            .unwrap_or(default)
+    }
+
+    /// Read a signed 32 bit field
+    pub fn i32(&self, index: usize, default: i32) -> i32 {
+        self.field(index)
+            .and_then(|p| bytes(self.buf, p))
+            .map(i32::from_le_bytes)
+            .unwrap_or(default)
+    }
+
+    /// Read an unsigned 64 bit field
+    pub fn u64(&self, index: usize, default: u64) -> u64 {
+        self.field(index)
+            .and_then(|p| bytes(self.buf, p))
+            .map(u64::from_le_bytes)
+            .unwrap_or(default)
+    }
+
+    /// Read a string field. Invalid UTF-8 reads as absent
+    pub fn string(&self, index: usize) -> Option<&'a str> {
+        core::str::from_utf8(self.vector(index, 1)?).ok()
+    }
+
+    /// Read a vector of bytes
+    pub fn bytes(&self, index: usize) -> Option<&'a [u8]> {
+        self.vector(index, 1)
+    }
+
+    /// Read a vector of unsigned 32 bit integers
+    pub fn u32s(&self, index: usize) -> Vec<u32> {
+        let data = self.vector(index, 4).unwrap_or_default();
+        data.as_chunks().0.iter().copied().map(u32::from_le_bytes).collect()
+    }
+
+    /// Read a vector of doubles
+    pub fn f64s(&self, index: usize) -> Vec<f64> {
+        let data = self.vector(index, 8).unwrap_or_default();
+        data.as_chunks().0.iter().copied().map(f64::from_le_bytes).collect()
+    }
+
+    /// Read a sub-table
+    pub fn table(&self, index: usize) -> Option<FlatTable<'a>> {
+        Self::new(self.buf, follow(self.buf, self.field(index)?)?)
+    }
+
+    /// Read a vector of tables
+    pub fn tables(&self, index: usize) -> Vec<FlatTable<'a>> {
+        let Some(start) = self.field(index).and_then(|p| follow(self.buf, p)) else {
+            return Vec::new();
+        };
+        let len = bytes(self.buf, start).map(u32::from_le_bytes).unwrap_or_default() as usize;
+        (0..len)
+            .map_while(|i| {
+                let element = start + 4 + 4 * i;
+                Self::new(self.buf, follow(self.buf, element)?)
+            })
+            .collect()
+    }
+
+    /// The raw bytes of a vector of `element_size` sized elements
+    fn vector(&self, index: usize, element_size: usize) -> Option<&'a [u8]> {
+        let start = follow(self.buf, self.field(index)?)?;
+        let len = u32::from_le_bytes(bytes(self.buf, start)?) as usize;
+        self.buf.get(start + 4..start + 4 + len.checked_mul(element_size)?)
+    }
+}
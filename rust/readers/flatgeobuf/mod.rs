@@ -0,0 +1,452 @@
+/// Feature geometry and property decoding
+pub mod feature;
+/// A minimal FlatBuffers table reader
+pub mod flatbuffer;
+/// Packed Hilbert R-tree index
+pub mod packed_rtree;
+/// Header, column and CRS schemas
+pub mod schema;
+
+pub use feature::*;
+pub use flatbuffer::*;
+pub use packed_rtree::*;
+pub use schema::*;
+
+use crate::{
+    geometry::{BBox, VectorFeature},
+    readers::{parse_wkt_crs, FeatureIterator, Reader, WKTCRS},
+};
+
+use alloc::vec::{IntoIter, Vec};
+
+/// The magic bytes at the start of every FlatGeobuf file. The 4th byte is the major version and
+/// the last byte the patch version
+pub const FGB_MAGIC_BYTES: [u8; 8] = [0x66, 0x67, 0x62, 0x03, 0x66, 0x67, 0x62, 0x00];
+/// The major version of the spec that is supported
+pub const FGB_VERSION: u8 = 3;
+
+/// Errors that can occur while reading a FlatGeobuf
+#[derive(Debug, PartialEq)]
+pub enum FlatGeobufError {
+    /// The file doesn't start with the FlatGeobuf magic bytes
+    InvalidMagicBytes,
+    /// The major version of the file isn't supported
+    UnsupportedVersion(u8),
+    /// The header is truncated, not a valid flatbuffer or has an index node size of 1
+    InvalidHeader,
+    /// The file has no spatial index to query
+    MissingIndex,
+    /// A node of the spatial index points outside of the level below it
+    InvalidIndex,
+}
+
+/// # FlatGeobuf Reader
+///
+/// ## Description
+/// Reads a FlatGeobuf file through any [`Reader`]. The header is parsed up front and the
+/// features are decoded as they are read. Implements the [`FeatureIterator`] trait to read all
+/// the features in order.
+///
+/// If the file has a packed Hilbert R-tree index, [`FlatGeobufReader::query`] uses it to find
+/// the features that intersect a bounding box. Only the index nodes that intersect the query and
+/// the matching features are read, so pairing it with a
+/// [`MMapReader`](crate::readers::MMapReader) serves spatial subsets of huge files without
+/// scanning them.
+///
+/// Feature ids are the position of the feature in the file. M values are stored in each point's
+/// M-value as `{ "m": value }`, string, JSON and date-time columns are stored as strings.
+///
+/// NOTE: Geometry collections, curved geometries and binary columns are not supported.
+/// Coordinates are not reprojected, see [`FlatGeobufReader::crs`].
+///
+/// ## Usage
+/// ```rust
+/// use gistools::{geometry::BBox, readers::{BufferReader, FeatureIterator, FlatGeobufReader}};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/flatgeobuf/fixtures/points.fgb");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = FlatGeobufReader::new(BufferReader::from(&data[..])).unwrap();
+/// assert_eq!(reader.len(), 20);
+///
+/// // only read the features within the bounding box
+/// let features: Vec<_> = reader.query(BBox::new(-1., 44., 6., 51.)).unwrap().collect();
+/// assert_eq!(features.len(), 4);
+///
+/// // or read all of them
+/// while let Some(feature) = reader.next_feature() {
+///     println!("{:?}", feature.properties);
+/// }
+/// ```
+///
+/// ## Links
+/// - <https://flatgeobuf.org/>
+/// - <https://github.com/flatgeobuf/flatgeobuf/blob/master/src/fbs/header.fbs>
+/// - <https://github.com/flatgeobuf/flatgeobuf/blob/master/src/fbs/feature.fbs>
+#[derive(Debug)]
+pub struct FlatGeobufReader<T: Reader> {
+    reader: T,
+    header: FGBHeader,
+    /// The byte offset of the index
+    index_offset: usize,
+    /// The byte offset of the first feature
+    features_offset: usize,
+    /// The byte offset of the next feature to iterate from the start of the features
+    offset: usize,
+    /// The index of the next feature to iterate
+    cursor: u64,
+}
+impl<T: Reader> FlatGeobufReader<T> {
+    /// Create a new FlatGeobuf reader, parsing the header
+    pub fn new(mut reader: T) -> Result<Self, FlatGeobufError> {
+        if reader.len() < 12 {
+            return Err(FlatGeobufError::InvalidMagicBytes);
+        }
+        let magic = reader.slice(Some(0), Some(8));
+        if magic[0..3] != FGB_MAGIC_BYTES[0..3] || magic[4..7] != FGB_MAGIC_BYTES[4..7] {
+            return Err(FlatGeobufError::InvalidMagicBytes);
+        }
+        if magic[3] != FGB_VERSION {
+            return Err(FlatGeobufError::UnsupportedVersion(magic[3]));
+        }
+        let header_size = reader.uint32_le(Some(8)) as usize;
+        let index_offset = 12 + header_size;
+        if index_offset > reader.len() {
+            return Err(FlatGeobufError::InvalidHeader);
+        }
+        let header_data = reader.slice(Some(12), Some(index_offset));
+        let header = FlatTable::root(&header_data)
+            .map(|t| FGBHeader::from(&t))
+            .ok_or(FlatGeobufError::InvalidHeader)?;
+        if header.index_node_size == 1 {
+            return Err(FlatGeobufError::InvalidHeader);
+        }
+        let features_offset =
+            index_offset + fgb_index_size(header.features_count, header.index_node_size);
+        if features_offset > reader.len() {
+            return Err(FlatGeobufError::InvalidHeader);
+        }
+
+        Ok(Self { reader, header, index_offset, features_offset, offset: 0, cursor: 0 })
+    }
+
+    /// Get the header
+    pub fn get_header(&self) -> &FGBHeader {
+        &self.header
+    }
+
+    /// Parse the well-known text of the reference system if the header stores it
+    pub fn crs(&self) -> Option<WKTCRS> {
+        parse_wkt_crs(self.header.crs.as_ref()?.wkt.as_ref()?).ok()
+    }
+
+    /// The number of features (0 if the header doesn't store it)
+    pub fn len(&self) -> usize {
+        self.header.features_count as usize
+    }
+
+    /// Returns true if there are no features (or the header doesn't store the count)
+    pub fn is_empty(&self) -> bool {
+        self.header.features_count == 0
+    }
+
+    /// Returns true if the file has a spatial index
+    pub fn has_index(&self) -> bool {
+        self.features_offset > self.index_offset
+    }
+
+    /// Use the spatial index to find the features whose bounding box intersects the query
+    pub fn search(&mut self, bbox: &BBox) -> Result<Vec<FGBSearchResult>, FlatGeobufError> {
+        if !self.has_index() {
+            return Err(FlatGeobufError::MissingIndex);
+        }
+        let FGBHeader { features_count, index_node_size, .. } = self.header;
+        fgb_search(&mut self.reader, self.index_offset, features_count, index_node_size, bbox)
+    }
+
+    /// Iterate over the features whose bounding box intersects the query using the spatial index
+    pub fn query(&mut self, bbox: BBox) -> Result<FlatGeobufQueryIterator<'_, T>, FlatGeobufError> {
+        let results = self.search(&bbox)?.into_iter();
+        Ok(FlatGeobufQueryIterator { reader: self, results })
+    }
+
+    /// Read the feature at the byte offset from the start of the features. The id is the index
+    /// of the feature in the file
+    pub fn get_feature(&mut self, offset: usize, id: u64) -> Option<VectorFeature> {
+        let start = self.features_offset.checked_add(offset)?.checked_add(4)?;
+        if start > self.reader.len() {
+            return None;
+        }
+        let size = self.reader.uint32_le(Some(start - 4)) as usize;
+        let end = start.checked_add(size)?;
+        if end > self.reader.len() {
+            return None;
+        }
+        let data = self.reader.slice(Some(start), Some(end));
+        fgb_feature(&data, &self.header, id)
+    }
+}
+impl<T: Reader> Iterator for FlatGeobufReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let count = self.header.features_count;
+        while count == 0 || self.cursor < count {
+            let start = self.features_offset + self.offset;
+            if start + 4 > self.reader.len() {
+                return None;
+            }
+            let feature = self.get_feature(self.offset, self.cursor);
+            self.offset += 4 + self.reader.uint32_le(Some(start)) as usize;
+            self.cursor += 1;
+            if feature.is_some() {
+                return feature;
+            }
+        }
+        None
+    }
+}
+impl<T: Reader> FeatureIterator for FlatGeobufReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Iterates over the features found by a [`FlatGeobufReader::query`] in file order
+#[derive(Debug)]
+pub struct FlatGeobufQueryIterator<'a, T: Reader> {
+    reader: &'a mut FlatGeobufReader<T>,
+    results: IntoIter<FGBSearchResult>,
+}
+impl<T: Reader> Iterator for FlatGeobufQueryIterator<'_, T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for FGBSearchResult { offset, index } in self.results.by_ref() {
+            let feature = self.reader.get_feature(offset as usize, index);
+            if feature.is_some() {
+                return feature;
+            }
+        }
+        None
+    }
+}
+impl<T: Reader> FeatureIterator for FlatGeobufQueryIterator<'_, T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{PrimitiveValue, Properties, ValueType, VectorGeometry, VectorPoint};
+    use crate::readers::{wkt::geometry::m_value, BufferReader};
+    use alloc::{string::String, vec};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn read_fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/flatgeobuf/fixtures");
+        path.push(name);
+        fs::read(&path).expect("Failed to read file expected")
+    }
+
+    fn name(feature: &VectorFeature) -> String {
+        match feature.properties.get("name") {
+            Some(ValueType::Primitive(PrimitiveValue::String(name))) => name.clone(),
+            _ => panic!("expected a name"),
+        }
+    }
+
+    #[test]
+    fn read_header() {
+        let data = read_fixture("points.fgb");
+        let reader = FlatGeobufReader::new(BufferReader::from(&data[..])).unwrap();
+        let header = reader.get_header();
+        assert_eq!(header.name, Some("grid".into()));
+        assert_eq!(header.envelope, Some(BBox::new(-10., 40., 10., 55.)));
+        assert_eq!(header.geometry_type, FGBGeometryType::Point);
+        assert_eq!(header.features_count, 20);
+        assert_eq!(header.index_node_size, 4);
+        assert!(reader.has_index());
+        assert_eq!(
+            header.crs.as_ref().map(|c| (c.org.as_deref(), c.code)),
+            Some((Some("EPSG"), 4326))
+        );
+        let columns: Vec<(&str, FGBColumnType)> =
+            header.columns.iter().map(|c| (c.name.as_str(), c.column_type)).collect();
+        assert_eq!(
+            columns,
+            vec![
+                ("name", FGBColumnType::String),
+                ("population", FGBColumnType::UInt),
+                ("elevation", FGBColumnType::Double),
+                ("capital", FGBColumnType::Bool),
+                ("rank", FGBColumnType::Short),
+            ]
+        );
+        assert_eq!(header.columns[0].title, Some("Name".into()));
+        assert!(header.columns[0].nullable);
+        assert_eq!(header.columns[0].width, -1);
+    }
+
+    #[test]
+    fn read_all_points() {
+        let data = read_fixture("points.fgb");
+        let reader = FlatGeobufReader::new(BufferReader::from(&data[..])).unwrap();
+        let features: Vec<VectorFeature> = reader.collect();
+        assert_eq!(features.len(), 20);
+        // features are stored in hilbert order
+        let names: Vec<String> = features.iter().take(4).map(name).collect();
+        assert_eq!(names, vec!["p0", "p1", "p2", "p7"]);
+
+        let p7 = &features[3];
+        assert_eq!(p7.id, Some(3));
+        let VectorGeometry::Point(point) = &p7.geometry else { panic!("expected a point") };
+        assert_eq!(point.coordinates, VectorPoint::new(0., 45., None, None));
+        let mut properties = Properties::new();
+        properties.insert("name".into(), ValueType::Primitive(PrimitiveValue::String("p7".into())));
+        properties.insert("population".into(), ValueType::Primitive(PrimitiveValue::U64(8_000)));
+        properties.insert("elevation".into(), ValueType::Primitive(PrimitiveValue::F64(10.5)));
+        properties.insert("capital".into(), ValueType::Primitive(PrimitiveValue::Bool(true)));
+        properties.insert("rank".into(), ValueType::Primitive(PrimitiveValue::I64(-7)));
+        assert_eq!(p7.properties, properties);
+        // null values are omitted
+        assert_eq!(features[0].properties.get("rank"), None);
+    }
+
+    #[test]
+    fn query_index() {
+        let data = read_fixture("points.fgb");
+        let mut reader = FlatGeobufReader::new(BufferReader::from(&data[..])).unwrap();
+
+        let results = reader.search(&BBox::new(-1., 44., 6., 51.)).unwrap();
+        assert_eq!(results.iter().map(|r| r.index).collect::<Vec<_>>(), vec![3, 11, 12, 17]);
+
+        let features: Vec<VectorFeature> =
+            reader.query(BBox::new(-1., 44., 6., 51.)).unwrap().collect();
+        let names: Vec<String> = features.iter().map(name).collect();
+        assert_eq!(names, vec!["p7", "p12", "p13", "p8"]);
+
+        // compare against a full scan
+        for bbox in [
+            BBox::new(-100., -100., 100., 100.),
+            BBox::new(2., 41., 9., 49.),
+            BBox::new(20., 20., 30., 30.),
+        ] {
+            let mut expected: Vec<String> = FlatGeobufReader::new(BufferReader::from(&data[..]))
+                .unwrap()
+                .filter(|f| {
+                    let VectorGeometry::Point(p) = &f.geometry else { return false };
+                    let VectorPoint { x, y, .. } = p.coordinates;
+                    x >= bbox.left && x <= bbox.right && y >= bbox.bottom && y <= bbox.top
+                })
+                .map(|f| name(&f))
+                .collect();
+            let mut found: Vec<String> = reader.query(bbox).unwrap().map(|f| name(&f)).collect();
+            expected.sort();
+            found.sort();
+            assert_eq!(found, expected);
+        }
+    }
+
+    #[test]
+    fn read_mixed_geometries() {
+        let data = read_fixture("shapes.fgb");
+        let mut reader = FlatGeobufReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.get_header().geometry_type, FGBGeometryType::Unknown);
+        assert!(!reader.has_index());
+        assert!(reader.crs().is_none());
+        assert_eq!(
+            reader.query(BBox::new(0., 0., 1., 1.)).err(),
+            Some(FlatGeobufError::MissingIndex)
+        );
+
+        let features: Vec<VectorFeature> = reader.collect();
+        assert_eq!(features.len(), 6);
+
+        let VectorGeometry::LineString(line) = &features[0].geometry else { panic!("line") };
+        assert_eq!(line.coordinates.len(), 3);
+        let mut properties = Properties::new();
+        properties
+            .insert("kind".into(), ValueType::Primitive(PrimitiveValue::String("line".into())));
+        properties.insert("id".into(), ValueType::Primitive(PrimitiveValue::I64(-1)));
+        properties.insert("ratio".into(), ValueType::Primitive(PrimitiveValue::F32(0.5)));
+        properties.insert(
+            "tags".into(),
+            ValueType::Primitive(PrimitiveValue::String("{\"a\":1}".into())),
+        );
+        properties.insert("level".into(), ValueType::Primitive(PrimitiveValue::U64(3)));
+        assert_eq!(features[0].properties, properties);
+
+        let VectorGeometry::Polygon(polygon) = &features[1].geometry else { panic!("polygon") };
+        assert_eq!(polygon.coordinates.iter().map(Vec::len).collect::<Vec<_>>(), vec![5, 5]);
+
+        let VectorGeometry::MultiPolygon(polygons) = &features[2].geometry else {
+            panic!("multipolygon")
+        };
+        assert_eq!(polygons.coordinates.len(), 2);
+        assert_eq!(polygons.coordinates[1][0][0], VectorPoint::new(5., 5., None, None));
+
+        let VectorGeometry::MultiLineString(lines) = &features[3].geometry else {
+            panic!("multilinestring")
+        };
+        assert_eq!(lines.coordinates.iter().map(Vec::len).collect::<Vec<_>>(), vec![2, 3]);
+
+        let VectorGeometry::MultiPoint(points) = &features[4].geometry else {
+            panic!("multipoint")
+        };
+        assert_eq!(points.coordinates.len(), 2);
+
+        let VectorGeometry::Point(point) = &features[5].geometry else { panic!("point") };
+        assert!(point.is_3d);
+        assert_eq!(point.coordinates, VectorPoint::new(1., 2., Some(3.), Some(m_value(4.))));
+    }
+
+    #[test]
+    fn invalid_files() {
+        let data = read_fixture("points.fgb");
+        let mut bad_magic = data.clone();
+        bad_magic[0] = b'x';
+        assert_eq!(
+            FlatGeobufReader::new(BufferReader::from(&bad_magic[..])).err(),
+            Some(FlatGeobufError::InvalidMagicBytes)
+        );
+        let mut bad_version = data.clone();
+        bad_version[3] = 2;
+        assert_eq!(
+            FlatGeobufReader::new(BufferReader::from(&bad_version[..])).err(),
+            Some(FlatGeobufError::UnsupportedVersion(2))
+        );
+        assert_eq!(
+            FlatGeobufReader::new(BufferReader::from(&data[..100])).err(),
+            Some(FlatGeobufError::InvalidHeader)
+        );
+
+        // find the index node size (field 9) of the header table
+        let u32_at = |pos: usize| u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap());
+        let table = 12 + u32_at(12) as usize;
+        let vtable = (table as i64 - u32_at(table) as i32 as i64) as usize;
+        let field = table + u16::from_le_bytes([data[vtable + 22], data[vtable + 23]]) as usize;
+        let mut bad_node_size = data.clone();
+        bad_node_size[field..field + 2].copy_from_slice(&1_u16.to_le_bytes());
+        assert_eq!(
+            FlatGeobufReader::new(BufferReader::from(&bad_node_size[..])).err(),
+            Some(FlatGeobufError::InvalidHeader)
+        );
+
+        // point the root node past the level below it
+        let index_offset = 12 + u32_at(8) as usize;
+        let mut bad_index = data.clone();
+        bad_index[index_offset + 32..index_offset + 40].copy_from_slice(&1000_u64.to_le_bytes());
+        let mut reader = FlatGeobufReader::new(BufferReader::from(&bad_index[..])).unwrap();
+        assert_eq!(
+            reader.search(&BBox::new(-180., -90., 180., 90.)),
+            Err(FlatGeobufError::InvalidIndex)
+        );
+    }
+}
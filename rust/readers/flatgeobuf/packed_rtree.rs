@@ -0,0 +1,143 @@
+use crate::geometry::BBox;
+use crate::readers::{FlatGeobufError, Reader};
+
+use alloc::{collections::VecDeque, vec, vec::Vec};
+use core::ops::Range;
+
+/// The size of a node of the index in bytes: the bounding box (4 x f64) and the offset (u64)
+pub const FGB_NODE_ITEM_SIZE: usize = 40;
+
+/// A node of the packed Hilbert R-tree
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct FGBNodeItem {
+    /// The bounding box of the node
+    pub bbox: BBox,
+    /// For leaf nodes, the byte offset of the feature from the start of the features. For
+    /// branch nodes, the index of the node's first child
+    pub offset: u64,
+}
+impl FGBNodeItem {
+    /// Read the node item at the byte offset
+    pub fn read<T: Reader>(reader: &mut T, offset: usize) -> Self {
+        FGBNodeItem {
+            bbox: BBox::new(
+                reader.f64_le(Some(offset)),
+                reader.f64_le(Some(offset + 8)),
+                reader.f64_le(Some(offset + 16)),
+                reader.f64_le(Some(offset + 24)),
+            ),
+            offset: reader.uint64_le(Some(offset + 32)),
+        }
+    }
+}
+
+/// A feature found by searching the index
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct FGBSearchResult {
+    /// The byte offset of the feature from the start of the features
+    pub offset: u64,
+    /// The index of the feature in the file
+    pub index: u64,
+}
+
+/// The range of node indexes of each level of the tree, starting with the leaves. The root is
+/// stored first so the leaves are the last `num_items` nodes. `node_size` must be at least 2.
+pub fn fgb_level_bounds(num_items: u64, node_size: u16) -> Vec<Range<usize>> {
+    let node_size = node_size as usize;
+    let mut n = num_items as usize;
+    let mut num_nodes = n;
+    let mut level_num_nodes = vec![n];
+    loop {
+        n = n.div_ceil(node_size);
+        num_nodes += n;
+        level_num_nodes.push(n);
+        if n <= 1 {
+            break;
+        }
+    }
+    level_num_nodes
+        .into_iter()
+        .map(|size| {
+            let range = num_nodes - size..num_nodes;
+            num_nodes -= size;
+            range
+        })
+        .collect()
+}
+
+/// The size of the index in bytes. There is no index if the item count is 0 or the node size is
+/// less than 2
+pub fn fgb_index_size(num_items: u64, node_size: u16) -> usize {
+    if num_items == 0 || node_size < 2 {
+        return 0;
+    }
+    let num_nodes = fgb_level_bounds(num_items, node_size).first().map(|r| r.end).unwrap_or(0);
+    num_nodes * FGB_NODE_ITEM_SIZE
+}
+
+/// Find all the features whose bounding box intersects the query. Only the nodes whose bounding
+/// box intersects the query are read, starting from the root. Results are sorted by offset.
+///
+/// Returns [`FlatGeobufError::InvalidIndex`] if a branch node points outside of the level below.
+pub fn fgb_search<T: Reader>(
+    reader: &mut T,
+    index_offset: usize,
+    num_items: u64,
+    node_size: u16,
+    bbox: &BBox,
+) -> Result<Vec<FGBSearchResult>, FlatGeobufError> {
+    let mut results = vec![];
+    if num_items == 0 || node_size < 2 {
+        return Ok(results);
+    }
+    let level_bounds = fgb_level_bounds(num_items, node_size);
+    let leaves_start = level_bounds[0].start;
+    // (first node index, level) of the nodes left to visit
+    let mut queue = VecDeque::from([(0, level_bounds.len() - 1)]);
+    while let Some((node_index, level)) = queue.pop_front() {
+        let end = (node_index + node_size as usize).min(level_bounds[level].end);
+        for pos in node_index..end {
+            let node = FGBNodeItem::read(reader, index_offset + pos * FGB_NODE_ITEM_SIZE);
+            if !intersects(&node.bbox, bbox) {
+                continue;
+            }
+            if level == 0 {
+                results.push(FGBSearchResult {
+                    offset: node.offset,
+                    index: (pos - leaves_start) as u64,
+                });
+            } else {
+                let child =
+                    usize::try_from(node.offset).map_err(|_| FlatGeobufError::InvalidIndex)?;
+                if !level_bounds[level - 1].contains(&child) {
+                    return Err(FlatGeobufError::InvalidIndex);
+                }
+                queue.push_back((child, level - 1));
+            }
+        }
+    }
+    results.sort_by_key(|r| r.offset);
+
+    Ok(results)
+}
+
+/// Check if two bounding boxes intersect
+fn intersects(a: &BBox, b: &BBox) -> bool {
+    a.left <= b.right && a.right >= b.left && a.bottom <= b.top && a.top >= b.bottom
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn level_bounds() {
+        assert_eq!(fgb_level_bounds(20, 4), vec![8..28, 3..8, 1..3, 0..1]);
+        assert_eq!(fgb_level_bounds(1, 16), vec![1..2, 0..1]);
+        assert_eq!(fgb_level_bounds(16, 16), vec![1..17, 0..1]);
+        assert_eq!(fgb_index_size(20, 4), 28 * FGB_NODE_ITEM_SIZE);
+        assert_eq!(fgb_index_size(20, 0), 0);
+        assert_eq!(fgb_index_size(20, 1), 0);
+        assert_eq!(fgb_index_size(0, 16), 0);
+    }
+}
@@ -0,0 +1,255 @@
+use super::FlatTable;
+use crate::geometry::BBox;
+
+use alloc::{string::String, vec::Vec};
+
+/// The geometry types a FlatGeobuf may store
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FGBGeometryType {
+    /// Unknown, each feature stores its own type
+    #[default]
+    Unknown = 0,
+    /// Point
+    Point = 1,
+    /// LineString
+    LineString = 2,
+    /// Polygon
+    Polygon = 3,
+    /// MultiPoint
+    MultiPoint = 4,
+    /// MultiLineString
+    MultiLineString = 5,
+    /// MultiPolygon
+    MultiPolygon = 6,
+    /// GeometryCollection (not supported)
+    GeometryCollection = 7,
+    /// CircularString (not supported)
+    CircularString = 8,
+    /// CompoundCurve (not supported)
+    CompoundCurve = 9,
+    /// CurvePolygon (not supported)
+    CurvePolygon = 10,
+    /// MultiCurve (not supported)
+    MultiCurve = 11,
+    /// MultiSurface (not supported)
+    MultiSurface = 12,
+    /// Curve (not supported)
+    Curve = 13,
+    /// Surface (not supported)
+    Surface = 14,
+    /// PolyhedralSurface (not supported)
+    PolyhedralSurface = 15,
+    /// TIN (not supported)
+    TIN = 16,
+    /// Triangle (not supported)
+    Triangle = 17,
+}
+impl From<u8> for FGBGeometryType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => FGBGeometryType::Point,
+            2 => FGBGeometryType::LineString,
+            3 => FGBGeometryType::Polygon,
+            4 => FGBGeometryType::MultiPoint,
+            5 => FGBGeometryType::MultiLineString,
+            6 => FGBGeometryType::MultiPolygon,
+            7 => FGBGeometryType::GeometryCollection,
+            8 => FGBGeometryType::CircularString,
+            9 => FGBGeometryType::CompoundCurve,
+            10 => FGBGeometryType::CurvePolygon,
+            11 => FGBGeometryType::MultiCurve,
+            12 => FGBGeometryType::MultiSurface,
+            13 => FGBGeometryType::Curve,
+            14 => FGBGeometryType::Surface,
+            15 => FGBGeometryType::PolyhedralSurface,
+            16 => FGBGeometryType::TIN,
+            17 => FGBGeometryType::Triangle,
+            _ => FGBGeometryType::Unknown,
+        }
+    }
+}
+
+/// The types of attribute columns
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FGBColumnType {
+    /// Signed 8 bit integer
+    #[default]
+    Byte = 0,
+    /// Unsigned 8 bit integer
+    UByte = 1,
+    /// Boolean
+    Bool = 2,
+    /// Signed 16 bit integer
+    Short = 3,
+    /// Unsigned 16 bit integer
+    UShort = 4,
+    /// Signed 32 bit integer
+    Int = 5,
+    /// Unsigned 32 bit integer
+    UInt = 6,
+    /// Signed 64 bit integer
+    Long = 7,
+    /// Unsigned 64 bit integer
+    ULong = 8,
+    /// Single precision float
+    Float = 9,
+    /// Double precision float
+    Double = 10,
+    /// UTF-8 string
+    String = 11,
+    /// JSON encoded as a UTF-8 string
+    Json = 12,
+    /// ISO 8601 date-time encoded as a UTF-8 string
+    DateTime = 13,
+    /// Raw bytes
+    Binary = 14,
+}
+impl From<u8> for FGBColumnType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => FGBColumnType::UByte,
+            2 => FGBColumnType::Bool,
+            3 => FGBColumnType::Short,
+            4 => FGBColumnType::UShort,
+            5 => FGBColumnType::Int,
+            6 => FGBColumnType::UInt,
+            7 => FGBColumnType::Long,
+            8 => FGBColumnType::ULong,
+            9 => FGBColumnType::Float,
+            10 => FGBColumnType::Double,
+            11 => FGBColumnType::String,
+            12 => FGBColumnType::Json,
+            13 => FGBColumnType::DateTime,
+            14 => FGBColumnType::Binary,
+            _ => FGBColumnType::Byte,
+        }
+    }
+}
+
+/// An attribute column of the dataset (or of a feature with its own schema)
+#[derive(Debug, Clone, PartialEq)]
+pub struct FGBColumn {
+    /// The column name
+    pub name: String,
+    /// The type of the column's values
+    pub column_type: FGBColumnType,
+    /// A human readable title
+    pub title: Option<String>,
+    /// A description of the column
+    pub description: Option<String>,
+    /// The width of the values (-1 if unknown)
+    pub width: i32,
+    /// The precision of the values (-1 if unknown)
+    pub precision: i32,
+    /// The scale of the values (-1 if unknown)
+    pub scale: i32,
+    /// True if values may be missing
+    pub nullable: bool,
+    /// True if values are unique
+    pub unique: bool,
+    /// True if the column is the primary key
+    pub primary_key: bool,
+    /// Additional metadata, usually JSON
+    pub metadata: Option<String>,
+}
+impl From<&FlatTable<'_>> for FGBColumn {
+    fn from(table: &FlatTable<'_>) -> Self {
+        FGBColumn {
+            name: table.string(0).unwrap_or_default().into(),
+            column_type: table.u8(1, 0).into(),
+            title: table.string(2).map(String::from),
+            description: table.string(3).map(String::from),
+            width: table.i32(4, -1),
+            precision: table.i32(5, -1),
+            scale: table.i32(6, -1),
+            nullable: table.bool(7, true),
+            unique: table.bool(8, false),
+            primary_key: table.bool(9, false),
+            metadata: table.string(10).map(String::from),
+        }
+    }
+}
+
+/// The spatial reference system of the dataset
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FGBCrs {
+    /// The organization that defines the code, assumed to be `EPSG` if absent
+    pub org: Option<String>,
+    /// The numeric code of the reference system
+    pub code: i32,
+    /// The name of the reference system
+    pub name: Option<String>,
+    /// A description of the reference system
+    pub description: Option<String>,
+    /// The well-known text of the reference system
+    pub wkt: Option<String>,
+    /// The code if it isn't numeric
+    pub code_string: Option<String>,
+}
+impl From<&FlatTable<'_>> for FGBCrs {
+    fn from(table: &FlatTable<'_>) -> Self {
+        FGBCrs {
+            org: table.string(0).map(String::from),
+            code: table.i32(1, 0),
+            name: table.string(2).map(String::from),
+            description: table.string(3).map(String::from),
+            wkt: table.string(4).map(String::from),
+            code_string: table.string(5).map(String::from),
+        }
+    }
+}
+
+/// The FlatGeobuf header describing the dataset
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FGBHeader {
+    /// The dataset name
+    pub name: Option<String>,
+    /// The bounds of all the features if provided
+    pub envelope: Option<BBox>,
+    /// The geometry type of all features, [`FGBGeometryType::Unknown`] if it's per feature
+    pub geometry_type: FGBGeometryType,
+    /// True if the geometries have Z values
+    pub has_z: bool,
+    /// True if the geometries have M values
+    pub has_m: bool,
+    /// True if the geometries have T values
+    pub has_t: bool,
+    /// True if the geometries have TM values
+    pub has_tm: bool,
+    /// The attribute columns (may be empty if each feature stores its own schema)
+    pub columns: Vec<FGBColumn>,
+    /// The number of features, 0 if unknown
+    pub features_count: u64,
+    /// The number of children of each index node, 0 if there is no index
+    pub index_node_size: u16,
+    /// The spatial reference system
+    pub crs: Option<FGBCrs>,
+    /// The dataset title
+    pub title: Option<String>,
+    /// The dataset description
+    pub description: Option<String>,
+    /// Additional metadata, usually JSON
+    pub metadata: Option<String>,
+}
+impl From<&FlatTable<'_>> for FGBHeader {
+    fn from(table: &FlatTable<'_>) -> Self {
+        let envelope = table.f64s(1);
+        FGBHeader {
+            name: table.string(0).map(String::from),
+            envelope: (envelope.len() >= 4)
+                .then(|| BBox::new(envelope[0], envelope[1], envelope[2], envelope[3])),
+            geometry_type: table.u8(2, 0).into(),
+            has_z: table.bool(3, false),
+            has_m: table.bool(4, false),
+            has_t: table.bool(5, false),
+            has_tm: table.bool(6, false),
+            columns: table.tables(7).iter().map(FGBColumn::from).collect(),
+            features_count: table.u64(8, 0),
+            index_node_size: table.u16(9, 16),
+            crs: table.table(10).as_ref().map(FGBCrs::from),
+            title: table.string(11).map(String::from),
+            description: table.string(12).map(String::from),
+            metadata: table.string(13).map(String::from),
+        }
+    }
+}
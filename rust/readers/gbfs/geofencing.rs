@@ -0,0 +1,121 @@
+use super::{flag, localized, string, timestamp};
+use crate::{geometry::VectorGeometry, readers::json_geometry};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use s2json::{PrimitiveValue, Properties, ValuePrimitiveType, ValueType};
+use serde_json::Value;
+
+/// # Geofencing Rule
+///
+/// ## Details
+/// The restrictions of a geofencing zone. The v2 `ride_allowed` is stored as both
+/// `ride_start_allowed` and `ride_end_allowed`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GBFSGeofencingRule {
+    /// **Optional** - The vehicle types the rule applies to. Empty applies to all vehicle types.
+    pub vehicle_type_ids: Vec<String>,
+    /// **Required** - Whether a ride can be started in the zone
+    pub ride_start_allowed: bool,
+    /// **Required** - Whether a ride can be ended in the zone
+    pub ride_end_allowed: bool,
+    /// **Required** - Whether a ride can travel through the zone
+    pub ride_through_allowed: bool,
+    /// **Optional** - The maximum speed allowed in the zone, in kilometers per hour
+    pub maximum_speed_kph: Option<u64>,
+    /// **Optional** - Whether vehicles can only be parked at stations in the zone
+    pub station_parking: Option<bool>,
+}
+impl GBFSGeofencingRule {
+    /// Create a rule from an element of a zone's `rules`
+    pub fn new(rule: &Value) -> Self {
+        let ride_allowed = flag(rule.get("ride_allowed"));
+        let allowed = |key: &str| flag(rule.get(key)).or(ride_allowed).unwrap_or(true);
+        GBFSGeofencingRule {
+            vehicle_type_ids: rule
+                .get("vehicle_type_ids")
+                .and_then(Value::as_array)
+                .map(|ids| ids.iter().filter_map(|id| string(Some(id))).collect())
+                .unwrap_or_default(),
+            ride_start_allowed: allowed("ride_start_allowed"),
+            ride_end_allowed: allowed("ride_end_allowed"),
+            ride_through_allowed: flag(rule.get("ride_through_allowed")).unwrap_or(true),
+            maximum_speed_kph: rule.get("maximum_speed_kph").and_then(Value::as_u64),
+            station_parking: flag(rule.get("station_parking")),
+        }
+    }
+}
+
+/// # Geofencing Zone
+///
+/// ## Details
+/// An area with restrictions on where vehicles may be ridden or parked
+/// (`geofencing_zones.json`, v2.1+).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GBFSGeofencingZone {
+    /// **Optional** - Public name of the zone
+    pub name: Option<String>,
+    /// **Optional** - Start time of the zone, in POSIX seconds
+    pub start: Option<i64>,
+    /// **Optional** - End time of the zone, in POSIX seconds
+    pub end: Option<i64>,
+    /// **Optional** - The restrictions of the zone. The first rule matching a vehicle type
+    /// applies.
+    pub rules: Vec<GBFSGeofencingRule>,
+    /// **Required** - The area of the zone as a MultiPolygon
+    pub geometry: VectorGeometry,
+}
+impl GBFSGeofencingZone {
+    /// Create a zone from a GeoJSON feature of `data.geofencing_zones`. Returns None if the
+    /// geometry is missing.
+    pub fn new(feature: &Value, locale: &str) -> Option<Self> {
+        let properties = feature.get("properties");
+        let property = |key: &str| properties.and_then(|properties| properties.get(key));
+        Some(GBFSGeofencingZone {
+            name: localized(property("name"), locale),
+            start: timestamp(property("start")),
+            end: timestamp(property("end")),
+            rules: property("rules")
+                .and_then(Value::as_array)
+                .map(|rules| rules.iter().map(GBFSGeofencingRule::new).collect())
+                .unwrap_or_default(),
+            geometry: json_geometry(feature.get("geometry")?)?,
+        })
+    }
+
+    /// The properties of the zone's feature. Each rule is stored as an object in `rules`, its
+    /// vehicle type ids joined by commas.
+    pub fn properties(&self) -> Properties {
+        let mut properties = Properties::new();
+        if let Some(name) = &self.name {
+            properties
+                .insert("name".into(), ValueType::Primitive(PrimitiveValue::String(name.clone())));
+        }
+        for (key, value) in [("start", self.start), ("end", self.end)] {
+            if let Some(value) = value {
+                properties.insert(key.into(), ValueType::Primitive(PrimitiveValue::I64(value)));
+            }
+        }
+        let rules = self.rules.iter().map(|rule| {
+            let mut object = BTreeMap::new();
+            if !rule.vehicle_type_ids.is_empty() {
+                let ids = rule.vehicle_type_ids.join(",");
+                object.insert("vehicle_type_ids".into(), PrimitiveValue::String(ids));
+            }
+            object
+                .insert("ride_start_allowed".into(), PrimitiveValue::Bool(rule.ride_start_allowed));
+            object.insert("ride_end_allowed".into(), PrimitiveValue::Bool(rule.ride_end_allowed));
+            object.insert(
+                "ride_through_allowed".into(),
+                PrimitiveValue::Bool(rule.ride_through_allowed),
+            );
+            if let Some(speed) = rule.maximum_speed_kph {
+                object.insert("maximum_speed_kph".into(), PrimitiveValue::U64(speed));
+            }
+            if let Some(station_parking) = rule.station_parking {
+                object.insert("station_parking".into(), PrimitiveValue::Bool(station_parking));
+            }
+            ValuePrimitiveType::NestedPrimitive(object)
+        });
+        properties.insert("rules".into(), ValueType::Array(rules.collect()));
+        properties
+    }
+}
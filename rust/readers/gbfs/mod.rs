@@ -0,0 +1,433 @@
+/// Geofencing zone tools
+pub mod geofencing;
+/// Station information and status tools
+pub mod station;
+/// System information tools
+pub mod system;
+/// Vehicle status tools
+pub mod vehicle_status;
+
+pub use geofencing::*;
+pub use station::*;
+pub use system::*;
+pub use vehicle_status::*;
+
+use crate::{
+    geometry::{VectorFeature, VectorPoint},
+    readers::{wkt::geometry::point_geometry, FeatureIterator},
+};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use serde_json::Value;
+
+/// Errors that can occur while reading a GBFS feed
+#[derive(Debug, PartialEq)]
+pub enum GBFSError {
+    /// The document isn't valid JSON
+    InvalidJSON,
+    /// The document doesn't have a `data` object
+    MissingData,
+    /// The document's `version` isn't a 1.x, 2.x or 3.x version
+    UnsupportedVersion(String),
+}
+
+/// The major version of a GBFS feed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GBFSVersion {
+    /// Version 1.x. Version 1.0 documents don't have a `version`.
+    #[default]
+    V1 = 1,
+    /// Version 2.x
+    V2 = 2,
+    /// Version 3.x
+    V3 = 3,
+}
+
+/// # General Bikeshare Feed Specification (GBFS) Reader
+///
+/// ## Description
+/// Reads already fetched GBFS v1, v2 and v3 documents into typed structs, normalizing the
+/// differences between the versions:
+/// - Localized texts (v3) are resolved to the locale of the reader
+/// - `0`/`1` flags (v1) become booleans
+/// - RFC 3339 times (v3) become POSIX seconds
+/// - Bikes (v1 and v2) become vehicles, e.g. `num_bikes_available` is `num_vehicles_available`
+/// - The geofencing `ride_allowed` rule (v2) becomes `ride_start_allowed` and `ride_end_allowed`
+///
+/// Supported feeds are `system_information`, `station_information`, `station_status`,
+/// `free_bike_status`, `vehicle_status` and `geofencing_zones`. Other feeds are ignored.
+///
+/// Implements the [`FeatureIterator`] through [`GBFSReader::iter`], yielding a MultiPolygon
+/// feature for each geofencing zone, then a Point feature for each station (joined with its
+/// status) and each vehicle with coordinates. The properties use the GBFS v3 field names.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{FeatureIterator, GBFSReader};
+/// use std::path::PathBuf;
+///
+/// let mut reader = GBFSReader::new("fr");
+/// for feed in ["system_information", "station_information", "station_status"] {
+///     let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+///     path.push(format!("tests/readers/gbfs/fixtures/v3.0/{feed}.json"));
+///     reader.add_feed(feed, &std::fs::read_to_string(path).unwrap()).unwrap();
+/// }
+///
+/// assert_eq!(reader.system_information.as_ref().unwrap().name, "CapCotentin");
+/// let features: Vec<_> = reader.iter().collect();
+/// assert_eq!(features.len(), 19);
+/// ```
+///
+/// ## Links
+/// - <https://github.com/MobilityData/gbfs>
+/// - <https://github.com/MobilityData/gbfs-json-schema>
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GBFSReader {
+    /// The major version of the documents
+    pub version: GBFSVersion,
+    /// The locale used to resolve localized texts, e.g. `en` or `en-US`
+    pub locale: String,
+    /// The system information (`system_information.json`)
+    pub system_information: Option<GBFSSystemInformation>,
+    /// The stations (`station_information.json`)
+    pub stations: Vec<GBFSStation>,
+    /// The status of the stations (`station_status.json`) keyed by their id
+    pub station_status: BTreeMap<String, GBFSStationStatus>,
+    /// The vehicles that aren't in active rental (`free_bike_status.json` or
+    /// `vehicle_status.json`)
+    pub vehicles: Vec<GBFSVehicle>,
+    /// The geofencing zones (`geofencing_zones.json`)
+    pub geofencing_zones: Vec<GBFSGeofencingZone>,
+}
+impl GBFSReader {
+    /// Create an empty reader resolving localized texts to the locale, e.g. `en`
+    pub fn new(locale: &str) -> Self {
+        GBFSReader { locale: locale.into(), ..Default::default() }
+    }
+
+    /// Add a GBFS document by its feed name (e.g. `station_information`, with or without the
+    /// `.json` extension)
+    pub fn add_feed(&mut self, name: &str, json: &str) -> Result<(), GBFSError> {
+        let document: Value = serde_json::from_str(json).map_err(|_| GBFSError::InvalidJSON)?;
+        self.version = match string(document.get("version")) {
+            None => GBFSVersion::V1,
+            Some(version) => match version.split('.').next() {
+                Some("1") => GBFSVersion::V1,
+                Some("2") => GBFSVersion::V2,
+                Some("3") => GBFSVersion::V3,
+                _ => return Err(GBFSError::UnsupportedVersion(version)),
+            },
+        };
+        let data = document.get("data").filter(|data| data.is_object());
+        let data = data.ok_or(GBFSError::MissingData)?;
+        let list = |key: &str| data.get(key).and_then(Value::as_array).into_iter().flatten();
+        let locale = self.locale.as_str();
+        match name.strip_suffix(".json").unwrap_or(name) {
+            "system_information" => {
+                self.system_information = Some(GBFSSystemInformation::new(data, locale));
+            }
+            "station_information" => {
+                self.stations =
+                    list("stations").filter_map(|s| GBFSStation::new(s, locale)).collect();
+            }
+            "station_status" => {
+                self.station_status = list("stations")
+                    .filter_map(GBFSStationStatus::new)
+                    .map(|status| (status.station_id.clone(), status))
+                    .collect();
+            }
+            "free_bike_status" | "vehicle_status" => {
+                self.vehicles =
+                    list("bikes").chain(list("vehicles")).filter_map(GBFSVehicle::new).collect();
+            }
+            "geofencing_zones" => {
+                let features = data.get("geofencing_zones").and_then(|zones| zones.get("features"));
+                self.geofencing_zones = features
+                    .and_then(Value::as_array)
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|feature| GBFSGeofencingZone::new(feature, locale))
+                    .collect();
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Iterate the geofencing zones, stations and vehicles as features
+    pub fn iter(&self) -> GBFSIterator<'_> {
+        GBFSIterator {
+            reader: self,
+            zones: self.geofencing_zones.iter(),
+            stations: self.stations.iter(),
+            vehicles: self.vehicles.iter(),
+        }
+    }
+}
+
+/// An iterator over the geofencing zone, station and vehicle features of a [`GBFSReader`]
+#[derive(Debug)]
+pub struct GBFSIterator<'a> {
+    reader: &'a GBFSReader,
+    zones: core::slice::Iter<'a, GBFSGeofencingZone>,
+    stations: core::slice::Iter<'a, GBFSStation>,
+    vehicles: core::slice::Iter<'a, GBFSVehicle>,
+}
+impl Iterator for GBFSIterator<'_> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(zone) = self.zones.next() {
+            let geometry = zone.geometry.clone();
+            return Some(VectorFeature::new_wm(None, zone.properties(), geometry, None));
+        }
+        if let Some(station) = self.stations.next() {
+            let properties =
+                station.properties(self.reader.station_status.get(&station.station_id));
+            let geometry = point_geometry(VectorPoint::new(station.lon, station.lat, None, None));
+            return Some(VectorFeature::new_wm(None, properties, geometry, None));
+        }
+        self.vehicles.by_ref().find_map(|vehicle| {
+            let point = VectorPoint::new(vehicle.lon?, vehicle.lat?, None, None);
+            Some(VectorFeature::new_wm(None, vehicle.properties(), point_geometry(point), None))
+        })
+    }
+}
+impl FeatureIterator for GBFSIterator<'_> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// A string or integer id
+pub(crate) fn string(value: Option<&Value>) -> Option<String> {
+    match value? {
+        Value::String(value) => Some(value.clone()),
+        Value::Number(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+/// A boolean, or a `0`/`1` flag (v1)
+pub(crate) fn flag(value: Option<&Value>) -> Option<bool> {
+    match value? {
+        Value::Bool(value) => Some(*value),
+        Value::Number(value) => Some(value.as_f64()? != 0.),
+        _ => None,
+    }
+}
+
+/// A plain string, or the text of a v3 localized string matching the locale. Falls back to the
+/// translation of the same language (e.g. `en` for `en-US`) and then the first translation.
+pub(crate) fn localized(value: Option<&Value>, locale: &str) -> Option<String> {
+    let translations = match value? {
+        Value::Array(translations) => translations,
+        value => return string(Some(value)),
+    };
+    let language = |translation: &&Value| string(translation.get("language")).unwrap_or_default();
+    let primary = |tag: &str| tag.split(['-', '_']).next().unwrap_or_default().to_lowercase();
+    let translation = translations
+        .iter()
+        .find(|translation| language(translation).eq_ignore_ascii_case(locale))
+        .or_else(|| translations.iter().find(|t| primary(&language(t)) == primary(locale)))
+        .or(translations.first())?;
+    string(translation.get("text"))
+}
+
+/// POSIX seconds, or an RFC 3339 date-time (v3) converted to POSIX seconds
+pub(crate) fn timestamp(value: Option<&Value>) -> Option<i64> {
+    match value? {
+        Value::Number(value) => value.as_i64().or_else(|| value.as_f64().map(|v| v as i64)),
+        Value::String(value) => value.parse().ok().or_else(|| parse_rfc3339(value)),
+        _ => None,
+    }
+}
+
+/// Parse an RFC 3339 date-time like `2024-12-27T13:11:41+01:00` into POSIX seconds. Fractional
+/// seconds are dropped.
+pub(crate) fn parse_rfc3339(value: &str) -> Option<i64> {
+    let value = value.trim();
+    let number = |range: core::ops::Range<usize>| value.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (number(0..4)?, number(5..7)?, number(8..10)?);
+    let (hour, minute, second) = (number(11..13)?, number(14..16)?, number(17..19)?);
+    if !matches!(value.as_bytes().get(10), Some(b'T' | b't' | b' ')) {
+        return None;
+    }
+    // skip fractional seconds to the zone designator
+    let zone = value[19..].trim_start_matches(|c: char| c == '.' || c.is_ascii_digit());
+    let offset = match zone.as_bytes().first()? {
+        b'Z' | b'z' => 0,
+        sign @ (b'+' | b'-') => {
+            let hours = zone.get(1..3)?.parse::<i64>().ok()?;
+            let minutes = zone.get(4..6)?.parse::<i64>().ok()?;
+            let offset = hours * 3_600 + minutes * 60;
+            if *sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        _ => return None,
+    };
+    // days since the epoch of the proleptic Gregorian date
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    Some(days * 86_400 + hour * 3_600 + minute * 60 + second - offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::VectorGeometry;
+    use alloc::format;
+    use s2json::{PrimitiveValue, ValueType};
+    use std::path::PathBuf;
+
+    fn reader(version: &str, feeds: &[&str], locale: &str) -> GBFSReader {
+        let mut reader = GBFSReader::new(locale);
+        for feed in feeds {
+            let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+            path.push("tests/readers/gbfs/fixtures");
+            path.push(version);
+            path.push(format!("{feed}.json"));
+            reader.add_feed(feed, &std::fs::read_to_string(path).unwrap()).unwrap();
+        }
+        reader
+    }
+
+    fn primitive(feature: &VectorFeature, key: &str) -> Option<PrimitiveValue> {
+        match feature.properties.get(key) {
+            Some(ValueType::Primitive(value)) => Some(value.clone()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn test_v1() {
+        let feeds = ["system_information", "station_information", "free_bike_status"];
+        let reader = reader("v1.1", &feeds, "en");
+        assert_eq!(reader.version, GBFSVersion::V1);
+        let system = reader.system_information.as_ref().unwrap();
+        assert_eq!(system.system_id, "HELBIZ-US");
+        assert_eq!(system.languages, ["en"]);
+        assert_eq!(system.name, "Helbiz USA");
+        assert!(reader.stations.is_empty());
+
+        assert_eq!(reader.vehicles.len(), 2);
+        let vehicle = &reader.vehicles[0];
+        assert_eq!(vehicle.vehicle_id, "U3YXAT");
+        assert!(vehicle.is_reserved && vehicle.is_disabled);
+        assert!(!reader.vehicles[1].is_reserved);
+
+        let features: Vec<VectorFeature> = reader.iter().collect();
+        assert_eq!(features.len(), 2);
+        let VectorGeometry::Point(point) = &features[0].geometry else {
+            panic!("expected a Point");
+        };
+        assert_eq!((point.coordinates.x, point.coordinates.y), (-78.90667, 35.968015));
+        assert_eq!(primitive(&features[0], "is_reserved"), Some(PrimitiveValue::Bool(true)));
+    }
+
+    #[test]
+    fn test_v2() {
+        let feeds = ["system_information", "free_bike_status", "geofencing_zones"];
+        let reader = reader("v2.2", &feeds, "en");
+        assert_eq!(reader.version, GBFSVersion::V2);
+        assert_eq!(reader.vehicles[0].last_reported, Some(1_651_014_967));
+        assert_eq!(reader.vehicles[0].vehicle_type_id.as_deref(), Some("scooter"));
+
+        assert_eq!(reader.geofencing_zones.len(), 92);
+        let rule = &reader.geofencing_zones[0].rules[0];
+        assert!(rule.ride_start_allowed && rule.ride_end_allowed && rule.ride_through_allowed);
+        assert_eq!(rule.maximum_speed_kph, Some(25));
+
+        let features: Vec<VectorFeature> = reader.iter().collect();
+        assert_eq!(features.len(), 92 + 2);
+        assert!(matches!(features[0].geometry, VectorGeometry::MultiPolygon(_)));
+        let Some(ValueType::Array(rules)) = features[0].properties.get("rules") else {
+            panic!("expected rules");
+        };
+        assert_eq!(rules.len(), 1);
+    }
+
+    #[test]
+    fn test_v3() {
+        let feeds = [
+            "system_information",
+            "station_information",
+            "station_status",
+            "vehicle_status",
+            "geofencing_zones",
+        ];
+        let reader = reader("v3.0", &feeds, "en-US");
+        assert_eq!(reader.version, GBFSVersion::V3);
+        let system = reader.system_information.as_ref().unwrap();
+        assert_eq!(system.name, "CapCotentin");
+        assert_eq!(system.languages, ["fr"]);
+        assert_eq!(system.timezone, "Europe/Paris");
+
+        assert_eq!(reader.stations.len(), 19);
+        assert_eq!(reader.stations[0].name, "Hôtel des Impôts");
+        let status = &reader.station_status["stn_XLN88vWNhaeHAMXa8jgxer"];
+        assert_eq!(status.num_vehicles_available, 4);
+        assert_eq!(status.last_reported, Some(1_735_301_501));
+        assert_eq!(reader.vehicles.len(), 85);
+        assert_eq!(reader.geofencing_zones[0].name.as_deref(), Some("Gare de Valogne"));
+        assert!(!reader.geofencing_zones[0].rules[0].ride_start_allowed);
+
+        let features: Vec<VectorFeature> = reader.iter().collect();
+        assert_eq!(features.len(), 1 + 19 + 85);
+        let station = &features[1];
+        assert_eq!(
+            primitive(station, "station_id"),
+            Some(PrimitiveValue::String("stn_XLN88vWNhaeHAMXa8jgxer".into()))
+        );
+        assert_eq!(primitive(station, "capacity"), Some(PrimitiveValue::U64(12)));
+        assert_eq!(primitive(station, "num_vehicles_available"), Some(PrimitiveValue::U64(4)));
+        assert_eq!(primitive(station, "is_renting"), Some(PrimitiveValue::Bool(true)));
+    }
+
+    #[test]
+    fn test_values() {
+        let mut reader = GBFSReader::new("en");
+        assert_eq!(reader.add_feed("station_status", "{"), Err(GBFSError::InvalidJSON));
+        assert_eq!(
+            reader.add_feed("station_status", "{\"version\":\"1.0\"}"),
+            Err(GBFSError::MissingData)
+        );
+        assert_eq!(
+            reader.add_feed("gbfs", "{\"version\":\"4.0\",\"data\":{}}"),
+            Err(GBFSError::UnsupportedVersion("4.0".into()))
+        );
+        let status = "{\"data\":{\"stations\":[{\"station_id\":7,\"num_bikes_available\":3,\"\
+                      is_installed\":1,\"is_renting\":0,\"is_returning\":1,\"last_reported\":\
+                      10}]}}";
+        reader.add_feed("station_status.json", status).unwrap();
+        let status = &reader.station_status["7"];
+        assert_eq!(status.num_vehicles_available, 3);
+        assert!(status.is_installed && !status.is_renting);
+
+        let text = |value: &str, locale: &str| {
+            localized(Some(&serde_json::from_str(value).unwrap()), locale)
+        };
+        let names = "[{\"language\":\"fr\",\"text\":\"Gare\"},{\"language\":\"en-GB\",\"text\":\"\
+                     Station\"}]";
+        assert_eq!(text(names, "en-US").as_deref(), Some("Station"));
+        assert_eq!(text(names, "de").as_deref(), Some("Gare"));
+        assert_eq!(text("\"Name\"", "de").as_deref(), Some("Name"));
+
+        assert_eq!(parse_rfc3339("1970-01-01T00:00:00Z"), Some(0));
+        assert_eq!(parse_rfc3339("2000-03-01T00:00:00.250Z"), Some(951_868_800));
+        assert_eq!(parse_rfc3339("1969-12-31T19:00:00-05:00"), Some(0));
+        assert_eq!(parse_rfc3339("2024-12-27"), None);
+    }
+}
@@ -0,0 +1,183 @@
+use super::{flag, localized, string, timestamp};
+use crate::{geometry::VectorGeometry, readers::json_geometry};
+use alloc::{string::String, vec::Vec};
+use s2json::{PrimitiveValue, Properties, ValuePrimitiveType, ValueType};
+use serde_json::Value;
+
+/// # Station Information
+///
+/// ## Details
+/// A station where vehicles may be rented or returned (`station_information.json`).
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GBFSStation {
+    /// **Required** - Identifier of the station
+    pub station_id: String,
+    /// **Required** - The public name of the station
+    pub name: String,
+    /// **Optional** - Short name or other type of identifier
+    pub short_name: Option<String>,
+    /// **Required** - Latitude of the station
+    pub lat: f64,
+    /// **Required** - Longitude of the station
+    pub lon: f64,
+    /// **Optional** - Address (street number and name) where the station is located
+    pub address: Option<String>,
+    /// **Optional** - Cross street or landmark where the station is located
+    pub cross_street: Option<String>,
+    /// **Optional** - The region (`system_regions.json`) of the station
+    pub region_id: Option<String>,
+    /// **Optional** - Postal code where the station is located
+    pub post_code: Option<String>,
+    /// **Optional** - Payment methods accepted at the station, e.g. `key` or `creditcard`
+    pub rental_methods: Vec<String>,
+    /// **Optional** - Number of total docking points installed at the station
+    pub capacity: Option<u64>,
+    /// **Optional** - Whether the station is a location without physical infrastructure
+    pub is_virtual_station: Option<bool>,
+    /// **Optional** - Whether valet services are provided at the station
+    pub is_valet_station: Option<bool>,
+    /// **Optional** - Whether the station has charging facilities for electric vehicles
+    pub is_charging_station: Option<bool>,
+    /// **Optional** - The area of a virtual station as a MultiPolygon
+    pub station_area: Option<VectorGeometry>,
+}
+impl GBFSStation {
+    /// Create a station from an element of `data.stations`. Returns None if the id or
+    /// coordinates are missing.
+    pub fn new(station: &Value, locale: &str) -> Option<Self> {
+        Some(GBFSStation {
+            station_id: string(station.get("station_id"))?,
+            name: localized(station.get("name"), locale).unwrap_or_default(),
+            short_name: localized(station.get("short_name"), locale),
+            lat: station.get("lat")?.as_f64()?,
+            lon: station.get("lon")?.as_f64()?,
+            address: string(station.get("address")),
+            cross_street: string(station.get("cross_street")),
+            region_id: string(station.get("region_id")),
+            post_code: string(station.get("post_code")),
+            rental_methods: station
+                .get("rental_methods")
+                .and_then(Value::as_array)
+                .map(|methods| methods.iter().filter_map(|m| string(Some(m))).collect())
+                .unwrap_or_default(),
+            capacity: station.get("capacity").and_then(Value::as_u64),
+            is_virtual_station: flag(station.get("is_virtual_station")),
+            is_valet_station: flag(station.get("is_valet_station")),
+            is_charging_station: flag(station.get("is_charging_station")),
+            station_area: station.get("station_area").and_then(json_geometry),
+        })
+    }
+
+    /// The properties of the station's point feature, joined with its status if known. Empty
+    /// fields are left out.
+    pub fn properties(&self, status: Option<&GBFSStationStatus>) -> Properties {
+        let mut properties = Properties::new();
+        let strings = [
+            ("station_id", Some(&self.station_id)),
+            ("name", Some(&self.name)),
+            ("short_name", self.short_name.as_ref()),
+            ("address", self.address.as_ref()),
+            ("cross_street", self.cross_street.as_ref()),
+            ("region_id", self.region_id.as_ref()),
+            ("post_code", self.post_code.as_ref()),
+        ];
+        for (key, value) in strings {
+            if let Some(value) = value {
+                properties.insert(
+                    key.into(),
+                    ValueType::Primitive(PrimitiveValue::String(value.clone())),
+                );
+            }
+        }
+        if !self.rental_methods.is_empty() {
+            let methods = self.rental_methods.iter().map(|method| {
+                ValuePrimitiveType::Primitive(PrimitiveValue::String(method.clone()))
+            });
+            properties.insert("rental_methods".into(), ValueType::Array(methods.collect()));
+        }
+        let mut numbers = [("capacity", self.capacity)].to_vec();
+        let mut flags = [
+            ("is_virtual_station", self.is_virtual_station),
+            ("is_valet_station", self.is_valet_station),
+            ("is_charging_station", self.is_charging_station),
+        ]
+        .to_vec();
+        if let Some(status) = status {
+            numbers.extend([
+                ("num_vehicles_available", Some(status.num_vehicles_available)),
+                ("num_vehicles_disabled", status.num_vehicles_disabled),
+                ("num_docks_available", status.num_docks_available),
+                ("num_docks_disabled", status.num_docks_disabled),
+            ]);
+            flags.extend([
+                ("is_installed", Some(status.is_installed)),
+                ("is_renting", Some(status.is_renting)),
+                ("is_returning", Some(status.is_returning)),
+            ]);
+            if let Some(last_reported) = status.last_reported {
+                properties.insert(
+                    "last_reported".into(),
+                    ValueType::Primitive(PrimitiveValue::I64(last_reported)),
+                );
+            }
+        }
+        for (key, value) in numbers {
+            if let Some(value) = value {
+                properties.insert(key.into(), ValueType::Primitive(PrimitiveValue::U64(value)));
+            }
+        }
+        for (key, value) in flags {
+            if let Some(value) = value {
+                properties.insert(key.into(), ValueType::Primitive(PrimitiveValue::Bool(value)));
+            }
+        }
+        properties
+    }
+}
+
+/// # Station Status
+///
+/// ## Details
+/// The number of available vehicles and docks at a station and its availability
+/// (`station_status.json`). The v1 and v2 `num_bikes_*` counts are stored as `num_vehicles_*`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GBFSStationStatus {
+    /// **Required** - Identifier of the station
+    pub station_id: String,
+    /// **Required** - Number of functional vehicles physically at the station
+    pub num_vehicles_available: u64,
+    /// **Optional** - Number of disabled vehicles at the station
+    pub num_vehicles_disabled: Option<u64>,
+    /// **Conditionally Required** - Number of functional docks able to accept vehicles
+    pub num_docks_available: Option<u64>,
+    /// **Optional** - Number of empty but disabled docks
+    pub num_docks_disabled: Option<u64>,
+    /// **Required** - Whether the station is currently on the street
+    pub is_installed: bool,
+    /// **Required** - Whether the station is currently renting vehicles
+    pub is_renting: bool,
+    /// **Required** - Whether the station is accepting vehicle returns
+    pub is_returning: bool,
+    /// **Required** - The last time the station reported its status, in POSIX seconds
+    pub last_reported: Option<i64>,
+}
+impl GBFSStationStatus {
+    /// Create a station status from an element of `data.stations`. Returns None if the id is
+    /// missing.
+    pub fn new(status: &Value) -> Option<Self> {
+        let count =
+            |v3: &str, v1: &str| status.get(v3).or_else(|| status.get(v1)).and_then(Value::as_u64);
+        Some(GBFSStationStatus {
+            station_id: string(status.get("station_id"))?,
+            num_vehicles_available: count("num_vehicles_available", "num_bikes_available")
+                .unwrap_or_default(),
+            num_vehicles_disabled: count("num_vehicles_disabled", "num_bikes_disabled"),
+            num_docks_available: status.get("num_docks_available").and_then(Value::as_u64),
+            num_docks_disabled: status.get("num_docks_disabled").and_then(Value::as_u64),
+            is_installed: flag(status.get("is_installed")).unwrap_or(true),
+            is_renting: flag(status.get("is_renting")).unwrap_or(true),
+            is_returning: flag(status.get("is_returning")).unwrap_or(true),
+            last_reported: timestamp(status.get("last_reported")),
+        })
+    }
+}
@@ -0,0 +1,68 @@
+use super::{localized, string};
+use alloc::{string::String, vec, vec::Vec};
+use serde_json::Value;
+
+/// # System Information
+///
+/// ## Details
+/// Details including the system operator, system location, year implemented, URL, contact info
+/// and time zone (`system_information.json`). Localized texts (v3) are resolved to the locale of
+/// the reader.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GBFSSystemInformation {
+    /// **Required** - Identifier for this vehicle share system, globally unique
+    pub system_id: String,
+    /// **Required** - The languages used in the feeds, e.g. `en` (v1 and v2 have a single
+    /// language)
+    pub languages: Vec<String>,
+    /// **Required** - Name of the system to be displayed to customers
+    pub name: String,
+    /// **Optional** - Abbreviation for the system
+    pub short_name: Option<String>,
+    /// **Optional** - Name of the system operator
+    pub operator: Option<String>,
+    /// **Optional** - The URL of the vehicle share system
+    pub url: Option<String>,
+    /// **Optional** - URL where a customer can purchase a membership
+    pub purchase_url: Option<String>,
+    /// **Optional** - Date that the system began operations (`YYYY-MM-DD`)
+    pub start_date: Option<String>,
+    /// **Optional** - A single voice telephone number for customers
+    pub phone_number: Option<String>,
+    /// **Optional** - Email address actively monitored by the customer service department
+    pub email: Option<String>,
+    /// **Optional** - Email address for technical issues with the feed
+    pub feed_contact_email: Option<String>,
+    /// **Required** - The time zone where the system is located (a TZ database name)
+    pub timezone: String,
+    /// **Optional** - URL of the license of the data (the terms of use in v3)
+    pub license_url: Option<String>,
+}
+impl GBFSSystemInformation {
+    /// Create the system information from the `data` of a `system_information.json` document
+    pub fn new(data: &Value, locale: &str) -> Self {
+        let text = |key: &str| localized(data.get(key), locale);
+        let languages = match (data.get("languages"), string(data.get("language"))) {
+            (Some(Value::Array(languages)), _) => {
+                languages.iter().filter_map(|language| string(Some(language))).collect()
+            }
+            (_, Some(language)) => vec![language],
+            _ => vec![],
+        };
+        GBFSSystemInformation {
+            system_id: string(data.get("system_id")).unwrap_or_default(),
+            languages,
+            name: text("name").unwrap_or_default(),
+            short_name: text("short_name"),
+            operator: text("operator"),
+            url: string(data.get("url")),
+            purchase_url: string(data.get("purchase_url")),
+            start_date: string(data.get("start_date")),
+            phone_number: string(data.get("phone_number")),
+            email: string(data.get("email")),
+            feed_contact_email: string(data.get("feed_contact_email")),
+            timezone: string(data.get("timezone")).unwrap_or_default(),
+            license_url: string(data.get("license_url")).or_else(|| text("terms_url")),
+        }
+    }
+}
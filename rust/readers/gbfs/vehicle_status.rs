@@ -0,0 +1,96 @@
+use super::{flag, string, timestamp};
+use alloc::string::String;
+use s2json::{PrimitiveValue, Properties, ValueType};
+use serde_json::Value;
+
+/// # Vehicle Status
+///
+/// ## Details
+/// A vehicle that is not currently in active rental (`free_bike_status.json` in v1 and v2,
+/// `vehicle_status.json` in v3). The v1 and v2 `bike_id` is stored as the `vehicle_id`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GBFSVehicle {
+    /// **Required** - Identifier of the vehicle
+    pub vehicle_id: String,
+    /// **Conditionally Required** - Latitude of the vehicle. May be missing if the vehicle is
+    /// parked at a station.
+    pub lat: Option<f64>,
+    /// **Conditionally Required** - Longitude of the vehicle. May be missing if the vehicle is
+    /// parked at a station.
+    pub lon: Option<f64>,
+    /// **Required** - Whether the vehicle is currently reserved
+    pub is_reserved: bool,
+    /// **Required** - Whether the vehicle is currently disabled (broken)
+    pub is_disabled: bool,
+    /// **Conditionally Required** - The vehicle type (`vehicle_types.json`)
+    pub vehicle_type_id: Option<String>,
+    /// **Conditionally Required** - The station the vehicle is parked at
+    pub station_id: Option<String>,
+    /// **Optional** - The pricing plan (`system_pricing_plans.json`) of the vehicle
+    pub pricing_plan_id: Option<String>,
+    /// **Conditionally Required** - The furthest distance in meters the vehicle can travel
+    /// without recharging or refueling
+    pub current_range_meters: Option<f64>,
+    /// **Optional** - The current fuel or battery level of the vehicle [0, 1]
+    pub current_fuel_percent: Option<f64>,
+    /// **Optional** - The last time the vehicle reported its status, in POSIX seconds
+    pub last_reported: Option<i64>,
+}
+impl GBFSVehicle {
+    /// Create a vehicle from an element of `data.bikes` or `data.vehicles`. Returns None if the
+    /// id is missing.
+    pub fn new(vehicle: &Value) -> Option<Self> {
+        Some(GBFSVehicle {
+            vehicle_id: string(vehicle.get("vehicle_id").or_else(|| vehicle.get("bike_id")))?,
+            lat: vehicle.get("lat").and_then(Value::as_f64),
+            lon: vehicle.get("lon").and_then(Value::as_f64),
+            is_reserved: flag(vehicle.get("is_reserved")).unwrap_or_default(),
+            is_disabled: flag(vehicle.get("is_disabled")).unwrap_or_default(),
+            vehicle_type_id: string(vehicle.get("vehicle_type_id")),
+            station_id: string(vehicle.get("station_id")),
+            pricing_plan_id: string(vehicle.get("pricing_plan_id")),
+            current_range_meters: vehicle.get("current_range_meters").and_then(Value::as_f64),
+            current_fuel_percent: vehicle.get("current_fuel_percent").and_then(Value::as_f64),
+            last_reported: timestamp(vehicle.get("last_reported")),
+        })
+    }
+
+    /// The properties of the vehicle's point feature. Empty fields are left out.
+    pub fn properties(&self) -> Properties {
+        let mut properties = Properties::new();
+        let strings = [
+            ("vehicle_id", Some(&self.vehicle_id)),
+            ("vehicle_type_id", self.vehicle_type_id.as_ref()),
+            ("station_id", self.station_id.as_ref()),
+            ("pricing_plan_id", self.pricing_plan_id.as_ref()),
+        ];
+        for (key, value) in strings {
+            if let Some(value) = value {
+                properties.insert(
+                    key.into(),
+                    ValueType::Primitive(PrimitiveValue::String(value.clone())),
+                );
+            }
+        }
+        let flags = [("is_reserved", self.is_reserved), ("is_disabled", self.is_disabled)];
+        for (key, value) in flags {
+            properties.insert(key.into(), ValueType::Primitive(PrimitiveValue::Bool(value)));
+        }
+        let numbers = [
+            ("current_range_meters", self.current_range_meters),
+            ("current_fuel_percent", self.current_fuel_percent),
+        ];
+        for (key, value) in numbers {
+            if let Some(value) = value {
+                properties.insert(key.into(), ValueType::Primitive(PrimitiveValue::F64(value)));
+            }
+        }
+        if let Some(last_reported) = self.last_reported {
+            properties.insert(
+                "last_reported".into(),
+                ValueType::Primitive(PrimitiveValue::I64(last_reported)),
+            );
+        }
+        properties
+    }
+}
@@ -0,0 +1,253 @@
+use super::GeoTIFFError;
+use crate::util::{decompress_lzw, decompress_sync, CompressError};
+
+use alloc::vec::Vec;
+
+/// The compression schemes supported by the GeoTIFF reader
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GeoTIFFCompression {
+    /// No compression (1)
+    #[default]
+    None,
+    /// LZW (5)
+    Lzw,
+    /// Deflate (8 or the legacy 32946)
+    Deflate,
+    /// PackBits (32773)
+    PackBits,
+}
+impl TryFrom<u16> for GeoTIFFCompression {
+    type Error = GeoTIFFError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(GeoTIFFCompression::None),
+            5 => Ok(GeoTIFFCompression::Lzw),
+            8 | 32946 => Ok(GeoTIFFCompression::Deflate),
+            32773 => Ok(GeoTIFFCompression::PackBits),
+            _ => Err(GeoTIFFError::UnsupportedCompression(value)),
+        }
+    }
+}
+impl GeoTIFFCompression {
+    /// Decompress a strip or tile
+    pub fn decode(&self, data: Vec<u8>) -> Result<Vec<u8>, GeoTIFFError> {
+        match self {
+            GeoTIFFCompression::None => Ok(data),
+            GeoTIFFCompression::Lzw => Ok(decompress_lzw(&data)),
+            GeoTIFFCompression::Deflate => {
+                if data.is_empty() {
+                    return Ok(data);
+                }
+                decompress_sync(&data, None)
+                    .map_err(|e| GeoTIFFError::Compression(CompressError::FFlate(e)))
+            }
+            GeoTIFFCompression::PackBits => Ok(decode_packbits(&data)),
+        }
+    }
+}
+
+/// Decode PackBits run-length encoded data
+pub fn decode_packbits(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() * 2);
+    let mut i = 0;
+    while i < data.len() {
+        let header = data[i] as i8;
+        i += 1;
+        if header >= 0 {
+            // copy the next header + 1 bytes literally
+            let end = (i + header as usize + 1).min(data.len());
+            out.extend_from_slice(&data[i..end]);
+            i = end;
+        } else if header != -128 {
+            // repeat the next byte 1 - header times
+            if let Some(byte) = data.get(i) {
+                out.extend(core::iter::repeat_n(*byte, (1 - header as isize) as usize));
+            }
+            i += 1;
+        }
+    }
+    out
+}
+
+/// Undo the predictor applied to a decompressed block.
+///
+/// - `predictor` 1 is a no-op, 2 is horizontal differencing and 3 is floating point prediction.
+/// - `width` and `height` describe the block in pixels. The last strip may be shorter than `height`.
+/// - `stride` is the number of samples per pixel stored in the block (1 for planar images).
+///
+/// Errors if a row of the block would be empty (no pixels, samples or bytes per sample).
+pub fn apply_predictor(
+    block: &mut [u8],
+    predictor: u16,
+    width: usize,
+    height: usize,
+    stride: usize,
+    bits_per_sample: u16,
+    little_endian: bool,
+) -> Result<(), GeoTIFFError> {
+    if predictor <= 1 {
+        return Ok(());
+    }
+    if predictor > 3 {
+        return Err(GeoTIFFError::UnsupportedPredictor(predictor));
+    }
+    if bits_per_sample % 8 != 0 {
+        return Err(GeoTIFFError::UnsupportedPredictor(predictor));
+    }
+    let bytes_per_sample = bits_per_sample as usize / 8;
+    let row_len = width
+        .checked_mul(stride)
+        .and_then(|len| len.checked_mul(bytes_per_sample))
+        .filter(|len| *len > 0)
+        .ok_or(GeoTIFFError::InvalidIFD)?;
+    for row in block.chunks_exact_mut(row_len).take(height) {
+        if predictor == 2 {
+            decode_row_horizontal(row, stride, bytes_per_sample, little_endian)?;
+        } else {
+            decode_row_floating_point(row, stride, bytes_per_sample, little_endian);
+        }
+    }
+    Ok(())
+}
+
+/// Horizontal differencing: each sample stores the difference to the same sample of the
+/// previous pixel
+fn decode_row_horizontal(
+    row: &mut [u8],
+    stride: usize,
+    bytes_per_sample: usize,
+    little_endian: bool,
+) -> Result<(), GeoTIFFError> {
+    macro_rules! accumulate {
+        ($ty:ty, $size:expr) => {{
+            let read = |b: &[u8]| {
+                let bytes: [u8; $size] = b.try_into().unwrap();
+                if little_endian {
+                    <$ty>::from_le_bytes(bytes)
+                } else {
+                    <$ty>::from_be_bytes(bytes)
+                }
+            };
+            for i in stride..row.len() / $size {
+                let prev = read(&row[(i - stride) * $size..(i - stride + 1) * $size]);
+                let cur = read(&row[i * $size..(i + 1) * $size]);
+                let sum = cur.wrapping_add(prev);
+                let bytes = if little_endian { sum.to_le_bytes() } else { sum.to_be_bytes() };
+                row[i * $size..(i + 1) * $size].copy_from_slice(&bytes);
+            }
+        }};
+    }
+    match bytes_per_sample {
+        1 => {
+            for i in stride..row.len() {
+                row[i] = row[i].wrapping_add(row[i - stride]);
+            }
+        }
+        2 => accumulate!(u16, 2),
+        4 => accumulate!(u32, 4),
+        8 => accumulate!(u64, 8),
+        _ => return Err(GeoTIFFError::UnsupportedPredictor(2)),
+    }
+    Ok(())
+}
+
+/// Floating point prediction: the bytes of each row are split into planes (most significant
+/// byte first) and then byte-wise differenced
+fn decode_row_floating_point(
+    row: &mut [u8],
+    stride: usize,
+    bytes_per_sample: usize,
+    little_endian: bool,
+) {
+    for i in stride..row.len() {
+        row[i] = row[i].wrapping_add(row[i - stride]);
+    }
+    let count = row.len() / bytes_per_sample;
+    let planes = row.to_vec();
+    for i in 0..count {
+        for b in 0..bytes_per_sample {
+            let plane = if little_endian { bytes_per_sample - b - 1 } else { b };
+            row[bytes_per_sample * i + b] = planes[plane * count + i];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_packbits() {
+        // example from the TIFF 6.0 specification
+        let input = [
+            0xFE, 0xAA, 0x02, 0x80, 0x00, 0x2A, 0xFD, 0xAA, 0x03, 0x80, 0x00, 0x2A, 0x22, 0xF7,
+            0xAA,
+        ];
+        let expected = [
+            0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0xAA, 0xAA, 0xAA, 0xAA, 0x80, 0x00, 0x2A, 0x22,
+            0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA, 0xAA,
+        ];
+        assert_eq!(decode_packbits(&input), expected.to_vec());
+    }
+
+    #[test]
+    fn test_horizontal_predictor() {
+        // two rows of two RGB pixels with 16 bit big endian samples
+        let mut block = vec![];
+        for v in [1_u16, 2, 3, 1, 1, 1, 10, 20, 30, 65535, 0, 1] {
+            block.extend(v.to_be_bytes());
+        }
+        apply_predictor(&mut block, 2, 2, 2, 3, 16, false).unwrap();
+        let values: Vec<u16> =
+            block.as_chunks::<2>().0.iter().map(|b| u16::from_be_bytes(*b)).collect();
+        assert_eq!(values, vec![1, 2, 3, 2, 3, 4, 10, 20, 30, 9, 20, 31]);
+    }
+
+    #[test]
+    fn test_predictor_empty_rows() {
+        let mut block = vec![1, 2, 3, 4];
+        // zero width, samples per pixel and bits per sample
+        assert_eq!(
+            apply_predictor(&mut block, 2, 0, 1, 1, 8, false),
+            Err(GeoTIFFError::InvalidIFD)
+        );
+        assert_eq!(
+            apply_predictor(&mut block, 3, 4, 1, 0, 8, false),
+            Err(GeoTIFFError::InvalidIFD)
+        );
+        assert_eq!(
+            apply_predictor(&mut block, 2, 4, 1, 1, 0, false),
+            Err(GeoTIFFError::InvalidIFD)
+        );
+        // a row length that overflows
+        assert_eq!(
+            apply_predictor(&mut block, 2, usize::MAX, 1, 2, 8, false),
+            Err(GeoTIFFError::InvalidIFD)
+        );
+        assert_eq!(block, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_floating_point_predictor() {
+        let floats = [1.5_f32, -2.25, 100.0];
+        // build the planes (MSB first) then difference the bytes
+        let be: Vec<[u8; 4]> = floats.iter().map(|f| f.to_be_bytes()).collect();
+        let mut block: Vec<u8> = (0..4).flat_map(|p| be.iter().map(move |b| b[p])).collect();
+        for i in (1..block.len()).rev() {
+            block[i] = block[i].wrapping_sub(block[i - 1]);
+        }
+        let mut le_block = block.clone();
+
+        apply_predictor(&mut le_block, 3, 3, 1, 1, 32, true).unwrap();
+        let decoded: Vec<f32> =
+            le_block.as_chunks::<4>().0.iter().map(|b| f32::from_le_bytes(*b)).collect();
+        assert_eq!(decoded, floats.to_vec());
+
+        apply_predictor(&mut block, 3, 3, 1, 1, 32, false).unwrap();
+        let decoded: Vec<f32> =
+            block.as_chunks::<4>().0.iter().map(|b| f32::from_be_bytes(*b)).collect();
+        assert_eq!(decoded, floats.to_vec());
+    }
+}
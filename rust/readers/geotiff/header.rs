@@ -0,0 +1,471 @@
+use super::GeoTIFFError;
+use crate::readers::Reader;
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+/// Image width in pixels
+pub const TAG_IMAGE_WIDTH: u16 = 256;
+/// Image height in pixels
+pub const TAG_IMAGE_LENGTH: u16 = 257;
+/// Bits per sample for each sample of a pixel
+pub const TAG_BITS_PER_SAMPLE: u16 = 258;
+/// The compression scheme used by the strips or tiles
+pub const TAG_COMPRESSION: u16 = 259;
+/// The color space of the image data
+pub const TAG_PHOTOMETRIC_INTERPRETATION: u16 = 262;
+/// The byte offset of each strip
+pub const TAG_STRIP_OFFSETS: u16 = 273;
+/// The number of samples per pixel
+pub const TAG_SAMPLES_PER_PIXEL: u16 = 277;
+/// The number of rows in each strip
+pub const TAG_ROWS_PER_STRIP: u16 = 278;
+/// The number of bytes in each strip
+pub const TAG_STRIP_BYTE_COUNTS: u16 = 279;
+/// How the samples of a pixel are stored (1 = chunky, 2 = planar)
+pub const TAG_PLANAR_CONFIGURATION: u16 = 284;
+/// The predictor applied before compression
+pub const TAG_PREDICTOR: u16 = 317;
+/// The tile width in pixels
+pub const TAG_TILE_WIDTH: u16 = 322;
+/// The tile height in pixels
+pub const TAG_TILE_LENGTH: u16 = 323;
+/// The byte offset of each tile
+pub const TAG_TILE_OFFSETS: u16 = 324;
+/// The number of bytes in each tile
+pub const TAG_TILE_BYTE_COUNTS: u16 = 325;
+/// Describes extra (e.g. alpha) samples
+pub const TAG_EXTRA_SAMPLES: u16 = 338;
+/// How to interpret each sample (1 = unsigned, 2 = signed, 3 = float)
+pub const TAG_SAMPLE_FORMAT: u16 = 339;
+/// The GDAL no data value stored as an ASCII string
+pub const TAG_GDAL_NODATA: u16 = 42113;
+/// The size of a raster pixel in model space
+pub const TAG_MODEL_PIXEL_SCALE: u16 = 33550;
+/// Raster to model space tiepoints
+pub const TAG_MODEL_TIEPOINT: u16 = 33922;
+/// The affine raster to model space transformation
+pub const TAG_MODEL_TRANSFORMATION: u16 = 34264;
+/// The GeoKey directory
+pub const TAG_GEO_KEY_DIRECTORY: u16 = 34735;
+/// Double values referenced by the GeoKey directory
+pub const TAG_GEO_DOUBLE_PARAMS: u16 = 34736;
+/// ASCII values referenced by the GeoKey directory
+pub const TAG_GEO_ASCII_PARAMS: u16 = 34737;
+
+/// The model type (1 = projected, 2 = geographic, 3 = geocentric)
+pub const GT_MODEL_TYPE_GEO_KEY: u16 = 1024;
+/// The raster type (1 = pixel is area, 2 = pixel is point)
+pub const GT_RASTER_TYPE_GEO_KEY: u16 = 1025;
+/// A description of the model
+pub const GT_CITATION_GEO_KEY: u16 = 1026;
+/// The EPSG code of the geographic coordinate system
+pub const GEOGRAPHIC_TYPE_GEO_KEY: u16 = 2048;
+/// A description of the geographic coordinate system
+pub const GEOG_CITATION_GEO_KEY: u16 = 2049;
+/// The EPSG code of the geodetic datum
+pub const GEOG_GEODETIC_DATUM_GEO_KEY: u16 = 2050;
+/// The EPSG code of the angular units
+pub const GEOG_ANGULAR_UNITS_GEO_KEY: u16 = 2054;
+/// The EPSG code of the ellipsoid
+pub const GEOG_ELLIPSOID_GEO_KEY: u16 = 2056;
+/// The EPSG code of the projected coordinate system
+pub const PROJECTED_CS_TYPE_GEO_KEY: u16 = 3072;
+/// A description of the projected coordinate system
+pub const PCS_CITATION_GEO_KEY: u16 = 3073;
+/// The EPSG code of the projection
+pub const PROJECTION_GEO_KEY: u16 = 3074;
+/// The coordinate transformation method of a user defined projection
+pub const PROJ_COORD_TRANS_GEO_KEY: u16 = 3075;
+/// The EPSG code of the linear units
+pub const PROJ_LINEAR_UNITS_GEO_KEY: u16 = 3076;
+/// The EPSG code of the vertical coordinate system
+pub const VERTICAL_CS_TYPE_GEO_KEY: u16 = 4096;
+/// A GeoKey value of 32767 means "user defined"
+pub const GEO_KEY_USER_DEFINED: u16 = 32767;
+
+/// A parsed TIFF tag value. Rationals are stored as flattened numerator/denominator pairs
+#[derive(Debug, Clone, PartialEq)]
+pub enum TagValue {
+    /// An ASCII string (without the trailing NUL)
+    Ascii(String),
+    /// Any numeric value(s)
+    Numbers(Vec<f64>),
+}
+impl TagValue {
+    /// Get the numbers if the value is numeric
+    pub fn numbers(&self) -> Option<&[f64]> {
+        match self {
+            TagValue::Numbers(numbers) => Some(numbers),
+            _ => None,
+        }
+    }
+
+    /// Get the first number if the value is numeric
+    pub fn number(&self) -> Option<f64> {
+        self.numbers().and_then(|n| n.first().copied())
+    }
+
+    /// Get the string if the value is ASCII
+    pub fn ascii(&self) -> Option<&str> {
+        match self {
+            TagValue::Ascii(ascii) => Some(ascii),
+            _ => None,
+        }
+    }
+}
+
+/// A GeoKey value
+#[derive(Debug, Clone, PartialEq)]
+pub enum GeoKeyValue {
+    /// A SHORT value stored directly in the directory (usually an EPSG code or enum)
+    Short(u16),
+    /// Values taken from the GeoDoubleParams tag
+    Doubles(Vec<f64>),
+    /// A string taken from the GeoAsciiParams tag
+    Ascii(String),
+}
+
+/// The GeoKey directory describing the coordinate reference system of an image
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GeoKeyDirectory {
+    /// The key directory version (always 1)
+    pub version: u16,
+    /// The GeoKeys indexed by their key ID, e.g. [`GT_MODEL_TYPE_GEO_KEY`]
+    pub keys: BTreeMap<u16, GeoKeyValue>,
+}
+impl GeoKeyDirectory {
+    /// Get a GeoKey
+    pub fn get(&self, key: u16) -> Option<&GeoKeyValue> {
+        self.keys.get(&key)
+    }
+
+    /// Get a GeoKey stored as a SHORT
+    pub fn get_short(&self, key: u16) -> Option<u16> {
+        match self.keys.get(&key) {
+            Some(GeoKeyValue::Short(value)) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Get a GeoKey stored as an ASCII string
+    pub fn get_ascii(&self, key: u16) -> Option<&str> {
+        match self.keys.get(&key) {
+            Some(GeoKeyValue::Ascii(value)) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The model type (1 = projected, 2 = geographic, 3 = geocentric)
+    pub fn model_type(&self) -> Option<u16> {
+        self.get_short(GT_MODEL_TYPE_GEO_KEY)
+    }
+
+    /// The raster type (1 = pixel is area, 2 = pixel is point)
+    pub fn raster_type(&self) -> Option<u16> {
+        self.get_short(GT_RASTER_TYPE_GEO_KEY)
+    }
+
+    /// The EPSG code of the coordinate system. Uses the projected coordinate system for projected
+    /// models, otherwise the geographic one. User defined systems have no code
+    pub fn epsg(&self) -> Option<u16> {
+        let key = if self.model_type() == Some(1) {
+            PROJECTED_CS_TYPE_GEO_KEY
+        } else {
+            GEOGRAPHIC_TYPE_GEO_KEY
+        };
+        self.get_short(key).filter(|code| *code != 0 && *code != GEO_KEY_USER_DEFINED)
+    }
+}
+
+/// The parsed Image File Directory (IFD) of a single image
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct ImageFileDirectory {
+    /// All tags indexed by their tag ID, e.g. [`TAG_IMAGE_WIDTH`]
+    pub tags: BTreeMap<u16, TagValue>,
+    /// The GeoKeys if the image has a GeoKey directory
+    pub geo_keys: Option<GeoKeyDirectory>,
+}
+impl ImageFileDirectory {
+    /// Get a tag's value
+    pub fn get(&self, tag: u16) -> Option<&TagValue> {
+        self.tags.get(&tag)
+    }
+
+    /// Get a tag's numbers
+    pub fn get_numbers(&self, tag: u16) -> Option<&[f64]> {
+        self.get(tag).and_then(TagValue::numbers)
+    }
+
+    /// Get a tag's first number
+    pub fn get_number(&self, tag: u16) -> Option<f64> {
+        self.get(tag).and_then(TagValue::number)
+    }
+
+    /// Get a tag's ASCII string
+    pub fn get_ascii(&self, tag: u16) -> Option<&str> {
+        self.get(tag).and_then(TagValue::ascii)
+    }
+}
+
+/// The TIFF header and all image file directories
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GeoTIFFHeader {
+    /// True if the file is little endian
+    pub little_endian: bool,
+    /// True if the file is a BigTIFF (64-bit offsets)
+    pub big_tiff: bool,
+    /// The image file directories in file order
+    pub image_directories: Vec<ImageFileDirectory>,
+}
+impl GeoTIFFHeader {
+    /// Parse the TIFF header and walk the chain of image file directories
+    pub fn new<T: Reader>(reader: &mut T) -> Result<Self, GeoTIFFError> {
+        if reader.len() < 8 {
+            return Err(GeoTIFFError::InvalidByteOrder);
+        }
+        let little_endian = match reader.uint16_be(Some(0)) {
+            0x4949 => true,
+            0x4d4d => false,
+            _ => return Err(GeoTIFFError::InvalidByteOrder),
+        };
+        let mut header = GeoTIFFHeader { little_endian, ..Default::default() };
+        match header.read_u16(reader, 2) {
+            42 => {}
+            43 => {
+                header.big_tiff = true;
+                if header.read_u16(reader, 4) != 8 {
+                    return Err(GeoTIFFError::UnsupportedOffsetSize);
+                }
+            }
+            _ => return Err(GeoTIFFError::InvalidMagicNumber),
+        }
+
+        let mut ifd_offset = header.read_offset(reader, if header.big_tiff { 8 } else { 4 });
+        let mut visited = vec![];
+        while ifd_offset != 0 && ifd_offset < reader.len() && !visited.contains(&ifd_offset) {
+            visited.push(ifd_offset);
+            let (ifd, next) = header.read_ifd(reader, ifd_offset)?;
+            header.image_directories.push(ifd);
+            ifd_offset = next;
+        }
+
+        Ok(header)
+    }
+
+    fn read_u16<T: Reader>(&self, reader: &mut T, offset: usize) -> u16 {
+        if self.little_endian {
+            reader.uint16_le(Some(offset))
+        } else {
+            reader.uint16_be(Some(offset))
+        }
+    }
+
+    fn read_u32<T: Reader>(&self, reader: &mut T, offset: usize) -> u32 {
+        if self.little_endian {
+            reader.uint32_le(Some(offset))
+        } else {
+            reader.uint32_be(Some(offset))
+        }
+    }
+
+    fn read_u64<T: Reader>(&self, reader: &mut T, offset: usize) -> u64 {
+        if self.little_endian {
+            reader.uint64_le(Some(offset))
+        } else {
+            reader.uint64_be(Some(offset))
+        }
+    }
+
+    /// Read an offset or count (32 bits, or 64 bits for BigTIFF)
+    fn read_offset<T: Reader>(&self, reader: &mut T, offset: usize) -> usize {
+        if self.big_tiff {
+            self.read_u64(reader, offset) as usize
+        } else {
+            self.read_u32(reader, offset) as usize
+        }
+    }
+
+    /// Read the IFD at `offset`, returning it and the offset of the next IFD
+    fn read_ifd<T: Reader>(
+        &self,
+        reader: &mut T,
+        offset: usize,
+    ) -> Result<(ImageFileDirectory, usize), GeoTIFFError> {
+        let (count_size, entry_size, inline_size) =
+            if self.big_tiff { (8, 20, 8) } else { (2, 12, 4) };
+        let num_entries = if self.big_tiff {
+            self.read_u64(reader, offset) as usize
+        } else {
+            self.read_u16(reader, offset) as usize
+        };
+        let entries_end = offset + count_size + num_entries * entry_size;
+        if entries_end + inline_size > reader.len() {
+            return Err(GeoTIFFError::InvalidIFD);
+        }
+
+        let mut ifd = ImageFileDirectory::default();
+        for i in 0..num_entries {
+            let entry = offset + count_size + i * entry_size;
+            let tag = self.read_u16(reader, entry);
+            let field_type = self.read_u16(reader, entry + 2);
+            let count = self.read_offset(reader, entry + 4);
+            let Some(type_size) = field_type_size(field_type) else {
+                // unknown field types must be skipped
+                continue;
+            };
+            let value_offset = entry + 4 + inline_size;
+            let data_offset = if type_size * count <= inline_size {
+                value_offset
+            } else {
+                self.read_offset(reader, value_offset)
+            };
+            if data_offset + type_size * count > reader.len() {
+                return Err(GeoTIFFError::InvalidIFD);
+            }
+            let value = self.read_value(reader, field_type, count, data_offset);
+            ifd.tags.insert(tag, value);
+        }
+        ifd.geo_keys = parse_geo_keys(&ifd)?;
+
+        Ok((ifd, self.read_offset(reader, entries_end)))
+    }
+
+    fn read_value<T: Reader>(
+        &self,
+        reader: &mut T,
+        field_type: u16,
+        count: usize,
+        offset: usize,
+    ) -> TagValue {
+        let le = self.little_endian;
+        if field_type == 2 {
+            let bytes = reader.slice(Some(offset), Some(offset + count));
+            let end = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+            return TagValue::Ascii(String::from_utf8_lossy(&bytes[..end]).into_owned());
+        }
+        let values = match field_type {
+            // BYTE, UNDEFINED
+            1 | 7 => (0..count).map(|i| reader.uint8(Some(offset + i)) as f64).collect(),
+            // SBYTE
+            6 => (0..count).map(|i| reader.int8(Some(offset + i)) as f64).collect(),
+            // SHORT
+            3 => (0..count).map(|i| self.read_u16(reader, offset + i * 2) as f64).collect(),
+            // SSHORT
+            8 => (0..count)
+                .map(|i| {
+                    let o = Some(offset + i * 2);
+                    (if le { reader.int16_le(o) } else { reader.int16_be(o) }) as f64
+                })
+                .collect(),
+            // LONG, IFD
+            4 | 13 => (0..count).map(|i| self.read_u32(reader, offset + i * 4) as f64).collect(),
+            // SLONG
+            9 => (0..count)
+                .map(|i| {
+                    let o = Some(offset + i * 4);
+                    (if le { reader.int32_le(o) } else { reader.int32_be(o) }) as f64
+                })
+                .collect(),
+            // RATIONAL
+            5 => (0..count * 2).map(|i| self.read_u32(reader, offset + i * 4) as f64).collect(),
+            // SRATIONAL
+            10 => (0..count * 2)
+                .map(|i| {
+                    let o = Some(offset + i * 4);
+                    (if le { reader.int32_le(o) } else { reader.int32_be(o) }) as f64
+                })
+                .collect(),
+            // FLOAT
+            11 => (0..count)
+                .map(|i| {
+                    let o = Some(offset + i * 4);
+                    (if le { reader.f32_le(o) } else { reader.f32_be(o) }) as f64
+                })
+                .collect(),
+            // DOUBLE
+            12 => (0..count)
+                .map(|i| {
+                    let o = Some(offset + i * 8);
+                    if le {
+                        reader.f64_le(o)
+                    } else {
+                        reader.f64_be(o)
+                    }
+                })
+                .collect(),
+            // LONG8, IFD8
+            16 | 18 => (0..count).map(|i| self.read_u64(reader, offset + i * 8) as f64).collect(),
+            // SLONG8
+            17 => (0..count)
+                .map(|i| {
+                    let o = Some(offset + i * 8);
+                    (if le { reader.int64_le(o) } else { reader.int64_be(o) }) as f64
+                })
+                .collect(),
+            _ => vec![],
+        };
+        TagValue::Numbers(values)
+    }
+}
+
+/// The size in bytes of a TIFF field type
+fn field_type_size(field_type: u16) -> Option<usize> {
+    match field_type {
+        1 | 2 | 6 | 7 => Some(1),
+        3 | 8 => Some(2),
+        4 | 9 | 11 | 13 => Some(4),
+        5 | 10 | 12 | 16 | 17 | 18 => Some(8),
+        _ => None,
+    }
+}
+
+/// Parse the GeoKey directory of an IFD, resolving values stored in the GeoDoubleParams and
+/// GeoAsciiParams tags
+fn parse_geo_keys(ifd: &ImageFileDirectory) -> Result<Option<GeoKeyDirectory>, GeoTIFFError> {
+    let Some(raw) = ifd.get_numbers(TAG_GEO_KEY_DIRECTORY) else {
+        return Ok(None);
+    };
+    let directory = parse_geo_key_directory(
+        raw,
+        ifd.get_numbers(TAG_GEO_DOUBLE_PARAMS),
+        ifd.get_ascii(TAG_GEO_ASCII_PARAMS),
+        |tag, offset| ifd.get_numbers(tag).and_then(|d| d.get(offset)).copied(),
+    );
+    directory.map(Some).ok_or(GeoTIFFError::InvalidGeoKeyDirectory)
+}
+
+/// Parse the raw values of a GeoKey directory. `doubles` and `ascii` are the GeoDoubleParams and
+/// GeoAsciiParams values, a key stored in any other tag is looked up with `other(tag, offset)`.
+/// Returns `None` if the directory is malformed or references missing values
+pub(crate) fn parse_geo_key_directory(
+    raw: &[f64],
+    doubles: Option<&[f64]>,
+    ascii: Option<&str>,
+    other: impl Fn(u16, usize) -> Option<f64>,
+) -> Option<GeoKeyDirectory> {
+    if raw.len() < 4 {
+        return None;
+    }
+    let mut directory = GeoKeyDirectory { version: raw[0] as u16, ..Default::default() };
+    let num_keys = raw[3] as usize;
+    for key in raw[4..].as_chunks::<4>().0.iter().take(num_keys) {
+        let [id, location, count, offset] = key.map(|v| v as usize);
+        let value = match location as u16 {
+            0 => GeoKeyValue::Short(offset as u16),
+            TAG_GEO_DOUBLE_PARAMS => {
+                GeoKeyValue::Doubles(doubles?.get(offset..offset + count)?.to_vec())
+            }
+            TAG_GEO_ASCII_PARAMS => {
+                let ascii = ascii?;
+                // strings are terminated with a '|' which is dropped
+                let end = (offset + count).saturating_sub(1).min(ascii.len());
+                let value = ascii.get(offset.min(end)..end).unwrap_or_default();
+                GeoKeyValue::Ascii(value.into())
+            }
+            tag => GeoKeyValue::Short(other(tag, offset)? as u16),
+        };
+        directory.keys.insert(id as u16, value);
+    }
+
+    Some(directory)
+}
@@ -0,0 +1,431 @@
+use super::{
+    apply_predictor, GeoTIFFCompression, GeoTIFFError, ImageFileDirectory, TAG_BITS_PER_SAMPLE,
+    TAG_COMPRESSION, TAG_GDAL_NODATA, TAG_IMAGE_LENGTH, TAG_IMAGE_WIDTH, TAG_MODEL_PIXEL_SCALE,
+    TAG_MODEL_TIEPOINT, TAG_MODEL_TRANSFORMATION, TAG_PLANAR_CONFIGURATION, TAG_PREDICTOR,
+    TAG_ROWS_PER_STRIP, TAG_SAMPLES_PER_PIXEL, TAG_SAMPLE_FORMAT, TAG_STRIP_BYTE_COUNTS,
+    TAG_STRIP_OFFSETS, TAG_TILE_BYTE_COUNTS, TAG_TILE_LENGTH, TAG_TILE_OFFSETS, TAG_TILE_WIDTH,
+};
+use crate::geometry::BBox;
+use crate::readers::{BufferReader, Reader};
+
+use alloc::{vec, vec::Vec};
+use libm::{floor, round};
+
+/// Decoded raster data. Samples are interleaved: `data[(y * width + x) * samples + sample]`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Raster {
+    /// The width of the raster in pixels
+    pub width: usize,
+    /// The height of the raster in pixels
+    pub height: usize,
+    /// The number of samples stored for each pixel
+    pub samples: usize,
+    /// The sample values
+    pub data: Vec<f64>,
+}
+
+/// # GeoTIFF Image
+///
+/// ## Description
+/// A single image (IFD) of a GeoTIFF. Decodes its strips or tiles on demand and maps between
+/// raster (pixel) space and model space using the tiepoints, pixel scale or model transformation.
+///
+/// NOTE: Model coordinates are in the image's coordinate reference system, see [`GeoTIFFImage::epsg`].
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, GeoTIFFReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/geotiff/fixtures/utm.tif");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+/// let mut image = reader.get_image(0).unwrap();
+/// assert_eq!(image.epsg(), Some(32617));
+/// // sample the pixel under a model coordinate
+/// let value = image.get_value_at(690000., 4550000.).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct GeoTIFFImage<'a, T: Reader> {
+    reader: &'a mut T,
+    ifd: &'a ImageFileDirectory,
+    little_endian: bool,
+    compression: GeoTIFFCompression,
+    /// the last decoded block and its index
+    cache: Option<(usize, Vec<u8>)>,
+}
+impl<'a, T: Reader> GeoTIFFImage<'a, T> {
+    /// Create a new image from its IFD
+    pub fn new(
+        reader: &'a mut T,
+        ifd: &'a ImageFileDirectory,
+        little_endian: bool,
+    ) -> Result<Self, GeoTIFFError> {
+        let compression = (ifd.get_number(TAG_COMPRESSION).unwrap_or(1.) as u16).try_into()?;
+        Ok(Self { reader, ifd, little_endian, compression, cache: None })
+    }
+
+    /// The image file directory
+    pub fn ifd(&self) -> &ImageFileDirectory {
+        self.ifd
+    }
+
+    /// The image width in pixels
+    pub fn width(&self) -> usize {
+        self.ifd.get_number(TAG_IMAGE_WIDTH).unwrap_or(0.) as usize
+    }
+
+    /// The image height in pixels
+    pub fn height(&self) -> usize {
+        self.ifd.get_number(TAG_IMAGE_LENGTH).unwrap_or(0.) as usize
+    }
+
+    /// True if the image is stored in tiles rather than strips
+    pub fn is_tiled(&self) -> bool {
+        self.ifd.get(TAG_STRIP_OFFSETS).is_none()
+    }
+
+    /// The width of a tile, or the image width for strips
+    pub fn tile_width(&self) -> usize {
+        if self.is_tiled() {
+            self.ifd.get_number(TAG_TILE_WIDTH).unwrap_or(0.) as usize
+        } else {
+            self.width()
+        }
+    }
+
+    /// The height of a tile, or the number of rows per strip
+    pub fn tile_height(&self) -> usize {
+        if self.is_tiled() {
+            self.ifd.get_number(TAG_TILE_LENGTH).unwrap_or(0.) as usize
+        } else {
+            let rows = self.ifd.get_number(TAG_ROWS_PER_STRIP).map(|r| r as usize);
+            rows.unwrap_or(usize::MAX).min(self.height())
+        }
+    }
+
+    /// The number of samples per pixel
+    pub fn samples_per_pixel(&self) -> usize {
+        self.ifd.get_number(TAG_SAMPLES_PER_PIXEL).unwrap_or(1.) as usize
+    }
+
+    /// The number of bits of a sample
+    pub fn bits_per_sample(&self, sample: usize) -> u16 {
+        self.ifd.get_numbers(TAG_BITS_PER_SAMPLE).and_then(|b| b.get(sample)).copied().unwrap_or(1.)
+            as u16
+    }
+
+    /// The format of a sample (1 = unsigned integer, 2 = signed integer, 3 = float)
+    pub fn sample_format(&self, sample: usize) -> u16 {
+        self.ifd.get_numbers(TAG_SAMPLE_FORMAT).and_then(|f| f.get(sample)).copied().unwrap_or(1.)
+            as u16
+    }
+
+    /// How the samples are stored (1 = chunky/interleaved, 2 = planar)
+    pub fn planar_configuration(&self) -> u16 {
+        self.ifd.get_number(TAG_PLANAR_CONFIGURATION).unwrap_or(1.) as u16
+    }
+
+    /// The GDAL no data value if present
+    pub fn no_data(&self) -> Option<f64> {
+        self.ifd.get_ascii(TAG_GDAL_NODATA).and_then(|v| v.trim().parse().ok())
+    }
+
+    /// The EPSG code of the image's coordinate reference system if it's known
+    pub fn epsg(&self) -> Option<u16> {
+        self.ifd.geo_keys.as_ref().and_then(|keys| keys.epsg())
+    }
+
+    /// True unless the GeoKeys state the pixels represent points
+    pub fn pixel_is_area(&self) -> bool {
+        self.ifd.geo_keys.as_ref().and_then(|keys| keys.raster_type()) != Some(2)
+    }
+
+    /// The affine transform `[a, b, c, d, e, f]` from raster space to model space where
+    /// `x = a * i + b * j + c` and `y = d * i + e * j + f`. Built from the ModelTransformation
+    /// tag, or from the first tiepoint and the pixel scale.
+    pub fn transform(&self) -> Option<[f64; 6]> {
+        if let Some(m) = self.ifd.get_numbers(TAG_MODEL_TRANSFORMATION).filter(|m| m.len() >= 8) {
+            return Some([m[0], m[1], m[3], m[4], m[5], m[7]]);
+        }
+        let tie = self.ifd.get_numbers(TAG_MODEL_TIEPOINT).filter(|t| t.len() >= 6)?;
+        let scale = self.ifd.get_numbers(TAG_MODEL_PIXEL_SCALE).filter(|s| s.len() >= 2)?;
+        let (sx, sy) = (scale[0], scale[1]);
+        Some([sx, 0., tie[3] - tie[0] * sx, 0., -sy, tie[4] + tie[1] * sy])
+    }
+
+    /// The model coordinate of the raster origin (top left corner)
+    pub fn origin(&self) -> Option<(f64, f64)> {
+        self.transform().map(|t| (t[2], t[5]))
+    }
+
+    /// The size of a pixel in model space. Y is negative for north-up images
+    pub fn resolution(&self) -> Option<(f64, f64)> {
+        let [a, b, _, d, e, _] = self.transform()?;
+        if b == 0. && d == 0. {
+            Some((a, e))
+        } else {
+            Some((libm::sqrt(a * a + d * d), -libm::sqrt(b * b + e * e)))
+        }
+    }
+
+    /// Convert a raster coordinate into a model coordinate
+    pub fn pixel_to_model(&self, i: f64, j: f64) -> Option<(f64, f64)> {
+        let [a, b, c, d, e, f] = self.transform()?;
+        Some((a * i + b * j + c, d * i + e * j + f))
+    }
+
+    /// Convert a model coordinate into a (fractional) raster coordinate
+    pub fn model_to_pixel(&self, x: f64, y: f64) -> Option<(f64, f64)> {
+        let [a, b, c, d, e, f] = self.transform()?;
+        let det = a * e - b * d;
+        if det == 0. {
+            return None;
+        }
+        let (x, y) = (x - c, y - f);
+        Some(((e * x - b * y) / det, (a * y - d * x) / det))
+    }
+
+    /// The bounding box of the image in model space
+    pub fn bounding_box(&self) -> Option<BBox> {
+        let (w, h) = (self.width() as f64, self.height() as f64);
+        let corners = [(0., 0.), (w, 0.), (0., h), (w, h)];
+        let mut bbox: Option<BBox> = None;
+        for (i, j) in corners {
+            let (x, y) = self.pixel_to_model(i, j)?;
+            let corner = BBox::new(x, y, x, y);
+            bbox = Some(bbox.map(|b| b.merge(&corner)).unwrap_or(corner));
+        }
+        bbox
+    }
+
+    /// The height of the block (tile or strip) in row `y`. The last strip may be shorter
+    pub fn block_height(&self, y: usize) -> usize {
+        let tile_height = self.tile_height();
+        if self.is_tiled() || (y + 1) * tile_height <= self.height() {
+            tile_height
+        } else {
+            self.height() - y * tile_height
+        }
+    }
+
+    /// Read and decode a tile or strip. `sample` is only used for planar images
+    pub fn get_tile_or_strip(
+        &mut self,
+        x: usize,
+        y: usize,
+        sample: usize,
+    ) -> Result<Vec<u8>, GeoTIFFError> {
+        let (tile_width, tile_height) = (self.tile_width(), self.tile_height());
+        if tile_width == 0 || tile_height == 0 {
+            return Err(GeoTIFFError::InvalidIFD);
+        }
+        let tiles_per_row = self.width().div_ceil(tile_width);
+        let tiles_per_col = self.height().div_ceil(tile_height);
+        let planar = self.planar_configuration() == 2;
+        let mut index = y * tiles_per_row + x;
+        if planar {
+            index += sample * tiles_per_row * tiles_per_col;
+        }
+        let (offsets, counts) = if self.is_tiled() {
+            (TAG_TILE_OFFSETS, TAG_TILE_BYTE_COUNTS)
+        } else {
+            (TAG_STRIP_OFFSETS, TAG_STRIP_BYTE_COUNTS)
+        };
+        let offset = self.ifd.get_numbers(offsets).and_then(|o| o.get(index)).copied();
+        let count = self.ifd.get_numbers(counts).and_then(|c| c.get(index)).copied();
+        let (Some(offset), Some(count)) = (offset, count) else {
+            return Err(GeoTIFFError::MissingBlock(index));
+        };
+        let (offset, count) = (offset as usize, count as usize);
+        if offset + count > self.reader.len() {
+            return Err(GeoTIFFError::MissingBlock(index));
+        }
+
+        let raw = self.reader.slice(Some(offset), Some(offset + count));
+        let mut data = self.compression.decode(raw)?;
+        let predictor = self.ifd.get_number(TAG_PREDICTOR).unwrap_or(1.) as u16;
+        if predictor > 1 {
+            let spp = self.samples_per_pixel();
+            let bits = self.bits_per_sample(0);
+            if (0..spp).any(|s| self.bits_per_sample(s) != bits) {
+                return Err(GeoTIFFError::UnsupportedPredictor(predictor));
+            }
+            let stride = if planar { 1 } else { spp };
+            let height = self.block_height(y);
+            apply_predictor(
+                &mut data,
+                predictor,
+                tile_width,
+                height,
+                stride,
+                bits,
+                self.little_endian,
+            )?;
+        }
+
+        Ok(data)
+    }
+
+    /// Get a block, reusing the last decoded block if possible
+    fn get_block(&mut self, x: usize, y: usize, sample: usize) -> Result<&[u8], GeoTIFFError> {
+        let tiles_per_row = self.width().div_ceil(self.tile_width());
+        let tiles = tiles_per_row * self.height().div_ceil(self.tile_height());
+        let planar = self.planar_configuration() == 2;
+        let index = y * tiles_per_row + x + if planar { sample * tiles } else { 0 };
+        if self.cache.as_ref().map(|(i, _)| *i) != Some(index) {
+            let block = self.get_tile_or_strip(x, y, sample)?;
+            self.cache = Some((index, block));
+        }
+        Ok(&self.cache.as_ref().unwrap().1)
+    }
+
+    /// The byte offset of each sample within a pixel and the number of bytes of a pixel in a
+    /// block. Planar blocks only store a single sample
+    fn sample_layout(&self, samples: &[usize]) -> Result<(Vec<usize>, usize), GeoTIFFError> {
+        let spp = self.samples_per_pixel();
+        let mut bytes = vec![0; spp];
+        for (s, b) in bytes.iter_mut().enumerate() {
+            let bits = self.bits_per_sample(s);
+            if bits % 8 != 0 {
+                return Err(GeoTIFFError::UnsupportedSampleFormat(self.sample_format(s), bits));
+            }
+            *b = bits as usize / 8;
+        }
+        if let Some(sample) = samples.iter().find(|s| **s >= spp) {
+            return Err(GeoTIFFError::InvalidSample(*sample));
+        }
+        if self.planar_configuration() == 2 {
+            Ok((vec![0; samples.len()], 0))
+        } else {
+            let offsets = samples.iter().map(|s| bytes[..*s].iter().sum()).collect();
+            Ok((offsets, bytes.iter().sum()))
+        }
+    }
+
+    /// Read a single sample value from a decoded block
+    fn read_sample(&self, block: &[u8], offset: usize, sample: usize) -> Result<f64, GeoTIFFError> {
+        let format = self.sample_format(sample);
+        let bits = self.bits_per_sample(sample);
+        if offset + bits as usize / 8 > block.len() {
+            return Err(GeoTIFFError::InvalidBlock);
+        }
+        let mut reader = BufferReader::new(block);
+        let o = Some(offset);
+        let le = self.little_endian;
+        let value = match (format, bits) {
+            (1 | 4, 8) => reader.uint8(o) as f64,
+            (1 | 4, 16) => (if le { reader.uint16_le(o) } else { reader.uint16_be(o) }) as f64,
+            (1 | 4, 32) => (if le { reader.uint32_le(o) } else { reader.uint32_be(o) }) as f64,
+            (1 | 4, 64) => (if le { reader.uint64_le(o) } else { reader.uint64_be(o) }) as f64,
+            (2, 8) => reader.int8(o) as f64,
+            (2, 16) => (if le { reader.int16_le(o) } else { reader.int16_be(o) }) as f64,
+            (2, 32) => (if le { reader.int32_le(o) } else { reader.int32_be(o) }) as f64,
+            (2, 64) => (if le { reader.int64_le(o) } else { reader.int64_be(o) }) as f64,
+            (3, 16) => (if le { reader.f16_le(o) } else { reader.f16_be(o) }) as f64,
+            (3, 32) => (if le { reader.f32_le(o) } else { reader.f32_be(o) }) as f64,
+            (3, 64) => {
+                if le {
+                    reader.f64_le(o)
+                } else {
+                    reader.f64_be(o)
+                }
+            }
+            _ => return Err(GeoTIFFError::UnsupportedSampleFormat(format, bits)),
+        };
+        Ok(value)
+    }
+
+    /// Get the sample values of the pixel at raster coordinate (`x`, `y`). If `samples` is
+    /// `None`, all samples are returned
+    pub fn get_value(
+        &mut self,
+        x: usize,
+        y: usize,
+        samples: Option<&[usize]>,
+    ) -> Result<Vec<f64>, GeoTIFFError> {
+        if x >= self.width() || y >= self.height() {
+            return Err(GeoTIFFError::OutOfBounds);
+        }
+        let all: Vec<usize> = (0..self.samples_per_pixel()).collect();
+        let samples = samples.unwrap_or(&all);
+        let (offsets, bytes_per_pixel) = self.sample_layout(samples)?;
+        let (tile_width, tile_height) = (self.tile_width(), self.tile_height());
+        let (x_tile, y_tile) = (x / tile_width, y / tile_height);
+        let (x, y) = (x % tile_width, y % tile_height);
+        let planar = self.planar_configuration() == 2;
+
+        let mut res = Vec::with_capacity(samples.len());
+        for (sample, offset) in samples.iter().zip(offsets) {
+            let pixel_bytes =
+                if planar { self.bits_per_sample(*sample) as usize / 8 } else { bytes_per_pixel };
+            let pixel_offset = (y * tile_width + x) * pixel_bytes + offset;
+            // take the block out of the cache to read from it while borrowing self
+            self.get_block(x_tile, y_tile, *sample)?;
+            let (index, block) = self.cache.take().unwrap();
+            let value = self.read_sample(&block, pixel_offset, *sample);
+            self.cache = Some((index, block));
+            res.push(value?);
+        }
+        Ok(res)
+    }
+
+    /// Get the sample values of the pixel under the model coordinate (`x`, `y`). Returns `None`
+    /// if the coordinate is outside the image
+    pub fn get_value_at(&mut self, x: f64, y: f64) -> Result<Option<Vec<f64>>, GeoTIFFError> {
+        let (i, j) = self.model_to_pixel(x, y).ok_or(GeoTIFFError::MissingTransform)?;
+        // with "pixel is point" the model coordinates describe the pixel centers
+        let (i, j) = if self.pixel_is_area() { (floor(i), floor(j)) } else { (round(i), round(j)) };
+        if i < 0. || j < 0. || i >= self.width() as f64 || j >= self.height() as f64 {
+            return Ok(None);
+        }
+        self.get_value(i as usize, j as usize, None).map(Some)
+    }
+
+    /// Decode the whole image. If `samples` is `None`, all samples are returned
+    pub fn raster_data(&mut self, samples: Option<&[usize]>) -> Result<Raster, GeoTIFFError> {
+        let all: Vec<usize> = (0..self.samples_per_pixel()).collect();
+        let samples = samples.unwrap_or(&all);
+        let (offsets, bytes_per_pixel) = self.sample_layout(samples)?;
+        let (width, height) = (self.width(), self.height());
+        let (tile_width, tile_height) = (self.tile_width(), self.tile_height());
+        if tile_width == 0 || tile_height == 0 {
+            return Err(GeoTIFFError::InvalidIFD);
+        }
+        let planar = self.planar_configuration() == 2;
+        let num_samples = samples.len();
+        let mut data = vec![0.; width * height * num_samples];
+
+        for y_tile in 0..height.div_ceil(tile_height) {
+            for x_tile in 0..width.div_ceil(tile_width) {
+                let mut block = vec![];
+                for (si, (sample, offset)) in samples.iter().zip(offsets.iter()).enumerate() {
+                    if si == 0 || planar {
+                        block = self.get_tile_or_strip(x_tile, y_tile, *sample)?;
+                    }
+                    let pixel_bytes = if planar {
+                        self.bits_per_sample(*sample) as usize / 8
+                    } else {
+                        bytes_per_pixel
+                    };
+                    let first_line = y_tile * tile_height;
+                    let first_col = x_tile * tile_width;
+                    let y_max = self.block_height(y_tile).min(height - first_line);
+                    let x_max = tile_width.min(width - first_col);
+                    for y in 0..y_max {
+                        for x in 0..x_max {
+                            let pixel_offset = (y * tile_width + x) * pixel_bytes + offset;
+                            let value = self.read_sample(&block, pixel_offset, *sample)?;
+                            let index = ((y + first_line) * width + x + first_col) * num_samples;
+                            data[index + si] = value;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Raster { width, height, samples: num_samples, data })
+    }
+}
@@ -0,0 +1,262 @@
+/// Strip and tile decompression and predictors
+pub mod decoder;
+/// TIFF/BigTIFF header, image file directory and GeoKey parsing
+pub mod header;
+/// GeoTIFF image decoding and georeferencing
+pub mod image;
+
+pub use decoder::*;
+pub use header::*;
+pub use image::*;
+
+use crate::{readers::Reader, util::CompressError};
+
+use alloc::vec::Vec;
+
+/// Errors that can occur while reading a GeoTIFF
+#[derive(Debug, PartialEq)]
+pub enum GeoTIFFError {
+    /// The byte order mark is neither "II" nor "MM"
+    InvalidByteOrder,
+    /// The magic number is neither 42 (TIFF) nor 43 (BigTIFF)
+    InvalidMagicNumber,
+    /// BigTIFF offsets must be 8 bytes
+    UnsupportedOffsetSize,
+    /// An image file directory points outside the file, or its blocks have no pixels or samples
+    InvalidIFD,
+    /// The GeoKey directory is malformed or references missing values
+    InvalidGeoKeyDirectory,
+    /// The compression scheme isn't supported
+    UnsupportedCompression(u16),
+    /// The predictor isn't supported for the image's samples
+    UnsupportedPredictor(u16),
+    /// The (sample format, bits per sample) combination isn't supported
+    UnsupportedSampleFormat(u16, u16),
+    /// The requested sample doesn't exist
+    InvalidSample(usize),
+    /// The strip or tile at the index doesn't exist
+    MissingBlock(usize),
+    /// A decoded strip or tile is smaller than expected
+    InvalidBlock,
+    /// The image has no tiepoints, pixel scale or model transformation
+    MissingTransform,
+    /// The requested image or pixel doesn't exist
+    OutOfBounds,
+    /// A strip or tile failed to decompress
+    Compression(CompressError),
+}
+impl From<CompressError> for GeoTIFFError {
+    fn from(err: CompressError) -> Self {
+        GeoTIFFError::Compression(err)
+    }
+}
+
+/// # GeoTIFF Reader
+///
+/// ## Description
+/// Parses the image file directories of a TIFF or BigTIFF through the [`Reader`] trait. Each
+/// directory is exposed as a [`GeoTIFFImage`] that decodes its strips or tiles (uncompressed,
+/// deflate, LZW or PackBits with horizontal or floating point predictors) and reads the GeoKeys
+/// for the model transform and EPSG code.
+///
+/// NOTE: JPEG compressed images and color space conversions are not supported. Model coordinates
+/// are not reprojected.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, GeoTIFFReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/geotiff/fixtures/small.tiff");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+/// assert_eq!(reader.len(), 1);
+/// let mut image = reader.get_image(0).unwrap();
+/// let raster = image.raster_data(None).unwrap();
+/// assert_eq!((raster.width, raster.height, raster.samples), (53, 44, 15));
+/// ```
+///
+/// ## Links
+/// - <https://www.ogc.org/publications/standard/geotiff/>
+/// - <https://docs.ogc.org/is/19-008r4/19-008r4.html>
+#[derive(Debug)]
+pub struct GeoTIFFReader<T: Reader> {
+    reader: T,
+    header: GeoTIFFHeader,
+}
+impl<T: Reader> GeoTIFFReader<T> {
+    /// Create a new GeoTIFF reader, parsing the header and all image file directories
+    pub fn new(mut reader: T) -> Result<Self, GeoTIFFError> {
+        let header = GeoTIFFHeader::new(&mut reader)?;
+        Ok(Self { reader, header })
+    }
+
+    /// The parsed header
+    pub fn header(&self) -> &GeoTIFFHeader {
+        &self.header
+    }
+
+    /// The image file directories
+    pub fn image_directories(&self) -> &Vec<ImageFileDirectory> {
+        &self.header.image_directories
+    }
+
+    /// The number of images
+    pub fn len(&self) -> usize {
+        self.header.image_directories.len()
+    }
+
+    /// Returns true if there are no images
+    pub fn is_empty(&self) -> bool {
+        self.header.image_directories.is_empty()
+    }
+
+    /// Get the image at `index`. The first image is usually the full resolution one
+    pub fn get_image(&mut self, index: usize) -> Result<GeoTIFFImage<'_, T>, GeoTIFFError> {
+        let ifd = self.header.image_directories.get(index).ok_or(GeoTIFFError::OutOfBounds)?;
+        GeoTIFFImage::new(&mut self.reader, ifd, self.header.little_endian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::BBox;
+    use crate::readers::BufferReader;
+    use alloc::{string::ToString, vec};
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/geotiff/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn raster(name: &str) -> Raster {
+        let data = fixture(name);
+        let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+        let mut image = reader.get_image(0).unwrap();
+        image.raster_data(None).unwrap()
+    }
+
+    #[test]
+    fn test_header() {
+        let data = fixture("utm.tif");
+        let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.len(), 1);
+        assert!(reader.header().little_endian);
+        assert!(!reader.header().big_tiff);
+        let ifd = &reader.image_directories()[0];
+        assert_eq!(ifd.get_number(TAG_IMAGE_WIDTH), Some(100.));
+        assert_eq!(
+            ifd.get_numbers(TAG_MODEL_PIXEL_SCALE),
+            Some(&[121.52985600000001, 164.762688, 0.][..])
+        );
+        let geo_keys = ifd.geo_keys.as_ref().unwrap();
+        assert_eq!(geo_keys.model_type(), Some(1));
+        assert_eq!(geo_keys.raster_type(), Some(1));
+        assert_eq!(geo_keys.get_short(PROJ_LINEAR_UNITS_GEO_KEY), Some(9001));
+        assert_eq!(
+            geo_keys.get(GT_CITATION_GEO_KEY),
+            Some(&GeoKeyValue::Ascii("WGS 84 / UTM zone 17N".to_string()))
+        );
+
+        let mut image = reader.get_image(0).unwrap();
+        assert_eq!(image.epsg(), Some(32617));
+        assert!(!image.is_tiled());
+        assert_eq!((image.tile_width(), image.tile_height()), (100, 81));
+        assert_eq!(image.block_height(1), 19);
+        assert_eq!(image.origin(), Some((688258.223819, 4555765.966137)));
+        assert_eq!(image.resolution(), Some((121.52985600000001, -164.762688)));
+        assert_eq!(
+            image.bounding_box(),
+            Some(BBox::new(688258.223819, 4539289.697337001, 700411.209419, 4555765.966137))
+        );
+        assert_eq!(image.get_value(10, 20, None), Ok(vec![1.]));
+        let (x, y) = image.pixel_to_model(10.5, 20.5).unwrap();
+        assert_eq!(image.get_value_at(x, y), Ok(Some(vec![1.])));
+        assert_eq!(image.get_value_at(0., 0.), Ok(None));
+        assert_eq!(image.get_value(100, 0, None), Err(GeoTIFFError::OutOfBounds));
+        assert_eq!(raster("utm.tif").data.iter().sum::<f64>(), 29_700.);
+
+        assert!(reader.get_image(1).is_err());
+        assert_eq!(
+            GeoTIFFReader::new(BufferReader::from(&[0_u8; 16][..])).err(),
+            Some(GeoTIFFError::InvalidByteOrder)
+        );
+    }
+
+    #[test]
+    fn test_deflate_predictor_strips_and_tiles() {
+        let strips = raster("deflate_predictor.tiff");
+        assert_eq!((strips.width, strips.height, strips.samples), (539, 448, 15));
+        assert_eq!(strips, raster("deflate_predictor_big_strips.tiff"));
+        assert_eq!(strips, raster("deflate_predictor_tiled.tiff"));
+    }
+
+    #[test]
+    fn test_lzw() {
+        let lzw = raster("projections/epsg_27563_allgeokeys.tif");
+        assert_eq!((lzw.width, lzw.height, lzw.samples), (10, 10, 3));
+        assert_eq!(lzw, raster("projections/epsg_27563_only_pcs_code.tif"));
+
+        // big endian, tiled and georeferenced with a model transformation
+        let data = fixture("nasa_raster.tiff");
+        let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+        assert!(!reader.header().little_endian);
+        let mut image = reader.get_image(0).unwrap();
+        assert_eq!(image.epsg(), Some(4326));
+        assert_eq!(image.transform(), Some([1., 0., -180., 0., -1., 90.]));
+        assert_eq!(image.get_value(100, 50, None), Ok(vec![87.]));
+        assert_eq!(image.get_value_at(-79.5, 39.5), Ok(Some(vec![87.])));
+        let raster = image.raster_data(None).unwrap();
+        assert_eq!(raster.data.iter().sum::<f64>(), 7_312_834.);
+    }
+
+    #[test]
+    fn test_packbits() {
+        let raster = raster("GeogToWGS84GeoKey5.tif");
+        assert_eq!((raster.width, raster.height), (101, 101));
+        assert_eq!(raster.data.iter().sum::<f64>(), 18_327.);
+        assert_eq!(raster.data[50 * 101 + 50], 1.);
+    }
+
+    #[test]
+    fn test_floating_point_predictor_planar() {
+        let data = fixture("mx_inegi_ggm10.tif");
+        let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+        let mut image = reader.get_image(0).unwrap();
+        assert_eq!(image.planar_configuration(), 2);
+        assert_eq!(image.get_value(0, 0, None), Ok(vec![-38.150001525878906]));
+        assert_eq!(image.get_value(100, 100, None), Ok(vec![-37.470001220703125]));
+        assert_eq!(image.get_value(700, 400, Some(&[0])), Ok(vec![-3.319999933242798]));
+        assert_eq!(image.get_value(0, 0, Some(&[1])), Err(GeoTIFFError::InvalidSample(1)));
+        let raster = image.raster_data(None).unwrap();
+        assert_eq!(raster.data[100 * 792 + 100], -37.470001220703125);
+    }
+
+    #[test]
+    fn test_bigtiff() {
+        let data = fixture("lcv_landuse.cropland_hyde_p_10km_s0..0cm_2016_v3.2.tif");
+        let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+        assert!(reader.header().big_tiff);
+        let mut image = reader.get_image(0).unwrap();
+        assert_eq!(image.no_data(), Some(255.));
+        assert_eq!(image.epsg(), Some(4326));
+        assert_eq!((image.width(), image.height()), (4320, 1792));
+        assert_eq!(image.get_value(1961, 259, None), Ok(vec![2.]));
+        assert_eq!(image.get_value(2438, 296, None), Ok(vec![11.]));
+        assert_eq!(image.get_value(4166, 1232, None), Ok(vec![7.]));
+        assert_eq!(image.get_value(4319, 1791, None), Ok(vec![255.]));
+    }
+
+    #[test]
+    fn test_unsupported_compression() {
+        let data = fixture("jpeg.tiff");
+        let mut reader = GeoTIFFReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.get_image(0).err(), Some(GeoTIFFError::UnsupportedCompression(7)));
+    }
+}
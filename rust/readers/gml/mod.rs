@@ -0,0 +1,573 @@
+use super::{
+    csv::infer_value,
+    kml::{group_shapes, Shape},
+};
+use crate::{
+    geometry::{VectorFeature, VectorPoint},
+    readers::{FeatureIterator, Reader, XMLError, XMLEvent, XMLNode, XMLReader},
+};
+use alloc::{collections::VecDeque, string::String, vec, vec::Vec};
+use s2json::{PrimitiveValue, Properties, ValuePrimitiveType, ValueType};
+
+/// Geographic (and projected) CRSs whose EPSG axis order is northing first. They're swapped to
+/// x/y order when referenced by URN or OGC URI
+const NORTHING_FIRST: [u32; 12] =
+    [3034, 3035, 4171, 4258, 4267, 4269, 4283, 4326, 4612, 4674, 4937, 4979];
+
+/// Errors that can occur while reading a GML file
+#[derive(Debug, PartialEq)]
+pub enum GMLError {
+    /// The document isn't well-formed XML
+    XML(XMLError),
+    /// The document has no root element
+    MissingRoot,
+}
+impl From<XMLError> for GMLError {
+    fn from(err: XMLError) -> Self {
+        GMLError::XML(err)
+    }
+}
+
+/// Find the EPSG code of a `srsName`, e.g. `EPSG:4326`, `urn:ogc:def:crs:EPSG::4326`,
+/// `http://www.opengis.net/def/crs/EPSG/0/4326` or
+/// `http://www.opengis.net/gml/srs/epsg.xml#4326`
+pub fn gml_srs_epsg(srs_name: &str) -> Option<u32> {
+    let lower = srs_name.trim().to_lowercase();
+    if !lower.contains("epsg") {
+        return None;
+    }
+    let code = lower.rsplit([':', '/', '#']).next()?;
+    code.parse().ok()
+}
+
+/// Whether the coordinates of the `srsName` are in northing/easting (lat/lon) order. Only the
+/// URN and OGC URI forms follow the EPSG axis order, `EPSG:4326` is treated as lon/lat
+pub fn gml_srs_is_northing_first(srs_name: &str) -> bool {
+    let srs_name = srs_name.trim();
+    let is_uri = srs_name.starts_with("urn:") || srs_name.contains("opengis.net/def/crs");
+    is_uri && gml_srs_epsg(srs_name).is_some_and(|code| NORTHING_FIRST.contains(&code))
+}
+
+/// How to read the coordinates of a geometry, inherited by its children
+#[derive(Debug, Clone, Copy)]
+struct Coordinates {
+    dimension: usize,
+    swap: bool,
+}
+impl Coordinates {
+    /// Update from the `srsName` and `srsDimension` of a geometry element
+    fn update(mut self, node: &XMLNode) -> Self {
+        if let Some(srs_name) = node.attribute("srsName") {
+            self.swap = gml_srs_is_northing_first(srs_name);
+        }
+        if let Some(dimension) = node.attribute("srsDimension").and_then(|d| d.parse().ok()) {
+            self.dimension = dimension;
+        }
+        self
+    }
+
+    /// Build a point from its ordinates
+    fn point(&self, ordinates: &[f64]) -> Option<VectorPoint> {
+        let (mut x, mut y) = (*ordinates.first()?, *ordinates.get(1)?);
+        if self.swap {
+            (x, y) = (y, x);
+        }
+        Some(VectorPoint::new(x, y, ordinates.get(2).copied(), None))
+    }
+}
+impl Default for Coordinates {
+    fn default() -> Self {
+        Coordinates { dimension: 2, swap: false }
+    }
+}
+
+/// # GML Reader
+///
+/// ## Description
+/// Reads GML 2, 3.1 and 3.2 simple feature documents, such as WFS responses and INSPIRE
+/// datasets, through the [`Reader`] trait, streamed with the [`XMLReader`].
+///
+/// Implements the [`FeatureIterator`], yielding each feature of the `featureMember`,
+/// `featureMembers` and (WFS 2.0) `member` elements in document order.
+/// - The first property holding a GML geometry becomes the feature's geometry. `Point`,
+///   `LineString`, `LinearRing`, `Curve`, `Polygon`, `Surface`, `MultiPoint`, `MultiLineString`,
+///   `MultiCurve`, `MultiPolygon`, `MultiSurface`, `MultiGeometry` and their composites are
+///   supported. A `MultiGeometry` mixing types yields one feature per type
+/// - Coordinates can be encoded as `posList`, `pos`, `coordinates` or `coord`. `srsDimension`
+///   3 sets the `z` of each point
+/// - The other properties are flattened into the feature's properties: nested elements use
+///   dotted keys (e.g. `address.street`) and repeated elements become arrays. Integers,
+///   decimals and booleans are converted, and an empty element with an `xlink:href` stores the
+///   link. The `gml:id` of the feature is stored as `gml_id`
+///
+/// If the document is malformed, iteration stops and the error is stored in `error`.
+///
+/// NOTE: Coordinates are always returned in x/y (lon/lat) order. A `srsName` written as a URN
+/// or OGC URI for a CRS whose EPSG axis order is northing first (such as EPSG:4326 or
+/// EPSG:4258) is swapped. No reprojection is done, check [`GMLReader::srs_name`].
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, FeatureIterator, GMLReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/gml/fixtures/wfs2.gml");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = GMLReader::new(BufferReader::from(&data[..])).unwrap();
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://www.ogc.org/standard/gml/>
+/// - <https://docs.ogc.org/is/09-025r2/09-025r2.html>
+#[derive(Debug)]
+pub struct GMLReader<T: Reader> {
+    xml: XMLReader<T>,
+    /// The `srsName` of the collection's bounds, or else of the first geometry read
+    pub srs_name: Option<String>,
+    /// The error that stopped iteration if the document is malformed
+    pub error: Option<GMLError>,
+    in_members: bool,
+    features: VecDeque<VectorFeature>,
+}
+impl<T: Reader> GMLReader<T> {
+    /// Create a new GML reader
+    pub fn new(reader: T) -> Result<Self, GMLError> {
+        let mut xml = XMLReader::new(reader);
+        let srs_name = loop {
+            match xml.next_event()? {
+                XMLEvent::Start(root) => break root.attribute("srsName").map(String::from),
+                XMLEvent::Eof => return Err(GMLError::MissingRoot),
+                _ => {}
+            }
+        };
+
+        Ok(GMLReader { xml, srs_name, error: None, in_members: false, features: VecDeque::new() })
+    }
+
+    /// The EPSG code of the [`GMLReader::srs_name`] if known
+    pub fn epsg(&self) -> Option<u32> {
+        self.srs_name.as_deref().and_then(gml_srs_epsg)
+    }
+
+    /// Read the next feature element. Returns `None` at the end of the document
+    fn read_feature(&mut self) -> Result<Option<XMLNode>, XMLError> {
+        loop {
+            if self.in_members {
+                match self.xml.read_node()? {
+                    Some(node) => return Ok(Some(node)),
+                    None => self.in_members = false,
+                }
+                continue;
+            }
+            let mut node = match self.xml.next_event()? {
+                XMLEvent::Start(start) => match start.local_name() {
+                    "featureMember" | "featureMembers" | "member" => {
+                        self.in_members = true;
+                        continue;
+                    }
+                    "boundedBy" => start.to_node(),
+                    _ => {
+                        self.xml.skip_element()?;
+                        continue;
+                    }
+                },
+                XMLEvent::Eof => return Ok(None),
+                _ => continue,
+            };
+            self.xml.read_children(&mut node)?;
+            if self.srs_name.is_none() {
+                self.srs_name =
+                    node.children.iter().find_map(|c| c.attribute("srsName")).map(String::from);
+            }
+        }
+    }
+
+    /// Build the features of a feature element
+    fn add_feature(&mut self, node: &XMLNode) {
+        let mut properties = Properties::new();
+        if let Some(id) = node.attribute("gml:id").or(node.attribute("fid")) {
+            properties
+                .insert("gml_id".into(), ValueType::Primitive(PrimitiveValue::String(id.into())));
+        }
+        let mut geometry = None;
+        add_properties(node, "", &mut properties, &mut geometry);
+
+        let mut shapes = vec![];
+        if let Some(geometry) = geometry {
+            if self.srs_name.is_none() {
+                self.srs_name = geometry.attribute("srsName").map(String::from);
+            }
+            let coordinates = self.srs_name.as_deref().map_or_else(Coordinates::default, |srs| {
+                Coordinates { swap: gml_srs_is_northing_first(srs), ..Default::default() }
+            });
+            add_shapes(geometry, coordinates, &mut shapes);
+        }
+        let geometries = group_shapes(shapes);
+        if geometries.is_empty() {
+            // features without a geometry are skipped
+            return;
+        }
+        for geometry in geometries {
+            let feature = VectorFeature::new_wm(None, properties.clone(), geometry, None);
+            self.features.push_back(feature);
+        }
+    }
+}
+
+impl<T: Reader> Iterator for GMLReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.features.is_empty() {
+            if self.error.is_some() {
+                return None;
+            }
+            match self.read_feature() {
+                Ok(node) => self.add_feature(&node?),
+                Err(err) => self.error = Some(err.into()),
+            }
+        }
+        self.features.pop_front()
+    }
+}
+impl<T: Reader> FeatureIterator for GMLReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Whether the element is a GML geometry
+fn is_geometry(node: &XMLNode) -> bool {
+    node.namespace.as_deref().is_some_and(|ns| ns.starts_with("http://www.opengis.net/gml"))
+        && matches!(
+            node.local_name(),
+            "Point"
+                | "LineString"
+                | "LinearRing"
+                | "Curve"
+                | "CompositeCurve"
+                | "Polygon"
+                | "Surface"
+                | "CompositeSurface"
+                | "MultiPoint"
+                | "MultiLineString"
+                | "MultiCurve"
+                | "MultiPolygon"
+                | "MultiSurface"
+                | "MultiGeometry"
+        )
+}
+
+/// Flatten the properties of a feature (or of a nested property) and find its geometry
+fn add_properties<'a>(
+    node: &'a XMLNode,
+    prefix: &str,
+    properties: &mut Properties,
+    geometry: &mut Option<&'a XMLNode>,
+) {
+    for child in &node.children {
+        if child.local_name() == "boundedBy" {
+            continue;
+        }
+        if let Some(shape) = child.children.iter().find(|c| is_geometry(c)) {
+            geometry.get_or_insert(shape);
+            continue;
+        }
+        let key = [prefix, child.local_name()].concat();
+        if !child.children.is_empty() {
+            add_properties(child, &[&key, "."].concat(), properties, geometry);
+            continue;
+        }
+        let text = child.text.trim();
+        let value = match child.attribute("xlink:href") {
+            Some(href) if text.is_empty() => PrimitiveValue::String(href.into()),
+            _ => infer_value(text),
+        };
+        match properties.get_mut(&key) {
+            Some(ValueType::Array(values)) => values.push(ValuePrimitiveType::Primitive(value)),
+            Some(ValueType::Primitive(first)) => {
+                let first = ValuePrimitiveType::Primitive(first.clone());
+                properties.insert(
+                    key,
+                    ValueType::Array(vec![first, ValuePrimitiveType::Primitive(value)]),
+                );
+            }
+            _ => {
+                properties.insert(key, ValueType::Primitive(value));
+            }
+        }
+    }
+}
+
+/// Collect the geometries of a geometry element, descending into its members
+fn add_shapes(node: &XMLNode, coordinates: Coordinates, shapes: &mut Vec<Shape>) {
+    let coordinates = coordinates.update(node);
+    match node.local_name() {
+        "Point" => {
+            if let Some(point) = positions(node, coordinates).into_iter().next() {
+                shapes.push(Shape::Point(point));
+            }
+        }
+        "LineString" | "LinearRing" | "Curve" => {
+            let line = line(node, coordinates);
+            if !line.is_empty() {
+                shapes.push(Shape::Line(line));
+            }
+        }
+        "Polygon" | "PolygonPatch" => {
+            let outer = node
+                .children
+                .iter()
+                .find(|c| matches!(c.local_name(), "exterior" | "outerBoundaryIs"));
+            let Some(outer) = outer.and_then(|o| ring(o, coordinates)) else {
+                return;
+            };
+            let mut polygon = vec![outer];
+            let inner = node
+                .children
+                .iter()
+                .filter(|c| matches!(c.local_name(), "interior" | "innerBoundaryIs"));
+            polygon.extend(inner.filter_map(|i| ring(i, coordinates)));
+            shapes.push(Shape::Polygon(polygon));
+        }
+        "Surface" => {
+            for patch in node.children("patches").flat_map(|p| &p.children) {
+                add_shapes(patch, coordinates, shapes);
+            }
+        }
+        _ => {
+            // multi geometries and composites: `*Member` holds one geometry, `*Members` many
+            for member in &node.children {
+                let count = if member.local_name().ends_with("Members") { usize::MAX } else { 1 };
+                for geometry in member.children.iter().take(count) {
+                    add_shapes(geometry, coordinates, shapes);
+                }
+            }
+        }
+    }
+}
+
+/// The points of a `LineString`, `LinearRing` or `Curve` (joining its segments)
+fn line(node: &XMLNode, coordinates: Coordinates) -> Vec<VectorPoint> {
+    if node.local_name() != "Curve" {
+        return positions(node, coordinates.update(node));
+    }
+    let mut line: Vec<VectorPoint> = vec![];
+    for segment in node.children("segments").flat_map(|s| &s.children) {
+        let mut points = positions(segment, coordinates.update(segment)).into_iter();
+        // segments share their end points
+        if let (Some(last), Some(first)) = (line.last(), points.clone().next()) {
+            if *last == first {
+                points.next();
+            }
+        }
+        line.extend(points);
+    }
+    line
+}
+
+/// The points of a polygon boundary: a `LinearRing` or a `Ring` of curves
+fn ring(boundary: &XMLNode, coordinates: Coordinates) -> Option<Vec<VectorPoint>> {
+    let ring = boundary.children.first()?;
+    let coordinates = coordinates.update(ring);
+    let points = match ring.local_name() {
+        "Ring" => {
+            let mut points: Vec<VectorPoint> = vec![];
+            for curve in ring.children("curveMember").flat_map(|m| m.children.first()) {
+                let mut line = line(curve, coordinates.update(curve)).into_iter().peekable();
+                if points.last().is_some_and(|last| line.peek() == Some(last)) {
+                    line.next();
+                }
+                points.extend(line);
+            }
+            points
+        }
+        _ => positions(ring, coordinates),
+    };
+    (!points.is_empty()).then_some(points)
+}
+
+/// Parse the `posList`, `pos`, `coordinates` or `coord` children of a geometry
+fn positions(node: &XMLNode, coordinates: Coordinates) -> Vec<VectorPoint> {
+    let numbers = |text: &str| -> Vec<f64> {
+        text.split_whitespace().filter_map(|v| v.parse().ok()).collect()
+    };
+    if let Some(list) = node.child("posList") {
+        let coordinates = coordinates.update(list);
+        let dimension = coordinates.dimension.max(2);
+        let ordinates = numbers(&list.text);
+        return ordinates.chunks_exact(dimension).filter_map(|o| coordinates.point(o)).collect();
+    }
+    if let Some(tuples) = node.child("coordinates") {
+        return gml2_coordinates(tuples, coordinates);
+    }
+    let pos = node.children("pos").filter_map(|p| coordinates.point(&numbers(&p.text)));
+    let coord = node.children("coord").filter_map(|c| {
+        let ordinates: Vec<f64> = ["X", "Y", "Z"]
+            .into_iter()
+            .map_while(|axis| c.child_text(axis).and_then(|v| v.parse().ok()))
+            .collect();
+        coordinates.point(&ordinates)
+    });
+    pos.chain(coord).collect()
+}
+
+/// Parse a GML 2 `<coordinates>` element, respecting its `cs`, `ts` and `decimal` separators
+fn gml2_coordinates(node: &XMLNode, coordinates: Coordinates) -> Vec<VectorPoint> {
+    let cs = node.attribute("cs").unwrap_or(",");
+    let ts = node.attribute("ts").unwrap_or(" ");
+    let decimal = node.attribute("decimal").unwrap_or(".");
+    let text = match decimal {
+        "." => node.text.trim().into(),
+        _ => node.text.trim().replace(decimal, "."),
+    };
+    let tuples: Vec<&str> = if ts.trim().is_empty() {
+        text.split_whitespace().collect()
+    } else {
+        text.split(ts).collect()
+    };
+    tuples
+        .into_iter()
+        .filter_map(|tuple| {
+            let ordinates: Vec<f64> =
+                tuple.split(cs).map_while(|v| v.trim().parse().ok()).collect();
+            coordinates.point(&ordinates)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::{VectorGeometry, VectorGeometryType, VectorPointGeometry},
+        readers::BufferReader,
+    };
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/gml/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn primitive(properties: &Properties, key: &str) -> PrimitiveValue {
+        match properties.get(key) {
+            Some(ValueType::Primitive(value)) => value.clone(),
+            _ => panic!("missing {key}"),
+        }
+    }
+
+    #[test]
+    fn test_srs_name() {
+        assert_eq!(gml_srs_epsg("EPSG:4326"), Some(4326));
+        assert_eq!(gml_srs_epsg("urn:ogc:def:crs:EPSG::25832"), Some(25832));
+        assert_eq!(gml_srs_epsg("http://www.opengis.net/def/crs/EPSG/0/3857"), Some(3857));
+        assert_eq!(gml_srs_epsg("http://www.opengis.net/gml/srs/epsg.xml#4258"), Some(4258));
+        assert_eq!(gml_srs_epsg("urn:ogc:def:crs:OGC:1.3:CRS84"), None);
+        assert!(gml_srs_is_northing_first("urn:ogc:def:crs:EPSG::4326"));
+        assert!(gml_srs_is_northing_first("http://www.opengis.net/def/crs/EPSG/0/4258"));
+        assert!(!gml_srs_is_northing_first("EPSG:4326"));
+        assert!(!gml_srs_is_northing_first("urn:ogc:def:crs:EPSG::3857"));
+        assert!(GMLReader::new(BufferReader::from(&b"<!-- empty -->"[..])).is_err());
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let data =
+            b"<FeatureCollection><featureMember><Parcel></featureMember></FeatureCollection>";
+        let mut reader = GMLReader::new(BufferReader::from(&data[..])).unwrap();
+        assert!(reader.next().is_none());
+        assert_eq!(reader.error, Some(GMLError::XML(XMLError::MismatchedEndTag(42))));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_wfs2() {
+        let data = fixture("wfs2.gml");
+        let mut reader = GMLReader::new(BufferReader::from(&data[..])).unwrap();
+        let city = reader.next().unwrap();
+        assert_eq!(reader.srs_name.as_deref(), Some("urn:ogc:def:crs:EPSG::4326"));
+        assert_eq!(reader.epsg(), Some(4326));
+
+        let props = &city.properties;
+        assert_eq!(primitive(props, "gml_id"), PrimitiveValue::String("city.1".into()));
+        assert_eq!(primitive(props, "name"), PrimitiveValue::String("Berlin".into()));
+        assert_eq!(primitive(props, "population"), PrimitiveValue::I64(3645000));
+        assert_eq!(primitive(props, "area"), PrimitiveValue::F64(891.7));
+        assert_eq!(primitive(props, "capital"), PrimitiveValue::Bool(true));
+        assert_eq!(primitive(props, "postcode"), PrimitiveValue::String("01067".into()));
+        assert_eq!(primitive(props, "address.number"), PrimitiveValue::I64(1));
+        assert_eq!(
+            primitive(props, "address.street"),
+            PrimitiveValue::String("Unter den Linden".into())
+        );
+        assert_eq!(
+            props.get("tag"),
+            Some(&ValueType::Array(vec![
+                ValuePrimitiveType::Primitive(PrimitiveValue::String("museum".into())),
+                ValuePrimitiveType::Primitive(PrimitiveValue::String("river".into())),
+            ]))
+        );
+        assert_eq!(primitive(props, "website"), PrimitiveValue::String("https://berlin.de".into()));
+        assert!(!props.contains_key("location"));
+        assert_eq!(
+            city.geometry,
+            VectorGeometry::Point(VectorPointGeometry {
+                _type: VectorGeometryType::Point,
+                coordinates: VectorPoint::new(13.405, 52.52, None, None),
+                ..Default::default()
+            })
+        );
+
+        let lake = reader.next().unwrap();
+        let VectorGeometry::MultiPolygon(lake) = &lake.geometry else { panic!("not polygons") };
+        assert_eq!(lake.coordinates.len(), 2);
+        assert_eq!(lake.coordinates[0].len(), 2);
+        assert_eq!(lake.coordinates[0][0][1], VectorPoint::new(11., 50., None, None));
+        assert_eq!(lake.coordinates[1][0].len(), 4);
+
+        let road = reader.next().unwrap();
+        let VectorGeometry::LineString(road) = &road.geometry else { panic!("not a line") };
+        assert!(road.is_3d);
+        assert_eq!(
+            road.coordinates,
+            vec![
+                VectorPoint::new(100., 200., Some(1.), None),
+                VectorPoint::new(110., 210., Some(2.), None),
+                VectorPoint::new(120., 220., Some(3.), None),
+            ]
+        );
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_gml2() {
+        let data = fixture("gml2.gml");
+        let mut reader = GMLReader::new(BufferReader::from(&data[..])).unwrap();
+        let parcel = reader.next().unwrap();
+        assert_eq!(reader.srs_name.as_deref(), Some("EPSG:4326"));
+        assert_eq!(
+            primitive(&parcel.properties, "gml_id"),
+            PrimitiveValue::String("parcels.0".into())
+        );
+        assert_eq!(primitive(&parcel.properties, "owner"), PrimitiveValue::String("Smith".into()));
+        let VectorGeometry::Polygon(polygon) = &parcel.geometry else { panic!("not a polygon") };
+        assert_eq!(polygon.coordinates.len(), 2);
+        assert_eq!(polygon.coordinates[0][3], VectorPoint::new(0.5, 10., None, None));
+        assert_eq!(polygon.coordinates[1][1], VectorPoint::new(4., 2., None, None));
+
+        let points = reader.next().unwrap();
+        let VectorGeometry::MultiPoint(points) = &points.geometry else { panic!("not points") };
+        assert_eq!(
+            points.coordinates,
+            vec![VectorPoint::new(1., 2., None, None), VectorPoint::new(3., 4., None, None)]
+        );
+        // the feature without a geometry is skipped
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,625 @@
+use crate::{
+    geometry::{
+        VectorFeature, VectorGeometry, VectorGeometryType, VectorLineStringGeometry,
+        VectorMultiLineStringGeometry, VectorPoint, VectorPointGeometry,
+    },
+    readers::{FeatureIterator, Reader, XMLError, XMLEvent, XMLNode, XMLReader},
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use s2json::{MValue, PrimitiveValue, Properties, ValuePrimitiveType, ValueType};
+
+/// Errors that can occur while reading a GPX file
+#[derive(Debug, PartialEq)]
+pub enum GPXError {
+    /// The document isn't well-formed XML
+    XML(XMLError),
+    /// The root element isn't `<gpx>`
+    MissingRoot,
+}
+
+/// Defines the bounding box of the GPX data
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GPXBounds {
+    /// Minimum latitude
+    pub minlat: f64,
+    /// Minimum longitude
+    pub minlon: f64,
+    /// Maximum latitude
+    pub maxlat: f64,
+    /// Maximum longitude
+    pub maxlon: f64,
+}
+
+/// Represents a hyperlink with optional text and MIME type
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GPXLink {
+    /// URL of the link
+    pub href: String,
+    /// Optional hyperlink text
+    pub text: Option<String>,
+    /// MIME type of the linked content
+    pub link_type: Option<String>,
+}
+
+/// Represents an email address, split into ID and domain parts
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GPXEmail {
+    /// Local part of the email address
+    pub id: String,
+    /// Domain part of the email address
+    pub domain: String,
+}
+
+/// Defines a person or organization associated with the GPX file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GPXPerson {
+    /// Name of the person or organization
+    pub name: Option<String>,
+    /// Email address
+    pub email: Option<GPXEmail>,
+    /// Link to external information about the person
+    pub link: Option<GPXLink>,
+}
+
+/// Defines copyright and license information
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GPXCopyright {
+    /// Copyright holder
+    pub author: String,
+    /// Year of copyright
+    pub year: Option<String>,
+    /// License URL
+    pub license: Option<String>,
+}
+
+/// Contains metadata information about the GPX file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GPXMetadata {
+    /// Name of the GPX file
+    pub name: Option<String>,
+    /// Description of the file's contents
+    pub desc: Option<String>,
+    /// Person or organization responsible for the file
+    pub author: Option<GPXPerson>,
+    /// Copyright and license information
+    pub copyright: Option<GPXCopyright>,
+    /// URLs associated with the GPX file
+    pub link: Vec<GPXLink>,
+    /// Creation timestamp in ISO 8601 format
+    pub time: Option<String>,
+    /// Keywords for classification
+    pub keywords: Option<String>,
+    /// Bounding box of the data
+    pub bounds: Option<GPXBounds>,
+}
+
+/// # GPX Reader
+///
+/// ## Description
+/// Reads GPX 1.0 and 1.1 documents (the GPS Exchange Format) through the [`Reader`] trait. The
+/// document is streamed with the [`XMLReader`]: the metadata is parsed up front and stored in
+/// [`GPXReader::metadata`], then each waypoint, route and track is read as it's iterated.
+///
+/// Implements the [`FeatureIterator`], yielding in document order:
+/// - waypoints (`<wpt>`) as Point features, the waypoint's values stored in the properties
+/// - routes (`<rte>`) as LineString features
+/// - tracks (`<trk>`) as MultiLineString features, one line per track segment
+///
+/// Coordinates are WGS84 longitude and latitude. Elevation (`<ele>`) becomes `z` and the
+/// geometry is 3D if any vertex has an elevation. The values of each route and track vertex
+/// (`time`, `hdop`, `sat`, `extensions`, etc.) are stored in the vertex's M-value. Extensions are
+/// nested objects keyed by their local names; numeric text becomes a number.
+///
+/// If the document is malformed, iteration stops and the error is stored in `error`.
+///
+/// NOTE: GPX 1.0 `<url>` and `<urlname>` pairs are read as links.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, FeatureIterator, GPXReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/gpx/fixtures/gpx-test-short.gpx");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = GPXReader::new(BufferReader::from(&data[..])).unwrap();
+/// assert_eq!(reader.metadata.name, Some("GPX DEMO".into()));
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://www.topografix.com/gpx.asp>
+/// - <https://www.topografix.com/gpx_manual.asp>
+#[derive(Debug)]
+pub struct GPXReader<T: Reader> {
+    xml: XMLReader<T>,
+    /// The GPX version, e.g. "1.1"
+    pub version: String,
+    /// Name or URL of the software that created the document
+    pub creator: Option<String>,
+    /// The metadata of the document
+    pub metadata: GPXMetadata,
+    /// The error that stopped iteration if the document is malformed
+    pub error: Option<GPXError>,
+    /// The first waypoint, route or track, read while looking for the metadata
+    pending: Option<XMLNode>,
+}
+impl<T: Reader> GPXReader<T> {
+    /// Create a new GPX reader, parsing the root element and the metadata
+    pub fn new(reader: T) -> Result<Self, GPXError> {
+        let mut xml = XMLReader::new(reader);
+        let (version, creator) = loop {
+            match xml.next_event().map_err(GPXError::XML)? {
+                XMLEvent::Start(root) if root.local_name() == "gpx" => {
+                    let version = root.attribute("version").unwrap_or("1.1".into()).into();
+                    break (version, root.attribute("creator").map(String::from));
+                }
+                XMLEvent::Start(_) | XMLEvent::Eof => return Err(GPXError::MissingRoot),
+                _ => {}
+            }
+        };
+        // GPX 1.0 stores the metadata directly in the root
+        let mut header = XMLNode::default();
+        let mut pending = None;
+        while let Some(node) = xml.read_node().map_err(GPXError::XML)? {
+            match node.local_name() {
+                "wpt" | "rte" | "trk" => {
+                    pending = Some(node);
+                    break;
+                }
+                "metadata" => header = node,
+                _ => header.children.push(node),
+            }
+        }
+        let metadata = parse_metadata(&header);
+
+        Ok(GPXReader { xml, version, creator, metadata, error: None, pending })
+    }
+
+    /// Build the feature of a waypoint, route or track
+    fn build_feature(&self, element: &XMLNode) -> Option<VectorFeature<GPXMetadata>> {
+        let (properties, geometry) = match element.local_name() {
+            "wpt" => {
+                let point = parse_waypoint(element);
+                let is_3d = point.z.is_some();
+                let geometry = VectorGeometry::Point(VectorPointGeometry {
+                    _type: VectorGeometryType::Point,
+                    is_3d,
+                    coordinates: VectorPoint::new(point.x, point.y, point.z, None),
+                    ..Default::default()
+                });
+                (point.m.unwrap_or_default(), geometry)
+            }
+            "rte" => {
+                let line: Vec<VectorPoint> =
+                    element.children("rtept").map(parse_waypoint).collect();
+                if line.is_empty() {
+                    return None;
+                }
+                let geometry = VectorGeometry::LineString(VectorLineStringGeometry {
+                    _type: VectorGeometryType::LineString,
+                    is_3d: line.iter().any(|p| p.z.is_some()),
+                    coordinates: line,
+                    ..Default::default()
+                });
+                (parse_path_properties(element), geometry)
+            }
+            "trk" => {
+                let lines: Vec<Vec<VectorPoint>> = element
+                    .children("trkseg")
+                    .map(|segment| segment.children("trkpt").map(parse_waypoint).collect())
+                    .filter(|line: &Vec<VectorPoint>| !line.is_empty())
+                    .collect();
+                if lines.is_empty() {
+                    return None;
+                }
+                let geometry = VectorGeometry::MultiLineString(VectorMultiLineStringGeometry {
+                    _type: VectorGeometryType::MultiLineString,
+                    is_3d: lines.iter().flatten().any(|p| p.z.is_some()),
+                    coordinates: lines,
+                    ..Default::default()
+                });
+                (parse_path_properties(element), geometry)
+            }
+            _ => return None,
+        };
+        Some(VectorFeature::new_wm(None, properties, geometry, Some(self.metadata.clone())))
+    }
+}
+
+impl<T: Reader> Iterator for GPXReader<T> {
+    type Item = VectorFeature<GPXMetadata>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.error.is_none() {
+            let element = match self.pending.take() {
+                Some(element) => element,
+                None => match self.xml.read_node() {
+                    Ok(node) => node?,
+                    Err(err) => {
+                        self.error = Some(GPXError::XML(err));
+                        return None;
+                    }
+                },
+            };
+            if let Some(feature) = self.build_feature(&element) {
+                return Some(feature);
+            }
+        }
+        None
+    }
+}
+impl<T: Reader> FeatureIterator<GPXMetadata> for GPXReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature<GPXMetadata>> {
+        self.next()
+    }
+}
+
+/// Parse the metadata of a GPX 1.1 `<metadata>` element or a GPX 1.0 root
+fn parse_metadata(element: &XMLNode) -> GPXMetadata {
+    GPXMetadata {
+        name: text(element, "name"),
+        desc: text(element, "desc"),
+        author: element.child("author").map(|author| match author.children.is_empty() {
+            // GPX 1.0 stores the author's name and email in the root
+            true => GPXPerson {
+                name: Some(author.text.trim().into()),
+                email: text(element, "email").and_then(|email| parse_email_address(&email)),
+                link: None,
+            },
+            false => parse_person(author),
+        }),
+        copyright: element.child("copyright").map(|copyright| GPXCopyright {
+            author: copyright.attribute("author").unwrap_or_default().into(),
+            year: text(copyright, "year"),
+            license: text(copyright, "license"),
+        }),
+        link: parse_links(element),
+        time: text(element, "time"),
+        keywords: text(element, "keywords"),
+        bounds: element.child("bounds").and_then(|bounds| {
+            let attribute = |name| bounds.attribute(name)?.trim().parse().ok();
+            Some(GPXBounds {
+                minlat: attribute("minlat")?,
+                minlon: attribute("minlon")?,
+                maxlat: attribute("maxlat")?,
+                maxlon: attribute("maxlon")?,
+            })
+        }),
+    }
+}
+
+/// Parse a `<author>` person
+fn parse_person(element: &XMLNode) -> GPXPerson {
+    GPXPerson {
+        name: text(element, "name"),
+        email: element.child("email").map(|email| GPXEmail {
+            id: email.attribute("id").unwrap_or_default().into(),
+            domain: email.attribute("domain").unwrap_or_default().into(),
+        }),
+        link: element.child("link").map(parse_link),
+    }
+}
+
+/// Split a GPX 1.0 email address into its ID and domain
+fn parse_email_address(email: &str) -> Option<GPXEmail> {
+    let (id, domain) = email.split_once('@')?;
+    Some(GPXEmail { id: id.into(), domain: domain.into() })
+}
+
+/// Parse a GPX 1.1 `<link>`
+fn parse_link(element: &XMLNode) -> GPXLink {
+    GPXLink {
+        href: element.attribute("href").unwrap_or_default().into(),
+        text: text(element, "text"),
+        link_type: text(element, "type"),
+    }
+}
+
+/// Parse the GPX 1.1 `<link>` children or the GPX 1.0 `<url>` and `<urlname>` pair
+fn parse_links(element: &XMLNode) -> Vec<GPXLink> {
+    let mut links: Vec<GPXLink> = element.children("link").map(parse_link).collect();
+    if let Some(url) = text(element, "url") {
+        links.push(GPXLink { href: url, text: text(element, "urlname"), link_type: None });
+    }
+    links
+}
+
+/// The trimmed text of a child element if it exists
+fn text(element: &XMLNode, name: &str) -> Option<String> {
+    element.child_text(name).map(String::from)
+}
+
+/// Parse a waypoint (`<wpt>`, `<rtept>` or `<trkpt>`) into a point storing its values in the
+/// M-value
+fn parse_waypoint(element: &XMLNode) -> VectorPoint {
+    let coordinate = |name| element.attribute(name).and_then(|v| v.trim().parse().ok());
+    let x = coordinate("lon").unwrap_or_default();
+    let y = coordinate("lat").unwrap_or_default();
+    let z = element.child_text("ele").and_then(|ele| ele.parse().ok());
+    let mut m = MValue::new();
+    for child in &element.children {
+        let key = child.local_name();
+        let value = child.text.trim();
+        match key {
+            "time" | "name" | "cmt" | "desc" | "src" | "sym" | "type" | "fix" => {
+                m.insert(key.into(), string_value(value));
+            }
+            "magvar" | "geoidheight" | "hdop" | "vdop" | "pdop" | "ageofdgpsdata" | "course"
+            | "speed" => {
+                if let Ok(value) = value.parse() {
+                    m.insert(key.into(), ValueType::Primitive(PrimitiveValue::F64(value)));
+                }
+            }
+            "sat" | "dgpsid" => {
+                if let Ok(value) = value.parse() {
+                    m.insert(key.into(), ValueType::Primitive(PrimitiveValue::U64(value)));
+                }
+            }
+            "extensions" => {
+                m.insert(key.into(), ValueType::Nested(parse_extensions(child)));
+            }
+            _ => {}
+        }
+    }
+    insert_links(&mut m, element);
+    VectorPoint::new(x, y, z, Some(m))
+}
+
+/// Parse the properties of a route or track
+fn parse_path_properties(element: &XMLNode) -> Properties {
+    let mut properties = Properties::new();
+    for key in ["name", "cmt", "desc", "src", "type"] {
+        if let Some(value) = element.child_text(key) {
+            properties.insert(key.into(), string_value(value));
+        }
+    }
+    if let Some(number) = element.child_text("number").and_then(|n| n.parse().ok()) {
+        properties.insert("number".into(), ValueType::Primitive(PrimitiveValue::U64(number)));
+    }
+    insert_links(&mut properties, element);
+    properties
+}
+
+/// Store the links of an element as an array of `{ href, text, type }` objects
+fn insert_links(value: &mut MValue, element: &XMLNode) {
+    let links = parse_links(element);
+    if links.is_empty() {
+        return;
+    }
+    let links = links
+        .into_iter()
+        .map(|link| {
+            let mut object = BTreeMap::new();
+            object.insert("href".into(), PrimitiveValue::String(link.href));
+            if let Some(text) = link.text {
+                object.insert("text".into(), PrimitiveValue::String(text));
+            }
+            if let Some(link_type) = link.link_type {
+                object.insert("type".into(), PrimitiveValue::String(link_type));
+            }
+            ValuePrimitiveType::NestedPrimitive(object)
+        })
+        .collect();
+    value.insert("link".into(), ValueType::Array(links));
+}
+
+/// Convert an `<extensions>` element into a nested value keyed by local names
+fn parse_extensions(element: &XMLNode) -> MValue {
+    let mut value = MValue::new();
+    for child in &element.children {
+        let key = child.local_name().into();
+        if child.children.is_empty() {
+            let text = child.text.trim();
+            let primitive = match text.parse::<f64>() {
+                Ok(number) => ValueType::Primitive(PrimitiveValue::F64(number)),
+                Err(_) => string_value(text),
+            };
+            value.insert(key, primitive);
+        } else {
+            value.insert(key, ValueType::Nested(parse_extensions(child)));
+        }
+    }
+    value
+}
+
+/// A string primitive value
+fn string_value(value: &str) -> ValueType {
+    ValueType::Primitive(PrimitiveValue::String(value.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::BufferReader;
+    use alloc::vec;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/gpx/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn primitive(value: &MValue, key: &str) -> PrimitiveValue {
+        match value.get(key) {
+            Some(ValueType::Primitive(value)) => value.clone(),
+            _ => panic!("missing {key}"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let reader = BufferReader::from(&b"<gpx><wpt></gpx>"[..]);
+        assert_eq!(
+            GPXReader::new(reader).err(),
+            Some(GPXError::XML(XMLError::MismatchedEndTag(10)))
+        );
+        let reader = BufferReader::from(&b"<kml></kml>"[..]);
+        assert_eq!(GPXReader::new(reader).err(), Some(GPXError::MissingRoot));
+        let reader = BufferReader::from(&b"<gpx><wpt lat=\"1\" lon=\"2\"/><wpt></gpx>"[..]);
+        let mut reader = GPXReader::new(reader).unwrap();
+        assert!(reader.next().is_some());
+        assert!(reader.next().is_none());
+        assert_eq!(reader.error, Some(GPXError::XML(XMLError::MismatchedEndTag(32))));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_short() {
+        let data = fixture("gpx-test-short.gpx");
+        let reader = GPXReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.version, "1.1");
+        assert_eq!(reader.creator, Some("Raidy".into()));
+        let link = GPXLink {
+            href: "http://example.com".into(),
+            text: Some("Author website".into()),
+            link_type: Some("Web".into()),
+        };
+        assert_eq!(
+            reader.metadata,
+            GPXMetadata {
+                name: Some("GPX DEMO".into()),
+                desc: Some("A full featured gpx demo file".into()),
+                author: Some(GPXPerson {
+                    name: Some("Demo Author".into()),
+                    email: Some(GPXEmail { id: "demo".into(), domain: "example.com".into() }),
+                    link: Some(link.clone()),
+                }),
+                copyright: None,
+                link: vec![link],
+                time: Some("2020-01-12T21:32:52".into()),
+                keywords: None,
+                bounds: Some(GPXBounds {
+                    minlat: 49.12965660728301,
+                    minlon: -1.5521714646550901,
+                    maxlat: 45.85097922514941,
+                    maxlon: 4.336738935765406,
+                }),
+            }
+        );
+
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 4);
+
+        let VectorGeometry::Point(point) = &features[0].geometry else { panic!("expected point") };
+        assert!(point.is_3d);
+        assert_eq!(point.coordinates.x, -1.5153741828293);
+        assert_eq!(point.coordinates.y, 47.253146555709);
+        assert_eq!(point.coordinates.z, Some(35.));
+        let properties = &features[0].properties;
+        assert_eq!(
+            primitive(properties, "name"),
+            PrimitiveValue::String("Porte de Carquefou".into())
+        );
+        assert_eq!(
+            primitive(properties, "sym"),
+            PrimitiveValue::String("Fishing Hot Spot Facility".into())
+        );
+        assert_eq!(features[1].properties.get("sym"), None);
+
+        // the track precedes the route in the document
+        let VectorGeometry::MultiLineString(track) = &features[2].geometry else {
+            panic!("expected multilinestring")
+        };
+        assert!(track.is_3d);
+        assert_eq!(track.coordinates.len(), 1);
+        assert_eq!(track.coordinates[0][0].z, Some(12.36));
+        assert_eq!(track.coordinates[0][1].z, Some(7.08));
+        let properties = &features[2].properties;
+        assert_eq!(primitive(properties, "name"), PrimitiveValue::String("Track".into()));
+        assert_eq!(primitive(properties, "type"), PrimitiveValue::String("MTB".into()));
+        assert_eq!(primitive(properties, "number"), PrimitiveValue::U64(1));
+
+        let VectorGeometry::LineString(route) = &features[3].geometry else {
+            panic!("expected linestring")
+        };
+        assert!(route.is_3d);
+        assert_eq!(route.coordinates[0].x, -1.5521714646550901);
+        assert_eq!(route.coordinates[0].y, 47.2278526991611);
+
+        assert_eq!(features[3].metadata.as_ref().unwrap().name, Some("GPX DEMO".into()));
+    }
+
+    #[test]
+    fn test_long_with_extensions() {
+        let data = fixture("gpx-test-long.gpx");
+        let reader = GPXReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(
+            reader.metadata,
+            GPXMetadata { time: Some("2023-01-12T16:03:11Z".into()), ..Default::default() }
+        );
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 1);
+        let VectorGeometry::MultiLineString(track) = &features[0].geometry else {
+            panic!("expected multilinestring")
+        };
+        assert_eq!(track.coordinates[0].len(), 3602);
+        let point = &track.coordinates[0][0];
+        assert_eq!((point.x, point.y, point.z), (-75.720004, 39.843201, Some(86.2)));
+        let m = point.m.as_ref().unwrap();
+        assert_eq!(primitive(m, "time"), PrimitiveValue::String("2023-01-12T16:03:11Z".into()));
+        let Some(ValueType::Nested(extensions)) = m.get("extensions") else {
+            panic!("missing extensions")
+        };
+        let Some(ValueType::Nested(track_point)) = extensions.get("TrackPointExtension") else {
+            panic!("missing TrackPointExtension")
+        };
+        assert_eq!(primitive(track_point, "hr"), PrimitiveValue::F64(87.));
+    }
+
+    #[test]
+    fn test_gpx_10() {
+        let xml = br#"<?xml version="1.0"?>
+<gpx version="1.0" creator="test" xmlns="http://www.topografix.com/GPX/1/0">
+  <name>Old &amp; Gold</name>
+  <author>Jane</author>
+  <email>jane@example.com</email>
+  <url>http://example.com</url>
+  <urlname>Home</urlname>
+  <!-- a comment with <wpt> inside -->
+  <rte>
+    <name><![CDATA[Route <1>]]></name>
+    <rtept lat="1" lon="2"><hdop>1.5</hdop><sat>7</sat><fix>3d</fix><speed>2.5</speed></rtept>
+    <rtept lat="3" lon="4"/>
+  </rte>
+</gpx>"#;
+        let reader = GPXReader::new(BufferReader::from(&xml[..])).unwrap();
+        assert_eq!(reader.version, "1.0");
+        assert_eq!(reader.metadata.name, Some("Old & Gold".into()));
+        assert_eq!(
+            reader.metadata.author,
+            Some(GPXPerson {
+                name: Some("Jane".into()),
+                email: Some(GPXEmail { id: "jane".into(), domain: "example.com".into() }),
+                link: None,
+            })
+        );
+        assert_eq!(
+            reader.metadata.link,
+            vec![GPXLink {
+                href: "http://example.com".into(),
+                text: Some("Home".into()),
+                link_type: None
+            }]
+        );
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 1);
+        assert_eq!(
+            primitive(&features[0].properties, "name"),
+            PrimitiveValue::String("Route <1>".into())
+        );
+        let VectorGeometry::LineString(route) = &features[0].geometry else {
+            panic!("expected linestring")
+        };
+        assert!(!route.is_3d);
+        let m = route.coordinates[0].m.as_ref().unwrap();
+        assert_eq!(primitive(m, "hdop"), PrimitiveValue::F64(1.5));
+        assert_eq!(primitive(m, "sat"), PrimitiveValue::U64(7));
+        assert_eq!(primitive(m, "fix"), PrimitiveValue::String("3d".into()));
+        assert_eq!(primitive(m, "speed"), PrimitiveValue::F64(2.5));
+        assert_eq!((route.coordinates[1].x, route.coordinates[1].y), (4., 3.));
+    }
+}
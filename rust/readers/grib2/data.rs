@@ -0,0 +1,433 @@
+use super::{
+    grib2_data_representation_template, grib2_original_field_type, sections::Octets, GRIB2Error,
+};
+use crate::readers::JpxImage;
+
+use alloc::{vec, vec::Vec};
+use libm::pow;
+
+/// The parameters shared by simple, complex and JPEG 2000 packing
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Grib2SimplePacking {
+    /// Reference value (R)
+    pub reference: f32,
+    /// Binary scale factor (E)
+    pub binary_scale: i32,
+    /// Decimal scale factor (D)
+    pub decimal_scale: i32,
+    /// Number of bits used for each packed value
+    pub bits: u8,
+    /// Type of original field values (Code Table 5.1)
+    pub original_type: u8,
+}
+impl Grib2SimplePacking {
+    fn new(section: &Octets) -> Result<Self, GRIB2Error> {
+        Ok(Self {
+            reference: section.f32(11)?,
+            binary_scale: section.i16(15)?,
+            decimal_scale: section.i16(17)?,
+            bits: section.u8(19)?,
+            original_type: section.u8(20)?,
+        })
+    }
+
+    /// The name of the original field values type (Code Table 5.1)
+    pub fn original_type_name(&self) -> Option<&'static str> {
+        grib2_original_field_type(self.original_type)
+    }
+
+    /// Y = (R + X * 2^E) / 10^D
+    fn scale(&self, value: i64) -> f64 {
+        let binary = pow(2., self.binary_scale as f64);
+        let decimal = pow(10., self.decimal_scale as f64);
+        (self.reference as f64 + value as f64 * binary) / decimal
+    }
+}
+
+/// The group parameters of complex packing (templates 5.2 and 5.3)
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Grib2ComplexPacking {
+    /// Group splitting method used (Code Table 5.4)
+    pub group_splitting: u8,
+    /// Missing value management used (Code Table 5.5)
+    pub missing_management: u8,
+    /// Primary missing value substitute
+    pub primary_missing: u32,
+    /// Secondary missing value substitute
+    pub secondary_missing: u32,
+    /// Number of groups of data values
+    pub groups: u32,
+    /// Reference for group widths
+    pub width_reference: u8,
+    /// Number of bits used for the group widths
+    pub width_bits: u8,
+    /// Reference for group lengths
+    pub length_reference: u32,
+    /// Length increment for the group lengths
+    pub length_increment: u8,
+    /// True length of the last group
+    pub last_length: u32,
+    /// Number of bits used for the scaled group lengths
+    pub length_bits: u8,
+    /// Order of spatial differencing (Code Table 5.6). 0 for template 5.2
+    pub spatial_order: u8,
+    /// Number of octets of the extra descriptors of spatial differencing
+    pub spatial_octets: u8,
+}
+impl Grib2ComplexPacking {
+    fn new(section: &Octets, spatial_differencing: bool) -> Result<Self, GRIB2Error> {
+        Ok(Self {
+            group_splitting: section.u8(21)?,
+            missing_management: section.u8(22)?,
+            primary_missing: section.u32(23)?,
+            secondary_missing: section.u32(27)?,
+            groups: section.u32(31)?,
+            width_reference: section.u8(35)?,
+            width_bits: section.u8(36)?,
+            length_reference: section.u32(37)?,
+            length_increment: section.u8(41)?,
+            last_length: section.u32(42)?,
+            length_bits: section.u8(46)?,
+            spatial_order: if spatial_differencing { section.u8(47)? } else { 0 },
+            spatial_octets: if spatial_differencing { section.u8(48)? } else { 0 },
+        })
+    }
+}
+
+/// How the values of a field are packed
+#[derive(Debug, Clone, PartialEq)]
+pub enum Grib2Packing {
+    /// Template 5.0: Grid point data - simple packing
+    Simple(Grib2SimplePacking),
+    /// Templates 5.2 and 5.3: Grid point data - complex packing, optionally with spatial
+    /// differencing
+    Complex(Grib2SimplePacking, Grib2ComplexPacking),
+    /// Template 5.4: Grid point data - IEEE floating point data
+    Ieee {
+        /// Precision (Code Table 5.7): 1 for 32 bit and 2 for 64 bit floats
+        precision: u8,
+    },
+    /// Template 5.40: Grid point data - JPEG 2000 code stream format
+    Jpeg2000 {
+        /// The scaling parameters of the decoded integers
+        simple: Grib2SimplePacking,
+        /// Type of compression used (Code Table 5.40)
+        compression_type: u8,
+        /// Target compression ratio
+        compression_ratio: u8,
+    },
+    /// A data representation template that isn't supported
+    Unsupported,
+}
+
+/// Section 5: Data Representation Section
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grib2DataRepresentation {
+    /// Number of data points where one or more values are specified in section 7
+    pub number_of_values: u32,
+    /// Data representation template number (Code Table 5.0)
+    pub template: u16,
+    /// The packing parameters of the template
+    pub packing: Grib2Packing,
+}
+impl Grib2DataRepresentation {
+    /// Parse section 5
+    pub fn new(data: &[u8]) -> Result<Self, GRIB2Error> {
+        let section = Octets::new(data, 5);
+        let template = section.u16(9)?;
+        let packing = match template {
+            0 => Grib2Packing::Simple(Grib2SimplePacking::new(&section)?),
+            2 | 3 => Grib2Packing::Complex(
+                Grib2SimplePacking::new(&section)?,
+                Grib2ComplexPacking::new(&section, template == 3)?,
+            ),
+            4 => Grib2Packing::Ieee { precision: section.u8(11)? },
+            40 => Grib2Packing::Jpeg2000 {
+                simple: Grib2SimplePacking::new(&section)?,
+                compression_type: section.u8(21)?,
+                compression_ratio: section.u8(22)?,
+            },
+            _ => Grib2Packing::Unsupported,
+        };
+        Ok(Self { number_of_values: section.u32(5)?, template, packing })
+    }
+
+    /// The name of the data representation template (Code Table 5.0)
+    pub fn template_name(&self) -> Option<&'static str> {
+        grib2_data_representation_template(self.template)
+    }
+
+    /// Unpack the `number_of_values` values stored in section 7. Values flagged missing by
+    /// complex packing are `None`
+    pub fn unpack(&self, data: &[u8]) -> Result<Vec<Option<f64>>, GRIB2Error> {
+        let count = self.number_of_values as usize;
+        match &self.packing {
+            Grib2Packing::Simple(simple) => {
+                if simple.bits == 0 {
+                    return Ok(vec![Some(simple.scale(0)); count]);
+                }
+                let mut bits = BitReader::new(data);
+                (0..count).map(|_| Ok(Some(simple.scale(bits.read(simple.bits)? as i64)))).collect()
+            }
+            Grib2Packing::Complex(simple, complex) => unpack_complex(data, count, simple, complex),
+            Grib2Packing::Ieee { precision: 1 } => {
+                let floats = data.as_chunks::<4>().0;
+                if floats.len() < count {
+                    return Err(GRIB2Error::InvalidData);
+                }
+                Ok(floats.iter().take(count).map(|b| Some(f32::from_be_bytes(*b) as f64)).collect())
+            }
+            Grib2Packing::Ieee { precision: 2 } => {
+                let floats = data.as_chunks::<8>().0;
+                if floats.len() < count {
+                    return Err(GRIB2Error::InvalidData);
+                }
+                Ok(floats.iter().take(count).map(|b| Some(f64::from_be_bytes(*b))).collect())
+            }
+            Grib2Packing::Jpeg2000 { simple, .. } => {
+                if simple.bits == 0 || data.is_empty() {
+                    return Ok(vec![Some(simple.scale(0)); count]);
+                }
+                let image = JpxImage::new(data)?;
+                let width = image.width;
+                let mut items = vec![0; width * image.height];
+                for tile in &image.tiles {
+                    let tile_width = tile.width;
+                    for (row, line) in tile.items.chunks(tile_width).enumerate() {
+                        let start = (tile.top + row) * width + tile.left;
+                        items[start..start + tile_width].copy_from_slice(line);
+                    }
+                }
+                if items.len() < count {
+                    return Err(GRIB2Error::InvalidData);
+                }
+                Ok(items.into_iter().take(count).map(|x| Some(simple.scale(x as i64))).collect())
+            }
+            _ => Err(GRIB2Error::UnsupportedTemplate(5, self.template)),
+        }
+    }
+}
+
+/// Unpack complex packing following the group layout of WMO GRIB2 templates 7.2 and 7.3
+fn unpack_complex(
+    data: &[u8],
+    count: usize,
+    simple: &Grib2SimplePacking,
+    complex: &Grib2ComplexPacking,
+) -> Result<Vec<Option<f64>>, GRIB2Error> {
+    let groups = complex.groups as usize;
+    if groups == 0 {
+        return Ok(vec![Some(simple.scale(0)); count]);
+    }
+    let mut bits = BitReader::new(data);
+
+    // the extra descriptors of spatial differencing: the first value(s) and the overall minimum
+    let mut first_values = [0_i64; 2];
+    let mut minimum = 0;
+    let descriptor_bits = complex.spatial_octets as u32 * 8;
+    if complex.spatial_order > 0 && descriptor_bits > 0 {
+        for value in first_values.iter_mut().take(complex.spatial_order.min(2) as usize) {
+            *value = bits.read_signed(descriptor_bits)?;
+        }
+        minimum = bits.read_signed(descriptor_bits)?;
+    }
+    bits.align();
+
+    let references = bits.read_all(simple.bits, groups)?;
+    bits.align();
+    let widths = bits.read_all(complex.width_bits, groups)?;
+    bits.align();
+    let mut lengths = bits.read_all(complex.length_bits, groups)?;
+    bits.align();
+    for length in lengths.iter_mut() {
+        *length = complex.length_reference as u64 + *length * complex.length_increment as u64;
+    }
+    lengths[groups - 1] = complex.last_length as u64;
+    if lengths.iter().sum::<u64>() != count as u64 {
+        return Err(GRIB2Error::InvalidData);
+    }
+
+    // unpack the groups, flagging missing values and keeping the others in order
+    let all_ones = |bits: u32| if bits >= 64 { u64::MAX } else { (1_u64 << bits) - 1 };
+    let management = complex.missing_management;
+    let mut present = Vec::with_capacity(count);
+    let mut values: Vec<i64> = Vec::with_capacity(count);
+    for ((reference, width), length) in references.into_iter().zip(widths).zip(lengths) {
+        let width = (width + complex.width_reference as u64) as u32;
+        let (missing1, missing2) = if width == 0 {
+            (all_ones(simple.bits as u32), all_ones(simple.bits as u32).wrapping_sub(1))
+        } else {
+            (all_ones(width), all_ones(width).wrapping_sub(1))
+        };
+        for _ in 0..length {
+            let packed = bits.read(width as u8)?;
+            let flag = if width == 0 { reference } else { packed };
+            if (management == 1 || management == 2) && flag == missing1
+                || management == 2 && flag == missing2
+            {
+                present.push(false);
+            } else {
+                present.push(true);
+                values.push((reference + packed) as i64);
+            }
+        }
+    }
+
+    // undo the spatial differencing of the non-missing values
+    match complex.spatial_order {
+        1 if !values.is_empty() => {
+            values[0] = first_values[0];
+            for n in 1..values.len() {
+                values[n] += minimum + values[n - 1];
+            }
+        }
+        2 if values.len() > 1 => {
+            values[0] = first_values[0];
+            values[1] = first_values[1];
+            for n in 2..values.len() {
+                values[n] += minimum + 2 * values[n - 1] - values[n - 2];
+            }
+        }
+        _ => {}
+    }
+
+    let mut values = values.into_iter();
+    Ok(present
+        .into_iter()
+        .map(|present| if present { values.next().map(|x| simple.scale(x)) } else { None })
+        .collect())
+}
+
+/// Reads big endian packed integers of any width up to 64 bits
+struct BitReader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+impl<'a> BitReader<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    fn read(&mut self, bits: u8) -> Result<u64, GRIB2Error> {
+        let bits = bits as usize;
+        if bits > 64 || self.position + bits > self.data.len() * 8 {
+            return Err(GRIB2Error::InvalidData);
+        }
+        let mut value = 0_u64;
+        let mut remaining = bits;
+        while remaining > 0 {
+            let byte = self.data[self.position >> 3] as u64;
+            let offset = self.position & 7;
+            let take = (8 - offset).min(remaining);
+            let chunk = (byte >> (8 - offset - take)) & ((1 << take) - 1);
+            value = (value << take) | chunk;
+            self.position += take;
+            remaining -= take;
+        }
+        Ok(value)
+    }
+
+    /// Read a sign-magnitude integer
+    fn read_signed(&mut self, bits: u32) -> Result<i64, GRIB2Error> {
+        let negative = self.read(1)? == 1;
+        let magnitude = self.read((bits - 1) as u8)? as i64;
+        Ok(if negative { -magnitude } else { magnitude })
+    }
+
+    fn read_all(&mut self, bits: u8, count: usize) -> Result<Vec<u64>, GRIB2Error> {
+        (0..count).map(|_| self.read(bits)).collect()
+    }
+
+    /// Skip to the start of the next octet
+    fn align(&mut self) {
+        self.position = (self.position + 7) & !7;
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Packs big endian integers of any width
+    #[derive(Default)]
+    pub(crate) struct BitWriter {
+        pub(crate) data: Vec<u8>,
+        position: usize,
+    }
+    impl BitWriter {
+        pub(crate) fn write(&mut self, value: u64, bits: u32) {
+            for i in (0..bits).rev() {
+                if self.position.is_multiple_of(8) {
+                    self.data.push(0);
+                }
+                let bit = ((value >> i) & 1) as u8;
+                *self.data.last_mut().unwrap() |= bit << (7 - (self.position & 7));
+                self.position += 1;
+            }
+        }
+
+        pub(crate) fn align(&mut self) {
+            self.position = self.data.len() * 8;
+        }
+    }
+
+    #[test]
+    fn test_bit_reader() {
+        let mut writer = BitWriter::default();
+        writer.write(5, 3);
+        writer.write(0x1ffff, 17);
+        writer.write(1, 1);
+        writer.write(u64::MAX, 64);
+        let mut reader = BitReader::new(&writer.data);
+        assert_eq!(reader.read(3), Ok(5));
+        assert_eq!(reader.read(17), Ok(0x1ffff));
+        assert_eq!(reader.read(1), Ok(1));
+        assert_eq!(reader.read(64), Ok(u64::MAX));
+        assert_eq!(reader.read(8), Err(GRIB2Error::InvalidData));
+    }
+
+    #[test]
+    fn test_complex_packing_spatial_differencing() {
+        let simple = Grib2SimplePacking {
+            reference: 0.,
+            binary_scale: 0,
+            decimal_scale: 1,
+            bits: 4,
+            original_type: 0,
+        };
+        let complex = Grib2ComplexPacking {
+            missing_management: 1,
+            groups: 2,
+            width_reference: 0,
+            width_bits: 2,
+            length_reference: 3,
+            length_increment: 1,
+            last_length: 3,
+            length_bits: 1,
+            spatial_order: 1,
+            spatial_octets: 2,
+            ..Default::default()
+        };
+        // the values 10, 12, (missing), 12, 8, 9 are differenced to 10, 2, 0, -4, 1 and offset
+        // by the minimum -4
+        let mut writer = BitWriter::default();
+        writer.write(10, 16); // first value
+        writer.write(0x8000 | 4, 16); // minimum
+        writer.align();
+        writer.write(0, 4); // group references
+        writer.write(0, 4);
+        writer.align();
+        writer.write(3, 2); // group widths
+        writer.write(3, 2);
+        writer.align();
+        writer.write(0, 1); // scaled group lengths
+        writer.write(0, 1);
+        writer.align();
+        // 7 is all ones for a width of 3 so the third value is missing
+        for value in [0, 6, 7, 4, 0, 5] {
+            writer.write(value, 3);
+        }
+        let values = unpack_complex(&writer.data, 6, &simple, &complex).unwrap();
+        assert_eq!(values, vec![Some(1.), Some(1.2), None, Some(1.2), Some(0.8), Some(0.9)]);
+    }
+}
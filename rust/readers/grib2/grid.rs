@@ -0,0 +1,482 @@
+use super::{grib2_earth_shape, grib2_grid_template, sections::Octets, GRIB2Error};
+
+use alloc::vec::Vec;
+use core::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+use libm::{atan, atan2, cos, fmod, log, pow, sin, sqrt, tan};
+
+/// A 32 bit value with every bit set flags a missing value
+const MISSING: u32 = u32::MAX;
+
+/// Scanning mode flag: points of the first row or column scan in the -i (-x) direction
+pub const SCAN_NEGATIVE_I: u8 = 0x80;
+/// Scanning mode flag: points of the first row or column scan in the +j (+y) direction
+pub const SCAN_POSITIVE_J: u8 = 0x40;
+/// Scanning mode flag: adjacent points in the j direction are consecutive
+pub const SCAN_J_CONSECUTIVE: u8 = 0x20;
+/// Scanning mode flag: adjacent rows scan in the opposite direction (boustrophedonic)
+pub const SCAN_BOUSTROPHEDON: u8 = 0x10;
+
+/// The projection of a grid. Angles are in degrees and lengths in metres
+#[derive(Debug, Clone, PartialEq)]
+pub enum Grib2Projection {
+    /// Template 3.0: Latitude/longitude (equidistant cylindrical)
+    LatLon {
+        /// Latitude of the first grid point
+        lat1: f64,
+        /// Longitude of the first grid point
+        lon1: f64,
+        /// Latitude of the last grid point
+        lat2: f64,
+        /// Longitude of the last grid point
+        lon2: f64,
+        /// i direction increment
+        dlon: f64,
+        /// j direction increment
+        dlat: f64,
+    },
+    /// Template 3.20: Polar stereographic
+    PolarStereographic {
+        /// Latitude of the first grid point
+        lat1: f64,
+        /// Longitude of the first grid point
+        lon1: f64,
+        /// Latitude where dx and dy are specified
+        lad: f64,
+        /// Orientation of the grid: the longitude parallel to the y-axis
+        lov: f64,
+        /// x direction grid length
+        dx: f64,
+        /// y direction grid length
+        dy: f64,
+        /// True if the south pole is on the projection plane
+        south_pole: bool,
+    },
+    /// Template 3.30: Lambert conformal
+    LambertConformal {
+        /// Latitude of the first grid point
+        lat1: f64,
+        /// Longitude of the first grid point
+        lon1: f64,
+        /// Latitude where dx and dy are specified
+        lad: f64,
+        /// Orientation of the grid: the longitude parallel to the y-axis
+        lov: f64,
+        /// x direction grid length
+        dx: f64,
+        /// y direction grid length
+        dy: f64,
+        /// True if the south pole is on the projection plane
+        south_pole: bool,
+        /// First latitude from the pole at which the secant cone cuts the sphere
+        latin1: f64,
+        /// Second latitude from the pole at which the secant cone cuts the sphere
+        latin2: f64,
+    },
+    /// A grid definition template that isn't supported
+    Unsupported,
+}
+
+/// Section 3: Grid Definition Section
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grib2GridDefinition {
+    /// Source of grid definition (Code Table 3.0)
+    pub source: u8,
+    /// Number of data points
+    pub number_of_points: u32,
+    /// Grid definition template number (Code Table 3.1)
+    pub template: u16,
+    /// Shape of the earth (Code Table 3.2)
+    pub earth_shape: u8,
+    /// Radius of the spherical earth in metres. For oblate spheroids it's the major axis
+    pub earth_radius: f64,
+    /// Number of points along a parallel (or the x-axis)
+    pub nx: u32,
+    /// Number of points along a meridian (or the y-axis)
+    pub ny: u32,
+    /// Scanning mode flags (Flag Table 3.4)
+    pub scan_mode: u8,
+    /// The grid's projection
+    pub projection: Grib2Projection,
+}
+impl Grib2GridDefinition {
+    /// Parse section 3
+    pub fn new(data: &[u8]) -> Result<Self, GRIB2Error> {
+        let section = Octets::new(data, 3);
+        let template = section.u16(12)?;
+        let mut grid = Self {
+            source: section.u8(5)?,
+            number_of_points: section.u32(6)?,
+            template,
+            earth_shape: 255,
+            earth_radius: 6_371_229.,
+            nx: 0,
+            ny: 0,
+            scan_mode: 0,
+            projection: Grib2Projection::Unsupported,
+        };
+        if !matches!(template, 0 | 20 | 30) {
+            return Ok(grid);
+        }
+        grid.earth_shape = section.u8(14)?;
+        grid.earth_radius = earth_radius(&section)?;
+        grid.nx = section.u32(30)?;
+        grid.ny = section.u32(34)?;
+        if grid.nx.checked_mul(grid.ny) != Some(grid.number_of_points) {
+            return Err(GRIB2Error::InvalidSection(3));
+        }
+        let degrees =
+            |offset: usize| -> Result<f64, GRIB2Error> { Ok(section.i32(offset)? as f64 * 1e-6) };
+        if template == 0 {
+            let basic_angle = section.u32(38)?;
+            let subdivisions = section.u32(42)?;
+            let ratio = if basic_angle == 0 || basic_angle == MISSING || subdivisions == MISSING {
+                1e-6
+            } else {
+                basic_angle as f64 / subdivisions as f64
+            };
+            let angle = |offset: usize| -> Result<f64, GRIB2Error> {
+                Ok(section.i32(offset)? as f64 * ratio)
+            };
+            grid.scan_mode = section.u8(71)?;
+            let (lat1, lon1, lat2, lon2) = (angle(46)?, angle(50)?, angle(55)?, angle(59)?);
+            let (dx, dy) = (section.u32(63)?, section.u32(67)?);
+            let dlon = if dx == MISSING {
+                let mut span = lon2 - lon1;
+                if grid.scan_mode & SCAN_NEGATIVE_I == 0 && span < 0. {
+                    span += 360.;
+                }
+                span.abs() / (grid.nx.max(2) - 1) as f64
+            } else {
+                dx as f64 * ratio
+            };
+            let dlat = if dy == MISSING {
+                (lat2 - lat1).abs() / (grid.ny.max(2) - 1) as f64
+            } else {
+                dy as f64 * ratio
+            };
+            grid.projection = Grib2Projection::LatLon { lat1, lon1, lat2, lon2, dlon, dlat };
+        } else {
+            grid.scan_mode = section.u8(64)?;
+            let (lat1, lon1, lad, lov) = (degrees(38)?, degrees(42)?, degrees(47)?, degrees(51)?);
+            let (dx, dy) = (section.u32(55)? as f64 * 1e-3, section.u32(59)? as f64 * 1e-3);
+            let south_pole = section.u8(63)? & 0x80 != 0;
+            grid.projection = if template == 20 {
+                Grib2Projection::PolarStereographic { lat1, lon1, lad, lov, dx, dy, south_pole }
+            } else {
+                Grib2Projection::LambertConformal {
+                    lat1,
+                    lon1,
+                    lad,
+                    lov,
+                    dx,
+                    dy,
+                    south_pole,
+                    latin1: degrees(65)?,
+                    latin2: degrees(69)?,
+                }
+            };
+        }
+        Ok(grid)
+    }
+
+    /// The name of the grid definition template (Code Table 3.1)
+    pub fn template_name(&self) -> Option<&'static str> {
+        grib2_grid_template(self.template)
+    }
+
+    /// The description of the shape of the earth (Code Table 3.2)
+    pub fn earth_shape_name(&self) -> Option<&'static str> {
+        grib2_earth_shape(self.earth_shape)
+    }
+
+    /// The (i, j) grid index of every point in the order the data is stored
+    pub fn indices(&self) -> Vec<(u32, u32)> {
+        let (nx, ny) = (self.nx, self.ny);
+        let scan = self.scan_mode;
+        let j_consecutive = scan & SCAN_J_CONSECUTIVE != 0;
+        let (rows, row_len) = if j_consecutive { (nx, ny) } else { (ny, nx) };
+        // only trust the dimensions if they agree with the number of data points
+        let count = nx.checked_mul(ny).filter(|count| *count == self.number_of_points);
+        let mut indices = Vec::with_capacity(count.unwrap_or(0) as usize);
+        for row in 0..rows {
+            for k in 0..row_len {
+                let k = if scan & SCAN_BOUSTROPHEDON != 0 && row % 2 == 1 {
+                    row_len - 1 - k
+                } else {
+                    k
+                };
+                indices.push(if j_consecutive { (row, k) } else { (k, row) });
+            }
+        }
+        indices
+    }
+
+    /// The (longitude, latitude) of every grid point in the order the data is stored. Longitudes
+    /// are in the range [0, 360) like GRIB2 stores them
+    pub fn points(&self) -> Result<Vec<(f64, f64)>, GRIB2Error> {
+        let di = if self.scan_mode & SCAN_NEGATIVE_I != 0 { -1. } else { 1. };
+        let dj = if self.scan_mode & SCAN_POSITIVE_J != 0 { 1. } else { -1. };
+        let indices = self.indices();
+        let radius = self.earth_radius;
+        let points = match self.projection {
+            Grib2Projection::LatLon { lat1, lon1, dlon, dlat, .. } => indices
+                .into_iter()
+                .map(|(i, j)| {
+                    let lon = normalize_lon(lon1 + di * dlon * i as f64);
+                    (lon, lat1 + dj * dlat * j as f64)
+                })
+                .collect(),
+            Grib2Projection::PolarStereographic {
+                lat1,
+                lon1,
+                lad,
+                lov,
+                dx,
+                dy,
+                south_pole,
+                ..
+            } => {
+                let projection = PolarStereographic::new(radius, lad, lov, south_pole);
+                let (x1, y1) = projection.forward(lon1, lat1);
+                indices
+                    .into_iter()
+                    .map(|(i, j)| {
+                        projection.inverse(x1 + di * dx * i as f64, y1 + dj * dy * j as f64)
+                    })
+                    .collect()
+            }
+            Grib2Projection::LambertConformal {
+                lat1,
+                lon1,
+                lad,
+                lov,
+                dx,
+                dy,
+                latin1,
+                latin2,
+                ..
+            } => {
+                let projection = LambertConformal::new(radius, lad, lov, latin1, latin2);
+                let (x1, y1) = projection.forward(lon1, lat1);
+                indices
+                    .into_iter()
+                    .map(|(i, j)| {
+                        projection.inverse(x1 + di * dx * i as f64, y1 + dj * dy * j as f64)
+                    })
+                    .collect()
+            }
+            Grib2Projection::Unsupported => {
+                return Err(GRIB2Error::UnsupportedTemplate(3, self.template));
+            }
+        };
+        Ok(points)
+    }
+}
+
+/// The earth radius in metres described by octets 15-30, shared by the supported templates
+fn earth_radius(section: &Octets) -> Result<f64, GRIB2Error> {
+    let scaled = |scale: usize, value: usize| -> Result<f64, GRIB2Error> {
+        Ok(section.u32(value)? as f64 / pow(10., section.u8(scale)? as f64))
+    };
+    Ok(match section.u8(14)? {
+        0 => 6_367_470.,
+        1 => scaled(15, 16)?,
+        2 => 6_378_160.,
+        3 => scaled(20, 21)? * 1_000.,
+        4 | 5 => 6_378_137.,
+        7 => scaled(20, 21)?,
+        8 => 6_371_200.,
+        _ => 6_371_229.,
+    })
+}
+
+/// Wrap a longitude into [0, 360)
+fn normalize_lon(lon: f64) -> f64 {
+    let lon = fmod(lon, 360.);
+    if lon < 0. {
+        lon + 360.
+    } else {
+        lon
+    }
+}
+
+/// Spherical polar stereographic projection with a true scale at `lad`
+struct PolarStereographic {
+    /// 1 for the north pole and -1 for the south pole
+    h: f64,
+    /// R * (1 + h * sin(lad))
+    k: f64,
+    lov: f64,
+}
+impl PolarStereographic {
+    fn new(radius: f64, lad: f64, lov: f64, south_pole: bool) -> Self {
+        let h = if south_pole { -1. } else { 1. };
+        Self { h, k: radius * (1. + h * sin(lad.to_radians())), lov: lov.to_radians() }
+    }
+
+    fn forward(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let rho = self.k * tan(FRAC_PI_4 - self.h * lat.to_radians() / 2.);
+        let dlon = lon.to_radians() - self.lov;
+        (rho * sin(dlon), -self.h * rho * cos(dlon))
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let rho = sqrt(x * x + y * y);
+        let lat = self.h * (FRAC_PI_2 - 2. * atan(rho / self.k));
+        let lon = self.lov + atan2(x, -self.h * y);
+        (normalize_lon(lon.to_degrees()), lat.to_degrees())
+    }
+}
+
+/// Spherical Lambert conformal conic projection with one or two standard parallels
+struct LambertConformal {
+    n: f64,
+    /// R * F
+    rf: f64,
+    rho0: f64,
+    lov: f64,
+}
+impl LambertConformal {
+    fn new(radius: f64, lad: f64, lov: f64, latin1: f64, latin2: f64) -> Self {
+        let (phi1, phi2) = (latin1.to_radians(), latin2.to_radians());
+        let n = if (latin1 - latin2).abs() < 1e-10 {
+            sin(phi1)
+        } else {
+            log(cos(phi1) / cos(phi2))
+                / log(tan(FRAC_PI_4 + phi2 / 2.) / tan(FRAC_PI_4 + phi1 / 2.))
+        };
+        let rf = radius * cos(phi1) * pow(tan(FRAC_PI_4 + phi1 / 2.), n) / n;
+        let rho0 = rf / pow(tan(FRAC_PI_4 + lad.to_radians() / 2.), n);
+        Self { n, rf, rho0, lov: lov.to_radians() }
+    }
+
+    fn forward(&self, lon: f64, lat: f64) -> (f64, f64) {
+        let rho = self.rf / pow(tan(FRAC_PI_4 + lat.to_radians() / 2.), self.n);
+        let mut dlon = lon.to_radians() - self.lov;
+        // keep the longitude difference within (-180, 180]
+        dlon =
+            fmod(dlon + core::f64::consts::PI * 3., core::f64::consts::TAU) - core::f64::consts::PI;
+        let theta = self.n * dlon;
+        (rho * sin(theta), self.rho0 - rho * cos(theta))
+    }
+
+    fn inverse(&self, x: f64, y: f64) -> (f64, f64) {
+        let sign = self.n.signum();
+        let rho = sign * sqrt(x * x + (self.rho0 - y) * (self.rho0 - y));
+        let theta = atan2(sign * x, sign * (self.rho0 - y));
+        let lat = 2. * atan(pow(self.rf / rho, 1. / self.n)) - FRAC_PI_2;
+        let lon = theta / self.n + self.lov;
+        (normalize_lon(lon.to_degrees()), lat.to_degrees())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn assert_close(a: (f64, f64), b: (f64, f64)) {
+        assert!((a.0 - b.0).abs() < 1e-4 && (a.1 - b.1).abs() < 1e-4, "{a:?} != {b:?}");
+    }
+
+    fn projected(
+        projection: Grib2Projection,
+        nx: u32,
+        ny: u32,
+        scan_mode: u8,
+    ) -> Grib2GridDefinition {
+        Grib2GridDefinition {
+            source: 0,
+            number_of_points: nx * ny,
+            template: 30,
+            earth_shape: 6,
+            earth_radius: 6_371_229.,
+            nx,
+            ny,
+            scan_mode,
+            projection,
+        }
+    }
+
+    #[test]
+    fn test_number_of_points() {
+        // a template 3.0 section with a 3x2 grid
+        let mut data = vec![0; 72];
+        data[14] = 6;
+        data[30..34].copy_from_slice(&3_u32.to_be_bytes());
+        data[34..38].copy_from_slice(&2_u32.to_be_bytes());
+        data[6..10].copy_from_slice(&6_u32.to_be_bytes());
+        let grid = Grib2GridDefinition::new(&data).unwrap();
+        assert_eq!(grid.indices().len(), 6);
+
+        data[6..10].copy_from_slice(&5_u32.to_be_bytes());
+        assert_eq!(Grib2GridDefinition::new(&data), Err(GRIB2Error::InvalidSection(3)));
+        // the dimensions overflow
+        data[30..38].fill(0xff);
+        assert_eq!(Grib2GridDefinition::new(&data), Err(GRIB2Error::InvalidSection(3)));
+    }
+
+    #[test]
+    fn test_scan_modes() {
+        let mut grid = projected(Grib2Projection::Unsupported, 3, 2, 0);
+        assert_eq!(grid.indices(), vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]);
+        grid.scan_mode = SCAN_BOUSTROPHEDON;
+        assert_eq!(grid.indices(), vec![(0, 0), (1, 0), (2, 0), (2, 1), (1, 1), (0, 1)]);
+        grid.scan_mode = SCAN_J_CONSECUTIVE;
+        assert_eq!(grid.indices(), vec![(0, 0), (0, 1), (1, 0), (1, 1), (2, 0), (2, 1)]);
+        assert_eq!(grid.points(), Err(GRIB2Error::UnsupportedTemplate(3, 30)));
+    }
+
+    #[test]
+    fn test_lambert_conformal() {
+        // the HRRR CONUS grid
+        let grid = projected(
+            Grib2Projection::LambertConformal {
+                lat1: 21.138123,
+                lon1: 237.280472,
+                lad: 38.5,
+                lov: 262.5,
+                dx: 3000.,
+                dy: 3000.,
+                south_pole: false,
+                latin1: 38.5,
+                latin2: 38.5,
+            },
+            1799,
+            1059,
+            SCAN_POSITIVE_J,
+        );
+        let points = grid.points().unwrap();
+        assert_eq!(points.len(), 1799 * 1059);
+        assert_close(points[0], (237.280472, 21.138123));
+        assert_close(points[points.len() - 1], (299.082807, 47.842195));
+    }
+
+    #[test]
+    fn test_polar_stereographic() {
+        let projection = PolarStereographic::new(6_371_229., 60., 0., false);
+        let (_, y) = projection.forward(0., 60.);
+        // the second row of a 1x3 grid starting at (0, 60) passes through the pole
+        let grid = Grib2GridDefinition {
+            template: 20,
+            projection: Grib2Projection::PolarStereographic {
+                lat1: 60.,
+                lon1: 0.,
+                lad: 60.,
+                lov: 0.,
+                dx: -y,
+                dy: -y,
+                south_pole: false,
+            },
+            ..projected(Grib2Projection::Unsupported, 1, 3, SCAN_POSITIVE_J)
+        };
+        let points = grid.points().unwrap();
+        assert_close(points[0], (0., 60.));
+        assert!((points[1].1 - 90.).abs() < 1e-9);
+        assert_close(points[2], (180., 60.));
+
+        let south = PolarStereographic::new(6_371_229., -60., 0., true);
+        let (x, y) = south.forward(45., -70.);
+        assert_close(south.inverse(x, y), (45., -70.));
+    }
+}
@@ -0,0 +1,498 @@
+/// Section 5 data representation and the unpacking of section 7
+pub mod data;
+/// Section 3 grid definitions and grid point locations
+pub mod grid;
+/// Section 4 product definitions
+pub mod product;
+/// Indicator, identification, bit-map and message parsing
+pub mod sections;
+/// WMO code tables
+pub mod tables;
+
+pub use data::*;
+pub use grid::*;
+pub use product::*;
+pub use sections::*;
+pub use tables::*;
+
+use crate::{
+    geometry::{
+        VectorFeature, VectorGeometry, VectorGeometryType, VectorMultiPointGeometry, VectorPoint,
+    },
+    readers::{FeatureIterator, JpxError, Reader},
+};
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use s2json::{MValue, PrimitiveValue, ValueType};
+
+/// Errors that can occur while reading a GRIB2 file
+#[derive(Debug, Clone, PartialEq)]
+pub enum GRIB2Error {
+    /// The message doesn't start with "GRIB"
+    InvalidIndicator,
+    /// Only GRIB edition 2 is supported
+    UnsupportedEdition(u8),
+    /// The section with the number is truncated or malformed
+    InvalidSection(u8),
+    /// A section required to decode a field is missing
+    MissingSection(u8),
+    /// The (section, template) isn't supported
+    UnsupportedTemplate(u8, u16),
+    /// The packed data doesn't match its data representation
+    InvalidData,
+    /// The JPEG 2000 code stream of a field failed to decode
+    Jpeg2000(JpxError),
+}
+impl From<JpxError> for GRIB2Error {
+    fn from(err: JpxError) -> Self {
+        GRIB2Error::Jpeg2000(err)
+    }
+}
+
+/// The location of a message described by a line of a GRIB2 `.idx` file
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SectionLocation {
+    /// The byte offset of the message
+    pub start: usize,
+    /// The byte offset of the next message. `None` for the last message
+    pub end: Option<usize>,
+    /// The line of the index file, e.g. `12:1231864:d=2024042612:DZDT:0.01 mb:anl:`
+    pub line: String,
+    /// The filter that matched the line
+    pub name: Option<String>,
+}
+
+/// Parse a GRIB2 `.idx` file. Each line is split by ':' and the byte offset of the message is
+/// read from the `offset_position` (1 if not provided) piece.
+///
+/// If `filters` are provided, only the lines containing one of the filters are kept and the
+/// `n`th location is named after the `n`th filter.
+pub fn parse_idx(
+    data: &str,
+    filters: &[&str],
+    offset_position: Option<usize>,
+) -> Vec<SectionLocation> {
+    let offset_position = offset_position.unwrap_or(1);
+    let mut locations: Vec<SectionLocation> = data
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| SectionLocation {
+            start: line.split(':').nth(offset_position).and_then(|s| s.parse().ok()).unwrap_or(0),
+            end: None,
+            line: line.into(),
+            name: None,
+        })
+        .collect();
+    for i in 1..locations.len() {
+        locations[i - 1].end = Some(locations[i].start);
+    }
+    if !filters.is_empty() {
+        locations.retain(|l| filters.iter().any(|f| l.line.contains(f)));
+    }
+    for (location, filter) in locations.iter_mut().zip(filters) {
+        location.name = Some(filter.to_string());
+    }
+    locations
+}
+
+/// # GRIB2 Reader
+///
+/// ## Description
+/// Reads the messages of a GRIB2 file through the [`Reader`] trait, walking sections 0 to 8 of
+/// each message. Fields on a latitude/longitude (3.0), polar stereographic (3.20) or Lambert
+/// conformal (3.30) grid packed with simple (5.0), complex (5.2 and 5.3), IEEE (5.4) or JPEG 2000
+/// (5.40) packing are supported.
+///
+/// Implements the [`FeatureIterator`]: each distinct grid is a MultiPoint feature of the grid
+/// points as lon/lat. The values of the fields on the grid are stored in each point's M-value,
+/// keyed by the `.idx` name of the message or the index of the field. Grid points without any
+/// value are skipped. The feature's metadata is the list of product definitions of its fields.
+/// If a field fails to decode, iteration stops and the error is stored in `error`.
+///
+/// NOTE: The longitudes of the features are wrapped to [-180, 180), while
+/// [`Grib2GridDefinition::points`] keeps them in [0, 360) as stored in GRIB2. Projected grids are
+/// computed on a sphere of the grid's earth radius.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, FeatureIterator, GRIB2Reader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/grib2/fixtures/ref_simple_packing.grib2");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = GRIB2Reader::new(BufferReader::from(&data[..])).unwrap();
+/// let product = &reader.fields().next().unwrap().product;
+/// assert_eq!(product.parameter().unwrap().abbrev, "TMP");
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://codes.wmo.int/grib2>
+/// - <https://www.nco.ncep.noaa.gov/pmb/docs/grib2/grib2_doc/>
+/// - <https://github.com/NOAA-EMC/NCEPLIBS-g2c>
+#[derive(Debug, Clone, PartialEq)]
+pub struct GRIB2Reader {
+    messages: Vec<Grib2Message>,
+    /// The `.idx` name of each message if it was provided
+    names: Vec<Option<String>>,
+    /// The distinct grids that haven't been yielded yet
+    grids: Vec<Grib2GridDefinition>,
+    /// The error that stopped iteration if a field failed to decode
+    pub error: Option<GRIB2Error>,
+}
+impl GRIB2Reader {
+    /// Create a new GRIB2 reader, parsing every message of the data
+    pub fn new<T: Reader>(mut reader: T) -> Result<Self, GRIB2Error> {
+        let data = reader.slice(Some(0), Some(reader.len()));
+        let mut messages = vec![];
+        let mut offset = 0;
+        while offset < data.len() {
+            // trailing bytes that don't start a message are padding
+            if offset > 0 && !data[offset..].starts_with(b"GRIB") {
+                break;
+            }
+            let message = Grib2Message::new(&data[offset..])?;
+            offset += message.indicator.length as usize;
+            messages.push(message);
+        }
+        let names = vec![None; messages.len()];
+        Ok(Self::from_messages(messages, names))
+    }
+
+    /// Create a new GRIB2 reader, parsing only the messages at the locations found by
+    /// [`parse_idx`]
+    pub fn from_idx<T: Reader>(
+        mut reader: T,
+        locations: &[SectionLocation],
+    ) -> Result<Self, GRIB2Error> {
+        let mut messages = vec![];
+        let mut names = vec![];
+        for location in locations {
+            let end = location.end.unwrap_or(reader.len()).min(reader.len());
+            let data = reader.slice(Some(location.start), Some(end));
+            messages.push(Grib2Message::new(&data)?);
+            names.push(location.name.clone());
+        }
+        Ok(Self::from_messages(messages, names))
+    }
+
+    fn from_messages(messages: Vec<Grib2Message>, names: Vec<Option<String>>) -> Self {
+        let mut grids: Vec<Grib2GridDefinition> = vec![];
+        for field in messages.iter().flat_map(|m| &m.fields) {
+            if !grids.contains(&field.grid) {
+                grids.push(field.grid.clone());
+            }
+        }
+        grids.reverse();
+        Self { messages, names, grids, error: None }
+    }
+
+    /// The parsed messages
+    pub fn messages(&self) -> &[Grib2Message] {
+        &self.messages
+    }
+
+    /// Every field of every message
+    pub fn fields(&self) -> impl Iterator<Item = &Grib2Field> {
+        self.messages.iter().flat_map(|m| &m.fields)
+    }
+
+    /// Build the MultiPoint feature of the fields on `grid`, with longitudes in [-180, 180)
+    pub fn grid_feature(
+        &self,
+        grid: &Grib2GridDefinition,
+    ) -> Result<VectorFeature<Vec<Grib2ProductDefinition>>, GRIB2Error> {
+        let points = grid.points()?;
+        let mut values: Vec<MValue> = vec![MValue::default(); points.len()];
+        let mut products = vec![];
+        let named_fields = self
+            .messages
+            .iter()
+            .zip(&self.names)
+            .flat_map(|(m, name)| m.fields.iter().map(move |f| (f, name)))
+            .enumerate();
+        for (index, (field, name)) in named_fields {
+            if field.grid != *grid {
+                continue;
+            }
+            let key = name.clone().unwrap_or_else(|| index.to_string());
+            for (m, value) in values.iter_mut().zip(field.values()?) {
+                if let Some(value) = value {
+                    m.insert(key.clone(), ValueType::Primitive(PrimitiveValue::F64(value)));
+                }
+            }
+            products.push(field.product.clone());
+        }
+        let coordinates = points
+            .into_iter()
+            .zip(values)
+            .filter(|(_, m)| !m.is_empty())
+            .map(|((lon, lat), m)| VectorPoint::new(wrap_lon(lon), lat, None, Some(m)))
+            .collect();
+        let geometry = VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+            _type: VectorGeometryType::MultiPoint,
+            coordinates,
+            ..Default::default()
+        });
+        Ok(VectorFeature::new_wm(None, BTreeMap::new(), geometry, Some(products)))
+    }
+}
+impl Iterator for GRIB2Reader {
+    type Item = VectorFeature<Vec<Grib2ProductDefinition>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let grid = self.grids.pop()?;
+        match self.grid_feature(&grid) {
+            Ok(feature) => Some(feature),
+            Err(err) => {
+                self.error = Some(err);
+                self.grids.clear();
+                None
+            }
+        }
+    }
+}
+impl FeatureIterator<Vec<Grib2ProductDefinition>> for GRIB2Reader {
+    fn next_feature(&mut self) -> Option<VectorFeature<Vec<Grib2ProductDefinition>>> {
+        self.next()
+    }
+}
+
+/// Wrap a [0, 360) longitude into [-180, 180)
+fn wrap_lon(lon: f64) -> f64 {
+    if lon >= 180. {
+        lon - 360.
+    } else {
+        lon
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::{data::tests::BitWriter, BufferReader};
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> PathBuf {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers");
+        path.push(name);
+        path
+    }
+
+    #[test]
+    fn test_simple_packing() {
+        let data = std::fs::read(fixture("grib2/fixtures/ref_simple_packing.grib2")).unwrap();
+        let mut reader = GRIB2Reader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.messages().len(), 1);
+        let message = &reader.messages()[0];
+        assert_eq!(message.indicator.discipline_name(), Some("Meteorological Products"));
+        assert_eq!(message.identification.center, 7);
+        let field = &message.fields[0];
+        assert_eq!((field.grid.nx, field.grid.ny), (360, 181));
+        assert_eq!(field.product.surface1.and_then(|s| s.value), Some(50_000.));
+
+        let feature = reader.next_feature().unwrap();
+        assert!(reader.next_feature().is_none());
+        assert_eq!(feature.metadata.as_ref().map(|m| m.len()), Some(1));
+        let VectorGeometry::MultiPoint(geometry) = feature.geometry else { panic!() };
+        assert_eq!(geometry._type, VectorGeometryType::MultiPoint);
+        let mut coordinates = geometry.coordinates;
+        assert_eq!(coordinates.len(), 360 * 181);
+        assert!(coordinates.iter().all(|p| (-180. ..180.).contains(&p.x)));
+        // the reference longitudes are in [0, 360)
+        for point in coordinates.iter_mut() {
+            point.x = point.x.rem_euclid(360.);
+        }
+        coordinates.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+
+        let expected =
+            std::fs::read_to_string(fixture("grib2/fixtures/ref_simple_packing.grib2.spread.txt"))
+                .unwrap();
+        for (point, line) in coordinates.iter().zip(expected.lines().skip(1)) {
+            let row: Vec<f64> = line.split(',').map(|v| v.parse().unwrap()).collect();
+            let value = match point.m.as_ref().and_then(|m| m.get("0")) {
+                Some(ValueType::Primitive(PrimitiveValue::F64(v))) => *v,
+                _ => panic!("missing value"),
+            };
+            assert!((point.x - row[0]).abs() < 1e-6 && (point.y - row[1]).abs() < 1e-6);
+            assert!((value - row[2]).abs() < 0.005, "{value} != {}", row[2]);
+        }
+    }
+
+    #[test]
+    fn test_parse_idx() {
+        let data = std::fs::read_to_string(fixture(
+            "grib2/fixtures/ref_sec0.gdas.t12z.pgrb2.1p00.anl.75r.grib2.txt",
+        ))
+        .unwrap();
+        let sections =
+            parse_idx(&data, &[":DZDT:0.01 mb:", ":TMP:0.4 mb:", ":ABSV:0.4 mb:anl:"], None);
+        assert_eq!(
+            sections,
+            vec![
+                SectionLocation {
+                    start: 1231864,
+                    end: Some(1337928),
+                    line: "12:1231864:d=2024042612:DZDT:0.01 mb:anl:".into(),
+                    name: Some(":DZDT:0.01 mb:".into()),
+                },
+                SectionLocation {
+                    start: 7024838,
+                    end: Some(7122757),
+                    line: "68:7024838:d=2024042612:TMP:0.4 mb:anl:".into(),
+                    name: Some(":TMP:0.4 mb:".into()),
+                },
+                SectionLocation {
+                    start: 7710271,
+                    end: None,
+                    line: "75:7710271:d=2024042612:ABSV:0.4 mb:anl:".into(),
+                    name: Some(":ABSV:0.4 mb:anl:".into()),
+                },
+            ]
+        );
+        assert_eq!(parse_idx(&data, &[], None).len(), 75);
+    }
+
+    /// Build a message with one field on a 2x2 lat/lon grid
+    fn message(
+        template: u16,
+        count: u32,
+        packing: &[u8],
+        bitmap: Option<u8>,
+        data: &[u8],
+    ) -> Vec<u8> {
+        let mut sections = vec![];
+        let mut section = |number: u8, body: &[u8]| {
+            sections.extend((body.len() as u32 + 5).to_be_bytes());
+            sections.push(number);
+            sections.extend(body);
+        };
+        section(1, &[0, 7, 0, 0, 2, 1, 1, 0x07, 0xe8, 4, 26, 12, 0, 0, 0, 0]);
+        let mut grid = vec![0, 0, 0, 0, 4, 0, 0, 0, 0, 6];
+        grid.extend([0; 15]);
+        grid.extend(2_u32.to_be_bytes()); // nx
+        grid.extend(2_u32.to_be_bytes()); // ny
+        grid.extend([0; 8]);
+        grid.extend(10_000_000_u32.to_be_bytes()); // lat1
+        grid.extend(350_000_000_u32.to_be_bytes()); // lon1
+        grid.push(0x30);
+        grid.extend(0x8000_0000_u32.to_be_bytes()); // lat2 = -0
+        grid.extend(10_000_000_u32.to_be_bytes()); // lon2
+        grid.extend(20_000_000_u32.to_be_bytes()); // dx
+        grid.extend(10_000_000_u32.to_be_bytes()); // dy
+        grid.push(0);
+        section(3, &grid);
+        let mut product = vec![0, 0, 0, 0, 0, 0, 2, 0, 96, 0, 0, 0, 1, 0, 0, 0, 6];
+        product.extend([1, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255]);
+        section(4, &product);
+        let mut representation = vec![];
+        representation.extend(count.to_be_bytes());
+        representation.extend(template.to_be_bytes());
+        representation.extend(packing);
+        section(5, &representation);
+        match bitmap {
+            Some(bitmap) => section(6, &[0, bitmap]),
+            None => section(6, &[255]),
+        }
+        section(7, data);
+        let mut message = b"GRIB\0\0\0\x02".to_vec();
+        message.extend((sections.len() as u64 + 20).to_be_bytes());
+        message.extend(sections);
+        message.extend(b"7777");
+        message
+    }
+
+    #[test]
+    fn test_bitmap_and_ieee() {
+        // 3 values stored for a 2x2 grid where the second point is masked
+        let mut data = vec![];
+        for v in [1.5_f32, -2., 3.25] {
+            data.extend(v.to_be_bytes());
+        }
+        let bytes = message(4, 3, &[1], Some(0b1011_0000), &data);
+        let mut reader = GRIB2Reader::new(BufferReader::from(&bytes[..])).unwrap();
+        let field = reader.fields().next().unwrap();
+        assert_eq!(field.values(), Ok(vec![Some(1.5), None, Some(-2.), Some(3.25)]));
+        assert_eq!(field.grid.points(), Ok(vec![(350., 10.), (10., 10.), (350., 0.), (10., 0.)]));
+        let feature = reader.next_feature().unwrap();
+        let VectorGeometry::MultiPoint(geometry) = feature.geometry else { panic!() };
+        assert_eq!(geometry.coordinates.len(), 3);
+        assert_eq!((geometry.coordinates[1].x, geometry.coordinates[1].y), (-10., 0.));
+    }
+
+    #[test]
+    fn test_simple_packing_scaling() {
+        // R = 100, E = 1, D = 1 with 4 bit values
+        let mut packing = 100_f32.to_be_bytes().to_vec();
+        packing.extend([0, 1, 0, 1, 4, 0]);
+        let mut bits = BitWriter::default();
+        for v in [0, 1, 2, 15] {
+            bits.write(v, 4);
+        }
+        let bytes = message(0, 4, &packing, None, &bits.data);
+        let reader = GRIB2Reader::new(BufferReader::from(&bytes[..])).unwrap();
+        let values = reader.fields().next().unwrap().values().unwrap();
+        assert_eq!(values, vec![Some(10.), Some(10.2), Some(10.4), Some(13.)]);
+
+        // constant fields store no data
+        packing[8] = 0;
+        let bytes = message(0, 4, &packing, None, &[]);
+        let reader = GRIB2Reader::new(BufferReader::from(&bytes[..])).unwrap();
+        assert_eq!(reader.fields().next().unwrap().values(), Ok(vec![Some(10.); 4]));
+
+        let bytes = message(50, 4, &packing, None, &[]);
+        let mut reader = GRIB2Reader::new(BufferReader::from(&bytes[..])).unwrap();
+        let field = reader.fields().next().unwrap();
+        assert_eq!(field.values(), Err(GRIB2Error::UnsupportedTemplate(5, 50)));
+        assert!(reader.next_feature().is_none());
+        assert_eq!(reader.error, Some(GRIB2Error::UnsupportedTemplate(5, 50)));
+        assert_eq!(
+            GRIB2Reader::new(BufferReader::from(&bytes[4..])),
+            Err(GRIB2Error::InvalidIndicator)
+        );
+    }
+
+    #[test]
+    fn test_message_lengths() {
+        let mut bytes = message(4, 4, &[1], None, &[0; 16]);
+        let length = bytes.len() as u64;
+        for bad in [0, 15, length + 1] {
+            bytes[8..16].copy_from_slice(&bad.to_be_bytes());
+            assert_eq!(
+                GRIB2Reader::new(BufferReader::from(&bytes[..])),
+                Err(GRIB2Error::InvalidSection(0))
+            );
+        }
+        bytes[8..16].copy_from_slice(&length.to_be_bytes());
+        let mut padded = bytes.repeat(2);
+        padded.extend([0; 8]);
+        let reader = GRIB2Reader::new(BufferReader::from(&padded[..])).unwrap();
+        assert_eq!(reader.messages().len(), 2);
+    }
+
+    #[test]
+    fn test_jpeg2000_packing() {
+        let codestream = std::fs::read(fixture("image/jpeg2000/fixtures/input.j2k")).unwrap();
+        let expected = std::fs::read(fixture("image/jpeg2000/fixtures/expected.raw")).unwrap();
+        let representation = Grib2DataRepresentation {
+            number_of_values: 63677,
+            template: 40,
+            packing: Grib2Packing::Jpeg2000 {
+                simple: Grib2SimplePacking { binary_scale: 1, bits: 8, ..Default::default() },
+                compression_type: 0,
+                compression_ratio: 255,
+            },
+        };
+        let expected: Vec<Option<f64>> =
+            expected.into_iter().map(|v| Some(v as f64 * 2.)).collect();
+        assert_eq!(representation.unpack(&codestream), Ok(expected));
+    }
+}
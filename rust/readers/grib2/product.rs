@@ -0,0 +1,174 @@
+use super::{
+    grib2_category, grib2_generating_process, grib2_parameter, grib2_product_template,
+    grib2_surface, grib2_time_unit, sections::Octets, GRIB2Error, Grib2Parameter,
+};
+
+use libm::pow;
+
+/// A fixed surface (a level or layer boundary) of a product
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grib2FixedSurface {
+    /// Type of fixed surface (Code Table 4.5)
+    pub surface_type: u8,
+    /// The scaled value of the surface. `None` if it's missing
+    pub value: Option<f64>,
+}
+impl Grib2FixedSurface {
+    /// Read the type, scale factor and scaled value starting at `offset`. A missing surface type
+    /// returns `None`
+    fn new(section: &Octets, offset: usize) -> Result<Option<Self>, GRIB2Error> {
+        let surface_type = section.u8(offset)?;
+        if surface_type == 255 {
+            return Ok(None);
+        }
+        let value = if section.u8(offset + 1)? == 255 || section.u32(offset + 2)? == u32::MAX {
+            None
+        } else {
+            let scale = section.i8(offset + 1)?;
+            Some(section.i32(offset + 2)? as f64 / pow(10., scale as f64))
+        };
+        Ok(Some(Self { surface_type, value }))
+    }
+
+    /// The name of the surface type (Code Table 4.5)
+    pub fn name(&self) -> Option<&'static str> {
+        grib2_surface(self.surface_type).map(|s| s.name)
+    }
+
+    /// The unit of the surface's value (Code Table 4.5)
+    pub fn unit(&self) -> Option<&'static str> {
+        grib2_surface(self.surface_type).map(|s| s.unit)
+    }
+}
+
+/// Section 4: Product Definition Section
+///
+/// The parameter is read for every template. The generating process, forecast time and fixed
+/// surfaces are read for the horizontal level templates 4.0 to 4.15 which share the same layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grib2ProductDefinition {
+    /// Discipline of the message (Code Table 0.0), needed to look up the parameter
+    pub discipline: u8,
+    /// Number of coordinate values after the template
+    pub coordinate_values: u16,
+    /// Product definition template number (Code Table 4.0)
+    pub template: u16,
+    /// Parameter category (Code Table 4.1)
+    pub category: u8,
+    /// Parameter number (Code Table 4.2)
+    pub number: u8,
+    /// Type of generating process (Code Table 4.3)
+    pub generating_process: Option<u8>,
+    /// Background generating process identifier
+    pub background_process: Option<u8>,
+    /// Analysis or forecast generating process identifier
+    pub forecast_process: Option<u8>,
+    /// Indicator of unit of time range (Code Table 4.4)
+    pub time_unit: Option<u8>,
+    /// Forecast time in units of `time_unit`
+    pub forecast_time: Option<u32>,
+    /// First fixed surface
+    pub surface1: Option<Grib2FixedSurface>,
+    /// Second fixed surface
+    pub surface2: Option<Grib2FixedSurface>,
+    /// Perturbation number of an ensemble forecast (templates 4.1 and 4.11)
+    pub perturbation_number: Option<u8>,
+    /// Statistical process of the time interval (Code Table 4.10, templates 4.8 and 4.11)
+    pub statistical_process: Option<u8>,
+}
+impl Grib2ProductDefinition {
+    /// Parse section 4
+    pub fn new(data: &[u8], discipline: u8) -> Result<Self, GRIB2Error> {
+        let section = Octets::new(data, 4);
+        let template = section.u16(7)?;
+        let mut product = Self {
+            discipline,
+            coordinate_values: section.u16(5)?,
+            template,
+            category: section.u8(9)?,
+            number: section.u8(10)?,
+            generating_process: None,
+            background_process: None,
+            forecast_process: None,
+            time_unit: None,
+            forecast_time: None,
+            surface1: None,
+            surface2: None,
+            perturbation_number: None,
+            statistical_process: None,
+        };
+        if template > 15 {
+            return Ok(product);
+        }
+        product.generating_process = Some(section.u8(11)?);
+        product.background_process = Some(section.u8(12)?);
+        product.forecast_process = Some(section.u8(13)?);
+        product.time_unit = Some(section.u8(17)?);
+        product.forecast_time = Some(section.u32(18)?);
+        product.surface1 = Grib2FixedSurface::new(&section, 22)?;
+        product.surface2 = Grib2FixedSurface::new(&section, 28)?;
+        match template {
+            1 => product.perturbation_number = Some(section.u8(35)?),
+            8 => product.statistical_process = Some(section.u8(46)?),
+            11 => {
+                product.perturbation_number = Some(section.u8(35)?);
+                product.statistical_process = Some(section.u8(49)?);
+            }
+            _ => {}
+        }
+        Ok(product)
+    }
+
+    /// The name of the product definition template (Code Table 4.0)
+    pub fn template_name(&self) -> Option<&'static str> {
+        grib2_product_template(self.template)
+    }
+
+    /// The name of the parameter category (Code Table 4.1)
+    pub fn category_name(&self) -> Option<&'static str> {
+        grib2_category(self.discipline, self.category)
+    }
+
+    /// The parameter's name, units and abbreviation (Code Table 4.2)
+    pub fn parameter(&self) -> Option<Grib2Parameter> {
+        grib2_parameter(self.discipline, self.category, self.number)
+    }
+
+    /// The name of the generating process (Code Table 4.3)
+    pub fn generating_process_name(&self) -> Option<&'static str> {
+        self.generating_process.and_then(grib2_generating_process)
+    }
+
+    /// The name of the unit of the forecast time (Code Table 4.4)
+    pub fn time_unit_name(&self) -> Option<&'static str> {
+        self.time_unit.and_then(grib2_time_unit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_product_definition() {
+        // template 4.0: temperature at 500 hPa, 6 hour forecast
+        let mut data = vec![0, 0, 0, 34, 4, 0, 0, 0, 0, 0, 0, 2, 0, 96, 0, 0, 0, 1, 0, 0, 0, 6];
+        data.extend([100, 0, 0, 0, 0xc3, 0x50, 255, 255, 255, 255, 255, 255]);
+        let product = Grib2ProductDefinition::new(&data, 0).unwrap();
+        assert_eq!(product.parameter().map(|p| p.abbrev), Some("TMP"));
+        assert_eq!(product.category_name(), Some("Temperature (see Table 4.2-0-0)"));
+        assert_eq!(product.forecast_time, Some(6));
+        assert_eq!(product.time_unit_name(), Some("Hour"));
+        let surface = product.surface1.unwrap();
+        assert_eq!(surface.value, Some(50_000.));
+        assert_eq!(surface.unit(), Some("Pa"));
+        assert_eq!(product.surface2, None);
+
+        // a negative scale factor
+        data[23] = 0x81;
+        let product = Grib2ProductDefinition::new(&data, 0).unwrap();
+        assert_eq!(product.surface1.unwrap().value, Some(500_000.));
+        assert_eq!(Grib2ProductDefinition::new(&data[..20], 0), Err(GRIB2Error::InvalidSection(4)));
+    }
+}
@@ -0,0 +1,316 @@
+use super::{
+    grib2_center, grib2_data_type, grib2_discipline, grib2_production_status,
+    grib2_reference_time_significance, GRIB2Error, Grib2DataRepresentation, Grib2GridDefinition,
+    Grib2ProductDefinition,
+};
+
+use alloc::{vec, vec::Vec};
+
+/// Big endian reads of a section's octets. Offsets are 0-based from the start of the section
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Octets<'a> {
+    data: &'a [u8],
+    section: u8,
+}
+impl<'a> Octets<'a> {
+    pub(crate) fn new(data: &'a [u8], section: u8) -> Self {
+        Self { data, section }
+    }
+
+    fn array<const N: usize>(&self, offset: usize) -> Result<[u8; N], GRIB2Error> {
+        self.data
+            .get(offset..offset + N)
+            .and_then(|b| b.try_into().ok())
+            .ok_or(GRIB2Error::InvalidSection(self.section))
+    }
+
+    pub(crate) fn u8(&self, offset: usize) -> Result<u8, GRIB2Error> {
+        Ok(self.array::<1>(offset)?[0])
+    }
+
+    pub(crate) fn u16(&self, offset: usize) -> Result<u16, GRIB2Error> {
+        Ok(u16::from_be_bytes(self.array(offset)?))
+    }
+
+    pub(crate) fn u32(&self, offset: usize) -> Result<u32, GRIB2Error> {
+        Ok(u32::from_be_bytes(self.array(offset)?))
+    }
+
+    pub(crate) fn u64(&self, offset: usize) -> Result<u64, GRIB2Error> {
+        Ok(u64::from_be_bytes(self.array(offset)?))
+    }
+
+    pub(crate) fn f32(&self, offset: usize) -> Result<f32, GRIB2Error> {
+        Ok(f32::from_be_bytes(self.array(offset)?))
+    }
+
+    /// GRIB2 signed integers store the sign in the most significant bit (sign-magnitude)
+    pub(crate) fn i8(&self, offset: usize) -> Result<i32, GRIB2Error> {
+        Ok(sign_magnitude(self.u8(offset)? as u32, 8))
+    }
+
+    /// A sign-magnitude 16-bit integer
+    pub(crate) fn i16(&self, offset: usize) -> Result<i32, GRIB2Error> {
+        Ok(sign_magnitude(self.u16(offset)? as u32, 16))
+    }
+
+    /// A sign-magnitude 32-bit integer
+    pub(crate) fn i32(&self, offset: usize) -> Result<i64, GRIB2Error> {
+        let value = self.u32(offset)?;
+        let magnitude = (value & 0x7fff_ffff) as i64;
+        Ok(if value & 0x8000_0000 != 0 { -magnitude } else { magnitude })
+    }
+}
+
+/// Convert a `bits` wide sign-magnitude integer
+pub(crate) fn sign_magnitude(value: u32, bits: u32) -> i32 {
+    let sign = 1 << (bits - 1);
+    let magnitude = (value & (sign - 1)) as i32;
+    if value & sign != 0 {
+        -magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Section 0: Indicator Section
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Grib2IndicatorSection {
+    /// Discipline of the processed data (Code Table 0.0)
+    pub discipline: u8,
+    /// The GRIB edition, always 2
+    pub edition: u8,
+    /// The total length of the message in octets
+    pub length: u64,
+}
+impl Grib2IndicatorSection {
+    /// Parse the 16 octets of section 0. `data` must hold the whole message
+    pub fn new(data: &[u8]) -> Result<Self, GRIB2Error> {
+        let section = Octets::new(data, 0);
+        if data.get(0..4) != Some(b"GRIB") {
+            return Err(GRIB2Error::InvalidIndicator);
+        }
+        let edition = section.u8(7)?;
+        if edition != 2 {
+            return Err(GRIB2Error::UnsupportedEdition(edition));
+        }
+        let length = section.u64(8)?;
+        if length < 16 || length > data.len() as u64 {
+            return Err(GRIB2Error::InvalidSection(0));
+        }
+        Ok(Self { discipline: section.u8(6)?, edition, length })
+    }
+
+    /// The name of the discipline (Code Table 0.0)
+    pub fn discipline_name(&self) -> Option<&'static str> {
+        grib2_discipline(self.discipline)
+    }
+}
+
+/// Section 1: Identification Section
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Grib2IdentificationSection {
+    /// Identification of the originating/generating centre (Common Code Table C-11)
+    pub center: u16,
+    /// Identification of the originating/generating sub-centre
+    pub subcenter: u16,
+    /// GRIB master tables version number (Code Table 1.0)
+    pub master_tables_version: u8,
+    /// Version number of the GRIB local tables (Code Table 1.1)
+    pub local_tables_version: u8,
+    /// Significance of the reference time (Code Table 1.2)
+    pub reference_time_significance: u8,
+    /// Year of the reference time
+    pub year: u16,
+    /// Month of the reference time
+    pub month: u8,
+    /// Day of the reference time
+    pub day: u8,
+    /// Hour of the reference time
+    pub hour: u8,
+    /// Minute of the reference time
+    pub minute: u8,
+    /// Second of the reference time
+    pub second: u8,
+    /// Production status of the processed data (Code Table 1.3)
+    pub production_status: u8,
+    /// Type of the processed data (Code Table 1.4)
+    pub data_type: u8,
+}
+impl Grib2IdentificationSection {
+    /// Parse section 1
+    pub fn new(data: &[u8]) -> Result<Self, GRIB2Error> {
+        let section = Octets::new(data, 1);
+        Ok(Self {
+            center: section.u16(5)?,
+            subcenter: section.u16(7)?,
+            master_tables_version: section.u8(9)?,
+            local_tables_version: section.u8(10)?,
+            reference_time_significance: section.u8(11)?,
+            year: section.u16(12)?,
+            month: section.u8(14)?,
+            day: section.u8(15)?,
+            hour: section.u8(16)?,
+            minute: section.u8(17)?,
+            second: section.u8(18)?,
+            production_status: section.u8(19)?,
+            data_type: section.u8(20)?,
+        })
+    }
+
+    /// The name of the originating centre (Common Code Table C-11)
+    pub fn center_name(&self) -> Option<&'static str> {
+        grib2_center(self.center)
+    }
+
+    /// The significance of the reference time (Code Table 1.2)
+    pub fn reference_time_significance_name(&self) -> Option<&'static str> {
+        grib2_reference_time_significance(self.reference_time_significance)
+    }
+
+    /// The production status (Code Table 1.3)
+    pub fn production_status_name(&self) -> Option<&'static str> {
+        grib2_production_status(self.production_status)
+    }
+
+    /// The type of data (Code Table 1.4)
+    pub fn data_type_name(&self) -> Option<&'static str> {
+        grib2_data_type(self.data_type)
+    }
+}
+
+/// A field of a GRIB2 message: sections 3 to 7 describing one gridded product
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grib2Field {
+    /// The discipline of the message the field belongs to (Code Table 0.0)
+    pub discipline: u8,
+    /// Section 2: Local Use Section, if one applies to the field
+    pub local: Option<Vec<u8>>,
+    /// Section 3: Grid Definition Section
+    pub grid: Grib2GridDefinition,
+    /// Section 4: Product Definition Section
+    pub product: Grib2ProductDefinition,
+    /// Section 5: Data Representation Section
+    pub data_representation: Grib2DataRepresentation,
+    /// Section 6: the bit-map, one bit per grid point (MSB first) where 1 means the value is
+    /// present. `None` if every grid point has a value
+    pub bitmap: Option<Vec<u8>>,
+    /// Section 7: the packed data
+    pub data: Vec<u8>,
+}
+impl Grib2Field {
+    /// Unpack the field's values in the grid's scan order. Grid points without a value (masked
+    /// by the bit-map or flagged missing by complex packing) are `None`
+    pub fn values(&self) -> Result<Vec<Option<f64>>, GRIB2Error> {
+        let packed = self.data_representation.unpack(&self.data)?;
+        let count = self.grid.number_of_points as usize;
+        match &self.bitmap {
+            None => {
+                if packed.len() < count {
+                    return Err(GRIB2Error::InvalidData);
+                }
+                Ok(packed.into_iter().take(count).collect())
+            }
+            Some(bitmap) => {
+                let mut packed = packed.into_iter();
+                let mut values = Vec::with_capacity(count);
+                for i in 0..count {
+                    let present = bitmap.get(i >> 3).is_some_and(|b| (b >> (7 - (i & 7))) & 1 == 1);
+                    values.push(if present {
+                        packed.next().ok_or(GRIB2Error::InvalidData)?
+                    } else {
+                        None
+                    });
+                }
+                Ok(values)
+            }
+        }
+    }
+}
+
+/// # GRIB2 Message
+///
+/// ## Description
+/// A single GRIB2 message, from the "GRIB" indicator to the "7777" end section. A message may
+/// repeat sections 2 to 7 (or 3 to 7, or 4 to 7) to store several fields, so each field is stored
+/// with the sections that apply to it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grib2Message {
+    /// Section 0: Indicator Section
+    pub indicator: Grib2IndicatorSection,
+    /// Section 1: Identification Section
+    pub identification: Grib2IdentificationSection,
+    /// The fields, one per data section
+    pub fields: Vec<Grib2Field>,
+}
+impl Grib2Message {
+    /// Parse a message. `data` must start with the indicator section and may be longer than the
+    /// message
+    pub fn new(data: &[u8]) -> Result<Self, GRIB2Error> {
+        let indicator = Grib2IndicatorSection::new(data)?;
+        let end = indicator.length as usize;
+        let mut identification = None;
+        let mut local = None;
+        let mut grid = None;
+        let mut product = None;
+        let mut data_representation = None;
+        let mut bitmap = None;
+        let mut previous_bitmap = None;
+        let mut fields = vec![];
+
+        let mut offset = 16;
+        while offset + 4 <= end {
+            if &data[offset..offset + 4] == b"7777" {
+                break;
+            }
+            let header = Octets::new(&data[offset..end], 255);
+            let length = header.u32(0)? as usize;
+            let number = header.u8(4)?;
+            if length < 5 || offset + length > end {
+                return Err(GRIB2Error::InvalidSection(number));
+            }
+            let section = &data[offset..offset + length];
+            match number {
+                1 => identification = Some(Grib2IdentificationSection::new(section)?),
+                2 => local = Some(section[5..].to_vec()),
+                3 => grid = Some(Grib2GridDefinition::new(section)?),
+                4 => product = Some(Grib2ProductDefinition::new(section, indicator.discipline)?),
+                5 => data_representation = Some(Grib2DataRepresentation::new(section)?),
+                6 => {
+                    bitmap = match Octets::new(section, 6).u8(5)? {
+                        0 => {
+                            previous_bitmap = Some(section[6..].to_vec());
+                            previous_bitmap.clone()
+                        }
+                        // a previously defined bit-map applies
+                        254 => Some(previous_bitmap.clone().ok_or(GRIB2Error::MissingSection(6))?),
+                        255 => None,
+                        // predefined bit-maps aren't supported
+                        indicator => {
+                            return Err(GRIB2Error::UnsupportedTemplate(6, indicator as u16))
+                        }
+                    };
+                }
+                7 => fields.push(Grib2Field {
+                    discipline: indicator.discipline,
+                    local: local.clone(),
+                    grid: grid.clone().ok_or(GRIB2Error::MissingSection(3))?,
+                    product: product.clone().ok_or(GRIB2Error::MissingSection(4))?,
+                    data_representation: data_representation
+                        .clone()
+                        .ok_or(GRIB2Error::MissingSection(5))?,
+                    bitmap: bitmap.clone(),
+                    data: section[5..].to_vec(),
+                }),
+                _ => return Err(GRIB2Error::InvalidSection(number)),
+            }
+            offset += length;
+        }
+
+        Ok(Self {
+            indicator,
+            identification: identification.ok_or(GRIB2Error::MissingSection(1))?,
+            fields,
+        })
+    }
+}
@@ -0,0 +1,11324 @@
+/// A parameter of Code Table 4.2
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grib2Parameter {
+    /// The parameter's description, e.g. "Temperature"
+    pub parameter: &'static str,
+    /// The parameter's units, e.g. "K"
+    pub units: &'static str,
+    /// The parameter's abbreviation, e.g. "TMP"
+    pub abbrev: &'static str,
+}
+
+/// A surface type of Code Table 4.5
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Grib2Surface {
+    /// The surface's description, e.g. "Isobaric Surface"
+    pub name: &'static str,
+    /// The units of the surface's value, e.g. "Pa"
+    pub unit: &'static str,
+}
+
+fn lookup<K: Ord + Copy, V: Copy>(table: &[(K, V)], key: K) -> Option<V> {
+    table.binary_search_by(|(k, _)| k.cmp(&key)).ok().map(|i| table[i].1)
+}
+
+/// Look up Common Code Table C-11: originating/generating centre
+pub fn grib2_center(code: u16) -> Option<&'static str> {
+    lookup(CENTERS, code)
+}
+
+/// Look up Code Table 0.0: discipline of processed data
+pub fn grib2_discipline(code: u8) -> Option<&'static str> {
+    lookup(DISCIPLINES, code)
+}
+
+/// Look up Code Table 1.2: significance of reference time
+pub fn grib2_reference_time_significance(code: u8) -> Option<&'static str> {
+    lookup(REFERENCE_TIME_SIGNIFICANCES, code)
+}
+
+/// Look up Code Table 1.3: production status of data
+pub fn grib2_production_status(code: u8) -> Option<&'static str> {
+    lookup(PRODUCTION_STATUSES, code)
+}
+
+/// Look up Code Table 1.4: type of data
+pub fn grib2_data_type(code: u8) -> Option<&'static str> {
+    lookup(DATA_TYPES, code)
+}
+
+/// Look up Code Table 3.1: grid definition template number
+pub fn grib2_grid_template(code: u16) -> Option<&'static str> {
+    lookup(GRID_TEMPLATES, code)
+}
+
+/// Look up Code Table 3.2: shape of the reference system
+pub fn grib2_earth_shape(code: u8) -> Option<&'static str> {
+    lookup(EARTH_SHAPES, code)
+}
+
+/// Look up Code Table 4.0: product definition template number
+pub fn grib2_product_template(code: u16) -> Option<&'static str> {
+    lookup(PRODUCT_TEMPLATES, code)
+}
+
+/// Look up Code Table 4.3: type of generating process
+pub fn grib2_generating_process(code: u8) -> Option<&'static str> {
+    lookup(GENERATING_PROCESSES, code)
+}
+
+/// Look up Code Table 4.4: indicator of unit of time range
+pub fn grib2_time_unit(code: u8) -> Option<&'static str> {
+    lookup(TIME_UNITS, code)
+}
+
+/// Look up Code Table 5.0: data representation template number
+pub fn grib2_data_representation_template(code: u16) -> Option<&'static str> {
+    lookup(DATA_REPRESENTATION_TEMPLATES, code)
+}
+
+/// Look up Code Table 5.1: type of original field values
+pub fn grib2_original_field_type(code: u8) -> Option<&'static str> {
+    lookup(ORIGINAL_FIELD_TYPES, code)
+}
+
+/// Look up Code Table 4.1: the parameter category of a discipline
+pub fn grib2_category(discipline: u8, category: u8) -> Option<&'static str> {
+    lookup(CATEGORIES, (discipline, category))
+}
+
+/// Look up Code Table 4.2: the parameter of a discipline's category
+pub fn grib2_parameter(discipline: u8, category: u8, number: u8) -> Option<Grib2Parameter> {
+    lookup(PARAMETERS, (discipline, category, number))
+}
+
+/// Look up Code Table 4.5: fixed surface types and units
+pub fn grib2_surface(code: u8) -> Option<Grib2Surface> {
+    lookup(SURFACES, code)
+}
+
+const CENTERS: &[(u16, &str)] = &[
+    (1, "Melbourne (WMC)"),
+    (2, "Melbourne (WMC)"),
+    (3, "Melbourne (WMC)"),
+    (4, "Moscow (WMC)"),
+    (5, "Moscow (WMC)"),
+    (6, "Moscow (WMC)"),
+    (7, "US National Weather Service - NCEP (WMC)"),
+    (8, "US National Weather Service - NWSTG (WMC)"),
+    (9, "US National Weather Service - Other (WMC)"),
+    (10, "Cairo (RSMC/RAFC)"),
+    (11, "Cairo (RSMC/RAFC)"),
+    (12, "Dakar (RSMC/RAFC)"),
+    (13, "Dakar (RSMC/RAFC)"),
+    (14, "Nairobi (RSMC/RAFC)"),
+    (15, "Nairobi (RSMC/RAFC)"),
+    (16, "Casablanca (RSMC)"),
+    (17, "Tunis (RSMC)"),
+    (18, "Tunis-Casablanca (RSMC)"),
+    (19, "Tunis-Casablanca (RSMC)"),
+    (20, "Las Palmas (RAFC)"),
+    (21, "Algiers (RSMC)"),
+    (22, "ACMAD"),
+    (23, "Mozambique (NMC)"),
+    (24, "Pretoria (RSMC)"),
+    (25, "La Reunion (RSMC)"),
+    (26, "Khabarovsk (RSMC)"),
+    (27, "Khabarovsk (RSMC)"),
+    (28, "New Delhi (RSMC/RAFC)"),
+    (29, "New Delhi (RSMC/RAFC)"),
+    (30, "Novosibirsk (RSMC)"),
+    (31, "Novosibirsk (RSMC)"),
+    (32, "Tashkent (RSMC)"),
+    (33, "Jeddah (RSMC)"),
+    (34, "Tokyo (RSMC), Japanese Meteorological Agency"),
+    (35, "Tokyo (RSMC), Japanese Meteorological Agency"),
+    (36, "Bankok"),
+    (37, "Ulan Bator"),
+    (38, "Beijing (RSMC)"),
+    (39, "Beijing (RSMC)"),
+    (40, "Seoul"),
+    (41, "Buenos Aires (RSMC/RAFC)"),
+    (42, "Buenos Aires (RSMC/RAFC)"),
+    (43, "Brasilia (RSMC/RAFC)"),
+    (44, "Brasilia (RSMC/RAFC)"),
+    (45, "Santiago"),
+    (46, "Brazilian Space Agency - INPE"),
+    (47, "Columbia (NMC)"),
+    (48, "Ecuador (NMC)"),
+    (49, "Peru (NMC)"),
+    (50, "Venezuela (NMC)"),
+    (51, "Miami (RSMC/RAFC)"),
+    (52, "Miami (RSMC), National Hurricane Center"),
+    (53, "Canadian Meteorological Service - Montreal (RSMC)"),
+    (54, "Canadian Meteorological Service - Montreal (RSMC)"),
+    (55, "San Francisco"),
+    (56, "ARINC Center"),
+    (57, "US Air Force - Air Force Global Weather Center"),
+    (58, "Fleet Numerical Meteorology and Oceanography Center,Monterey,CA,USA"),
+    (59, "The NOAA Forecast Systems Lab, Boulder, CO, USA"),
+    (60, "National Center for Atmospheric Research (NCAR), Boulder, CO"),
+    (61, "Service ARGOS - Landover, MD, USA"),
+    (62, "US Naval Oceanographic Office"),
+    (63, "International Research Institude for Climate and Society"),
+    (64, "Honolulu"),
+    (65, "Darwin (RSMC)"),
+    (66, "Darwin (RSMC)"),
+    (67, "Melbourne (RSMC)"),
+    (69, "Wellington (RSMC/RAFC)"),
+    (70, "Wellington (RSMC/RAFC)"),
+    (71, "Nadi (RSMC)"),
+    (72, "Singapore"),
+    (73, "Malaysia (NMC)"),
+    (74, "U.K. Met Office - Exeter (RSMC)"),
+    (75, "U.K. Met Office - Exeter (RSMC)"),
+    (76, "Moscow (RSMC/RAFC)"),
+    (78, "Offenbach (RSMC)"),
+    (79, "Offenbach (RSMC)"),
+    (80, "Rome (RSMC)"),
+    (81, "Rome (RSMC)"),
+    (82, "Norrkoping"),
+    (83, "Norrkoping"),
+    (84, "French Weather Service - Toulouse"),
+    (85, "French Weather Service - Toulouse"),
+    (86, "Helsinki"),
+    (87, "Belgrade"),
+    (88, "Oslo"),
+    (89, "Prague"),
+    (90, "Episkopi"),
+    (91, "Ankara"),
+    (92, "Frankfurt/Main (RAFC)"),
+    (93, "London (WAFC)"),
+    (94, "Copenhagen"),
+    (95, "Rota"),
+    (96, "Athens"),
+    (97, "European Space Agency (ESA)"),
+    (98, "European Center for Medium-Range Weather Forecasts (RSMC)"),
+    (99, "De Bilt, Netherlands"),
+    (100, "Brazzaville"),
+    (101, "Abidjan"),
+    (102, "Libyan Arab Jamahiriya (NMC)"),
+    (103, "Madagascar (NMC)"),
+    (104, "Mauritius (NMC)"),
+    (105, "Niger (NMC)"),
+    (106, "Seychelles (NMC)"),
+    (107, "Uganda (NMC)"),
+    (108, "United Republic of Tanzania (NMC)"),
+    (109, "Zimbabwe (NMC)"),
+    (110, "Hong-Kong"),
+    (111, "Afghanistan (NMC)"),
+    (112, "Bahrain (NMC)"),
+    (113, "Bangladesh (NMC)"),
+    (114, "Bhutan (NMC)"),
+    (115, "Cambodia (NMC)"),
+    (116, "Democratic People's Republic of Korea (NMC)"),
+    (117, "Islamic Republic of Iran (NMC)"),
+    (118, "Iraq (NMC)"),
+    (119, "Kazakhstan (NMC)"),
+    (120, "Kuwait (NMC)"),
+    (121, "Kyrgyz Republic (NMC)"),
+    (122, "Lao People's Democratic Republic (NMC)"),
+    (123, "Macao, China"),
+    (124, "Maldives (NMC)"),
+    (125, "Myanmar (NMC)"),
+    (126, "Nepal (NMC)"),
+    (127, "Oman (NMC)"),
+    (128, "Pakistan (NMC)"),
+    (129, "Qatar (NMC)"),
+    (130, "Yemen (NMC)"),
+    (131, "Sri Lanka (NMC)"),
+    (132, "Tajikistan (NMC)"),
+    (133, "Turkmenistan (NMC)"),
+    (134, "United Arab Emirates (NMC)"),
+    (135, "Uzbekistan (NMC)"),
+    (136, "Viet Nam (NMC)"),
+    (140, "Bolivia (NMC)"),
+    (141, "Guyana (NMC)"),
+    (142, "Paraguay (NMC)"),
+    (143, "Suriname (NMC)"),
+    (144, "Uruguay (NMC)"),
+    (145, "French Guyana"),
+    (146, "Brazilian Navy Hydrographic Center"),
+    (147, "National Commission on Space Activities - Argentina"),
+    (148, "Brazilian Department of Airspace Control - DECEA"),
+    (150, "Antigua and Barbuda (NMC)"),
+    (151, "Bahamas (NMC)"),
+    (152, "Barbados (NMC)"),
+    (153, "Belize (NMC)"),
+    (154, "British Caribbean Territories Center"),
+    (155, "San Jose"),
+    (156, "Cuba (NMC)"),
+    (157, "Dominica (NMC)"),
+    (158, "Dominican Republic (NMC)"),
+    (159, "El Salvador (NMC)"),
+    (160, "US NOAA/NESDIS"),
+    (161, "US NOAA Office of Oceanic and Atmospheric Research"),
+    (162, "Guatemala (NMC)"),
+    (163, "Haiti (NMC)"),
+    (164, "Honduras (NMC)"),
+    (165, "Jamaica (NMC)"),
+    (166, "Mexico City"),
+    (167, "Netherlands Antilles and Aruba (NMC)"),
+    (168, "Nicaragua (NMC)"),
+    (169, "Panama (NMC)"),
+    (170, "Saint Lucia (NMC)"),
+    (171, "Trinidad and Tobago (NMC)"),
+    (172, "French Departments in RA IV"),
+    (173, "US National Aeronautics and Space Administration (NASA)"),
+    (
+        174,
+        "Integrated System Data Management/Marine Environmental Data Service (ISDM/MEDS) - Canada",
+    ),
+    (176, "US Cooperative Institude for Meteorological Satellite Studies"),
+    (190, "Cook Islands (NMC)"),
+    (191, "French Polynesia (NMC)"),
+    (192, "Tonga (NMC)"),
+    (193, "Vanuatu (NMC)"),
+    (194, "Brunei (NMC)"),
+    (195, "Indonesia (NMC)"),
+    (196, "Kiribati (NMC)"),
+    (197, "Federated States of Micronesia (NMC)"),
+    (198, "New Caledonia (NMC)"),
+    (199, "Niue"),
+    (200, "Papua New Guinea (NMC)"),
+    (201, "Philippines (NMC)"),
+    (202, "Samoa (NMC)"),
+    (203, "Solomon Islands (NMC)"),
+    (204, "Narional Institude of Water and Atmospheric Research - New Zealand"),
+    (210, "Frascati (ESA/ESRIN)"),
+    (211, "Lanion"),
+    (212, "Lisbon"),
+    (213, "Reykjavik"),
+    (214, "Madrid"),
+    (215, "Zurich"),
+    (216, "Service ARGOS - Toulouse"),
+    (217, "Bratislava"),
+    (218, "Budapest"),
+    (219, "Ljubljana"),
+    (220, "Warsaw"),
+    (221, "Zagreb"),
+    (222, "Albania (NMC)"),
+    (223, "Armenia (NMC)"),
+    (224, "Austria (NMC)"),
+    (225, "Azerbaijan (NMC)"),
+    (226, "Belarus (NMC)"),
+    (227, "Belgium (NMC)"),
+    (228, "Bosnia and Herzegovina (NMC)"),
+    (229, "Bulgaria (NMC)"),
+    (230, "Cyprus (NMC)"),
+    (231, "Estonia (NMC)"),
+    (232, "Georgia (NMC)"),
+    (233, "Dublin"),
+    (234, "Israel (NMC)"),
+    (235, "Jordan (NMC)"),
+    (236, "Latvia (NMC)"),
+    (237, "Lebanon (NMC)"),
+    (238, "Lithuania (NMC)"),
+    (239, "Luxembourg"),
+    (240, "Malta (NMC)"),
+    (241, "Monaco"),
+    (242, "Romania (NMC)"),
+    (243, "Syrian Arab Republic (NMC)"),
+    (244, "The former Yugoslav Republic of Macedonia (NMC)"),
+    (245, "Ukraine (NMC)"),
+    (246, "Republic of Moldova (NMC)"),
+    (247, "Operational Programme for the Exchange of Weather RAdar Information (OPERA) - EUMETNET"),
+    (250, "COnsortium for Small scale  MOdelling (COSMO)"),
+    (254, "EUMETSAT Operations Center"),
+    (255, "Missing Value"),
+];
+
+const DISCIPLINES: &[(u8, &str)] = &[
+    (0, "Meteorological Products"),
+    (1, "Hydrological Products"),
+    (2, "Land Surface Products"),
+    (3, "Satellite Remote Sensing Products (formerly \"Space Products\")"),
+    (4, "Space Weather Products"),
+    (10, "Oceanographic Products"),
+];
+
+const REFERENCE_TIME_SIGNIFICANCES: &[(u8, &str)] = &[
+    (0, "Analysis"),
+    (1, "Start of Forecast"),
+    (2, "Verifying Time of Forecast"),
+    (3, "Observation Time"),
+    (4, "Local Time"),
+    (5, "Simulation start"),
+    (255, "Missing"),
+];
+
+const PRODUCTION_STATUSES: &[(u8, &str)] = &[
+    (0, "Operational Products"),
+    (1, "Operational Test Products"),
+    (2, "Research Products"),
+    (3, "Re-Analysis Products"),
+    (4, "THORPEX Interactive Grand Global Ensemble (TIGGE)"),
+    (5, "THORPEX Interactive Grand Global Ensemble (TIGGE) test"),
+    (6, "S2S Operational Products"),
+    (7, "S2S Test Products"),
+    (8, "Uncertainties in ensembles of regional reanalysis project (UERRA)"),
+    (9, "Uncertainties in ensembles of regional reanalysis project (UERRA) Test"),
+    (10, "Copernicus Regional Reanalysis"),
+    (11, "Copernicus Regional Reanalysis Test"),
+    (12, "Destination Earth"),
+    (13, "Destination Earth test"),
+    (255, "Missing"),
+];
+
+const DATA_TYPES: &[(u8, &str)] = &[
+    (0, "Analysis Products"),
+    (1, "Forecast Products"),
+    (2, "Analysis and Forecast Products"),
+    (3, "Control Forecast Products"),
+    (4, "Perturbed Forecast Products"),
+    (5, "Control and Perturbed Forecast Products"),
+    (6, "Processed Satellite Observations"),
+    (7, "Processed Radar Observations"),
+    (8, "Event Probability"),
+    (192, "Experimental Products"),
+    (255, "Missing"),
+];
+
+const GRID_TEMPLATES: &[(u16, &str)] = &[
+    (0, "Latitude/Longitude (See Template 3.0) Also called Equidistant Cylindrical or Plate Caree"),
+    (1, "Rotated Latitude/Longitude (See Template 3.1)"),
+    (2, "Stretched Latitude/Longitude (See Template 3.2)"),
+    (3, "Rotated and Stretched Latitude/Longitude (See Template 3.3)"),
+    (4, "Variable Resolution Latitude/longitude (See Template 3.4)"),
+    (5, "Variable Resolution Rotated Latitude/longitude (See Template 3.5)"),
+    (10, "Mercator (See Template 3.10)"),
+    (11, "Reserved"),
+    (12, "Transverse Mercator (See Template 3.12)"),
+    (13, "Mercator with modelling subdomains definition (See Template 3.13)"),
+    (20, "Polar Stereographic Projection (Can be North or South) (See Template 3.20)"),
+    (23, "Polar Stereographic with modelling subdomains definition (See Template 3.23)"),
+    (30, "Lambert Conformal (Can be Secant, Tangent, Conical, or Bipolar) (See Template 3.30)"),
+    (31, "Albers Equal Area (See Template 3.31)"),
+    (32, "Reserved"),
+    (33, "Lambert conformal with modelling subdomains definition (See Template 3.33)"),
+    (40, "Gaussian Latitude/Longitude (See Template 3.40)"),
+    (41, "Rotated Gaussian Latitude/Longitude (See Template 3.41)"),
+    (42, "Stretched Gaussian Latitude/Longitude (See Template 3.42)"),
+    (43, "Rotated and Stretched Gaussian Latitude/Longitude (See Template 3.43)"),
+    (50, "Spherical Harmonic Coefficients (See Template 3.50)"),
+    (51, "Rotated Spherical Harmonic Coefficients (See Template 3.51)"),
+    (52, "Stretched Spherical Harmonic Coefficients (See Template 3.52)"),
+    (53, "Rotated and Stretched Spherical Harmonic Coefficients (See Template 3.53)"),
+    (60, "Cubed-Sphere Gnomonic (See Template 3.60) Validation"),
+    (61, "Spectral Mercator with modelling subdomains definition (See Template 3.61)"),
+    (62, "Spectral Polar Stereographic with modelling subdomains definition (See Template 3.62)"),
+    (63, "Spectral Lambert conformal with modelling subdomains definition (See Template 3.63)"),
+    (90, "Space View Perspective or Orthographic (See Template 3.90)"),
+    (100, "Triangular Grid Based on an Icosahedron (See Template 3.100)"),
+    (101, "General Unstructured Grid (see Template 3.101)"),
+    (110, "Equatorial Azimuthal Equidistant Projection (See Template 3.110)"),
+    (120, "Azimuth-Range Projection (See Template 3.120)"),
+    (140, "Lambert Azimuthal Equal Area Projection (See Template 3.140)"),
+    (150, "Hierarchical Equal Area isoLatitude Pixelization grid (HEALPix) (See Template 3.150)"),
+    (204, "Curvilinear Orthogonal Grids (See Template 3.204)"),
+    (1000, "Cross Section Grid with Points Equally Spaced on the Horizontal (See Template 3.1000)"),
+    (1100, "Hovmoller Diagram with Points Equally Spaced on the Horizontal (See Template 3.1100)"),
+    (1200, "Time Section Grid (See Template 3.1200)"),
+    (32768, "Rotated Latitude/Longitude (Arakawa Staggered E-Grid) (See Template 3.32768)"),
+    (32769, "Rotated Latitude/Longitude (Arakawa Non-E Staggered Grid) (See Template 3.32769)"),
+    (65535, "Missing"),
+];
+
+const EARTH_SHAPES: &[(u8, &str)] = &[
+    (0, "Earth assumed spherical with radius = 6,367,470.0 m"),
+    (1, "Earth assumed spherical with radius specified (in m) by data producer"),
+    (
+        2,
+        "Earth assumed oblate spheroid with size as determined by IAU in 1965 (major axis = \
+         6,378,160.0 m, minor axis = 6,356,775.0 m, f = 1/297.0)",
+    ),
+    (
+        3,
+        "Earth assumed oblate spheroid with major and minor axes specified (in km) by data \
+         producer",
+    ),
+    (
+        4,
+        "Earth assumed oblate spheroid as defined in IAG-GRS80 model (major axis = 6,378,137.0 m, \
+         minor axis = 6,356,752.314 m, f = 1/298.257222101)",
+    ),
+    (
+        5,
+        "Earth assumed represented by WGS84 (as used by ICAO since 1998) (Uses IAG-GRS80 as a \
+         basis)",
+    ),
+    (6, "Earth assumed spherical with radius = 6,371,229.0 m"),
+    (
+        7,
+        "Earth assumed oblate spheroid with major and minor axes specified (in m) by data producer",
+    ),
+    (
+        8,
+        "Earth model assumed spherical with radius 6,371,200 m, but the horizontal datum of the \
+         resulting Latitude/Longitude field is the WGS84 reference frame",
+    ),
+    (
+        9,
+        "Earth represented by the OSGB 1936 Datum, using the Airy_1830 Spheroid, the Greenwich \
+         meridian as 0 Longitude, the Newlyn datum as mean sea level, 0 height.",
+    ),
+    (
+        10,
+        "Earth model assumed WGS84 with corrected geomagnetic coordinates (latitude and \
+         longitude) defined by Gustafsson et al., 1992\". (see Note 1)",
+    ),
+    (
+        11,
+        "Sun assumed spherical with radius = 695 990 000 m (Allen, C.W., Astrophysical \
+         Quantities, 3rd ed.; Athlone: London, 1976) and Stonyhurst latitude and longitude system \
+         with origin at the intersection of the solar central meridian (as seen from Earth) and \
+         the solar equator (Thompson, W., Coordinate systems for solar image data, Astron. \
+         Astrophys. 2006, 449, 791-803)",
+    ),
+    (255, "Missing"),
+];
+
+const PRODUCT_TEMPLATES: &[(u16, &str)] = &[
+    (0, "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time."),
+    (
+        1,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time.",
+    ),
+    (
+        2,
+        "Derived forecasts based on all ensemble members at a horizontal level or in a horizontal \
+         layer at a point in time.",
+    ),
+    (
+        3,
+        "Derived forecasts based on a cluster of ensemble members over a rectangular area at a \
+         horizontal level or in a horizontal layer at a point in time.",
+    ),
+    (
+        4,
+        "Derived forecasts based on a cluster of ensemble members over a circular area at a \
+         horizontal level or in a horizontal layer at a point in time.",
+    ),
+    (5, "Probability forecasts at a horizontal level or in a horizontal layer at a point in time."),
+    (6, "Percentile forecasts at a horizontal level or in a horizontal layer at a point in time."),
+    (
+        7,
+        "Analysis or forecast error at a horizontal level or in a horizontal layer at a point in \
+         time.",
+    ),
+    (
+        8,
+        "Average, accumulation, extreme values or other statistically processed values at a \
+         horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval.",
+    ),
+    (
+        9,
+        "Probability forecasts at a horizontal level or in a horizontal layer in a continuous or \
+         non-continuous time interval.",
+    ),
+    (
+        10,
+        "Percentile forecasts at a horizontal level or in a horizontal layer in a continuous or \
+         non-continuous time interval.",
+    ),
+    (
+        11,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous time interval.",
+    ),
+    (
+        12,
+        "Derived forecasts based on all ensemble members at a horizontal level or in a horizontal \
+         layer, in a continuous or non-continuous time interval.",
+    ),
+    (
+        13,
+        "Derived forecasts based on a cluster of ensemble members over a rectangular area at a \
+         horizontal level or in a horizontal layer, in a continuous or non-continuous time \
+         interval.",
+    ),
+    (
+        14,
+        "Derived forecasts based on a cluster of ensemble members over a circular area at a \
+         horizontal level or in a horizontal layer, in a continuous or non-continuous time \
+         interval.",
+    ),
+    (
+        15,
+        "Average, accumulation, extreme values or other statistically-processed values over a \
+         spatial area at a horizontal level or in a horizontal layer at a point in time.",
+    ),
+    (
+        32,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for simulate (synthetic) satellite data.",
+    ),
+    (
+        33,
+        "Individual Ensemble Forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for simulated (synthetic) satellite data.",
+    ),
+    (
+        34,
+        "Individual Ensemble Forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous interval for simulated (synthetic) \
+         satellite data.",
+    ),
+    (35, "Satellite product with or without associated quality values."),
+    (
+        40,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for atmospheric chemical constituents.",
+    ),
+    (
+        41,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for atmospheric chemical constituents.",
+    ),
+    (
+        42,
+        "Average, accumulation, and/or extreme values or other statistically processed values at \
+         a horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for atmospheric chemical constituents.",
+    ),
+    (
+        43,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous time interval for atmospheric \
+         chemical constituents.",
+    ),
+    (
+        44,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for aerosol.",
+    ),
+    (
+        45,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous time interval for aerosol.",
+    ),
+    (
+        46,
+        "Average, accumulation, and/or extreme values or other statistically processed values at \
+         a horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for aerosol.",
+    ),
+    (
+        47,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous time interval for aerosol.",
+    ),
+    (
+        48,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for optical properties of aerosol.",
+    ),
+    (
+        49,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for optical properties of aerosol.",
+    ),
+    (51, "Categorical forecast at a horizontal level or in a horizontal layer at a point in time."),
+    (53, "Partitioned parameters at a horizontal level or horizontal layer at a point in time."),
+    (
+        54,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for partitioned parameters.",
+    ),
+    (
+        55,
+        "Spatio-temporal changing tiles at a horizontal level or horizontal layer at a point in \
+         time.",
+    ),
+    (
+        56,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for spatio-temporal changing tile parameters \
+         (DEPRECATED).",
+    ),
+    (
+        57,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for atmospheric chemical constituents based on a distribution function.",
+    ),
+    (
+        58,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time interval for atmospheric chemical constituents based \
+         on a distribution function.",
+    ),
+    (
+        59,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for spatio-temporal changing tile parameters \
+         (corrected version of template 4.56).",
+    ),
+    (
+        60,
+        "Individual Ensemble Reforecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time.",
+    ),
+    (
+        61,
+        "Individual Ensemble Reforecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous time interval.",
+    ),
+    (
+        62,
+        "Average, accumulation and/or extreme values or other statistically processed values at a \
+         horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for spatio-temporal changing tiles at a horizontal level or horizontal layer at \
+         a point in time.",
+    ),
+    (
+        63,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval for spatio-temporal \
+         changing tiles.",
+    ),
+    (
+        67,
+        "Average, accumulation and/or extreme values or other statistically processed values at a \
+         horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for atmospheric chemical constituents based on a distribution function.",
+    ),
+    (
+        68,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval for atmospheric \
+         chemical constituents based on a distribution function.",
+    ),
+    (
+        70,
+        "Post-processing analysis or forecast at a horizontal level or in a horizontal layer at a \
+         point in time.",
+    ),
+    (
+        71,
+        "Post-processing individual ensemble forecast, control and perturbed, at a horizontal \
+         level or in a horizontal layer at a point in time.",
+    ),
+    (
+        72,
+        "Post-processing average, accumulation, extreme values or other statistically processed \
+         values at a horizontal level or in a horizontal layer in a continuous or non-continuous \
+         time interval.",
+    ),
+    (
+        73,
+        "Post-processing individual ensemble forecast, control and perturbed, at a horizontal \
+         level or in a horizontal layer, in a continuous or non-continuous time interval.",
+    ),
+    (
+        76,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for atmospheric chemical constituents with source or sink.",
+    ),
+    (
+        77,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for atmospheric chemical constituents with source or \
+         sink.",
+    ),
+    (
+        78,
+        "Average, accumulation, and/or extreme values or other statistically processed values at \
+         a horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for atmospheric chemical constituents with source or sink.",
+    ),
+    (
+        79,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval for atmospheric \
+         chemical constituents with source or sink.",
+    ),
+    (
+        80,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for optical properties of aerosol with source or sink.",
+    ),
+    (
+        81,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for optical properties of aerosol with source or \
+         sink.",
+    ),
+    (
+        82,
+        "Average, accumulation, and/or extreme values or other statistically processed values at \
+         a horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for aerosol with source or sink.",
+    ),
+    (
+        83,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval for aerosol with source \
+         or sink.",
+    ),
+    (
+        84,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval for aerosol.",
+    ),
+    (
+        85,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval for aerosol with source \
+         or sink.",
+    ),
+    (86, "Quantile forecasts at a horizontal level or in a horizontal layer at a point in time."),
+    (
+        87,
+        "Quantile forecasts at a horizontal level or in a horizontal layer in a continuous or \
+         non-continuous time interval.",
+    ),
+    (
+        88,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a specified local \
+         time.",
+    ),
+    (
+        89,
+        "Post-processed quantile forecasts at a horizontal level or in a horizontal layer at a \
+         point in time.",
+    ),
+    (
+        90,
+        "Post-processed quantile forecasts at a horizontal level or in a horizontal layer in a \
+         continuous or non-continuous time interval.",
+    ),
+    (
+        91,
+        "Categorical forecast at a horizontal level or in a horizontal layer in a continuous or \
+         non-continuous time interval.",
+    ),
+    (
+        92,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a specified local time.",
+    ),
+    (
+        93,
+        "Post-processing analysis or forecast at a horizontal level or in a horizontal layer at a \
+         specified local time.",
+    ),
+    (
+        94,
+        "Post-processing individual ensemble forecast, control and perturbed, at a horizontal \
+         level or in a horizontal layer at a specified local time.",
+    ),
+    (
+        95,
+        "Average, accumulation, extreme values or other statistically processed values at a \
+         horizontal level or in a horizontal layer at a specified local time.",
+    ),
+    (
+        96,
+        "Average, accumulation, extreme values or other statistically processed values of an \
+         individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a specified local time.",
+    ),
+    (
+        97,
+        "Average, accumulation, extreme values or other statistically processed values of \
+         post-processing analysis or forecast at a horizontal level or in a horizontal layer at a \
+         specified local time.",
+    ),
+    (
+        98,
+        "Average, accumulation, extreme values or other statistically processed values of a \
+         post-processing individual ensemble forecast, control and perturbed, at a horizontal \
+         level or in a horizontal layer at a specified local time.",
+    ),
+    (
+        99,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for wave 2D spectra with explicit list of frequencies and directions.",
+    ),
+    (
+        100,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for wave 2D spectra with explicit list of \
+         frequencies and directions.",
+    ),
+    (
+        101,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for wave 2D spectra with frequencies and directions defined by formulae.",
+    ),
+    (
+        102,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for wave 2D spectra with frequencies and directions \
+         defined by formulae.",
+    ),
+    (
+        103,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for waves selected by period range.",
+    ),
+    (
+        104,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for waves selected by period range.",
+    ),
+    (
+        105,
+        "Anomalies, significance and other derived products from an analysis or forecast in \
+         relation to a reference period at a horizontal level or in a horizontal layer in a \
+         continuous or non-continuous time interval.",
+    ),
+    (
+        106,
+        "Anomalies, significance and other derived products from an individual ensemble forecast, \
+         control and perturbed in relation to a reference period at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval.",
+    ),
+    (
+        107,
+        "Anomalies, significance and other derived products from derived forecasts based on all \
+         ensemble members in relation to a reference period at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval.",
+    ),
+    (
+        108,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for generic optical products.",
+    ),
+    (
+        109,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for generic optical products.",
+    ),
+    (
+        110,
+        "Average, accumulation, extreme values or other statistically processed values at a \
+         horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for generic optical products.",
+    ),
+    (
+        111,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous interval for generic optical \
+         products.",
+    ),
+    (
+        112,
+        "Anomalies, significance and other derived products as probability forecasts in relation \
+         to a reference period at a horizontal level or in a horizontal layer in a continuous or \
+         non-continuous time interval.",
+    ),
+    (113, "Generalized tiles at a horizontal level or horizontal layer at a point in time."),
+    (
+        114,
+        "Average, accumulation, and/or extreme values or other statistically processed values on \
+         generalized tiles at a horizontal level or in a horizontal layer in a continuous or \
+         non-continuous time interval.",
+    ),
+    (
+        115,
+        "Individual ensemble forecast, control and perturbed on generalized tiles at a horizontal \
+         level or in a horizontal layer at a point in time.",
+    ),
+    (
+        116,
+        "Individual ensemble forecast, control and perturbed on generalized tiles at a horizontal \
+         level or in a horizontal layer in a continuous or non-continuous time interval.",
+    ),
+    (
+        117,
+        "Individual large ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time.",
+    ),
+    (
+        118,
+        "Individual large ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer, in a continuous or non-continuous interval.",
+    ),
+    (
+        119,
+        "Probability forecasts from large ensembles at a horizontal level or in a horizontal \
+         layer at a point in time.",
+    ),
+    (
+        120,
+        "Probability forecasts from large ensembles at a horizontal level or in a horizontal \
+         layer in a continuous or non-continuous time interval.",
+    ),
+    (
+        121,
+        "Probability forecasts from large ensembles with spatiotemporal processing based on focal \
+         (moving window) statistics at a horizontal level or in a horizontal layer at a point in \
+         time.",
+    ),
+    (
+        122,
+        "Probability forecasts from large ensembles with spatiotemporal processing based on focal \
+         (moving window) statistics at a horizontal level or in a horizontal layer in a \
+         continuous or non-continuous time interval.",
+    ),
+    (
+        123,
+        "Probability forecasts from large ensembles with spatiotemporal processing based on focal \
+         (moving window) statistics in relation to a reference period at a horizontal level or in \
+         a horizontal layer in a continuous or non-continuous time interval.",
+    ),
+    (
+        124,
+        "Analysis or forecast at a horizontal level or in a horizontal layer at a point in time \
+         for radionuclides.",
+    ),
+    (
+        125,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer at a point in time for radionuclides.",
+    ),
+    (
+        126,
+        "Average, accumulation, and/or extreme values or other statistically processed values at \
+         a horizontal level or in a horizontal layer in a continuous or non-continuous time \
+         interval for radionuclides.",
+    ),
+    (
+        127,
+        "Individual ensemble forecast, control and perturbed, at a horizontal level or in a \
+         horizontal layer in a continuous or non-continuous time interval for radionuclides.",
+    ),
+    (254, "CCITT IA5 character string."),
+    (1000, "Cross-section of analysis and forecast at a point in time."),
+    (
+        1001,
+        "Cross-section of averaged or otherwise statistically processed analysis or forecast over \
+         a range of time.",
+    ),
+    (
+        1002,
+        "Cross-section of analysis and forecast, averaged or otherwise statistically-processed \
+         over latitude or longitude.",
+    ),
+    (1100, "Hovmoller-type grid with no averaging or other statistical processing."),
+    (1101, "Hovmoller-type grid with averaging or other statistical processing."),
+    (65535, "Missing"),
+];
+
+const GENERATING_PROCESSES: &[(u8, &str)] = &[
+    (0, "Analysis"),
+    (1, "Initialization"),
+    (2, "Forecast"),
+    (3, "Bias Corrected Forecast"),
+    (4, "Ensemble Forecast"),
+    (5, "Probability Forecast"),
+    (6, "Forecast Error"),
+    (7, "Analysis Error"),
+    (8, "Observation"),
+    (9, "Climatological"),
+    (10, "Probability-Weighted Forecast"),
+    (11, "Bias-Corrected Ensemble Forecast"),
+    (12, "Post-processed Analysis"),
+    (13, "Post-processed Forecast"),
+    (14, "Nowcast"),
+    (15, "Hindcast"),
+    (16, "Physical Retrieval"),
+    (17, "Regression Analysis"),
+    (18, "Difference Between Two Forecasts"),
+    (19, "First guess"),
+    (20, "Analysis increment"),
+    (21, "Initialization increment for analysis"),
+    (192, "Forecast Confidence Indicator"),
+    (193, "Probability-matched Mean"),
+    (194, "Neighborhood Probability"),
+    (195, "Bias-Corrected and Downscaled Ensemble Forecast"),
+    (196, "Perturbed Analysis for Ensemble Initialization"),
+    (197, "Ensemble Agreement Scale Probability"),
+    (198, "Post-Processed Deterministic-Expert-Weighted Forecast"),
+    (199, "Ensemble Forecast Based on Counting"),
+    (200, "Local Probability-matched Mean"),
+    (255, "Missing"),
+];
+
+const TIME_UNITS: &[(u8, &str)] = &[
+    (0, "Minute"),
+    (1, "Hour"),
+    (2, "Day"),
+    (3, "Month"),
+    (4, "Year"),
+    (5, "Decade (10 Years)"),
+    (6, "Normal (30 Years)"),
+    (7, "Century (100 Years)"),
+    (8, "Reserved"),
+    (9, "Reserved"),
+    (10, "3 Hours"),
+    (11, "6 Hours"),
+    (12, "12 Hours"),
+    (13, "Second"),
+    (255, "Missing"),
+];
+
+const DATA_REPRESENTATION_TEMPLATES: &[(u16, &str)] = &[
+    (0, "Grid Point Data - Simple Packing (see Template 5.0)"),
+    (1, "Matrix Value at Grid Point - Simple Packing (see Template 5.1)"),
+    (2, "Grid Point Data - Complex Packing (see Template 5.2)"),
+    (3, "Grid Point Data - Complex Packing and Spatial Differencing (see Template 5.3)"),
+    (4, "Grid Point Data - IEEE Floating Point Data (see Template 5.4)"),
+    (40, "Grid point data - JPEG 2000 code stream format (see Template 5.40)"),
+    (41, "Grid point data - Portable Network Graphics (PNG) (see Template 5.41)"),
+    (42, "Grid point data - CCSDS recommended lossless compression (see Template 5.42)"),
+    (50, "Spectral Data - Simple Packing (see Template 5.50)"),
+    (51, "Spectral Data - Complex Packing (see Template 5.51)"),
+    (53, "Spectral data for limited area models - complex packing (see Template 5.53)"),
+    (61, "Grid Point Data - Simple Packing With Logarithm Pre-processing (see Template 5.61)"),
+    (200, "Run Length Packing With Level Values (see Template 5.200)"),
+    (65535, "Missing"),
+];
+
+const ORIGINAL_FIELD_TYPES: &[(u8, &str)] =
+    &[(0, "Floating Point"), (1, "Integer"), (255, "Missing")];
+
+const CATEGORIES: &[((u8, u8), &str)] = &[
+    ((0, 0), "Temperature (see Table 4.2-0-0)"),
+    ((0, 1), "Moisture (see Table 4.2-0-1)"),
+    ((0, 2), "Momentum (see Table 4.2-0-2)"),
+    ((0, 3), "Mass (see Table 4.2-0-3)"),
+    ((0, 4), "Short-wave radiation (see Table 4.2-0-4)"),
+    ((0, 5), "Long-wave radiation (see Table 4.2-0-5)"),
+    ((0, 6), "Cloud (see Table 4.2-0-6)"),
+    ((0, 7), "Thermodynamic Stability indices (see Table 4.2-0-7)"),
+    ((0, 8), "Kinematic Stability indices"),
+    ((0, 9), "Temperature Probabilities*"),
+    ((0, 10), "Moisture Probabilities*"),
+    ((0, 11), "Momentum Probabilities*"),
+    ((0, 12), "Mass Probabilities*"),
+    ((0, 13), "Aerosols (see Table 4.2-0-13)"),
+    ((0, 14), "Trace gases (e.g. Ozone, CO2) (see Table 4.2-0-14)"),
+    ((0, 15), "Radar (see Table 4.2-0-15)"),
+    ((0, 16), "Forecast Radar Imagery (see Table 4.2-0-16)"),
+    ((0, 17), "Electrodynamics (see Table 4.2-0-17)"),
+    ((0, 18), "Nuclear/radiology (see Table 4.2-0-18)"),
+    ((0, 19), "Physical atmospheric properties (see Table 4.2-0-19)"),
+    ((0, 20), "Atmospheric chemical Constituents (see Table 4.2-0-20)"),
+    ((0, 21), "Thermodynamic Properties (see Table 4.2-0-21)"),
+    ((0, 22), "Drought Indices (see Table 4.2-0-22)"),
+    ((0, 190), "CCITT IA5 string (see Table 4.2-0-190)"),
+    ((0, 191), "Miscellaneous (see Table 4.2-0-191)"),
+    ((0, 192), "Covariance (see Table 4.2-0-192)"),
+    ((0, 255), "Missing"),
+    ((1, 0), "Hydrology basic products (see Table 4.2-1-0)"),
+    ((1, 1), "Hydrology probabilities (see Table 4.2-1-1)"),
+    ((1, 2), "Inland water and sediment properties (see Table 4.2-1-2)"),
+    ((1, 255), "Missing"),
+    ((2, 0), "Vegetation/Biomass (see Table 4.2-2-0)"),
+    ((2, 1), "Agricultural/Aquacultural Special Products (see Table 4.2-2-1)"),
+    ((2, 2), "Transportation-related Products"),
+    ((2, 3), "Soil Products (see Table 4.2-2-3)"),
+    ((2, 4), "Fire Weather Products (see Table 4.2-2-4)"),
+    ((2, 5), "Land Surface Products (see Table 4.2-2-5)"),
+    ((2, 6), "Urban areas (see Table 4.2-2-6)"),
+    ((2, 255), "Missing"),
+    ((3, 0), "Image format products (See note 1) (see Table 4.2-3-0)"),
+    ((3, 1), "Quantitative products (See note 2) (see Table 4.2-3-1)"),
+    ((3, 2), "Cloud Properties (see Table 4.2-3-2)"),
+    ((3, 3), "Flight Rules Conditions (see Table 4.2-3-3)"),
+    ((3, 4), "Volcanic Ash (see Table 4.2-3-4)"),
+    ((3, 5), "Sea-surface Temperature (see Table 4.2-3-5)"),
+    ((3, 6), "Solar Radiation (see Table 4.2-3-6)"),
+    ((3, 192), "Forecast Satellite Imagery (See note 2) (see Table 4.2-3-192)"),
+    ((3, 255), "Missing"),
+    ((4, 0), "Temperature (see Table 4.2-4-0)"),
+    ((4, 1), "Momentum (see Table 4.2-4-1)"),
+    ((4, 2), "Charged Particle Mass and Number (see Table 4.2-4-2)"),
+    ((4, 3), "Electric and Magnetic Fields (see Table 4.2-4-3)"),
+    ((4, 4), "Energetic Particles (see Table 4.2-4-4)"),
+    ((4, 5), "Waves (see Table 4.2-4-5)"),
+    ((4, 6), "Solar Electromagnetic Emissions (see Table 4.2-4-6)"),
+    ((4, 7), "Terrestrial Electromagnetic Emissions (see Table 4.2-4-7)"),
+    ((4, 8), "Imagery (see Table 4.2-4-8)"),
+    ((4, 9), "Ion-Neutral Coupling (see Table 4.2-4-9)"),
+    ((4, 10), "Space Weather Indices (see Table 4.2-4-10)"),
+    ((4, 255), "Missing"),
+    ((10, 0), "Waves (see Table 4.2-10-0)"),
+    ((10, 1), "Currents (see Table 4.2-10-1)"),
+    ((10, 2), "Ice (see Table 4.2-10-2)"),
+    ((10, 3), "Surface Properties (see Table 4.2-10-3)"),
+    ((10, 4), "Sub-surface Properties (see Table 4.2-10-4)"),
+    ((10, 191), "Miscellaneous (see Table 4.2-10-191)"),
+    ((10, 255), "Missing"),
+    ((20, 0), "Health Indicators (see Table 4.2-20-0)"),
+    ((20, 1), "Epidemiology (see Table 4.2-20-1)"),
+    ((20, 2), "Socioeconomic indicators (see Table 4.2-20-2)"),
+    ((20, 3), "Renewable energy sector (see Table 4.2-20-3)"),
+    ((20, 255), "Missing"),
+];
+
+const PARAMETERS: &[((u8, u8, u8), Grib2Parameter)] = &[
+    ((0, 0, 0), Grib2Parameter { parameter: "Temperature", units: "K", abbrev: "TMP" }),
+    ((0, 0, 1), Grib2Parameter { parameter: "Virtual Temperature", units: "K", abbrev: "VTMP" }),
+    ((0, 0, 2), Grib2Parameter { parameter: "Potential Temperature", units: "K", abbrev: "POT" }),
+    (
+        (0, 0, 3),
+        Grib2Parameter {
+            parameter: "Pseudo-Adiabatic Potential Temperature (or Equivalent Potential \
+                        Temperature)",
+            units: "K",
+            abbrev: "EPOT",
+        },
+    ),
+    ((0, 0, 4), Grib2Parameter { parameter: "Maximum Temperature", units: "K", abbrev: "TMAX" }),
+    ((0, 0, 5), Grib2Parameter { parameter: "Minimum Temperature", units: "K", abbrev: "TMIN" }),
+    ((0, 0, 6), Grib2Parameter { parameter: "Dew Point Temperature", units: "K", abbrev: "DPT" }),
+    (
+        (0, 0, 7),
+        Grib2Parameter {
+            parameter: "Dew Point Depression (or Deficit)",
+            units: "K",
+            abbrev: "DEPR",
+        },
+    ),
+    ((0, 0, 8), Grib2Parameter { parameter: "Lapse Rate", units: "K m-1", abbrev: "LAPR" }),
+    ((0, 0, 9), Grib2Parameter { parameter: "Temperature Anomaly", units: "K", abbrev: "TMPA" }),
+    (
+        (0, 0, 10),
+        Grib2Parameter { parameter: "Latent Heat Net Flux", units: "W m-2", abbrev: "LHTFL" },
+    ),
+    (
+        (0, 0, 11),
+        Grib2Parameter { parameter: "Sensible Heat Net Flux", units: "W m-2", abbrev: "SHTFL" },
+    ),
+    ((0, 0, 12), Grib2Parameter { parameter: "Heat Index", units: "K", abbrev: "HEATX" }),
+    ((0, 0, 13), Grib2Parameter { parameter: "Wind Chill Factor", units: "K", abbrev: "WCF" }),
+    (
+        (0, 0, 14),
+        Grib2Parameter { parameter: "Minimum Dew Point Depression", units: "K", abbrev: "MINDPD" },
+    ),
+    (
+        (0, 0, 15),
+        Grib2Parameter { parameter: "Virtual Potential Temperature", units: "K", abbrev: "VPTMP" },
+    ),
+    (
+        (0, 0, 16),
+        Grib2Parameter {
+            parameter: "Snow Phase Change Heat Flux",
+            units: "W m-2",
+            abbrev: "SNOHF",
+        },
+    ),
+    ((0, 0, 17), Grib2Parameter { parameter: "Skin Temperature", units: "K", abbrev: "SKINT" }),
+    (
+        (0, 0, 18),
+        Grib2Parameter { parameter: "Snow Temperature (top of snow)", units: "K", abbrev: "SNOT" },
+    ),
+    (
+        (0, 0, 19),
+        Grib2Parameter {
+            parameter: "Turbulent Transfer Coefficient for Heat",
+            units: "Numeric",
+            abbrev: "TTCHT",
+        },
+    ),
+    (
+        (0, 0, 20),
+        Grib2Parameter {
+            parameter: "Turbulent Diffusion Coefficient for Heat",
+            units: "m2s-1",
+            abbrev: "TDCHT",
+        },
+    ),
+    ((0, 0, 21), Grib2Parameter { parameter: "Apparent Temperature", units: "K", abbrev: "APTMP" }),
+    (
+        (0, 0, 22),
+        Grib2Parameter {
+            parameter: "Temperature Tendency due to Short-Wave Radiation",
+            units: "K s-1",
+            abbrev: "TTSWR",
+        },
+    ),
+    (
+        (0, 0, 23),
+        Grib2Parameter {
+            parameter: "Temperature Tendency due to Long-Wave Radiation",
+            units: "K s-1",
+            abbrev: "TTLWR",
+        },
+    ),
+    (
+        (0, 0, 24),
+        Grib2Parameter {
+            parameter: "Temperature Tendency due to Short-Wave Radiation, Clear Sky",
+            units: "K s-1",
+            abbrev: "TTSWRCS",
+        },
+    ),
+    (
+        (0, 0, 25),
+        Grib2Parameter {
+            parameter: "Temperature Tendency due to Long-Wave Radiation, Clear Sky",
+            units: "K s-1",
+            abbrev: "TTLWRCS",
+        },
+    ),
+    (
+        (0, 0, 26),
+        Grib2Parameter {
+            parameter: "Temperature Tendency due to parameterizations",
+            units: "K s-1",
+            abbrev: "TTPARM",
+        },
+    ),
+    ((0, 0, 27), Grib2Parameter { parameter: "Wet Bulb Temperature", units: "K", abbrev: "WETBT" }),
+    (
+        (0, 0, 28),
+        Grib2Parameter {
+            parameter: "Unbalanced Component of Temperature",
+            units: "K",
+            abbrev: "UCTMP",
+        },
+    ),
+    (
+        (0, 0, 29),
+        Grib2Parameter { parameter: "Temperature Advection", units: "K s-1", abbrev: "TMPADV" },
+    ),
+    (
+        (0, 0, 30),
+        Grib2Parameter {
+            parameter: "Latent Heat Net Flux Due to Evaporation",
+            units: "W m-2",
+            abbrev: "LHFLXE",
+        },
+    ),
+    (
+        (0, 0, 31),
+        Grib2Parameter {
+            parameter: "Latent Heat Net Flux Due to Sublimation",
+            units: "W m-2",
+            abbrev: "LHFLXS",
+        },
+    ),
+    (
+        (0, 0, 32),
+        Grib2Parameter {
+            parameter: "Wet-Bulb Potential Temperature",
+            units: "K",
+            abbrev: "WETBPT",
+        },
+    ),
+    (
+        (0, 0, 192),
+        Grib2Parameter {
+            parameter: "Snow Phase Change Heat Flux",
+            units: "W m-2",
+            abbrev: "SNOHF",
+        },
+    ),
+    (
+        (0, 0, 193),
+        Grib2Parameter {
+            parameter: "Temperature Tendency by All Radiation",
+            units: "K s-1",
+            abbrev: "TTRAD",
+        },
+    ),
+    (
+        (0, 0, 194),
+        Grib2Parameter { parameter: "Relative Error Variance", units: "", abbrev: "REV" },
+    ),
+    (
+        (0, 0, 195),
+        Grib2Parameter {
+            parameter: "Large Scale Condensate Heating Rate",
+            units: "K s-1",
+            abbrev: "LRGHR",
+        },
+    ),
+    (
+        (0, 0, 196),
+        Grib2Parameter {
+            parameter: "Deep Convective Heating Rate",
+            units: "K s-1",
+            abbrev: "CNVHR",
+        },
+    ),
+    (
+        (0, 0, 197),
+        Grib2Parameter {
+            parameter: "Total Downward Heat Flux at Surface",
+            units: "W m-2",
+            abbrev: "THFLX",
+        },
+    ),
+    (
+        (0, 0, 198),
+        Grib2Parameter {
+            parameter: "Temperature Tendency by All Physics",
+            units: "K s-1",
+            abbrev: "TTDIA",
+        },
+    ),
+    (
+        (0, 0, 199),
+        Grib2Parameter {
+            parameter: "Temperature Tendency by Non-radiation Physics",
+            units: "K s-1",
+            abbrev: "TTPHY",
+        },
+    ),
+    (
+        (0, 0, 200),
+        Grib2Parameter {
+            parameter: "Standard Dev. of IR Temp. over 1x1 deg. area",
+            units: "K",
+            abbrev: "TSD1D",
+        },
+    ),
+    (
+        (0, 0, 201),
+        Grib2Parameter {
+            parameter: "Shallow Convective Heating Rate",
+            units: "K s-1",
+            abbrev: "SHAHR",
+        },
+    ),
+    (
+        (0, 0, 202),
+        Grib2Parameter {
+            parameter: "Vertical Diffusion Heating rate",
+            units: "K s-1",
+            abbrev: "VDFHR",
+        },
+    ),
+    (
+        (0, 0, 203),
+        Grib2Parameter {
+            parameter: "Potential Temperature at Top of Viscous Sublayer",
+            units: "K",
+            abbrev: "THZ0",
+        },
+    ),
+    (
+        (0, 0, 204),
+        Grib2Parameter {
+            parameter: "Tropical Cyclone Heat Potential",
+            units: "J m-2 K",
+            abbrev: "TCHP",
+        },
+    ),
+    (
+        (0, 0, 205),
+        Grib2Parameter {
+            parameter: "Effective Layer (EL) Temperature",
+            units: "C",
+            abbrev: "ELMELT",
+        },
+    ),
+    (
+        (0, 0, 206),
+        Grib2Parameter { parameter: "Wet Bulb Globe Temperature", units: "K", abbrev: "WETGLBT" },
+    ),
+    ((0, 0, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 1, 0),
+        Grib2Parameter { parameter: "Specific Humidity", units: "kg kg-1", abbrev: "SPFH" },
+    ),
+    ((0, 1, 1), Grib2Parameter { parameter: "Relative Humidity", units: "%", abbrev: "RH" }),
+    (
+        (0, 1, 2),
+        Grib2Parameter { parameter: "Humidity Mixing Ratio", units: "kg kg-1", abbrev: "MIXR" },
+    ),
+    (
+        (0, 1, 3),
+        Grib2Parameter { parameter: "Precipitable Water", units: "kg m-2", abbrev: "PWAT" },
+    ),
+    ((0, 1, 4), Grib2Parameter { parameter: "Vapour Pressure", units: "Pa", abbrev: "VAPP" }),
+    ((0, 1, 5), Grib2Parameter { parameter: "Saturation Deficit", units: "Pa", abbrev: "SATD" }),
+    ((0, 1, 6), Grib2Parameter { parameter: "Evaporation", units: "kg m-2", abbrev: "EVP" }),
+    (
+        (0, 1, 7),
+        Grib2Parameter { parameter: "Precipitation Rate", units: "kg m-2 s-1", abbrev: "PRATE" },
+    ),
+    (
+        (0, 1, 8),
+        Grib2Parameter { parameter: "Total Precipitation", units: "kg m-2", abbrev: "APCP" },
+    ),
+    (
+        (0, 1, 9),
+        Grib2Parameter {
+            parameter: "Large-Scale Precipitation (non-convective)",
+            units: "kg m-2",
+            abbrev: "NCPCP",
+        },
+    ),
+    (
+        (0, 1, 10),
+        Grib2Parameter { parameter: "Convective Precipitation", units: "kg m-2", abbrev: "ACPCP" },
+    ),
+    ((0, 1, 11), Grib2Parameter { parameter: "Snow Depth", units: "m", abbrev: "SNOD" }),
+    (
+        (0, 1, 12),
+        Grib2Parameter {
+            parameter: "Snowfall Rate Water Equivalent",
+            units: "kg m-2 s-1",
+            abbrev: "SRWEQ",
+        },
+    ),
+    (
+        (0, 1, 13),
+        Grib2Parameter {
+            parameter: "Water Equivalent of Accumulated Snow Depth",
+            units: "kg m-2",
+            abbrev: "WEASD",
+        },
+    ),
+    ((0, 1, 14), Grib2Parameter { parameter: "Convective Snow", units: "kg m-2", abbrev: "SNOC" }),
+    ((0, 1, 15), Grib2Parameter { parameter: "Large-Scale Snow", units: "kg m-2", abbrev: "SNOL" }),
+    ((0, 1, 16), Grib2Parameter { parameter: "Snow Melt", units: "kg m-2", abbrev: "SNOM" }),
+    ((0, 1, 17), Grib2Parameter { parameter: "Snow Age", units: "day", abbrev: "SNOAG" }),
+    (
+        (0, 1, 18),
+        Grib2Parameter { parameter: "Absolute Humidity", units: "kg m-3", abbrev: "ABSH" },
+    ),
+    (
+        (0, 1, 19),
+        Grib2Parameter {
+            parameter: "Precipitation Type",
+            units: "See Table 4.201",
+            abbrev: "PTYPE",
+        },
+    ),
+    (
+        (0, 1, 20),
+        Grib2Parameter { parameter: "Integrated Liquid Water", units: "kg m-2", abbrev: "ILIQW" },
+    ),
+    ((0, 1, 21), Grib2Parameter { parameter: "Condensate", units: "kg kg-1", abbrev: "TCOND" }),
+    (
+        (0, 1, 22),
+        Grib2Parameter { parameter: "Cloud Mixing Ratio", units: "kg kg-1", abbrev: "CLMR" },
+    ),
+    (
+        (0, 1, 23),
+        Grib2Parameter { parameter: "Ice Water Mixing Ratio", units: "kg kg-1", abbrev: "ICMR" },
+    ),
+    (
+        (0, 1, 24),
+        Grib2Parameter { parameter: "Rain Mixing Ratio", units: "kg kg-1", abbrev: "RWMR" },
+    ),
+    (
+        (0, 1, 25),
+        Grib2Parameter { parameter: "Snow Mixing Ratio", units: "kg kg-1", abbrev: "SNMR" },
+    ),
+    (
+        (0, 1, 26),
+        Grib2Parameter {
+            parameter: "Horizontal Moisture Convergence",
+            units: "kg kg-1 s-1",
+            abbrev: "MCONV",
+        },
+    ),
+    (
+        (0, 1, 27),
+        Grib2Parameter { parameter: "Maximum Relative Humidity", units: "%", abbrev: "MAXRH" },
+    ),
+    (
+        (0, 1, 28),
+        Grib2Parameter { parameter: "Maximum Absolute Humidity", units: "kg m-3", abbrev: "MAXAH" },
+    ),
+    ((0, 1, 29), Grib2Parameter { parameter: "Total Snowfall", units: "m", abbrev: "ASNOW" }),
+    (
+        (0, 1, 30),
+        Grib2Parameter {
+            parameter: "Precipitable Water Category",
+            units: "See Table 4.202",
+            abbrev: "PWCAT",
+        },
+    ),
+    ((0, 1, 31), Grib2Parameter { parameter: "Hail", units: "m", abbrev: "HAIL" }),
+    ((0, 1, 32), Grib2Parameter { parameter: "Graupel", units: "kg kg-1", abbrev: "GRLE" }),
+    (
+        (0, 1, 33),
+        Grib2Parameter {
+            parameter: "Categorical Rain",
+            units: "Code table 4.222",
+            abbrev: "CRAIN",
+        },
+    ),
+    (
+        (0, 1, 34),
+        Grib2Parameter {
+            parameter: "Categorical Freezing Rain",
+            units: "Code table 4.222",
+            abbrev: "CFRZR",
+        },
+    ),
+    (
+        (0, 1, 35),
+        Grib2Parameter {
+            parameter: "Categorical Ice Pellets",
+            units: "Code table 4.222",
+            abbrev: "CICEP",
+        },
+    ),
+    (
+        (0, 1, 36),
+        Grib2Parameter {
+            parameter: "Categorical Snow",
+            units: "Code table 4.222",
+            abbrev: "CSNOW",
+        },
+    ),
+    (
+        (0, 1, 37),
+        Grib2Parameter {
+            parameter: "Convective Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "CPRAT",
+        },
+    ),
+    (
+        (0, 1, 38),
+        Grib2Parameter {
+            parameter: "Horizontal Moisture Divergence",
+            units: "kg kg-1 s-1",
+            abbrev: "MDIVER",
+        },
+    ),
+    (
+        (0, 1, 39),
+        Grib2Parameter { parameter: "Percent frozen precipitation", units: "%", abbrev: "CPOFP" },
+    ),
+    (
+        (0, 1, 40),
+        Grib2Parameter { parameter: "Potential Evaporation", units: "kg m-2", abbrev: "PEVAP" },
+    ),
+    (
+        (0, 1, 41),
+        Grib2Parameter { parameter: "Potential Evaporation Rate", units: "W m-2", abbrev: "PEVPR" },
+    ),
+    ((0, 1, 42), Grib2Parameter { parameter: "Snow Cover", units: "%", abbrev: "SNOWC" }),
+    (
+        (0, 1, 43),
+        Grib2Parameter {
+            parameter: "Rain Fraction of Total Cloud Water",
+            units: "Proportion",
+            abbrev: "FRAIN",
+        },
+    ),
+    ((0, 1, 44), Grib2Parameter { parameter: "Rime Factor", units: "Numeric", abbrev: "RIME" }),
+    (
+        (0, 1, 45),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Rain",
+            units: "kg m-2",
+            abbrev: "TCOLR",
+        },
+    ),
+    (
+        (0, 1, 46),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Snow",
+            units: "kg m-2",
+            abbrev: "TCOLS",
+        },
+    ),
+    (
+        (0, 1, 47),
+        Grib2Parameter {
+            parameter: "Large Scale Water Precipitation",
+            units: "kg m-2",
+            abbrev: "LSWP",
+        },
+    ),
+    (
+        (0, 1, 48),
+        Grib2Parameter {
+            parameter: "Convective Water Precipitation",
+            units: "kg m-2",
+            abbrev: "CWP",
+        },
+    ),
+    (
+        (0, 1, 49),
+        Grib2Parameter { parameter: "Total Water Precipitation", units: "kg m-2", abbrev: "TWATP" },
+    ),
+    (
+        (0, 1, 50),
+        Grib2Parameter { parameter: "Total Snow Precipitation", units: "kg m-2", abbrev: "TSNOWP" },
+    ),
+    (
+        (0, 1, 51),
+        Grib2Parameter { parameter: "Total Column Water", units: "kg m-2", abbrev: "TCWAT" },
+    ),
+    (
+        (0, 1, 52),
+        Grib2Parameter {
+            parameter: "Total Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "TPRATE",
+        },
+    ),
+    (
+        (0, 1, 53),
+        Grib2Parameter {
+            parameter: "Total Snowfall Rate Water Equivalent",
+            units: "kg m-2 s-1",
+            abbrev: "TSRWE",
+        },
+    ),
+    (
+        (0, 1, 54),
+        Grib2Parameter {
+            parameter: "Large Scale Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "LSPRATE",
+        },
+    ),
+    (
+        (0, 1, 55),
+        Grib2Parameter {
+            parameter: "Convective Snowfall Rate Water Equivalent",
+            units: "kg m-2 s-1",
+            abbrev: "CSRWE",
+        },
+    ),
+    (
+        (0, 1, 56),
+        Grib2Parameter {
+            parameter: "Large Scale Snowfall Rate Water Equivalent",
+            units: "kg m-2 s-1",
+            abbrev: "LSSRWE",
+        },
+    ),
+    (
+        (0, 1, 57),
+        Grib2Parameter { parameter: "Total Snowfall Rate", units: "m s-1", abbrev: "TSRATE" },
+    ),
+    (
+        (0, 1, 58),
+        Grib2Parameter { parameter: "Convective Snowfall Rate", units: "m s-1", abbrev: "CSRATE" },
+    ),
+    (
+        (0, 1, 59),
+        Grib2Parameter {
+            parameter: "Large Scale Snowfall Rate",
+            units: "m s-1",
+            abbrev: "LSSRATE",
+        },
+    ),
+    (
+        (0, 1, 60),
+        Grib2Parameter {
+            parameter: "Snow Depth Water Equivalent",
+            units: "kg m-2",
+            abbrev: "SDWE",
+        },
+    ),
+    ((0, 1, 61), Grib2Parameter { parameter: "Snow Density", units: "kg m-3", abbrev: "SDEN" }),
+    (
+        (0, 1, 62),
+        Grib2Parameter { parameter: "Snow Evaporation", units: "kg m-2", abbrev: "SEVAP" },
+    ),
+    ((0, 1, 63), Grib2Parameter { parameter: "Reserved", units: "", abbrev: "" }),
+    (
+        (0, 1, 64),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Water Vapour",
+            units: "kg m-2",
+            abbrev: "TCIWV",
+        },
+    ),
+    (
+        (0, 1, 65),
+        Grib2Parameter {
+            parameter: "Rain Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "RPRATE",
+        },
+    ),
+    (
+        (0, 1, 66),
+        Grib2Parameter {
+            parameter: "Snow Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "SPRATE",
+        },
+    ),
+    (
+        (0, 1, 67),
+        Grib2Parameter {
+            parameter: "Freezing Rain Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "FPRATE",
+        },
+    ),
+    (
+        (0, 1, 68),
+        Grib2Parameter {
+            parameter: "Ice Pellets Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "IPRATE",
+        },
+    ),
+    (
+        (0, 1, 69),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Cloud Water",
+            units: "kg m-2",
+            abbrev: "TCOLW",
+        },
+    ),
+    (
+        (0, 1, 70),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Cloud Ice",
+            units: "kg m-2",
+            abbrev: "TCOLI",
+        },
+    ),
+    (
+        (0, 1, 71),
+        Grib2Parameter { parameter: "Hail Mixing Ratio", units: "kg kg-1", abbrev: "HAILMXR" },
+    ),
+    (
+        (0, 1, 72),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Hail",
+            units: "kg m-2",
+            abbrev: "TCOLH",
+        },
+    ),
+    (
+        (0, 1, 73),
+        Grib2Parameter {
+            parameter: "Hail Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "HAILPR",
+        },
+    ),
+    (
+        (0, 1, 74),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Graupel",
+            units: "kg m-2",
+            abbrev: "TCOLG",
+        },
+    ),
+    (
+        (0, 1, 75),
+        Grib2Parameter {
+            parameter: "Graupel (Snow Pellets) Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "GPRATE",
+        },
+    ),
+    (
+        (0, 1, 76),
+        Grib2Parameter { parameter: "Convective Rain Rate", units: "kg m-2 s-1", abbrev: "CRRATE" },
+    ),
+    (
+        (0, 1, 77),
+        Grib2Parameter {
+            parameter: "Large Scale Rain Rate",
+            units: "kg m-2 s-1",
+            abbrev: "LSRRATE",
+        },
+    ),
+    (
+        (0, 1, 78),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Water (All components including precipitation)",
+            units: "kg m-2",
+            abbrev: "TCOLWA",
+        },
+    ),
+    (
+        (0, 1, 79),
+        Grib2Parameter { parameter: "Evaporation Rate", units: "kg m-2 s-1", abbrev: "EVARATE" },
+    ),
+    (
+        (0, 1, 80),
+        Grib2Parameter { parameter: "Total Condensate", units: "kg kg-1", abbrev: "TOTCON" },
+    ),
+    (
+        (0, 1, 81),
+        Grib2Parameter {
+            parameter: "Total Column-Integrated Condensate",
+            units: "kg m-2",
+            abbrev: "TCICON",
+        },
+    ),
+    (
+        (0, 1, 82),
+        Grib2Parameter { parameter: "Cloud Ice Mixing Ratio", units: "kg kg-1", abbrev: "CIMIXR" },
+    ),
+    (
+        (0, 1, 83),
+        Grib2Parameter {
+            parameter: "Specific Cloud Liquid Water Content",
+            units: "kg kg-1",
+            abbrev: "SCLLWC",
+        },
+    ),
+    (
+        (0, 1, 84),
+        Grib2Parameter {
+            parameter: "Specific Cloud Ice Water Content",
+            units: "kg kg-1",
+            abbrev: "SCLIWC",
+        },
+    ),
+    (
+        (0, 1, 85),
+        Grib2Parameter {
+            parameter: "Specific Rain Water Content",
+            units: "kg kg-1",
+            abbrev: "SRAINW",
+        },
+    ),
+    (
+        (0, 1, 86),
+        Grib2Parameter {
+            parameter: "Specific Snow Water Content",
+            units: "kg kg-1",
+            abbrev: "SSNOWW",
+        },
+    ),
+    (
+        (0, 1, 87),
+        Grib2Parameter {
+            parameter: "Stratiform Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "STRPRATE",
+        },
+    ),
+    (
+        (0, 1, 88),
+        Grib2Parameter {
+            parameter: "Categorical Convective Precipitation",
+            units: "Code table 4.222",
+            abbrev: "CATCP",
+        },
+    ),
+    ((0, 1, 89), Grib2Parameter { parameter: "Reserved", units: "", abbrev: "" }),
+    (
+        (0, 1, 90),
+        Grib2Parameter {
+            parameter: "Total Kinematic Moisture Flux",
+            units: "kg kg-1 m s-1",
+            abbrev: "TKMFLX",
+        },
+    ),
+    (
+        (0, 1, 91),
+        Grib2Parameter {
+            parameter: "U-component (zonal) Kinematic Moisture Flux",
+            units: "kg kg-1 m s-1",
+            abbrev: "UKMFLX",
+        },
+    ),
+    (
+        (0, 1, 92),
+        Grib2Parameter {
+            parameter: "V-component (meridional) Kinematic Moisture Flux",
+            units: "kg kg-1 m s-1",
+            abbrev: "VKMFLX",
+        },
+    ),
+    (
+        (0, 1, 93),
+        Grib2Parameter {
+            parameter: "Relative Humidity With Respect to Water",
+            units: "%",
+            abbrev: "RHWATER",
+        },
+    ),
+    (
+        (0, 1, 94),
+        Grib2Parameter {
+            parameter: "Relative Humidity With Respect to Ice",
+            units: "%",
+            abbrev: "RHICE",
+        },
+    ),
+    (
+        (0, 1, 95),
+        Grib2Parameter {
+            parameter: "Freezing or Frozen Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "FZPRATE",
+        },
+    ),
+    (
+        (0, 1, 96),
+        Grib2Parameter { parameter: "Mass Density of Rain", units: "kg m-3", abbrev: "MASSDR" },
+    ),
+    (
+        (0, 1, 97),
+        Grib2Parameter { parameter: "Mass Density of Snow", units: "kg m-3", abbrev: "MASSDS" },
+    ),
+    (
+        (0, 1, 98),
+        Grib2Parameter { parameter: "Mass Density of Graupel", units: "kg m-3", abbrev: "MASSDG" },
+    ),
+    (
+        (0, 1, 99),
+        Grib2Parameter { parameter: "Mass Density of Hail", units: "kg m-3", abbrev: "MASSDH" },
+    ),
+    (
+        (0, 1, 100),
+        Grib2Parameter {
+            parameter: "Specific Number Concentration of Rain",
+            units: "kg-1",
+            abbrev: "SPNCR",
+        },
+    ),
+    (
+        (0, 1, 101),
+        Grib2Parameter {
+            parameter: "Specific Number Concentration of Snow",
+            units: "kg-1",
+            abbrev: "SPNCS",
+        },
+    ),
+    (
+        (0, 1, 102),
+        Grib2Parameter {
+            parameter: "Specific Number Concentration of Graupel",
+            units: "kg-1",
+            abbrev: "SPNCG",
+        },
+    ),
+    (
+        (0, 1, 103),
+        Grib2Parameter {
+            parameter: "Specific Number Concentration of Hail",
+            units: "kg-1",
+            abbrev: "SPNCH",
+        },
+    ),
+    (
+        (0, 1, 104),
+        Grib2Parameter { parameter: "Number Density of Rain", units: "m-3", abbrev: "NUMDR" },
+    ),
+    (
+        (0, 1, 105),
+        Grib2Parameter { parameter: "Number Density of Snow", units: "m-3", abbrev: "NUMDS" },
+    ),
+    (
+        (0, 1, 106),
+        Grib2Parameter { parameter: "Number Density of Graupel", units: "m-3", abbrev: "NUMDG" },
+    ),
+    (
+        (0, 1, 107),
+        Grib2Parameter { parameter: "Number Density of Hail", units: "m-3", abbrev: "NUMDH" },
+    ),
+    (
+        (0, 1, 108),
+        Grib2Parameter {
+            parameter: "Specific Humidity Tendency due to Parameterizations",
+            units: "kg kg-1 s-1",
+            abbrev: "SHTPRM",
+        },
+    ),
+    (
+        (0, 1, 109),
+        Grib2Parameter {
+            parameter: "Mass Density of Liquid Water Coating on Hail Expressed as Mass of Liquid \
+                        Water per Unit Volume of Air",
+            units: "kg m-3",
+            abbrev: "MDLWHVA",
+        },
+    ),
+    (
+        (0, 1, 110),
+        Grib2Parameter {
+            parameter: "Specific Mass of Liquid Water Coating on Hail Expressed as Mass of Liquid \
+                        Water per Unit Mass of Moist Air",
+            units: "kg kg-1",
+            abbrev: "SMLWHMA",
+        },
+    ),
+    (
+        (0, 1, 111),
+        Grib2Parameter {
+            parameter: "Mass Mixing Ratio of Liquid Water Coating on Hail Expressed as Mass of \
+                        Liquid Water per Unit Mass of Dry Air",
+            units: "kg kg-1",
+            abbrev: "MMLWHDA",
+        },
+    ),
+    (
+        (0, 1, 112),
+        Grib2Parameter {
+            parameter: "Mass Density of Liquid Water Coating on Graupel Expressed as Mass of \
+                        Liquid Water per Unit Volume of Air",
+            units: "kg m-3",
+            abbrev: "MDLWGVA",
+        },
+    ),
+    (
+        (0, 1, 113),
+        Grib2Parameter {
+            parameter: "Specific Mass of Liquid Water Coating on Graupel Expressed as Mass of \
+                        Liquid Water per Unit Mass of Moist Air",
+            units: "kg kg-1",
+            abbrev: "SMLWGMA",
+        },
+    ),
+    (
+        (0, 1, 114),
+        Grib2Parameter {
+            parameter: "Mass Mixing Ratio of Liquid Water Coating on Graupel Expressed as Mass of \
+                        Liquid Water per Unit Mass of Dry Air",
+            units: "kg kg-1",
+            abbrev: "MMLWGDA",
+        },
+    ),
+    (
+        (0, 1, 115),
+        Grib2Parameter {
+            parameter: "Mass Density of Liquid Water Coating on Snow Expressed as Mass of Liquid \
+                        Water per Unit Volume of Air",
+            units: "kg m-3",
+            abbrev: "MDLWSVA",
+        },
+    ),
+    (
+        (0, 1, 116),
+        Grib2Parameter {
+            parameter: "Specific Mass of Liquid Water Coating on Snow Expressed as Mass of Liquid \
+                        Water per Unit Mass of Moist Air",
+            units: "kg kg-1",
+            abbrev: "SMLWSMA",
+        },
+    ),
+    (
+        (0, 1, 117),
+        Grib2Parameter {
+            parameter: "Mass Mixing Ratio of Liquid Water Coating on Snow Expressed as Mass of \
+                        Liquid Water per Unit Mass of Dry Air",
+            units: "kg kg-1",
+            abbrev: "MMLWSDA",
+        },
+    ),
+    (
+        (0, 1, 118),
+        Grib2Parameter {
+            parameter: "Unbalanced Component of Specific Humidity",
+            units: "kg kg-1",
+            abbrev: "UNCSH",
+        },
+    ),
+    (
+        (0, 1, 119),
+        Grib2Parameter {
+            parameter: "Unbalanced Component of Specific Cloud Liquid Water content",
+            units: "kg kg-1",
+            abbrev: "UCSCLW",
+        },
+    ),
+    (
+        (0, 1, 120),
+        Grib2Parameter {
+            parameter: "Unbalanced Component of Specific Cloud Ice Water content",
+            units: "kg kg-1",
+            abbrev: "UCSCIW",
+        },
+    ),
+    (
+        (0, 1, 121),
+        Grib2Parameter {
+            parameter: "Fraction of Snow Cover",
+            units: "Proportion",
+            abbrev: "FSNOWC",
+        },
+    ),
+    (
+        (0, 1, 122),
+        Grib2Parameter {
+            parameter: "Precipitation intensity index",
+            units: "See Table 4.247",
+            abbrev: "PIIDX",
+        },
+    ),
+    (
+        (0, 1, 123),
+        Grib2Parameter {
+            parameter: "Dominant precipitation type",
+            units: "See Table 4.201",
+            abbrev: "DPTYPE",
+        },
+    ),
+    (
+        (0, 1, 124),
+        Grib2Parameter {
+            parameter: "Presence of showers",
+            units: "See Table 4.222",
+            abbrev: "PSHOW",
+        },
+    ),
+    (
+        (0, 1, 125),
+        Grib2Parameter {
+            parameter: "Presence of blowing snow",
+            units: "See Table 4.222",
+            abbrev: "PBSNOW",
+        },
+    ),
+    (
+        (0, 1, 126),
+        Grib2Parameter {
+            parameter: "Presence of blizzard",
+            units: "See Table 4.222",
+            abbrev: "PBLIZZ",
+        },
+    ),
+    (
+        (0, 1, 127),
+        Grib2Parameter {
+            parameter: "Ice pellets (non-water equivalent) precipitation rate",
+            units: "m s-1",
+            abbrev: "ICEP",
+        },
+    ),
+    (
+        (0, 1, 128),
+        Grib2Parameter {
+            parameter: "Total solid precipitation rate",
+            units: "kg m-2 s-1",
+            abbrev: "TSPRATE",
+        },
+    ),
+    (
+        (0, 1, 129),
+        Grib2Parameter {
+            parameter: "Effective Radius of Cloud Water",
+            units: "m",
+            abbrev: "EFRCWAT",
+        },
+    ),
+    (
+        (0, 1, 130),
+        Grib2Parameter { parameter: "Effective Radius of Rain", units: "m", abbrev: "EFRRAIN" },
+    ),
+    (
+        (0, 1, 131),
+        Grib2Parameter {
+            parameter: "Effective Radius of Cloud Ice",
+            units: "m",
+            abbrev: "EFRCICE",
+        },
+    ),
+    (
+        (0, 1, 132),
+        Grib2Parameter { parameter: "Effective Radius of Snow", units: "m", abbrev: "EFRSNOW" },
+    ),
+    (
+        (0, 1, 133),
+        Grib2Parameter { parameter: "Effective Radius of Graupel", units: "m", abbrev: "EFRGRL" },
+    ),
+    (
+        (0, 1, 134),
+        Grib2Parameter { parameter: "Effective Radius of Hail", units: "m", abbrev: "EFRHAIL" },
+    ),
+    (
+        (0, 1, 135),
+        Grib2Parameter {
+            parameter: "Effective Radius of Subgrid Liquid Clouds",
+            units: "m",
+            abbrev: "EFRSLC",
+        },
+    ),
+    (
+        (0, 1, 136),
+        Grib2Parameter {
+            parameter: "Effective Radius of Subgrid Ice Clouds",
+            units: "m",
+            abbrev: "EFRSICEC",
+        },
+    ),
+    (
+        (0, 1, 137),
+        Grib2Parameter {
+            parameter: "Effective Aspect Ratio of Rain",
+            units: "",
+            abbrev: "EFARRAIN",
+        },
+    ),
+    (
+        (0, 1, 138),
+        Grib2Parameter {
+            parameter: "Effective Aspect Ratio of Cloud Ice",
+            units: "",
+            abbrev: "EFARCICE",
+        },
+    ),
+    (
+        (0, 1, 139),
+        Grib2Parameter {
+            parameter: "Effective Aspect Ratio of Snow",
+            units: "",
+            abbrev: "EFARSNOW",
+        },
+    ),
+    (
+        (0, 1, 140),
+        Grib2Parameter {
+            parameter: "Effective Aspect Ratio of Graupel",
+            units: "",
+            abbrev: "EFARGRL",
+        },
+    ),
+    (
+        (0, 1, 141),
+        Grib2Parameter {
+            parameter: "Effective Aspect Ratio of Hail",
+            units: "",
+            abbrev: "EFARHAIL",
+        },
+    ),
+    (
+        (0, 1, 142),
+        Grib2Parameter {
+            parameter: "Effective Aspect Ratio of Subgrid Ice Clouds",
+            units: "",
+            abbrev: "EFARSIC",
+        },
+    ),
+    (
+        (0, 1, 143),
+        Grib2Parameter {
+            parameter: "Potential evaporation rate",
+            units: "kg m-2 s-1",
+            abbrev: "PERATE",
+        },
+    ),
+    (
+        (0, 1, 144),
+        Grib2Parameter {
+            parameter: "Specific rain water content (convective)",
+            units: "kg kg-1",
+            abbrev: "SRWATERC",
+        },
+    ),
+    (
+        (0, 1, 145),
+        Grib2Parameter {
+            parameter: "Specific snow water content (convective)",
+            units: "kg kg-1",
+            abbrev: "SSNOWWC",
+        },
+    ),
+    (
+        (0, 1, 146),
+        Grib2Parameter {
+            parameter: "Cloud ice precipitation rate",
+            units: "kg m-2 s-1",
+            abbrev: "CICEPR",
+        },
+    ),
+    (
+        (0, 1, 147),
+        Grib2Parameter {
+            parameter: "Character of precipitation",
+            units: "See Table 4.249",
+            abbrev: "CHPRECIP",
+        },
+    ),
+    (
+        (0, 1, 148),
+        Grib2Parameter {
+            parameter: "Snow evaporation rate",
+            units: "kg m-2 s-1",
+            abbrev: "SNOWERAT",
+        },
+    ),
+    (
+        (0, 1, 149),
+        Grib2Parameter {
+            parameter: "Cloud water mixing ratio",
+            units: "kg kg-1",
+            abbrev: "CWATERMR",
+        },
+    ),
+    (
+        (0, 1, 150),
+        Grib2Parameter {
+            parameter: "Column integrated eastward water vapour mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CEWVMF",
+        },
+    ),
+    (
+        (0, 1, 151),
+        Grib2Parameter {
+            parameter: "Column integrated northward water vapour mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CNWVMF",
+        },
+    ),
+    (
+        (0, 1, 152),
+        Grib2Parameter {
+            parameter: "Column integrated eastward cloud liquid water mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CECLWMF",
+        },
+    ),
+    (
+        (0, 1, 153),
+        Grib2Parameter {
+            parameter: "Column integrated northward cloud liquid water mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CNCLWMF",
+        },
+    ),
+    (
+        (0, 1, 154),
+        Grib2Parameter {
+            parameter: "Column integrated eastward cloud ice mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CECIMF",
+        },
+    ),
+    (
+        (0, 1, 155),
+        Grib2Parameter {
+            parameter: "Column integrated northward cloud ice mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CNCIMF",
+        },
+    ),
+    (
+        (0, 1, 156),
+        Grib2Parameter {
+            parameter: "Column integrated eastward rain mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CERMF",
+        },
+    ),
+    (
+        (0, 1, 157),
+        Grib2Parameter {
+            parameter: "Column integrated northward rain mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CNRMF",
+        },
+    ),
+    (
+        (0, 1, 158),
+        Grib2Parameter {
+            parameter: "Column integrated eastward snow mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CEFMF",
+        },
+    ),
+    (
+        (0, 1, 159),
+        Grib2Parameter {
+            parameter: "Column integrated northward snow mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CNSMF",
+        },
+    ),
+    (
+        (0, 1, 160),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of water vapour mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CDWFMF",
+        },
+    ),
+    (
+        (0, 1, 161),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of cloud liquid water mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CDCLWMF",
+        },
+    ),
+    (
+        (0, 1, 162),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of cloud ice mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CDCIMF",
+        },
+    ),
+    (
+        (0, 1, 163),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of rain mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CDRMF",
+        },
+    ),
+    (
+        (0, 1, 164),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of snow mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CDSMF",
+        },
+    ),
+    (
+        (0, 1, 165),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of total water mass flux",
+            units: "kg m-1s-1",
+            abbrev: "CDTWMF",
+        },
+    ),
+    (
+        (0, 1, 166),
+        Grib2Parameter {
+            parameter: "Column integrated water vapour flux",
+            units: "kg m-1s-1",
+            abbrev: "CWVF",
+        },
+    ),
+    (
+        (0, 1, 167),
+        Grib2Parameter {
+            parameter: "Total column supercooled liquid water",
+            units: "kg m-2",
+            abbrev: "TCSLW",
+        },
+    ),
+    (
+        (0, 1, 168),
+        Grib2Parameter {
+            parameter: "Saturation specific humidity with respect to water",
+            units: "kg m-3",
+            abbrev: "SSPFHW",
+        },
+    ),
+    (
+        (0, 1, 169),
+        Grib2Parameter {
+            parameter: "Total column integrated saturation specific humidity with respect to water",
+            units: "kg m-2",
+            abbrev: "TCISSPFHW",
+        },
+    ),
+    (
+        (0, 1, 192),
+        Grib2Parameter {
+            parameter: "Categorical Rain",
+            units: "Code table 4.222",
+            abbrev: "CRAIN",
+        },
+    ),
+    (
+        (0, 1, 193),
+        Grib2Parameter {
+            parameter: "Categorical Freezing Rain",
+            units: "Code table 4.222",
+            abbrev: "CFRZR",
+        },
+    ),
+    (
+        (0, 1, 194),
+        Grib2Parameter {
+            parameter: "Categorical Ice Pellets",
+            units: "Code table 4.222",
+            abbrev: "CICEP",
+        },
+    ),
+    (
+        (0, 1, 195),
+        Grib2Parameter {
+            parameter: "Categorical Snow",
+            units: "Code table 4.222",
+            abbrev: "CSNOW",
+        },
+    ),
+    (
+        (0, 1, 196),
+        Grib2Parameter {
+            parameter: "Convective Precipitation Rate",
+            units: "kg m-2 s-1",
+            abbrev: "CPRAT",
+        },
+    ),
+    (
+        (0, 1, 197),
+        Grib2Parameter {
+            parameter: "Horizontal Moisture Divergence",
+            units: "kg kg-1 s-1",
+            abbrev: "MDIV",
+        },
+    ),
+    (
+        (0, 1, 198),
+        Grib2Parameter { parameter: "Minimum Relative Humidity", units: "%", abbrev: "MINRH" },
+    ),
+    (
+        (0, 1, 199),
+        Grib2Parameter { parameter: "Potential Evaporation", units: "kg m-2", abbrev: "PEVAP" },
+    ),
+    (
+        (0, 1, 200),
+        Grib2Parameter { parameter: "Potential Evaporation Rate", units: "W m-2", abbrev: "PEVPR" },
+    ),
+    ((0, 1, 201), Grib2Parameter { parameter: "Snow Cover", units: "%", abbrev: "SNOWC" }),
+    (
+        (0, 1, 202),
+        Grib2Parameter {
+            parameter: "Rain Fraction of Total Liquid Water",
+            units: "non-dim",
+            abbrev: "FRAIN",
+        },
+    ),
+    ((0, 1, 203), Grib2Parameter { parameter: "Rime Factor", units: "non-dim", abbrev: "RIME" }),
+    (
+        (0, 1, 204),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Rain",
+            units: "kg m-2",
+            abbrev: "TCOLR",
+        },
+    ),
+    (
+        (0, 1, 205),
+        Grib2Parameter {
+            parameter: "Total Column Integrated Snow",
+            units: "kg m-2",
+            abbrev: "TCOLS",
+        },
+    ),
+    (
+        (0, 1, 206),
+        Grib2Parameter {
+            parameter: "Total Icing Potential Diagnostic",
+            units: "non-dim",
+            abbrev: "TIPD",
+        },
+    ),
+    (
+        (0, 1, 207),
+        Grib2Parameter {
+            parameter: "Number concentration for ice particles",
+            units: "non-dim",
+            abbrev: "NCIP",
+        },
+    ),
+    ((0, 1, 208), Grib2Parameter { parameter: "Snow temperature", units: "K", abbrev: "SNOT" }),
+    (
+        (0, 1, 209),
+        Grib2Parameter {
+            parameter: "Total column-integrated supercooled liquid water",
+            units: "kg m-2",
+            abbrev: "TCLSW",
+        },
+    ),
+    (
+        (0, 1, 210),
+        Grib2Parameter {
+            parameter: "Total column-integrated melting ice",
+            units: "kg m-2",
+            abbrev: "TCOLM",
+        },
+    ),
+    (
+        (0, 1, 211),
+        Grib2Parameter {
+            parameter: "Evaporation - Precipitation",
+            units: "cm/day",
+            abbrev: "EMNP",
+        },
+    ),
+    (
+        (0, 1, 212),
+        Grib2Parameter {
+            parameter: "Sublimation (evaporation from snow)",
+            units: "W m-2",
+            abbrev: "SBSNO",
+        },
+    ),
+    (
+        (0, 1, 213),
+        Grib2Parameter {
+            parameter: "Deep Convective Moistening Rate",
+            units: "kg kg-1 s-1",
+            abbrev: "CNVMR",
+        },
+    ),
+    (
+        (0, 1, 214),
+        Grib2Parameter {
+            parameter: "Shallow Convective Moistening Rate",
+            units: "kg kg-1 s-1",
+            abbrev: "SHAMR",
+        },
+    ),
+    (
+        (0, 1, 215),
+        Grib2Parameter {
+            parameter: "Vertical Diffusion Moistening Rate",
+            units: "kg kg-1 s-1",
+            abbrev: "VDFMR",
+        },
+    ),
+    (
+        (0, 1, 216),
+        Grib2Parameter {
+            parameter: "Condensation Pressure of Parcali",
+            units: "Pa",
+            abbrev: "CONDP",
+        },
+    ),
+    (
+        (0, 1, 217),
+        Grib2Parameter {
+            parameter: "Large scale moistening rate",
+            units: "kg kg-1 s-1",
+            abbrev: "LRGMR",
+        },
+    ),
+    (
+        (0, 1, 218),
+        Grib2Parameter {
+            parameter: "Specific humidity at top of viscous sublayer",
+            units: "kg kg-1",
+            abbrev: "QZ0",
+        },
+    ),
+    (
+        (0, 1, 219),
+        Grib2Parameter {
+            parameter: "Maximum specific humidity at 2m",
+            units: "kg kg-1",
+            abbrev: "QMAX",
+        },
+    ),
+    (
+        (0, 1, 220),
+        Grib2Parameter {
+            parameter: "Minimum specific humidity at 2m",
+            units: "kg kg-1",
+            abbrev: "QMIN",
+        },
+    ),
+    (
+        (0, 1, 221),
+        Grib2Parameter {
+            parameter: "Liquid precipitation (Rainfall)",
+            units: "kg m-2",
+            abbrev: "ARAIN",
+        },
+    ),
+    (
+        (0, 1, 222),
+        Grib2Parameter { parameter: "Snow temperature, depth-avg", units: "K", abbrev: "SNOWT" },
+    ),
+    (
+        (0, 1, 223),
+        Grib2Parameter {
+            parameter: "Total precipitation (nearest grid point)",
+            units: "kg m-2",
+            abbrev: "APCPN",
+        },
+    ),
+    (
+        (0, 1, 224),
+        Grib2Parameter {
+            parameter: "Convective precipitation (nearest grid point)",
+            units: "kg m-2",
+            abbrev: "ACPCPN",
+        },
+    ),
+    ((0, 1, 225), Grib2Parameter { parameter: "Freezing Rain", units: "kg m-2", abbrev: "FRZR" }),
+    (
+        (0, 1, 226),
+        Grib2Parameter { parameter: "Dominant Weather", units: "Numeric", abbrev: "PWTHER" },
+    ),
+    ((0, 1, 227), Grib2Parameter { parameter: "Frozen Rain", units: "kg m-2", abbrev: "FROZR" }),
+    (
+        (0, 1, 228),
+        Grib2Parameter {
+            parameter: "Flat Ice Accumulation (FRAM)",
+            units: "kg m-2",
+            abbrev: "FICEAC",
+        },
+    ),
+    (
+        (0, 1, 229),
+        Grib2Parameter {
+            parameter: "Line Ice Accumulation (FRAM)",
+            units: "kg m-2",
+            abbrev: "LICEAC",
+        },
+    ),
+    (
+        (0, 1, 230),
+        Grib2Parameter { parameter: "Sleet Accumulation", units: "kg m-2", abbrev: "SLACC" },
+    ),
+    (
+        (0, 1, 231),
+        Grib2Parameter { parameter: "Precipitation Potential Index", units: "%", abbrev: "PPINDX" },
+    ),
+    (
+        (0, 1, 232),
+        Grib2Parameter {
+            parameter: "Probability Cloud Ice Present",
+            units: "%",
+            abbrev: "PROBCIP",
+        },
+    ),
+    (
+        (0, 1, 233),
+        Grib2Parameter { parameter: "Snow Liquid Ratio", units: "kg kg-1", abbrev: "SNOWLR" },
+    ),
+    (
+        (0, 1, 234),
+        Grib2Parameter { parameter: "Precipitation Duration", units: "hour", abbrev: "PCPDUR" },
+    ),
+    (
+        (0, 1, 235),
+        Grib2Parameter {
+            parameter: "Cloud Liquid Mixing Ratio",
+            units: "kg kg-1",
+            abbrev: "CLLMR",
+        },
+    ),
+    ((0, 1, 241), Grib2Parameter { parameter: "Total Snow", units: "kg m-2", abbrev: "TSNOW" }),
+    (
+        (0, 1, 242),
+        Grib2Parameter {
+            parameter: "Relative Humidity with Respect to Precipitable Water",
+            units: "%",
+            abbrev: "RHPW",
+        },
+    ),
+    (
+        (0, 1, 245),
+        Grib2Parameter {
+            parameter: "Hourly Maximum of Column Vertical Integrated Graupel",
+            units: "kg m-2",
+            abbrev: "MAXVIG",
+        },
+    ),
+    ((0, 1, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 2, 0),
+        Grib2Parameter {
+            parameter: "Wind Direction (from which blowing)",
+            units: "°",
+            abbrev: "WDIR",
+        },
+    ),
+    ((0, 2, 1), Grib2Parameter { parameter: "Wind Speed", units: "m s-1", abbrev: "WIND" }),
+    (
+        (0, 2, 2),
+        Grib2Parameter { parameter: "U-Component of Wind", units: "m s-1", abbrev: "UGRD" },
+    ),
+    (
+        (0, 2, 3),
+        Grib2Parameter { parameter: "V-Component of Wind", units: "m s-1", abbrev: "VGRD" },
+    ),
+    ((0, 2, 4), Grib2Parameter { parameter: "Stream Function", units: "m2 s-1", abbrev: "STRM" }),
+    (
+        (0, 2, 5),
+        Grib2Parameter { parameter: "Velocity Potential", units: "m2 s-1", abbrev: "VPOT" },
+    ),
+    (
+        (0, 2, 6),
+        Grib2Parameter {
+            parameter: "Montgomery Stream Function",
+            units: "m2 s-2",
+            abbrev: "MNTSF",
+        },
+    ),
+    (
+        (0, 2, 7),
+        Grib2Parameter {
+            parameter: "Sigma Coordinate Vertical Velocity",
+            units: "s-1",
+            abbrev: "SGCVV",
+        },
+    ),
+    (
+        (0, 2, 8),
+        Grib2Parameter {
+            parameter: "Vertical Velocity (Pressure)",
+            units: "Pa s-1",
+            abbrev: "VVEL",
+        },
+    ),
+    (
+        (0, 2, 9),
+        Grib2Parameter {
+            parameter: "Vertical Velocity (Geometric)",
+            units: "m s-1",
+            abbrev: "DZDT",
+        },
+    ),
+    ((0, 2, 10), Grib2Parameter { parameter: "Absolute Vorticity", units: "s-1", abbrev: "ABSV" }),
+    ((0, 2, 11), Grib2Parameter { parameter: "Absolute Divergence", units: "s-1", abbrev: "ABSD" }),
+    ((0, 2, 12), Grib2Parameter { parameter: "Relative Vorticity", units: "s-1", abbrev: "RELV" }),
+    ((0, 2, 13), Grib2Parameter { parameter: "Relative Divergence", units: "s-1", abbrev: "RELD" }),
+    (
+        (0, 2, 14),
+        Grib2Parameter {
+            parameter: "Potential Vorticity",
+            units: "K m2 kg-1 s-1",
+            abbrev: "PVORT",
+        },
+    ),
+    (
+        (0, 2, 15),
+        Grib2Parameter { parameter: "Vertical U-Component Shear", units: "s-1", abbrev: "VUCSH" },
+    ),
+    (
+        (0, 2, 16),
+        Grib2Parameter { parameter: "Vertical V-Component Shear", units: "s-1", abbrev: "VVCSH" },
+    ),
+    (
+        (0, 2, 17),
+        Grib2Parameter { parameter: "Momentum Flux, U-Component", units: "N m-2", abbrev: "UFLX" },
+    ),
+    (
+        (0, 2, 18),
+        Grib2Parameter { parameter: "Momentum Flux, V-Component", units: "N m-2", abbrev: "VFLX" },
+    ),
+    ((0, 2, 19), Grib2Parameter { parameter: "Wind Mixing Energy", units: "J", abbrev: "WMIXE" }),
+    (
+        (0, 2, 20),
+        Grib2Parameter { parameter: "Boundary Layer Dissipation", units: "W m-2", abbrev: "BLYDP" },
+    ),
+    (
+        (0, 2, 21),
+        Grib2Parameter { parameter: "Maximum Wind Speed", units: "m s-1", abbrev: "MAXGUST" },
+    ),
+    ((0, 2, 22), Grib2Parameter { parameter: "Wind Speed (Gust)", units: "m s-1", abbrev: "GUST" }),
+    (
+        (0, 2, 23),
+        Grib2Parameter { parameter: "U-Component of Wind (Gust)", units: "m s-1", abbrev: "UGUST" },
+    ),
+    (
+        (0, 2, 24),
+        Grib2Parameter { parameter: "V-Component of Wind (Gust)", units: "m s-1", abbrev: "VGUST" },
+    ),
+    (
+        (0, 2, 25),
+        Grib2Parameter { parameter: "Vertical Speed Shear", units: "s-1", abbrev: "VWSH" },
+    ),
+    (
+        (0, 2, 26),
+        Grib2Parameter { parameter: "Horizontal Momentum Flux", units: "N m-2", abbrev: "MFLX" },
+    ),
+    (
+        (0, 2, 27),
+        Grib2Parameter { parameter: "U-Component Storm Motion", units: "m s-1", abbrev: "USTM" },
+    ),
+    (
+        (0, 2, 28),
+        Grib2Parameter { parameter: "V-Component Storm Motion", units: "m s-1", abbrev: "VSTM" },
+    ),
+    ((0, 2, 29), Grib2Parameter { parameter: "Drag Coefficient", units: "Numeric", abbrev: "CD" }),
+    (
+        (0, 2, 30),
+        Grib2Parameter { parameter: "Frictional Velocity", units: "m s-1", abbrev: "FRICV" },
+    ),
+    (
+        (0, 2, 31),
+        Grib2Parameter {
+            parameter: "Turbulent Diffusion Coefficient for Momentum",
+            units: "m2 s-1",
+            abbrev: "TDCMOM",
+        },
+    ),
+    (
+        (0, 2, 32),
+        Grib2Parameter {
+            parameter: "Eta Coordinate Vertical Velocity",
+            units: "s-1",
+            abbrev: "ETACVV",
+        },
+    ),
+    ((0, 2, 33), Grib2Parameter { parameter: "Wind Fetch", units: "m", abbrev: "WINDF" }),
+    (
+        (0, 2, 34),
+        Grib2Parameter { parameter: "Normal Wind Component", units: "m s-1", abbrev: "NWIND" },
+    ),
+    (
+        (0, 2, 35),
+        Grib2Parameter { parameter: "Tangential Wind Component", units: "m s-1", abbrev: "TWIND" },
+    ),
+    (
+        (0, 2, 36),
+        Grib2Parameter {
+            parameter: "Amplitude Function for Rossby Wave Envelope",
+            units: "m s-1",
+            abbrev: "AFRWE",
+        },
+    ),
+    (
+        (0, 2, 37),
+        Grib2Parameter {
+            parameter: "Northward Turbulent Surface Stress",
+            units: "N m-2 s",
+            abbrev: "NTSS",
+        },
+    ),
+    (
+        (0, 2, 38),
+        Grib2Parameter {
+            parameter: "Eastward Turbulent Surface Stress",
+            units: "N m-2 s",
+            abbrev: "ETSS",
+        },
+    ),
+    (
+        (0, 2, 39),
+        Grib2Parameter {
+            parameter: "Eastward Wind Tendency Due to Parameterizations",
+            units: "m s-2",
+            abbrev: "EWTPARM",
+        },
+    ),
+    (
+        (0, 2, 40),
+        Grib2Parameter {
+            parameter: "Northward Wind Tendency Due to Parameterizations",
+            units: "m s-2",
+            abbrev: "NWTPARM",
+        },
+    ),
+    (
+        (0, 2, 41),
+        Grib2Parameter {
+            parameter: "U-Component of Geostrophic Wind",
+            units: "m s-1",
+            abbrev: "UGWIND",
+        },
+    ),
+    (
+        (0, 2, 42),
+        Grib2Parameter {
+            parameter: "V-Component of Geostrophic Wind",
+            units: "m s-1",
+            abbrev: "VGWIND",
+        },
+    ),
+    (
+        (0, 2, 43),
+        Grib2Parameter { parameter: "Geostrophic Wind Direction", units: "°", abbrev: "GEOWD" },
+    ),
+    (
+        (0, 2, 44),
+        Grib2Parameter { parameter: "Geostrophic Wind Speed", units: "m s-1", abbrev: "GEOWS" },
+    ),
+    (
+        (0, 2, 45),
+        Grib2Parameter {
+            parameter: "Unbalanced Component of Divergence",
+            units: "s-1",
+            abbrev: "UNDIV",
+        },
+    ),
+    (
+        (0, 2, 46),
+        Grib2Parameter { parameter: "Vorticity Advection", units: "s-2", abbrev: "VORTADV" },
+    ),
+    (
+        (0, 2, 47),
+        Grib2Parameter { parameter: "Surface Roughness for Heat", units: "m", abbrev: "SFRHEAT" },
+    ),
+    (
+        (0, 2, 48),
+        Grib2Parameter {
+            parameter: "Surface Roughness for Moisture",
+            units: "m",
+            abbrev: "SFRMOIST",
+        },
+    ),
+    ((0, 2, 49), Grib2Parameter { parameter: "Wind Stress", units: "N m-2", abbrev: "WINDSTR" }),
+    (
+        (0, 2, 50),
+        Grib2Parameter { parameter: "Eastward Wind Stress", units: "N m-2", abbrev: "EWINDSTR" },
+    ),
+    (
+        (0, 2, 51),
+        Grib2Parameter { parameter: "Northward Wind Stress", units: "N m-2", abbrev: "NWINDSTR" },
+    ),
+    (
+        (0, 2, 52),
+        Grib2Parameter {
+            parameter: "U-Component of Wind Stress",
+            units: "N m-2",
+            abbrev: "UWINDSTR",
+        },
+    ),
+    (
+        (0, 2, 53),
+        Grib2Parameter {
+            parameter: "V-Component of Wind Stress",
+            units: "N m-2",
+            abbrev: "VWINDSTR",
+        },
+    ),
+    (
+        (0, 2, 54),
+        Grib2Parameter {
+            parameter: "Natural Logarithm of Surface Roughness Length for Heat",
+            units: "m",
+            abbrev: "NLSRLH",
+        },
+    ),
+    (
+        (0, 2, 55),
+        Grib2Parameter {
+            parameter: "Natural Logarithm of Surface Roughness Length for Moisture",
+            units: "m",
+            abbrev: "NLSRLM",
+        },
+    ),
+    (
+        (0, 2, 56),
+        Grib2Parameter {
+            parameter: "U-Component of Neutral Wind",
+            units: "m s-1",
+            abbrev: "UNWIND",
+        },
+    ),
+    (
+        (0, 2, 57),
+        Grib2Parameter {
+            parameter: "V-Component of Neutral Wind",
+            units: "m s-1",
+            abbrev: "VNWIND",
+        },
+    ),
+    (
+        (0, 2, 58),
+        Grib2Parameter {
+            parameter: "Magnitude of Turbulent Surface Stress",
+            units: "N m-2",
+            abbrev: "TSFCSTR",
+        },
+    ),
+    ((0, 2, 59), Grib2Parameter { parameter: "Vertical Divergence", units: "s-1", abbrev: "VDIV" }),
+    (
+        (0, 2, 60),
+        Grib2Parameter { parameter: "Drag Thermal Coefficient", units: "Numeric", abbrev: "DTC" },
+    ),
+    (
+        (0, 2, 61),
+        Grib2Parameter {
+            parameter: "Drag Evaporation Coefficient",
+            units: "Numeric",
+            abbrev: "DEC",
+        },
+    ),
+    (
+        (0, 2, 62),
+        Grib2Parameter {
+            parameter: "Eastward Turbulent Surface Stress",
+            units: "N m-2",
+            abbrev: "EASTTSS",
+        },
+    ),
+    (
+        (0, 2, 63),
+        Grib2Parameter {
+            parameter: "Northward Turbulent Surface Stress",
+            units: "N m-2",
+            abbrev: "NRTHTSS",
+        },
+    ),
+    (
+        (0, 2, 192),
+        Grib2Parameter { parameter: "Vertical Speed Shear", units: "s-1", abbrev: "VWSH" },
+    ),
+    (
+        (0, 2, 193),
+        Grib2Parameter { parameter: "Horizontal Momentum Flux", units: "N m-2", abbrev: "MFLX" },
+    ),
+    (
+        (0, 2, 194),
+        Grib2Parameter { parameter: "U-Component Storm Motion", units: "m s-1", abbrev: "USTM" },
+    ),
+    (
+        (0, 2, 195),
+        Grib2Parameter { parameter: "V-Component Storm Motion", units: "m s-1", abbrev: "VSTM" },
+    ),
+    ((0, 2, 196), Grib2Parameter { parameter: "Drag Coefficient", units: "non-dim", abbrev: "CD" }),
+    (
+        (0, 2, 197),
+        Grib2Parameter { parameter: "Frictional Velocity", units: "m s-1", abbrev: "FRICV" },
+    ),
+    (
+        (0, 2, 198),
+        Grib2Parameter {
+            parameter: "Latitude of U Wind Component of Velocity",
+            units: "deg",
+            abbrev: "LAUV",
+        },
+    ),
+    (
+        (0, 2, 199),
+        Grib2Parameter {
+            parameter: "Longitude of U Wind Component of Velocity",
+            units: "deg",
+            abbrev: "LOUV",
+        },
+    ),
+    (
+        (0, 2, 200),
+        Grib2Parameter {
+            parameter: "Latitude of V Wind Component of Velocity",
+            units: "deg",
+            abbrev: "LAVV",
+        },
+    ),
+    (
+        (0, 2, 201),
+        Grib2Parameter {
+            parameter: "Longitude of V Wind Component of Velocity",
+            units: "deg",
+            abbrev: "LOVV",
+        },
+    ),
+    (
+        (0, 2, 202),
+        Grib2Parameter { parameter: "Latitude of Pressure Point", units: "deg", abbrev: "LAPP" },
+    ),
+    (
+        (0, 2, 203),
+        Grib2Parameter { parameter: "Longitude of Pressure Point", units: "deg", abbrev: "LOPP" },
+    ),
+    (
+        (0, 2, 204),
+        Grib2Parameter {
+            parameter: "Vertical Eddy Diffusivity Heat Exchange",
+            units: "m2 s-1",
+            abbrev: "VEDH",
+        },
+    ),
+    (
+        (0, 2, 205),
+        Grib2Parameter {
+            parameter: "Covariance between Meridional and Zonal Components of the Wind",
+            units: "m2 s-2",
+            abbrev: "COVMZ",
+        },
+    ),
+    (
+        (0, 2, 206),
+        Grib2Parameter {
+            parameter: "Covariance between Temperature and Zonal Components of the Wind",
+            units: "K*m s-1",
+            abbrev: "COVTZ",
+        },
+    ),
+    (
+        (0, 2, 207),
+        Grib2Parameter {
+            parameter: "Covariance between Temperature and Meridional Components of the Wind",
+            units: "K*m s-1",
+            abbrev: "COVTM",
+        },
+    ),
+    (
+        (0, 2, 208),
+        Grib2Parameter {
+            parameter: "Vertical Diffusion Zonal Acceleration",
+            units: "m s-2",
+            abbrev: "VDFUA",
+        },
+    ),
+    (
+        (0, 2, 209),
+        Grib2Parameter {
+            parameter: "Vertical Diffusion Meridional Acceleration",
+            units: "m s-2",
+            abbrev: "VDFVA",
+        },
+    ),
+    (
+        (0, 2, 210),
+        Grib2Parameter {
+            parameter: "Gravity Wave Drag Zonal Acceleration",
+            units: "m s-2",
+            abbrev: "GWDU",
+        },
+    ),
+    (
+        (0, 2, 211),
+        Grib2Parameter {
+            parameter: "Gravity Wave Drag Meridional Acceleration",
+            units: "m s-2",
+            abbrev: "GWDV",
+        },
+    ),
+    (
+        (0, 2, 212),
+        Grib2Parameter {
+            parameter: "Convective Zonal Momentum Mixing Acceleration",
+            units: "m s-2",
+            abbrev: "CNVU",
+        },
+    ),
+    (
+        (0, 2, 213),
+        Grib2Parameter {
+            parameter: "Convective Meridional Momentum Mixing Acceleration",
+            units: "m s-2",
+            abbrev: "CNVV",
+        },
+    ),
+    (
+        (0, 2, 214),
+        Grib2Parameter {
+            parameter: "Tendency of Vertical Velocity",
+            units: "m s-2",
+            abbrev: "WTEND",
+        },
+    ),
+    (
+        (0, 2, 215),
+        Grib2Parameter {
+            parameter: "Omega (Dp/Dt) Divide by Density",
+            units: "K",
+            abbrev: "OMGALF",
+        },
+    ),
+    (
+        (0, 2, 216),
+        Grib2Parameter {
+            parameter: "Convective Gravity Wave Drag Zonal Acceleration",
+            units: "m s-2",
+            abbrev: "CNGWDU",
+        },
+    ),
+    (
+        (0, 2, 217),
+        Grib2Parameter {
+            parameter: "Convective Gravity Wave Drag Meridional Acceleration",
+            units: "m s-2",
+            abbrev: "CNGWDV",
+        },
+    ),
+    (
+        (0, 2, 218),
+        Grib2Parameter { parameter: "Velocity Point Model Surface", units: "m", abbrev: "LMV" },
+    ),
+    (
+        (0, 2, 219),
+        Grib2Parameter {
+            parameter: "Potential Vorticity (Mass-Weighted)",
+            units: "1/s/m",
+            abbrev: "PVMWW",
+        },
+    ),
+    (
+        (0, 2, 220),
+        Grib2Parameter {
+            parameter: "Hourly Maximum of Upward Vertical Velocity",
+            units: "m s-1",
+            abbrev: "MAXUVV",
+        },
+    ),
+    (
+        (0, 2, 221),
+        Grib2Parameter {
+            parameter: "Hourly Maximum of Downward Vertical Velocity",
+            units: "m s-1",
+            abbrev: "MAXDVV",
+        },
+    ),
+    (
+        (0, 2, 222),
+        Grib2Parameter {
+            parameter: "U Component of Hourly Maximum 10m Wind Speed",
+            units: "m s-1",
+            abbrev: "MAXUW",
+        },
+    ),
+    (
+        (0, 2, 223),
+        Grib2Parameter {
+            parameter: "V Component of Hourly Maximum 10m Wind Speed",
+            units: "m s-1",
+            abbrev: "MAXVW",
+        },
+    ),
+    (
+        (0, 2, 224),
+        Grib2Parameter { parameter: "Ventilation Rate", units: "m2 s-1", abbrev: "VRATE" },
+    ),
+    (
+        (0, 2, 225),
+        Grib2Parameter { parameter: "Transport Wind Speed", units: "m s-1", abbrev: "TRWSPD" },
+    ),
+    (
+        (0, 2, 226),
+        Grib2Parameter { parameter: "Transport Wind Direction", units: "°", abbrev: "TRWDIR" },
+    ),
+    (
+        (0, 2, 227),
+        Grib2Parameter {
+            parameter: "Earliest Reasonable Arrival Time (10% Exceedance)",
+            units: "s",
+            abbrev: "TOA10",
+        },
+    ),
+    (
+        (0, 2, 228),
+        Grib2Parameter {
+            parameter: "Most Likely Arrival Time (50% Exceedance)",
+            units: "s",
+            abbrev: "TOA50",
+        },
+    ),
+    (
+        (0, 2, 229),
+        Grib2Parameter {
+            parameter: "Most Likely Departure Time (50% Exceedance)",
+            units: "s",
+            abbrev: "TOD50",
+        },
+    ),
+    (
+        (0, 2, 230),
+        Grib2Parameter {
+            parameter: "Latest Reasonable Departure Time (90% Exceedance)",
+            units: "s",
+            abbrev: "TOD90",
+        },
+    ),
+    (
+        (0, 2, 231),
+        Grib2Parameter { parameter: "Tropical Wind Direction", units: "°", abbrev: "TPWDIR" },
+    ),
+    (
+        (0, 2, 232),
+        Grib2Parameter { parameter: "Tropical Wind Speed", units: "m s-1", abbrev: "TPWSPD" },
+    ),
+    (
+        (0, 2, 233),
+        Grib2Parameter {
+            parameter: "Inflow Based (ESFC) to 50% EL Shear Magnitude",
+            units: "kt",
+            abbrev: "ESHR",
+        },
+    ),
+    (
+        (0, 2, 234),
+        Grib2Parameter {
+            parameter: "U Component Inflow Based to 50% EL Shear Vector",
+            units: "kt",
+            abbrev: "UESH",
+        },
+    ),
+    (
+        (0, 2, 235),
+        Grib2Parameter {
+            parameter: "V Component Inflow Based to 50% EL Shear Vector",
+            units: "kt",
+            abbrev: "VESH",
+        },
+    ),
+    (
+        (0, 2, 236),
+        Grib2Parameter {
+            parameter: "U Component Bunkers Effective Right Motion",
+            units: "kt",
+            abbrev: "UEID",
+        },
+    ),
+    (
+        (0, 2, 237),
+        Grib2Parameter {
+            parameter: "V Component Bunkers Effective Right Motion",
+            units: "kt",
+            abbrev: "VEID",
+        },
+    ),
+    ((0, 2, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    ((0, 3, 0), Grib2Parameter { parameter: "Pressure", units: "Pa", abbrev: "PRES" }),
+    (
+        (0, 3, 1),
+        Grib2Parameter { parameter: "Pressure Reduced to MSL", units: "Pa", abbrev: "PRMSL" },
+    ),
+    (
+        (0, 3, 2),
+        Grib2Parameter { parameter: "Pressure Tendency", units: "Pa s-1", abbrev: "PTEND" },
+    ),
+    (
+        (0, 3, 3),
+        Grib2Parameter {
+            parameter: "ICAO Standard Atmosphere Reference Height",
+            units: "m",
+            abbrev: "ICAHT",
+        },
+    ),
+    ((0, 3, 4), Grib2Parameter { parameter: "Geopotential", units: "m2 s-2", abbrev: "GP" }),
+    ((0, 3, 5), Grib2Parameter { parameter: "Geopotential Height", units: "gpm", abbrev: "HGT" }),
+    ((0, 3, 6), Grib2Parameter { parameter: "Geometric Height", units: "m", abbrev: "DIST" }),
+    (
+        (0, 3, 7),
+        Grib2Parameter { parameter: "Standard Deviation of Height", units: "m", abbrev: "HSTDV" },
+    ),
+    ((0, 3, 8), Grib2Parameter { parameter: "Pressure Anomaly", units: "Pa", abbrev: "PRESA" }),
+    (
+        (0, 3, 9),
+        Grib2Parameter { parameter: "Geopotential Height Anomaly", units: "gpm", abbrev: "GPA" },
+    ),
+    ((0, 3, 10), Grib2Parameter { parameter: "Density", units: "kg m-3", abbrev: "DEN" }),
+    ((0, 3, 11), Grib2Parameter { parameter: "Altimeter Setting", units: "Pa", abbrev: "ALTS" }),
+    ((0, 3, 12), Grib2Parameter { parameter: "Thickness", units: "m", abbrev: "THICK" }),
+    ((0, 3, 13), Grib2Parameter { parameter: "Pressure Altitude", units: "m", abbrev: "PRESALT" }),
+    ((0, 3, 14), Grib2Parameter { parameter: "Density Altitude", units: "m", abbrev: "DENALT" }),
+    (
+        (0, 3, 15),
+        Grib2Parameter { parameter: "5-Wave Geopotential Height", units: "gpm", abbrev: "5WAVH" },
+    ),
+    (
+        (0, 3, 16),
+        Grib2Parameter {
+            parameter: "Zonal Flux of Gravity Wave Stress",
+            units: "N m-2",
+            abbrev: "U-GWD",
+        },
+    ),
+    (
+        (0, 3, 17),
+        Grib2Parameter {
+            parameter: "Meridional Flux of Gravity Wave Stress",
+            units: "N m-2",
+            abbrev: "V-GWD",
+        },
+    ),
+    (
+        (0, 3, 18),
+        Grib2Parameter { parameter: "Planetary Boundary Layer Height", units: "m", abbrev: "HPBL" },
+    ),
+    (
+        (0, 3, 19),
+        Grib2Parameter {
+            parameter: "5-Wave Geopotential Height Anomaly",
+            units: "gpm",
+            abbrev: "5WAVA",
+        },
+    ),
+    (
+        (0, 3, 20),
+        Grib2Parameter {
+            parameter: "Standard Deviation of Sub-Grid Scale Orography",
+            units: "m",
+            abbrev: "SDSGSO",
+        },
+    ),
+    (
+        (0, 3, 21),
+        Grib2Parameter {
+            parameter: "Angle of Sub-Grid Scale Orography",
+            units: "rad",
+            abbrev: "AOSGSO",
+        },
+    ),
+    (
+        (0, 3, 22),
+        Grib2Parameter {
+            parameter: "Slope of Sub-Grid Scale Orography",
+            units: "Numeric",
+            abbrev: "SSGSO",
+        },
+    ),
+    (
+        (0, 3, 23),
+        Grib2Parameter { parameter: "Gravity Wave Dissipation", units: "W m-2", abbrev: "GWD" },
+    ),
+    (
+        (0, 3, 24),
+        Grib2Parameter {
+            parameter: "Anisotropy of Sub-Grid Scale Orography",
+            units: "Numeric",
+            abbrev: "ASGSO",
+        },
+    ),
+    (
+        (0, 3, 25),
+        Grib2Parameter {
+            parameter: "Natural Logarithm of Pressure in Pa",
+            units: "Numeric",
+            abbrev: "NLPRES",
+        },
+    ),
+    (
+        (0, 3, 26),
+        Grib2Parameter { parameter: "Exner Pressure", units: "Numeric", abbrev: "EXPRES" },
+    ),
+    (
+        (0, 3, 27),
+        Grib2Parameter { parameter: "Updraught Mass Flux", units: "kg m-2 s-1", abbrev: "UMFLX" },
+    ),
+    (
+        (0, 3, 28),
+        Grib2Parameter { parameter: "Downdraught Mass Flux", units: "kg m-2 s-1", abbrev: "DMFLX" },
+    ),
+    (
+        (0, 3, 29),
+        Grib2Parameter {
+            parameter: "Updraught Detrainment Rate",
+            units: "kg m-3 s-1",
+            abbrev: "UDRATE",
+        },
+    ),
+    (
+        (0, 3, 30),
+        Grib2Parameter {
+            parameter: "Downdraught Detrainment Rate",
+            units: "kg m-3 s-1",
+            abbrev: "DDRATE",
+        },
+    ),
+    (
+        (0, 3, 31),
+        Grib2Parameter {
+            parameter: "Unbalanced Component of Logarithm of Surface Pressure",
+            units: "-",
+            abbrev: "UCLSPRS",
+        },
+    ),
+    (
+        (0, 3, 32),
+        Grib2Parameter {
+            parameter: "Saturation water vapour pressure",
+            units: "Pa",
+            abbrev: "SWATERVP",
+        },
+    ),
+    (
+        (0, 3, 33),
+        Grib2Parameter {
+            parameter: "Geometric altitude above mean sea level",
+            units: "m",
+            abbrev: "GAMSL",
+        },
+    ),
+    (
+        (0, 3, 34),
+        Grib2Parameter {
+            parameter: "Geometric height above ground level",
+            units: "m",
+            abbrev: "GHAGRD",
+        },
+    ),
+    (
+        (0, 3, 35),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of total mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CDTMF",
+        },
+    ),
+    (
+        (0, 3, 36),
+        Grib2Parameter {
+            parameter: "Column integrated eastward total mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CETMF",
+        },
+    ),
+    (
+        (0, 3, 37),
+        Grib2Parameter {
+            parameter: "Column integrated northward total mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CNTMF",
+        },
+    ),
+    (
+        (0, 3, 38),
+        Grib2Parameter {
+            parameter: "Standard deviation of filtered subgrid orography",
+            units: "m",
+            abbrev: "SDFSO",
+        },
+    ),
+    (
+        (0, 3, 39),
+        Grib2Parameter {
+            parameter: "Column integrated mass of atmosphere",
+            units: "kg m-2 s-1",
+            abbrev: "CMATMOS",
+        },
+    ),
+    (
+        (0, 3, 40),
+        Grib2Parameter {
+            parameter: "Column integrated eastward geopotential flux",
+            units: "W m-1",
+            abbrev: "CEGFLUX",
+        },
+    ),
+    (
+        (0, 3, 41),
+        Grib2Parameter {
+            parameter: "Column integrated northward geopotential flux",
+            units: "W m-1",
+            abbrev: "CNGFLUX",
+        },
+    ),
+    (
+        (0, 3, 42),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of water geopotential flux",
+            units: "W m-2",
+            abbrev: "CDWGFLUX",
+        },
+    ),
+    (
+        (0, 3, 43),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of geopotential flux",
+            units: "W m-2",
+            abbrev: "CDGFLUX",
+        },
+    ),
+    (
+        (0, 3, 44),
+        Grib2Parameter {
+            parameter: "Height of zero-degree wet-bulb temperature",
+            units: "m",
+            abbrev: "HWBT",
+        },
+    ),
+    (
+        (0, 3, 45),
+        Grib2Parameter {
+            parameter: "Height of one-degree wet-bulb temperature",
+            units: "m",
+            abbrev: "WOBT",
+        },
+    ),
+    (
+        (0, 3, 46),
+        Grib2Parameter {
+            parameter: "Pressure departure from hydrostatic state",
+            units: "Pa",
+            abbrev: "PRESDHS",
+        },
+    ),
+    (
+        (0, 3, 192),
+        Grib2Parameter { parameter: "MSLP (Eta model reduction)", units: "Pa", abbrev: "MSLET" },
+    ),
+    (
+        (0, 3, 193),
+        Grib2Parameter { parameter: "5-Wave Geopotential Height", units: "gpm", abbrev: "5WAVH" },
+    ),
+    (
+        (0, 3, 194),
+        Grib2Parameter {
+            parameter: "Zonal Flux of Gravity Wave Stress",
+            units: "N m-2",
+            abbrev: "U-GWD",
+        },
+    ),
+    (
+        (0, 3, 195),
+        Grib2Parameter {
+            parameter: "Meridional Flux of Gravity Wave Stress",
+            units: "N m-2",
+            abbrev: "V-GWD",
+        },
+    ),
+    (
+        (0, 3, 196),
+        Grib2Parameter { parameter: "Planetary Boundary Layer Height", units: "m", abbrev: "HPBL" },
+    ),
+    (
+        (0, 3, 197),
+        Grib2Parameter {
+            parameter: "5-Wave Geopotential Height Anomaly",
+            units: "gpm",
+            abbrev: "5WAVA",
+        },
+    ),
+    (
+        (0, 3, 198),
+        Grib2Parameter { parameter: "MSLP (MAPS System Reduction)", units: "Pa", abbrev: "MSLMA" },
+    ),
+    (
+        (0, 3, 199),
+        Grib2Parameter {
+            parameter: "3-hr pressure tendency (Std. Atmos. Reduction)",
+            units: "Pa s-1",
+            abbrev: "TSLSA",
+        },
+    ),
+    (
+        (0, 3, 200),
+        Grib2Parameter {
+            parameter: "Pressure of level from which parcel was lifted",
+            units: "Pa",
+            abbrev: "PLPL",
+        },
+    ),
+    (
+        (0, 3, 201),
+        Grib2Parameter { parameter: "X-gradient of Log Pressure", units: "m-1", abbrev: "LPSX" },
+    ),
+    (
+        (0, 3, 202),
+        Grib2Parameter { parameter: "Y-gradient of Log Pressure", units: "m-1", abbrev: "LPSY" },
+    ),
+    (
+        (0, 3, 203),
+        Grib2Parameter { parameter: "X-gradient of Height", units: "m-1", abbrev: "HGTX" },
+    ),
+    (
+        (0, 3, 204),
+        Grib2Parameter { parameter: "Y-gradient of Height", units: "m-1", abbrev: "HGTY" },
+    ),
+    ((0, 3, 205), Grib2Parameter { parameter: "Layer Thickness", units: "m", abbrev: "LAYTH" }),
+    (
+        (0, 3, 206),
+        Grib2Parameter {
+            parameter: "Natural Log of Surface Pressure",
+            units: "ln (kPa)",
+            abbrev: "NLGSP",
+        },
+    ),
+    (
+        (0, 3, 207),
+        Grib2Parameter {
+            parameter: "Convective updraft mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CNVUMF",
+        },
+    ),
+    (
+        (0, 3, 208),
+        Grib2Parameter {
+            parameter: "Convective downdraft mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CNVDMF",
+        },
+    ),
+    (
+        (0, 3, 209),
+        Grib2Parameter {
+            parameter: "Convective detrainment mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CNVDEMF",
+        },
+    ),
+    (
+        (0, 3, 210),
+        Grib2Parameter { parameter: "Mass Point Model Surface", units: "", abbrev: "LMH" },
+    ),
+    (
+        (0, 3, 211),
+        Grib2Parameter {
+            parameter: "Geopotential Height (nearest grid point)",
+            units: "gpm",
+            abbrev: "HGTN",
+        },
+    ),
+    (
+        (0, 3, 212),
+        Grib2Parameter { parameter: "Pressure (nearest grid point)", units: "Pa", abbrev: "PRESN" },
+    ),
+    (
+        (0, 3, 213),
+        Grib2Parameter { parameter: "Orographic Convexity", units: "", abbrev: "ORCONV" },
+    ),
+    (
+        (0, 3, 214),
+        Grib2Parameter {
+            parameter: "Orographic Asymmetry, W Component",
+            units: "",
+            abbrev: "ORASW",
+        },
+    ),
+    (
+        (0, 3, 215),
+        Grib2Parameter {
+            parameter: "Orographic Asymmetry, S Component",
+            units: "",
+            abbrev: "ORASS",
+        },
+    ),
+    (
+        (0, 3, 216),
+        Grib2Parameter {
+            parameter: "Orographic Asymmetry, SW Component",
+            units: "",
+            abbrev: "ORASSW",
+        },
+    ),
+    (
+        (0, 3, 217),
+        Grib2Parameter {
+            parameter: "Orographic Asymmetry, NW Component",
+            units: "",
+            abbrev: "ORASNW",
+        },
+    ),
+    (
+        (0, 3, 218),
+        Grib2Parameter {
+            parameter: "Orographic Length Scale, W Component",
+            units: "",
+            abbrev: "ORLSW",
+        },
+    ),
+    (
+        (0, 3, 219),
+        Grib2Parameter {
+            parameter: "Orographic Length Scale, S Component",
+            units: "",
+            abbrev: "ORLSS",
+        },
+    ),
+    (
+        (0, 3, 220),
+        Grib2Parameter {
+            parameter: "Orographic Length Scale, SW Component",
+            units: "",
+            abbrev: "ORLSSW",
+        },
+    ),
+    (
+        (0, 3, 221),
+        Grib2Parameter {
+            parameter: "Orographic Length Scale, NW Component",
+            units: "",
+            abbrev: "ORLSNW",
+        },
+    ),
+    (
+        (0, 3, 222),
+        Grib2Parameter { parameter: "Effective Surface Height", units: "m", abbrev: "EFSH" },
+    ),
+    ((0, 3, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 4, 0),
+        Grib2Parameter {
+            parameter: "Net Short-Wave Radiation Flux (Surface)",
+            units: "W m-2",
+            abbrev: "NSWRS",
+        },
+    ),
+    (
+        (0, 4, 1),
+        Grib2Parameter {
+            parameter: "Net Short-Wave Radiation Flux (Top of Atmosphere)",
+            units: "W m-2",
+            abbrev: "NSWRT",
+        },
+    ),
+    (
+        (0, 4, 2),
+        Grib2Parameter { parameter: "Short-Wave Radiation Flux", units: "W m-2", abbrev: "SWAVR" },
+    ),
+    (
+        (0, 4, 3),
+        Grib2Parameter { parameter: "Global Radiation Flux", units: "W m-2", abbrev: "GRAD" },
+    ),
+    (
+        (0, 4, 4),
+        Grib2Parameter { parameter: "Brightness Temperature", units: "K", abbrev: "BRTMP" },
+    ),
+    (
+        (0, 4, 5),
+        Grib2Parameter {
+            parameter: "Radiance (with respect to wave number)",
+            units: "W m-1 sr-1",
+            abbrev: "LWRAD",
+        },
+    ),
+    (
+        (0, 4, 6),
+        Grib2Parameter {
+            parameter: "Radiance (with respect to wavelength)",
+            units: "W m-3 sr-1",
+            abbrev: "SWRAD",
+        },
+    ),
+    (
+        (0, 4, 7),
+        Grib2Parameter {
+            parameter: "Downward Short-Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "DSWRF",
+        },
+    ),
+    (
+        (0, 4, 8),
+        Grib2Parameter {
+            parameter: "Upward Short-Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "USWRF",
+        },
+    ),
+    (
+        (0, 4, 9),
+        Grib2Parameter {
+            parameter: "Net Short Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "NSWRF",
+        },
+    ),
+    (
+        (0, 4, 10),
+        Grib2Parameter {
+            parameter: "Photosynthetically Active Radiation",
+            units: "W m-2",
+            abbrev: "PHOTAR",
+        },
+    ),
+    (
+        (0, 4, 11),
+        Grib2Parameter {
+            parameter: "Net Short-Wave Radiation Flux, Clear Sky",
+            units: "W m-2",
+            abbrev: "NSWRFCS",
+        },
+    ),
+    (
+        (0, 4, 12),
+        Grib2Parameter { parameter: "Downward UV Radiation", units: "W m-2", abbrev: "DWUVR" },
+    ),
+    (
+        (0, 4, 13),
+        Grib2Parameter {
+            parameter: "Direct Short Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "DSWRFLX",
+        },
+    ),
+    (
+        (0, 4, 14),
+        Grib2Parameter {
+            parameter: "Diffuse Short Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "DIFSWRF",
+        },
+    ),
+    (
+        (0, 4, 15),
+        Grib2Parameter {
+            parameter: "Upward UV radiation emitted/reflected from the Earth's surface",
+            units: "W m-2",
+            abbrev: "UVVEARTH",
+        },
+    ),
+    (
+        (0, 4, 50),
+        Grib2Parameter {
+            parameter: "UV Index (Under Clear Sky)",
+            units: "Numeric",
+            abbrev: "UVIUCS",
+        },
+    ),
+    ((0, 4, 51), Grib2Parameter { parameter: "UV Index", units: "Numeric", abbrev: "UVI" }),
+    (
+        (0, 4, 52),
+        Grib2Parameter {
+            parameter: "Downward Short-Wave Radiation Flux, Clear Sky",
+            units: "W m-2",
+            abbrev: "DSWRFCS",
+        },
+    ),
+    (
+        (0, 4, 53),
+        Grib2Parameter {
+            parameter: "Upward Short-Wave Radiation Flux, Clear Sky",
+            units: "W m-2",
+            abbrev: "USWRFCS",
+        },
+    ),
+    (
+        (0, 4, 54),
+        Grib2Parameter {
+            parameter: "Direct normal short-wave radiation flux",
+            units: "W m-2",
+            abbrev: "DNSWRFLX",
+        },
+    ),
+    (
+        (0, 4, 55),
+        Grib2Parameter {
+            parameter: "UV visible albedo for diffuse radiation",
+            units: "%",
+            abbrev: "UVALBDIF",
+        },
+    ),
+    (
+        (0, 4, 56),
+        Grib2Parameter {
+            parameter: "UV visible albedo for direct radiation",
+            units: "%",
+            abbrev: "UVALBDIR",
+        },
+    ),
+    (
+        (0, 4, 57),
+        Grib2Parameter {
+            parameter: "UV visible albedo for direct radiation, geometric component",
+            units: "%",
+            abbrev: "UBALBDIRG",
+        },
+    ),
+    (
+        (0, 4, 58),
+        Grib2Parameter {
+            parameter: "UV visible albedo for direct radiation, isotropic component",
+            units: "%",
+            abbrev: "UVALBDIRI",
+        },
+    ),
+    (
+        (0, 4, 59),
+        Grib2Parameter {
+            parameter: "UV visible albedo for direct radiation, volumetric component",
+            units: "%",
+            abbrev: "UVBDIRV",
+        },
+    ),
+    (
+        (0, 4, 60),
+        Grib2Parameter {
+            parameter: "Photosynthetically active radiation flux, clear sky",
+            units: "W m-2",
+            abbrev: "PHOARFCS",
+        },
+    ),
+    (
+        (0, 4, 61),
+        Grib2Parameter {
+            parameter: "Direct short-wave radiation flux, clear sky",
+            units: "W m-2",
+            abbrev: "DSWRFLXCS",
+        },
+    ),
+    (
+        (0, 4, 192),
+        Grib2Parameter {
+            parameter: "Downward Short-Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "DSWRF",
+        },
+    ),
+    (
+        (0, 4, 193),
+        Grib2Parameter {
+            parameter: "Upward Short-Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "USWRF",
+        },
+    ),
+    (
+        (0, 4, 194),
+        Grib2Parameter { parameter: "UV-B Downward Solar Flux", units: "W m-2", abbrev: "DUVB" },
+    ),
+    (
+        (0, 4, 195),
+        Grib2Parameter {
+            parameter: "Clear sky UV-B Downward Solar Flux",
+            units: "W m-2",
+            abbrev: "CDUVB",
+        },
+    ),
+    (
+        (0, 4, 196),
+        Grib2Parameter {
+            parameter: "Clear Sky Downward Solar Flux",
+            units: "W m-2",
+            abbrev: "CSDSF",
+        },
+    ),
+    (
+        (0, 4, 197),
+        Grib2Parameter {
+            parameter: "Solar Radiative Heating Rate",
+            units: "K s-1",
+            abbrev: "SWHR",
+        },
+    ),
+    (
+        (0, 4, 198),
+        Grib2Parameter {
+            parameter: "Clear Sky Upward Solar Flux",
+            units: "W m-2",
+            abbrev: "CSUSF",
+        },
+    ),
+    (
+        (0, 4, 199),
+        Grib2Parameter {
+            parameter: "Cloud Forcing Net Solar Flux",
+            units: "W m-2",
+            abbrev: "CFNSF",
+        },
+    ),
+    (
+        (0, 4, 200),
+        Grib2Parameter {
+            parameter: "Visible Beam Downward Solar Flux",
+            units: "W m-2",
+            abbrev: "VBDSF",
+        },
+    ),
+    (
+        (0, 4, 201),
+        Grib2Parameter {
+            parameter: "Visible Diffuse Downward Solar Flux",
+            units: "W m-2",
+            abbrev: "VDDSF",
+        },
+    ),
+    (
+        (0, 4, 202),
+        Grib2Parameter {
+            parameter: "Near IR Beam Downward Solar Flux",
+            units: "W m-2",
+            abbrev: "NBDSF",
+        },
+    ),
+    (
+        (0, 4, 203),
+        Grib2Parameter {
+            parameter: "Near IR Diffuse Downward Solar Flux",
+            units: "W m-2",
+            abbrev: "NDDSF",
+        },
+    ),
+    (
+        (0, 4, 204),
+        Grib2Parameter {
+            parameter: "Downward Total Radiation Flux",
+            units: "W m-2",
+            abbrev: "DTRF",
+        },
+    ),
+    (
+        (0, 4, 205),
+        Grib2Parameter { parameter: "Upward Total Radiation Flux", units: "W m-2", abbrev: "UTRF" },
+    ),
+    ((0, 4, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 5, 0),
+        Grib2Parameter {
+            parameter: "Net Long-Wave Radiation Flux (Surface)",
+            units: "W m-2",
+            abbrev: "NLWRS",
+        },
+    ),
+    (
+        (0, 5, 1),
+        Grib2Parameter {
+            parameter: "Net Long-Wave Radiation Flux (Top of Atmosphere)",
+            units: "W m-2",
+            abbrev: "NLWRT",
+        },
+    ),
+    (
+        (0, 5, 2),
+        Grib2Parameter { parameter: "Long-Wave Radiation Flux", units: "W m-2", abbrev: "LWAVR" },
+    ),
+    (
+        (0, 5, 3),
+        Grib2Parameter {
+            parameter: "Downward Long-Wave Rad. Flux",
+            units: "W m-2",
+            abbrev: "DLWRF",
+        },
+    ),
+    (
+        (0, 5, 4),
+        Grib2Parameter { parameter: "Upward Long-Wave Rad. Flux", units: "W m-2", abbrev: "ULWRF" },
+    ),
+    (
+        (0, 5, 5),
+        Grib2Parameter {
+            parameter: "Net Long-Wave Radiation Flux",
+            units: "W m-2",
+            abbrev: "NLWRF",
+        },
+    ),
+    (
+        (0, 5, 6),
+        Grib2Parameter {
+            parameter: "Net Long-Wave Radiation Flux, Clear Sky",
+            units: "W m-2",
+            abbrev: "NLWRCS",
+        },
+    ),
+    (
+        (0, 5, 7),
+        Grib2Parameter { parameter: "Brightness Temperature", units: "K", abbrev: "BRTEMP" },
+    ),
+    (
+        (0, 5, 8),
+        Grib2Parameter {
+            parameter: "Downward Long-Wave Radiation Flux, Clear Sky",
+            units: "W m-2",
+            abbrev: "DLWRFCS",
+        },
+    ),
+    (
+        (0, 5, 9),
+        Grib2Parameter {
+            parameter: "Near IR albedo for diffuse radiation",
+            units: "%",
+            abbrev: "NIRALBDIF",
+        },
+    ),
+    (
+        (0, 5, 10),
+        Grib2Parameter {
+            parameter: "Near IR albedo for direct radiation",
+            units: "%",
+            abbrev: "NIRALBDIR",
+        },
+    ),
+    (
+        (0, 5, 11),
+        Grib2Parameter {
+            parameter: "Near IR albedo for direct radiation, geometric component",
+            units: "%",
+            abbrev: "NIRALBDIRG",
+        },
+    ),
+    (
+        (0, 5, 12),
+        Grib2Parameter {
+            parameter: "Near IR albedo for direct radiation, isotropic component",
+            units: "%",
+            abbrev: "NIRALBDIRI",
+        },
+    ),
+    (
+        (0, 5, 13),
+        Grib2Parameter {
+            parameter: "Near IR albedo for direct radiation, volumetric component",
+            units: "%",
+            abbrev: "NIRALBDIRV",
+        },
+    ),
+    (
+        (0, 5, 192),
+        Grib2Parameter {
+            parameter: "Downward Long-Wave Rad. Flux",
+            units: "W m-2",
+            abbrev: "DLWRF",
+        },
+    ),
+    (
+        (0, 5, 193),
+        Grib2Parameter { parameter: "Upward Long-Wave Rad. Flux", units: "W m-2", abbrev: "ULWRF" },
+    ),
+    (
+        (0, 5, 194),
+        Grib2Parameter {
+            parameter: "Long-Wave Radiative Heating Rate",
+            units: "K s-1",
+            abbrev: "LWHR",
+        },
+    ),
+    (
+        (0, 5, 195),
+        Grib2Parameter {
+            parameter: "Clear Sky Upward Long Wave Flux",
+            units: "W m-2",
+            abbrev: "CSULF",
+        },
+    ),
+    (
+        (0, 5, 196),
+        Grib2Parameter {
+            parameter: "Clear Sky Downward Long Wave Flux",
+            units: "W m-2",
+            abbrev: "CSDLF",
+        },
+    ),
+    (
+        (0, 5, 197),
+        Grib2Parameter {
+            parameter: "Cloud Forcing Net Long Wave Flux",
+            units: "W m-2",
+            abbrev: "CFNLF",
+        },
+    ),
+    ((0, 5, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    ((0, 6, 0), Grib2Parameter { parameter: "Cloud Ice", units: "kg m-2", abbrev: "CICE" }),
+    ((0, 6, 1), Grib2Parameter { parameter: "Total Cloud Cover", units: "%", abbrev: "TCDC" }),
+    (
+        (0, 6, 2),
+        Grib2Parameter { parameter: "Convective Cloud Cover", units: "%", abbrev: "CDCON" },
+    ),
+    ((0, 6, 3), Grib2Parameter { parameter: "Low Cloud Cover", units: "%", abbrev: "LCDC" }),
+    ((0, 6, 4), Grib2Parameter { parameter: "Medium Cloud Cover", units: "%", abbrev: "MCDC" }),
+    ((0, 6, 5), Grib2Parameter { parameter: "High Cloud Cover", units: "%", abbrev: "HCDC" }),
+    ((0, 6, 6), Grib2Parameter { parameter: "Cloud Water", units: "kg m-2", abbrev: "CWAT" }),
+    ((0, 6, 7), Grib2Parameter { parameter: "Cloud Amount", units: "%", abbrev: "CDCA" }),
+    (
+        (0, 6, 8),
+        Grib2Parameter { parameter: "Cloud Type", units: "See Table 4.203", abbrev: "CDCT" },
+    ),
+    (
+        (0, 6, 9),
+        Grib2Parameter { parameter: "Thunderstorm Maximum Tops", units: "m", abbrev: "TMAXT" },
+    ),
+    (
+        (0, 6, 10),
+        Grib2Parameter {
+            parameter: "Thunderstorm Coverage",
+            units: "See Table 4.204",
+            abbrev: "THUNC",
+        },
+    ),
+    ((0, 6, 11), Grib2Parameter { parameter: "Cloud Base", units: "m", abbrev: "CDCB" }),
+    ((0, 6, 12), Grib2Parameter { parameter: "Cloud Top", units: "m", abbrev: "CDCTOP" }),
+    ((0, 6, 13), Grib2Parameter { parameter: "Ceiling", units: "m", abbrev: "CEIL" }),
+    (
+        (0, 6, 14),
+        Grib2Parameter { parameter: "Non-Convective Cloud Cover", units: "%", abbrev: "CDLYR" },
+    ),
+    (
+        (0, 6, 15),
+        Grib2Parameter { parameter: "Cloud Work Function", units: "J kg-1", abbrev: "CWORK" },
+    ),
+    (
+        (0, 6, 16),
+        Grib2Parameter {
+            parameter: "Convective Cloud Efficiency",
+            units: "Proportion",
+            abbrev: "CUEFI",
+        },
+    ),
+    (
+        (0, 6, 17),
+        Grib2Parameter { parameter: "Total Condensate", units: "kg kg-1", abbrev: "TCONDO" },
+    ),
+    (
+        (0, 6, 18),
+        Grib2Parameter {
+            parameter: "Total Column-Integrated Cloud Water",
+            units: "kg m-2",
+            abbrev: "TCOLWO",
+        },
+    ),
+    (
+        (0, 6, 19),
+        Grib2Parameter {
+            parameter: "Total Column-Integrated Cloud Ice",
+            units: "kg m-2",
+            abbrev: "TCOLIO",
+        },
+    ),
+    (
+        (0, 6, 20),
+        Grib2Parameter {
+            parameter: "Total Column-Integrated Condensate",
+            units: "kg m-2",
+            abbrev: "TCOLC",
+        },
+    ),
+    (
+        (0, 6, 21),
+        Grib2Parameter {
+            parameter: "Ice fraction of total condensate",
+            units: "Proportion",
+            abbrev: "FICE",
+        },
+    ),
+    ((0, 6, 22), Grib2Parameter { parameter: "Cloud Cover", units: "%", abbrev: "CDCC" }),
+    (
+        (0, 6, 23),
+        Grib2Parameter { parameter: "Cloud Ice Mixing Ratio", units: "kg kg-1", abbrev: "CDCIMR" },
+    ),
+    ((0, 6, 24), Grib2Parameter { parameter: "Sunshine", units: "Numeric", abbrev: "SUNS" }),
+    (
+        (0, 6, 25),
+        Grib2Parameter {
+            parameter: "Horizontal Extent of Cumulonimbus (CB)",
+            units: "%",
+            abbrev: "CBHE",
+        },
+    ),
+    (
+        (0, 6, 26),
+        Grib2Parameter {
+            parameter: "Height of Convective Cloud Base",
+            units: "m",
+            abbrev: "HCONCB",
+        },
+    ),
+    (
+        (0, 6, 27),
+        Grib2Parameter {
+            parameter: "Height of Convective Cloud Top",
+            units: "m",
+            abbrev: "HCONCT",
+        },
+    ),
+    (
+        (0, 6, 28),
+        Grib2Parameter {
+            parameter: "Number Concentration of Cloud Droplets",
+            units: "kg-1",
+            abbrev: "NCONCD",
+        },
+    ),
+    (
+        (0, 6, 29),
+        Grib2Parameter {
+            parameter: "Number Concentration of Cloud Ice",
+            units: "kg-1",
+            abbrev: "NCCICE",
+        },
+    ),
+    (
+        (0, 6, 30),
+        Grib2Parameter {
+            parameter: "Number Density of Cloud Droplets",
+            units: "m-3",
+            abbrev: "NDENCD",
+        },
+    ),
+    (
+        (0, 6, 31),
+        Grib2Parameter { parameter: "Number Density of Cloud Ice", units: "m-3", abbrev: "NDCICE" },
+    ),
+    (
+        (0, 6, 32),
+        Grib2Parameter { parameter: "Fraction of Cloud Cover", units: "Numeric", abbrev: "FRACCC" },
+    ),
+    ((0, 6, 33), Grib2Parameter { parameter: "Sunshine Duration", units: "s", abbrev: "SUNSD" }),
+    (
+        (0, 6, 34),
+        Grib2Parameter {
+            parameter: "Surface Long Wave Effective Total Cloudiness",
+            units: "Numeric",
+            abbrev: "SLWTC",
+        },
+    ),
+    (
+        (0, 6, 35),
+        Grib2Parameter {
+            parameter: "Surface Short Wave Effective Total Cloudiness",
+            units: "Numeric",
+            abbrev: "SSWTC",
+        },
+    ),
+    (
+        (0, 6, 36),
+        Grib2Parameter {
+            parameter: "Fraction of Stratiform Precipitation Cover",
+            units: "Proportion",
+            abbrev: "FSTRPC",
+        },
+    ),
+    (
+        (0, 6, 37),
+        Grib2Parameter {
+            parameter: "Fraction of Convective Precipitation Cover",
+            units: "Proportion",
+            abbrev: "FCONPC",
+        },
+    ),
+    (
+        (0, 6, 38),
+        Grib2Parameter {
+            parameter: "Mass Density of Cloud Droplets",
+            units: "kg m-3",
+            abbrev: "MASSDCD",
+        },
+    ),
+    (
+        (0, 6, 39),
+        Grib2Parameter {
+            parameter: "Mass Density of Cloud Ice",
+            units: "kg m-3",
+            abbrev: "MASSDCI",
+        },
+    ),
+    (
+        (0, 6, 40),
+        Grib2Parameter {
+            parameter: "Mass Density of Convective Cloud Water Droplets",
+            units: "kg m-3",
+            abbrev: "MDCCWD",
+        },
+    ),
+    (
+        (0, 6, 47),
+        Grib2Parameter {
+            parameter: "Volume Fraction of Cloud Water Droplets",
+            units: "Numeric",
+            abbrev: "VFRCWD",
+        },
+    ),
+    (
+        (0, 6, 48),
+        Grib2Parameter {
+            parameter: "Volume Fraction of Cloud Ice Particles",
+            units: "Numeric",
+            abbrev: "VFRCICE",
+        },
+    ),
+    (
+        (0, 6, 49),
+        Grib2Parameter {
+            parameter: "Volume Fraction of Cloud (Ice and/or Water)",
+            units: "Numeric",
+            abbrev: "VFRCIW",
+        },
+    ),
+    ((0, 6, 50), Grib2Parameter { parameter: "Fog", units: "%", abbrev: "FOG" }),
+    (
+        (0, 6, 51),
+        Grib2Parameter {
+            parameter: "Sunshine Duration Fraction",
+            units: "Proportion",
+            abbrev: "SUNFRAC",
+        },
+    ),
+    (
+        (0, 6, 192),
+        Grib2Parameter { parameter: "Non-Convective Cloud Cover", units: "%", abbrev: "CDLYR" },
+    ),
+    (
+        (0, 6, 193),
+        Grib2Parameter { parameter: "Cloud Work Function", units: "J kg-1", abbrev: "CWORK" },
+    ),
+    (
+        (0, 6, 194),
+        Grib2Parameter {
+            parameter: "Convective Cloud Efficiency",
+            units: "non-dim",
+            abbrev: "CUEFI",
+        },
+    ),
+    (
+        (0, 6, 195),
+        Grib2Parameter { parameter: "Total Condensate", units: "kg kg-1", abbrev: "TCOND" },
+    ),
+    (
+        (0, 6, 196),
+        Grib2Parameter {
+            parameter: "Total Column-Integrated Cloud Water",
+            units: "kg m-2",
+            abbrev: "TCOLW",
+        },
+    ),
+    (
+        (0, 6, 197),
+        Grib2Parameter {
+            parameter: "Total Column-Integrated Cloud Ice",
+            units: "kg m-2",
+            abbrev: "TCOLI",
+        },
+    ),
+    (
+        (0, 6, 198),
+        Grib2Parameter {
+            parameter: "Total Column-Integrated Condensate",
+            units: "kg m-2",
+            abbrev: "TCOLC",
+        },
+    ),
+    (
+        (0, 6, 199),
+        Grib2Parameter {
+            parameter: "Ice fraction of total condensate",
+            units: "non-dim",
+            abbrev: "FICE",
+        },
+    ),
+    (
+        (0, 6, 200),
+        Grib2Parameter {
+            parameter: "Convective Cloud Mass Flux",
+            units: "Pa s-1",
+            abbrev: "MFLUX",
+        },
+    ),
+    ((0, 6, 201), Grib2Parameter { parameter: "Sunshine Duration", units: "s", abbrev: "SUNSD" }),
+    ((0, 6, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 7, 0),
+        Grib2Parameter { parameter: "Parcel Lifted Index (to 500 hPa)", units: "K", abbrev: "PLI" },
+    ),
+    (
+        (0, 7, 1),
+        Grib2Parameter { parameter: "Best Lifted Index (to 500 hPa)", units: "K", abbrev: "BLI" },
+    ),
+    ((0, 7, 2), Grib2Parameter { parameter: "K Index", units: "K", abbrev: "KX" }),
+    ((0, 7, 3), Grib2Parameter { parameter: "KO Index", units: "K", abbrev: "KOX" }),
+    ((0, 7, 4), Grib2Parameter { parameter: "Total Totals Index", units: "K", abbrev: "TOTALX" }),
+    ((0, 7, 5), Grib2Parameter { parameter: "Sweat Index", units: "Numeric", abbrev: "SX" }),
+    (
+        (0, 7, 6),
+        Grib2Parameter {
+            parameter: "Convective Available Potential Energy",
+            units: "J kg-1",
+            abbrev: "CAPE",
+        },
+    ),
+    (
+        (0, 7, 7),
+        Grib2Parameter { parameter: "Convective Inhibition", units: "J kg-1", abbrev: "CIN" },
+    ),
+    (
+        (0, 7, 8),
+        Grib2Parameter { parameter: "Storm Relative Helicity", units: "m2 s-2", abbrev: "HLCY" },
+    ),
+    (
+        (0, 7, 9),
+        Grib2Parameter { parameter: "Energy Helicity Index", units: "Numeric", abbrev: "EHLX" },
+    ),
+    ((0, 7, 10), Grib2Parameter { parameter: "Surface Lifted Index", units: "K", abbrev: "LFT X" }),
+    (
+        (0, 7, 11),
+        Grib2Parameter { parameter: "Best (4 layer) Lifted Index", units: "K", abbrev: "4LFTX" },
+    ),
+    ((0, 7, 12), Grib2Parameter { parameter: "Richardson Number", units: "Numeric", abbrev: "RI" }),
+    ((0, 7, 13), Grib2Parameter { parameter: "Showalter Index", units: "K", abbrev: "SHWINX" }),
+    ((0, 7, 15), Grib2Parameter { parameter: "Updraft Helicity", units: "m2 s-2", abbrev: "UPHL" }),
+    (
+        (0, 7, 16),
+        Grib2Parameter { parameter: "Bulk Richardson Number", units: "Numeric", abbrev: "BLKRN" },
+    ),
+    (
+        (0, 7, 17),
+        Grib2Parameter {
+            parameter: "Gradient Richardson Number",
+            units: "Numeric",
+            abbrev: "GRDRN",
+        },
+    ),
+    (
+        (0, 7, 18),
+        Grib2Parameter { parameter: "Flux Richardson Number", units: "Numeric", abbrev: "FLXRN" },
+    ),
+    (
+        (0, 7, 19),
+        Grib2Parameter {
+            parameter: "Convective Available Potential Energy Shear",
+            units: "m2 s-2",
+            abbrev: "CONAPES",
+        },
+    ),
+    (
+        (0, 7, 20),
+        Grib2Parameter {
+            parameter: "Thunderstorm intensity index",
+            units: "See Table 4.246",
+            abbrev: "TIIDEX",
+        },
+    ),
+    (
+        (0, 7, 192),
+        Grib2Parameter { parameter: "Surface Lifted Index", units: "K", abbrev: "LFT X" },
+    ),
+    (
+        (0, 7, 193),
+        Grib2Parameter { parameter: "Best (4 layer) Lifted Index", units: "K", abbrev: "4LFTX" },
+    ),
+    (
+        (0, 7, 194),
+        Grib2Parameter { parameter: "Richardson Number", units: "Numeric", abbrev: "RI" },
+    ),
+    (
+        (0, 7, 195),
+        Grib2Parameter {
+            parameter: "Convective Weather Detection Index",
+            units: "",
+            abbrev: "CWDI",
+        },
+    ),
+    (
+        (0, 7, 196),
+        Grib2Parameter { parameter: "Ultra Violet Index", units: "W m-2", abbrev: "UVI" },
+    ),
+    (
+        (0, 7, 197),
+        Grib2Parameter { parameter: "Updraft Helicity", units: "m2 s-2", abbrev: "UPHL" },
+    ),
+    ((0, 7, 198), Grib2Parameter { parameter: "Leaf Area Index", units: "Numeric", abbrev: "LAI" }),
+    (
+        (0, 7, 199),
+        Grib2Parameter {
+            parameter: "Hourly Maximum of Updraft Helicity",
+            units: "m2 s-2",
+            abbrev: "MXUPHL",
+        },
+    ),
+    (
+        (0, 7, 200),
+        Grib2Parameter {
+            parameter: "Hourly Minimum of Updraft Helicity",
+            units: "m2 s-2",
+            abbrev: "MNUPHL",
+        },
+    ),
+    (
+        (0, 7, 201),
+        Grib2Parameter {
+            parameter: "Bourgoiun Negative Energy Layer (surface to freezing level)",
+            units: "J kg-1",
+            abbrev: "BNEGELAY",
+        },
+    ),
+    (
+        (0, 7, 202),
+        Grib2Parameter {
+            parameter: "Bourgoiun Positive Energy Layer (2k ft AGL to 400 hPa)",
+            units: "J kg-1",
+            abbrev: "BPOSELAY",
+        },
+    ),
+    ((0, 7, 203), Grib2Parameter { parameter: "Downdraft CAPE", units: "J kg-1", abbrev: "DCAPE" }),
+    (
+        (0, 7, 204),
+        Grib2Parameter {
+            parameter: "Effective Storm Relative Helicity",
+            units: "m2 s-2",
+            abbrev: "EFHL",
+        },
+    ),
+    (
+        (0, 7, 205),
+        Grib2Parameter {
+            parameter: "Enhanced Stretching Potential",
+            units: "Numeric",
+            abbrev: "ESP",
+        },
+    ),
+    (
+        (0, 7, 206),
+        Grib2Parameter { parameter: "Critical Angle", units: "Degree", abbrev: "CANGLE" },
+    ),
+    (
+        (0, 7, 207),
+        Grib2Parameter { parameter: "Effective Surface Helicity", units: "m2 s-2", abbrev: "E3KH" },
+    ),
+    (
+        (0, 7, 208),
+        Grib2Parameter {
+            parameter: "Significant Tornado Parameter with CIN-Effective Layer",
+            units: "numeric",
+            abbrev: "STPC",
+        },
+    ),
+    (
+        (0, 7, 209),
+        Grib2Parameter {
+            parameter: "Significant Hail Parameter",
+            units: "numeric",
+            abbrev: "SIGH",
+        },
+    ),
+    (
+        (0, 7, 210),
+        Grib2Parameter {
+            parameter: "Supercell Composite Parameter-Effective Layer",
+            units: "numeric",
+            abbrev: "SCCP",
+        },
+    ),
+    (
+        (0, 7, 211),
+        Grib2Parameter {
+            parameter: "Significant Tornado parameter-Fixed Layer",
+            units: "numeric",
+            abbrev: "SIGT",
+        },
+    ),
+    (
+        (0, 7, 212),
+        Grib2Parameter {
+            parameter: "Mixed Layer (100 mb) Virtual LFC",
+            units: "numeric",
+            abbrev: "MLFC",
+        },
+    ),
+    ((0, 7, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 13, 0),
+        Grib2Parameter { parameter: "Aerosol Type", units: "See Table 4.205", abbrev: "AEROT" },
+    ),
+    (
+        (0, 13, 192),
+        Grib2Parameter {
+            parameter: "Particulate matter (coarse)",
+            units: "µg m-3",
+            abbrev: "PMTC",
+        },
+    ),
+    (
+        (0, 13, 193),
+        Grib2Parameter { parameter: "Particulate matter (fine)", units: "µg m-3", abbrev: "PMTF" },
+    ),
+    (
+        (0, 13, 194),
+        Grib2Parameter {
+            parameter: "Particulate matter (fine)",
+            units: "log10 (µg m-3)",
+            abbrev: "LPMTF",
+        },
+    ),
+    (
+        (0, 13, 195),
+        Grib2Parameter {
+            parameter: "Integrated column particulate matter (fine)",
+            units: "log10 (µg m-3)",
+            abbrev: "LIPMF",
+        },
+    ),
+    ((0, 13, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    ((0, 14, 0), Grib2Parameter { parameter: "Total Ozone", units: "DU", abbrev: "TOZNE" }),
+    (
+        (0, 14, 1),
+        Grib2Parameter { parameter: "Ozone Mixing Ratio", units: "kg kg-1", abbrev: "O3MR" },
+    ),
+    (
+        (0, 14, 2),
+        Grib2Parameter { parameter: "Total Column Integrated Ozone", units: "DU", abbrev: "TCIOZ" },
+    ),
+    (
+        (0, 14, 192),
+        Grib2Parameter { parameter: "Ozone Mixing Ratio", units: "kg kg-1", abbrev: "O3MR" },
+    ),
+    (
+        (0, 14, 193),
+        Grib2Parameter { parameter: "Ozone Concentration", units: "ppb", abbrev: "OZCON" },
+    ),
+    (
+        (0, 14, 194),
+        Grib2Parameter {
+            parameter: "Categorical Ozone Concentration",
+            units: "Non-Dim",
+            abbrev: "OZCAT",
+        },
+    ),
+    (
+        (0, 14, 195),
+        Grib2Parameter {
+            parameter: "Ozone Vertical Diffusion",
+            units: "kg kg-1 s-1",
+            abbrev: "VDFOZ",
+        },
+    ),
+    (
+        (0, 14, 196),
+        Grib2Parameter { parameter: "Ozone Production", units: "kg kg-1 s-1", abbrev: "POZ" },
+    ),
+    (
+        (0, 14, 197),
+        Grib2Parameter { parameter: "Ozone Tendency", units: "kg kg-1 s-1", abbrev: "TOZ" },
+    ),
+    (
+        (0, 14, 198),
+        Grib2Parameter {
+            parameter: "Ozone Production from Temperature Term",
+            units: "kg kg-1 s-1",
+            abbrev: "POZT",
+        },
+    ),
+    (
+        (0, 14, 199),
+        Grib2Parameter {
+            parameter: "Ozone Production from Column Ozone Term",
+            units: "kg kg-1 s-1",
+            abbrev: "POZO",
+        },
+    ),
+    (
+        (0, 14, 200),
+        Grib2Parameter {
+            parameter: "Ozone Daily Max from 1-hour Average",
+            units: "ppbV",
+            abbrev: "OZMAX1",
+        },
+    ),
+    (
+        (0, 14, 201),
+        Grib2Parameter {
+            parameter: "Ozone Daily Max from 8-hour Average",
+            units: "ppbV",
+            abbrev: "OZMAX8",
+        },
+    ),
+    (
+        (0, 14, 202),
+        Grib2Parameter {
+            parameter: "PM 2.5 Daily Max from 1-hour Average",
+            units: "μg m-3",
+            abbrev: "PDMAX1",
+        },
+    ),
+    (
+        (0, 14, 203),
+        Grib2Parameter {
+            parameter: "PM 2.5 Daily Max from 24-hour Average",
+            units: "μg m-3",
+            abbrev: "PDMAX24",
+        },
+    ),
+    (
+        (0, 14, 204),
+        Grib2Parameter {
+            parameter: "Acetaldehyde & Higher Aldehydes",
+            units: "ppbV",
+            abbrev: "ALD2",
+        },
+    ),
+    ((0, 14, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 15, 0),
+        Grib2Parameter { parameter: "Base Spectrum Width", units: "m s-1", abbrev: "BSWID" },
+    ),
+    ((0, 15, 1), Grib2Parameter { parameter: "Base Reflectivity", units: "dB", abbrev: "BREF" }),
+    (
+        (0, 15, 2),
+        Grib2Parameter { parameter: "Base Radial Velocity", units: "m s-1", abbrev: "BRVEL" },
+    ),
+    (
+        (0, 15, 3),
+        Grib2Parameter {
+            parameter: "Vertically-Integrated Liquid Water",
+            units: "kg m-2",
+            abbrev: "VIL",
+        },
+    ),
+    (
+        (0, 15, 4),
+        Grib2Parameter {
+            parameter: "Layer Maximum Base Reflectivity",
+            units: "dB",
+            abbrev: "LMAXBR",
+        },
+    ),
+    ((0, 15, 5), Grib2Parameter { parameter: "Precipitation", units: "kg m-2", abbrev: "PREC" }),
+    ((0, 15, 6), Grib2Parameter { parameter: "Radar Spectra (1)", units: "", abbrev: "RDSP1" }),
+    ((0, 15, 7), Grib2Parameter { parameter: "Radar Spectra (2)", units: "", abbrev: "RDSP2" }),
+    ((0, 15, 8), Grib2Parameter { parameter: "Radar Spectra (3)", units: "", abbrev: "RDSP3" }),
+    (
+        (0, 15, 9),
+        Grib2Parameter { parameter: "Reflectivity of Cloud Droplets", units: "dB", abbrev: "RFCD" },
+    ),
+    (
+        (0, 15, 10),
+        Grib2Parameter { parameter: "Reflectivity of Cloud Ice", units: "dB", abbrev: "RFCI" },
+    ),
+    (
+        (0, 15, 11),
+        Grib2Parameter { parameter: "Reflectivity of Snow", units: "dB", abbrev: "RFSNOW" },
+    ),
+    (
+        (0, 15, 12),
+        Grib2Parameter { parameter: "Reflectivity of Rain", units: "dB", abbrev: "RFRAIN" },
+    ),
+    (
+        (0, 15, 13),
+        Grib2Parameter { parameter: "Reflectivity of Graupel", units: "dB", abbrev: "RFGRPL" },
+    ),
+    (
+        (0, 15, 14),
+        Grib2Parameter { parameter: "Reflectivity of Hail", units: "dB", abbrev: "RFHAIL" },
+    ),
+    (
+        (0, 15, 15),
+        Grib2Parameter { parameter: "Hybrid Scan Reflectivity", units: "dB", abbrev: "HSR" },
+    ),
+    (
+        (0, 15, 16),
+        Grib2Parameter {
+            parameter: "Hybrid Scan Reflectivity Height",
+            units: "m",
+            abbrev: "HSRHT",
+        },
+    ),
+    ((0, 15, 192), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 16, 0),
+        Grib2Parameter {
+            parameter: "Equivalent radar reflectivity factor for rain",
+            units: "m m6 m-3",
+            abbrev: "REFZR",
+        },
+    ),
+    (
+        (0, 16, 1),
+        Grib2Parameter {
+            parameter: "Equivalent radar reflectivity factor for snow",
+            units: "m m6 m-3",
+            abbrev: "REFZI",
+        },
+    ),
+    (
+        (0, 16, 2),
+        Grib2Parameter {
+            parameter: "Equivalent radar reflectivity factor for parameterized convection",
+            units: "m m6 m-3",
+            abbrev: "REFZC",
+        },
+    ),
+    ((0, 16, 3), Grib2Parameter { parameter: "Echo Top", units: "m", abbrev: "RETOP" }),
+    ((0, 16, 4), Grib2Parameter { parameter: "Reflectivity", units: "dB", abbrev: "REFD" }),
+    (
+        (0, 16, 5),
+        Grib2Parameter { parameter: "Composite reflectivity", units: "dB", abbrev: "REFC" },
+    ),
+    (
+        (0, 16, 192),
+        Grib2Parameter {
+            parameter: "Equivalent radar reflectivity factor for rain",
+            units: "m m6 m-3",
+            abbrev: "REFZR",
+        },
+    ),
+    (
+        (0, 16, 193),
+        Grib2Parameter {
+            parameter: "Equivalent radar reflectivity factor for snow",
+            units: "m m6 m-3",
+            abbrev: "REFZI",
+        },
+    ),
+    (
+        (0, 16, 194),
+        Grib2Parameter {
+            parameter: "Equivalent radar reflectivity factor for parameterized convection",
+            units: "m m6 m-3",
+            abbrev: "REFZC",
+        },
+    ),
+    ((0, 16, 195), Grib2Parameter { parameter: "Reflectivity", units: "dB", abbrev: "REFD" }),
+    (
+        (0, 16, 196),
+        Grib2Parameter { parameter: "Composite reflectivity", units: "dB", abbrev: "REFC" },
+    ),
+    ((0, 16, 197), Grib2Parameter { parameter: "Echo Top", units: "m", abbrev: "RETOP" }),
+    (
+        (0, 16, 198),
+        Grib2Parameter {
+            parameter: "Hourly Maximum of Simulated Reflectivity",
+            units: "dB",
+            abbrev: "MAXREF",
+        },
+    ),
+    ((0, 16, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 17, 0),
+        Grib2Parameter {
+            parameter: "Lightning Strike Density",
+            units: "m-2 s-1",
+            abbrev: "LTNGSD",
+        },
+    ),
+    (
+        (0, 17, 1),
+        Grib2Parameter {
+            parameter: "Lightning Potential Index (LPI)",
+            units: "J kg-1",
+            abbrev: "LTPINX",
+        },
+    ),
+    (
+        (0, 17, 2),
+        Grib2Parameter {
+            parameter: "Cloud-to-Ground Lightning Flash Density",
+            units: "km-2 day-1",
+            abbrev: "CDGDLTFD",
+        },
+    ),
+    (
+        (0, 17, 3),
+        Grib2Parameter {
+            parameter: "Cloud-to-Cloud Lightning Flash Density",
+            units: "km-2 day-1",
+            abbrev: "CDCDLTFD",
+        },
+    ),
+    (
+        (0, 17, 4),
+        Grib2Parameter {
+            parameter: "Total Lightning Flash Density",
+            units: "km-2 day-1",
+            abbrev: "TLGTFD",
+        },
+    ),
+    (
+        (0, 17, 5),
+        Grib2Parameter {
+            parameter: "Subgrid-scale lightning potential index",
+            units: "J kg-1",
+            abbrev: "SLNGPIDX",
+        },
+    ),
+    ((0, 17, 192), Grib2Parameter { parameter: "Lightning", units: "non-dim", abbrev: "LTNG" }),
+    ((0, 17, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    (
+        (0, 18, 0),
+        Grib2Parameter {
+            parameter: "Air Concentration of Caesium 137",
+            units: "Bq m-3",
+            abbrev: "ACCES",
+        },
+    ),
+    (
+        (0, 18, 1),
+        Grib2Parameter {
+            parameter: "Air Concentration of Iodine 131",
+            units: "Bq m-3",
+            abbrev: "ACIOD",
+        },
+    ),
+    (
+        (0, 18, 2),
+        Grib2Parameter {
+            parameter: "Air Concentration of Radioactive Pollutant",
+            units: "Bq m-3",
+            abbrev: "ACRADP",
+        },
+    ),
+    (
+        (0, 18, 3),
+        Grib2Parameter {
+            parameter: "Ground Deposition of Caesium 137",
+            units: "Bq m-2",
+            abbrev: "GDCES",
+        },
+    ),
+    (
+        (0, 18, 4),
+        Grib2Parameter {
+            parameter: "Ground Deposition of Iodine 131",
+            units: "Bq m-2",
+            abbrev: "GDIOD",
+        },
+    ),
+    (
+        (0, 18, 5),
+        Grib2Parameter {
+            parameter: "Ground Deposition of Radioactive Pollutant",
+            units: "Bq m-2",
+            abbrev: "GDRADP",
+        },
+    ),
+    (
+        (0, 18, 6),
+        Grib2Parameter {
+            parameter: "Time Integrated Air Concentration of Cesium Pollutant",
+            units: "Bq s m-3",
+            abbrev: "TIACCP",
+        },
+    ),
+    (
+        (0, 18, 7),
+        Grib2Parameter {
+            parameter: "Time Integrated Air Concentration of Iodine Pollutant",
+            units: "Bq s m-3",
+            abbrev: "TIACIP",
+        },
+    ),
+    (
+        (0, 18, 8),
+        Grib2Parameter {
+            parameter: "Time Integrated Air Concentration of Radioactive Pollutant",
+            units: "Bq s m-3",
+            abbrev: "TIACRP",
+        },
+    ),
+    ((0, 18, 9), Grib2Parameter { parameter: "Reserved", units: "", abbrev: "Reserved" }),
+    (
+        (0, 18, 10),
+        Grib2Parameter { parameter: "Air Concentration", units: "Bq m-3", abbrev: "AIRCON" },
+    ),
+    (
+        (0, 18, 11),
+        Grib2Parameter { parameter: "Wet Deposition", units: "Bq m-2", abbrev: "WETDEP" },
+    ),
+    (
+        (0, 18, 12),
+        Grib2Parameter { parameter: "Dry Deposition", units: "Bq m-2", abbrev: "DRYDEP" },
+    ),
+    (
+        (0, 18, 13),
+        Grib2Parameter {
+            parameter: "Total Deposition (Wet + Dry)",
+            units: "Bq m-2",
+            abbrev: "TOTLWD",
+        },
+    ),
+    (
+        (0, 18, 14),
+        Grib2Parameter {
+            parameter: "Specific Activity Concentration",
+            units: "Bq kg-1",
+            abbrev: "SACON",
+        },
+    ),
+    (
+        (0, 18, 15),
+        Grib2Parameter {
+            parameter: "Maximum of Air Concentration in Layer",
+            units: "Bq m-3",
+            abbrev: "MAXACON",
+        },
+    ),
+    (
+        (0, 18, 16),
+        Grib2Parameter {
+            parameter: "Height of Maximum of Air Concentration",
+            units: "m",
+            abbrev: "HMXACON",
+        },
+    ),
+    (
+        (0, 18, 17),
+        Grib2Parameter {
+            parameter: "Column-Integrated Air Concentration",
+            units: "Bq m-2",
+            abbrev: "CIAIRC",
+        },
+    ),
+    (
+        (0, 18, 18),
+        Grib2Parameter {
+            parameter: "Column-Averaged Air Concentration in Layer",
+            units: "Bq m-3",
+            abbrev: "CAACL",
+        },
+    ),
+    (
+        (0, 18, 19),
+        Grib2Parameter { parameter: "Deposition activity arrival", units: "s", abbrev: "DEPACTA" },
+    ),
+    (
+        (0, 18, 20),
+        Grib2Parameter { parameter: "Deposition activity ended", units: "s", abbrev: "DEPACTE" },
+    ),
+    (
+        (0, 18, 21),
+        Grib2Parameter { parameter: "Cloud activity arrival", units: "s", abbrev: "CLDACTA" },
+    ),
+    (
+        (0, 18, 22),
+        Grib2Parameter { parameter: "Cloud activity ended", units: "s", abbrev: "CLDACTE" },
+    ),
+    (
+        (0, 18, 23),
+        Grib2Parameter { parameter: "Effective dose rate", units: "nSv h-1", abbrev: "EFFDOSER" },
+    ),
+    (
+        (0, 18, 24),
+        Grib2Parameter {
+            parameter: "Thyroid dose rate (adult)",
+            units: "nSv h-1",
+            abbrev: "THYDOSER",
+        },
+    ),
+    (
+        (0, 18, 25),
+        Grib2Parameter {
+            parameter: "Gamma dose rate (adult)",
+            units: "nSv h-1",
+            abbrev: "GAMDOSER",
+        },
+    ),
+    (
+        (0, 18, 26),
+        Grib2Parameter { parameter: "Activity emission", units: "Bq s-1", abbrev: "ACTEMM" },
+    ),
+    ((0, 18, 192), Grib2Parameter { parameter: "Lightning", units: "non-dim", abbrev: "LTNG" }),
+    ((0, 18, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "Missing" }),
+    ((0, 19, 0), Grib2Parameter { parameter: "Visibility", units: "m", abbrev: "VIS" }),
+    ((0, 19, 1), Grib2Parameter { parameter: "Albedo", units: "%", abbrev: "ALBDO" }),
+    (
+        (0, 19, 2),
+        Grib2Parameter { parameter: "Thunderstorm Probability", units: "%", abbrev: "TSTM" },
+    ),
+    ((0, 19, 3), Grib2Parameter { parameter: "Mixed Layer Depth", units: "m", abbrev: "MIXHT" }),
+    (
+        (0, 19, 4),
+        Grib2Parameter { parameter: "Volcanic Ash", units: "See Table 4.206", abbrev: "VOLASH" },
+    ),
+    ((0, 19, 5), Grib2Parameter { parameter: "Icing Top", units: "m", abbrev: "ICIT" }),
+    ((0, 19, 6), Grib2Parameter { parameter: "Icing Base", units: "m", abbrev: "ICIB" }),
+    ((0, 19, 7), Grib2Parameter { parameter: "Icing", units: "See Table 4.207", abbrev: "ICI" }),
+    ((0, 19, 8), Grib2Parameter { parameter: "Turbulence Top", units: "m", abbrev: "TURBT" }),
+    ((0, 19, 9), Grib2Parameter { parameter: "Turbulence Base", units: "m", abbrev: "TURBB" }),
+    (
+        (0, 19, 10),
+        Grib2Parameter { parameter: "Turbulence", units: "See Table 4.208", abbrev: "TURB" },
+    ),
+    (
+        (0, 19, 11),
+        Grib2Parameter { parameter: "Turbulent Kinetic Energy", units: "J kg-1", abbrev: "TKE" },
+    ),
+    (
+        (0, 19, 12),
+        Grib2Parameter {
+            parameter: "Planetary Boundary Layer Regime",
+            units: "See Table 4.209",
+            abbrev: "PBLREG",
+        },
+    ),
+    (
+        (0, 19, 13),
+        Grib2Parameter {
+            parameter: "Contrail Intensity",
+            units: "See Table 4.210",
+            abbrev: "CONTI",
+        },
+    ),
+    (
+        (0, 19, 14),
+        Grib2Parameter {
+            parameter: "Contrail Engine Type",
+            units: "See Table 4.211",
+            abbrev: "CONTET",
+        },
+    ),
+    ((0, 19, 15), Grib2Parameter { parameter: "Contrail Top", units: "m", abbrev: "CONTT" }),
+    ((0, 19, 16), Grib2Parameter { parameter: "Contrail Base", units: "m", abbrev: "CONTB" }),
+    (
+        (0, 19, 17),
+        Grib2Parameter { parameter: "Maximum Snow Albedo", units: "%", abbrev: "MXSALB" },
+    ),
+    ((0, 19, 18), Grib2Parameter { parameter: "Snow-Free Albedo", units: "%", abbrev: "SNFALB" }),
+    ((0, 19, 19), Grib2Parameter { parameter: "Snow Albedo", units: "%", abbrev: "SALBD" }),
+    ((0, 19, 20), Grib2Parameter { parameter: "Icing", units: "%", abbrev: "ICIP" }),
+    ((0, 19, 21), Grib2Parameter { parameter: "In-Cloud Turbulence", units: "%", abbrev: "CTP" }),
+    (
+        (0, 19, 22),
+        Grib2Parameter { parameter: "Clear Air Turbulence (CAT)", units: "%", abbrev: "CAT" },
+    ),
+    (
+        (0, 19, 23),
+        Grib2Parameter {
+            parameter: "Supercooled Large Droplet Probability",
+            units: "%",
+            abbrev: "SLDP",
+        },
+    ),
+    (
+        (0, 19, 24),
+        Grib2Parameter {
+            parameter: "Convective Turbulent Kinetic Energy",
+            units: "J kg-1",
+            abbrev: "CONTKE",
+        },
+    ),
+    (
+        (0, 19, 25),
+        Grib2Parameter { parameter: "Weather", units: "See Table 4.225", abbrev: "WIWW" },
+    ),
+    (
+        (0, 19, 26),
+        Grib2Parameter {
+            parameter: "Convective Outlook",
+            units: "See Table 4.224",
+            abbrev: "CONVO",
+        },
+    ),
+    (
+        (0, 19, 27),
+        Grib2Parameter { parameter: "Icing Scenario", units: "See Table 4.227", abbrev: "ICESC" },
+    ),
+    (
+        (0, 19, 28),
+        Grib2Parameter {
+            parameter: "Mountain Wave Turbulence (Eddy Dissipation Rate)",
+            units: "m2/3 s-1",
+            abbrev: "MWTURB",
+        },
+    ),
+    (
+        (0, 19, 29),
+        Grib2Parameter {
+            parameter: "Clear Air Turbulence (CAT) (Eddy Dissipation Rate)",
+            units: "m2/3 s-1",
+            abbrev: "CATEDR",
+        },
+    ),
+    (
+        (0, 19, 30),
+        Grib2Parameter {
+            parameter: "Eddy Dissipation Parameter",
+            units: "m2/3 s-1",
+            abbrev: "EDPARM",
+        },
+    ),
+    (
+        (0, 19, 31),
+        Grib2Parameter {
+            parameter: "Maximum of Eddy Dissipation Parameter in Layer",
+            units: "m2/3 s-1",
+            abbrev: "MXEDPRM",
+        },
+    ),
+    (
+        (0, 19, 32),
+        Grib2Parameter { parameter: "Highest Freezing Level", units: "m", abbrev: "HIFREL" },
+    ),
+    (
+        (0, 19, 33),
+        Grib2Parameter {
+            parameter: "Visibility Through Liquid Fog",
+            units: "m",
+            abbrev: "VISLFOG",
+        },
+    ),
+    (
+        (0, 19, 34),
+        Grib2Parameter { parameter: "Visibility Through Ice Fog", units: "m", abbrev: "VISIFOG" },
+    ),
+    (
+        (0, 19, 35),
+        Grib2Parameter {
+            parameter: "Visibility Through Blowing Snow",
+            units: "m",
+            abbrev: "VISBSN",
+        },
+    ),
+    (
+        (0, 19, 36),
+        Grib2Parameter {
+            parameter: "Presence of Snow Squalls",
+            units: "See Table 4.222",
+            abbrev: "PSNOWS",
+        },
+    ),
+    (
+        (0, 19, 37),
+        Grib2Parameter { parameter: "Icing Severity", units: "See Table 4.228", abbrev: "ICESEV" },
+    ),
+    (
+        (0, 19, 38),
+        Grib2Parameter {
+            parameter: "Sky transparency index",
+            units: "See Table 4.214",
+            abbrev: "SKYIDX",
+        },
+    ),
+    (
+        (0, 19, 39),
+        Grib2Parameter { parameter: "Seeing index", units: "See Table 4.214", abbrev: "SEEINDEX" },
+    ),
+    ((0, 19, 40), Grib2Parameter { parameter: "Snow level", units: "m", abbrev: "SNOWLVL" }),
+    ((0, 19, 41), Grib2Parameter { parameter: "Duct base height", units: "m", abbrev: "DBHEIGHT" }),
+    (
+        (0, 19, 42),
+        Grib2Parameter { parameter: "Trapping layer base height", units: "m", abbrev: "TLBHEIGHT" },
+    ),
+    (
+        (0, 19, 43),
+        Grib2Parameter { parameter: "Trapping layer top height", units: "m", abbrev: "TLTHEIGHT" },
+    ),
+    (
+        (0, 19, 44),
+        Grib2Parameter {
+            parameter: "Mean vertical gradient of refractivity inside trapping layer",
+            units: "m-1",
+            abbrev: "MEANVGRTL",
+        },
+    ),
+    (
+        (0, 19, 45),
+        Grib2Parameter {
+            parameter: "Minimum vertical gradient of refractivity inside trapping layer",
+            units: "m-1",
+            abbrev: "MINVGRTL",
+        },
+    ),
+    (
+        (0, 19, 46),
+        Grib2Parameter { parameter: "Net radiation flux", units: "W m-2", abbrev: "NETRADFLUX" },
+    ),
+    (
+        (0, 19, 47),
+        Grib2Parameter {
+            parameter: "Global irradiance on tilted surfaces",
+            units: "W m-2",
+            abbrev: "GLIRRTS",
+        },
+    ),
+    (
+        (0, 19, 48),
+        Grib2Parameter { parameter: "Top of persistent contrails", units: "m", abbrev: "PCONTT" },
+    ),
+    (
+        (0, 19, 49),
+        Grib2Parameter { parameter: "Base of persistent contrails", units: "m", abbrev: "PCONTB" },
+    ),
+    (
+        (0, 19, 50),
+        Grib2Parameter {
+            parameter: "Convectively-induced turbulence (CIT) (eddy dissipation rate)",
+            units: "m2/3 s-1",
+            abbrev: "CITEDR",
+        },
+    ),
+    (
+        (0, 19, 192),
+        Grib2Parameter { parameter: "Maximum Snow Albedo", units: "%", abbrev: "MXSALB" },
+    ),
+    ((0, 19, 193), Grib2Parameter { parameter: "Snow-Free Albedo", units: "%", abbrev: "SNFALB" }),
+    (
+        (0, 19, 194),
+        Grib2Parameter {
+            parameter: "Slight risk convective outlook",
+            units: "categorical",
+            abbrev: "SRCONO",
+        },
+    ),
+    (
+        (0, 19, 195),
+        Grib2Parameter {
+            parameter: "Moderate risk convective outlook",
+            units: "categorical",
+            abbrev: "MRCONO",
+        },
+    ),
+    (
+        (0, 19, 196),
+        Grib2Parameter {
+            parameter: "High risk convective outlook",
+            units: "categorical",
+            abbrev: "HRCONO",
+        },
+    ),
+    (
+        (0, 19, 197),
+        Grib2Parameter { parameter: "Tornado probability", units: "%", abbrev: "TORPROB" },
+    ),
+    (
+        (0, 19, 198),
+        Grib2Parameter { parameter: "Hail probability", units: "%", abbrev: "HAILPROB" },
+    ),
+    (
+        (0, 19, 199),
+        Grib2Parameter { parameter: "Wind probability", units: "%", abbrev: "WINDPROB" },
+    ),
+    (
+        (0, 19, 200),
+        Grib2Parameter {
+            parameter: "Significant Tornado probability",
+            units: "%",
+            abbrev: "STORPROB",
+        },
+    ),
+    (
+        (0, 19, 201),
+        Grib2Parameter {
+            parameter: "Significant Hail probability",
+            units: "%",
+            abbrev: "SHAILPRO",
+        },
+    ),
+    (
+        (0, 19, 202),
+        Grib2Parameter {
+            parameter: "Significant Wind probability",
+            units: "%",
+            abbrev: "SWINDPRO",
+        },
+    ),
+    (
+        (0, 19, 203),
+        Grib2Parameter {
+            parameter: "Categorical Thunderstorm",
+            units: "Code table 4.222",
+            abbrev: "TSTMC",
+        },
+    ),
+    (
+        (0, 19, 204),
+        Grib2Parameter {
+            parameter: "Number of mixed layers next to surface",
+            units: "integer",
+            abbrev: "MIXLY",
+        },
+    ),
+    ((0, 19, 205), Grib2Parameter { parameter: "Flight Category", units: "", abbrev: "FLGHT" }),
+    (
+        (0, 19, 206),
+        Grib2Parameter { parameter: "Confidence - Ceiling", units: "", abbrev: "CICEL" },
+    ),
+    (
+        (0, 19, 207),
+        Grib2Parameter { parameter: "Confidence - Visibility", units: "", abbrev: "CIVIS" },
+    ),
+    (
+        (0, 19, 208),
+        Grib2Parameter { parameter: "Confidence - Flight Category", units: "", abbrev: "CIFLT" },
+    ),
+    (
+        (0, 19, 209),
+        Grib2Parameter { parameter: "Low-Level aviation interest", units: "", abbrev: "LAVNI" },
+    ),
+    (
+        (0, 19, 210),
+        Grib2Parameter { parameter: "High-Level aviation interest", units: "", abbrev: "HAVNI" },
+    ),
+    (
+        (0, 19, 211),
+        Grib2Parameter { parameter: "Visible, Black Sky Albedo", units: "%", abbrev: "SBSALB" },
+    ),
+    (
+        (0, 19, 212),
+        Grib2Parameter { parameter: "Visible, White Sky Albedo", units: "%", abbrev: "SWSALB" },
+    ),
+    (
+        (0, 19, 213),
+        Grib2Parameter { parameter: "Near IR, Black Sky Albedo", units: "%", abbrev: "NBSALB" },
+    ),
+    (
+        (0, 19, 214),
+        Grib2Parameter { parameter: "Near IR, White Sky Albedo", units: "%", abbrev: "NWSALB" },
+    ),
+    (
+        (0, 19, 215),
+        Grib2Parameter {
+            parameter: "Total Probability of Severe Thunderstorms (Days 2,3)",
+            units: "%",
+            abbrev: "PRSVR",
+        },
+    ),
+    (
+        (0, 19, 216),
+        Grib2Parameter {
+            parameter: "Total Probability of Extreme Severe Thunderstorms (Days 2,3)",
+            units: "%",
+            abbrev: "PRSIGSVR",
+        },
+    ),
+    (
+        (0, 19, 217),
+        Grib2Parameter {
+            parameter: "Supercooled Large Droplet (SLD) Icing",
+            units: "See Table 4.207",
+            abbrev: "SIPD",
+        },
+    ),
+    ((0, 19, 218), Grib2Parameter { parameter: "Radiative emissivity", units: "", abbrev: "EPSR" }),
+    (
+        (0, 19, 219),
+        Grib2Parameter {
+            parameter: "Turbulence Potential Forecast Index",
+            units: "",
+            abbrev: "TPFI",
+        },
+    ),
+    (
+        (0, 19, 220),
+        Grib2Parameter {
+            parameter: "Categorical Severe Thunderstorm",
+            units: "Code table 4.222",
+            abbrev: "SVRTS",
+        },
+    ),
+    (
+        (0, 19, 221),
+        Grib2Parameter { parameter: "Probability of Convection", units: "%", abbrev: "PROCON" },
+    ),
+    (
+        (0, 19, 222),
+        Grib2Parameter {
+            parameter: "Convection Potential",
+            units: "Code table 4.222",
+            abbrev: "CONVP",
+        },
+    ),
+    (
+        (0, 19, 232),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Forecast Transport and Dispersion",
+            units: "log10 (kg m-3)",
+            abbrev: "VAFTD",
+        },
+    ),
+    (
+        (0, 19, 233),
+        Grib2Parameter { parameter: "Icing probability", units: "non-dim", abbrev: "ICPRB" },
+    ),
+    (
+        (0, 19, 234),
+        Grib2Parameter { parameter: "Icing Severity", units: "non-dim", abbrev: "ICSEV" },
+    ),
+    (
+        (0, 19, 235),
+        Grib2Parameter {
+            parameter: "Joint Fire Weather Probability",
+            units: "%",
+            abbrev: "JFWPRB",
+        },
+    ),
+    ((0, 19, 236), Grib2Parameter { parameter: "Snow Level", units: "m", abbrev: "SNOWLVL" }),
+    (
+        (0, 19, 237),
+        Grib2Parameter {
+            parameter: "Dry Thunderstorm Probability",
+            units: "%",
+            abbrev: "DRYTPROB",
+        },
+    ),
+    ((0, 19, 238), Grib2Parameter { parameter: "Ellrod Index", units: "", abbrev: "ELLINX" }),
+    (
+        (0, 19, 239),
+        Grib2Parameter {
+            parameter: "Craven-Wiedenfeld Aggregate Severe Parameter",
+            units: "Numeric",
+            abbrev: "CWASP",
+        },
+    ),
+    (
+        (0, 19, 240),
+        Grib2Parameter {
+            parameter: "Continuous Icing Severity",
+            units: "non-dim",
+            abbrev: "ICESEVCON",
+        },
+    ),
+    ((0, 19, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 20, 0),
+        Grib2Parameter {
+            parameter: "Mass Density (Concentration)",
+            units: "kg m-3",
+            abbrev: "MASSDEN",
+        },
+    ),
+    (
+        (0, 20, 1),
+        Grib2Parameter {
+            parameter: "Column-Integrated Mass Density",
+            units: "kg m-2",
+            abbrev: "COLMD",
+        },
+    ),
+    (
+        (0, 20, 2),
+        Grib2Parameter {
+            parameter: "Mass Mixing Ratio (Mass Fraction in Air)",
+            units: "kg kg-1",
+            abbrev: "MASSMR",
+        },
+    ),
+    (
+        (0, 20, 3),
+        Grib2Parameter {
+            parameter: "Atmosphere Emission Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "AEMFLX",
+        },
+    ),
+    (
+        (0, 20, 4),
+        Grib2Parameter {
+            parameter: "Atmosphere Net Production Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "ANPMFLX",
+        },
+    ),
+    (
+        (0, 20, 5),
+        Grib2Parameter {
+            parameter: "Atmosphere Net Production And Emission Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "ANPEMFLX",
+        },
+    ),
+    (
+        (0, 20, 6),
+        Grib2Parameter {
+            parameter: "Surface Dry Deposition Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "SDDMFLX",
+        },
+    ),
+    (
+        (0, 20, 7),
+        Grib2Parameter {
+            parameter: "Surface Wet Deposition Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "SWDMFLX",
+        },
+    ),
+    (
+        (0, 20, 8),
+        Grib2Parameter {
+            parameter: "Atmosphere Re-Emission Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "AREMFLX",
+        },
+    ),
+    (
+        (0, 20, 9),
+        Grib2Parameter {
+            parameter: "Wet Deposition by Large-Scale Precipitation Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "WLSMFLX",
+        },
+    ),
+    (
+        (0, 20, 10),
+        Grib2Parameter {
+            parameter: "Wet Deposition by Convective Precipitation Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "WDCPMFLX",
+        },
+    ),
+    (
+        (0, 20, 11),
+        Grib2Parameter {
+            parameter: "Sedimentation Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "SEDMFLX",
+        },
+    ),
+    (
+        (0, 20, 12),
+        Grib2Parameter {
+            parameter: "Dry Deposition Mass Flux",
+            units: "kg m-2s-1",
+            abbrev: "DDMFLX",
+        },
+    ),
+    (
+        (0, 20, 13),
+        Grib2Parameter {
+            parameter: "Transfer From Hydrophobic to Hydrophilic",
+            units: "kg kg-1s-1",
+            abbrev: "TRANHH",
+        },
+    ),
+    (
+        (0, 20, 14),
+        Grib2Parameter {
+            parameter: "Transfer From SO2 to SO4",
+            units: "kg kg-1s-1",
+            abbrev: "TRSDS",
+        },
+    ),
+    (
+        (0, 20, 15),
+        Grib2Parameter { parameter: "Dry deposition velocity", units: "m s-1", abbrev: "DDVEL" },
+    ),
+    (
+        (0, 20, 16),
+        Grib2Parameter {
+            parameter: "Mass mixing ratio with respect to dry air",
+            units: "kg kg-1",
+            abbrev: "MSSRDRYA",
+        },
+    ),
+    (
+        (0, 20, 17),
+        Grib2Parameter {
+            parameter: "Mass mixing ratio with respect to wet air",
+            units: "kg kg-1",
+            abbrev: "MSSRWETA",
+        },
+    ),
+    (
+        (0, 20, 18),
+        Grib2Parameter { parameter: "Potential of hydrogen (pH)", units: "pH", abbrev: "POTHPH" },
+    ),
+    (
+        (0, 20, 50),
+        Grib2Parameter { parameter: "Amount in Atmosphere", units: "mol", abbrev: "AIA" },
+    ),
+    (
+        (0, 20, 51),
+        Grib2Parameter { parameter: "Concentration In Air", units: "mol m-3", abbrev: "CONAIR" },
+    ),
+    (
+        (0, 20, 52),
+        Grib2Parameter { parameter: "Volume Mixing Ratio", units: "mol mol-1", abbrev: "VMXR" },
+    ),
+    (
+        (0, 20, 53),
+        Grib2Parameter {
+            parameter: "Chemical Gross Production Rate of Concentration",
+            units: "mol m-3s-1",
+            abbrev: "CGPRC",
+        },
+    ),
+    (
+        (0, 20, 54),
+        Grib2Parameter {
+            parameter: "Chemical Gross Destruction Rate of Concentration",
+            units: "mol m-3s-1",
+            abbrev: "CGDRC",
+        },
+    ),
+    (
+        (0, 20, 55),
+        Grib2Parameter { parameter: "Surface Flux", units: "mol m-2s-1", abbrev: "SFLUX" },
+    ),
+    (
+        (0, 20, 56),
+        Grib2Parameter {
+            parameter: "Changes Of Amount in Atmosphere",
+            units: "mol s-1",
+            abbrev: "COAIA",
+        },
+    ),
+    (
+        (0, 20, 57),
+        Grib2Parameter {
+            parameter: "Total Yearly Average Burden of The Atmosphere",
+            units: "mol",
+            abbrev: "TYABA",
+        },
+    ),
+    (
+        (0, 20, 58),
+        Grib2Parameter {
+            parameter: "Total Yearly Average Atmospheric Loss",
+            units: "mol s-1",
+            abbrev: "TYAAL",
+        },
+    ),
+    (
+        (0, 20, 59),
+        Grib2Parameter { parameter: "Aerosol Number Concentration", units: "m-3", abbrev: "ANCON" },
+    ),
+    (
+        (0, 20, 60),
+        Grib2Parameter {
+            parameter: "Aerosol Specific Number Concentration",
+            units: "kg-1",
+            abbrev: "ASNCON",
+        },
+    ),
+    (
+        (0, 20, 61),
+        Grib2Parameter { parameter: "Maximum of Mass Density", units: "kg m-3", abbrev: "MXMASSD" },
+    ),
+    (
+        (0, 20, 62),
+        Grib2Parameter { parameter: "Height of Mass Density", units: "m", abbrev: "HGTMD" },
+    ),
+    (
+        (0, 20, 63),
+        Grib2Parameter {
+            parameter: "Column-Averaged Mass Density in Layer",
+            units: "kg m-3",
+            abbrev: "CAVEMDL",
+        },
+    ),
+    (
+        (0, 20, 64),
+        Grib2Parameter {
+            parameter: "Mole fraction with respect to dry air",
+            units: "mol mol-1",
+            abbrev: "MOLRDRYA",
+        },
+    ),
+    (
+        (0, 20, 65),
+        Grib2Parameter {
+            parameter: "Mole fraction with respect to wet air",
+            units: "mol mol-1",
+            abbrev: "MOLRWETA",
+        },
+    ),
+    (
+        (0, 20, 66),
+        Grib2Parameter {
+            parameter: "Column-integrated in-cloud scavenging rate by precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CINCLDSP",
+        },
+    ),
+    (
+        (0, 20, 67),
+        Grib2Parameter {
+            parameter: "Column-integrated below-cloud scavenging rate by precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CBLCLDSP",
+        },
+    ),
+    (
+        (0, 20, 68),
+        Grib2Parameter {
+            parameter: "Column-integrated release rate from evaporating precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CIRELREP",
+        },
+    ),
+    (
+        (0, 20, 69),
+        Grib2Parameter {
+            parameter: "Column-integrated in-cloud scavenging rate by large-scale precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CINCSLSP",
+        },
+    ),
+    (
+        (0, 20, 70),
+        Grib2Parameter {
+            parameter: "Column-integrated below-cloud scavenging rate by large-scale precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CBECSLSP",
+        },
+    ),
+    (
+        (0, 20, 71),
+        Grib2Parameter {
+            parameter: "Column-integrated release rate from evaporating large-scale precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CRERELSP",
+        },
+    ),
+    (
+        (0, 20, 72),
+        Grib2Parameter {
+            parameter: "Column-integrated in-cloud scavenging rate by convective precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CINCSRCP",
+        },
+    ),
+    (
+        (0, 20, 73),
+        Grib2Parameter {
+            parameter: "Column-integrated below-cloud scavenging rate by convective precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CBLCSRCP",
+        },
+    ),
+    (
+        (0, 20, 74),
+        Grib2Parameter {
+            parameter: "Column-integrated release rate from evaporating convective precipitation",
+            units: "kg m-2 s-1",
+            abbrev: "CIRERECP",
+        },
+    ),
+    (
+        (0, 20, 75),
+        Grib2Parameter { parameter: "Wildfire flux", units: "kg m-2 s-1", abbrev: "WFIREFLX" },
+    ),
+    (
+        (0, 20, 76),
+        Grib2Parameter { parameter: "Emission Rate", units: "kg kg-1 s-1", abbrev: "EMISFLX" },
+    ),
+    (
+        (0, 20, 77),
+        Grib2Parameter {
+            parameter: "Surface Emission flux",
+            units: "kg m-2 s-1",
+            abbrev: "SFCEFLX",
+        },
+    ),
+    (
+        (0, 20, 78),
+        Grib2Parameter {
+            parameter: "Column integrated eastward mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CEMF",
+        },
+    ),
+    (
+        (0, 20, 79),
+        Grib2Parameter {
+            parameter: "Column integrated northward mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CNMF",
+        },
+    ),
+    (
+        (0, 20, 80),
+        Grib2Parameter {
+            parameter: "Column integrated divergence of mass flux",
+            units: "kg m-2 s-1",
+            abbrev: "CDIVMF",
+        },
+    ),
+    (
+        (0, 20, 81),
+        Grib2Parameter {
+            parameter: "Column integrated net source",
+            units: "kg m-2 s-1",
+            abbrev: "CNETS",
+        },
+    ),
+    (
+        (0, 20, 100),
+        Grib2Parameter {
+            parameter: "Surface Area Density (Aerosol)",
+            units: "m-1",
+            abbrev: "SADEN",
+        },
+    ),
+    (
+        (0, 20, 101),
+        Grib2Parameter { parameter: "Vertical Visual Range", units: "m", abbrev: "ATMTK" },
+    ),
+    (
+        (0, 20, 102),
+        Grib2Parameter { parameter: "Aerosol Optical Thickness", units: "Numeric", abbrev: "AOTK" },
+    ),
+    (
+        (0, 20, 103),
+        Grib2Parameter {
+            parameter: "Single Scattering Albedo",
+            units: "Numeric",
+            abbrev: "SSALBK",
+        },
+    ),
+    (
+        (0, 20, 104),
+        Grib2Parameter { parameter: "Asymmetry Factor", units: "Numeric", abbrev: "ASYSFK" },
+    ),
+    (
+        (0, 20, 105),
+        Grib2Parameter {
+            parameter: "Aerosol Extinction Coefficient",
+            units: "m-1",
+            abbrev: "AECOEF",
+        },
+    ),
+    (
+        (0, 20, 106),
+        Grib2Parameter {
+            parameter: "Aerosol Absorption Coefficient",
+            units: "m-1",
+            abbrev: "AACOEF",
+        },
+    ),
+    (
+        (0, 20, 107),
+        Grib2Parameter {
+            parameter: "Aerosol Lidar Backscatter from Satellite",
+            units: "m-1sr-1",
+            abbrev: "ALBSAT",
+        },
+    ),
+    (
+        (0, 20, 108),
+        Grib2Parameter {
+            parameter: "Aerosol Lidar Backscatter from the Ground",
+            units: "m-1sr-1",
+            abbrev: "ALBGRD",
+        },
+    ),
+    (
+        (0, 20, 109),
+        Grib2Parameter {
+            parameter: "Aerosol Lidar Extinction from Satellite",
+            units: "m-1",
+            abbrev: "ALESAT",
+        },
+    ),
+    (
+        (0, 20, 110),
+        Grib2Parameter {
+            parameter: "Aerosol Lidar Extinction from the Ground",
+            units: "m-1",
+            abbrev: "ALEGRD",
+        },
+    ),
+    (
+        (0, 20, 111),
+        Grib2Parameter { parameter: "Angstrom Exponent", units: "Numeric", abbrev: "ANGSTEXP" },
+    ),
+    (
+        (0, 20, 112),
+        Grib2Parameter {
+            parameter: "Scattering Aerosol Optical Thickness",
+            units: "Numeric",
+            abbrev: "SCTAOTK",
+        },
+    ),
+    ((0, 20, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 21, 0),
+        Grib2Parameter {
+            parameter: "Column Integrated Potential + Internal Energy",
+            units: "J m-2",
+            abbrev: "POTINTENG",
+        },
+    ),
+    (
+        (0, 21, 1),
+        Grib2Parameter {
+            parameter: "Column Integrated Kinetic Energy",
+            units: "J m-2",
+            abbrev: "KINENG",
+        },
+    ),
+    (
+        (0, 21, 2),
+        Grib2Parameter {
+            parameter: "Column Integrated Total Energy",
+            units: "J m-2",
+            abbrev: "TOTENG",
+        },
+    ),
+    (
+        (0, 21, 3),
+        Grib2Parameter {
+            parameter: "Column Integrated Enthalpy",
+            units: "J m-2",
+            abbrev: "ENTHALPY",
+        },
+    ),
+    (
+        (0, 21, 4),
+        Grib2Parameter {
+            parameter: "Column Integrated Water Enthalpy",
+            units: "J m-2",
+            abbrev: "WATENTHALPY",
+        },
+    ),
+    (
+        (0, 21, 5),
+        Grib2Parameter {
+            parameter: "Column Integrated Eastward Enthalpy Flux",
+            units: "W m-1",
+            abbrev: "EASTENTFLUX",
+        },
+    ),
+    (
+        (0, 21, 6),
+        Grib2Parameter {
+            parameter: "Column Integrated Northward Enthalpy Flux",
+            units: "W m-1",
+            abbrev: "NRTHENTFLUX",
+        },
+    ),
+    (
+        (0, 21, 7),
+        Grib2Parameter {
+            parameter: "Column Integrated Eastward Potential Energy Flux",
+            units: "W m-1",
+            abbrev: "EASTPOTFLUX",
+        },
+    ),
+    (
+        (0, 21, 8),
+        Grib2Parameter {
+            parameter: "Column Integrated Northward Potential Energy Flux",
+            units: "W m-1",
+            abbrev: "NRTHPOTFLUX",
+        },
+    ),
+    (
+        (0, 21, 9),
+        Grib2Parameter {
+            parameter: "Column Integrated Eastward Kinetic Energy Flux",
+            units: "W m-1",
+            abbrev: "EASTKINFLUX",
+        },
+    ),
+    (
+        (0, 21, 10),
+        Grib2Parameter {
+            parameter: "Column Integrated Northward Kinetic Energy Flux",
+            units: "W m-1",
+            abbrev: "NRTHKINFLUX",
+        },
+    ),
+    (
+        (0, 21, 11),
+        Grib2Parameter {
+            parameter: "Column Integrated Eastward Total Energy Flux",
+            units: "W m-1",
+            abbrev: "EASTTOTFLUX",
+        },
+    ),
+    (
+        (0, 21, 12),
+        Grib2Parameter {
+            parameter: "Column Integrated Northward Total Energy Flux",
+            units: "W m-1",
+            abbrev: "NRTHTOTFLUX",
+        },
+    ),
+    (
+        (0, 21, 13),
+        Grib2Parameter {
+            parameter: "Divergence of Column Integrated Enthalpy Flux",
+            units: "W m-1",
+            abbrev: "DIVENTFLUX",
+        },
+    ),
+    (
+        (0, 21, 14),
+        Grib2Parameter {
+            parameter: "Divergence of Column Integrated Potential Energy Flux",
+            units: "W m-1",
+            abbrev: "DIVPOTFLUX",
+        },
+    ),
+    (
+        (0, 21, 15),
+        Grib2Parameter {
+            parameter: "Divergence of Column Integrated Water Potential Energy Flux",
+            units: "W m-1",
+            abbrev: "DIVWPOTFLUX",
+        },
+    ),
+    (
+        (0, 21, 16),
+        Grib2Parameter {
+            parameter: "Divergence of Column Integrated Kinetic Energy Flux",
+            units: "W m-1",
+            abbrev: "DIVKENGFLUX",
+        },
+    ),
+    (
+        (0, 21, 17),
+        Grib2Parameter {
+            parameter: "Divergence of Column Integrated Total Energy Flux",
+            units: "W m-1",
+            abbrev: "DIVTOTFLUX",
+        },
+    ),
+    (
+        (0, 21, 18),
+        Grib2Parameter {
+            parameter: "Divergence of Column Integrated Water Enthalpy Flux",
+            units: "W m-1",
+            abbrev: "DIVWENTFLUX",
+        },
+    ),
+    (
+        (0, 21, 19),
+        Grib2Parameter {
+            parameter: "Column Integrated Eastward Heat Flux",
+            units: "W m-1",
+            abbrev: "EASTHFLUX",
+        },
+    ),
+    (
+        (0, 21, 20),
+        Grib2Parameter {
+            parameter: "Column Integrated Northward Heat Flux",
+            units: "W m-1",
+            abbrev: "NRTHHFLUX",
+        },
+    ),
+    (
+        (0, 21, 21),
+        Grib2Parameter {
+            parameter: "Column Integrated Potential + Internal + Latent Energy",
+            units: "J m-2",
+            abbrev: "PILENERGY",
+        },
+    ),
+    (
+        (0, 21, 22),
+        Grib2Parameter { parameter: "Eady Growth Rate", units: "day-1", abbrev: "EADYGR" },
+    ),
+    ((0, 21, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 22, 0),
+        Grib2Parameter {
+            parameter: "Standard Precipitation Index (SPI)",
+            units: "dimensionless",
+            abbrev: "SPI",
+        },
+    ),
+    (
+        (0, 22, 1),
+        Grib2Parameter {
+            parameter: "Standardized Precipitation Evapotranspiration Index (SPEI)",
+            units: "dimensionless",
+            abbrev: "SPEI",
+        },
+    ),
+    (
+        (0, 22, 2),
+        Grib2Parameter {
+            parameter: "Standardized Streamflow Index (SSFI)",
+            units: "dimensionless",
+            abbrev: "SSFI",
+        },
+    ),
+    (
+        (0, 22, 3),
+        Grib2Parameter {
+            parameter: "Standardized Reservoir Supply Index (SRSI)",
+            units: "dimensionless",
+            abbrev: "SRSI",
+        },
+    ),
+    (
+        (0, 22, 4),
+        Grib2Parameter {
+            parameter: "Standardized Water-level Index (SWI)",
+            units: "dimensionless",
+            abbrev: "SWI",
+        },
+    ),
+    (
+        (0, 22, 5),
+        Grib2Parameter {
+            parameter: "Standardized Snowmelt and Rain Index (SMRI)",
+            units: "dimensionless",
+            abbrev: "SMRI",
+        },
+    ),
+    (
+        (0, 22, 6),
+        Grib2Parameter {
+            parameter: "Streamflow Drought Index (SDI)",
+            units: "dimensionless",
+            abbrev: "SDI",
+        },
+    ),
+    ((0, 22, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 190, 0),
+        Grib2Parameter { parameter: "Arbitrary Text String", units: "CCITTIA5", abbrev: "ATEXT" },
+    ),
+    ((0, 190, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 191, 0),
+        Grib2Parameter {
+            parameter: "Seconds prior to initial reference time (defined in Section 1)",
+            units: "s",
+            abbrev: "TSEC",
+        },
+    ),
+    (
+        (0, 191, 1),
+        Grib2Parameter { parameter: "Geographical Latitude", units: "° N", abbrev: "GEOLAT" },
+    ),
+    (
+        (0, 191, 2),
+        Grib2Parameter { parameter: "Geographical Longitude", units: "° E", abbrev: "GEOLON" },
+    ),
+    (
+        (0, 191, 3),
+        Grib2Parameter { parameter: "Days Since Last Observation", units: "d", abbrev: "DSLOBS" },
+    ),
+    (
+        (0, 191, 4),
+        Grib2Parameter {
+            parameter: "Tropical cyclone density track",
+            units: "Numeric",
+            abbrev: "TCDTRACK",
+        },
+    ),
+    (
+        (0, 191, 5),
+        Grib2Parameter {
+            parameter: "Hurricane track in spatiotemporal vicinity",
+            units: "boolean",
+            abbrev: "HURTSV",
+        },
+    ),
+    (
+        (0, 191, 6),
+        Grib2Parameter {
+            parameter: "Tropical storm track in spatiotemporal vicinity",
+            units: "boolean",
+            abbrev: "TSTSV",
+        },
+    ),
+    (
+        (0, 191, 7),
+        Grib2Parameter {
+            parameter: "Tropical depression track in spatiotemporal vicinity",
+            units: "boolean",
+            abbrev: "TDTSV",
+        },
+    ),
+    (
+        (0, 191, 192),
+        Grib2Parameter { parameter: "Latitude (-90 to 90)", units: "°", abbrev: "NLAT" },
+    ),
+    (
+        (0, 191, 193),
+        Grib2Parameter { parameter: "East Longitude (0 to 360)", units: "°", abbrev: "ELON" },
+    ),
+    (
+        (0, 191, 194),
+        Grib2Parameter {
+            parameter: "Seconds prior to initial reference time",
+            units: "s",
+            abbrev: "RTSEC",
+        },
+    ),
+    (
+        (0, 191, 195),
+        Grib2Parameter {
+            parameter: "Model Layer number (From bottom up)",
+            units: "",
+            abbrev: "MLYNO",
+        },
+    ),
+    (
+        (0, 191, 196),
+        Grib2Parameter {
+            parameter: "Latitude (nearest neighbor) (-90 to 90)",
+            units: "°",
+            abbrev: "NLATN",
+        },
+    ),
+    (
+        (0, 191, 197),
+        Grib2Parameter {
+            parameter: "East Longitude (nearest neighbor) (0 to 360)",
+            units: "°",
+            abbrev: "ELONN",
+        },
+    ),
+    ((0, 191, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (0, 192, 1),
+        Grib2Parameter {
+            parameter: "Covariance between zonal and meridional components of the wind",
+            units: "m2/s2",
+            abbrev: "COVMZ",
+        },
+    ),
+    (
+        (0, 192, 2),
+        Grib2Parameter {
+            parameter: "Covariance between zonal component of the wind and temperature",
+            units: "K*m/s",
+            abbrev: "COVTZ",
+        },
+    ),
+    (
+        (0, 192, 3),
+        Grib2Parameter {
+            parameter: "Covariance between meridional component of the wind and temperature",
+            units: "K*m/s",
+            abbrev: "COVTM",
+        },
+    ),
+    (
+        (0, 192, 4),
+        Grib2Parameter {
+            parameter: "Covariance between temperature and vertical component of the wind",
+            units: "K*m/s",
+            abbrev: "COVTW",
+        },
+    ),
+    (
+        (0, 192, 5),
+        Grib2Parameter {
+            parameter: "Covariance between zonal and zonal components of the wind",
+            units: "m2/s2",
+            abbrev: "COVZZ",
+        },
+    ),
+    (
+        (0, 192, 6),
+        Grib2Parameter {
+            parameter: "Covariance between meridional and meridional components of the wind",
+            units: "m2/s2",
+            abbrev: "COVMM",
+        },
+    ),
+    (
+        (0, 192, 7),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and zonal components of the wind",
+            units: "kg/kg*m/s",
+            abbrev: "COVQZ",
+        },
+    ),
+    (
+        (0, 192, 8),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and meridional components of the wind",
+            units: "kg/kg*m/s",
+            abbrev: "COVQM",
+        },
+    ),
+    (
+        (0, 192, 9),
+        Grib2Parameter {
+            parameter: "Covariance between temperature and vertical components of the wind",
+            units: "K*Pa/s",
+            abbrev: "COVTVV",
+        },
+    ),
+    (
+        (0, 192, 10),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and vertical components of the wind",
+            units: "kg/kg*Pa/s",
+            abbrev: "COVQVV",
+        },
+    ),
+    (
+        (0, 192, 11),
+        Grib2Parameter {
+            parameter: "Covariance between surface pressure and surface pressure",
+            units: "Pa*Pa",
+            abbrev: "COVPSPS",
+        },
+    ),
+    (
+        (0, 192, 12),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and specific humidity",
+            units: "kg/kg*kg/kg",
+            abbrev: "COVQQ",
+        },
+    ),
+    (
+        (0, 192, 13),
+        Grib2Parameter {
+            parameter: "Covariance between vertical and vertical components of the wind",
+            units: "Pa2/s2",
+            abbrev: "COVVVVV",
+        },
+    ),
+    (
+        (0, 192, 14),
+        Grib2Parameter {
+            parameter: "Covariance between temperature and temperature",
+            units: "K*K",
+            abbrev: "COVTT",
+        },
+    ),
+    ((0, 192, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (1, 0, 0),
+        Grib2Parameter {
+            parameter: "Flash Flood Guidance (Encoded as an accumulation over a floating \
+                        subinterval of time between the reference time and valid time)",
+            units: "kg m-2",
+            abbrev: "FFLDG",
+        },
+    ),
+    (
+        (1, 0, 1),
+        Grib2Parameter {
+            parameter: "Flash Flood Runoff (Encoded as an accumulation over a floating \
+                        subinterval of time)",
+            units: "kg m-2",
+            abbrev: "FFLDRO",
+        },
+    ),
+    (
+        (1, 0, 2),
+        Grib2Parameter {
+            parameter: "Remotely Sensed Snow Cover",
+            units: "See Table 4.215",
+            abbrev: "RSSC",
+        },
+    ),
+    (
+        (1, 0, 3),
+        Grib2Parameter {
+            parameter: "Elevation of Snow Covered Terrain",
+            units: "See Table 4.216",
+            abbrev: "ESCT",
+        },
+    ),
+    (
+        (1, 0, 4),
+        Grib2Parameter {
+            parameter: "Snow Water Equivalent Percent of Normal",
+            units: "%",
+            abbrev: "SWEPON",
+        },
+    ),
+    (
+        (1, 0, 5),
+        Grib2Parameter {
+            parameter: "Baseflow-Groundwater Runoff",
+            units: "kg m-2",
+            abbrev: "BGRUN",
+        },
+    ),
+    (
+        (1, 0, 6),
+        Grib2Parameter { parameter: "Storm Surface Runoff", units: "kg m-2", abbrev: "SSRUN" },
+    ),
+    (
+        (1, 0, 7),
+        Grib2Parameter {
+            parameter: "Discharge from Rivers or Streams",
+            units: "m3 s-1",
+            abbrev: "DISRS",
+        },
+    ),
+    (
+        (1, 0, 8),
+        Grib2Parameter { parameter: "Group Water Upper Storage", units: "kg m-2", abbrev: "GWUPS" },
+    ),
+    (
+        (1, 0, 9),
+        Grib2Parameter {
+            parameter: "Group Water Lower Storage",
+            units: "kg m-2",
+            abbrev: "GWLOWS",
+        },
+    ),
+    (
+        (1, 0, 10),
+        Grib2Parameter {
+            parameter: "Side Flow into River Channel",
+            units: "m3 s-1 m-1",
+            abbrev: "SFLORC",
+        },
+    ),
+    (
+        (1, 0, 11),
+        Grib2Parameter { parameter: "River Storage of Water", units: "m3", abbrev: "RVERSW" },
+    ),
+    (
+        (1, 0, 12),
+        Grib2Parameter { parameter: "Flood Plain Storage of Water", units: "m3", abbrev: "FLDPSW" },
+    ),
+    (
+        (1, 0, 13),
+        Grib2Parameter {
+            parameter: "Depth of Water on Soil Surface",
+            units: "kg m-2",
+            abbrev: "DEPWSS",
+        },
+    ),
+    (
+        (1, 0, 14),
+        Grib2Parameter {
+            parameter: "Upstream Accumulated Precipitation",
+            units: "kg m-2",
+            abbrev: "UPAPCP",
+        },
+    ),
+    (
+        (1, 0, 15),
+        Grib2Parameter {
+            parameter: "Upstream Accumulated Snow Melt",
+            units: "kg m-2",
+            abbrev: "UPASM",
+        },
+    ),
+    (
+        (1, 0, 16),
+        Grib2Parameter { parameter: "Percolation Rate", units: "kg m-2 s-1", abbrev: "PERRATE" },
+    ),
+    (
+        (1, 0, 17),
+        Grib2Parameter { parameter: "River Outflow of Water", units: "m3 s-1", abbrev: "RVEROW" },
+    ),
+    (
+        (1, 0, 18),
+        Grib2Parameter {
+            parameter: "Floodplain Outflow of Water",
+            units: "m3 s-1",
+            abbrev: "FLDPOW",
+        },
+    ),
+    (
+        (1, 0, 19),
+        Grib2Parameter {
+            parameter: "Floodpath Outflow of Water",
+            units: "m3 s-1",
+            abbrev: "FLDPATHOW",
+        },
+    ),
+    (
+        (1, 0, 20),
+        Grib2Parameter { parameter: "Water on Surface", units: "kg m-2", abbrev: "WATSURF" },
+    ),
+    (
+        (1, 0, 192),
+        Grib2Parameter {
+            parameter: "Baseflow-Groundwater Runoff",
+            units: "kg m-2",
+            abbrev: "BGRUN",
+        },
+    ),
+    (
+        (1, 0, 193),
+        Grib2Parameter { parameter: "Storm Surface Runoff", units: "kg m-2", abbrev: "SSRUN" },
+    ),
+    ((1, 0, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (1, 1, 0),
+        Grib2Parameter {
+            parameter: "Conditional percent precipitation amount fractile for an overall period \
+                        (encoded as an accumulation)",
+            units: "kg m-2",
+            abbrev: "CPPOP",
+        },
+    ),
+    (
+        (1, 1, 1),
+        Grib2Parameter {
+            parameter: "Percent Precipitation in a sub-period of an overall period (encoded as a \
+                        percent accumulation over the sub-period)",
+            units: "%",
+            abbrev: "PPOSP",
+        },
+    ),
+    (
+        (1, 1, 2),
+        Grib2Parameter {
+            parameter: "Probability of 0.01 inch of precipitation (POP)",
+            units: "%",
+            abbrev: "POP",
+        },
+    ),
+    (
+        (1, 1, 192),
+        Grib2Parameter {
+            parameter: "Probability of Freezing Precipitation",
+            units: "%",
+            abbrev: "CPOZP",
+        },
+    ),
+    (
+        (1, 1, 193),
+        Grib2Parameter {
+            parameter: "Percent of Frozen Precipitation",
+            units: "%",
+            abbrev: "CPOFP",
+        },
+    ),
+    (
+        (1, 1, 194),
+        Grib2Parameter {
+            parameter: "Probability of precipitation exceeding flash flood guidance values",
+            units: "%",
+            abbrev: "PPFFG",
+        },
+    ),
+    (
+        (1, 1, 195),
+        Grib2Parameter {
+            parameter: "Probability of Wetting Rain, exceeding in 0.10\" in a given time period",
+            units: "%",
+            abbrev: "CWR",
+        },
+    ),
+    (
+        (1, 1, 196),
+        Grib2Parameter {
+            parameter: "Binary Probability of precipitation exceeding average recurrence \
+                        intervals (ARI)",
+            units: "see Code table 4.222",
+            abbrev: "QPFARI",
+        },
+    ),
+    (
+        (1, 1, 197),
+        Grib2Parameter {
+            parameter: "Binary Probability of precipitation exceeding flash flood guidance values",
+            units: "see Code table 4.222",
+            abbrev: "QPFFFG",
+        },
+    ),
+    ((1, 1, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    ((1, 2, 0), Grib2Parameter { parameter: "Water Depth", units: "m", abbrev: "WDPTHIL" }),
+    ((1, 2, 1), Grib2Parameter { parameter: "Water Temperature", units: "K", abbrev: "WTMPIL" }),
+    (
+        (1, 2, 2),
+        Grib2Parameter { parameter: "Water Fraction", units: "Proportion", abbrev: "WFRACT" },
+    ),
+    ((1, 2, 3), Grib2Parameter { parameter: "Sediment Thickness", units: "m", abbrev: "SEDTK" }),
+    ((1, 2, 4), Grib2Parameter { parameter: "Sediment Temperature", units: "K", abbrev: "SEDTMP" }),
+    ((1, 2, 5), Grib2Parameter { parameter: "Ice Thickness", units: "m", abbrev: "ICTKIL" }),
+    ((1, 2, 6), Grib2Parameter { parameter: "Ice Temperature", units: "K", abbrev: "ICETIL" }),
+    ((1, 2, 7), Grib2Parameter { parameter: "Ice Cover", units: "Proportion", abbrev: "ICECIL" }),
+    (
+        (1, 2, 8),
+        Grib2Parameter {
+            parameter: "Land Cover (0=water, 1=land)",
+            units: "Proportion",
+            abbrev: "LANDIL",
+        },
+    ),
+    (
+        (1, 2, 9),
+        Grib2Parameter {
+            parameter: "Shape Factor with Respect to Salinity Profile",
+            units: "",
+            abbrev: "SFSAL",
+        },
+    ),
+    (
+        (1, 2, 10),
+        Grib2Parameter {
+            parameter: "Shape Factor with Respect to Temperature Profile in Thermocline",
+            units: "",
+            abbrev: "SFTMP",
+        },
+    ),
+    (
+        (1, 2, 11),
+        Grib2Parameter {
+            parameter: "Attenuation Coefficient of Water with Respect to Solar Radiation",
+            units: "m-1",
+            abbrev: "ACWSR",
+        },
+    ),
+    ((1, 2, 12), Grib2Parameter { parameter: "Salinity", units: "kg kg-1", abbrev: "SALTIL" }),
+    (
+        (1, 2, 13),
+        Grib2Parameter {
+            parameter: "Cross Sectional Area of Flow in Channel",
+            units: "m2",
+            abbrev: "CSAFC",
+        },
+    ),
+    ((1, 2, 14), Grib2Parameter { parameter: "Snow Temperature", units: "K", abbrev: "LNDSNOWT" }),
+    ((1, 2, 15), Grib2Parameter { parameter: "Lake Depth", units: "m", abbrev: "LDEPTH" }),
+    ((1, 2, 16), Grib2Parameter { parameter: "River Depth", units: "m", abbrev: "RDEPTH" }),
+    ((1, 2, 17), Grib2Parameter { parameter: "Floodplain Depth", units: "m", abbrev: "FLDPDEPTH" }),
+    (
+        (1, 2, 18),
+        Grib2Parameter {
+            parameter: "Floodplain Flooded Fraction",
+            units: "Proportion",
+            abbrev: "FLDPFLFR",
+        },
+    ),
+    (
+        (1, 2, 19),
+        Grib2Parameter { parameter: "Floodplain Flooded Area", units: "m2", abbrev: "FLDPFLAR" },
+    ),
+    (
+        (1, 2, 20),
+        Grib2Parameter { parameter: "River Fraction", units: "Proportion", abbrev: "RVERFR" },
+    ),
+    ((1, 2, 21), Grib2Parameter { parameter: "River Area", units: "m2", abbrev: "RVERAR" }),
+    (
+        (1, 2, 22),
+        Grib2Parameter {
+            parameter: "Fraction of River Coverage Plus River Related Flooding",
+            units: "Proportion",
+            abbrev: "FRCRF",
+        },
+    ),
+    (
+        (1, 2, 23),
+        Grib2Parameter {
+            parameter: "Area of River Coverage Plus River Related Flooding",
+            units: "m2",
+            abbrev: "ARCRF",
+        },
+    ),
+    ((1, 2, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (2, 0, 0),
+        Grib2Parameter {
+            parameter: "Land Cover (0=sea, 1=land)",
+            units: "Proportion",
+            abbrev: "LAND",
+        },
+    ),
+    ((2, 0, 1), Grib2Parameter { parameter: "Surface Roughness", units: "m", abbrev: "SFCR" }),
+    (
+        (2, 0, 2),
+        Grib2Parameter {
+            parameter: "Soil Temperature (Parameter Deprecated)",
+            units: "K",
+            abbrev: "TSOIL",
+        },
+    ),
+    (
+        (2, 0, 3),
+        Grib2Parameter {
+            parameter: "Soil Moisture Content (Parameter Deprecated)",
+            units: "",
+            abbrev: "",
+        },
+    ),
+    ((2, 0, 4), Grib2Parameter { parameter: "Vegetation", units: "%", abbrev: "VEG" }),
+    ((2, 0, 5), Grib2Parameter { parameter: "Water Runoff", units: "kg m-2", abbrev: "WATR" }),
+    (
+        (2, 0, 6),
+        Grib2Parameter { parameter: "Evapotranspiration", units: "kg-2 s-1", abbrev: "EVAPT" },
+    ),
+    ((2, 0, 7), Grib2Parameter { parameter: "Model Terrain Height", units: "m", abbrev: "MTERH" }),
+    (
+        (2, 0, 8),
+        Grib2Parameter { parameter: "Land Use", units: "See Table 4.212", abbrev: "LANDU" },
+    ),
+    (
+        (2, 0, 9),
+        Grib2Parameter {
+            parameter: "Volumetric Soil Moisture Content",
+            units: "Proportion",
+            abbrev: "SOILW",
+        },
+    ),
+    ((2, 0, 10), Grib2Parameter { parameter: "Ground Heat Flux", units: "W m-2", abbrev: "GFLUX" }),
+    (
+        (2, 0, 11),
+        Grib2Parameter { parameter: "Moisture Availability", units: "%", abbrev: "MSTAV" },
+    ),
+    (
+        (2, 0, 12),
+        Grib2Parameter { parameter: "Exchange Coefficient", units: "kg m-2 s-1", abbrev: "SFEXC" },
+    ),
+    (
+        (2, 0, 13),
+        Grib2Parameter {
+            parameter: "Plant Canopy Surface Water",
+            units: "kg m-2",
+            abbrev: "CNWAT",
+        },
+    ),
+    (
+        (2, 0, 14),
+        Grib2Parameter {
+            parameter: "Blackadar's Mixing Length Scale",
+            units: "m",
+            abbrev: "BMIXL",
+        },
+    ),
+    (
+        (2, 0, 15),
+        Grib2Parameter { parameter: "Canopy Conductance", units: "m s-1", abbrev: "CCOND" },
+    ),
+    (
+        (2, 0, 16),
+        Grib2Parameter {
+            parameter: "Minimal Stomatal Resistance",
+            units: "s m-1",
+            abbrev: "RSMIN",
+        },
+    ),
+    (
+        (2, 0, 17),
+        Grib2Parameter {
+            parameter: "Wilting Point (Parameter Deprecated)",
+            units: "Proportion",
+            abbrev: "WILT",
+        },
+    ),
+    (
+        (2, 0, 18),
+        Grib2Parameter {
+            parameter: "Solar parameter in canopy conductance",
+            units: "Proportion",
+            abbrev: "RCS",
+        },
+    ),
+    (
+        (2, 0, 19),
+        Grib2Parameter {
+            parameter: "Temperature parameter in canopy",
+            units: "Proportion",
+            abbrev: "RCT",
+        },
+    ),
+    (
+        (2, 0, 20),
+        Grib2Parameter {
+            parameter: "Humidity parameter in canopy conductance",
+            units: "Proportion",
+            abbrev: "RCQ",
+        },
+    ),
+    (
+        (2, 0, 21),
+        Grib2Parameter {
+            parameter: "Soil moisture parameter in canopy conductance",
+            units: "Proportion",
+            abbrev: "RCSOL",
+        },
+    ),
+    (
+        (2, 0, 22),
+        Grib2Parameter { parameter: "Soil Moisture (Parameter Deprecated)", units: "", abbrev: "" },
+    ),
+    (
+        (2, 0, 23),
+        Grib2Parameter {
+            parameter: "Column-Integrated Soil Water (Parameter Deprecated)",
+            units: "kg m-2",
+            abbrev: "CISOILW",
+        },
+    ),
+    ((2, 0, 24), Grib2Parameter { parameter: "Heat Flux", units: "W m-2", abbrev: "HFLUX" }),
+    (
+        (2, 0, 25),
+        Grib2Parameter { parameter: "Volumetric Soil Moisture", units: "m3 m-3", abbrev: "VSOILM" },
+    ),
+    ((2, 0, 26), Grib2Parameter { parameter: "Wilting Point", units: "kg m-3", abbrev: "WILT" }),
+    (
+        (2, 0, 27),
+        Grib2Parameter { parameter: "Volumetric Wilting Point", units: "m3 m-3", abbrev: "VWILTP" },
+    ),
+    (
+        (2, 0, 28),
+        Grib2Parameter { parameter: "Leaf Area Index", units: "Numeric", abbrev: "LEAINX" },
+    ),
+    (
+        (2, 0, 29),
+        Grib2Parameter {
+            parameter: "Evergreen Forest Cover",
+            units: "Proportion",
+            abbrev: "EVGFC",
+        },
+    ),
+    (
+        (2, 0, 30),
+        Grib2Parameter {
+            parameter: "Deciduous Forest Cover",
+            units: "Proportion",
+            abbrev: "DECFC",
+        },
+    ),
+    (
+        (2, 0, 31),
+        Grib2Parameter {
+            parameter: "Normalized Differential Vegetation Index (NDVI)",
+            units: "Numeric",
+            abbrev: "NDVINX",
+        },
+    ),
+    (
+        (2, 0, 32),
+        Grib2Parameter { parameter: "Root Depth of Vegetation", units: "m", abbrev: "RDVEG" },
+    ),
+    (
+        (2, 0, 33),
+        Grib2Parameter { parameter: "Water Runoff and Drainage", units: "kg m-2", abbrev: "WROD" },
+    ),
+    (
+        (2, 0, 34),
+        Grib2Parameter { parameter: "Surface Water Runoff", units: "kg m-2", abbrev: "SFCWRO" },
+    ),
+    (
+        (2, 0, 35),
+        Grib2Parameter { parameter: "Tile Class", units: "See Table 4.243", abbrev: "TCLASS" },
+    ),
+    (
+        (2, 0, 36),
+        Grib2Parameter { parameter: "Tile Fraction", units: "Proportion", abbrev: "TFRCT" },
+    ),
+    ((2, 0, 37), Grib2Parameter { parameter: "Tile Percentage", units: "%", abbrev: "TPERCT" }),
+    (
+        (2, 0, 38),
+        Grib2Parameter {
+            parameter: "Soil Volumetric Ice Content (Water Equivalent)",
+            units: "m3 m-3",
+            abbrev: "SOILVIC",
+        },
+    ),
+    (
+        (2, 0, 39),
+        Grib2Parameter {
+            parameter: "Evapotranspiration Rate",
+            units: "kg m-2 s-1",
+            abbrev: "EVAPTRAT",
+        },
+    ),
+    (
+        (2, 0, 40),
+        Grib2Parameter {
+            parameter: "Potential Evapotranspiration Rate",
+            units: "kg m-2 s-1",
+            abbrev: "PEVAPTRAT",
+        },
+    ),
+    (
+        (2, 0, 41),
+        Grib2Parameter { parameter: "Snow Melt Rate", units: "kg m-2 s-1", abbrev: "SMRATE" },
+    ),
+    (
+        (2, 0, 42),
+        Grib2Parameter {
+            parameter: "Water Runoff and Drainage Rate",
+            units: "kg m-2 s-1",
+            abbrev: "WRDRATE",
+        },
+    ),
+    (
+        (2, 0, 43),
+        Grib2Parameter {
+            parameter: "Drainage direction",
+            units: "See Table 4.250",
+            abbrev: "DRAINDIR",
+        },
+    ),
+    ((2, 0, 44), Grib2Parameter { parameter: "Upstream Area", units: "m2", abbrev: "UPSAREA" }),
+    (
+        (2, 0, 45),
+        Grib2Parameter { parameter: "Wetland Cover", units: "Proportion", abbrev: "WETCOV" },
+    ),
+    (
+        (2, 0, 46),
+        Grib2Parameter { parameter: "Wetland Type", units: "See Table 4.239", abbrev: "WETTYPE" },
+    ),
+    (
+        (2, 0, 47),
+        Grib2Parameter { parameter: "Irrigation Cover", units: "Proportion", abbrev: "IRRCOV" },
+    ),
+    (
+        (2, 0, 48),
+        Grib2Parameter { parameter: "C4 Crop Cover", units: "Proportion", abbrev: "CROPCOV" },
+    ),
+    (
+        (2, 0, 49),
+        Grib2Parameter { parameter: "C4 Grass Cover", units: "Proportion", abbrev: "GRASSCOV" },
+    ),
+    (
+        (2, 0, 50),
+        Grib2Parameter { parameter: "Skin Reservoir Content", units: "kg m-2", abbrev: "SKINRC" },
+    ),
+    (
+        (2, 0, 51),
+        Grib2Parameter {
+            parameter: "Surface Runoff Rate",
+            units: "kg m-2 s-1",
+            abbrev: "SURFRATE",
+        },
+    ),
+    (
+        (2, 0, 52),
+        Grib2Parameter {
+            parameter: "Subsurface Runoff Rate",
+            units: "kg m-2 s-1",
+            abbrev: "SUBSRATE",
+        },
+    ),
+    (
+        (2, 0, 53),
+        Grib2Parameter {
+            parameter: "Low-Vegetation Cover",
+            units: "Proportion",
+            abbrev: "LOVEGCOV",
+        },
+    ),
+    (
+        (2, 0, 54),
+        Grib2Parameter {
+            parameter: "High-Vegetation Cover",
+            units: "Proportion",
+            abbrev: "HIVEGCOV",
+        },
+    ),
+    (
+        (2, 0, 55),
+        Grib2Parameter {
+            parameter: "Leaf Area Index (Low-Vegetation)",
+            units: "m2 m-2",
+            abbrev: "LAILO",
+        },
+    ),
+    (
+        (2, 0, 56),
+        Grib2Parameter {
+            parameter: "Leaf Area Index (High-Vegetation)",
+            units: "m2 m-2",
+            abbrev: "LAIHI",
+        },
+    ),
+    (
+        (2, 0, 57),
+        Grib2Parameter {
+            parameter: "Type of Low-Vegetation",
+            units: "See Table 4.234",
+            abbrev: "TYPLOVEG",
+        },
+    ),
+    (
+        (2, 0, 58),
+        Grib2Parameter {
+            parameter: "Type of High-Vegetation",
+            units: "See Table 4.234",
+            abbrev: "TYPHIVEG",
+        },
+    ),
+    (
+        (2, 0, 59),
+        Grib2Parameter {
+            parameter: "Net Ecosystem Exchange Flux",
+            units: "kg-2 s-1",
+            abbrev: "NECOFLUX",
+        },
+    ),
+    (
+        (2, 0, 60),
+        Grib2Parameter {
+            parameter: "Gross Primary Production Flux",
+            units: "kg-2 s-1",
+            abbrev: "GROSSFLUX",
+        },
+    ),
+    (
+        (2, 0, 61),
+        Grib2Parameter {
+            parameter: "Ecosystem Respiration Flux",
+            units: "kg-2 s-1",
+            abbrev: "ECORFLUX",
+        },
+    ),
+    ((2, 0, 62), Grib2Parameter { parameter: "Emissivity", units: "Proportion", abbrev: "EMISS" }),
+    (
+        (2, 0, 63),
+        Grib2Parameter { parameter: "Canopy Air Temperature", units: "K", abbrev: "CANTMP" },
+    ),
+    (
+        (2, 0, 192),
+        Grib2Parameter {
+            parameter: "Volumetric Soil Moisture Content",
+            units: "Fraction",
+            abbrev: "SOILW",
+        },
+    ),
+    (
+        (2, 0, 193),
+        Grib2Parameter { parameter: "Ground Heat Flux", units: "W m-2", abbrev: "GFLUX" },
+    ),
+    (
+        (2, 0, 194),
+        Grib2Parameter { parameter: "Moisture Availability", units: "%", abbrev: "MSTAV" },
+    ),
+    (
+        (2, 0, 195),
+        Grib2Parameter {
+            parameter: "Exchange Coefficient",
+            units: "(kg m-3) (m s-1)",
+            abbrev: "SFEXC",
+        },
+    ),
+    (
+        (2, 0, 196),
+        Grib2Parameter {
+            parameter: "Plant Canopy Surface Water",
+            units: "kg m-2",
+            abbrev: "CNWAT",
+        },
+    ),
+    (
+        (2, 0, 197),
+        Grib2Parameter {
+            parameter: "Blackadar's Mixing Length Scale",
+            units: "m",
+            abbrev: "BMIXL",
+        },
+    ),
+    (
+        (2, 0, 198),
+        Grib2Parameter { parameter: "Vegetation Type", units: "Integer (0-13)", abbrev: "VGTYP" },
+    ),
+    (
+        (2, 0, 199),
+        Grib2Parameter { parameter: "Canopy Conductance", units: "m s-1", abbrev: "CCOND" },
+    ),
+    (
+        (2, 0, 200),
+        Grib2Parameter {
+            parameter: "Minimal Stomatal Resistance",
+            units: "s m-1",
+            abbrev: "RSMIN",
+        },
+    ),
+    ((2, 0, 201), Grib2Parameter { parameter: "Wilting Point", units: "Fraction", abbrev: "WILT" }),
+    (
+        (2, 0, 202),
+        Grib2Parameter {
+            parameter: "Solar parameter in canopy conductance",
+            units: "Fraction",
+            abbrev: "RCS",
+        },
+    ),
+    (
+        (2, 0, 203),
+        Grib2Parameter {
+            parameter: "Temperature parameter in canopy conductance",
+            units: "Fraction",
+            abbrev: "RCT",
+        },
+    ),
+    (
+        (2, 0, 204),
+        Grib2Parameter {
+            parameter: "Humidity parameter in canopy conductance",
+            units: "Fraction",
+            abbrev: "RCQ",
+        },
+    ),
+    (
+        (2, 0, 205),
+        Grib2Parameter {
+            parameter: "Soil moisture parameter in canopy conductance",
+            units: "Fraction",
+            abbrev: "RCSOL",
+        },
+    ),
+    (
+        (2, 0, 206),
+        Grib2Parameter {
+            parameter: "Rate of water dropping from canopy to ground",
+            units: "",
+            abbrev: "RDRIP",
+        },
+    ),
+    (
+        (2, 0, 207),
+        Grib2Parameter { parameter: "Ice-free water surface", units: "%", abbrev: "ICWAT" },
+    ),
+    (
+        (2, 0, 208),
+        Grib2Parameter {
+            parameter: "Surface exchange coefficients for T and Q divided by delta z",
+            units: "m s-1",
+            abbrev: "AKHS",
+        },
+    ),
+    (
+        (2, 0, 209),
+        Grib2Parameter {
+            parameter: "Surface exchange coefficients for U and V divided by delta z",
+            units: "m s-1",
+            abbrev: "AKMS",
+        },
+    ),
+    (
+        (2, 0, 210),
+        Grib2Parameter { parameter: "Vegetation Canopy Temperature", units: "K", abbrev: "VEGT" },
+    ),
+    (
+        (2, 0, 211),
+        Grib2Parameter { parameter: "Surface Water Storage", units: "kg m-2", abbrev: "SSTOR" },
+    ),
+    (
+        (2, 0, 212),
+        Grib2Parameter {
+            parameter: "Liquid Soil Moisture Content (non-frozen)",
+            units: "kg m-2",
+            abbrev: "LSOIL",
+        },
+    ),
+    (
+        (2, 0, 213),
+        Grib2Parameter {
+            parameter: "Open Water Evaporation (standing water)",
+            units: "W m-2",
+            abbrev: "EWATR",
+        },
+    ),
+    (
+        (2, 0, 214),
+        Grib2Parameter { parameter: "Groundwater Recharge", units: "kg m-2", abbrev: "GWREC" },
+    ),
+    (
+        (2, 0, 215),
+        Grib2Parameter { parameter: "Flood Plain Recharge", units: "kg m-2", abbrev: "QREC" },
+    ),
+    (
+        (2, 0, 216),
+        Grib2Parameter { parameter: "Roughness Length for Heat", units: "m", abbrev: "SFCRH" },
+    ),
+    (
+        (2, 0, 217),
+        Grib2Parameter {
+            parameter: "Normalized Difference Vegetation Index",
+            units: "",
+            abbrev: "NDVI",
+        },
+    ),
+    (
+        (2, 0, 218),
+        Grib2Parameter {
+            parameter: "Land-Sea Coverage (nearest neighbor) [land=1, sea=0]",
+            units: "",
+            abbrev: "LANDN",
+        },
+    ),
+    (
+        (2, 0, 219),
+        Grib2Parameter { parameter: "Asymptotic Mixing Length Scale", units: "m", abbrev: "AMIXL" },
+    ),
+    (
+        (2, 0, 220),
+        Grib2Parameter {
+            parameter: "Water Vapor Added by Precip Assimilation",
+            units: "kg m-2",
+            abbrev: "WVINC",
+        },
+    ),
+    (
+        (2, 0, 221),
+        Grib2Parameter {
+            parameter: "Water Condensate Added by Precip Assimilation",
+            units: "kg m-2",
+            abbrev: "WCINC",
+        },
+    ),
+    (
+        (2, 0, 222),
+        Grib2Parameter {
+            parameter: "Water Vapor Flux Convergence (Vertical Int)",
+            units: "kg m-2",
+            abbrev: "WVCONV",
+        },
+    ),
+    (
+        (2, 0, 223),
+        Grib2Parameter {
+            parameter: "Water Condensate Flux Convergence (Vertical Int)",
+            units: "kg m-2",
+            abbrev: "WCCONV",
+        },
+    ),
+    (
+        (2, 0, 224),
+        Grib2Parameter {
+            parameter: "Water Vapor Zonal Flux (Vertical Int)",
+            units: "kg m-2",
+            abbrev: "WVUFLX",
+        },
+    ),
+    (
+        (2, 0, 225),
+        Grib2Parameter {
+            parameter: "Water Vapor Meridional Flux (Vertical Int)",
+            units: "kg m-2",
+            abbrev: "WVVFLX",
+        },
+    ),
+    (
+        (2, 0, 226),
+        Grib2Parameter {
+            parameter: "Water Condensate Zonal Flux (Vertical Int)",
+            units: "kg m-2",
+            abbrev: "WCUFLX",
+        },
+    ),
+    (
+        (2, 0, 227),
+        Grib2Parameter {
+            parameter: "Water Condensate Meridional Flux (Vertical Int)",
+            units: "kg m-2",
+            abbrev: "WCVFLX",
+        },
+    ),
+    (
+        (2, 0, 228),
+        Grib2Parameter { parameter: "Aerodynamic Conductance", units: "m s-1", abbrev: "ACOND" },
+    ),
+    (
+        (2, 0, 229),
+        Grib2Parameter { parameter: "Canopy Water Evaporation", units: "W m-2", abbrev: "EVCW" },
+    ),
+    ((2, 0, 230), Grib2Parameter { parameter: "Transpiration", units: "W m-2", abbrev: "TRANS" }),
+    (
+        (2, 0, 231),
+        Grib2Parameter {
+            parameter: "Seasonally Minimum Green Vegetation Fraction (over 1-year period)",
+            units: "%",
+            abbrev: "VEGMIN",
+        },
+    ),
+    (
+        (2, 0, 232),
+        Grib2Parameter {
+            parameter: "Seasonally Maximum Green Vegetation Fraction (over 1-year period)",
+            units: "%",
+            abbrev: "VEGMAX",
+        },
+    ),
+    (
+        (2, 0, 233),
+        Grib2Parameter { parameter: "Land Fraction", units: "Fraction", abbrev: "LANDFRC" },
+    ),
+    (
+        (2, 0, 234),
+        Grib2Parameter { parameter: "Lake Fraction", units: "Fraction", abbrev: "LAKEFRC" },
+    ),
+    (
+        (2, 0, 235),
+        Grib2Parameter {
+            parameter: "Precipitation Advected Heat Flux",
+            units: "W m-2",
+            abbrev: "PAHFLX",
+        },
+    ),
+    (
+        (2, 0, 236),
+        Grib2Parameter {
+            parameter: "Water Storage in Aquifer",
+            units: "kg m-2",
+            abbrev: "WATERSA",
+        },
+    ),
+    (
+        (2, 0, 237),
+        Grib2Parameter {
+            parameter: "Evaporation of Intercepted Water",
+            units: "kg m-2",
+            abbrev: "EIWATER",
+        },
+    ),
+    (
+        (2, 0, 238),
+        Grib2Parameter { parameter: "Plant Transpiration", units: "kg m-2", abbrev: "PLANTTR" },
+    ),
+    (
+        (2, 0, 239),
+        Grib2Parameter { parameter: "Soil Surface Evaporation", units: "kg m-2", abbrev: "SOILSE" },
+    ),
+    ((2, 0, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (2, 1, 192),
+        Grib2Parameter {
+            parameter: "Cold Advisory for Newborn Livestock",
+            units: "",
+            abbrev: "CANL",
+        },
+    ),
+    ((2, 1, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (2, 3, 0),
+        Grib2Parameter { parameter: "Soil Type", units: "See Table 4.213", abbrev: "SOTYP" },
+    ),
+    (
+        (2, 3, 1),
+        Grib2Parameter {
+            parameter: "Upper Layer Soil Temperature (Deprecated)",
+            units: "K",
+            abbrev: "UPLST",
+        },
+    ),
+    (
+        (2, 3, 2),
+        Grib2Parameter {
+            parameter: "Upper Layer Soil Moisture (Deprecated)",
+            units: "kg m-3",
+            abbrev: "UPLSM",
+        },
+    ),
+    (
+        (2, 3, 3),
+        Grib2Parameter {
+            parameter: "Lower Layer Soil Moisture (Deprecated)",
+            units: "kg m-3",
+            abbrev: "LOWLSM",
+        },
+    ),
+    (
+        (2, 3, 4),
+        Grib2Parameter {
+            parameter: "Bottom Layer Soil Temperature (Deprecated)",
+            units: "K",
+            abbrev: "BOTLST",
+        },
+    ),
+    (
+        (2, 3, 5),
+        Grib2Parameter {
+            parameter: "Liquid Volumetric Soil Moisture (non-frozen)",
+            units: "Proportion",
+            abbrev: "SOILL",
+        },
+    ),
+    (
+        (2, 3, 6),
+        Grib2Parameter {
+            parameter: "Number of Soil Layers in Root Zone",
+            units: "Numeric",
+            abbrev: "RLYRS",
+        },
+    ),
+    (
+        (2, 3, 7),
+        Grib2Parameter {
+            parameter: "Transpiration Stress-onset (soil moisture)",
+            units: "Proportion",
+            abbrev: "SMREF",
+        },
+    ),
+    (
+        (2, 3, 8),
+        Grib2Parameter {
+            parameter: "Direct Evaporation Cease (soil moisture)",
+            units: "Proportion",
+            abbrev: "SMDRY",
+        },
+    ),
+    (
+        (2, 3, 9),
+        Grib2Parameter { parameter: "Soil Porosity", units: "Proportion", abbrev: "POROS" },
+    ),
+    (
+        (2, 3, 10),
+        Grib2Parameter {
+            parameter: "Liquid Volumetric Soil Moisture (Non-Frozen)",
+            units: "m3 m-3",
+            abbrev: "LIQVSM",
+        },
+    ),
+    (
+        (2, 3, 11),
+        Grib2Parameter {
+            parameter: "Volumetric Transpiration Stress-Onset (Soil Moisture)",
+            units: "m3 m-3",
+            abbrev: "VOLTSO",
+        },
+    ),
+    (
+        (2, 3, 12),
+        Grib2Parameter {
+            parameter: "Transpiration Stress-Onset (Soil Moisture)",
+            units: "kg m-3",
+            abbrev: "TRANSO",
+        },
+    ),
+    (
+        (2, 3, 13),
+        Grib2Parameter {
+            parameter: "Volumetric Direct Evaporation Cease (Soil Moisture)",
+            units: "m3 m-3",
+            abbrev: "VOLDEC",
+        },
+    ),
+    (
+        (2, 3, 14),
+        Grib2Parameter {
+            parameter: "Direct Evaporation Cease (Soil Moisture)",
+            units: "kg m-3",
+            abbrev: "DIREC",
+        },
+    ),
+    ((2, 3, 15), Grib2Parameter { parameter: "Soil Porosity", units: "m3 m-3", abbrev: "SOILP" }),
+    (
+        (2, 3, 16),
+        Grib2Parameter {
+            parameter: "Volumetric Saturation Of Soil Moisture",
+            units: "m3 m-3",
+            abbrev: "VSOSM",
+        },
+    ),
+    (
+        (2, 3, 17),
+        Grib2Parameter {
+            parameter: "Saturation Of Soil Moisture",
+            units: "kg m-3",
+            abbrev: "SATOSM",
+        },
+    ),
+    ((2, 3, 18), Grib2Parameter { parameter: "Soil Temperature", units: "K", abbrev: "SOILTMP" }),
+    ((2, 3, 19), Grib2Parameter { parameter: "Soil Moisture", units: "kg m-3", abbrev: "SOILMOI" }),
+    (
+        (2, 3, 20),
+        Grib2Parameter {
+            parameter: "Column-Integrated Soil Moisture",
+            units: "kg m-2",
+            abbrev: "CISOILM",
+        },
+    ),
+    ((2, 3, 21), Grib2Parameter { parameter: "Soil Ice", units: "kg m-3", abbrev: "SOILICE" }),
+    (
+        (2, 3, 22),
+        Grib2Parameter {
+            parameter: "Column-Integrated Soil Ice",
+            units: "kg m-2",
+            abbrev: "CISICE",
+        },
+    ),
+    (
+        (2, 3, 23),
+        Grib2Parameter {
+            parameter: "Liquid Water in Snow Pack",
+            units: "kg m-2",
+            abbrev: "LWSNWP",
+        },
+    ),
+    ((2, 3, 24), Grib2Parameter { parameter: "Frost Index", units: "kg day-1", abbrev: "FRSTINX" }),
+    (
+        (2, 3, 25),
+        Grib2Parameter {
+            parameter: "Snow Depth at Elevation Bands",
+            units: "kg m-2",
+            abbrev: "SNWDEB",
+        },
+    ),
+    ((2, 3, 26), Grib2Parameter { parameter: "Soil Heat Flux", units: "W m-2", abbrev: "SHFLX" }),
+    ((2, 3, 27), Grib2Parameter { parameter: "Soil Depth", units: "m", abbrev: "SOILDEP" }),
+    ((2, 3, 28), Grib2Parameter { parameter: "Snow Temperature", units: "K", abbrev: "SNOWTMP" }),
+    ((2, 3, 29), Grib2Parameter { parameter: "Ice Temperature", units: "K", abbrev: "ICETEMP" }),
+    (
+        (2, 3, 30),
+        Grib2Parameter { parameter: "Soil Wetness Index", units: "Numeric", abbrev: "SWET" },
+    ),
+    (
+        (2, 3, 192),
+        Grib2Parameter {
+            parameter: "Liquid Volumetric Soil Moisture (non Frozen)",
+            units: "Proportion",
+            abbrev: "SOILL",
+        },
+    ),
+    (
+        (2, 3, 193),
+        Grib2Parameter {
+            parameter: "Number of Soil Layers in Root Zone",
+            units: "non-dim",
+            abbrev: "RLYRS",
+        },
+    ),
+    (
+        (2, 3, 194),
+        Grib2Parameter { parameter: "Surface Slope Type", units: "Index", abbrev: "SLTYP" },
+    ),
+    (
+        (2, 3, 195),
+        Grib2Parameter {
+            parameter: "Transpiration Stress-onset (soil moisture)",
+            units: "Proportion",
+            abbrev: "SMREF",
+        },
+    ),
+    (
+        (2, 3, 196),
+        Grib2Parameter {
+            parameter: "Direct Evaporation Cease (soil moisture)",
+            units: "Proportion",
+            abbrev: "SMDRY",
+        },
+    ),
+    (
+        (2, 3, 197),
+        Grib2Parameter { parameter: "Soil Porosity", units: "Proportion", abbrev: "POROS" },
+    ),
+    (
+        (2, 3, 198),
+        Grib2Parameter {
+            parameter: "Direct Evaporation from Bare Soil",
+            units: "W m-2",
+            abbrev: "EVBS",
+        },
+    ),
+    (
+        (2, 3, 199),
+        Grib2Parameter {
+            parameter: "Land Surface Precipitation Accumulation",
+            units: "kg m-2",
+            abbrev: "LSPA",
+        },
+    ),
+    (
+        (2, 3, 200),
+        Grib2Parameter {
+            parameter: "Bare Soil Surface Skin Temperature",
+            units: "K",
+            abbrev: "BARET",
+        },
+    ),
+    (
+        (2, 3, 201),
+        Grib2Parameter {
+            parameter: "Average Surface Skin Temperature",
+            units: "K",
+            abbrev: "AVSFT",
+        },
+    ),
+    (
+        (2, 3, 202),
+        Grib2Parameter {
+            parameter: "Effective Radiative Skin Temperature",
+            units: "K",
+            abbrev: "RADT",
+        },
+    ),
+    (
+        (2, 3, 203),
+        Grib2Parameter { parameter: "Field Capacity", units: "Fraction", abbrev: "FLDCP" },
+    ),
+    (
+        (2, 3, 204),
+        Grib2Parameter {
+            parameter: "Soil Moisture Availability In The Top Soil Layer",
+            units: "%",
+            abbrev: "MSTAV",
+        },
+    ),
+    ((2, 3, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (2, 4, 0),
+        Grib2Parameter { parameter: "Fire Outlook", units: "See Table 4.224", abbrev: "FIREOLK" },
+    ),
+    (
+        (2, 4, 1),
+        Grib2Parameter {
+            parameter: "Fire Outlook Due to Dry Thunderstorm",
+            units: "See Table 4.224",
+            abbrev: "FIREODT",
+        },
+    ),
+    ((2, 4, 2), Grib2Parameter { parameter: "Haines Index", units: "Numeric", abbrev: "HINDEX" }),
+    ((2, 4, 3), Grib2Parameter { parameter: "Fire Burned Area", units: "%", abbrev: "FBAREA" }),
+    ((2, 4, 4), Grib2Parameter { parameter: "Fosberg Index", units: "Numeric", abbrev: "FOSINDX" }),
+    (
+        (2, 4, 5),
+        Grib2Parameter {
+            parameter: "Fire Weather Index (Canadian Forest Service)",
+            units: "Numeric",
+            abbrev: "FWINX",
+        },
+    ),
+    (
+        (2, 4, 6),
+        Grib2Parameter {
+            parameter: "Fine Fuel Moisture Code (Canadian Forest Service)",
+            units: "Numeric",
+            abbrev: "FFMCODE",
+        },
+    ),
+    (
+        (2, 4, 7),
+        Grib2Parameter {
+            parameter: "Duff Moisture Code (Canadian Forest Service)",
+            units: "Numeric",
+            abbrev: "DUFMCODE",
+        },
+    ),
+    (
+        (2, 4, 8),
+        Grib2Parameter {
+            parameter: "Drought Code (Canadian Forest Service)",
+            units: "Numeric",
+            abbrev: "DRTCODE",
+        },
+    ),
+    (
+        (2, 4, 9),
+        Grib2Parameter {
+            parameter: "Initial Fire Spread Index (Canadian Forest Service)",
+            units: "Numeric",
+            abbrev: "INFSINX",
+        },
+    ),
+    (
+        (2, 4, 10),
+        Grib2Parameter {
+            parameter: "Fire Build Up Index (Canadian Forest Service)",
+            units: "Numeric",
+            abbrev: "FBUPINX",
+        },
+    ),
+    (
+        (2, 4, 11),
+        Grib2Parameter {
+            parameter: "Fire Daily Severity Rating (Canadian Forest Service)",
+            units: "Numeric",
+            abbrev: "FDSRTE",
+        },
+    ),
+    (
+        (2, 4, 12),
+        Grib2Parameter {
+            parameter: "Keetch-Byram Drought Index",
+            units: "Numeric",
+            abbrev: "KRIDX",
+        },
+    ),
+    (
+        (2, 4, 13),
+        Grib2Parameter {
+            parameter: "Drought Factor (Australian forest service)",
+            units: "Numeric",
+            abbrev: "DRFACT",
+        },
+    ),
+    (
+        (2, 4, 14),
+        Grib2Parameter {
+            parameter: "Rate of Spread (Australian forest service)",
+            units: "m s-1",
+            abbrev: "RATESPRD",
+        },
+    ),
+    (
+        (2, 4, 15),
+        Grib2Parameter {
+            parameter: "Fire Danger Index (Australian forest service)",
+            units: "Numeric",
+            abbrev: "FIREDIDX",
+        },
+    ),
+    (
+        (2, 4, 16),
+        Grib2Parameter {
+            parameter: "Spread Component (US Forest Service NFDRS)",
+            units: "Numeric",
+            abbrev: "SPRDCOMP",
+        },
+    ),
+    (
+        (2, 4, 17),
+        Grib2Parameter {
+            parameter: "Burning Index (Australian forest service)",
+            units: "Numeric",
+            abbrev: "BURNIDX",
+        },
+    ),
+    (
+        (2, 4, 18),
+        Grib2Parameter {
+            parameter: "Ignition Component (Australian forest service)",
+            units: "%",
+            abbrev: "IGNCOMP",
+        },
+    ),
+    (
+        (2, 4, 19),
+        Grib2Parameter {
+            parameter: "Energy Release Component (Australian forest service)",
+            units: "J m-2",
+            abbrev: "ENRELCOM",
+        },
+    ),
+    ((2, 4, 20), Grib2Parameter { parameter: "Burning Area", units: "%", abbrev: "BURNAREA" }),
+    ((2, 4, 21), Grib2Parameter { parameter: "Burnable Area", units: "%", abbrev: "BURNABAREA" }),
+    ((2, 4, 22), Grib2Parameter { parameter: "Unburnable Area", units: "%", abbrev: "UNBURNAREA" }),
+    ((2, 4, 23), Grib2Parameter { parameter: "Fuel Load", units: "kg m-2", abbrev: "FUELLOAD" }),
+    (
+        (2, 4, 24),
+        Grib2Parameter { parameter: "Combustion Completeness", units: "%", abbrev: "COMBCO" },
+    ),
+    (
+        (2, 4, 25),
+        Grib2Parameter { parameter: "Fuel Moisture Content", units: "kg kg-1", abbrev: "FUELMC" },
+    ),
+    (
+        (2, 4, 26),
+        Grib2Parameter {
+            parameter: "Wildfire Potential (NOAA GSL)",
+            units: "Numeric",
+            abbrev: "WFIREPOT",
+        },
+    ),
+    (
+        (2, 4, 27),
+        Grib2Parameter { parameter: "Live Leaf Fuel Load", units: "kg m-2", abbrev: "LLFL" },
+    ),
+    (
+        (2, 4, 28),
+        Grib2Parameter { parameter: "Live Wood Fuel Load", units: "kg m-2", abbrev: "LWFL" },
+    ),
+    (
+        (2, 4, 29),
+        Grib2Parameter { parameter: "Dead Leaf Fuel Load", units: "kg m-2", abbrev: "DLFL" },
+    ),
+    (
+        (2, 4, 30),
+        Grib2Parameter { parameter: "Dead Wood Fuel Load", units: "kg m-2", abbrev: "DWFL" },
+    ),
+    (
+        (2, 4, 31),
+        Grib2Parameter {
+            parameter: "Live Fuel Moisture Content",
+            units: "kg kg-1",
+            abbrev: "LFMC",
+        },
+    ),
+    (
+        (2, 4, 32),
+        Grib2Parameter {
+            parameter: "Fine Dead Leaf Moisture Content",
+            units: "kg kg-1",
+            abbrev: "FDLMC",
+        },
+    ),
+    (
+        (2, 4, 33),
+        Grib2Parameter {
+            parameter: "Dense Dead Leaf Moisture Content",
+            units: "kg kg-1",
+            abbrev: "DDLMC",
+        },
+    ),
+    (
+        (2, 4, 34),
+        Grib2Parameter {
+            parameter: "Fine Dead Wood Moisture Content",
+            units: "kg kg-1",
+            abbrev: "FDWMC",
+        },
+    ),
+    (
+        (2, 4, 35),
+        Grib2Parameter {
+            parameter: "Dense Dead Wood Moisture Content",
+            units: "kg kg-1",
+            abbrev: "DDWMC",
+        },
+    ),
+    (
+        (2, 4, 36),
+        Grib2Parameter { parameter: "Fire Radiative Power", units: "W", abbrev: "FRADPOW" },
+    ),
+    ((2, 4, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (2, 5, 0),
+        Grib2Parameter { parameter: "Glacier Cover", units: "Proportion", abbrev: "GLACCOV" },
+    ),
+    ((2, 5, 1), Grib2Parameter { parameter: "Glacier Temperature", units: "K", abbrev: "GLACTMP" }),
+    ((2, 5, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (2, 6, 0),
+        Grib2Parameter { parameter: "Urban Cover", units: "Proportion", abbrev: "URBCOVER" },
+    ),
+    (
+        (2, 6, 1),
+        Grib2Parameter { parameter: "Road Cover", units: "Proportion", abbrev: "ROADCOVER" },
+    ),
+    (
+        (2, 6, 2),
+        Grib2Parameter { parameter: "Building Cover", units: "Proportion", abbrev: "BUILDCOVER" },
+    ),
+    ((2, 6, 3), Grib2Parameter { parameter: "Building Height", units: "m", abbrev: "BUILDHGT" }),
+    (
+        (2, 6, 4),
+        Grib2Parameter {
+            parameter: "Vertical-to-Horizontal Area Fraction",
+            units: "m2 m-2",
+            abbrev: "VZAFRAC",
+        },
+    ),
+    (
+        (2, 6, 5),
+        Grib2Parameter {
+            parameter: "Standard Deviation of Building Height",
+            units: "m",
+            abbrev: "SDBUILDHGT",
+        },
+    ),
+    (
+        (2, 6, 6),
+        Grib2Parameter {
+            parameter: "Distance downward from roof surface",
+            units: "m",
+            abbrev: "DDROOF",
+        },
+    ),
+    (
+        (2, 6, 7),
+        Grib2Parameter {
+            parameter: "Distance inward from outer wall surface",
+            units: "m",
+            abbrev: "DIOWALL",
+        },
+    ),
+    (
+        (2, 6, 8),
+        Grib2Parameter {
+            parameter: "Distance downward from road surface",
+            units: "m",
+            abbrev: "DDROAD",
+        },
+    ),
+    ((2, 6, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    ((3, 0, 0), Grib2Parameter { parameter: "Scaled Radiance", units: "Numeric", abbrev: "SRAD" }),
+    ((3, 0, 1), Grib2Parameter { parameter: "Scaled Albedo", units: "Numeric", abbrev: "SALBEDO" }),
+    (
+        (3, 0, 2),
+        Grib2Parameter {
+            parameter: "Scaled Brightness Temperature",
+            units: "Numeric",
+            abbrev: "SBTMP",
+        },
+    ),
+    (
+        (3, 0, 3),
+        Grib2Parameter {
+            parameter: "Scaled Precipitable Water",
+            units: "Numeric",
+            abbrev: "SPWAT",
+        },
+    ),
+    (
+        (3, 0, 4),
+        Grib2Parameter { parameter: "Scaled Lifted Index", units: "Numeric", abbrev: "SLFTI" },
+    ),
+    (
+        (3, 0, 5),
+        Grib2Parameter {
+            parameter: "Scaled Cloud Top Pressure",
+            units: "Numeric",
+            abbrev: "SCTPRES",
+        },
+    ),
+    (
+        (3, 0, 6),
+        Grib2Parameter { parameter: "Scaled Skin Temperature", units: "Numeric", abbrev: "SSTMP" },
+    ),
+    (
+        (3, 0, 7),
+        Grib2Parameter { parameter: "Cloud Mask", units: "See Table 4.217", abbrev: "CLOUDM" },
+    ),
+    (
+        (3, 0, 8),
+        Grib2Parameter { parameter: "Pixel Scene Type", units: "See Table 4.218", abbrev: "PIXST" },
+    ),
+    (
+        (3, 0, 9),
+        Grib2Parameter {
+            parameter: "Fire Detection Indicator",
+            units: "See Table 4.223",
+            abbrev: "FIREDI",
+        },
+    ),
+    ((3, 0, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (3, 1, 0),
+        Grib2Parameter { parameter: "Estimated Precipitation", units: "kg m-2", abbrev: "ESTP" },
+    ),
+    (
+        (3, 1, 1),
+        Grib2Parameter {
+            parameter: "Instantaneous Rain Rate",
+            units: "kg m-2 s-1",
+            abbrev: "IRRATE",
+        },
+    ),
+    ((3, 1, 2), Grib2Parameter { parameter: "Cloud Top Height", units: "m", abbrev: "CTOPH" }),
+    (
+        (3, 1, 3),
+        Grib2Parameter {
+            parameter: "Cloud Top Height Quality Indicator",
+            units: "Code table 4.219",
+            abbrev: "CTOPHQI",
+        },
+    ),
+    (
+        (3, 1, 4),
+        Grib2Parameter {
+            parameter: "Estimated u-Component of Wind",
+            units: "m s-1",
+            abbrev: "ESTUGRD",
+        },
+    ),
+    (
+        (3, 1, 5),
+        Grib2Parameter {
+            parameter: "Estimated v-Component of Wind",
+            units: "m s-1",
+            abbrev: "ESTVGRD",
+        },
+    ),
+    (
+        (3, 1, 6),
+        Grib2Parameter { parameter: "Number Of Pixels Used", units: "Numeric", abbrev: "NPIXU" },
+    ),
+    ((3, 1, 7), Grib2Parameter { parameter: "Solar Zenith Angle", units: "°", abbrev: "SOLZA" }),
+    ((3, 1, 8), Grib2Parameter { parameter: "Relative Azimuth Angle", units: "°", abbrev: "RAZA" }),
+    (
+        (3, 1, 9),
+        Grib2Parameter {
+            parameter: "Reflectance in 0.6 Micron Channel",
+            units: "%",
+            abbrev: "RFL06",
+        },
+    ),
+    (
+        (3, 1, 10),
+        Grib2Parameter {
+            parameter: "Reflectance in 0.8 Micron Channel",
+            units: "%",
+            abbrev: "RFL08",
+        },
+    ),
+    (
+        (3, 1, 11),
+        Grib2Parameter {
+            parameter: "Reflectance in 1.6 Micron Channel",
+            units: "%",
+            abbrev: "RFL16",
+        },
+    ),
+    (
+        (3, 1, 12),
+        Grib2Parameter {
+            parameter: "Reflectance in 3.9 Micron Channel",
+            units: "%",
+            abbrev: "RFL39",
+        },
+    ),
+    (
+        (3, 1, 13),
+        Grib2Parameter { parameter: "Atmospheric Divergence", units: "s-1", abbrev: "ATMDIV" },
+    ),
+    (
+        (3, 1, 14),
+        Grib2Parameter { parameter: "Cloudy Brightness Temperature", units: "K", abbrev: "CBTMP" },
+    ),
+    (
+        (3, 1, 15),
+        Grib2Parameter {
+            parameter: "Clear Sky Brightness Temperature",
+            units: "K",
+            abbrev: "CSBTMP",
+        },
+    ),
+    (
+        (3, 1, 16),
+        Grib2Parameter {
+            parameter: "Cloudy Radiance (with respect to wave number)",
+            units: "W m-1 sr-1",
+            abbrev: "CLDRAD",
+        },
+    ),
+    (
+        (3, 1, 17),
+        Grib2Parameter {
+            parameter: "Clear Sky Radiance (with respect to wave number)",
+            units: "W m-1 sr-1",
+            abbrev: "CSKYRAD",
+        },
+    ),
+    ((3, 1, 19), Grib2Parameter { parameter: "Wind Speed", units: "m s-1", abbrev: "WINDS" }),
+    (
+        (3, 1, 20),
+        Grib2Parameter {
+            parameter: "Aerosol Optical Thickness at 0.635 µm",
+            units: "",
+            abbrev: "AOT06",
+        },
+    ),
+    (
+        (3, 1, 21),
+        Grib2Parameter {
+            parameter: "Aerosol Optical Thickness at 0.810 µm",
+            units: "",
+            abbrev: "AOT08",
+        },
+    ),
+    (
+        (3, 1, 22),
+        Grib2Parameter {
+            parameter: "Aerosol Optical Thickness at 1.640 µm",
+            units: "",
+            abbrev: "AOT16",
+        },
+    ),
+    ((3, 1, 23), Grib2Parameter { parameter: "Angstrom Coefficient", units: "", abbrev: "ANGCOE" }),
+    (
+        (3, 1, 27),
+        Grib2Parameter {
+            parameter: "Bidirectional Reflectance Factor",
+            units: "Numeric",
+            abbrev: "BRFLF",
+        },
+    ),
+    (
+        (3, 1, 28),
+        Grib2Parameter { parameter: "Brightness Temperature", units: "K", abbrev: "SPBRT" },
+    ),
+    (
+        (3, 1, 29),
+        Grib2Parameter { parameter: "Scaled Radiance", units: "Numeric", abbrev: "SCRAD" },
+    ),
+    (
+        (3, 1, 30),
+        Grib2Parameter {
+            parameter: "Reflectance in 0.4 Micron Channel",
+            units: "%",
+            abbrev: "RFL04",
+        },
+    ),
+    ((3, 1, 31), Grib2Parameter { parameter: "Cloudy Reflectance", units: "%", abbrev: "CLDREF" }),
+    ((3, 1, 32), Grib2Parameter { parameter: "Clear Reflectance", units: "%", abbrev: "CLRREF" }),
+    (
+        (3, 1, 98),
+        Grib2Parameter {
+            parameter: "Correlation Coefficient Between MPE Rain Rates for Co-located IR Data and \
+                        Microwave Data Rain Rates",
+            units: "Numeric",
+            abbrev: "CCMPEMRR",
+        },
+    ),
+    (
+        (3, 1, 99),
+        Grib2Parameter {
+            parameter: "Standard Deviation Between MPE Rain Rates for Co-located IR Data and \
+                        Microwave Data Rain Rates",
+            units: "Numeric",
+            abbrev: "SDMPEMRR",
+        },
+    ),
+    (
+        (3, 1, 192),
+        Grib2Parameter {
+            parameter: "Scatterometer Estimated U Wind Component",
+            units: "m s-1",
+            abbrev: "USCT",
+        },
+    ),
+    (
+        (3, 1, 193),
+        Grib2Parameter {
+            parameter: "Scatterometer Estimated V Wind Component",
+            units: "m s-1",
+            abbrev: "VSCT",
+        },
+    ),
+    (
+        (3, 1, 194),
+        Grib2Parameter { parameter: "Scatterometer Wind Quality", units: "", abbrev: "SWQI" },
+    ),
+    ((3, 1, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (3, 2, 0),
+        Grib2Parameter { parameter: "Clear Sky Probability", units: "%", abbrev: "CSKPROB" },
+    ),
+    (
+        (3, 2, 1),
+        Grib2Parameter { parameter: "Cloud Top Temperature", units: "K", abbrev: "CTOPTMP" },
+    ),
+    ((3, 2, 2), Grib2Parameter { parameter: "Cloud Top Pressure", units: "Pa", abbrev: "CTOPRES" }),
+    (
+        (3, 2, 3),
+        Grib2Parameter { parameter: "Cloud Type", units: "See Table 4.218", abbrev: "CLDTYPE" },
+    ),
+    (
+        (3, 2, 4),
+        Grib2Parameter { parameter: "Cloud Phase", units: "See Table 4.218", abbrev: "CLDPHAS" },
+    ),
+    (
+        (3, 2, 5),
+        Grib2Parameter { parameter: "Cloud Optical Depth", units: "Numeric", abbrev: "CLDODEP" },
+    ),
+    (
+        (3, 2, 6),
+        Grib2Parameter {
+            parameter: "Cloud Particle Effective Radius",
+            units: "m",
+            abbrev: "CLDPER",
+        },
+    ),
+    (
+        (3, 2, 7),
+        Grib2Parameter { parameter: "Cloud Liquid Water Path", units: "kg m-2", abbrev: "CLDLWP" },
+    ),
+    (
+        (3, 2, 8),
+        Grib2Parameter { parameter: "Cloud Ice Water Path", units: "kg m-2", abbrev: "CLDIWP" },
+    ),
+    ((3, 2, 9), Grib2Parameter { parameter: "Cloud Albedo", units: "Numeric", abbrev: "CLDALB" }),
+    (
+        (3, 2, 10),
+        Grib2Parameter { parameter: "Cloud Emissivity", units: "Numeric", abbrev: "CLDEMISS" },
+    ),
+    (
+        (3, 2, 11),
+        Grib2Parameter {
+            parameter: "Effective Absorption Optical Depth Ratio",
+            units: "Numeric",
+            abbrev: "EAODR",
+        },
+    ),
+    (
+        (3, 2, 30),
+        Grib2Parameter { parameter: "Measurement Cost", units: "Numeric", abbrev: "MEACST" },
+    ),
+    (
+        (3, 2, 31),
+        Grib2Parameter {
+            parameter: "Upper Layer Cloud Optical Depth (Deprecated)",
+            units: "Numeric",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 32),
+        Grib2Parameter {
+            parameter: "Upper Layer Cloud Top Pressure (Deprecated)",
+            units: "Pa",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 33),
+        Grib2Parameter {
+            parameter: "Upper Layer Cloud Effective Radius (Deprecated)",
+            units: "m",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 34),
+        Grib2Parameter {
+            parameter: "Error in Upper Layer Cloud Optical Depth (Deprecated)",
+            units: "Numeric",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 35),
+        Grib2Parameter {
+            parameter: "Error in Upper Layer Cloud Top Pressure (Deprecated)",
+            units: "Pa",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 36),
+        Grib2Parameter {
+            parameter: "Error in Upper Layer Cloud Effective Radius (Deprecated)",
+            units: "m",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 37),
+        Grib2Parameter {
+            parameter: "Lower Layer Cloud Optical Depth (Deprecated)",
+            units: "Numeric",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 38),
+        Grib2Parameter {
+            parameter: "Lower Layer Cloud Top Pressure (Deprecated)",
+            units: "Pa",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 39),
+        Grib2Parameter {
+            parameter: "Error in Lower Layer Cloud Optical Depth (Deprecated)",
+            units: "Numeric",
+            abbrev: "",
+        },
+    ),
+    (
+        (3, 2, 40),
+        Grib2Parameter {
+            parameter: "Error in Lower Layer Cloud Top Pressure (Deprecated)",
+            units: "Pa",
+            abbrev: "",
+        },
+    ),
+    ((3, 2, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (3, 3, 0),
+        Grib2Parameter {
+            parameter: "Probability of Encountering Marginal Visual Flight Rules Conditions",
+            units: "%",
+            abbrev: "PBMVFRC",
+        },
+    ),
+    (
+        (3, 3, 1),
+        Grib2Parameter {
+            parameter: "Probability of Encountering Low Instrument Flight Rules Conditions",
+            units: "%",
+            abbrev: "PBLIFRC",
+        },
+    ),
+    (
+        (3, 3, 2),
+        Grib2Parameter {
+            parameter: "Probability of Encountering Instrument Flight Rules Conditions",
+            units: "%",
+            abbrev: "PBINFRC",
+        },
+    ),
+    ((3, 3, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (3, 4, 0),
+        Grib2Parameter { parameter: "Volcanic Ash Probability", units: "%", abbrev: "VOLAPROB" },
+    ),
+    (
+        (3, 4, 1),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Cloud Top Temperature",
+            units: "K",
+            abbrev: "VOLACDTT",
+        },
+    ),
+    (
+        (3, 4, 2),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Cloud Top Pressure",
+            units: "Pa",
+            abbrev: "VOLACDTP",
+        },
+    ),
+    (
+        (3, 4, 3),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Cloud Top Height",
+            units: "m",
+            abbrev: "VOLACDTH",
+        },
+    ),
+    (
+        (3, 4, 4),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Cloud Emissivity",
+            units: "Numeric",
+            abbrev: "VOLACDEM",
+        },
+    ),
+    (
+        (3, 4, 5),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Effective Absorption Depth Ratio",
+            units: "Numeric",
+            abbrev: "VOLAEADR",
+        },
+    ),
+    (
+        (3, 4, 6),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Cloud Optical Depth",
+            units: "Numeric",
+            abbrev: "VOLACDOD",
+        },
+    ),
+    (
+        (3, 4, 7),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Column Density",
+            units: "kg m-2",
+            abbrev: "VOLACDEN",
+        },
+    ),
+    (
+        (3, 4, 8),
+        Grib2Parameter {
+            parameter: "Volcanic Ash Particle Effective Radius",
+            units: "m",
+            abbrev: "VOLAPER",
+        },
+    ),
+    ((3, 4, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (3, 5, 0),
+        Grib2Parameter {
+            parameter: "Interface Sea-Surface Temperature",
+            units: "K",
+            abbrev: "ISSTMP",
+        },
+    ),
+    (
+        (3, 5, 1),
+        Grib2Parameter { parameter: "Skin Sea-Surface Temperature", units: "K", abbrev: "SKSSTMP" },
+    ),
+    (
+        (3, 5, 2),
+        Grib2Parameter {
+            parameter: "Sub-Skin Sea-Surface Temperature",
+            units: "K",
+            abbrev: "SSKSSTMP",
+        },
+    ),
+    (
+        (3, 5, 3),
+        Grib2Parameter {
+            parameter: "Foundation Sea-Surface Temperature",
+            units: "K",
+            abbrev: "FDNSSTMP",
+        },
+    ),
+    (
+        (3, 5, 4),
+        Grib2Parameter {
+            parameter: "Estimated Bias Between Sea-Surface Temperature and Standard",
+            units: "K",
+            abbrev: "EBSSTSTD",
+        },
+    ),
+    (
+        (3, 5, 5),
+        Grib2Parameter {
+            parameter: "Estimated Bias Standard Deviation Between Sea-Surface Temperature and \
+                        Standard",
+            units: "K",
+            abbrev: "EBSDSSTS",
+        },
+    ),
+    ((3, 5, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (3, 6, 0),
+        Grib2Parameter { parameter: "Global Solar Irradiance", units: "W m-2", abbrev: "GSOLIRR" },
+    ),
+    (
+        (3, 6, 1),
+        Grib2Parameter { parameter: "Global Solar Exposure", units: "J m-2", abbrev: "GSOLEXP" },
+    ),
+    (
+        (3, 6, 2),
+        Grib2Parameter { parameter: "Direct Solar Irradiance", units: "W m-2", abbrev: "DIRSOLIR" },
+    ),
+    (
+        (3, 6, 3),
+        Grib2Parameter { parameter: "Direct Solar Exposure", units: "J m-2", abbrev: "DIRSOLEX" },
+    ),
+    (
+        (3, 6, 4),
+        Grib2Parameter {
+            parameter: "Diffuse Solar Irradiance",
+            units: "W m-2",
+            abbrev: "DIFSOLIR",
+        },
+    ),
+    (
+        (3, 6, 5),
+        Grib2Parameter { parameter: "Diffuse Solar Exposure", units: "J m-2", abbrev: "DIFSOLEX" },
+    ),
+    ((3, 6, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (3, 192, 1),
+        Grib2Parameter {
+            parameter: "Covariance between zonal and meridional components of the wind",
+            units: "m2/s2",
+            abbrev: "COVMZ",
+        },
+    ),
+    (
+        (3, 192, 2),
+        Grib2Parameter {
+            parameter: "Covariance between zonal component of the wind and temperature",
+            units: "K*m/s",
+            abbrev: "COVTZ",
+        },
+    ),
+    (
+        (3, 192, 3),
+        Grib2Parameter {
+            parameter: "Covariance between meridional component of the wind and temperature",
+            units: "K*m/s",
+            abbrev: "COVTM",
+        },
+    ),
+    (
+        (3, 192, 4),
+        Grib2Parameter {
+            parameter: "Covariance between temperature and vertical component of the wind",
+            units: "K*m/s",
+            abbrev: "COVTW",
+        },
+    ),
+    (
+        (3, 192, 5),
+        Grib2Parameter {
+            parameter: "Covariance between zonal and zonal components of the wind",
+            units: "m2/s2",
+            abbrev: "COVZZ",
+        },
+    ),
+    (
+        (3, 192, 6),
+        Grib2Parameter {
+            parameter: "Covariance between meridional and meridional components of the wind",
+            units: "m2/s2",
+            abbrev: "COVMM",
+        },
+    ),
+    (
+        (3, 192, 7),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and zonal components of the wind",
+            units: "kg/kg*m/s",
+            abbrev: "COVQZ",
+        },
+    ),
+    (
+        (3, 192, 8),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and meridional components of the wind",
+            units: "kg/kg*m/s",
+            abbrev: "COVQM",
+        },
+    ),
+    (
+        (3, 192, 9),
+        Grib2Parameter {
+            parameter: "Covariance between temperature and vertical components of the wind",
+            units: "K*Pa/s",
+            abbrev: "COVTVV",
+        },
+    ),
+    (
+        (3, 192, 10),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and vertical components of the wind",
+            units: "kg/kg*Pa/s",
+            abbrev: "COVQVV",
+        },
+    ),
+    (
+        (3, 192, 11),
+        Grib2Parameter {
+            parameter: "Covariance between surface pressure and surface pressure",
+            units: "Pa*Pa",
+            abbrev: "COVPSPS",
+        },
+    ),
+    (
+        (3, 192, 12),
+        Grib2Parameter {
+            parameter: "Covariance between specific humidity and specific humidity",
+            units: "kg/kg*kg/kg",
+            abbrev: "COVQQ",
+        },
+    ),
+    (
+        (3, 192, 13),
+        Grib2Parameter {
+            parameter: "Covariance between vertical and vertical components of the wind",
+            units: "Pa2/s2",
+            abbrev: "COVVVVV",
+        },
+    ),
+    (
+        (3, 192, 14),
+        Grib2Parameter {
+            parameter: "Covariance between temperature and temperature",
+            units: "K*K",
+            abbrev: "COVTT",
+        },
+    ),
+    ((3, 192, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    ((4, 0, 0), Grib2Parameter { parameter: "Temperature", units: "K", abbrev: "TMPSWP" }),
+    (
+        (4, 0, 1),
+        Grib2Parameter { parameter: "Electron Temperature", units: "K", abbrev: "ELECTMP" },
+    ),
+    ((4, 0, 2), Grib2Parameter { parameter: "Proton Temperature", units: "K", abbrev: "PROTTMP" }),
+    ((4, 0, 3), Grib2Parameter { parameter: "Ion Temperature", units: "K", abbrev: "IONTMP" }),
+    ((4, 0, 4), Grib2Parameter { parameter: "Parallel Temperature", units: "K", abbrev: "PRATMP" }),
+    (
+        (4, 0, 5),
+        Grib2Parameter { parameter: "Perpendicular Temperature", units: "K", abbrev: "PRPTMP" },
+    ),
+    ((4, 0, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 1, 0),
+        Grib2Parameter { parameter: "Velocity Magnitude (Speed)", units: "m s-1", abbrev: "SPEED" },
+    ),
+    (
+        (4, 1, 1),
+        Grib2Parameter {
+            parameter: "1st Vector Component of Velocity (Coordinate system dependent)",
+            units: "m s-1",
+            abbrev: "VEL1",
+        },
+    ),
+    (
+        (4, 1, 2),
+        Grib2Parameter {
+            parameter: "2nd Vector Component of Velocity (Coordinate system dependent)",
+            units: "m s-1",
+            abbrev: "VEL2",
+        },
+    ),
+    (
+        (4, 1, 3),
+        Grib2Parameter {
+            parameter: "3rd Vector Component of Velocity (Coordinate system dependent)",
+            units: "m s-1",
+            abbrev: "VEL3",
+        },
+    ),
+    ((4, 1, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 2, 0),
+        Grib2Parameter { parameter: "Particle Number Density", units: "m-3", abbrev: "PLSMDEN" },
+    ),
+    ((4, 2, 1), Grib2Parameter { parameter: "Electron Density", units: "m-3", abbrev: "ELCDEN" }),
+    ((4, 2, 2), Grib2Parameter { parameter: "Proton Density", units: "m-3", abbrev: "PROTDEN" }),
+    ((4, 2, 3), Grib2Parameter { parameter: "Ion Density", units: "m-3", abbrev: "IONDEN" }),
+    (
+        (4, 2, 4),
+        Grib2Parameter {
+            parameter: "Vertical Total Electron Content",
+            units: "TECU",
+            abbrev: "VTEC",
+        },
+    ),
+    (
+        (4, 2, 5),
+        Grib2Parameter { parameter: "HF Absorption Frequency", units: "Hz", abbrev: "ABSFRQ" },
+    ),
+    ((4, 2, 6), Grib2Parameter { parameter: "HF Absorption", units: "dB", abbrev: "ABSRB" }),
+    ((4, 2, 7), Grib2Parameter { parameter: "Spread F", units: "m", abbrev: "SPRDF" }),
+    ((4, 2, 8), Grib2Parameter { parameter: "h'F", units: "m", abbrev: "HPRIMF" }),
+    ((4, 2, 9), Grib2Parameter { parameter: "Critical Frequency", units: "Hz", abbrev: "CRTFRQ" }),
+    (
+        (4, 2, 10),
+        Grib2Parameter {
+            parameter: "Maximal Usable Frequency (MUF)",
+            units: "Hz",
+            abbrev: "MAXUFZ",
+        },
+    ),
+    ((4, 2, 11), Grib2Parameter { parameter: "Peak Height (hm)", units: "m", abbrev: "PEAKH" }),
+    ((4, 2, 12), Grib2Parameter { parameter: "Peak Density", units: "m-3", abbrev: "PEAKDEN" }),
+    (
+        (4, 2, 13),
+        Grib2Parameter {
+            parameter: "Equivalent Slab Thickness (tau)",
+            units: "km",
+            abbrev: "EQSLABT",
+        },
+    ),
+    ((4, 2, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 3, 0),
+        Grib2Parameter { parameter: "Magnetic Field Magnitude", units: "T", abbrev: "BTOT" },
+    ),
+    (
+        (4, 3, 1),
+        Grib2Parameter {
+            parameter: "1st Vector Component of Magnetic Field",
+            units: "T",
+            abbrev: "BVEC1",
+        },
+    ),
+    (
+        (4, 3, 2),
+        Grib2Parameter {
+            parameter: "2nd Vector Component of Magnetic Field",
+            units: "T",
+            abbrev: "BVEC2",
+        },
+    ),
+    (
+        (4, 3, 3),
+        Grib2Parameter {
+            parameter: "3rd Vector Component of Magnetic Field",
+            units: "T",
+            abbrev: "BVEC3",
+        },
+    ),
+    (
+        (4, 3, 4),
+        Grib2Parameter { parameter: "Electric Field Magnitude", units: "V m-1", abbrev: "ETOT" },
+    ),
+    (
+        (4, 3, 5),
+        Grib2Parameter {
+            parameter: "1st Vector Component of Electric Field",
+            units: "V m-1",
+            abbrev: "EVEC1",
+        },
+    ),
+    (
+        (4, 3, 6),
+        Grib2Parameter {
+            parameter: "2nd Vector Component of Electric Field",
+            units: "V m-1",
+            abbrev: "EVEC2",
+        },
+    ),
+    (
+        (4, 3, 7),
+        Grib2Parameter {
+            parameter: "3rd Vector Component of Electric Field",
+            units: "V m-1",
+            abbrev: "EVEC3",
+        },
+    ),
+    ((4, 3, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 4, 0),
+        Grib2Parameter {
+            parameter: "Proton Flux (Differential)",
+            units: "(m2 s sr eV)-1",
+            abbrev: "DIFPFLUX",
+        },
+    ),
+    (
+        (4, 4, 1),
+        Grib2Parameter {
+            parameter: "Proton Flux (Integral)",
+            units: "(m2 s sr)-1",
+            abbrev: "INTPFLUX",
+        },
+    ),
+    (
+        (4, 4, 2),
+        Grib2Parameter {
+            parameter: "Electron Flux (Differential)",
+            units: "(m2 s sr eV)-1",
+            abbrev: "DIFEFLUX",
+        },
+    ),
+    (
+        (4, 4, 3),
+        Grib2Parameter {
+            parameter: "Electron Flux (Integral)",
+            units: "(m2 s sr)-1",
+            abbrev: "INTEFLUX",
+        },
+    ),
+    (
+        (4, 4, 4),
+        Grib2Parameter {
+            parameter: "Heavy Ion Flux (Differential)",
+            units: "(m2 s sr eV / nuc)-1",
+            abbrev: "DIFIFLUX",
+        },
+    ),
+    (
+        (4, 4, 5),
+        Grib2Parameter {
+            parameter: "Heavy Ion Flux (Integral)",
+            units: "(m2 s sr)-1",
+            abbrev: "INTIFLUX",
+        },
+    ),
+    (
+        (4, 4, 6),
+        Grib2Parameter { parameter: "Cosmic Ray Neutron Flux", units: "h-1", abbrev: "NTRNFLUX" },
+    ),
+    ((4, 4, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    ((4, 5, 0), Grib2Parameter { parameter: "Amplitude", units: "rad", abbrev: "AMPL" }),
+    ((4, 5, 1), Grib2Parameter { parameter: "Phase", units: "rad", abbrev: "PHASE" }),
+    ((4, 5, 2), Grib2Parameter { parameter: "Frequency", units: "Hz", abbrev: "FREQ" }),
+    ((4, 5, 3), Grib2Parameter { parameter: "Wavelength", units: "m", abbrev: "WAVELGTH" }),
+    ((4, 5, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 6, 0),
+        Grib2Parameter { parameter: "Integrated Solar Irradiance", units: "W m-2", abbrev: "TSI" },
+    ),
+    (
+        (4, 6, 1),
+        Grib2Parameter {
+            parameter: "Solar X-ray Flux (XRS Long)",
+            units: "W m-2",
+            abbrev: "XLONG",
+        },
+    ),
+    (
+        (4, 6, 2),
+        Grib2Parameter {
+            parameter: "Solar X-ray Flux (XRS Short)",
+            units: "W m-2",
+            abbrev: "XSHRT",
+        },
+    ),
+    (
+        (4, 6, 3),
+        Grib2Parameter { parameter: "Solar EUV Irradiance", units: "W m-2", abbrev: "EUVIRR" },
+    ),
+    (
+        (4, 6, 4),
+        Grib2Parameter {
+            parameter: "Solar Spectral Irradiance",
+            units: "W m-2 nm-1",
+            abbrev: "SPECIRR",
+        },
+    ),
+    ((4, 6, 5), Grib2Parameter { parameter: "F10.7", units: "W m-2 Hz-1", abbrev: "F107" }),
+    (
+        (4, 6, 6),
+        Grib2Parameter { parameter: "Solar Radio Emissions", units: "W m-2 Hz-1", abbrev: "SOLRF" },
+    ),
+    ((4, 6, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 7, 0),
+        Grib2Parameter { parameter: "Limb Intensity", units: "J m-2 s-1", abbrev: "LMBINT" },
+    ),
+    (
+        (4, 7, 1),
+        Grib2Parameter { parameter: "Disk Intensity", units: "J m-2 s-1", abbrev: "DSKINT" },
+    ),
+    (
+        (4, 7, 2),
+        Grib2Parameter { parameter: "Disk Intensity Day", units: "J m-2 s-1", abbrev: "DSKDAY" },
+    ),
+    (
+        (4, 7, 3),
+        Grib2Parameter { parameter: "Disk Intensity Night", units: "J m-2 s-1", abbrev: "DSKNGT" },
+    ),
+    ((4, 7, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 8, 0),
+        Grib2Parameter { parameter: "X-Ray Radiance", units: "W sr-1 m-2", abbrev: "XRAYRAD" },
+    ),
+    (
+        (4, 8, 1),
+        Grib2Parameter { parameter: "EUV Radiance", units: "W sr-1 m-2", abbrev: "EUVRAD" },
+    ),
+    (
+        (4, 8, 2),
+        Grib2Parameter { parameter: "H-Alpha Radiance", units: "W sr-1 m-2", abbrev: "HARAD" },
+    ),
+    (
+        (4, 8, 3),
+        Grib2Parameter { parameter: "White Light Radiance", units: "W sr-1 m-2", abbrev: "WHTRAD" },
+    ),
+    (
+        (4, 8, 4),
+        Grib2Parameter { parameter: "CaII-K Radiance", units: "W sr-1 m-2", abbrev: "CAIIRAD" },
+    ),
+    (
+        (4, 8, 5),
+        Grib2Parameter {
+            parameter: "White Light Coronagraph Radiance",
+            units: "W sr-1 m-2",
+            abbrev: "WHTCOR",
+        },
+    ),
+    (
+        (4, 8, 6),
+        Grib2Parameter {
+            parameter: "Heliospheric Radiance",
+            units: "W sr-1 m-2",
+            abbrev: "HELCOR",
+        },
+    ),
+    ((4, 8, 7), Grib2Parameter { parameter: "Thematic Mask", units: "Numeric", abbrev: "MASK" }),
+    (
+        (4, 8, 8),
+        Grib2Parameter {
+            parameter: "Solar Induced Chlorophyll Fluorescence",
+            units: "W sr-1 m-2",
+            abbrev: "SICFL",
+        },
+    ),
+    ((4, 8, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 9, 0),
+        Grib2Parameter { parameter: "Pedersen Conductivity", units: "S m-1", abbrev: "SIGPED" },
+    ),
+    (
+        (4, 9, 1),
+        Grib2Parameter { parameter: "Hall Conductivity", units: "S m-1", abbrev: "SIGHAL" },
+    ),
+    (
+        (4, 9, 2),
+        Grib2Parameter { parameter: "Parallel Conductivity", units: "S m-1", abbrev: "SIGPAR" },
+    ),
+    ((4, 9, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (4, 10, 0),
+        Grib2Parameter {
+            parameter: "Scintillation Index (sigma phi)",
+            units: "rad",
+            abbrev: "SCINIDX",
+        },
+    ),
+    (
+        (4, 10, 1),
+        Grib2Parameter {
+            parameter: "Scintillation Index S4",
+            units: "Numeric",
+            abbrev: "SCIDEXS4",
+        },
+    ),
+    (
+        (4, 10, 2),
+        Grib2Parameter {
+            parameter: "Rate of Change of TEC Index (ROTI)",
+            units: "TECU/min",
+            abbrev: "ROTIDX",
+        },
+    ),
+    (
+        (4, 10, 3),
+        Grib2Parameter {
+            parameter: "Disturbance Ionosphere Index Spatial Gradient (DIXSG)",
+            units: "Numeric",
+            abbrev: "DIDXSG",
+        },
+    ),
+    (
+        (4, 10, 4),
+        Grib2Parameter {
+            parameter: "Along Arc TEC Rate (AATR)",
+            units: "TECU/min",
+            abbrev: "AATRATE",
+        },
+    ),
+    ((4, 10, 5), Grib2Parameter { parameter: "Kp", units: "Numeric", abbrev: "KP" }),
+    (
+        (4, 10, 6),
+        Grib2Parameter {
+            parameter: "Equatorial Disturbance Storm Time Index (Dst)",
+            units: "nT",
+            abbrev: "EDISSTIX",
+        },
+    ),
+    (
+        (4, 10, 7),
+        Grib2Parameter { parameter: "Auroral Electrojet (AE)", units: "nT", abbrev: "AURELEC" },
+    ),
+    ((4, 10, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    ((10, 0, 0), Grib2Parameter { parameter: "Wave Spectra (1)", units: "-", abbrev: "WVSP1" }),
+    ((10, 0, 1), Grib2Parameter { parameter: "Wave Spectra (2)", units: "-", abbrev: "WVSP2" }),
+    ((10, 0, 2), Grib2Parameter { parameter: "Wave Spectra (3)", units: "-", abbrev: "WVSP3" }),
+    (
+        (10, 0, 3),
+        Grib2Parameter {
+            parameter: "Significant Height of Combined Wind Waves and Swell",
+            units: "m",
+            abbrev: "HTSGW",
+        },
+    ),
+    (
+        (10, 0, 4),
+        Grib2Parameter {
+            parameter: "Direction of Wind Waves",
+            units: "degree true",
+            abbrev: "WVDIR",
+        },
+    ),
+    (
+        (10, 0, 5),
+        Grib2Parameter {
+            parameter: "Significant Height of Wind Waves",
+            units: "m",
+            abbrev: "WVHGT",
+        },
+    ),
+    (
+        (10, 0, 6),
+        Grib2Parameter { parameter: "Mean Period of Wind Waves", units: "s", abbrev: "WVPER" },
+    ),
+    (
+        (10, 0, 7),
+        Grib2Parameter {
+            parameter: "Direction of Swell Waves",
+            units: "degree true",
+            abbrev: "SWDIR",
+        },
+    ),
+    (
+        (10, 0, 8),
+        Grib2Parameter {
+            parameter: "Significant Height of Swell Waves",
+            units: "m",
+            abbrev: "SWELL",
+        },
+    ),
+    (
+        (10, 0, 9),
+        Grib2Parameter { parameter: "Mean Period of Swell Waves", units: "s", abbrev: "SWPER" },
+    ),
+    (
+        (10, 0, 10),
+        Grib2Parameter {
+            parameter: "Primary Wave Direction",
+            units: "degree true",
+            abbrev: "DIRPW",
+        },
+    ),
+    (
+        (10, 0, 11),
+        Grib2Parameter { parameter: "Primary Wave Mean Period", units: "s", abbrev: "PERPW" },
+    ),
+    (
+        (10, 0, 12),
+        Grib2Parameter {
+            parameter: "Secondary Wave Direction",
+            units: "degree true",
+            abbrev: "DIRSW",
+        },
+    ),
+    (
+        (10, 0, 13),
+        Grib2Parameter { parameter: "Secondary Wave Mean Period", units: "s", abbrev: "PERSW" },
+    ),
+    (
+        (10, 0, 14),
+        Grib2Parameter {
+            parameter: "Direction of Combined Wind Waves and Swell",
+            units: "degree true",
+            abbrev: "WWSDIR",
+        },
+    ),
+    (
+        (10, 0, 15),
+        Grib2Parameter {
+            parameter: "Mean Period of Combined Wind Waves and Swell",
+            units: "s",
+            abbrev: "MWSPER",
+        },
+    ),
+    (
+        (10, 0, 16),
+        Grib2Parameter { parameter: "Coefficient of Drag With Waves", units: "-", abbrev: "CDWW" },
+    ),
+    (
+        (10, 0, 17),
+        Grib2Parameter { parameter: "Friction Velocity", units: "m s-1", abbrev: "FRICVW" },
+    ),
+    ((10, 0, 18), Grib2Parameter { parameter: "Wave Stress", units: "N m-2", abbrev: "WSTR" }),
+    (
+        (10, 0, 19),
+        Grib2Parameter { parameter: "Normalised Waves Stress", units: "-", abbrev: "NWSTR" },
+    ),
+    (
+        (10, 0, 20),
+        Grib2Parameter { parameter: "Mean Square Slope of Waves", units: "-", abbrev: "MSSW" },
+    ),
+    (
+        (10, 0, 21),
+        Grib2Parameter {
+            parameter: "U-component Surface Stokes Drift",
+            units: "m s-1",
+            abbrev: "USSD",
+        },
+    ),
+    (
+        (10, 0, 22),
+        Grib2Parameter {
+            parameter: "V-component Surface Stokes Drift",
+            units: "m s-1",
+            abbrev: "VSSD",
+        },
+    ),
+    (
+        (10, 0, 23),
+        Grib2Parameter {
+            parameter: "Period of Maximum Individual Wave Height",
+            units: "s",
+            abbrev: "PMAXWH",
+        },
+    ),
+    (
+        (10, 0, 24),
+        Grib2Parameter { parameter: "Maximum Individual Wave Height", units: "m", abbrev: "MAXWH" },
+    ),
+    (
+        (10, 0, 25),
+        Grib2Parameter { parameter: "Inverse Mean Wave Frequency", units: "s", abbrev: "IMWF" },
+    ),
+    (
+        (10, 0, 26),
+        Grib2Parameter {
+            parameter: "Inverse Mean Frequency of The Wind Waves",
+            units: "s",
+            abbrev: "IMFWW",
+        },
+    ),
+    (
+        (10, 0, 27),
+        Grib2Parameter {
+            parameter: "Inverse Mean Frequency of The Total Swell",
+            units: "s",
+            abbrev: "IMFTSW",
+        },
+    ),
+    (
+        (10, 0, 28),
+        Grib2Parameter {
+            parameter: "Mean Zero-Crossing Wave Period",
+            units: "s",
+            abbrev: "MZWPER",
+        },
+    ),
+    (
+        (10, 0, 29),
+        Grib2Parameter {
+            parameter: "Mean Zero-Crossing Period of The Wind Waves",
+            units: "s",
+            abbrev: "MZPWW",
+        },
+    ),
+    (
+        (10, 0, 30),
+        Grib2Parameter {
+            parameter: "Mean Zero-Crossing Period of The Total Swell",
+            units: "s",
+            abbrev: "MZPTSW",
+        },
+    ),
+    (
+        (10, 0, 31),
+        Grib2Parameter { parameter: "Wave Directional Width", units: "-", abbrev: "WDIRW" },
+    ),
+    (
+        (10, 0, 32),
+        Grib2Parameter {
+            parameter: "Directional Width of The Wind Waves",
+            units: "-",
+            abbrev: "DIRWWW",
+        },
+    ),
+    (
+        (10, 0, 33),
+        Grib2Parameter {
+            parameter: "Directional Width of The Total Swell",
+            units: "-",
+            abbrev: "DIRWTS",
+        },
+    ),
+    ((10, 0, 34), Grib2Parameter { parameter: "Peak Wave Period", units: "s", abbrev: "PWPER" }),
+    (
+        (10, 0, 35),
+        Grib2Parameter { parameter: "Peak Period of The Wind Waves", units: "s", abbrev: "PPERWW" },
+    ),
+    (
+        (10, 0, 36),
+        Grib2Parameter {
+            parameter: "Peak Period of The Total Swell",
+            units: "s",
+            abbrev: "PPERTS",
+        },
+    ),
+    (
+        (10, 0, 37),
+        Grib2Parameter { parameter: "Altimeter Wave Height", units: "m", abbrev: "ALTWH" },
+    ),
+    (
+        (10, 0, 38),
+        Grib2Parameter {
+            parameter: "Altimeter Corrected Wave Height",
+            units: "m",
+            abbrev: "ALCWH",
+        },
+    ),
+    (
+        (10, 0, 39),
+        Grib2Parameter {
+            parameter: "Altimeter Range Relative Correction",
+            units: "-",
+            abbrev: "ALRRC",
+        },
+    ),
+    (
+        (10, 0, 40),
+        Grib2Parameter {
+            parameter: "10 Metre Neutral Wind Speed Over Waves",
+            units: "m s-1",
+            abbrev: "MNWSOW",
+        },
+    ),
+    (
+        (10, 0, 41),
+        Grib2Parameter {
+            parameter: "10 Metre Wind Direction Over Waves",
+            units: "degree true",
+            abbrev: "MWDIRW",
+        },
+    ),
+    (
+        (10, 0, 42),
+        Grib2Parameter { parameter: "Wave Engery Spectrum", units: "m-2 s rad-1", abbrev: "WESP" },
+    ),
+    (
+        (10, 0, 43),
+        Grib2Parameter {
+            parameter: "Kurtosis of The Sea Surface Elevation Due to Waves",
+            units: "-",
+            abbrev: "KSSEW",
+        },
+    ),
+    (
+        (10, 0, 44),
+        Grib2Parameter { parameter: "Benjamin-Feir Index", units: "-", abbrev: "BENINX" },
+    ),
+    (
+        (10, 0, 45),
+        Grib2Parameter { parameter: "Spectral Peakedness Factor", units: "s-1", abbrev: "SPFTR" },
+    ),
+    (
+        (10, 0, 46),
+        Grib2Parameter { parameter: "Peak wave direction", units: "°", abbrev: "PWAVEDIR" },
+    ),
+    (
+        (10, 0, 47),
+        Grib2Parameter {
+            parameter: "Significant wave height of first swell partition",
+            units: "m",
+            abbrev: "SWHFSWEL",
+        },
+    ),
+    (
+        (10, 0, 48),
+        Grib2Parameter {
+            parameter: "Significant wave height of second swell partition",
+            units: "m",
+            abbrev: "SWHSSWEL",
+        },
+    ),
+    (
+        (10, 0, 49),
+        Grib2Parameter {
+            parameter: "Significant wave height of third swell partition",
+            units: "m",
+            abbrev: "SWHTSWEL",
+        },
+    ),
+    (
+        (10, 0, 50),
+        Grib2Parameter {
+            parameter: "Mean wave period of first swell partition",
+            units: "s",
+            abbrev: "MWPFSWEL",
+        },
+    ),
+    (
+        (10, 0, 51),
+        Grib2Parameter {
+            parameter: "Mean wave period of second swell partition",
+            units: "s",
+            abbrev: "MWPSSWEL",
+        },
+    ),
+    (
+        (10, 0, 52),
+        Grib2Parameter {
+            parameter: "Mean wave period of third swell partition",
+            units: "s",
+            abbrev: "MWPTSWEL",
+        },
+    ),
+    (
+        (10, 0, 53),
+        Grib2Parameter {
+            parameter: "Mean wave direction of first swell partition",
+            units: "°",
+            abbrev: "MWDFSWEL",
+        },
+    ),
+    (
+        (10, 0, 54),
+        Grib2Parameter {
+            parameter: "Mean wave direction of second swell partition",
+            units: "°",
+            abbrev: "MWDSSWEL",
+        },
+    ),
+    (
+        (10, 0, 55),
+        Grib2Parameter {
+            parameter: "Mean wave direction of third swell partition",
+            units: "°",
+            abbrev: "MWDTSWEL",
+        },
+    ),
+    (
+        (10, 0, 56),
+        Grib2Parameter {
+            parameter: "Wave directional width of first swell partition",
+            units: "-",
+            abbrev: "WDWFSWEL",
+        },
+    ),
+    (
+        (10, 0, 57),
+        Grib2Parameter {
+            parameter: "Wave directional width of second swell partition",
+            units: "-",
+            abbrev: "WDWSSWEL",
+        },
+    ),
+    (
+        (10, 0, 58),
+        Grib2Parameter {
+            parameter: "Wave directional width of third swell partition",
+            units: "-",
+            abbrev: "WDWTSWEL",
+        },
+    ),
+    (
+        (10, 0, 59),
+        Grib2Parameter {
+            parameter: "Wave frequency width of first swell partition",
+            units: "-",
+            abbrev: "WFWFSWEL",
+        },
+    ),
+    (
+        (10, 0, 60),
+        Grib2Parameter {
+            parameter: "Wave frequency width of second swell partition",
+            units: "-",
+            abbrev: "WFWSSWEL",
+        },
+    ),
+    (
+        (10, 0, 61),
+        Grib2Parameter {
+            parameter: "Wave frequency width of third swell partition",
+            units: "-",
+            abbrev: "WFWTSWEL",
+        },
+    ),
+    (
+        (10, 0, 62),
+        Grib2Parameter { parameter: "Wave frequency width", units: "-", abbrev: "WAVEFREW" },
+    ),
+    (
+        (10, 0, 63),
+        Grib2Parameter { parameter: "Frequency width of wind waves", units: "-", abbrev: "FREWWW" },
+    ),
+    (
+        (10, 0, 64),
+        Grib2Parameter {
+            parameter: "Frequency width of total swell",
+            units: "-",
+            abbrev: "FREWTSW",
+        },
+    ),
+    (
+        (10, 0, 65),
+        Grib2Parameter {
+            parameter: "Peak Wave Period of First Swell Partition",
+            units: "s",
+            abbrev: "PWPFSPAR",
+        },
+    ),
+    (
+        (10, 0, 66),
+        Grib2Parameter {
+            parameter: "Peak Wave Period of Second Swell Partition",
+            units: "s",
+            abbrev: "PWPSSPAR",
+        },
+    ),
+    (
+        (10, 0, 67),
+        Grib2Parameter {
+            parameter: "Peak Wave Period of Third Swell Partition",
+            units: "s",
+            abbrev: "PWPTSPAR",
+        },
+    ),
+    (
+        (10, 0, 68),
+        Grib2Parameter {
+            parameter: "Peak Wave Direction of First Swell Partition",
+            units: "degree true",
+            abbrev: "PWDFSPAR",
+        },
+    ),
+    (
+        (10, 0, 69),
+        Grib2Parameter {
+            parameter: "Peak Wave Direction of Second Swell Partition",
+            units: "degree true",
+            abbrev: "PWDSSPAR",
+        },
+    ),
+    (
+        (10, 0, 70),
+        Grib2Parameter {
+            parameter: "Peak Wave Direction of Third Swell Partition",
+            units: "degree true",
+            abbrev: "PWDTSPAR",
+        },
+    ),
+    (
+        (10, 0, 71),
+        Grib2Parameter {
+            parameter: "Peak Direction of Wind Waves",
+            units: "degree true",
+            abbrev: "PDWWAVE",
+        },
+    ),
+    (
+        (10, 0, 72),
+        Grib2Parameter {
+            parameter: "Peak Direction of Total Swell",
+            units: "degree true",
+            abbrev: "PDTSWELL",
+        },
+    ),
+    (
+        (10, 0, 73),
+        Grib2Parameter { parameter: "Whitecap Fraction", units: "fraction", abbrev: "WCAPFRAC" },
+    ),
+    (
+        (10, 0, 74),
+        Grib2Parameter {
+            parameter: "Mean Direction of Total Swell",
+            units: "degree",
+            abbrev: "MDTSWEL",
+        },
+    ),
+    (
+        (10, 0, 75),
+        Grib2Parameter {
+            parameter: "Mean Direction of Wind Waves",
+            units: "degree",
+            abbrev: "MDWWAVE",
+        },
+    ),
+    (
+        (10, 0, 76),
+        Grib2Parameter { parameter: "Charnock (see Note 2)", units: "Numeric", abbrev: "CHNCK" },
+    ),
+    (
+        (10, 0, 77),
+        Grib2Parameter {
+            parameter: "Wave Spectral Skewness",
+            units: "Numeric",
+            abbrev: "WAVESPSK",
+        },
+    ),
+    (
+        (10, 0, 78),
+        Grib2Parameter {
+            parameter: "Wave Energy Flux Magnitude",
+            units: "W m-1",
+            abbrev: "WAVEFMAG",
+        },
+    ),
+    (
+        (10, 0, 79),
+        Grib2Parameter {
+            parameter: "Wave Energy Flux Mean Direction",
+            units: "degree true",
+            abbrev: "WAVEFDIR",
+        },
+    ),
+    (
+        (10, 0, 80),
+        Grib2Parameter {
+            parameter: "Ratio of Wave Angular and Frequency Width",
+            units: "Numeric",
+            abbrev: "RWAVEAFW",
+        },
+    ),
+    (
+        (10, 0, 81),
+        Grib2Parameter {
+            parameter: "Free Convective Velocity over the Oceans",
+            units: "m s-1",
+            abbrev: "FCVOCEAN",
+        },
+    ),
+    (
+        (10, 0, 82),
+        Grib2Parameter {
+            parameter: "Air Density over the Oceans",
+            units: "kg m-3",
+            abbrev: "AIRDENOC",
+        },
+    ),
+    (
+        (10, 0, 83),
+        Grib2Parameter {
+            parameter: "Normalized Energy Flux into Waves (see Note 3)",
+            units: "Numeric",
+            abbrev: "NEFW",
+        },
+    ),
+    (
+        (10, 0, 84),
+        Grib2Parameter {
+            parameter: "Normalized Stress into Ocean (see Note 3)",
+            units: "Numeric",
+            abbrev: "NSOCEAN",
+        },
+    ),
+    (
+        (10, 0, 85),
+        Grib2Parameter {
+            parameter: "Normalized Energy Flux into Ocean (see Note 3)",
+            units: "Numeric",
+            abbrev: "NEFOCEAN",
+        },
+    ),
+    (
+        (10, 0, 86),
+        Grib2Parameter {
+            parameter: "Surface Elevation Variance due to Waves (over all frequencies and \
+                        directions)",
+            units: "m2 s rad-1",
+            abbrev: "SEVWAVE",
+        },
+    ),
+    (
+        (10, 0, 87),
+        Grib2Parameter {
+            parameter: "Wave Induced Mean Sea Level Correction",
+            units: "m",
+            abbrev: "WAVEMSLC",
+        },
+    ),
+    (
+        (10, 0, 88),
+        Grib2Parameter { parameter: "Spectral Width Index", units: "Numeric", abbrev: "SPECWI" },
+    ),
+    (
+        (10, 0, 89),
+        Grib2Parameter {
+            parameter: "Number of Events in Freak Wave Statistics",
+            units: "Numeric",
+            abbrev: "EFWS",
+        },
+    ),
+    (
+        (10, 0, 90),
+        Grib2Parameter {
+            parameter: "U-Component of Surface Momentum Flux into Ocean",
+            units: "N m-2",
+            abbrev: "USMFO",
+        },
+    ),
+    (
+        (10, 0, 91),
+        Grib2Parameter {
+            parameter: "V-Component of Surface Momentum Flux into Ocean",
+            units: "N m-2",
+            abbrev: "VSMFO",
+        },
+    ),
+    (
+        (10, 0, 92),
+        Grib2Parameter {
+            parameter: "Wave Turbulent Energy Flux into Ocean",
+            units: "W m-2",
+            abbrev: "WAVETEFO",
+        },
+    ),
+    (
+        (10, 0, 93),
+        Grib2Parameter {
+            parameter: "Envelope Maximum Individual Wave Height",
+            units: "m",
+            abbrev: "EMIWAVE",
+        },
+    ),
+    (
+        (10, 0, 94),
+        Grib2Parameter {
+            parameter: "Time Domain Maximum Individual Crest Height",
+            units: "m",
+            abbrev: "TDMCREST",
+        },
+    ),
+    (
+        (10, 0, 95),
+        Grib2Parameter {
+            parameter: "Time Domain Maximum Individual Wave Height",
+            units: "m",
+            abbrev: "TDMWAVE",
+        },
+    ),
+    (
+        (10, 0, 96),
+        Grib2Parameter {
+            parameter: "Space Time Maximum Individual Crest Height",
+            units: "m",
+            abbrev: "STMCREST",
+        },
+    ),
+    (
+        (10, 0, 97),
+        Grib2Parameter {
+            parameter: "Space Time Maximum Individual Wave Height",
+            units: "m",
+            abbrev: "STMWAVE",
+        },
+    ),
+    (
+        (10, 0, 98),
+        Grib2Parameter {
+            parameter: "Goda Peakedness Factor",
+            units: "Numeric",
+            abbrev: "GODAPEAK",
+        },
+    ),
+    (
+        (10, 0, 192),
+        Grib2Parameter { parameter: "Wave Steepness", units: "proportion", abbrev: "WSTP" },
+    ),
+    ((10, 0, 193), Grib2Parameter { parameter: "Wave Length", units: "-", abbrev: "WLENG" }),
+    ((10, 0, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (10, 1, 0),
+        Grib2Parameter { parameter: "Current Direction", units: "degree True", abbrev: "DIRC" },
+    ),
+    ((10, 1, 1), Grib2Parameter { parameter: "Current Speed", units: "m s-1", abbrev: "SPC" }),
+    (
+        (10, 1, 2),
+        Grib2Parameter { parameter: "U-Component of Current", units: "m s-1", abbrev: "UOGRD" },
+    ),
+    (
+        (10, 1, 3),
+        Grib2Parameter { parameter: "V-Component of Current", units: "m s-1", abbrev: "VOGRD" },
+    ),
+    (
+        (10, 1, 4),
+        Grib2Parameter {
+            parameter: "Rip Current Occurrence Probability",
+            units: "%",
+            abbrev: "RIPCOP",
+        },
+    ),
+    (
+        (10, 1, 5),
+        Grib2Parameter { parameter: "Eastward Current", units: "m s-1", abbrev: "EASTCUR" },
+    ),
+    (
+        (10, 1, 6),
+        Grib2Parameter { parameter: "Northward Current", units: "m s-1", abbrev: "NRTHCUR" },
+    ),
+    (
+        (10, 1, 192),
+        Grib2Parameter {
+            parameter: "Ocean Mixed Layer U Velocity",
+            units: "m s-1",
+            abbrev: "OMLU",
+        },
+    ),
+    (
+        (10, 1, 193),
+        Grib2Parameter {
+            parameter: "Ocean Mixed Layer V Velocity",
+            units: "m s-1",
+            abbrev: "OMLV",
+        },
+    ),
+    (
+        (10, 1, 194),
+        Grib2Parameter { parameter: "Barotropic U velocity", units: "m s-1", abbrev: "UBARO" },
+    ),
+    (
+        (10, 1, 195),
+        Grib2Parameter { parameter: "Barotropic V velocity", units: "m s-1", abbrev: "VBARO" },
+    ),
+    ((10, 1, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    ((10, 2, 0), Grib2Parameter { parameter: "Ice Cover", units: "Proportion", abbrev: "ICEC" }),
+    ((10, 2, 1), Grib2Parameter { parameter: "Ice Thickness", units: "m", abbrev: "ICETK" }),
+    (
+        (10, 2, 2),
+        Grib2Parameter {
+            parameter: "Direction of Ice Drift",
+            units: "degree True",
+            abbrev: "DICED",
+        },
+    ),
+    (
+        (10, 2, 3),
+        Grib2Parameter { parameter: "Speed of Ice Drift", units: "m s-1", abbrev: "SICED" },
+    ),
+    (
+        (10, 2, 4),
+        Grib2Parameter { parameter: "U-Component of Ice Drift", units: "m s-1", abbrev: "UICE" },
+    ),
+    (
+        (10, 2, 5),
+        Grib2Parameter { parameter: "V-Component of Ice Drift", units: "m s-1", abbrev: "VICE" },
+    ),
+    ((10, 2, 6), Grib2Parameter { parameter: "Ice Growth Rate", units: "m s-1", abbrev: "ICEG" }),
+    ((10, 2, 7), Grib2Parameter { parameter: "Ice Divergence", units: "s-1", abbrev: "ICED" }),
+    ((10, 2, 8), Grib2Parameter { parameter: "Ice Temperature", units: "K", abbrev: "ICETMP" }),
+    (
+        (10, 2, 9),
+        Grib2Parameter {
+            parameter: "Module of Ice Internal Pressure",
+            units: "Pa m",
+            abbrev: "ICEPRS",
+        },
+    ),
+    (
+        (10, 2, 10),
+        Grib2Parameter {
+            parameter: "Zonal Vector Component of Vertically Integrated Ice Internal Pressure",
+            units: "Pa m",
+            abbrev: "ZVCICEP",
+        },
+    ),
+    (
+        (10, 2, 11),
+        Grib2Parameter {
+            parameter: "Meridional Vector Component of Vertically Integrated Ice Internal Pressure",
+            units: "Pa m",
+            abbrev: "MVCICEP",
+        },
+    ),
+    (
+        (10, 2, 12),
+        Grib2Parameter { parameter: "Compressive Ice Strength", units: "N m-1", abbrev: "CICES" },
+    ),
+    (
+        (10, 2, 13),
+        Grib2Parameter {
+            parameter: "Snow Temperature (over sea ice)",
+            units: "K",
+            abbrev: "SNOWTSI",
+        },
+    ),
+    ((10, 2, 14), Grib2Parameter { parameter: "Albedo", units: "Numeric", abbrev: "ALBDOICE" }),
+    (
+        (10, 2, 15),
+        Grib2Parameter {
+            parameter: "Sea Ice Volume per Unit Area",
+            units: "m3m-2",
+            abbrev: "SICEVOL",
+        },
+    ),
+    (
+        (10, 2, 16),
+        Grib2Parameter {
+            parameter: "Snow Volume Over Sea Ice per Unit Area",
+            units: "m3m-2",
+            abbrev: "SNVOLSI",
+        },
+    ),
+    (
+        (10, 2, 17),
+        Grib2Parameter { parameter: "Sea Ice Heat Content", units: "J m-2", abbrev: "SICEHC" },
+    ),
+    (
+        (10, 2, 18),
+        Grib2Parameter {
+            parameter: "Snow over Sea Ice Heat Content",
+            units: "J m-2",
+            abbrev: "SNCEHC",
+        },
+    ),
+    (
+        (10, 2, 19),
+        Grib2Parameter { parameter: "Ice Freeboard Thickness", units: "m", abbrev: "ICEFTHCK" },
+    ),
+    (
+        (10, 2, 20),
+        Grib2Parameter { parameter: "Ice Melt Pond Fraction", units: "fraction", abbrev: "ICEMPF" },
+    ),
+    (
+        (10, 2, 21),
+        Grib2Parameter { parameter: "Ice Melt Pond Depth", units: "m", abbrev: "ICEMPD" },
+    ),
+    (
+        (10, 2, 22),
+        Grib2Parameter {
+            parameter: "Ice Melt Pond Volume per Unit Area",
+            units: "m3m-2",
+            abbrev: "ICEMPV",
+        },
+    ),
+    (
+        (10, 2, 23),
+        Grib2Parameter {
+            parameter: "Sea Ice Fraction Tendency due to Parameterization",
+            units: "s-1",
+            abbrev: "SIFTP",
+        },
+    ),
+    (
+        (10, 2, 24),
+        Grib2Parameter { parameter: "x-component of ice drift", units: "m s-1", abbrev: "XICE" },
+    ),
+    (
+        (10, 2, 25),
+        Grib2Parameter { parameter: "y-component of ice drift", units: "m s-1", abbrev: "YICE" },
+    ),
+    (
+        (10, 2, 27),
+        Grib2Parameter {
+            parameter: "Freezing/melting potential (Tentatively accepted)",
+            units: "W m-2",
+            abbrev: "FRZMLTPOT",
+        },
+    ),
+    (
+        (10, 2, 28),
+        Grib2Parameter {
+            parameter: "Melt onset date (Tentatively accepted)",
+            units: "Numeric",
+            abbrev: "MLTDATE",
+        },
+    ),
+    (
+        (10, 2, 29),
+        Grib2Parameter {
+            parameter: "Freeze onset date (Tentatively accepted)",
+            units: "Numeric",
+            abbrev: "FRZDATE",
+        },
+    ),
+    ((10, 2, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    ((10, 3, 0), Grib2Parameter { parameter: "Water Temperature", units: "K", abbrev: "WTMP" }),
+    (
+        (10, 3, 1),
+        Grib2Parameter {
+            parameter: "Deviation of Sea Level from Mean",
+            units: "m",
+            abbrev: "DSLM",
+        },
+    ),
+    (
+        (10, 3, 2),
+        Grib2Parameter { parameter: "Heat Exchange Coefficient", units: "", abbrev: "CH" },
+    ),
+    (
+        (10, 3, 3),
+        Grib2Parameter { parameter: "Practical Salinity", units: "Numeric", abbrev: "PRACTSAL" },
+    ),
+    (
+        (10, 3, 4),
+        Grib2Parameter { parameter: "Downward Heat Flux", units: "W m-2", abbrev: "DWHFLUX" },
+    ),
+    (
+        (10, 3, 5),
+        Grib2Parameter { parameter: "Eastward Surface Stress", units: "N m-2", abbrev: "EASTWSS" },
+    ),
+    (
+        (10, 3, 6),
+        Grib2Parameter {
+            parameter: "Northward Surface Stress",
+            units: "N m-2",
+            abbrev: "NORTHWSS",
+        },
+    ),
+    (
+        (10, 3, 7),
+        Grib2Parameter {
+            parameter: "x-component Surface Stress",
+            units: "N m-2",
+            abbrev: "XCOMPSS",
+        },
+    ),
+    (
+        (10, 3, 8),
+        Grib2Parameter {
+            parameter: "y-component Surface Stress",
+            units: "N m-2",
+            abbrev: "YCOMPSS",
+        },
+    ),
+    (
+        (10, 3, 9),
+        Grib2Parameter {
+            parameter: "Thermosteric Change in Sea Surface Height",
+            units: "m",
+            abbrev: "THERCSSH",
+        },
+    ),
+    (
+        (10, 3, 10),
+        Grib2Parameter {
+            parameter: "Halosteric Change in Sea Surface Height",
+            units: "m",
+            abbrev: "HALOCSSH",
+        },
+    ),
+    (
+        (10, 3, 11),
+        Grib2Parameter {
+            parameter: "Steric Change in Sea Surface Height",
+            units: "m",
+            abbrev: "STERCSSH",
+        },
+    ),
+    (
+        (10, 3, 12),
+        Grib2Parameter { parameter: "Sea Salt Flux", units: "kg m-2s-1", abbrev: "SEASFLUX" },
+    ),
+    (
+        (10, 3, 13),
+        Grib2Parameter {
+            parameter: "Net Upward Water Flux",
+            units: "kg m-2s-1",
+            abbrev: "NETUPWFLUX",
+        },
+    ),
+    (
+        (10, 3, 14),
+        Grib2Parameter {
+            parameter: "Eastward Surface Water Velocity",
+            units: "m s-1",
+            abbrev: "ESURFWVEL",
+        },
+    ),
+    (
+        (10, 3, 15),
+        Grib2Parameter {
+            parameter: "Northward Surface Water Velocity",
+            units: "m s-1",
+            abbrev: "NSURFWVEL",
+        },
+    ),
+    (
+        (10, 3, 16),
+        Grib2Parameter {
+            parameter: "x-component of Surface Water Velocity",
+            units: "m s-1",
+            abbrev: "XSURFWVEL",
+        },
+    ),
+    (
+        (10, 3, 17),
+        Grib2Parameter {
+            parameter: "y-component of Surface Water Velocity",
+            units: "m s-1",
+            abbrev: "YSURFWVEL",
+        },
+    ),
+    (
+        (10, 3, 18),
+        Grib2Parameter { parameter: "Heat Flux Correction", units: "W m-2", abbrev: "HFLUXCOR" },
+    ),
+    (
+        (10, 3, 19),
+        Grib2Parameter {
+            parameter: "Sea Surface Height Tendency due to Parameterization",
+            units: "m s-1",
+            abbrev: "SSHGTPARM",
+        },
+    ),
+    (
+        (10, 3, 20),
+        Grib2Parameter {
+            parameter: "Deviation of Sea Level from Mean with Inverse Barometer Correction",
+            units: "m",
+            abbrev: "DSLIBARCOR",
+        },
+    ),
+    ((10, 3, 21), Grib2Parameter { parameter: "Salinity", units: "kg kg-1", abbrev: "SALINITY" }),
+    (
+        (10, 3, 192),
+        Grib2Parameter { parameter: "Hurricane Storm Surge", units: "m", abbrev: "SURGE" },
+    ),
+    (
+        (10, 3, 193),
+        Grib2Parameter { parameter: "Extra Tropical Storm Surge", units: "m", abbrev: "ETSRG" },
+    ),
+    (
+        (10, 3, 194),
+        Grib2Parameter {
+            parameter: "Ocean Surface Elevation Relative to Geoid",
+            units: "m",
+            abbrev: "ELEV",
+        },
+    ),
+    (
+        (10, 3, 195),
+        Grib2Parameter {
+            parameter: "Sea Surface Height Relative to Geoid",
+            units: "m",
+            abbrev: "SSHG",
+        },
+    ),
+    (
+        (10, 3, 196),
+        Grib2Parameter {
+            parameter: "Ocean Mixed Layer Potential Density (Reference 2000m)",
+            units: "kg m-3",
+            abbrev: "P2OMLT",
+        },
+    ),
+    (
+        (10, 3, 197),
+        Grib2Parameter { parameter: "Net Air-Ocean Heat Flux", units: "W m-2", abbrev: "AOHFLX" },
+    ),
+    (
+        (10, 3, 198),
+        Grib2Parameter { parameter: "Assimilative Heat Flux", units: "W m-2", abbrev: "ASHFL" },
+    ),
+    (
+        (10, 3, 199),
+        Grib2Parameter {
+            parameter: "Surface Temperature Trend",
+            units: "degree per day",
+            abbrev: "SSTT",
+        },
+    ),
+    (
+        (10, 3, 200),
+        Grib2Parameter {
+            parameter: "Surface Salinity Trend",
+            units: "psu per day",
+            abbrev: "SSST",
+        },
+    ),
+    ((10, 3, 201), Grib2Parameter { parameter: "Kinetic Energy", units: "J kg-1", abbrev: "KENG" }),
+    ((10, 3, 202), Grib2Parameter { parameter: "Salt Flux", units: "kg m-2s-1", abbrev: "SLTFL" }),
+    (
+        (10, 3, 203),
+        Grib2Parameter { parameter: "Heat Exchange Coefficient", units: "", abbrev: "LCH" },
+    ),
+    ((10, 3, 204), Grib2Parameter { parameter: "Freezing Spray", units: "", abbrev: "FRZSPR" }),
+    (
+        (10, 3, 205),
+        Grib2Parameter {
+            parameter: "Total Water Level Accounting for Tide, Wind and Waves",
+            units: "m",
+            abbrev: "TWLWAV",
+        },
+    ),
+    (
+        (10, 3, 206),
+        Grib2Parameter {
+            parameter: "Total Water Level Increase due to Waves",
+            units: "m",
+            abbrev: "RUNUP",
+        },
+    ),
+    (
+        (10, 3, 207),
+        Grib2Parameter {
+            parameter: "Mean Increase in Water Level due to Waves",
+            units: "m",
+            abbrev: "SETUP",
+        },
+    ),
+    (
+        (10, 3, 208),
+        Grib2Parameter {
+            parameter: "Time-varying Increase in Water Level due to Waves",
+            units: "m",
+            abbrev: "SWASH",
+        },
+    ),
+    (
+        (10, 3, 209),
+        Grib2Parameter {
+            parameter: "Total Water Level Above Dune Toe",
+            units: "m",
+            abbrev: "TWLDT",
+        },
+    ),
+    (
+        (10, 3, 210),
+        Grib2Parameter {
+            parameter: "Total Water Level Above Dune Crest",
+            units: "m",
+            abbrev: "TWLDC",
+        },
+    ),
+    (
+        (10, 3, 242),
+        Grib2Parameter {
+            parameter: "20% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG20",
+        },
+    ),
+    (
+        (10, 3, 243),
+        Grib2Parameter {
+            parameter: "30% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG30",
+        },
+    ),
+    (
+        (10, 3, 244),
+        Grib2Parameter {
+            parameter: "40% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG40",
+        },
+    ),
+    (
+        (10, 3, 245),
+        Grib2Parameter {
+            parameter: "50% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG50",
+        },
+    ),
+    (
+        (10, 3, 246),
+        Grib2Parameter {
+            parameter: "60% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG60",
+        },
+    ),
+    (
+        (10, 3, 247),
+        Grib2Parameter {
+            parameter: "70% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG70",
+        },
+    ),
+    (
+        (10, 3, 248),
+        Grib2Parameter {
+            parameter: "80% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG80",
+        },
+    ),
+    (
+        (10, 3, 249),
+        Grib2Parameter {
+            parameter: "90% Tropical Cyclone Storm Surge Exceedance",
+            units: "m",
+            abbrev: "TCSRG90",
+        },
+    ),
+    (
+        (10, 3, 250),
+        Grib2Parameter {
+            parameter: "Extra Tropical Storm Surge Combined Surge and Tide",
+            units: "m",
+            abbrev: "ETCWL",
+        },
+    ),
+    ((10, 3, 251), Grib2Parameter { parameter: "Tide", units: "m", abbrev: "TIDE" }),
+    (
+        (10, 3, 252),
+        Grib2Parameter {
+            parameter: "Erosion Occurrence Probability",
+            units: "%",
+            abbrev: "EROSNP",
+        },
+    ),
+    (
+        (10, 3, 253),
+        Grib2Parameter {
+            parameter: "Overwash Occurrence Probability",
+            units: "%",
+            abbrev: "OWASHP",
+        },
+    ),
+    ((10, 3, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (10, 4, 0),
+        Grib2Parameter { parameter: "Main Thermocline Depth", units: "m", abbrev: "MTHD" },
+    ),
+    (
+        (10, 4, 1),
+        Grib2Parameter { parameter: "Main Thermocline Anomaly", units: "m", abbrev: "MTHA" },
+    ),
+    (
+        (10, 4, 2),
+        Grib2Parameter { parameter: "Transient Thermocline Depth", units: "m", abbrev: "TTHDP" },
+    ),
+    ((10, 4, 3), Grib2Parameter { parameter: "Salinity", units: "kg kg-1", abbrev: "SALTY" }),
+    (
+        (10, 4, 4),
+        Grib2Parameter {
+            parameter: "Ocean Vertical Heat Diffusivity",
+            units: "m2 s-1",
+            abbrev: "OVHD",
+        },
+    ),
+    (
+        (10, 4, 5),
+        Grib2Parameter {
+            parameter: "Ocean Vertical Salt Diffusivity",
+            units: "m2 s-1",
+            abbrev: "OVSD",
+        },
+    ),
+    (
+        (10, 4, 6),
+        Grib2Parameter {
+            parameter: "Ocean Vertical Momentum Diffusivity",
+            units: "m2 s-1",
+            abbrev: "OVMD",
+        },
+    ),
+    ((10, 4, 7), Grib2Parameter { parameter: "Bathymetry", units: "m", abbrev: "BATHY" }),
+    (
+        (10, 4, 11),
+        Grib2Parameter {
+            parameter: "Shape Factor With Respect To Salinity Profile",
+            units: "",
+            abbrev: "SFSALP",
+        },
+    ),
+    (
+        (10, 4, 12),
+        Grib2Parameter {
+            parameter: "Shape Factor With Respect To Temperature Profile In Thermocline",
+            units: "",
+            abbrev: "SFTMPP",
+        },
+    ),
+    (
+        (10, 4, 13),
+        Grib2Parameter {
+            parameter: "Attenuation Coefficient Of Water With Respect to Solar Radiation",
+            units: "m-1",
+            abbrev: "ACWSRD",
+        },
+    ),
+    ((10, 4, 14), Grib2Parameter { parameter: "Water Depth", units: "m", abbrev: "WDEPTH" }),
+    ((10, 4, 15), Grib2Parameter { parameter: "Water Temperature", units: "K", abbrev: "WTMPSS" }),
+    (
+        (10, 4, 16),
+        Grib2Parameter { parameter: "Water Density (rho)", units: "kg m-3", abbrev: "WATERDEN" },
+    ),
+    (
+        (10, 4, 17),
+        Grib2Parameter {
+            parameter: "Water Density Anomaly (sigma)",
+            units: "kg m-3",
+            abbrev: "WATDENA",
+        },
+    ),
+    (
+        (10, 4, 18),
+        Grib2Parameter {
+            parameter: "Water Potential Temperature (theta)",
+            units: "K",
+            abbrev: "WATPTEMP",
+        },
+    ),
+    (
+        (10, 4, 19),
+        Grib2Parameter {
+            parameter: "Water Potential Density (rho theta)",
+            units: "kg m-3",
+            abbrev: "WATPDEN",
+        },
+    ),
+    (
+        (10, 4, 20),
+        Grib2Parameter {
+            parameter: "Water Potential Density Anomaly (sigma theta)",
+            units: "kg m-3",
+            abbrev: "WATPDENA",
+        },
+    ),
+    (
+        (10, 4, 21),
+        Grib2Parameter {
+            parameter: "Practical Salinity",
+            units: "psu (numeric)",
+            abbrev: "PRTSAL",
+        },
+    ),
+    (
+        (10, 4, 22),
+        Grib2Parameter {
+            parameter: "Water Column-integrated Heat Content",
+            units: "J m-2",
+            abbrev: "WCHEATC",
+        },
+    ),
+    (
+        (10, 4, 23),
+        Grib2Parameter { parameter: "Eastward Water Velocity", units: "m s-1", abbrev: "EASTWVEL" },
+    ),
+    (
+        (10, 4, 24),
+        Grib2Parameter {
+            parameter: "Northward Water Velocity",
+            units: "m s-1",
+            abbrev: "NRTHWVEL",
+        },
+    ),
+    (
+        (10, 4, 25),
+        Grib2Parameter {
+            parameter: "X-Component Water Velocity",
+            units: "m s-1",
+            abbrev: "XCOMPWV",
+        },
+    ),
+    (
+        (10, 4, 26),
+        Grib2Parameter {
+            parameter: "Y-Component Water Velocity",
+            units: "m s-1",
+            abbrev: "YCOMPWV",
+        },
+    ),
+    (
+        (10, 4, 27),
+        Grib2Parameter { parameter: "Upward Water Velocity", units: "m s-1", abbrev: "UPWWVEL" },
+    ),
+    (
+        (10, 4, 28),
+        Grib2Parameter {
+            parameter: "Vertical Eddy Diffusivity",
+            units: "m2 s-1",
+            abbrev: "VEDDYDIF",
+        },
+    ),
+    (
+        (10, 4, 29),
+        Grib2Parameter {
+            parameter: "Bottom Pressure Equivalent Height",
+            units: "m",
+            abbrev: "BPEH",
+        },
+    ),
+    (
+        (10, 4, 30),
+        Grib2Parameter {
+            parameter: "Fresh Water Flux into Sea Water from Rivers",
+            units: "kg m-2s-1",
+            abbrev: "FWFSW",
+        },
+    ),
+    (
+        (10, 4, 31),
+        Grib2Parameter {
+            parameter: "Fresh Water Flux Correction",
+            units: "kg m-2s-1",
+            abbrev: "FWFC",
+        },
+    ),
+    (
+        (10, 4, 32),
+        Grib2Parameter {
+            parameter: "Virtual Salt Flux into Sea Water",
+            units: "g kg-1 m-2s-1",
+            abbrev: "VSFSW",
+        },
+    ),
+    (
+        (10, 4, 33),
+        Grib2Parameter {
+            parameter: "Virtual Salt Flux Correction",
+            units: "g kg-1 m-2s-1",
+            abbrev: "VSFC",
+        },
+    ),
+    (
+        (10, 4, 34),
+        Grib2Parameter {
+            parameter: "Sea Water Temperature Tendency due to Newtonian Relaxation",
+            units: "K s-1",
+            abbrev: "SWTTNR",
+        },
+    ),
+    (
+        (10, 4, 35),
+        Grib2Parameter {
+            parameter: "Sea Water Salinity Tendency due to Newtonian Relaxation",
+            units: "g kg-1s-1",
+            abbrev: "SWSTNR",
+        },
+    ),
+    (
+        (10, 4, 36),
+        Grib2Parameter {
+            parameter: "Sea Water Temperature Tendency due to Parameterization",
+            units: "K s-1",
+            abbrev: "SWTTP",
+        },
+    ),
+    (
+        (10, 4, 37),
+        Grib2Parameter {
+            parameter: "Sea Water Salinity Tendency due to Parameterization",
+            units: "g kg-1s-1",
+            abbrev: "SWSTP",
+        },
+    ),
+    (
+        (10, 4, 38),
+        Grib2Parameter {
+            parameter: "Eastward Sea Water Velocity Tendency Due To Parameterization",
+            units: "m s-2",
+            abbrev: "ESWVP",
+        },
+    ),
+    (
+        (10, 4, 39),
+        Grib2Parameter {
+            parameter: "Northward Sea Water Velocity Tendency Due To Parameterization",
+            units: "m s-2",
+            abbrev: "NSWVP",
+        },
+    ),
+    (
+        (10, 4, 40),
+        Grib2Parameter {
+            parameter: "Sea Water Temperature Tendency Due to Direct Bias Correction",
+            units: "K s-1",
+            abbrev: "SWTTBC",
+        },
+    ),
+    (
+        (10, 4, 41),
+        Grib2Parameter {
+            parameter: "Sea Water Salinity Tendency due to Direct Bias Correction",
+            units: "g kg-1s-1",
+            abbrev: "SWSTBC",
+        },
+    ),
+    (
+        (10, 4, 42),
+        Grib2Parameter {
+            parameter: "Sea Water Meridional Volume Transport",
+            units: "m3 m-2 s-1",
+            abbrev: "SEAMVT",
+        },
+    ),
+    (
+        (10, 4, 43),
+        Grib2Parameter {
+            parameter: "Sea Water Zonal Volume Transport",
+            units: "m3 m-2 s-1",
+            abbrev: "SEAZVT",
+        },
+    ),
+    (
+        (10, 4, 44),
+        Grib2Parameter {
+            parameter: "Sea Water Column Integrated Meridional Volume Transport",
+            units: "m3 m-2 s-1",
+            abbrev: "SEACMVT",
+        },
+    ),
+    (
+        (10, 4, 45),
+        Grib2Parameter {
+            parameter: "Sea Water Column Integrated Zonal Volume Transport",
+            units: "m3 m-2 s-1",
+            abbrev: "SEACZVT",
+        },
+    ),
+    (
+        (10, 4, 46),
+        Grib2Parameter {
+            parameter: "Sea Water Meridional Mass Transport",
+            units: "kg m-2 s-1",
+            abbrev: "SEAMMT",
+        },
+    ),
+    (
+        (10, 4, 47),
+        Grib2Parameter {
+            parameter: "Sea Water Zonal Mass Transport",
+            units: "kg m-2 s-1",
+            abbrev: "SEAZMT",
+        },
+    ),
+    (
+        (10, 4, 48),
+        Grib2Parameter {
+            parameter: "Sea Water Column Integrated Meridional Mass Transport",
+            units: "kg m-2 s-1",
+            abbrev: "SEACMMT",
+        },
+    ),
+    (
+        (10, 4, 49),
+        Grib2Parameter {
+            parameter: "Sea Water Column Integrated Zonal Mass Transport",
+            units: "kg m-2 s-1",
+            abbrev: "SEACZMT",
+        },
+    ),
+    (
+        (10, 4, 50),
+        Grib2Parameter {
+            parameter: "Sea Water Column Integrated Practical Salinity",
+            units: "g kg-1 m",
+            abbrev: "SEACPSALT",
+        },
+    ),
+    (
+        (10, 4, 51),
+        Grib2Parameter {
+            parameter: "Sea Water Column Integrated Salinity",
+            units: "kg kg-1 m",
+            abbrev: "SEACSALT",
+        },
+    ),
+    ((10, 4, 192), Grib2Parameter { parameter: "3-D Temperature", units: "°C", abbrev: "WTMPC" }),
+    ((10, 4, 193), Grib2Parameter { parameter: "3-D Salinity", units: "psu", abbrev: "SALIN" }),
+    (
+        (10, 4, 194),
+        Grib2Parameter { parameter: "Barotropic Kinetic Energy", units: "J kg-1", abbrev: "BKENG" },
+    ),
+    (
+        (10, 4, 195),
+        Grib2Parameter {
+            parameter: "Geometric Depth Below Sea Surface",
+            units: "m",
+            abbrev: "DBSS",
+        },
+    ),
+    ((10, 4, 196), Grib2Parameter { parameter: "Interface Depths", units: "m", abbrev: "INTFD" }),
+    (
+        (10, 4, 197),
+        Grib2Parameter { parameter: "Ocean Heat Content", units: "J m-2", abbrev: "OHC" },
+    ),
+    ((10, 4, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (10, 191, 0),
+        Grib2Parameter {
+            parameter: "Seconds Prior To Initial Reference Time (Defined In Section 1)",
+            units: "s",
+            abbrev: "IRTSEC",
+        },
+    ),
+    (
+        (10, 191, 1),
+        Grib2Parameter {
+            parameter: "Meridional Overturning Stream Function",
+            units: "m3 s-1",
+            abbrev: "MOSF",
+        },
+    ),
+    (
+        (10, 191, 3),
+        Grib2Parameter { parameter: "Days Since Last Observation", units: "d", abbrev: "DSLOBSO" },
+    ),
+    (
+        (10, 191, 4),
+        Grib2Parameter {
+            parameter: "Barotropic Stream Function",
+            units: "m3 s-1",
+            abbrev: "BARDSF",
+        },
+    ),
+    ((10, 191, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (20, 0, 0),
+        Grib2Parameter {
+            parameter: "Universal Thermal Climate Index",
+            units: "K",
+            abbrev: "UTHCIDX",
+        },
+    ),
+    (
+        (20, 0, 1),
+        Grib2Parameter { parameter: "Mean Radiant Temperature", units: "K", abbrev: "MEANRTMP" },
+    ),
+    (
+        (20, 0, 2),
+        Grib2Parameter { parameter: "Wet-bulb Globe Temperature", units: "K", abbrev: "WETBGTMP" },
+    ),
+    ((20, 0, 3), Grib2Parameter { parameter: "Globe Temperature", units: "K", abbrev: "GLOBETMP" }),
+    ((20, 0, 4), Grib2Parameter { parameter: "Humidex", units: "K", abbrev: "HUMIDX" }),
+    (
+        (20, 0, 5),
+        Grib2Parameter { parameter: "Effective Temperature", units: "K", abbrev: "EFFTEMP" },
+    ),
+    (
+        (20, 0, 6),
+        Grib2Parameter {
+            parameter: "Normal Effective Temperature",
+            units: "K",
+            abbrev: "NOREFTMP",
+        },
+    ),
+    (
+        (20, 0, 7),
+        Grib2Parameter {
+            parameter: "Standard Effective Temperature",
+            units: "K",
+            abbrev: "STDEFTMP",
+        },
+    ),
+    (
+        (20, 0, 8),
+        Grib2Parameter {
+            parameter: "Physiological Equivalent Temperature",
+            units: "K",
+            abbrev: "PEQUTMP",
+        },
+    ),
+    ((20, 0, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (20, 1, 0),
+        Grib2Parameter { parameter: "Malaria Cases", units: "Fraction", abbrev: "MALACASE" },
+    ),
+    (
+        (20, 1, 1),
+        Grib2Parameter {
+            parameter: "Malaria Circumsporozoite Protein Rate",
+            units: "Fraction",
+            abbrev: "MACPRATE",
+        },
+    ),
+    (
+        (20, 1, 2),
+        Grib2Parameter {
+            parameter: "Plasmodium Falciparum Entomological Inoculation Rate",
+            units: "Bites per day per person",
+            abbrev: "PFEIRATE",
+        },
+    ),
+    (
+        (20, 1, 3),
+        Grib2Parameter {
+            parameter: "Human Bite Rate by Anopheles Vectors",
+            units: "Bites per day per person",
+            abbrev: "HBRATEAV",
+        },
+    ),
+    (
+        (20, 1, 4),
+        Grib2Parameter { parameter: "Malaria Immunity", units: "Fraction", abbrev: "MALAIMM" },
+    ),
+    (
+        (20, 1, 5),
+        Grib2Parameter {
+            parameter: "Falciparum Parasite Rates",
+            units: "Fraction",
+            abbrev: "FALPRATE",
+        },
+    ),
+    (
+        (20, 1, 6),
+        Grib2Parameter {
+            parameter: "Detectable Falciparum Parasite Ratio (after day 10)",
+            units: "Fraction",
+            abbrev: "DFPRATIO",
+        },
+    ),
+    (
+        (20, 1, 7),
+        Grib2Parameter {
+            parameter: "Anopheles Vector to Host Ratio",
+            units: "Fraction",
+            abbrev: "AVHRATIO",
+        },
+    ),
+    (
+        (20, 1, 8),
+        Grib2Parameter {
+            parameter: "Anopheles Vector Number",
+            units: "Number m-2",
+            abbrev: "AVECTNUM",
+        },
+    ),
+    (
+        (20, 1, 9),
+        Grib2Parameter {
+            parameter: "Fraction of Malarial Vector Reproductive Habitat",
+            units: "Fraction",
+            abbrev: "FMALVRH",
+        },
+    ),
+    ((20, 1, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (20, 2, 0),
+        Grib2Parameter { parameter: "Population Density", units: "Person m-2", abbrev: "POPDEN" },
+    ),
+    ((20, 2, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+    (
+        (20, 3, 0),
+        Grib2Parameter { parameter: "Renewable power capacity", units: "W", abbrev: "RENPCAP" },
+    ),
+    (
+        (20, 3, 1),
+        Grib2Parameter {
+            parameter: "Renewable power production rate",
+            units: "W",
+            abbrev: "RENPPROD",
+        },
+    ),
+    (
+        (20, 3, 2),
+        Grib2Parameter { parameter: "Wind power capacity", units: "W", abbrev: "WINDPCAP" },
+    ),
+    (
+        (20, 3, 3),
+        Grib2Parameter { parameter: "Wind power production rate", units: "W", abbrev: "WINDPPROD" },
+    ),
+    (
+        (20, 3, 4),
+        Grib2Parameter {
+            parameter: "Solar photovoltaic (PV) power capacity",
+            units: "W",
+            abbrev: "SPVPCAP",
+        },
+    ),
+    (
+        (20, 3, 5),
+        Grib2Parameter {
+            parameter: "Solar photovoltaic (PV) power production rate",
+            units: "W",
+            abbrev: "SPVPPROD",
+        },
+    ),
+    (
+        (20, 3, 6),
+        Grib2Parameter {
+            parameter: "Solar non-photovoltaic (PV) power capacity",
+            units: "W",
+            abbrev: "SNPVPCAP",
+        },
+    ),
+    (
+        (20, 3, 7),
+        Grib2Parameter {
+            parameter: "Solar non-photovoltaic (PV) power production rate",
+            units: "W",
+            abbrev: "SNPVPPROD",
+        },
+    ),
+    (
+        (20, 3, 8),
+        Grib2Parameter {
+            parameter: "Concentrated solar power (CSP) power capacity",
+            units: "W",
+            abbrev: "CSPPCAP",
+        },
+    ),
+    (
+        (20, 3, 9),
+        Grib2Parameter {
+            parameter: "Concentrated solar power (CSP) power production rate",
+            units: "W",
+            abbrev: "CSPPROD",
+        },
+    ),
+    ((20, 3, 255), Grib2Parameter { parameter: "Missing", units: "", abbrev: "" }),
+];
+
+const SURFACES: &[(u8, Grib2Surface)] = &[
+    (0, Grib2Surface { name: "Reserved", unit: "" }),
+    (1, Grib2Surface { name: "Ground or Water Surface", unit: "" }),
+    (2, Grib2Surface { name: "Cloud Base Level", unit: "" }),
+    (3, Grib2Surface { name: "Level of Cloud Tops", unit: "" }),
+    (4, Grib2Surface { name: "Level of 0°C Isotherm", unit: "°C" }),
+    (5, Grib2Surface { name: "Level of Adiabatic Condensation Lifted from the Surface", unit: "" }),
+    (6, Grib2Surface { name: "Maximum Wind Level", unit: "" }),
+    (7, Grib2Surface { name: "Tropopause", unit: "" }),
+    (8, Grib2Surface { name: "Nominal Top of the Atmosphere", unit: "" }),
+    (9, Grib2Surface { name: "Sea Bottom", unit: "" }),
+    (10, Grib2Surface { name: "Entire Atmosphere", unit: "" }),
+    (11, Grib2Surface { name: "Cumulonimbus Base (CB)", unit: "m" }),
+    (12, Grib2Surface { name: "Cumulonimbus Top (CT)", unit: "m" }),
+    (
+        13,
+        Grib2Surface {
+            name: "Lowest level where vertically integrated cloud cover exceeds the specified \
+                   percentage",
+            unit: "%",
+        },
+    ),
+    (14, Grib2Surface { name: "Level of free convection (LFC)", unit: "" }),
+    (15, Grib2Surface { name: "Convection condensation level (CCL)", unit: "" }),
+    (16, Grib2Surface { name: "Level of neutral buoyancy or equilibrium (LNB)", unit: "" }),
+    (
+        17,
+        Grib2Surface {
+            name: "Departure level of the most unstable parcel of air (MUDL)",
+            unit: "",
+        },
+    ),
+    (
+        18,
+        Grib2Surface {
+            name: "Departure level of a mixed layer parcel of air with specified layer depth",
+            unit: "Pa",
+        },
+    ),
+    (
+        19,
+        Grib2Surface {
+            name: "Lowest level where cloud cover exceeds the specified percentage",
+            unit: "%",
+        },
+    ),
+    (20, Grib2Surface { name: "Isothermal Level", unit: "K" }),
+    (
+        21,
+        Grib2Surface {
+            name: "Lowest level where mass density exceeds the specified value",
+            unit: "kg m-3",
+        },
+    ),
+    (
+        22,
+        Grib2Surface {
+            name: "Highest level where mass density exceeds the specified value",
+            unit: "kg m-3",
+        },
+    ),
+    (
+        23,
+        Grib2Surface {
+            name: "Lowest level where air concentration exceeds the specified value",
+            unit: "Bq m-3",
+        },
+    ),
+    (
+        24,
+        Grib2Surface {
+            name: "Highest level where air concentration exceeds the specified value",
+            unit: "Bq m-3",
+        },
+    ),
+    (
+        25,
+        Grib2Surface {
+            name: "Highest level where radar reflectivity exceeds the specified value",
+            unit: "dBZ",
+        },
+    ),
+    (26, Grib2Surface { name: "Convective cloud layer base", unit: "m" }),
+    (27, Grib2Surface { name: "Convective cloud layer top", unit: "m" }),
+    (28, Grib2Surface { name: "Reserved", unit: "" }),
+    (29, Grib2Surface { name: "Reserved", unit: "" }),
+    (30, Grib2Surface { name: "Specified radius from the centre of the Sun", unit: "m" }),
+    (31, Grib2Surface { name: "Solar photosphere", unit: "" }),
+    (32, Grib2Surface { name: "Ionospheric D-region level", unit: "" }),
+    (33, Grib2Surface { name: "Ionospheric E-region level", unit: "" }),
+    (34, Grib2Surface { name: "Ionospheric F1-region level", unit: "" }),
+    (35, Grib2Surface { name: "Ionospheric F2-region level", unit: "" }),
+    (100, Grib2Surface { name: "Isobaric Surface", unit: "Pa" }),
+    (101, Grib2Surface { name: "Mean Sea Level", unit: "" }),
+    (102, Grib2Surface { name: "Specific Altitude Above Mean Sea Level", unit: "m" }),
+    (103, Grib2Surface { name: "Specified Height Level Above Ground", unit: "m" }),
+    (104, Grib2Surface { name: "Sigma Level", unit: "" }),
+    (105, Grib2Surface { name: "Hybrid Level", unit: "" }),
+    (106, Grib2Surface { name: "Depth Below Land Surface", unit: "m" }),
+    (107, Grib2Surface { name: "Isentropic (theta) Level", unit: "K" }),
+    (
+        108,
+        Grib2Surface {
+            name: "Level at Specified Pressure Difference from Ground to Level",
+            unit: "Pa",
+        },
+    ),
+    (109, Grib2Surface { name: "Potential Vorticity Surface", unit: "K m² kg⁻¹ s⁻¹" }),
+    (110, Grib2Surface { name: "Reserved", unit: "" }),
+    (111, Grib2Surface { name: "Eta Level", unit: "" }),
+    (112, Grib2Surface { name: "Reserved", unit: "" }),
+    (113, Grib2Surface { name: "Logarithmic Hybrid Level", unit: "" }),
+    (114, Grib2Surface { name: "Snow Level", unit: "" }),
+    (115, Grib2Surface { name: "Sigma height level", unit: "" }),
+    (116, Grib2Surface { name: "Reserved", unit: "" }),
+    (117, Grib2Surface { name: "Mixed Layer Depth", unit: "m" }),
+    (118, Grib2Surface { name: "Hybrid Height Level", unit: "" }),
+    (119, Grib2Surface { name: "Hybrid Pressure Level", unit: "" }),
+    (150, Grib2Surface { name: "Generalized Vertical Height Coordinate", unit: "" }),
+    (151, Grib2Surface { name: "Soil level", unit: "" }),
+    (152, Grib2Surface { name: "Sea-ice level", unit: "" }),
+    (160, Grib2Surface { name: "Depth Below Sea Level", unit: "m" }),
+    (161, Grib2Surface { name: "Depth Below Water Surface", unit: "m" }),
+    (162, Grib2Surface { name: "Lake or River Bottom", unit: "" }),
+    (163, Grib2Surface { name: "Bottom Of Sediment Layer", unit: "" }),
+    (164, Grib2Surface { name: "Bottom Of Thermally Active Sediment Layer", unit: "" }),
+    (165, Grib2Surface { name: "Bottom Of Sediment Layer Penetrated By Thermal Wave", unit: "" }),
+    (166, Grib2Surface { name: "Mixing Layer", unit: "" }),
+    (167, Grib2Surface { name: "Bottom of Root Zone", unit: "" }),
+    (168, Grib2Surface { name: "Ocean Model Level", unit: "" }),
+    (
+        169,
+        Grib2Surface {
+            name: "Ocean level defined by water density (sigma-theta) difference from \
+                   near-surface to level",
+            unit: "kg m-3",
+        },
+    ),
+    (
+        170,
+        Grib2Surface {
+            name: "Ocean level defined by water potential temperature difference from \
+                   near-surface to level",
+            unit: "K",
+        },
+    ),
+    (
+        171,
+        Grib2Surface {
+            name: "Ocean level defined by vertical eddy diffusivity difference from near-surface \
+                   to level",
+            unit: "m² s-1",
+        },
+    ),
+    (
+        172,
+        Grib2Surface {
+            name: "Ocean level defined by water density (rho) difference from near-surface to \
+                   level",
+            unit: "m",
+        },
+    ),
+    (173, Grib2Surface { name: "Top of Snow Over Sea Ice on Sea, Lake or River", unit: "" }),
+    (174, Grib2Surface { name: "Top Surface of Ice on Sea, Lake or River", unit: "" }),
+    (175, Grib2Surface { name: "Top Surface of Ice, under Snow, on Sea, Lake or River", unit: "" }),
+    (176, Grib2Surface { name: "Bottom Surface (underside) Ice on Sea, Lake or River", unit: "" }),
+    (177, Grib2Surface { name: "Deep Soil (of indefinite depth)", unit: "" }),
+    (178, Grib2Surface { name: "Reserved", unit: "" }),
+    (179, Grib2Surface { name: "Top Surface of Glacier Ice and Inland Ice", unit: "" }),
+    (180, Grib2Surface { name: "Deep Inland or Glacier Ice (of indefinite depth)", unit: "" }),
+    (181, Grib2Surface { name: "Grid Tile Land Fraction as a Model Surface", unit: "" }),
+    (182, Grib2Surface { name: "Grid Tile Water Fraction as a Model Surface", unit: "" }),
+    (
+        183,
+        Grib2Surface {
+            name: "Grid Tile Ice Fraction on Sea, Lake or River as a Model Surface",
+            unit: "",
+        },
+    ),
+    (
+        184,
+        Grib2Surface {
+            name: "Grid Tile Glacier Ice and Inland Ice Fraction as a Model Surface",
+            unit: "",
+        },
+    ),
+    (185, Grib2Surface { name: "Roof Level", unit: "" }),
+    (186, Grib2Surface { name: "Wall level", unit: "" }),
+    (187, Grib2Surface { name: "Road Level", unit: "" }),
+    (188, Grib2Surface { name: "Melt pond Top Surface", unit: "" }),
+    (189, Grib2Surface { name: "Melt Pond Bottom Surface", unit: "" }),
+    (200, Grib2Surface { name: "Entire atmosphere (considered as a single layer)", unit: "" }),
+    (201, Grib2Surface { name: "Entire ocean (considered as a single layer)", unit: "" }),
+    (202, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (203, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (204, Grib2Surface { name: "Highest tropospheric freezing level", unit: "" }),
+    (205, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (206, Grib2Surface { name: "Grid scale cloud bottom level", unit: "" }),
+    (207, Grib2Surface { name: "Grid scale cloud top level", unit: "" }),
+    (208, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (209, Grib2Surface { name: "Boundary layer cloud bottom level", unit: "" }),
+    (210, Grib2Surface { name: "Boundary layer cloud top level", unit: "" }),
+    (211, Grib2Surface { name: "Boundary layer cloud layer", unit: "" }),
+    (212, Grib2Surface { name: "Low cloud bottom level", unit: "" }),
+    (213, Grib2Surface { name: "Low cloud top level", unit: "" }),
+    (214, Grib2Surface { name: "Low cloud layer", unit: "" }),
+    (215, Grib2Surface { name: "Cloud ceiling", unit: "" }),
+    (216, Grib2Surface { name: "Effective Layer Top Level", unit: "m" }),
+    (217, Grib2Surface { name: "Effective Layer Bottom Level", unit: "m" }),
+    (218, Grib2Surface { name: "Effective Layer", unit: "m" }),
+    (219, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (220, Grib2Surface { name: "Planetary Boundary Layer", unit: "" }),
+    (221, Grib2Surface { name: "Layer Between Two Hybrid Levels", unit: "" }),
+    (222, Grib2Surface { name: "Middle cloud bottom level", unit: "" }),
+    (223, Grib2Surface { name: "Middle cloud top level", unit: "" }),
+    (224, Grib2Surface { name: "Middle cloud layer", unit: "" }),
+    (225, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (226, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (227, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (228, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (229, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (230, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (231, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (232, Grib2Surface { name: "High cloud bottom level", unit: "" }),
+    (233, Grib2Surface { name: "High cloud top level", unit: "" }),
+    (234, Grib2Surface { name: "High cloud layer", unit: "" }),
+    (235, Grib2Surface { name: "Ocean Isotherm Level (1/10 °C)", unit: "°C" }),
+    (236, Grib2Surface { name: "Layer between two depths below ocean surface", unit: "" }),
+    (237, Grib2Surface { name: "Bottom of Ocean Mixed Layer", unit: "m" }),
+    (238, Grib2Surface { name: "Bottom of Ocean Isothermal Layer", unit: "m" }),
+    (239, Grib2Surface { name: "Layer Ocean Surface and 26°C Ocean Isothermal Level", unit: "" }),
+    (240, Grib2Surface { name: "Ocean Mixed Layer", unit: "" }),
+    (241, Grib2Surface { name: "Ordered Sequence of Data", unit: "" }),
+    (242, Grib2Surface { name: "Convective cloud bottom level", unit: "" }),
+    (243, Grib2Surface { name: "Convective cloud top level", unit: "" }),
+    (244, Grib2Surface { name: "Convective cloud layer", unit: "" }),
+    (245, Grib2Surface { name: "Lowest level of the wet bulb zero", unit: "" }),
+    (246, Grib2Surface { name: "Maximum equivalent potential temperature level", unit: "" }),
+    (247, Grib2Surface { name: "Equilibrium level", unit: "" }),
+    (248, Grib2Surface { name: "Shallow convective cloud bottom level", unit: "" }),
+    (249, Grib2Surface { name: "Shallow convective cloud top level", unit: "" }),
+    (250, Grib2Surface { name: "Reserved for Local Use", unit: "" }),
+    (251, Grib2Surface { name: "Deep convective cloud bottom level", unit: "" }),
+    (252, Grib2Surface { name: "Deep convective cloud top level", unit: "" }),
+    (253, Grib2Surface { name: "Lowest bottom level of supercooled liquid water layer", unit: "" }),
+    (254, Grib2Surface { name: "Highest top level of supercooled liquid water layer", unit: "" }),
+    (255, Grib2Surface { name: "Missing", unit: "" }),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_sorted<K: Ord, V>(table: &[(K, V)]) -> bool {
+        table.windows(2).all(|w| w[0].0 < w[1].0)
+    }
+
+    #[test]
+    fn test_tables() {
+        assert!(is_sorted(CENTERS) && is_sorted(CATEGORIES) && is_sorted(PARAMETERS));
+        assert!(is_sorted(SURFACES) && is_sorted(GRID_TEMPLATES) && is_sorted(PRODUCT_TEMPLATES));
+        assert_eq!(grib2_center(7), Some("US National Weather Service - NCEP (WMC)"));
+        assert_eq!(grib2_discipline(0), Some("Meteorological Products"));
+        assert_eq!(
+            grib2_parameter(0, 0, 0),
+            Some(Grib2Parameter { parameter: "Temperature", units: "K", abbrev: "TMP" })
+        );
+        assert_eq!(grib2_surface(100).map(|s| s.unit), Some("Pa"));
+        assert_eq!(grib2_parameter(0, 0, 254), None);
+    }
+}
@@ -0,0 +1,7 @@
+/// GTFS Realtime reader
+pub mod realtime;
+/// GTFS static schedule reader
+pub mod schedule;
+
+pub use realtime::*;
+pub use schedule::*;
@@ -0,0 +1,274 @@
+use super::{message, GTFSRealtimeEntitySelector};
+use alloc::{string::String, vec::Vec};
+use pbf::{ProtoRead, Protobuf};
+
+/// Cause of an alert.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeCause {
+    /// Unknown cause
+    #[default]
+    UnknownCause = 1,
+    /// Not machine-representable
+    OtherCause = 2,
+    /// Technical problem
+    TechnicalProblem = 3,
+    /// Public transit agency employees stopped working
+    Strike = 4,
+    /// People are blocking the streets
+    Demonstration = 5,
+    /// Accident
+    Accident = 6,
+    /// Holiday
+    Holiday = 7,
+    /// Weather
+    Weather = 8,
+    /// Maintenance
+    Maintenance = 9,
+    /// Construction
+    Construction = 10,
+    /// Police activity
+    PoliceActivity = 11,
+    /// Medical emergency
+    MedicalEmergency = 12,
+}
+impl From<u64> for GTFSRealtimeCause {
+    fn from(value: u64) -> Self {
+        match value {
+            2 => GTFSRealtimeCause::OtherCause,
+            3 => GTFSRealtimeCause::TechnicalProblem,
+            4 => GTFSRealtimeCause::Strike,
+            5 => GTFSRealtimeCause::Demonstration,
+            6 => GTFSRealtimeCause::Accident,
+            7 => GTFSRealtimeCause::Holiday,
+            8 => GTFSRealtimeCause::Weather,
+            9 => GTFSRealtimeCause::Maintenance,
+            10 => GTFSRealtimeCause::Construction,
+            11 => GTFSRealtimeCause::PoliceActivity,
+            12 => GTFSRealtimeCause::MedicalEmergency,
+            _ => GTFSRealtimeCause::UnknownCause,
+        }
+    }
+}
+
+/// The effect of an alert's problem on the affected entity.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeEffect {
+    /// No service
+    NoService = 1,
+    /// Reduced service
+    ReducedService = 2,
+    /// Significant delays (insignificant delays aren't reported)
+    SignificantDelays = 3,
+    /// Detour
+    Detour = 4,
+    /// Additional service
+    AdditionalService = 5,
+    /// Modified service
+    ModifiedService = 6,
+    /// Other effect
+    OtherEffect = 7,
+    /// Unknown effect
+    #[default]
+    UnknownEffect = 8,
+    /// Stop moved
+    StopMoved = 9,
+    /// No effect
+    NoEffect = 10,
+    /// Accessibility issue
+    AccessibilityIssue = 11,
+}
+impl From<u64> for GTFSRealtimeEffect {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => GTFSRealtimeEffect::NoService,
+            2 => GTFSRealtimeEffect::ReducedService,
+            3 => GTFSRealtimeEffect::SignificantDelays,
+            4 => GTFSRealtimeEffect::Detour,
+            5 => GTFSRealtimeEffect::AdditionalService,
+            6 => GTFSRealtimeEffect::ModifiedService,
+            7 => GTFSRealtimeEffect::OtherEffect,
+            9 => GTFSRealtimeEffect::StopMoved,
+            10 => GTFSRealtimeEffect::NoEffect,
+            11 => GTFSRealtimeEffect::AccessibilityIssue,
+            _ => GTFSRealtimeEffect::UnknownEffect,
+        }
+    }
+}
+
+/// Severity of an alert.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeSeverityLevel {
+    /// Unknown severity
+    #[default]
+    UnknownSeverity = 1,
+    /// Info
+    Info = 2,
+    /// Warning
+    Warning = 3,
+    /// Severe
+    Severe = 4,
+}
+impl From<u64> for GTFSRealtimeSeverityLevel {
+    fn from(value: u64) -> Self {
+        match value {
+            2 => GTFSRealtimeSeverityLevel::Info,
+            3 => GTFSRealtimeSeverityLevel::Warning,
+            4 => GTFSRealtimeSeverityLevel::Severe,
+            _ => GTFSRealtimeSeverityLevel::UnknownSeverity,
+        }
+    }
+}
+
+/// An alert, indicating some sort of incident in the public transit network.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeAlert {
+    /// Times when the alert should be shown to the user. If empty, the alert is shown as long as
+    /// it appears in the feed.
+    pub active_periods: Vec<GTFSRealtimeTimeRange>,
+    /// Entities whose users should be notified of this alert.
+    pub informed_entities: Vec<GTFSRealtimeEntitySelector>,
+    /// Cause of this alert.
+    pub cause: GTFSRealtimeCause,
+    /// The effect of this problem on the affected entity.
+    pub effect: GTFSRealtimeEffect,
+    /// The URL which provides additional information about the alert.
+    pub url: Option<GTFSRealtimeTranslatedString>,
+    /// Alert header. Contains a short summary of the alert text as plain-text.
+    pub header_text: Option<GTFSRealtimeTranslatedString>,
+    /// Full description for the alert as plain-text.
+    pub description_text: Option<GTFSRealtimeTranslatedString>,
+    /// The text-to-speech version of `header_text`.
+    pub tts_header_text: Option<GTFSRealtimeTranslatedString>,
+    /// The text-to-speech version of `description_text`.
+    pub tts_description_text: Option<GTFSRealtimeTranslatedString>,
+    /// Severity of this alert.
+    pub severity_level: GTFSRealtimeSeverityLevel,
+    /// Image displayed along the alert text to explain the alert visually.
+    pub image: Option<GTFSRealtimeTranslatedImage>,
+    /// Text describing the appearance of the image, e.g. for accessibility.
+    pub image_alternative_text: Option<GTFSRealtimeTranslatedString>,
+    /// Agency specific description of the cause of the alert.
+    pub cause_detail: Option<GTFSRealtimeTranslatedString>,
+    /// Agency specific description of the effect of the alert.
+    pub effect_detail: Option<GTFSRealtimeTranslatedString>,
+}
+impl ProtoRead for GTFSRealtimeAlert {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.active_periods.push(message(pb)),
+            5 => self.informed_entities.push(message(pb)),
+            6 => self.cause = pb.read_varint::<u64>().into(),
+            7 => self.effect = pb.read_varint::<u64>().into(),
+            8 => self.url = Some(message(pb)),
+            10 => self.header_text = Some(message(pb)),
+            11 => self.description_text = Some(message(pb)),
+            12 => self.tts_header_text = Some(message(pb)),
+            13 => self.tts_description_text = Some(message(pb)),
+            14 => self.severity_level = pb.read_varint::<u64>().into(),
+            15 => self.image = Some(message(pb)),
+            16 => self.image_alternative_text = Some(message(pb)),
+            17 => self.cause_detail = Some(message(pb)),
+            18 => self.effect_detail = Some(message(pb)),
+            _ => {}
+        }
+    }
+}
+
+/// A time interval. The interval is active at time `t` if `start <= t < end`.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GTFSRealtimeTimeRange {
+    /// Start time in POSIX seconds. If missing, the interval starts at minus infinity.
+    pub start: Option<u64>,
+    /// End time in POSIX seconds. If missing, the interval ends at plus infinity.
+    pub end: Option<u64>,
+}
+impl ProtoRead for GTFSRealtimeTimeRange {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.start = Some(pb.read_varint::<u64>()),
+            2 => self.end = Some(pb.read_varint::<u64>()),
+            _ => {}
+        }
+    }
+}
+
+/// An internationalized message containing per-language versions of a snippet of text or a URL.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeTranslatedString {
+    /// At least one translation must be provided.
+    pub translations: Vec<GTFSRealtimeTranslation>,
+}
+impl GTFSRealtimeTranslatedString {
+    /// Pick the text of a language: the first translation matching the language, else the first
+    /// translation without a language, else the first translation.
+    pub fn text(&self, language: Option<&str>) -> Option<&str> {
+        let find = |language: Option<&str>| {
+            self.translations.iter().find(|translation| translation.language.as_deref() == language)
+        };
+        language
+            .and_then(|language| find(Some(language)))
+            .or_else(|| find(None))
+            .or(self.translations.first())
+            .map(|translation| translation.text.as_str())
+    }
+}
+impl ProtoRead for GTFSRealtimeTranslatedString {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        if tag == 1 {
+            self.translations.push(message(pb));
+        }
+    }
+}
+
+/// A translation of a [`GTFSRealtimeTranslatedString`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeTranslation {
+    /// A UTF-8 string containing the message.
+    pub text: String,
+    /// BCP-47 language code. Omitted if the language is unknown.
+    pub language: Option<String>,
+}
+impl ProtoRead for GTFSRealtimeTranslation {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.text = pb.read_string(),
+            2 => self.language = Some(pb.read_string()),
+            _ => {}
+        }
+    }
+}
+
+/// An internationalized image containing per-language versions of a URL linking to an image.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeTranslatedImage {
+    /// At least one localized image must be provided.
+    pub localized_images: Vec<GTFSRealtimeLocalizedImage>,
+}
+impl ProtoRead for GTFSRealtimeTranslatedImage {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        if tag == 1 {
+            self.localized_images.push(message(pb));
+        }
+    }
+}
+
+/// A localized image of a [`GTFSRealtimeTranslatedImage`]
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeLocalizedImage {
+    /// String containing an URL linking to an image.
+    pub url: String,
+    /// IANA media type of the image, e.g. `image/png`.
+    pub media_type: String,
+    /// BCP-47 language code. Omitted if the language is unknown.
+    pub language: Option<String>,
+}
+impl ProtoRead for GTFSRealtimeLocalizedImage {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.url = pb.read_string(),
+            2 => self.media_type = pb.read_string(),
+            3 => self.language = Some(pb.read_string()),
+            _ => {}
+        }
+    }
+}
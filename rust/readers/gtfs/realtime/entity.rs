@@ -0,0 +1,167 @@
+use super::{
+    message, GTFSRealtimeAlert, GTFSRealtimeTranslatedString, GTFSRealtimeTripDescriptor,
+    GTFSRealtimeTripUpdate, GTFSRealtimeVehiclePosition,
+};
+use alloc::string::String;
+use pbf::{ProtoRead, Protobuf};
+
+/// A definition (or update) of an entity in the transit feed. At least one of the trip update,
+/// vehicle, alert, shape or stop is present unless the entity is being deleted.
+///
+/// NOTE: Trip modifications are skipped.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeEntity {
+    /// The id of the entity, unique within a feed message. Used only to provide incrementality
+    /// support.
+    pub id: String,
+    /// Whether this entity is to be deleted. Relevant only for incremental fetches.
+    pub is_deleted: bool,
+    /// Realtime update of the progress of a vehicle along a trip.
+    pub trip_update: Option<GTFSRealtimeTripUpdate>,
+    /// Realtime positioning information for a given vehicle.
+    pub vehicle: Option<GTFSRealtimeVehiclePosition>,
+    /// An incident in the public transit network.
+    pub alert: Option<GTFSRealtimeAlert>,
+    /// A path a vehicle takes that isn't part of the static GTFS, e.g. a detour.
+    pub shape: Option<GTFSRealtimeShape>,
+    /// A new or updated stop.
+    pub stop: Option<GTFSRealtimeStop>,
+}
+impl ProtoRead for GTFSRealtimeEntity {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.id = pb.read_string(),
+            2 => self.is_deleted = pb.read_varint::<bool>(),
+            3 => self.trip_update = Some(message(pb)),
+            4 => self.vehicle = Some(message(pb)),
+            5 => self.alert = Some(message(pb)),
+            6 => self.shape = Some(message(pb)),
+            7 => self.stop = Some(message(pb)),
+            _ => {}
+        }
+    }
+}
+
+/// A selector for an entity in a GTFS feed. If several specifiers are given, the matching has to
+/// apply to all of them.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeEntitySelector {
+    /// The agency (`agency.txt` `agency_id`)
+    pub agency_id: Option<String>,
+    /// The route (`routes.txt` `route_id`)
+    pub route_id: Option<String>,
+    /// The route type (`routes.txt` `route_type`)
+    pub route_type: Option<i32>,
+    /// The trip instance
+    pub trip: Option<GTFSRealtimeTripDescriptor>,
+    /// The stop (`stops.txt` `stop_id`)
+    pub stop_id: Option<String>,
+    /// The direction (`trips.txt` `direction_id`). The route id must also be provided.
+    pub direction_id: Option<u32>,
+}
+impl ProtoRead for GTFSRealtimeEntitySelector {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.agency_id = Some(pb.read_string()),
+            2 => self.route_id = Some(pb.read_string()),
+            3 => self.route_type = Some(pb.read_varint::<i32>()),
+            4 => self.trip = Some(message(pb)),
+            5 => self.stop_id = Some(pb.read_string()),
+            6 => self.direction_id = Some(pb.read_varint::<u32>()),
+            _ => {}
+        }
+    }
+}
+
+/// The physical path a vehicle takes when it's not part of the static GTFS, such as for a detour.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeShape {
+    /// Identifier of the shape. Differs from any `shape_id` of the static GTFS.
+    pub shape_id: Option<String>,
+    /// Encoded polyline representation of the shape with at least two points.
+    pub encoded_polyline: Option<String>,
+}
+impl ProtoRead for GTFSRealtimeShape {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.shape_id = Some(pb.read_string()),
+            2 => self.encoded_polyline = Some(pb.read_string()),
+            _ => {}
+        }
+    }
+}
+
+/// Wheelchair boarding of a stop
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeWheelchairBoarding {
+    /// No information
+    #[default]
+    Unknown = 0,
+    /// Wheelchair boarding is possible
+    Available = 1,
+    /// Wheelchair boarding isn't possible
+    NotAvailable = 2,
+}
+impl From<u64> for GTFSRealtimeWheelchairBoarding {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => GTFSRealtimeWheelchairBoarding::Available,
+            2 => GTFSRealtimeWheelchairBoarding::NotAvailable,
+            _ => GTFSRealtimeWheelchairBoarding::Unknown,
+        }
+    }
+}
+
+/// A new or updated stop, following the fields of `stops.txt`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeStop {
+    /// Identifies the stop
+    pub stop_id: Option<String>,
+    /// Short text or a number that identifies the stop for riders
+    pub stop_code: Option<GTFSRealtimeTranslatedString>,
+    /// Name of the stop
+    pub stop_name: Option<GTFSRealtimeTranslatedString>,
+    /// Readable version of the name for text-to-speech systems
+    pub tts_stop_name: Option<GTFSRealtimeTranslatedString>,
+    /// Description of the stop
+    pub stop_desc: Option<GTFSRealtimeTranslatedString>,
+    /// Latitude of the stop
+    pub stop_lat: Option<f32>,
+    /// Longitude of the stop
+    pub stop_lon: Option<f32>,
+    /// The fare zone of the stop
+    pub zone_id: Option<String>,
+    /// URL of a web page about the stop
+    pub stop_url: Option<GTFSRealtimeTranslatedString>,
+    /// The parent station
+    pub parent_station: Option<String>,
+    /// Timezone of the stop
+    pub stop_timezone: Option<String>,
+    /// Wheelchair boarding of the stop
+    pub wheelchair_boarding: GTFSRealtimeWheelchairBoarding,
+    /// Level of the stop
+    pub level_id: Option<String>,
+    /// Platform identifier of the stop
+    pub platform_code: Option<GTFSRealtimeTranslatedString>,
+}
+impl ProtoRead for GTFSRealtimeStop {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.stop_id = Some(pb.read_string()),
+            2 => self.stop_code = Some(message(pb)),
+            3 => self.stop_name = Some(message(pb)),
+            4 => self.tts_stop_name = Some(message(pb)),
+            5 => self.stop_desc = Some(message(pb)),
+            6 => self.stop_lat = Some(pb.read_fixed::<f32>()),
+            7 => self.stop_lon = Some(pb.read_fixed::<f32>()),
+            8 => self.zone_id = Some(pb.read_string()),
+            9 => self.stop_url = Some(message(pb)),
+            11 => self.parent_station = Some(pb.read_string()),
+            12 => self.stop_timezone = Some(pb.read_string()),
+            13 => self.wheelchair_boarding = pb.read_varint::<u64>().into(),
+            14 => self.level_id = Some(pb.read_string()),
+            15 => self.platform_code = Some(message(pb)),
+            _ => {}
+        }
+    }
+}
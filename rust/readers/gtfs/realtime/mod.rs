@@ -0,0 +1,420 @@
+/// Alert tools
+pub mod alert;
+/// Feed entity, entity selector, shape and stop tools
+pub mod entity;
+/// Trip descriptor and trip update tools
+pub mod trip;
+/// Vehicle position tools
+pub mod vehicle;
+/// Protobuf wire format schemas of the feed messages
+mod wire;
+
+pub use alert::*;
+pub use entity::*;
+pub use trip::*;
+pub use vehicle::*;
+use wire::*;
+
+use crate::{
+    geometry::{VectorFeature, VectorPoint},
+    readers::{wkt::geometry::point_geometry, FeatureIterator},
+};
+use alloc::{format, string::String, vec::Vec};
+use pbf::{ProtoRead, Protobuf};
+use s2json::{PrimitiveValue, Properties, ValueType};
+
+/// Errors that can occur while decoding a GTFS Realtime feed
+#[derive(Debug, Clone, PartialEq)]
+pub enum GTFSRealtimeError {
+    /// The `FeedMessage` is truncated, or a field has the wrong wire type or isn't valid UTF-8
+    InvalidMessage,
+}
+
+/// Determines whether the current fetch is incremental.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeIncrementality {
+    /// The feed contains the full dataset
+    #[default]
+    FullDataset = 0,
+    /// The feed only contains changes (unsupported by the spec, behavior is unspecified)
+    Differential = 1,
+}
+impl From<u64> for GTFSRealtimeIncrementality {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => GTFSRealtimeIncrementality::Differential,
+            _ => GTFSRealtimeIncrementality::FullDataset,
+        }
+    }
+}
+
+/// Metadata about a feed, included in feed messages.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeHeader {
+    /// Version of the feed specification, e.g. "2.0" or "1.0".
+    pub gtfs_realtime_version: String,
+    /// Determines whether the current fetch is incremental.
+    pub incrementality: GTFSRealtimeIncrementality,
+    /// The moment the content of this feed was created (server time), in POSIX seconds.
+    pub timestamp: Option<u64>,
+    /// Matches the `feed_info.feed_version` of the static GTFS feed the realtime data is based on.
+    pub feed_version: Option<String>,
+}
+impl ProtoRead for GTFSRealtimeHeader {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.gtfs_realtime_version = pb.read_string(),
+            2 => self.incrementality = pb.read_varint::<u64>().into(),
+            3 => self.timestamp = Some(pb.read_varint::<u64>()),
+            4 => self.feed_version = Some(pb.read_string()),
+            _ => {}
+        }
+    }
+}
+
+/// # GTFS Realtime Reader
+///
+/// ## Description
+/// Decodes a GTFS Realtime `FeedMessage` protobuf into its header and entities: trip updates,
+/// vehicle positions, alerts, shapes and stops. Entity ids, trips, routes and stops refer to the
+/// static GTFS feed (see [`crate::readers::GTFSScheduleReader`]).
+///
+/// Implements the [`FeatureIterator`] through [`GTFSRealtimeReader::iter`], yielding a Point
+/// feature for each vehicle position. The properties store the entity's `id` and the vehicle's
+/// trip (`tripId`, `routeId`, `directionId`, `startTime`, `startDate`), descriptor (`vehicleId`,
+/// `vehicleLabel`, `licensePlate`), status (`currentStopSequence`, `stopId`, `currentStatus`,
+/// `timestamp`, `congestionLevel`, `occupancyStatus`, `occupancyPercentage`) and motion
+/// (`bearing`, `odometer`, `speed`). Enumerations are stored as their protobuf value.
+///
+/// NOTE: Unknown fields are skipped. Times are POSIX seconds. The feed is checked before it's
+/// decoded, so a truncated or malformed feed returns an error.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{FeatureIterator, GTFSRealtimeReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/gtfs/fixtures/vehicle_position.pb");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let reader = GTFSRealtimeReader::new(data).unwrap();
+/// assert_eq!(reader.header.gtfs_realtime_version, "1.0");
+/// let features: Vec<_> = reader.iter().collect();
+/// assert_eq!(features.len(), 1);
+/// ```
+///
+/// ## Links
+/// - <https://gtfs.org/documentation/realtime/reference/>
+/// - <https://github.com/google/transit/blob/master/gtfs-realtime/proto/gtfs-realtime.proto>
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeReader {
+    /// Metadata about the feed
+    pub header: GTFSRealtimeHeader,
+    /// The contents of the feed
+    pub entities: Vec<GTFSRealtimeEntity>,
+}
+impl GTFSRealtimeReader {
+    /// Decode a `FeedMessage`
+    pub fn new(data: Vec<u8>) -> Result<Self, GTFSRealtimeError> {
+        if !is_valid_message(&data, feed_message_schema) {
+            return Err(GTFSRealtimeError::InvalidMessage);
+        }
+        let mut reader = GTFSRealtimeReader::default();
+        let mut pbf = Protobuf::from(data);
+        pbf.read_fields(&mut reader, None);
+        Ok(reader)
+    }
+
+    /// The trip updates of the feed
+    pub fn trip_updates(&self) -> impl Iterator<Item = &GTFSRealtimeTripUpdate> {
+        self.entities.iter().filter_map(|entity| entity.trip_update.as_ref())
+    }
+
+    /// The alerts of the feed
+    pub fn alerts(&self) -> impl Iterator<Item = &GTFSRealtimeAlert> {
+        self.entities.iter().filter_map(|entity| entity.alert.as_ref())
+    }
+
+    /// Iterate the vehicle positions as Point features
+    pub fn iter(&self) -> GTFSRealtimeIterator<'_> {
+        GTFSRealtimeIterator { entities: self.entities.iter() }
+    }
+}
+impl ProtoRead for GTFSRealtimeReader {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.header = message(pb),
+            2 => self.entities.push(message(pb)),
+            _ => {}
+        }
+    }
+}
+
+/// An iterator over the vehicle position features of a [`GTFSRealtimeReader`]
+#[derive(Debug)]
+pub struct GTFSRealtimeIterator<'a> {
+    entities: core::slice::Iter<'a, GTFSRealtimeEntity>,
+}
+impl Iterator for GTFSRealtimeIterator<'_> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.entities.by_ref().find_map(vehicle_feature)
+    }
+}
+impl FeatureIterator for GTFSRealtimeIterator<'_> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Convert an entity's vehicle position to a Point feature
+fn vehicle_feature(entity: &GTFSRealtimeEntity) -> Option<VectorFeature> {
+    let vehicle = entity.vehicle.as_ref()?;
+    let position = vehicle.position?;
+    let mut properties = Properties::new();
+    let mut string = |key: &str, value: Option<&String>| {
+        if let Some(value) = value {
+            properties
+                .insert(key.into(), ValueType::Primitive(PrimitiveValue::String(value.clone())));
+        }
+    };
+    string("id", Some(&entity.id));
+    if let Some(trip) = &vehicle.trip {
+        string("tripId", trip.trip_id.as_ref());
+        string("routeId", trip.route_id.as_ref());
+        string("startTime", trip.start_time.as_ref());
+        let date = trip.start_date.map(|d| format!("{:04}{:02}{:02}", d.year, d.month, d.day));
+        string("startDate", date.as_ref());
+    }
+    if let Some(descriptor) = &vehicle.vehicle {
+        string("vehicleId", descriptor.id.as_ref());
+        string("vehicleLabel", descriptor.label.as_ref());
+        string("licensePlate", descriptor.license_plate.as_ref());
+    }
+    string("stopId", vehicle.stop_id.as_ref());
+    let numbers = [
+        ("directionId", vehicle.trip.as_ref().and_then(|trip| trip.direction_id.map(u64::from))),
+        ("currentStopSequence", vehicle.current_stop_sequence.map(u64::from)),
+        ("currentStatus", Some(vehicle.current_status as u64)),
+        ("timestamp", vehicle.timestamp),
+        ("congestionLevel", vehicle.congestion_level.map(|level| level as u64)),
+        ("occupancyStatus", vehicle.occupancy_status.map(|status| status as u64)),
+        ("occupancyPercentage", vehicle.occupancy_percentage.map(u64::from)),
+    ];
+    for (key, value) in numbers {
+        if let Some(value) = value {
+            properties.insert(key.into(), ValueType::Primitive(PrimitiveValue::U64(value)));
+        }
+    }
+    let motion = [
+        ("bearing", position.bearing.map(f64::from)),
+        ("odometer", position.odometer),
+        ("speed", position.speed.map(f64::from)),
+    ];
+    for (key, value) in motion {
+        if let Some(value) = value {
+            properties.insert(key.into(), ValueType::Primitive(PrimitiveValue::F64(value)));
+        }
+    }
+    let point = VectorPoint::new(position.longitude.into(), position.latitude.into(), None, None);
+
+    Some(VectorFeature::new_wm(None, properties, point_geometry(point), None))
+}
+
+/// Read a length-delimited sub-message
+pub(crate) fn message<T: ProtoRead + Default>(pb: &mut Protobuf) -> T {
+    let mut t = T::default();
+    pb.read_message(&mut t);
+    t
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{geometry::VectorGeometry, readers::GTFSDate};
+    use alloc::vec;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/gtfs/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    /// Encode a sub-message from its fields
+    fn encode(write: impl Fn(&mut Protobuf)) -> Vec<u8> {
+        let mut pb = Protobuf::new();
+        write(&mut pb);
+        pb.take()
+    }
+
+    #[test]
+    fn test_vehicle_position() {
+        let reader = GTFSRealtimeReader::new(fixture("vehicle_position.pb")).unwrap();
+        assert_eq!(reader.header.gtfs_realtime_version, "1.0");
+        assert_eq!(reader.header.incrementality, GTFSRealtimeIncrementality::FullDataset);
+        assert_eq!(reader.entities.len(), 1);
+        let entity = &reader.entities[0];
+        assert_eq!(entity.id, "1");
+        let vehicle = entity.vehicle.as_ref().unwrap();
+        assert_eq!(vehicle.trip.as_ref().unwrap().trip_id.as_deref(), Some("t0"));
+        assert_eq!(vehicle.vehicle.as_ref().unwrap().id.as_deref(), Some("1"));
+        assert_eq!(vehicle.current_status, GTFSRealtimeVehicleStopStatus::InTransitTo);
+        assert_eq!(reader.trip_updates().count(), 0);
+
+        let features: Vec<VectorFeature> = reader.iter().collect();
+        assert_eq!(features.len(), 1);
+        let VectorGeometry::Point(point) = &features[0].geometry else {
+            panic!("expected a Point");
+        };
+        assert_eq!((point.coordinates.x, point.coordinates.y), (-122., 47.));
+        let property = |key: &str| features[0].properties.get(key).cloned();
+        assert_eq!(
+            property("tripId"),
+            Some(ValueType::Primitive(PrimitiveValue::String("t0".into())))
+        );
+        assert_eq!(
+            property("vehicleId"),
+            Some(ValueType::Primitive(PrimitiveValue::String("1".into())))
+        );
+        assert_eq!(property("currentStatus"), Some(ValueType::Primitive(PrimitiveValue::U64(2))));
+        assert_eq!(property("speed"), None);
+    }
+
+    #[test]
+    fn test_trip_update_and_alert() {
+        let trip = encode(|pb| {
+            pb.write_string_field(1, "trip-1");
+            pb.write_string_field(3, "20240131");
+            pb.write_varint_field(4, 3_u64);
+            pb.write_string_field(5, "route-1");
+        });
+        let arrival = encode(|pb| {
+            pb.write_varint_field(1, -90_i32);
+            pb.write_varint_field(2, 1_706_700_000_i64);
+        });
+        let stop_time_update = encode(|pb| {
+            pb.write_varint_field(1, 4_u32);
+            pb.write_bytes_field(2, &arrival);
+            pb.write_string_field(4, "stop-4");
+            pb.write_varint_field(5, 1_u64);
+            pb.write_bytes_field(6, &encode(|pb| pb.write_string_field(1, "stop-4b")));
+        });
+        let trip_update = encode(|pb| {
+            pb.write_bytes_field(1, &trip);
+            pb.write_bytes_field(2, &stop_time_update);
+            pb.write_varint_field(5, 120_i32);
+        });
+        let translation = |text: &str, language: &str| {
+            encode(|pb| {
+                pb.write_string_field(1, text);
+                pb.write_string_field(2, language);
+            })
+        };
+        let header_text = encode(|pb| {
+            pb.write_bytes_field(1, &translation("Umleitung", "de"));
+            pb.write_bytes_field(1, &translation("Detour", "en"));
+        });
+        let alert = encode(|pb| {
+            pb.write_bytes_field(1, &encode(|pb| pb.write_varint_field(1, 100_u64)));
+            pb.write_bytes_field(5, &encode(|pb| pb.write_string_field(2, "route-1")));
+            pb.write_varint_field(6, 10_u64);
+            pb.write_varint_field(7, 4_u64);
+            pb.write_bytes_field(10, &header_text);
+            pb.write_varint_field(14, 3_u64);
+            pb.write_varint_field(99, 1_u64);
+        });
+        let data = encode(|pb| {
+            pb.write_bytes_field(1, &encode(|pb| pb.write_string_field(1, "2.0")));
+            pb.write_bytes_field(
+                2,
+                &encode(|pb| {
+                    pb.write_string_field(1, "a");
+                    pb.write_bytes_field(3, &trip_update);
+                }),
+            );
+            pb.write_bytes_field(
+                2,
+                &encode(|pb| {
+                    pb.write_string_field(1, "b");
+                    pb.write_bytes_field(5, &alert);
+                }),
+            );
+        });
+
+        let reader = GTFSRealtimeReader::new(data).unwrap();
+        assert_eq!(reader.header.gtfs_realtime_version, "2.0");
+        assert_eq!(reader.iter().count(), 0);
+
+        let update = reader.trip_updates().next().unwrap();
+        assert_eq!(update.trip.trip_id.as_deref(), Some("trip-1"));
+        assert_eq!(update.trip.route_id.as_deref(), Some("route-1"));
+        assert_eq!(update.trip.start_date, Some(GTFSDate { year: 2024, month: 1, day: 31 }));
+        assert_eq!(
+            update.trip.schedule_relationship,
+            Some(GTFSRealtimeScheduleRelationship::Canceled)
+        );
+        assert_eq!(update.delay, Some(120));
+        let stop_time = &update.stop_time_updates[0];
+        assert_eq!(stop_time.stop_sequence, Some(4));
+        assert_eq!(stop_time.stop_id.as_deref(), Some("stop-4"));
+        assert_eq!(stop_time.assigned_stop_id.as_deref(), Some("stop-4b"));
+        assert_eq!(
+            stop_time.schedule_relationship,
+            GTFSRealtimeStopTimeScheduleRelationship::Skipped
+        );
+        let arrival = stop_time.arrival.unwrap();
+        assert_eq!((arrival.delay, arrival.time), (Some(-90), Some(1_706_700_000)));
+
+        let alert = reader.alerts().next().unwrap();
+        assert_eq!(alert.active_periods, [GTFSRealtimeTimeRange { start: Some(100), end: None }]);
+        assert_eq!(alert.informed_entities[0].route_id.as_deref(), Some("route-1"));
+        assert_eq!(alert.cause, GTFSRealtimeCause::Construction);
+        assert_eq!(alert.effect, GTFSRealtimeEffect::Detour);
+        assert_eq!(alert.severity_level, GTFSRealtimeSeverityLevel::Warning);
+        let header_text = alert.header_text.as_ref().unwrap();
+        assert_eq!(header_text.text(Some("en")), Some("Detour"));
+        assert_eq!(header_text.text(Some("fr")), Some("Umleitung"));
+        assert_eq!(alert.description_text, None);
+    }
+
+    #[test]
+    fn test_malformed_feed() {
+        let data = fixture("vehicle_position.pb");
+        // truncated in the middle of the entity
+        let truncated = data[..data.len() - 3].to_vec();
+        assert_eq!(GTFSRealtimeReader::new(truncated), Err(GTFSRealtimeError::InvalidMessage));
+        // a varint key without its value
+        assert_eq!(GTFSRealtimeReader::new(vec![0x08]), Err(GTFSRealtimeError::InvalidMessage));
+        // a header length that runs past the end
+        assert_eq!(
+            GTFSRealtimeReader::new(vec![0x0a, 0x7f, 0x0a]),
+            Err(GTFSRealtimeError::InvalidMessage)
+        );
+        // a group wire type
+        assert_eq!(GTFSRealtimeReader::new(vec![0x0b]), Err(GTFSRealtimeError::InvalidMessage));
+        // a version that isn't UTF-8
+        let header = encode(|pb| pb.write_bytes_field(1, &[0xff, 0xfe]));
+        let data = encode(|pb| pb.write_bytes_field(1, &header));
+        assert_eq!(GTFSRealtimeReader::new(data), Err(GTFSRealtimeError::InvalidMessage));
+        // a latitude stored as a varint
+        let position = encode(|pb| pb.write_varint_field(1, 47_u64));
+        let vehicle = encode(|pb| pb.write_bytes_field(2, &position));
+        let entity = encode(|pb| {
+            pb.write_string_field(1, "1");
+            pb.write_bytes_field(4, &vehicle);
+        });
+        let data = encode(|pb| pb.write_bytes_field(2, &entity));
+        assert_eq!(GTFSRealtimeReader::new(data), Err(GTFSRealtimeError::InvalidMessage));
+        // unknown fields of any wire type are skipped
+        let data = encode(|pb| {
+            pb.write_fixed_field(20, 1.5_f32);
+            pb.write_fixed_field(21, 1.5_f64);
+            pb.write_bytes_field(22, &[0xff]);
+        });
+        assert_eq!(GTFSRealtimeReader::new(data), Ok(GTFSRealtimeReader::default()));
+    }
+}
@@ -0,0 +1,249 @@
+use super::{message, GTFSRealtimeOccupancyStatus, GTFSRealtimeVehicleDescriptor};
+use crate::readers::GTFSDate;
+use alloc::{string::String, vec::Vec};
+use pbf::{ProtoRead, Protobuf};
+
+/// The relation between a trip and the static schedule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeScheduleRelationship {
+    /// Trip that is running in accordance with its GTFS schedule, or is close enough to the
+    /// scheduled trip to be associated with it.
+    #[default]
+    Scheduled = 0,
+    /// An extra trip that was added in addition to a running schedule.
+    Added = 1,
+    /// A trip that is running with no schedule associated to it (frequency based, exact_times=0).
+    Unscheduled = 2,
+    /// A trip that existed in the schedule but was removed.
+    Canceled = 3,
+    /// A trip that replaces an existing trip in the schedule (deprecated).
+    Replacement = 5,
+    /// A new trip that is the same as an existing scheduled trip except for its start date and
+    /// time.
+    Duplicated = 6,
+    /// A trip that existed in the schedule but was removed and must not be shown to users.
+    Deleted = 7,
+}
+impl From<u64> for GTFSRealtimeScheduleRelationship {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => GTFSRealtimeScheduleRelationship::Added,
+            2 => GTFSRealtimeScheduleRelationship::Unscheduled,
+            3 => GTFSRealtimeScheduleRelationship::Canceled,
+            5 => GTFSRealtimeScheduleRelationship::Replacement,
+            6 => GTFSRealtimeScheduleRelationship::Duplicated,
+            7 => GTFSRealtimeScheduleRelationship::Deleted,
+            _ => GTFSRealtimeScheduleRelationship::Scheduled,
+        }
+    }
+}
+
+/// A descriptor that identifies an instance of a GTFS trip, or all instances of a trip along a
+/// route.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeTripDescriptor {
+    /// The trip (`trips.txt` `trip_id`) that this selector refers to.
+    pub trip_id: Option<String>,
+    /// The route (`routes.txt` `route_id`) that this selector refers to.
+    pub route_id: Option<String>,
+    /// The direction (`trips.txt` `direction_id`) of the trip.
+    pub direction_id: Option<u32>,
+    /// The initially scheduled start time of this trip instance, e.g. `25:35:00`.
+    pub start_time: Option<String>,
+    /// The scheduled start date of this trip instance.
+    pub start_date: Option<GTFSDate>,
+    /// The relation between this trip and the static schedule.
+    pub schedule_relationship: Option<GTFSRealtimeScheduleRelationship>,
+    /// Links to the trip modifications that affect this trip.
+    pub modified_trip: Option<GTFSRealtimeModifiedTripSelector>,
+}
+impl ProtoRead for GTFSRealtimeTripDescriptor {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.trip_id = Some(pb.read_string()),
+            2 => self.start_time = Some(pb.read_string()),
+            3 => self.start_date = pb.read_string().parse().ok(),
+            4 => self.schedule_relationship = Some(pb.read_varint::<u64>().into()),
+            5 => self.route_id = Some(pb.read_string()),
+            6 => self.direction_id = Some(pb.read_varint::<u32>()),
+            7 => self.modified_trip = Some(message(pb)),
+            _ => {}
+        }
+    }
+}
+
+/// Selects the trip modifications that affect a trip.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeModifiedTripSelector {
+    /// The id of the trip modifications entity that affects the trip.
+    pub modifications_id: Option<String>,
+    /// The trip id from the GTFS feed that is modified.
+    pub affected_trip_id: Option<String>,
+    /// The initially scheduled start time of the modified trip instance.
+    pub start_time: Option<String>,
+    /// The start date of the modified trip instance.
+    pub start_date: Option<GTFSDate>,
+}
+impl ProtoRead for GTFSRealtimeModifiedTripSelector {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.modifications_id = Some(pb.read_string()),
+            2 => self.affected_trip_id = Some(pb.read_string()),
+            3 => self.start_time = Some(pb.read_string()),
+            4 => self.start_date = pb.read_string().parse().ok(),
+            _ => {}
+        }
+    }
+}
+
+/// Realtime update of the progress of a vehicle along a trip. The updates can be for future,
+/// predicted arrival/departure events, or for past events that already occurred.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeTripUpdate {
+    /// The trip that this message applies to.
+    pub trip: GTFSRealtimeTripDescriptor,
+    /// Additional information on the vehicle that is serving this trip.
+    pub vehicle: Option<GTFSRealtimeVehicleDescriptor>,
+    /// Updates to the stop times of the trip, sorted by their stop sequence. Each update applies
+    /// to the following stops up to the next specified one.
+    pub stop_time_updates: Vec<GTFSRealtimeStopTimeUpdate>,
+    /// The most recent moment the vehicle's progress was measured, in POSIX seconds.
+    pub timestamp: Option<u64>,
+    /// The current schedule deviation for the trip in seconds. Positive values are late.
+    pub delay: Option<i32>,
+    /// Updated properties of the trip.
+    pub trip_properties: Option<GTFSRealtimeTripProperties>,
+}
+impl ProtoRead for GTFSRealtimeTripUpdate {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.trip = message(pb),
+            2 => self.stop_time_updates.push(message(pb)),
+            3 => self.vehicle = Some(message(pb)),
+            4 => self.timestamp = Some(pb.read_varint::<u64>()),
+            5 => self.delay = Some(pb.read_varint::<i32>()),
+            6 => self.trip_properties = Some(message(pb)),
+            _ => {}
+        }
+    }
+}
+
+/// Timing information for a single predicted event (either arrival or departure). If both `time`
+/// and `delay` are specified, `time` takes precedence.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GTFSRealtimeStopTimeEvent {
+    /// Delay in seconds relative to the schedule. Positive values are late.
+    pub delay: Option<i32>,
+    /// Event as absolute time in POSIX seconds.
+    pub time: Option<i64>,
+    /// The expected error of the delay (or time) in seconds. 0 is a completely certain
+    /// prediction; missing is unknown.
+    pub uncertainty: Option<i32>,
+}
+impl ProtoRead for GTFSRealtimeStopTimeEvent {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.delay = Some(pb.read_varint::<i32>()),
+            2 => self.time = Some(pb.read_varint::<i64>()),
+            3 => self.uncertainty = Some(pb.read_varint::<i32>()),
+            _ => {}
+        }
+    }
+}
+
+/// The relation between a stop time and the static schedule.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeStopTimeScheduleRelationship {
+    /// The vehicle is proceeding in accordance with its static schedule of stops.
+    #[default]
+    Scheduled = 0,
+    /// The stop is skipped.
+    Skipped = 1,
+    /// No data is given for this stop.
+    NoData = 2,
+    /// The vehicle is operating a frequency-based trip and has no schedule.
+    Unscheduled = 3,
+}
+impl From<u64> for GTFSRealtimeStopTimeScheduleRelationship {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => GTFSRealtimeStopTimeScheduleRelationship::Skipped,
+            2 => GTFSRealtimeStopTimeScheduleRelationship::NoData,
+            3 => GTFSRealtimeStopTimeScheduleRelationship::Unscheduled,
+            _ => GTFSRealtimeStopTimeScheduleRelationship::Scheduled,
+        }
+    }
+}
+
+/// Realtime update for the arrival and/or departure events of a stop of a trip.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeStopTimeUpdate {
+    /// Must be the same as in `stop_times.txt` of the static GTFS feed.
+    pub stop_sequence: Option<u32>,
+    /// Must be the same as in `stops.txt` of the static GTFS feed.
+    pub stop_id: Option<String>,
+    /// The predicted arrival
+    pub arrival: Option<GTFSRealtimeStopTimeEvent>,
+    /// The predicted departure
+    pub departure: Option<GTFSRealtimeStopTimeEvent>,
+    /// The predicted occupancy immediately after departure from this stop.
+    pub departure_occupancy_status: Option<GTFSRealtimeOccupancyStatus>,
+    /// The relation between this stop time and the static schedule.
+    pub schedule_relationship: GTFSRealtimeStopTimeScheduleRelationship,
+    /// Realtime updates to the properties of the stop time, e.g. an assigned platform.
+    pub assigned_stop_id: Option<String>,
+}
+impl ProtoRead for GTFSRealtimeStopTimeUpdate {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.stop_sequence = Some(pb.read_varint::<u32>()),
+            2 => self.arrival = Some(message(pb)),
+            3 => self.departure = Some(message(pb)),
+            4 => self.stop_id = Some(pb.read_string()),
+            5 => self.schedule_relationship = pb.read_varint::<u64>().into(),
+            6 => {
+                let properties: GTFSRealtimeStopTimeProperties = message(pb);
+                self.assigned_stop_id = properties.assigned_stop_id;
+            }
+            7 => self.departure_occupancy_status = Some(pb.read_varint::<u64>().into()),
+            _ => {}
+        }
+    }
+}
+
+/// The `StopTimeProperties` of a stop time update
+#[derive(Debug, Default)]
+struct GTFSRealtimeStopTimeProperties {
+    assigned_stop_id: Option<String>,
+}
+impl ProtoRead for GTFSRealtimeStopTimeProperties {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        if tag == 1 {
+            self.assigned_stop_id = Some(pb.read_string());
+        }
+    }
+}
+
+/// Defines updated properties of the trip, e.g. for a duplicated trip.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeTripProperties {
+    /// The id of a new trip that is a duplicate of an existing trip.
+    pub trip_id: Option<String>,
+    /// The service date of the new trip.
+    pub start_date: Option<GTFSDate>,
+    /// The departure start time of the new trip, e.g. `25:35:00`.
+    pub start_time: Option<String>,
+    /// The shape (`shapes.txt` or a realtime Shape) the vehicle travels along.
+    pub shape_id: Option<String>,
+}
+impl ProtoRead for GTFSRealtimeTripProperties {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.trip_id = Some(pb.read_string()),
+            2 => self.start_date = pb.read_string().parse().ok(),
+            3 => self.start_time = Some(pb.read_string()),
+            4 => self.shape_id = Some(pb.read_string()),
+            _ => {}
+        }
+    }
+}
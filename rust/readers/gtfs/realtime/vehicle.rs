@@ -0,0 +1,255 @@
+use super::{message, GTFSRealtimeTripDescriptor};
+use alloc::{string::String, vec::Vec};
+use pbf::{ProtoRead, Protobuf};
+
+/// A Position is a point on the Earth's surface.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct GTFSRealtimePosition {
+    /// Degrees North, in the WGS-84 coordinate system.
+    pub latitude: f32,
+    /// Degrees East, in the WGS-84 coordinate system.
+    pub longitude: f32,
+    /// Bearing, in degrees, clockwise from North, i.e., 0 is North and 90 is East. This can be
+    /// the compass bearing, or the direction towards the next stop or intermediate location.
+    pub bearing: Option<f32>,
+    /// Odometer value, in meters.
+    pub odometer: Option<f64>,
+    /// Momentary speed measured by the vehicle, in meters per second.
+    pub speed: Option<f32>,
+}
+impl ProtoRead for GTFSRealtimePosition {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.latitude = pb.read_fixed::<f32>(),
+            2 => self.longitude = pb.read_fixed::<f32>(),
+            3 => self.bearing = Some(pb.read_fixed::<f32>()),
+            4 => self.odometer = Some(pb.read_fixed::<f64>()),
+            5 => self.speed = Some(pb.read_fixed::<f32>()),
+            _ => {}
+        }
+    }
+}
+
+/// Status of the vehicle relative to the stop
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeVehicleStopStatus {
+    /// The vehicle is just about to arrive at the stop (on a stop display, the vehicle symbol
+    /// typically flashes).
+    IncomingAt = 0,
+    /// The vehicle is standing at the stop.
+    StoppedAt = 1,
+    /// The vehicle has departed and is in transit to the next stop.
+    #[default]
+    InTransitTo = 2,
+}
+impl From<u64> for GTFSRealtimeVehicleStopStatus {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => GTFSRealtimeVehicleStopStatus::IncomingAt,
+            1 => GTFSRealtimeVehicleStopStatus::StoppedAt,
+            _ => GTFSRealtimeVehicleStopStatus::InTransitTo,
+        }
+    }
+}
+
+/// Congestion level that is affecting this vehicle.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeCongestionLevel {
+    /// Unknown congestion level
+    #[default]
+    UnknownCongestionLevel = 0,
+    /// Running smoothly
+    RunningSmoothly = 1,
+    /// Stop and go
+    StopAndGo = 2,
+    /// Congestion
+    Congestion = 3,
+    /// Severe congestion (people leaving their cars)
+    SevereCongestion = 4,
+}
+impl From<u64> for GTFSRealtimeCongestionLevel {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => GTFSRealtimeCongestionLevel::RunningSmoothly,
+            2 => GTFSRealtimeCongestionLevel::StopAndGo,
+            3 => GTFSRealtimeCongestionLevel::Congestion,
+            4 => GTFSRealtimeCongestionLevel::SevereCongestion,
+            _ => GTFSRealtimeCongestionLevel::UnknownCongestionLevel,
+        }
+    }
+}
+
+/// The state of passenger occupancy for the vehicle or carriage. Consumers must not assume that
+/// the values follow a linear scale; see `occupancy_percentage` for that.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeOccupancyStatus {
+    /// Few or no passengers onboard, but still accepting passengers.
+    Empty = 0,
+    /// A large number of seats available.
+    ManySeatsAvailable = 1,
+    /// A relatively small number of seats available.
+    FewSeatsAvailable = 2,
+    /// Can currently accommodate only standing passengers.
+    StandingRoomOnly = 3,
+    /// Can currently accommodate only standing passengers and has limited space for them.
+    CrushedStandingRoomOnly = 4,
+    /// Considered full by most measures, but may still be allowing passengers to board.
+    Full = 5,
+    /// Not accepting passengers, but usually accepts passengers for boarding.
+    NotAcceptingPassengers = 6,
+    /// No occupancy data available at that time.
+    #[default]
+    NoDataAvailable = 7,
+    /// Not boardable and never accepts passengers (e.g. an engine or maintenance carriage).
+    NotBoardable = 8,
+}
+impl From<u64> for GTFSRealtimeOccupancyStatus {
+    fn from(value: u64) -> Self {
+        match value {
+            0 => GTFSRealtimeOccupancyStatus::Empty,
+            1 => GTFSRealtimeOccupancyStatus::ManySeatsAvailable,
+            2 => GTFSRealtimeOccupancyStatus::FewSeatsAvailable,
+            3 => GTFSRealtimeOccupancyStatus::StandingRoomOnly,
+            4 => GTFSRealtimeOccupancyStatus::CrushedStandingRoomOnly,
+            5 => GTFSRealtimeOccupancyStatus::Full,
+            6 => GTFSRealtimeOccupancyStatus::NotAcceptingPassengers,
+            8 => GTFSRealtimeOccupancyStatus::NotBoardable,
+            _ => GTFSRealtimeOccupancyStatus::NoDataAvailable,
+        }
+    }
+}
+
+/// Realtime positioning information for a given vehicle.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeVehiclePosition {
+    /// The Trip that this vehicle is serving. Can be empty or partial if the vehicle can not be
+    /// identified with a given trip instance.
+    pub trip: Option<GTFSRealtimeTripDescriptor>,
+    /// Additional information on the vehicle that is serving this trip.
+    pub vehicle: Option<GTFSRealtimeVehicleDescriptor>,
+    /// Current position of this vehicle.
+    pub position: Option<GTFSRealtimePosition>,
+    /// The stop sequence index of the current stop. Its meaning is determined by
+    /// `current_status`.
+    pub current_stop_sequence: Option<u32>,
+    /// Identifies the current stop (`stops.txt` `stop_id`).
+    pub stop_id: Option<String>,
+    /// The exact status of the vehicle with respect to the current stop. Ignored if
+    /// `current_stop_sequence` is missing.
+    pub current_status: GTFSRealtimeVehicleStopStatus,
+    /// Moment at which the vehicle's position was measured, in POSIX seconds.
+    pub timestamp: Option<u64>,
+    /// Congestion level that is affecting this vehicle.
+    pub congestion_level: Option<GTFSRealtimeCongestionLevel>,
+    /// Occupancy of the entire vehicle.
+    pub occupancy_status: Option<GTFSRealtimeOccupancyStatus>,
+    /// Passenger occupancy of the vehicle in percent. May exceed 100.
+    pub occupancy_percentage: Option<u32>,
+    /// Details of the carriages of the vehicle, the first being the first carriage in the
+    /// direction of travel.
+    pub multi_carriage_details: Vec<GTFSRealtimeCarriageDetails>,
+}
+impl ProtoRead for GTFSRealtimeVehiclePosition {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.trip = Some(message(pb)),
+            2 => self.position = Some(message(pb)),
+            3 => self.current_stop_sequence = Some(pb.read_varint::<u32>()),
+            4 => self.current_status = pb.read_varint::<u64>().into(),
+            5 => self.timestamp = Some(pb.read_varint::<u64>()),
+            6 => self.congestion_level = Some(pb.read_varint::<u64>().into()),
+            7 => self.stop_id = Some(pb.read_string()),
+            8 => self.vehicle = Some(message(pb)),
+            9 => self.occupancy_status = Some(pb.read_varint::<u64>().into()),
+            10 => self.occupancy_percentage = Some(pb.read_varint::<u32>()),
+            11 => self.multi_carriage_details.push(message(pb)),
+            _ => {}
+        }
+    }
+}
+
+/// Wheelchair accessibility of the trip
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum GTFSRealtimeWheelchairAccessible {
+    /// No information. The value from the static GTFS isn't overwritten.
+    #[default]
+    NoValue = 0,
+    /// The trip has no accessibility value present. Overwrites the static GTFS.
+    Unknown = 1,
+    /// The trip is wheelchair accessible. Overwrites the static GTFS.
+    WheelchairAccessible = 2,
+    /// The trip is **not** wheelchair accessible. Overwrites the static GTFS.
+    WheelchairInaccessible = 3,
+}
+impl From<u64> for GTFSRealtimeWheelchairAccessible {
+    fn from(value: u64) -> Self {
+        match value {
+            1 => GTFSRealtimeWheelchairAccessible::Unknown,
+            2 => GTFSRealtimeWheelchairAccessible::WheelchairAccessible,
+            3 => GTFSRealtimeWheelchairAccessible::WheelchairInaccessible,
+            _ => GTFSRealtimeWheelchairAccessible::NoValue,
+        }
+    }
+}
+
+/// Identification information for the vehicle performing the trip.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRealtimeVehicleDescriptor {
+    /// Internal system identification of the vehicle. Should be unique per vehicle.
+    pub id: Option<String>,
+    /// User visible label shown to the passenger to help identify the vehicle.
+    pub label: Option<String>,
+    /// The license plate of the vehicle.
+    pub license_plate: Option<String>,
+    /// Wheelchair accessibility of the trip
+    pub wheelchair_accessible: GTFSRealtimeWheelchairAccessible,
+}
+impl ProtoRead for GTFSRealtimeVehicleDescriptor {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.id = Some(pb.read_string()),
+            2 => self.label = Some(pb.read_string()),
+            3 => self.license_plate = Some(pb.read_string()),
+            4 => self.wheelchair_accessible = pb.read_varint::<u64>().into(),
+            _ => {}
+        }
+    }
+}
+
+/// Carriage specific details, used for vehicles composed of several carriages.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GTFSRealtimeCarriageDetails {
+    /// Identification of the carriage. Should be unique per vehicle.
+    pub id: Option<String>,
+    /// User visible label that may be shown to the passenger, e.g. "7712" or "Car ABC-32".
+    pub label: Option<String>,
+    /// Occupancy status for this carriage.
+    pub occupancy_status: GTFSRealtimeOccupancyStatus,
+    /// Occupancy percentage for this carriage, -1 if not available.
+    pub occupancy_percentage: i32,
+    /// The order of this carriage in the vehicle, starting at 1 in the direction of travel.
+    pub carriage_sequence: Option<u32>,
+}
+impl Default for GTFSRealtimeCarriageDetails {
+    fn default() -> Self {
+        GTFSRealtimeCarriageDetails {
+            id: None,
+            label: None,
+            occupancy_status: GTFSRealtimeOccupancyStatus::NoDataAvailable,
+            occupancy_percentage: -1,
+            carriage_sequence: None,
+        }
+    }
+}
+impl ProtoRead for GTFSRealtimeCarriageDetails {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.id = Some(pb.read_string()),
+            2 => self.label = Some(pb.read_string()),
+            3 => self.occupancy_status = pb.read_varint::<u64>().into(),
+            4 => self.occupancy_percentage = pb.read_varint::<i32>(),
+            5 => self.carriage_sequence = Some(pb.read_varint::<u32>()),
+            _ => {}
+        }
+    }
+}
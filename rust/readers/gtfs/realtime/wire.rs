@@ -0,0 +1,197 @@
+pub use crate::readers::wire::*;
+
+/// FeedMessage: the header and the entities
+pub fn feed_message_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(feed_header_schema)),
+        2 => Some(WireField::Message(feed_entity_schema)),
+        _ => None,
+    }
+}
+
+fn feed_header_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 4 => Some(WireField::String),
+        2 | 3 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn feed_entity_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::String),
+        2 => Some(WireField::Varint),
+        3 => Some(WireField::Message(trip_update_schema)),
+        4 => Some(WireField::Message(vehicle_position_schema)),
+        5 => Some(WireField::Message(alert_schema)),
+        6 => Some(WireField::Message(shape_schema)),
+        7 => Some(WireField::Message(stop_schema)),
+        _ => None,
+    }
+}
+
+fn trip_update_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(trip_descriptor_schema)),
+        2 => Some(WireField::Message(stop_time_update_schema)),
+        3 => Some(WireField::Message(vehicle_descriptor_schema)),
+        4 | 5 => Some(WireField::Varint),
+        6 => Some(WireField::Message(trip_properties_schema)),
+        _ => None,
+    }
+}
+
+fn trip_descriptor_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=3 | 5 => Some(WireField::String),
+        4 | 6 => Some(WireField::Varint),
+        7 => Some(WireField::Message(modified_trip_selector_schema)),
+        _ => None,
+    }
+}
+
+fn modified_trip_selector_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=4 => Some(WireField::String),
+        _ => None,
+    }
+}
+
+fn stop_time_update_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 5 | 7 => Some(WireField::Varint),
+        2 | 3 => Some(WireField::Message(stop_time_event_schema)),
+        4 => Some(WireField::String),
+        6 => Some(WireField::Message(stop_time_properties_schema)),
+        _ => None,
+    }
+}
+
+fn stop_time_event_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=3 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn stop_time_properties_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::String),
+        _ => None,
+    }
+}
+
+fn trip_properties_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=4 => Some(WireField::String),
+        _ => None,
+    }
+}
+
+fn vehicle_position_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(trip_descriptor_schema)),
+        2 => Some(WireField::Message(position_schema)),
+        3..=6 | 9 | 10 => Some(WireField::Varint),
+        7 => Some(WireField::String),
+        8 => Some(WireField::Message(vehicle_descriptor_schema)),
+        11 => Some(WireField::Message(carriage_details_schema)),
+        _ => None,
+    }
+}
+
+fn position_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=3 | 5 => Some(WireField::Fixed32),
+        4 => Some(WireField::Fixed64),
+        _ => None,
+    }
+}
+
+fn vehicle_descriptor_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=3 => Some(WireField::String),
+        4 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn carriage_details_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 2 => Some(WireField::String),
+        3..=5 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn alert_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(time_range_schema)),
+        5 => Some(WireField::Message(entity_selector_schema)),
+        6 | 7 | 14 => Some(WireField::Varint),
+        8 | 10..=13 | 16..=18 => Some(WireField::Message(translated_string_schema)),
+        15 => Some(WireField::Message(translated_image_schema)),
+        _ => None,
+    }
+}
+
+fn entity_selector_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 2 | 5 => Some(WireField::String),
+        3 | 6 => Some(WireField::Varint),
+        4 => Some(WireField::Message(trip_descriptor_schema)),
+        _ => None,
+    }
+}
+
+fn time_range_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 2 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn translated_string_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(translation_schema)),
+        _ => None,
+    }
+}
+
+fn translation_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 2 => Some(WireField::String),
+        _ => None,
+    }
+}
+
+fn translated_image_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(localized_image_schema)),
+        _ => None,
+    }
+}
+
+fn localized_image_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=3 => Some(WireField::String),
+        _ => None,
+    }
+}
+
+fn shape_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 2 => Some(WireField::String),
+        _ => None,
+    }
+}
+
+fn stop_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 8 | 11 | 12 | 14 => Some(WireField::String),
+        2..=5 | 9 | 15 => Some(WireField::Message(translated_string_schema)),
+        6 | 7 => Some(WireField::Fixed32),
+        13 => Some(WireField::Varint),
+        _ => None,
+    }
+}
@@ -0,0 +1,68 @@
+use super::{parse_field, GTFSDate, GTFSRecord};
+use crate::readers::parse_csv_as_record;
+use alloc::{string::String, vec::Vec};
+
+/// # Calendar Information
+///
+/// ## Details
+/// **Conditionally Required** - Service dates specified using a weekly schedule with start and end
+/// dates.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSCalendar {
+    /// **Required** - Identifies a set of dates when service is available for one or more routes
+    pub service_id: String,
+    /// **Required** - The days of the week the service is available, Monday first
+    pub days: [bool; 7],
+    /// **Required** - First day of the service interval
+    pub start_date: GTFSDate,
+    /// **Required** - Last day of the service interval (inclusive)
+    pub end_date: GTFSDate,
+}
+impl GTFSCalendar {
+    /// Create a calendar from a `calendar.txt` record
+    pub fn new(data: &GTFSRecord) -> Self {
+        let days = ["monday", "tuesday", "wednesday", "thursday", "friday", "saturday", "sunday"]
+            .map(|day| data.get(day).is_some_and(|value| value == "1"));
+        GTFSCalendar {
+            service_id: data.get("service_id").cloned().unwrap_or_default(),
+            days,
+            start_date: parse_field(data, "start_date").unwrap_or_default(),
+            end_date: parse_field(data, "end_date").unwrap_or_default(),
+        }
+    }
+}
+
+/// # Calendar Date Information
+///
+/// ## Details
+/// **Conditionally Required** - Exceptions for the services defined in `calendar.txt`, or all
+/// the service dates if `calendar.txt` is omitted.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSCalendarDate {
+    /// **Required** - Identifies a set of dates when a service exception occurs
+    pub service_id: String,
+    /// **Required** - Date when the service exception occurs
+    pub date: GTFSDate,
+    /// **Required** - 1 = service added for the date, 2 = service removed for the date
+    pub exception_type: u8,
+}
+impl GTFSCalendarDate {
+    /// Create a calendar date from a `calendar_dates.txt` record
+    pub fn new(data: &GTFSRecord) -> Self {
+        GTFSCalendarDate {
+            service_id: data.get("service_id").cloned().unwrap_or_default(),
+            date: parse_field(data, "date").unwrap_or_default(),
+            exception_type: parse_field(data, "exception_type").unwrap_or_default(),
+        }
+    }
+}
+
+/// Parse `calendar.txt`
+pub fn parse_gtfs_calendars(input: &str) -> Vec<GTFSCalendar> {
+    parse_csv_as_record(input).iter().map(GTFSCalendar::new).collect()
+}
+
+/// Parse `calendar_dates.txt`
+pub fn parse_gtfs_calendar_dates(input: &str) -> Vec<GTFSCalendarDate> {
+    parse_csv_as_record(input).iter().map(GTFSCalendarDate::new).collect()
+}
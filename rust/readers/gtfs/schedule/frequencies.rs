@@ -0,0 +1,41 @@
+use super::{parse_field, parse_gtfs_time, GTFSRecord};
+use crate::readers::parse_csv_as_record;
+use alloc::{string::String, vec::Vec};
+
+/// # Frequency Information
+///
+/// ## Details
+/// **Optional** - Headway (time between trips) for headway-based service or a compressed
+/// representation of fixed-schedule service. Times are seconds like [`super::GTFSStopTime`].
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSFrequency {
+    /// **Required** - The trip the frequency applies to (`trips.trip_id`)
+    pub trip_id: String,
+    /// **Required** - Time the first vehicle departs from the first stop, in seconds
+    pub start_time: u32,
+    /// **Required** - Time service changes or ends, in seconds
+    pub end_time: u32,
+    /// **Required** - Seconds between departures from the same stop
+    pub headway_secs: u32,
+    /// **Optional** - 0 or empty = frequency-based trips, 1 = schedule-based trips
+    pub exact_times: Option<u8>,
+}
+impl GTFSFrequency {
+    /// Create a frequency from a `frequencies.txt` record
+    pub fn new(data: &GTFSRecord) -> Self {
+        let time =
+            |key: &str| data.get(key).and_then(|time| parse_gtfs_time(time)).unwrap_or_default();
+        GTFSFrequency {
+            trip_id: data.get("trip_id").cloned().unwrap_or_default(),
+            start_time: time("start_time"),
+            end_time: time("end_time"),
+            headway_secs: parse_field(data, "headway_secs").unwrap_or_default(),
+            exact_times: parse_field(data, "exact_times"),
+        }
+    }
+}
+
+/// Parse `frequencies.txt`
+pub fn parse_gtfs_frequencies(input: &str) -> Vec<GTFSFrequency> {
+    parse_csv_as_record(input).iter().map(GTFSFrequency::new).collect()
+}
@@ -0,0 +1,330 @@
+/// Calendar and calendar date tools
+pub mod calendar;
+/// Frequency tools
+pub mod frequencies;
+/// Route tools
+pub mod routes;
+/// Shape tools
+pub mod shapes;
+/// Stop time tools
+pub mod stop_times;
+/// Stop tools
+pub mod stops;
+/// Trip tools
+pub mod trips;
+
+pub use calendar::*;
+pub use frequencies::*;
+pub use routes::*;
+pub use shapes::*;
+pub use stop_times::*;
+pub use stops::*;
+pub use trips::*;
+
+use crate::{
+    geometry::{VectorFeature, VectorPoint},
+    readers::{
+        wkt::geometry::{line_string_geometry, point_geometry},
+        FeatureIterator,
+    },
+    util::{iter_items, CompressError},
+};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+use core::str::FromStr;
+use s2json::{PrimitiveValue, ValueType};
+
+/// A record of a GTFS file, mapping the column names to the non-empty values
+pub type GTFSRecord = BTreeMap<String, String>;
+
+/// A GTFS service day, stored as `YYYYMMDD`
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GTFSDate {
+    /// The year
+    pub year: u16,
+    /// The month [1, 12]
+    pub month: u8,
+    /// The day of the month [1, 31]
+    pub day: u8,
+}
+impl FromStr for GTFSDate {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        if value.len() != 8 || !value.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(());
+        }
+        let year = value[0..4].parse().map_err(|_| ())?;
+        let month = value[4..6].parse().map_err(|_| ())?;
+        let day = value[6..8].parse().map_err(|_| ())?;
+        Ok(GTFSDate { year, month, day })
+    }
+}
+
+/// Parse a GTFS time `HH:MM:SS` (the hour may be a single digit or exceed 23) into seconds
+pub fn parse_gtfs_time(time: &str) -> Option<u32> {
+    let mut parts = time.trim().split(':').map(|part| part.parse::<u32>().ok());
+    let (hours, minutes, seconds) = (parts.next()??, parts.next()??, parts.next()??);
+    if parts.next().is_some() || minutes > 59 || seconds > 59 {
+        return None;
+    }
+    Some(hours * 3_600 + minutes * 60 + seconds)
+}
+
+/// Parse the value of a record's column, returning None if it's missing or invalid
+pub(crate) fn parse_field<T: FromStr>(data: &GTFSRecord, key: &str) -> Option<T> {
+    data.get(key).and_then(|value| value.trim().parse().ok())
+}
+
+/// # GTFS Schedule Reader
+///
+/// ## Description
+/// Reads a static GTFS feed, storing the stops, routes, trips, stop times, shapes, calendars and
+/// frequencies as typed structs. Missing files are left empty. Build it from the zipped feed with
+/// [`build_gtfs_schedule`].
+///
+/// Implements the [`FeatureIterator`], yielding a LineString feature for each shape followed by a
+/// Point feature for each stop with coordinates.
+/// - Shape features store the shape's `id` and the attributes of the route whose trips follow it
+///   (`routeId`, `agencyId`, `routeShortName`, `routeLongName`, `routeType`, `routeColor`, ...)
+/// - Stop features store the stop's attributes (`id`, `code`, `name`, `locationType`,
+///   `parentStation`, ...)
+///
+/// NOTE: Times are stored as seconds since the start of the service day. Invalid UTF-8 is
+/// replaced.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{build_gtfs_schedule, FeatureIterator};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/gtfs/fixtures/caltrain_20160406.zip");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let reader = build_gtfs_schedule(&data).unwrap();
+/// assert_eq!(reader.stops.len(), 95);
+/// let features: Vec<_> = reader.iter().collect();
+/// assert_eq!(features.len(), 103);
+/// ```
+///
+/// ## Links
+/// - <https://gtfs.org/documentation/schedule/reference/>
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSScheduleReader {
+    /// The stops (`stops.txt`) keyed by their id
+    pub stops: BTreeMap<String, GTFSStop>,
+    /// The routes (`routes.txt`) keyed by their id
+    pub routes: BTreeMap<String, GTFSRoute>,
+    /// The trips (`trips.txt`) keyed by their id
+    pub trips: BTreeMap<String, GTFSTrip>,
+    /// The stop times (`stop_times.txt`)
+    pub stop_times: Vec<GTFSStopTime>,
+    /// The points of each shape (`shapes.txt`) keyed by the shape id, sorted by their sequence
+    pub shapes: BTreeMap<String, Vec<GTFSShapePoint>>,
+    /// The weekly service schedules (`calendar.txt`)
+    pub calendar: Vec<GTFSCalendar>,
+    /// The service exceptions (`calendar_dates.txt`)
+    pub calendar_dates: Vec<GTFSCalendarDate>,
+    /// The headways of frequency based trips (`frequencies.txt`)
+    pub frequencies: Vec<GTFSFrequency>,
+}
+impl GTFSScheduleReader {
+    /// Add a GTFS file to the schedule by its name (e.g. `stops.txt`). Unsupported files are
+    /// ignored.
+    pub fn add_file(&mut self, name: &str, input: &str) {
+        match name {
+            "stops" => self.stops = parse_gtfs_stops(input),
+            "routes" => self.routes = parse_gtfs_routes(input),
+            "trips" => self.trips = parse_gtfs_trips(input),
+            "stop_times" => self.stop_times = parse_gtfs_stop_times(input),
+            "shapes" => self.shapes = parse_gtfs_shapes(input),
+            "calendar" => self.calendar = parse_gtfs_calendars(input),
+            "calendar_dates" => self.calendar_dates = parse_gtfs_calendar_dates(input),
+            "frequencies" => self.frequencies = parse_gtfs_frequencies(input),
+            _ => {}
+        }
+    }
+
+    /// The route a shape is used by, found through the trips that follow it
+    pub fn shape_route(&self, shape_id: &str) -> Option<&GTFSRoute> {
+        self.trips
+            .values()
+            .find(|trip| trip.shape_id.as_deref() == Some(shape_id))
+            .and_then(|trip| self.routes.get(&trip.route_id))
+    }
+
+    /// Iterate the shapes as LineString features followed by the stops as Point features
+    pub fn iter(&self) -> GTFSScheduleIterator<'_> {
+        GTFSScheduleIterator {
+            reader: self,
+            shapes: self.shapes.iter(),
+            stops: self.stops.values(),
+        }
+    }
+}
+
+/// An iterator over the shape and stop features of a [`GTFSScheduleReader`]
+#[derive(Debug)]
+pub struct GTFSScheduleIterator<'a> {
+    reader: &'a GTFSScheduleReader,
+    shapes: alloc::collections::btree_map::Iter<'a, String, Vec<GTFSShapePoint>>,
+    stops: alloc::collections::btree_map::Values<'a, String, GTFSStop>,
+}
+impl Iterator for GTFSScheduleIterator<'_> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some((id, points)) = self.shapes.next() {
+            let mut properties =
+                self.reader.shape_route(id).map(GTFSRoute::properties).unwrap_or_default();
+            properties
+                .insert("id".into(), ValueType::Primitive(PrimitiveValue::String(id.clone())));
+            let line = points
+                .iter()
+                .map(|point| VectorPoint::new(point.lon, point.lat, None, None))
+                .collect();
+            return Some(VectorFeature::new_wm(None, properties, line_string_geometry(line), None));
+        }
+        for stop in self.stops.by_ref() {
+            let (Some(lon), Some(lat)) = (stop.lon, stop.lat) else {
+                continue;
+            };
+            let geometry = point_geometry(VectorPoint::new(lon, lat, None, None));
+            return Some(VectorFeature::new_wm(None, stop.properties(), geometry, None));
+        }
+        None
+    }
+}
+impl FeatureIterator for GTFSScheduleIterator<'_> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// # Read a zipped GTFS feed
+///
+/// ## Description
+/// Reads each `.txt` (or `.csv`) file of the archive into a [`GTFSScheduleReader`]. Folders
+/// inside the archive are ignored, so feeds zipped with a parent directory are supported.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::build_gtfs_schedule;
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/gtfs/fixtures/caltrain_20160406.zip");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let reader = build_gtfs_schedule(&data).unwrap();
+/// assert_eq!(reader.routes.len(), 4);
+/// ```
+pub fn build_gtfs_schedule(data: &[u8]) -> Result<GTFSScheduleReader, CompressError> {
+    let mut reader = GTFSScheduleReader::default();
+    for item in iter_items(data)? {
+        if item.filename.ends_with('/') {
+            continue;
+        }
+        let file = item.filename.rsplit('/').next().unwrap_or_default();
+        let Some(name) = file.strip_suffix(".txt").or_else(|| file.strip_suffix(".csv")) else {
+            continue;
+        };
+        let data = (item.read)()?;
+        reader.add_file(name, &String::from_utf8_lossy(&data));
+    }
+
+    Ok(reader)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::VectorGeometry;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/gtfs/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn string(value: &str) -> ValueType {
+        ValueType::Primitive(PrimitiveValue::String(value.into()))
+    }
+
+    #[test]
+    fn test_caltrain() {
+        let reader = build_gtfs_schedule(&fixture("caltrain_20160406.zip")).unwrap();
+        assert_eq!(reader.stops.len(), 95);
+        assert_eq!(reader.routes.len(), 4);
+        assert_eq!(reader.trips.len(), 218);
+        assert_eq!(reader.stop_times.len(), 3_103);
+        assert_eq!(reader.shapes.len(), 8);
+        assert_eq!(reader.shapes.values().map(Vec::len).sum::<usize>(), 3_008);
+        assert_eq!(reader.calendar.len(), 3);
+        assert_eq!(reader.calendar_dates.len(), 8);
+        assert!(reader.frequencies.is_empty());
+
+        let shape = &reader.shapes["cal_sf_gil"];
+        assert!(shape.windows(2).all(|pair| pair[0].sequence < pair[1].sequence));
+
+        let calendar = &reader.calendar[0];
+        assert_eq!(calendar.days.iter().filter(|day| **day).count(), 5);
+        assert!(calendar.start_date < calendar.end_date);
+        assert!(reader.calendar_dates.iter().all(|date| matches!(date.exception_type, 1 | 2)));
+
+        let stop_time = &reader.stop_times[0];
+        assert!(stop_time.arrival_time.is_some() && stop_time.departure_time.is_some());
+        assert!(reader.stops.contains_key(stop_time.stop_id.as_ref().unwrap()));
+
+        let features: Vec<VectorFeature> = reader.iter().collect();
+        assert_eq!(features.len(), 8 + 95);
+
+        let shape = features.iter().find(|f| f.properties.get("id") == Some(&string("cal_sf_gil")));
+        let shape = shape.unwrap();
+        let VectorGeometry::LineString(line) = &shape.geometry else {
+            panic!("expected a LineString");
+        };
+        assert_eq!(line.coordinates.len(), reader.shapes["cal_sf_gil"].len());
+        let route = reader.shape_route("cal_sf_gil").unwrap();
+        assert_eq!(shape.properties.get("routeId"), Some(&string(&route.id)));
+        assert!(shape.properties.contains_key("routeType"));
+
+        let stop = features.last().unwrap();
+        assert!(matches!(stop.geometry, VectorGeometry::Point(_)));
+        assert!(stop.properties.contains_key("id") && stop.properties.contains_key("name"));
+    }
+
+    #[test]
+    fn test_frequencies() {
+        let input = String::from_utf8(fixture("frequencies.csv")).unwrap();
+        let frequencies = parse_gtfs_frequencies(&input);
+        assert_eq!(frequencies.len(), 2);
+        assert_eq!(frequencies[0].trip_id, "22M-GLOBAUX-00-S_1_2");
+        assert!(frequencies[0].start_time < frequencies[0].end_time);
+        assert!(frequencies[0].headway_secs > 0);
+    }
+
+    #[test]
+    fn test_values() {
+        assert_eq!(parse_gtfs_time("7:33:00"), Some(27_180));
+        assert_eq!(parse_gtfs_time("25:01:02"), Some(90_062));
+        assert_eq!(parse_gtfs_time("12:60:00"), None);
+        assert_eq!(parse_gtfs_time("noon"), None);
+        assert_eq!("20160406".parse(), Ok(GTFSDate { year: 2016, month: 4, day: 6 }));
+        assert!("2016-04-06".parse::<GTFSDate>().is_err());
+
+        let input = "stop_id,stop_name,stop_lat,stop_lon,location_type\nA,Alpha,1.5,2.5,1\nB,,,\n";
+        let stops = parse_gtfs_stops(input);
+        assert_eq!(stops["A"].lat, Some(1.5));
+        assert_eq!(stops["A"].location_type, Some(1));
+        assert_eq!(stops["B"].name, None);
+        let mut reader = GTFSScheduleReader { stops, ..Default::default() };
+        reader.add_file("unknown", "a,b\n1,2\n");
+        let features: Vec<VectorFeature> = reader.iter().collect();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].properties.get("name"), Some(&string("Alpha")));
+    }
+}
@@ -0,0 +1,99 @@
+use super::{parse_field, GTFSRecord};
+use crate::readers::parse_csv_as_record;
+use alloc::{collections::BTreeMap, string::String};
+use s2json::{PrimitiveValue, Properties, ValueType};
+
+/// # Route Information
+///
+/// ## Details
+/// **Required** - Transit routes. A route is a group of trips that are displayed to riders as a
+/// single service.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSRoute {
+    /// **Required** - Identifies a route
+    pub id: String,
+    /// **Conditionally Required** - Agency of the route. Required if multiple agencies are defined
+    pub agency_id: Option<String>,
+    /// **Conditionally Required** - Short name of the route, e.g. "32", "100X", "Green"
+    pub short_name: Option<String>,
+    /// **Conditionally Required** - Full name of the route
+    pub long_name: Option<String>,
+    /// **Optional** - Description of the route
+    pub desc: Option<String>,
+    /// **Required** - The type of transportation: 0 = Tram, 1 = Subway, 2 = Rail, 3 = Bus,
+    /// 4 = Ferry, 5 = Cable tram, 6 = Aerial lift, 7 = Funicular, 11 = Trolleybus, 12 = Monorail
+    pub route_type: u16,
+    /// **Optional** - URL of a web page about the route
+    pub url: Option<String>,
+    /// **Optional** - Route color as hex, e.g. `FFFFFF`
+    pub color: Option<String>,
+    /// **Optional** - Text color as hex used against the route color, e.g. `000000`
+    pub text_color: Option<String>,
+    /// **Optional** - Orders routes for presentation (smaller values displayed first)
+    pub sort_order: Option<u32>,
+    /// **Conditionally Forbidden** - Continuous pickup: 0 = continuous stopping, 1 or empty = none,
+    /// 2 = must phone agency, 3 = must coordinate with driver
+    pub continuous_pickup: Option<u8>,
+    /// **Conditionally Forbidden** - Continuous drop off: 0 = continuous stopping, 1 or empty =
+    /// none, 2 = must phone agency, 3 = must coordinate with driver
+    pub continuous_drop_off: Option<u8>,
+    /// **Conditionally Forbidden** - Identifies a group of routes
+    pub network_id: Option<String>,
+}
+impl GTFSRoute {
+    /// Create a route from a `routes.txt` record
+    pub fn new(data: &GTFSRecord) -> Self {
+        GTFSRoute {
+            id: data.get("route_id").cloned().unwrap_or_default(),
+            agency_id: data.get("agency_id").cloned(),
+            short_name: data.get("route_short_name").cloned(),
+            long_name: data.get("route_long_name").cloned(),
+            desc: data.get("route_desc").cloned(),
+            route_type: parse_field(data, "route_type").unwrap_or_default(),
+            url: data.get("route_url").cloned(),
+            color: data.get("route_color").cloned(),
+            text_color: data.get("route_text_color").cloned(),
+            sort_order: parse_field(data, "route_sort_order"),
+            continuous_pickup: parse_field(data, "continuous_pickup"),
+            continuous_drop_off: parse_field(data, "continuous_drop_off"),
+            network_id: data.get("network_id").cloned(),
+        }
+    }
+
+    /// The route attributes joined to shape features. Empty fields are left out.
+    pub fn properties(&self) -> Properties {
+        let mut properties = Properties::new();
+        let strings = [
+            ("routeId", Some(&self.id)),
+            ("agencyId", self.agency_id.as_ref()),
+            ("routeShortName", self.short_name.as_ref()),
+            ("routeLongName", self.long_name.as_ref()),
+            ("routeDesc", self.desc.as_ref()),
+            ("routeUrl", self.url.as_ref()),
+            ("routeColor", self.color.as_ref()),
+            ("routeTextColor", self.text_color.as_ref()),
+        ];
+        for (key, value) in strings {
+            if let Some(value) = value {
+                properties.insert(
+                    key.into(),
+                    ValueType::Primitive(PrimitiveValue::String(value.clone())),
+                );
+            }
+        }
+        properties.insert(
+            "routeType".into(),
+            ValueType::Primitive(PrimitiveValue::U64(self.route_type.into())),
+        );
+        properties
+    }
+}
+
+/// Parse `routes.txt` into routes keyed by their id
+pub fn parse_gtfs_routes(input: &str) -> BTreeMap<String, GTFSRoute> {
+    parse_csv_as_record(input)
+        .iter()
+        .map(GTFSRoute::new)
+        .map(|route| (route.id.clone(), route))
+        .collect()
+}
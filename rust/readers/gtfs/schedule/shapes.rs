@@ -0,0 +1,42 @@
+use super::parse_field;
+use crate::readers::parse_csv_as_record;
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+/// # Shape Point
+///
+/// ## Details
+/// **Optional** - A point of the path a vehicle travels along a route alignment
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSShapePoint {
+    /// **Required** - Latitude of the point
+    pub lat: f64,
+    /// **Required** - Longitude of the point
+    pub lon: f64,
+    /// **Required** - Order of the point along the shape
+    pub sequence: u32,
+    /// **Optional** - Distance traveled along the shape from the first point
+    pub dist_traveled: Option<f64>,
+}
+
+/// Parse `shapes.txt` into the points of each shape keyed by the shape id. The points are sorted
+/// by their sequence and points without coordinates are skipped.
+pub fn parse_gtfs_shapes(input: &str) -> BTreeMap<String, Vec<GTFSShapePoint>> {
+    let mut shapes: BTreeMap<String, Vec<GTFSShapePoint>> = BTreeMap::new();
+    for data in parse_csv_as_record(input) {
+        let (Some(id), Some(lat), Some(lon)) = (
+            data.get("shape_id"),
+            parse_field(&data, "shape_pt_lat"),
+            parse_field(&data, "shape_pt_lon"),
+        ) else {
+            continue;
+        };
+        shapes.entry(id.clone()).or_default().push(GTFSShapePoint {
+            lat,
+            lon,
+            sequence: parse_field(&data, "shape_pt_sequence").unwrap_or_default(),
+            dist_traveled: parse_field(&data, "shape_dist_traveled"),
+        });
+    }
+    shapes.values_mut().for_each(|points| points.sort_by_key(|point| point.sequence));
+    shapes
+}
@@ -0,0 +1,83 @@
+use super::{parse_field, parse_gtfs_time, GTFSRecord};
+use crate::readers::parse_csv_as_record;
+use alloc::{string::String, vec::Vec};
+
+/// # Stop Time Information
+///
+/// ## Details
+/// **Required** - Times that a vehicle arrives at and departs from stops for each trip.
+///
+/// Times are seconds since "noon minus 12h" of the service day, so they may exceed 24 hours for
+/// trips running past midnight.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSStopTime {
+    /// **Required** - The trip (`trips.trip_id`)
+    pub trip_id: String,
+    /// **Conditionally Required** - Arrival time in seconds
+    pub arrival_time: Option<u32>,
+    /// **Conditionally Required** - Departure time in seconds
+    pub departure_time: Option<u32>,
+    /// **Conditionally Required** - The stop (`stops.stop_id`)
+    pub stop_id: Option<String>,
+    /// **Conditionally Forbidden** - The location group (`location_groups.location_group_id`)
+    pub location_group_id: Option<String>,
+    /// **Conditionally Forbidden** - The GeoJSON location (`locations.geojson` id)
+    pub location_id: Option<String>,
+    /// **Required** - Order of the stops of the trip. Values increase along the trip.
+    pub stop_sequence: u32,
+    /// **Optional** - Text shown to riders identifying the destination, overriding the trip's
+    pub stop_headsign: Option<String>,
+    /// **Conditionally Required** - Start of an on-demand pickup/drop off window in seconds
+    pub start_pickup_drop_off_window: Option<u32>,
+    /// **Conditionally Required** - End of an on-demand pickup/drop off window in seconds
+    pub end_pickup_drop_off_window: Option<u32>,
+    /// **Conditionally Forbidden** - Pickup method: 0 or empty = regular, 1 = none, 2 = must
+    /// phone agency, 3 = must coordinate with driver
+    pub pickup_type: Option<u8>,
+    /// **Conditionally Forbidden** - Drop off method: 0 or empty = regular, 1 = none, 2 = must
+    /// phone agency, 3 = must coordinate with driver
+    pub drop_off_type: Option<u8>,
+    /// **Conditionally Forbidden** - Continuous pickup from this stop to the next
+    pub continuous_pickup: Option<u8>,
+    /// **Conditionally Forbidden** - Continuous drop off from this stop to the next
+    pub continuous_drop_off: Option<u8>,
+    /// **Optional** - Distance traveled along the shape from the first stop
+    pub shape_dist_traveled: Option<f64>,
+    /// **Optional** - 0 = approximate times, 1 or empty = exact times
+    pub timepoint: Option<u8>,
+    /// **Optional** - Booking rule of the pickup (`booking_rules.booking_rule_id`)
+    pub pickup_booking_rule_id: Option<String>,
+    /// **Optional** - Booking rule of the drop off (`booking_rules.booking_rule_id`)
+    pub drop_off_booking_rule_id: Option<String>,
+}
+impl GTFSStopTime {
+    /// Create a stop time from a `stop_times.txt` record
+    pub fn new(data: &GTFSRecord) -> Self {
+        let time = |key: &str| data.get(key).and_then(|time| parse_gtfs_time(time));
+        GTFSStopTime {
+            trip_id: data.get("trip_id").cloned().unwrap_or_default(),
+            arrival_time: time("arrival_time"),
+            departure_time: time("departure_time"),
+            stop_id: data.get("stop_id").cloned(),
+            location_group_id: data.get("location_group_id").cloned(),
+            location_id: data.get("location_id").cloned(),
+            stop_sequence: parse_field(data, "stop_sequence").unwrap_or_default(),
+            stop_headsign: data.get("stop_headsign").cloned(),
+            start_pickup_drop_off_window: time("start_pickup_drop_off_window"),
+            end_pickup_drop_off_window: time("end_pickup_drop_off_window"),
+            pickup_type: parse_field(data, "pickup_type"),
+            drop_off_type: parse_field(data, "drop_off_type"),
+            continuous_pickup: parse_field(data, "continuous_pickup"),
+            continuous_drop_off: parse_field(data, "continuous_drop_off"),
+            shape_dist_traveled: parse_field(data, "shape_dist_traveled"),
+            timepoint: parse_field(data, "timepoint"),
+            pickup_booking_rule_id: data.get("pickup_booking_rule_id").cloned(),
+            drop_off_booking_rule_id: data.get("drop_off_booking_rule_id").cloned(),
+        }
+    }
+}
+
+/// Parse `stop_times.txt`
+pub fn parse_gtfs_stop_times(input: &str) -> Vec<GTFSStopTime> {
+    parse_csv_as_record(input).iter().map(GTFSStopTime::new).collect()
+}
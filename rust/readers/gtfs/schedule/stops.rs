@@ -0,0 +1,113 @@
+use super::{parse_field, GTFSRecord};
+use crate::readers::parse_csv_as_record;
+use alloc::{collections::BTreeMap, string::String};
+use s2json::{PrimitiveValue, Properties, ValueType};
+
+/// # Stop Information
+///
+/// ## Details
+/// **Conditionally Required** - Stops where vehicles pick up or drop off riders.
+/// Also defines stations, entrances, etc.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSStop {
+    /// **Required** - Identifies a stop/platform, station, entrance/exit, generic node or boarding
+    /// area
+    pub id: String,
+    /// **Optional** - Short text or a number that identifies the location for riders
+    pub code: Option<String>,
+    /// **Conditionally Required** - Name of the location. Required if `location_type` is 0, 1 or 2
+    pub name: Option<String>,
+    /// **Optional** - Readable version of the name for text-to-speech systems
+    pub tts_name: Option<String>,
+    /// **Optional** - Description providing useful information about the location
+    pub desc: Option<String>,
+    /// **Conditionally Required** - Latitude. Required if `location_type` is 0, 1 or 2
+    pub lat: Option<f64>,
+    /// **Conditionally Required** - Longitude. Required if `location_type` is 0, 1 or 2
+    pub lon: Option<f64>,
+    /// **Optional** - Identifies the fare zone of the stop
+    pub zone_id: Option<String>,
+    /// **Optional** - URL of a web page about the location
+    pub url: Option<String>,
+    /// **Optional** - Location type: 0 or empty = Stop/Platform, 1 = Station, 2 = Entrance/Exit,
+    /// 3 = Generic Node, 4 = Boarding Area
+    pub location_type: Option<u8>,
+    /// **Conditionally Required** - The parent station. Required if `location_type` is 2, 3 or 4
+    pub parent_station: Option<String>,
+    /// **Optional** - Timezone of the location, inherited from the parent station if empty
+    pub timezone: Option<String>,
+    /// **Optional** - Wheelchair boarding: 0 = no info, 1 = possible, 2 = not possible
+    pub wheelchair_boarding: Option<u8>,
+    /// **Optional** - Level of the location (references `levels.level_id`)
+    pub level_id: Option<String>,
+    /// **Optional** - Platform identifier of a platform stop
+    pub platform_code: Option<String>,
+}
+impl GTFSStop {
+    /// Create a stop from a `stops.txt` record
+    pub fn new(data: &GTFSRecord) -> Self {
+        GTFSStop {
+            id: data.get("stop_id").cloned().unwrap_or_default(),
+            code: data.get("stop_code").cloned(),
+            name: data.get("stop_name").cloned(),
+            tts_name: data.get("tts_stop_name").cloned(),
+            desc: data.get("stop_desc").cloned(),
+            lat: parse_field(data, "stop_lat"),
+            lon: parse_field(data, "stop_lon"),
+            zone_id: data.get("zone_id").cloned(),
+            url: data.get("stop_url").cloned(),
+            location_type: parse_field(data, "location_type"),
+            parent_station: data.get("parent_station").cloned(),
+            timezone: data.get("stop_timezone").cloned(),
+            wheelchair_boarding: parse_field(data, "wheelchair_boarding"),
+            level_id: data.get("level_id").cloned(),
+            platform_code: data.get("platform_code").cloned(),
+        }
+    }
+
+    /// The properties of the stop's point feature. Empty fields are left out.
+    pub fn properties(&self) -> Properties {
+        let mut properties = Properties::new();
+        let strings = [
+            ("id", Some(&self.id)),
+            ("code", self.code.as_ref()),
+            ("name", self.name.as_ref()),
+            ("ttsName", self.tts_name.as_ref()),
+            ("desc", self.desc.as_ref()),
+            ("zoneId", self.zone_id.as_ref()),
+            ("url", self.url.as_ref()),
+            ("parentStation", self.parent_station.as_ref()),
+            ("timezone", self.timezone.as_ref()),
+            ("levelId", self.level_id.as_ref()),
+            ("platformCode", self.platform_code.as_ref()),
+        ];
+        for (key, value) in strings {
+            if let Some(value) = value {
+                properties.insert(
+                    key.into(),
+                    ValueType::Primitive(PrimitiveValue::String(value.clone())),
+                );
+            }
+        }
+        let numbers = [
+            ("locationType", self.location_type),
+            ("wheelchairBoarding", self.wheelchair_boarding),
+        ];
+        for (key, value) in numbers {
+            if let Some(value) = value {
+                properties
+                    .insert(key.into(), ValueType::Primitive(PrimitiveValue::U64(value.into())));
+            }
+        }
+        properties
+    }
+}
+
+/// Parse `stops.txt` into stops keyed by their id
+pub fn parse_gtfs_stops(input: &str) -> BTreeMap<String, GTFSStop> {
+    parse_csv_as_record(input)
+        .iter()
+        .map(GTFSStop::new)
+        .map(|stop| (stop.id.clone(), stop))
+        .collect()
+}
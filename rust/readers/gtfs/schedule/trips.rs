@@ -0,0 +1,59 @@
+use super::{parse_field, GTFSRecord};
+use crate::readers::parse_csv_as_record;
+use alloc::{collections::BTreeMap, string::String};
+
+/// # Trip Information
+///
+/// ## Details
+/// **Required** - Trips for each route. A trip is a sequence of two or more stops that occur
+/// during a specific time period.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct GTFSTrip {
+    /// **Required** - The route of the trip
+    pub route_id: String,
+    /// **Required** - The service dates of the trip (`calendar.service_id` or
+    /// `calendar_dates.service_id`)
+    pub service_id: String,
+    /// **Required** - Identifies a trip
+    pub id: String,
+    /// **Optional** - Text shown to riders identifying the trip's destination
+    pub headsign: Option<String>,
+    /// **Optional** - Public facing text identifying the trip, e.g. a train number
+    pub short_name: Option<String>,
+    /// **Optional** - Direction of travel: 0 = one direction (e.g. outbound), 1 = the opposite
+    pub direction_id: Option<u8>,
+    /// **Optional** - The block the trip belongs to
+    pub block_id: Option<String>,
+    /// **Conditionally Required** - The geospatial shape of the trip (`shapes.shape_id`)
+    pub shape_id: Option<String>,
+    /// **Optional** - Wheelchair accessibility: 0 = no info, 1 = accessible, 2 = not accessible
+    pub wheelchair_accessible: Option<u8>,
+    /// **Optional** - Bikes allowed: 0 = no info, 1 = allowed, 2 = not allowed
+    pub bikes_allowed: Option<u8>,
+}
+impl GTFSTrip {
+    /// Create a trip from a `trips.txt` record
+    pub fn new(data: &GTFSRecord) -> Self {
+        GTFSTrip {
+            route_id: data.get("route_id").cloned().unwrap_or_default(),
+            service_id: data.get("service_id").cloned().unwrap_or_default(),
+            id: data.get("trip_id").cloned().unwrap_or_default(),
+            headsign: data.get("trip_headsign").cloned(),
+            short_name: data.get("trip_short_name").cloned(),
+            direction_id: parse_field(data, "direction_id"),
+            block_id: data.get("block_id").cloned(),
+            shape_id: data.get("shape_id").cloned(),
+            wheelchair_accessible: parse_field(data, "wheelchair_accessible"),
+            bikes_allowed: parse_field(data, "bikes_allowed"),
+        }
+    }
+}
+
+/// Parse `trips.txt` into trips keyed by their id
+pub fn parse_gtfs_trips(input: &str) -> BTreeMap<String, GTFSTrip> {
+    parse_csv_as_record(input)
+        .iter()
+        .map(GTFSTrip::new)
+        .map(|trip| (trip.id.clone(), trip))
+        .collect()
+}
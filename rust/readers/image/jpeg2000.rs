@@ -0,0 +1,2068 @@
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+use libm::{ceil, floor, pow};
+
+/// Errors that can occur while decoding a JPEG 2000 image
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JpxError {
+    /// A JP2 box has a length smaller than its header
+    InvalidBox,
+    /// The quantization style (Sqcd) isn't valid
+    InvalidQuantization(u8),
+    /// The codestream contains a marker that isn't supported
+    UnknownMarker(u16),
+    /// The progression order isn't one of the five defined orders
+    UnsupportedProgressionOrder(u8),
+    /// The code-block style uses bypass, context resets, termination or vertical stripes
+    UnsupportedCodeBlockStyle(u8),
+    /// The codestream ended before a marker or packet header was complete
+    UnexpectedEnd,
+    /// A tile has more packets than its coding style describes
+    OutOfPackets,
+    /// A code-block's segmentation symbol didn't decode to 0b1010
+    InvalidSegmentationSymbol,
+    /// The coding style has more than 32 decomposition levels
+    InvalidDecompositionLevels(u8),
+    /// A code-block has more bit planes than its coefficients can hold
+    TooManyBitPlanes,
+}
+
+/// A decoded tile. Components are interleaved
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct JpxTile {
+    /// The tile's left position in the reference grid
+    pub left: usize,
+    /// The tile's top position in the reference grid
+    pub top: usize,
+    /// The tile's width
+    pub width: usize,
+    /// The tile's height
+    pub height: usize,
+    /// The decoded samples, `width * height * components_count` of them
+    pub items: Vec<i32>,
+}
+
+/// # JPEG 2000 Image
+///
+/// ## Description
+/// Decodes a JPEG 2000 codestream (J2K) or a JP2 file wrapping one. Samples are returned at their
+/// full precision, with signed components kept signed, so the decoder can be used for data
+/// payloads like GRIB2's template 5.40 and not just imagery.
+///
+/// NOTE: Code-block styles other than the segmentation symbol (bypass, context resets,
+/// termination on each pass, vertical stripes) and component coding style overrides (COC) are not
+/// supported.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::JpxImage;
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/image/jpeg2000/fixtures/input.j2k");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let image = JpxImage::new(&data).unwrap();
+/// assert_eq!((image.width, image.height, image.components_count), (63677, 1, 1));
+/// assert_eq!(image.tiles[0].items.len(), 63677);
+/// ```
+///
+/// ## Links
+/// - <https://www.itu.int/rec/T-REC-T.800>
+/// - <https://github.com/mozilla/pdf.js/blob/master/src/core/jpx.js>
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct JpxImage {
+    /// The image width
+    pub width: usize,
+    /// The image height
+    pub height: usize,
+    /// The number of components per sample
+    pub components_count: usize,
+    /// The decoded tiles
+    pub tiles: Vec<JpxTile>,
+}
+impl JpxImage {
+    /// Decode a raw codestream or a JP2 file
+    pub fn new(data: &[u8]) -> Result<Self, JpxError> {
+        let mut image = JpxImage::default();
+        if read_u16(data, 0)? == 0xff4f {
+            image.parse_codestream(data, 0, data.len())?;
+            return Ok(image);
+        }
+
+        let mut position = 0;
+        while position < data.len() {
+            let mut header_size = 8;
+            let mut lbox = read_u32(data, position)? as usize;
+            let tbox = read_u32(data, position + 4)?;
+            position += header_size;
+            if lbox == 1 {
+                // XLBox
+                lbox = ((read_u32(data, position)? as usize) << 32)
+                    | read_u32(data, position + 4)? as usize;
+                position += 8;
+                header_size += 8;
+            }
+            if lbox == 0 {
+                lbox = data.len() - position + header_size;
+            }
+            if lbox < header_size {
+                return Err(JpxError::InvalidBox);
+            }
+            let data_length = lbox - header_size;
+            match tbox {
+                // 'jp2h' is a superbox, so parse its children
+                0x6a703268 => continue,
+                // 'jp2c'
+                0x6a703263 => image.parse_codestream(data, position, position + data_length)?,
+                _ => {}
+            }
+            position += data_length;
+        }
+
+        Ok(image)
+    }
+
+    fn parse_codestream(&mut self, data: &[u8], start: usize, end: usize) -> Result<(), JpxError> {
+        let mut context = Context::default();
+        // corrupted packets are common, so decode what was parsed before the failure
+        match parse_markers(&mut context, data, start, end) {
+            Err(err @ JpxError::UnsupportedCodeBlockStyle(_)) => return Err(err),
+            Err(err @ JpxError::UnsupportedProgressionOrder(_)) => return Err(err),
+            Err(err @ JpxError::InvalidDecompositionLevels(_)) => return Err(err),
+            _ => {}
+        }
+        self.tiles = transform_components(&context)?;
+        self.width = context.siz.xsiz.saturating_sub(context.siz.xosiz);
+        self.height = context.siz.ysiz.saturating_sub(context.siz.yosiz);
+        self.components_count = context.siz.csiz;
+        Ok(())
+    }
+}
+
+/// Image and tile size (SIZ)
+#[derive(Debug, Default, Clone)]
+struct Siz {
+    xsiz: usize,
+    ysiz: usize,
+    xosiz: usize,
+    yosiz: usize,
+    xtsiz: usize,
+    ytsiz: usize,
+    xtosiz: usize,
+    ytosiz: usize,
+    csiz: usize,
+}
+
+#[derive(Debug, Default, Clone)]
+struct Component {
+    precision: u32,
+    is_signed: bool,
+    xrsiz: usize,
+    yrsiz: usize,
+}
+
+/// Coding style (COD)
+#[derive(Debug, Default, Clone)]
+struct CodingStyle {
+    entropy_coder_with_custom_precincts: bool,
+    sop_marker_used: bool,
+    eph_marker_used: bool,
+    progression_order: u8,
+    layers_count: usize,
+    multiple_component_transform: bool,
+    decomposition_levels_count: usize,
+    xcb: u32,
+    ycb: u32,
+    segmentation_symbol_used: bool,
+    reversible_transformation: bool,
+    /// (PPx, PPy) per resolution
+    precincts_sizes: Vec<(u32, u32)>,
+}
+
+/// Quantization (QCD and QCC)
+#[derive(Debug, Default, Clone)]
+struct Quantization {
+    scalar_expounded: bool,
+    guard_bits: u32,
+    /// (epsilon, mu) per subband
+    spqcds: Vec<(u32, u32)>,
+}
+
+#[derive(Debug, Default, Clone)]
+struct TilePart {
+    index: usize,
+    data_end: usize,
+    part_index: u8,
+    cod: CodingStyle,
+    qcd: Quantization,
+    qcc: Vec<Option<Quantization>>,
+}
+
+#[derive(Debug, Default)]
+struct Context {
+    main_header: bool,
+    siz: Siz,
+    components: Vec<Component>,
+    cod: CodingStyle,
+    qcd: Quantization,
+    qcc: Vec<Option<Quantization>>,
+    tiles: Vec<Tile>,
+    current_tile: TilePart,
+}
+
+#[derive(Debug, Default)]
+struct Tile {
+    components: Vec<TileComponent>,
+    cod: CodingStyle,
+    packets: Vec<PacketId>,
+    next_packet: usize,
+}
+
+#[derive(Debug, Default)]
+struct TileComponent {
+    tcx0: usize,
+    tcy0: usize,
+    tcx1: usize,
+    tcy1: usize,
+    quantization: Quantization,
+    coding_style: CodingStyle,
+    resolutions: Vec<Resolution>,
+}
+
+#[derive(Debug, Default)]
+struct Resolution {
+    trx0: usize,
+    try0: usize,
+    trx1: usize,
+    try1: usize,
+    precincts: PrecinctParameters,
+    subbands: Vec<SubBand>,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct PrecinctParameters {
+    precinct_width: usize,
+    precinct_height: usize,
+    num_wide: usize,
+    num_high: usize,
+    count: usize,
+    width_in_subband: usize,
+    height_in_subband: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum SubBandType {
+    #[default]
+    LL,
+    HL,
+    LH,
+    HH,
+}
+impl SubBandType {
+    fn gain_log2(&self) -> i32 {
+        match self {
+            SubBandType::LL => 0,
+            SubBandType::HL | SubBandType::LH => 1,
+            SubBandType::HH => 2,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct SubBand {
+    kind: SubBandType,
+    tbx0: usize,
+    tby0: usize,
+    tbx1: usize,
+    tby1: usize,
+    codeblocks: Vec<CodeBlock>,
+    precincts: BTreeMap<usize, Precinct>,
+}
+
+#[derive(Debug)]
+struct Precinct {
+    cbx_min: usize,
+    cby_min: usize,
+    cbx_max: usize,
+    cby_max: usize,
+    /// the inclusion and zero bit-planes trees
+    trees: Option<(InclusionTree, TagTree)>,
+}
+
+#[derive(Debug, Default)]
+struct CodeBlock {
+    cbx: usize,
+    cby: usize,
+    tbx0: usize,
+    tby0: usize,
+    tbx1: usize,
+    tby1: usize,
+    precinct_number: usize,
+    lblock: u32,
+    included: bool,
+    zero_bit_planes: u32,
+    data: Vec<u8>,
+    coding_passes: usize,
+}
+
+/// (layer, component, resolution, precinct)
+#[derive(Debug, Clone, Copy)]
+struct PacketId {
+    layer: usize,
+    component: usize,
+    resolution: usize,
+    precinct: usize,
+}
+
+fn read_u8(data: &[u8], position: usize) -> Result<u8, JpxError> {
+    data.get(position).copied().ok_or(JpxError::UnexpectedEnd)
+}
+
+fn read_u16(data: &[u8], position: usize) -> Result<u16, JpxError> {
+    Ok(((read_u8(data, position)? as u16) << 8) | read_u8(data, position + 1)? as u16)
+}
+
+fn read_u32(data: &[u8], position: usize) -> Result<u32, JpxError> {
+    Ok(((read_u16(data, position)? as u32) << 16) | read_u16(data, position + 2)? as u32)
+}
+
+/// The ceiling of the base 2 logarithm, 0 for values <= 1
+fn log2(x: usize) -> u32 {
+    if x <= 1 {
+        0
+    } else {
+        usize::BITS - (x - 1).leading_zeros()
+    }
+}
+
+/// ceil(x / scale - 0.5), used to position the high-pass subbands
+fn ceil_half(x: usize, scale: usize) -> usize {
+    ceil(x as f64 / scale as f64 - 0.5).max(0.) as usize
+}
+
+fn parse_markers(
+    context: &mut Context,
+    data: &[u8],
+    start: usize,
+    end: usize,
+) -> Result<(), JpxError> {
+    let mut position = start;
+    while position + 1 < end {
+        let code = read_u16(data, position)?;
+        position += 2;
+
+        let mut length = 0;
+        match code {
+            // Start of codestream (SOC)
+            0xff4f => context.main_header = true,
+            // End of codestream (EOC)
+            0xffd9 => {}
+            // Image and tile size (SIZ)
+            0xff51 => {
+                length = read_u16(data, position)? as usize;
+                let siz = Siz {
+                    xsiz: read_u32(data, position + 4)? as usize,
+                    ysiz: read_u32(data, position + 8)? as usize,
+                    xosiz: read_u32(data, position + 12)? as usize,
+                    yosiz: read_u32(data, position + 16)? as usize,
+                    xtsiz: read_u32(data, position + 20)? as usize,
+                    ytsiz: read_u32(data, position + 24)? as usize,
+                    xtosiz: read_u32(data, position + 28)? as usize,
+                    ytosiz: read_u32(data, position + 32)? as usize,
+                    csiz: read_u16(data, position + 36)? as usize,
+                };
+                let mut j = position + 38;
+                context.components.clear();
+                for _ in 0..siz.csiz {
+                    let ssiz = read_u8(data, j)?;
+                    context.components.push(Component {
+                        precision: (ssiz & 0x7f) as u32 + 1,
+                        is_signed: ssiz & 0x80 != 0,
+                        xrsiz: read_u8(data, j + 1)?.max(1) as usize,
+                        yrsiz: read_u8(data, j + 2)?.max(1) as usize,
+                    });
+                    j += 3;
+                }
+                context.siz = siz;
+                calculate_tile_grids(context);
+                context.qcc = vec![];
+            }
+            // Quantization default (QCD)
+            0xff5c => {
+                length = read_u16(data, position)? as usize;
+                let qcd = parse_quantization(data, position + 2, position + length)?;
+                if context.main_header {
+                    context.qcd = qcd;
+                } else {
+                    context.current_tile.qcd = qcd;
+                    context.current_tile.qcc = vec![];
+                }
+            }
+            // Quantization component (QCC)
+            0xff5d => {
+                length = read_u16(data, position)? as usize;
+                let mut j = position + 2;
+                let cqcc = if context.siz.csiz < 257 {
+                    j += 1;
+                    read_u8(data, j - 1)? as usize
+                } else {
+                    j += 2;
+                    read_u16(data, j - 2)? as usize
+                };
+                let qcc = parse_quantization(data, j, position + length)?;
+                let list = if context.main_header {
+                    &mut context.qcc
+                } else {
+                    &mut context.current_tile.qcc
+                };
+                if list.len() <= cqcc {
+                    list.resize(cqcc + 1, None);
+                }
+                list[cqcc] = Some(qcc);
+            }
+            // Coding style default (COD)
+            0xff52 => {
+                length = read_u16(data, position)? as usize;
+                let cod = parse_coding_style(data, position + 2, position + length)?;
+                if context.main_header {
+                    context.cod = cod;
+                } else {
+                    context.current_tile.cod = cod;
+                }
+            }
+            // Start of tile-part (SOT)
+            0xff90 => {
+                length = read_u16(data, position)? as usize;
+                let tile_length = read_u32(data, position + 4)? as usize;
+                let data_end = if tile_length == 0 {
+                    end.saturating_sub(2)
+                } else {
+                    tile_length + position - 2
+                };
+                let mut tile = TilePart {
+                    index: read_u16(data, position + 2)? as usize,
+                    data_end,
+                    part_index: read_u8(data, position + 8)?,
+                    ..Default::default()
+                };
+                context.main_header = false;
+                if tile.part_index == 0 {
+                    // reset component specific settings
+                    tile.cod = context.cod.clone();
+                    tile.qcd = context.qcd.clone();
+                    tile.qcc = context.qcc.clone();
+                }
+                context.current_tile = tile;
+            }
+            // Start of data (SOD)
+            0xff93 => {
+                if context.current_tile.part_index == 0 {
+                    initialize_tile(context)?;
+                    build_packets(context)?;
+                }
+                // moving to the end of the data
+                length = context.current_tile.data_end.saturating_sub(position);
+                parse_tile_packets(context, data, position, length)?;
+            }
+            // Coding style component (COC) isn't applied. Tile-part lengths (TLM), packet
+            // lengths (PLM and PLT) and comments (COM) aren't needed
+            0xff53 | 0xff55 | 0xff57 | 0xff58 | 0xff64 => {
+                length = read_u16(data, position)? as usize;
+            }
+            _ => return Err(JpxError::UnknownMarker(code)),
+        }
+        position += length;
+    }
+    Ok(())
+}
+
+fn parse_quantization(data: &[u8], mut j: usize, end: usize) -> Result<Quantization, JpxError> {
+    let sqcd = read_u8(data, j)?;
+    j += 1;
+    let (spqcd_size, scalar_expounded) = match sqcd & 0x1f {
+        0 => (8, true),
+        1 => (16, false),
+        2 => (16, true),
+        _ => return Err(JpxError::InvalidQuantization(sqcd)),
+    };
+    let mut spqcds = vec![];
+    while j < end {
+        if spqcd_size == 8 {
+            spqcds.push(((read_u8(data, j)? >> 3) as u32, 0));
+            j += 1;
+        } else {
+            let value = read_u16(data, j)?;
+            spqcds.push(((value >> 11) as u32, (value & 0x7ff) as u32));
+            j += 2;
+        }
+    }
+    Ok(Quantization { scalar_expounded, guard_bits: (sqcd >> 5) as u32, spqcds })
+}
+
+fn parse_coding_style(data: &[u8], j: usize, end: usize) -> Result<CodingStyle, JpxError> {
+    let scod = read_u8(data, j)?;
+    let block_style = read_u8(data, j + 8)?;
+    // selective bypass, context resets, termination on each pass, vertical stripes and
+    // predictable termination
+    if block_style & 0x1f != 0 {
+        return Err(JpxError::UnsupportedCodeBlockStyle(block_style));
+    }
+    let mut cod = CodingStyle {
+        entropy_coder_with_custom_precincts: scod & 1 != 0,
+        sop_marker_used: scod & 2 != 0,
+        eph_marker_used: scod & 4 != 0,
+        progression_order: read_u8(data, j + 1)?,
+        layers_count: read_u16(data, j + 2)? as usize,
+        multiple_component_transform: read_u8(data, j + 4)? != 0,
+        decomposition_levels_count: match read_u8(data, j + 5)? {
+            levels @ 0..=32 => levels as usize,
+            levels => return Err(JpxError::InvalidDecompositionLevels(levels)),
+        },
+        xcb: (read_u8(data, j + 6)? & 0xf) as u32 + 2,
+        ycb: (read_u8(data, j + 7)? & 0xf) as u32 + 2,
+        segmentation_symbol_used: block_style & 32 != 0,
+        reversible_transformation: read_u8(data, j + 9)? != 0,
+        precincts_sizes: vec![],
+    };
+    if cod.entropy_coder_with_custom_precincts {
+        for k in j + 10..end {
+            let size = read_u8(data, k)?;
+            cod.precincts_sizes.push(((size & 0xf) as u32, (size >> 4) as u32));
+        }
+    }
+    Ok(cod)
+}
+
+/// Section B.3 Division into tile and tile-components
+fn calculate_tile_grids(context: &mut Context) {
+    let siz = &context.siz;
+    context.tiles.clear();
+    if siz.xtsiz == 0 || siz.ytsiz == 0 {
+        return;
+    }
+    let num_x_tiles = siz.xsiz.saturating_sub(siz.xtosiz).div_ceil(siz.xtsiz);
+    let num_y_tiles = siz.ysiz.saturating_sub(siz.ytosiz).div_ceil(siz.ytsiz);
+    for q in 0..num_y_tiles {
+        for p in 0..num_x_tiles {
+            let tx0 = (siz.xtosiz + p * siz.xtsiz).max(siz.xosiz);
+            let ty0 = (siz.ytosiz + q * siz.ytsiz).max(siz.yosiz);
+            let tx1 = (siz.xtosiz + (p + 1) * siz.xtsiz).min(siz.xsiz);
+            let ty1 = (siz.ytosiz + (q + 1) * siz.ytsiz).min(siz.ysiz);
+            let components = context
+                .components
+                .iter()
+                .map(|component| TileComponent {
+                    tcx0: tx0.div_ceil(component.xrsiz),
+                    tcy0: ty0.div_ceil(component.yrsiz),
+                    tcx1: tx1.div_ceil(component.xrsiz),
+                    tcy1: ty1.div_ceil(component.yrsiz),
+                    ..Default::default()
+                })
+                .collect();
+            context.tiles.push(Tile { components, ..Default::default() });
+        }
+    }
+}
+
+fn initialize_tile(context: &mut Context) -> Result<(), JpxError> {
+    let current = &context.current_tile;
+    let tile = context.tiles.get_mut(current.index).ok_or(JpxError::OutOfPackets)?;
+    for (c, component) in tile.components.iter_mut().enumerate() {
+        component.quantization =
+            current.qcc.get(c).cloned().flatten().unwrap_or_else(|| current.qcd.clone());
+        component.coding_style = current.cod.clone();
+    }
+    tile.cod = current.cod.clone();
+    Ok(())
+}
+
+/// Returns (PPx, PPy, xcb', ycb')
+fn get_blocks_dimensions(coding_style: &CodingStyle, r: usize) -> (u32, u32, u32, u32) {
+    let (ppx, ppy) = if coding_style.entropy_coder_with_custom_precincts {
+        coding_style.precincts_sizes.get(r).copied().unwrap_or((15, 15))
+    } else {
+        (15, 15)
+    };
+    // calculate codeblock size as described in section B.7
+    let xcb =
+        if r > 0 { coding_style.xcb.min(ppx.saturating_sub(1)) } else { coding_style.xcb.min(ppx) };
+    let ycb =
+        if r > 0 { coding_style.ycb.min(ppy.saturating_sub(1)) } else { coding_style.ycb.min(ppy) };
+    (ppx, ppy, xcb, ycb)
+}
+
+/// Section B.6 Division resolution to precincts
+fn build_precincts(resolution: &mut Resolution, r: usize, ppx: u32, ppy: u32) {
+    let precinct_width = 1 << ppx;
+    let precinct_height = 1 << ppy;
+    // The precincts of a resolution are mapped into its subbands at half the size, except for the
+    // lowest resolution which only has the LL band
+    let width_in_subband = if r == 0 { precinct_width } else { precinct_width >> 1 };
+    let height_in_subband = if r == 0 { precinct_height } else { precinct_height >> 1 };
+    let num_wide = if resolution.trx1 > resolution.trx0 {
+        resolution.trx1.div_ceil(precinct_width) - resolution.trx0 / precinct_width
+    } else {
+        0
+    };
+    let num_high = if resolution.try1 > resolution.try0 {
+        resolution.try1.div_ceil(precinct_height) - resolution.try0 / precinct_height
+    } else {
+        0
+    };
+    resolution.precincts = PrecinctParameters {
+        precinct_width,
+        precinct_height,
+        num_wide,
+        num_high,
+        count: num_wide * num_high,
+        width_in_subband: width_in_subband.max(1),
+        height_in_subband: height_in_subband.max(1),
+    };
+}
+
+/// Section B.7 Division sub-band into code-blocks
+fn build_codeblocks(subband: &mut SubBand, precincts: &PrecinctParameters, xcb: u32, ycb: u32) {
+    let codeblock_width = 1 << xcb;
+    let codeblock_height = 1 << ycb;
+    let cbx0 = subband.tbx0 >> xcb;
+    let cby0 = subband.tby0 >> ycb;
+    let cbx1 = (subband.tbx1 + codeblock_width - 1) >> xcb;
+    let cby1 = (subband.tby1 + codeblock_height - 1) >> ycb;
+    for j in cby0..cby1 {
+        for i in cbx0..cbx1 {
+            let codeblock = CodeBlock {
+                cbx: i,
+                cby: j,
+                tbx0: subband.tbx0.max(codeblock_width * i),
+                tby0: subband.tby0.max(codeblock_height * j),
+                tbx1: subband.tbx1.min(codeblock_width * (i + 1)),
+                tby1: subband.tby1.min(codeblock_height * (j + 1)),
+                lblock: 3,
+                ..Default::default()
+            };
+            if codeblock.tbx1 <= codeblock.tbx0 || codeblock.tby1 <= codeblock.tby0 {
+                continue;
+            }
+            // the precinct is found with the codeblock's position relative to its subband
+            let pi = (codeblock.tbx0 - subband.tbx0) / precincts.width_in_subband;
+            let pj = (codeblock.tby0 - subband.tby0) / precincts.height_in_subband;
+            let precinct_number = pi + pj * precincts.num_wide;
+            subband
+                .precincts
+                .entry(precinct_number)
+                .and_modify(|precinct| {
+                    precinct.cbx_min = precinct.cbx_min.min(i);
+                    precinct.cbx_max = precinct.cbx_max.max(i);
+                    precinct.cby_min = precinct.cby_min.min(j);
+                    precinct.cby_max = precinct.cby_max.max(j);
+                })
+                .or_insert(Precinct {
+                    cbx_min: i,
+                    cby_min: j,
+                    cbx_max: i,
+                    cby_max: j,
+                    trees: None,
+                });
+            subband.codeblocks.push(CodeBlock { precinct_number, ..codeblock });
+        }
+    }
+}
+
+/// Build the resolutions, subbands and codeblocks of the current tile and the order its packets
+/// are stored in
+fn build_packets(context: &mut Context) -> Result<(), JpxError> {
+    let tile = context.tiles.get_mut(context.current_tile.index).ok_or(JpxError::OutOfPackets)?;
+    // Section B.5 Resolution levels and sub-bands
+    for component in tile.components.iter_mut() {
+        let levels = component.coding_style.decomposition_levels_count;
+        let (tcx0, tcy0, tcx1, tcy1) =
+            (component.tcx0, component.tcy0, component.tcx1, component.tcy1);
+        component.resolutions.clear();
+        for r in 0..=levels {
+            let (ppx, ppy, xcb, ycb) = get_blocks_dimensions(&component.coding_style, r);
+            // 32 levels overflow a 32-bit usize. Such a scale rounds every coordinate up to 0 or 1
+            let scale = 1_usize.checked_shl((levels - r) as u32).unwrap_or(usize::MAX);
+            let mut resolution = Resolution {
+                trx0: tcx0.div_ceil(scale),
+                try0: tcy0.div_ceil(scale),
+                trx1: tcx1.div_ceil(scale),
+                try1: tcy1.div_ceil(scale),
+                ..Default::default()
+            };
+            build_precincts(&mut resolution, r, ppx, ppy);
+            if r == 0 {
+                // one sub-band (LL) with the last decomposition
+                resolution.subbands.push(SubBand {
+                    kind: SubBandType::LL,
+                    tbx0: resolution.trx0,
+                    tby0: resolution.try0,
+                    tbx1: resolution.trx1,
+                    tby1: resolution.try1,
+                    ..Default::default()
+                });
+            } else {
+                // three sub-bands (HL, LH and HH) with the rest of the decompositions
+                let bscale = 1 << (levels - r + 1);
+                resolution.subbands.push(SubBand {
+                    kind: SubBandType::HL,
+                    tbx0: ceil_half(tcx0, bscale),
+                    tby0: tcy0.div_ceil(bscale),
+                    tbx1: ceil_half(tcx1, bscale),
+                    tby1: tcy1.div_ceil(bscale),
+                    ..Default::default()
+                });
+                resolution.subbands.push(SubBand {
+                    kind: SubBandType::LH,
+                    tbx0: tcx0.div_ceil(bscale),
+                    tby0: ceil_half(tcy0, bscale),
+                    tbx1: tcx1.div_ceil(bscale),
+                    tby1: ceil_half(tcy1, bscale),
+                    ..Default::default()
+                });
+                resolution.subbands.push(SubBand {
+                    kind: SubBandType::HH,
+                    tbx0: ceil_half(tcx0, bscale),
+                    tby0: ceil_half(tcy0, bscale),
+                    tbx1: ceil_half(tcx1, bscale),
+                    tby1: ceil_half(tcy1, bscale),
+                    ..Default::default()
+                });
+            }
+            let precincts = resolution.precincts;
+            for subband in resolution.subbands.iter_mut() {
+                build_codeblocks(subband, &precincts, xcb, ycb);
+            }
+            component.resolutions.push(resolution);
+        }
+    }
+
+    // Section B.12 Progression order
+    let layers = tile.cod.layers_count;
+    let components = &tile.components;
+    let levels = |c: usize| components[c].coding_style.decomposition_levels_count;
+    let max_levels = (0..components.len()).map(levels).max().unwrap_or(0);
+    let count = |c: usize, r: usize| components[c].resolutions[r].precincts.count;
+    let mut packets = vec![];
+    let mut push = |layer, component, resolution, precinct| {
+        packets.push(PacketId { layer, component, resolution, precinct });
+    };
+    match tile.cod.progression_order {
+        // Layer-resolution-component-position
+        0 => {
+            for l in 0..layers {
+                for r in 0..=max_levels {
+                    for c in (0..components.len()).filter(|&c| r <= levels(c)) {
+                        for k in 0..count(c, r) {
+                            push(l, c, r, k);
+                        }
+                    }
+                }
+            }
+        }
+        // Resolution-layer-component-position
+        1 => {
+            for r in 0..=max_levels {
+                for l in 0..layers {
+                    for c in (0..components.len()).filter(|&c| r <= levels(c)) {
+                        for k in 0..count(c, r) {
+                            push(l, c, r, k);
+                        }
+                    }
+                }
+            }
+        }
+        // Resolution-position-component-layer
+        2 => {
+            for r in 0..=max_levels {
+                let max_precincts = (0..components.len())
+                    .filter(|&c| r <= levels(c))
+                    .map(|c| count(c, r))
+                    .max()
+                    .unwrap_or(0);
+                for k in 0..max_precincts {
+                    for c in (0..components.len()).filter(|&c| r <= levels(c) && k < count(c, r)) {
+                        for l in 0..layers {
+                            push(l, c, r, k);
+                        }
+                    }
+                }
+            }
+        }
+        // Position-component-resolution-layer
+        3 => {
+            let sizes = precinct_sizes_in_image_scale(components);
+            for py in 0..sizes.max_num_high {
+                for px in 0..sizes.max_num_wide {
+                    for c in 0..components.len() {
+                        for r in 0..=levels(c) {
+                            let size = sizes.components[c].resolutions[r];
+                            let resolution = &components[c].resolutions[r];
+                            if let Some(k) = precinct_index(px, py, size, &sizes, resolution) {
+                                for l in 0..layers {
+                                    push(l, c, r, k);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        // Component-position-resolution-layer
+        4 => {
+            let sizes = precinct_sizes_in_image_scale(components);
+            for c in 0..components.len() {
+                let component_sizes = &sizes.components[c];
+                for py in 0..component_sizes.max_num_high {
+                    for px in 0..component_sizes.max_num_wide {
+                        for r in 0..=levels(c) {
+                            let size = component_sizes.resolutions[r];
+                            let resolution = &components[c].resolutions[r];
+                            if let Some(k) = precinct_index(px, py, size, &sizes, resolution) {
+                                for l in 0..layers {
+                                    push(l, c, r, k);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        order => return Err(JpxError::UnsupportedProgressionOrder(order)),
+    }
+    tile.packets = packets;
+    tile.next_packet = 0;
+    Ok(())
+}
+
+#[derive(Debug, Default)]
+struct PrecinctSizes {
+    /// (width, height) of the precincts per resolution in the image's scale
+    resolutions: Vec<(usize, usize)>,
+    min_width: usize,
+    min_height: usize,
+    max_num_wide: usize,
+    max_num_high: usize,
+}
+
+#[derive(Debug, Default)]
+struct PrecinctSizesInImageScale {
+    components: Vec<PrecinctSizes>,
+    min_width: usize,
+    min_height: usize,
+    max_num_wide: usize,
+    max_num_high: usize,
+}
+
+fn precinct_sizes_in_image_scale(components: &[TileComponent]) -> PrecinctSizesInImageScale {
+    let mut sizes = PrecinctSizesInImageScale {
+        min_width: usize::MAX,
+        min_height: usize::MAX,
+        ..Default::default()
+    };
+    for component in components {
+        let levels = component.coding_style.decomposition_levels_count;
+        let mut current = PrecinctSizes {
+            resolutions: vec![(0, 0); levels + 1],
+            min_width: usize::MAX,
+            min_height: usize::MAX,
+            ..Default::default()
+        };
+        let mut scale = 1;
+        for r in (0..=levels).rev() {
+            let precincts = &component.resolutions[r].precincts;
+            let width = precincts.precinct_width.saturating_mul(scale);
+            let height = precincts.precinct_height.saturating_mul(scale);
+            current.min_width = current.min_width.min(width);
+            current.min_height = current.min_height.min(height);
+            current.max_num_wide = current.max_num_wide.max(precincts.num_wide);
+            current.max_num_high = current.max_num_high.max(precincts.num_high);
+            current.resolutions[r] = (width, height);
+            scale <<= 1;
+        }
+        sizes.min_width = sizes.min_width.min(current.min_width);
+        sizes.min_height = sizes.min_height.min(current.min_height);
+        sizes.max_num_wide = sizes.max_num_wide.max(current.max_num_wide);
+        sizes.max_num_high = sizes.max_num_high.max(current.max_num_high);
+        sizes.components.push(current);
+    }
+    sizes
+}
+
+fn precinct_index(
+    px: usize,
+    py: usize,
+    (width, height): (usize, usize),
+    sizes: &PrecinctSizesInImageScale,
+    resolution: &Resolution,
+) -> Option<usize> {
+    let pos_x = px * sizes.min_width;
+    let pos_y = py * sizes.min_height;
+    if !pos_x.is_multiple_of(width) || !pos_y.is_multiple_of(height) {
+        return None;
+    }
+    Some(pos_x / width + (pos_y / height) * resolution.precincts.num_wide)
+}
+
+/// Reads packet headers, which stuff a zero bit after every 0xff byte
+struct PacketReader<'a> {
+    data: &'a [u8],
+    offset: usize,
+    position: usize,
+    buffer: u64,
+    buffer_size: u32,
+    skip_next_bit: bool,
+}
+impl PacketReader<'_> {
+    fn read_bits(&mut self, count: u32) -> Result<u32, JpxError> {
+        while self.buffer_size < count {
+            let b = read_u8(self.data, self.offset + self.position)?;
+            self.position += 1;
+            if self.skip_next_bit {
+                self.buffer = (self.buffer << 7) | b as u64;
+                self.buffer_size += 7;
+                self.skip_next_bit = false;
+            } else {
+                self.buffer = (self.buffer << 8) | b as u64;
+                self.buffer_size += 8;
+            }
+            if b == 0xff {
+                self.skip_next_bit = true;
+            }
+        }
+        self.buffer_size -= count;
+        Ok(((self.buffer >> self.buffer_size) & ((1 << count) - 1)) as u32)
+    }
+
+    fn skip_marker_if_equal(&mut self, value: u8) -> bool {
+        let at = |i: usize| self.data.get(i).copied();
+        let position = self.offset + self.position;
+        if position > 0 && at(position - 1) == Some(0xff) && at(position) == Some(value) {
+            self.position += 1;
+            true
+        } else if at(position) == Some(0xff) && at(position + 1) == Some(value) {
+            self.position += 2;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn align_to_byte(&mut self) {
+        self.buffer_size = 0;
+        if self.skip_next_bit {
+            self.position += 1;
+            self.skip_next_bit = false;
+        }
+    }
+
+    fn read_coding_passes(&mut self) -> Result<usize, JpxError> {
+        if self.read_bits(1)? == 0 {
+            return Ok(1);
+        }
+        if self.read_bits(1)? == 0 {
+            return Ok(2);
+        }
+        let value = self.read_bits(2)? as usize;
+        if value < 3 {
+            return Ok(value + 3);
+        }
+        let value = self.read_bits(5)? as usize;
+        if value < 31 {
+            return Ok(value + 6);
+        }
+        Ok(self.read_bits(7)? as usize + 37)
+    }
+}
+
+/// Section B.10 Packet header information coding
+fn parse_tile_packets(
+    context: &mut Context,
+    data: &[u8],
+    offset: usize,
+    data_length: usize,
+) -> Result<(), JpxError> {
+    let (sop_marker_used, eph_marker_used) =
+        (context.cod.sop_marker_used, context.cod.eph_marker_used);
+    let tile = context.tiles.get_mut(context.current_tile.index).ok_or(JpxError::OutOfPackets)?;
+    let mut reader =
+        PacketReader { data, offset, position: 0, buffer: 0, buffer_size: 0, skip_next_bit: false };
+    while reader.position < data_length {
+        reader.align_to_byte();
+        // skip the marker segment length and packet sequence ID too
+        if sop_marker_used && reader.skip_marker_if_equal(0x91) {
+            reader.position += 4;
+        }
+        let packet = *tile.packets.get(tile.next_packet).ok_or(JpxError::OutOfPackets)?;
+        tile.next_packet += 1;
+        if reader.read_bits(1)? == 0 {
+            continue;
+        }
+        let resolution = &mut tile.components[packet.component].resolutions[packet.resolution];
+        // (subband, codeblock, coding passes, data length)
+        let mut queue = vec![];
+        // Section B.10.8 Order of info in packet, the subbands are already ordered LL, HL, LH, HH
+        for (s, subband) in resolution.subbands.iter_mut().enumerate() {
+            let SubBand { codeblocks, precincts, .. } = subband;
+            for (b, codeblock) in codeblocks.iter_mut().enumerate() {
+                if codeblock.precinct_number != packet.precinct {
+                    continue;
+                }
+                let Some(precinct) = precincts.get_mut(&codeblock.precinct_number) else {
+                    continue;
+                };
+                let column = codeblock.cbx - precinct.cbx_min;
+                let row = codeblock.cby - precinct.cby_min;
+                // the inclusion and zero bit-planes trees are built on the precinct's first packet
+                let width = precinct.cbx_max - precinct.cbx_min + 1;
+                let height = precinct.cby_max - precinct.cby_min + 1;
+                let (inclusion_tree, zero_bit_planes_tree) =
+                    precinct.trees.get_or_insert_with(|| {
+                        (
+                            InclusionTree::new(width, height, packet.layer),
+                            TagTree::new(width, height),
+                        )
+                    });
+                let mut included = false;
+                let mut first_time_inclusion = false;
+                if codeblock.included {
+                    included = reader.read_bits(1)? != 0;
+                } else if inclusion_tree.reset(column, row, packet.layer) {
+                    loop {
+                        if reader.read_bits(1)? != 0 {
+                            if !inclusion_tree.next_level() {
+                                codeblock.included = true;
+                                included = true;
+                                first_time_inclusion = true;
+                                break;
+                            }
+                        } else {
+                            inclusion_tree.increment_value(packet.layer);
+                            break;
+                        }
+                    }
+                }
+                if !included {
+                    continue;
+                }
+                if first_time_inclusion {
+                    zero_bit_planes_tree.reset(column, row);
+                    loop {
+                        if reader.read_bits(1)? != 0 {
+                            if !zero_bit_planes_tree.next_level() {
+                                break;
+                            }
+                        } else {
+                            zero_bit_planes_tree.increment_value();
+                        }
+                    }
+                    codeblock.zero_bit_planes = zero_bit_planes_tree.value;
+                }
+                let coding_passes = reader.read_coding_passes()?;
+                while reader.read_bits(1)? != 0 {
+                    codeblock.lblock += 1;
+                }
+                // rounding down log2
+                let passes_log2 = log2(coding_passes);
+                let bits =
+                    if coding_passes < 1 << passes_log2 { passes_log2 - 1 } else { passes_log2 }
+                        + codeblock.lblock;
+                let length = reader.read_bits(bits)? as usize;
+                queue.push((s, b, coding_passes, length));
+            }
+        }
+        reader.align_to_byte();
+        if eph_marker_used {
+            reader.skip_marker_if_equal(0x92);
+        }
+        for (s, b, coding_passes, length) in queue {
+            let codeblock = &mut resolution.subbands[s].codeblocks[b];
+            let start = (offset + reader.position).min(data.len());
+            let end = (offset + reader.position + length).min(data.len());
+            codeblock.data.extend_from_slice(&data[start..end]);
+            codeblock.coding_passes += coding_passes;
+            reader.position += length;
+        }
+    }
+    Ok(())
+}
+
+/// Section B.10.2 Tag trees
+#[derive(Debug)]
+struct TagTree {
+    /// (width, items, index) per level
+    levels: Vec<(usize, Vec<Option<u32>>, usize)>,
+    current_level: usize,
+    value: u32,
+}
+impl TagTree {
+    fn new(mut width: usize, mut height: usize) -> Self {
+        let mut levels = vec![];
+        for _ in 0..=log2(width.max(height)) {
+            levels.push((width, vec![None; width * height], 0));
+            width = width.div_ceil(2);
+            height = height.div_ceil(2);
+        }
+        TagTree { levels, current_level: 0, value: 0 }
+    }
+
+    fn reset(&mut self, mut i: usize, mut j: usize) {
+        let mut current_level = 0;
+        let mut value = 0;
+        while current_level < self.levels.len() {
+            let (width, items, index) = &mut self.levels[current_level];
+            let position = i + j * *width;
+            if let Some(v) = items[position] {
+                value = v;
+                break;
+            }
+            *index = position;
+            i >>= 1;
+            j >>= 1;
+            current_level += 1;
+        }
+        let current_level = current_level.saturating_sub(1);
+        let (_, items, index) = &mut self.levels[current_level];
+        items[*index] = Some(value);
+        self.current_level = current_level;
+        self.value = 0;
+    }
+
+    fn increment_value(&mut self) {
+        let (_, items, index) = &mut self.levels[self.current_level];
+        items[*index] = Some(items[*index].unwrap_or(0) + 1);
+    }
+
+    fn next_level(&mut self) -> bool {
+        let (_, items, index) = &self.levels[self.current_level];
+        let value = items[*index];
+        if self.current_level == 0 {
+            self.value = value.unwrap_or(0);
+            return false;
+        }
+        self.current_level -= 1;
+        let (_, items, index) = &mut self.levels[self.current_level];
+        items[*index] = value;
+        true
+    }
+}
+
+/// Section B.10.3 Inclusion trees
+#[derive(Debug)]
+struct InclusionTree {
+    /// (width, items, index) per level
+    levels: Vec<(usize, Vec<usize>, usize)>,
+    current_level: usize,
+}
+impl InclusionTree {
+    /// Marks a node whose codeblock has been included
+    const INCLUDED: usize = usize::MAX;
+
+    fn new(mut width: usize, mut height: usize, default_value: usize) -> Self {
+        let mut levels = vec![];
+        for _ in 0..=log2(width.max(height)) {
+            levels.push((width, vec![default_value; width * height], 0));
+            width = width.div_ceil(2);
+            height = height.div_ceil(2);
+        }
+        InclusionTree { levels, current_level: 0 }
+    }
+
+    fn reset(&mut self, mut i: usize, mut j: usize, stop_value: usize) -> bool {
+        let mut current_level = 0;
+        while current_level < self.levels.len() {
+            let (width, items, index) = &mut self.levels[current_level];
+            *index = i + j * *width;
+            let value = items[*index];
+            if value == Self::INCLUDED {
+                break;
+            }
+            if value > stop_value {
+                self.current_level = current_level;
+                // already known, propagate the value to the top levels
+                self.propagate_values();
+                return false;
+            }
+            i >>= 1;
+            j >>= 1;
+            current_level += 1;
+        }
+        self.current_level = current_level.saturating_sub(1);
+        true
+    }
+
+    fn increment_value(&mut self, stop_value: usize) {
+        let (_, items, index) = &mut self.levels[self.current_level];
+        items[*index] = stop_value + 1;
+        self.propagate_values();
+    }
+
+    fn propagate_values(&mut self) {
+        let (_, items, index) = &self.levels[self.current_level];
+        let value = items[*index];
+        for (_, items, index) in self.levels[..self.current_level].iter_mut() {
+            items[*index] = value;
+        }
+    }
+
+    fn next_level(&mut self) -> bool {
+        let (_, items, index) = &mut self.levels[self.current_level];
+        let value = items[*index];
+        items[*index] = Self::INCLUDED;
+        if self.current_level == 0 {
+            return false;
+        }
+        self.current_level -= 1;
+        let (_, items, index) = &mut self.levels[self.current_level];
+        items[*index] = value;
+        true
+    }
+}
+
+const UNIFORM_CONTEXT: usize = 17;
+const RUNLENGTH_CONTEXT: usize = 18;
+
+/// Table D-1, indexed by 0dddvvhh: the sum of diagonal, vertical and horizontal neighbours
+const LL_LH_CONTEXT_LABELS: [u8; 75] = [
+    0, 5, 8, 0, 3, 7, 8, 0, 4, 7, 8, 0, 0, 0, 0, 0, 1, 6, 8, 0, 3, 7, 8, 0, 4, 7, 8, 0, 0, 0, 0, 0,
+    2, 6, 8, 0, 3, 7, 8, 0, 4, 7, 8, 0, 0, 0, 0, 0, 2, 6, 8, 0, 3, 7, 8, 0, 4, 7, 8, 0, 0, 0, 0, 0,
+    2, 6, 8, 0, 3, 7, 8, 0, 4, 7, 8,
+];
+const HL_CONTEXT_LABELS: [u8; 75] = [
+    0, 3, 4, 0, 5, 7, 7, 0, 8, 8, 8, 0, 0, 0, 0, 0, 1, 3, 4, 0, 6, 7, 7, 0, 8, 8, 8, 0, 0, 0, 0, 0,
+    2, 3, 4, 0, 6, 7, 7, 0, 8, 8, 8, 0, 0, 0, 0, 0, 2, 3, 4, 0, 6, 7, 7, 0, 8, 8, 8, 0, 0, 0, 0, 0,
+    2, 3, 4, 0, 6, 7, 7, 0, 8, 8, 8,
+];
+const HH_CONTEXT_LABELS: [u8; 75] = [
+    0, 1, 2, 0, 1, 2, 2, 0, 2, 2, 2, 0, 0, 0, 0, 0, 3, 4, 5, 0, 4, 5, 5, 0, 5, 5, 5, 0, 0, 0, 0, 0,
+    6, 7, 7, 0, 7, 7, 7, 0, 7, 7, 7, 0, 0, 0, 0, 0, 8, 8, 8, 0, 8, 8, 8, 0, 8, 8, 8, 0, 0, 0, 0, 0,
+    8, 8, 8, 0, 8, 8, 8, 0, 8, 8, 8,
+];
+
+const PROCESSED: u8 = 1;
+const FIRST_MAGNITUDE_BIT: u8 = 2;
+
+/// Section D. Coefficient bit modeling
+struct BitModel<'a> {
+    width: usize,
+    height: usize,
+    labels: &'static [u8; 75],
+    neighbors_significance: Vec<u8>,
+    coefficients_sign: Vec<u8>,
+    coefficients_magnitude: Vec<u32>,
+    processing_flags: Vec<u8>,
+    bits_decoded: Vec<u8>,
+    contexts: [u8; 19],
+    decoder: ArithmeticDecoder<'a>,
+}
+impl<'a> BitModel<'a> {
+    fn new(
+        width: usize,
+        height: usize,
+        kind: SubBandType,
+        zero_bit_planes: u32,
+        decoder: ArithmeticDecoder<'a>,
+    ) -> Self {
+        let count = width * height;
+        let mut contexts = [0; 19];
+        // contexts pack the state index in the upper 7 bits and the MPS in the lowest bit
+        contexts[0] = 4 << 1;
+        contexts[UNIFORM_CONTEXT] = 46 << 1;
+        contexts[RUNLENGTH_CONTEXT] = 3 << 1;
+        BitModel {
+            width,
+            height,
+            labels: match kind {
+                SubBandType::HH => &HH_CONTEXT_LABELS,
+                SubBandType::HL => &HL_CONTEXT_LABELS,
+                _ => &LL_LH_CONTEXT_LABELS,
+            },
+            neighbors_significance: vec![0; count],
+            coefficients_sign: vec![0; count],
+            coefficients_magnitude: vec![0; count],
+            processing_flags: vec![0; count],
+            bits_decoded: vec![zero_bit_planes as u8; count],
+            contexts,
+            decoder,
+        }
+    }
+
+    fn read_bit(&mut self, context: usize) -> u32 {
+        self.decoder.read_bit(&mut self.contexts, context)
+    }
+
+    fn set_neighbors_significance(&mut self, row: usize, column: usize, index: usize) {
+        let width = self.width;
+        let ns = &mut self.neighbors_significance;
+        let left = column > 0;
+        let right = column + 1 < width;
+        let mut mark = |i: usize, value: u8| ns[i] = ns[i].wrapping_add(value);
+        if row > 0 {
+            let i = index - width;
+            if left {
+                mark(i - 1, 0x10);
+            }
+            if right {
+                mark(i + 1, 0x10);
+            }
+            mark(i, 0x04);
+        }
+        if row + 1 < self.height {
+            let i = index + width;
+            if left {
+                mark(i - 1, 0x10);
+            }
+            if right {
+                mark(i + 1, 0x10);
+            }
+            mark(i, 0x04);
+        }
+        if left {
+            mark(index - 1, 0x01);
+        }
+        if right {
+            mark(index + 1, 0x01);
+        }
+        ns[index] |= 0x80;
+    }
+
+    fn set_significant(&mut self, row: usize, column: usize, index: usize) {
+        self.coefficients_sign[index] = self.decode_sign_bit(row, column, index);
+        self.coefficients_magnitude[index] = 1;
+        self.set_neighbors_significance(row, column, index);
+        self.processing_flags[index] |= FIRST_MAGNITUDE_BIT;
+    }
+
+    fn run_significance_propagation_pass(&mut self) {
+        let (width, height) = (self.width, self.height);
+        for i0 in (0..height).step_by(4) {
+            for j in 0..width {
+                let mut index = i0 * width + j;
+                for i in i0..(i0 + 4).min(height) {
+                    // clear the processed flag first
+                    self.processing_flags[index] &= !PROCESSED;
+                    if self.coefficients_magnitude[index] == 0
+                        && self.neighbors_significance[index] != 0
+                    {
+                        let label = self.labels[self.neighbors_significance[index] as usize];
+                        if self.read_bit(label as usize) != 0 {
+                            self.set_significant(i, j, index);
+                        }
+                        self.bits_decoded[index] += 1;
+                        self.processing_flags[index] |= PROCESSED;
+                    }
+                    index += width;
+                }
+            }
+        }
+    }
+
+    fn decode_sign_bit(&mut self, row: usize, column: usize, index: usize) -> u8 {
+        let (width, height) = (self.width, self.height);
+        let magnitude = &self.coefficients_magnitude;
+        let sign = |i: usize| self.coefficients_sign[i] as i32;
+
+        // the horizontal contribution
+        let significance1 = column > 0 && magnitude[index - 1] != 0;
+        let contribution = if column + 1 < width && magnitude[index + 1] != 0 {
+            let sign1 = sign(index + 1);
+            if significance1 {
+                1 - sign1 - sign(index - 1)
+            } else {
+                1 - sign1 - sign1
+            }
+        } else if significance1 {
+            1 - sign(index - 1) - sign(index - 1)
+        } else {
+            0
+        };
+        let horizontal = 3 * contribution;
+
+        // combined with the vertical contribution
+        let significance1 = row > 0 && magnitude[index - width] != 0;
+        let contribution = if row + 1 < height && magnitude[index + width] != 0 {
+            let sign1 = sign(index + width);
+            if significance1 {
+                1 - sign1 - sign(index - width) + horizontal
+            } else {
+                1 - sign1 - sign1 + horizontal
+            }
+        } else if significance1 {
+            1 - sign(index - width) - sign(index - width) + horizontal
+        } else {
+            horizontal
+        };
+
+        if contribution >= 0 {
+            self.read_bit((9 + contribution) as usize) as u8
+        } else {
+            (self.read_bit((9 - contribution) as usize) ^ 1) as u8
+        }
+    }
+
+    fn run_magnitude_refinement_pass(&mut self) {
+        let width = self.width;
+        let length = width * self.height;
+        let mut index0 = 0;
+        while index0 < length {
+            let index_next = length.min(index0 + width * 4);
+            for j in 0..width {
+                for index in (index0 + j..index_next).step_by(width) {
+                    // significant, but not the ones that just became significant
+                    if self.coefficients_magnitude[index] == 0
+                        || self.processing_flags[index] & PROCESSED != 0
+                    {
+                        continue;
+                    }
+                    let mut context = 16;
+                    if self.processing_flags[index] & FIRST_MAGNITUDE_BIT != 0 {
+                        self.processing_flags[index] ^= FIRST_MAGNITUDE_BIT;
+                        // first refinement
+                        context =
+                            if self.neighbors_significance[index] & 127 == 0 { 15 } else { 14 };
+                    }
+                    let bit = self.read_bit(context);
+                    self.coefficients_magnitude[index] =
+                        (self.coefficients_magnitude[index] << 1) | bit;
+                    self.bits_decoded[index] += 1;
+                    self.processing_flags[index] |= PROCESSED;
+                }
+            }
+            index0 = index_next;
+        }
+    }
+
+    fn run_cleanup_pass(&mut self) {
+        let (width, height) = (self.width, self.height);
+        let mut i0 = 0;
+        while i0 < height {
+            let i_next = (i0 + 4).min(height);
+            let check_all_empty = i0 + 3 < height;
+            for j in 0..width {
+                let index0 = i0 * width + j;
+                // labels[neighbors_significance[index]] is 0 when neighbors_significance[index] is 0
+                let all_empty = check_all_empty
+                    && (0..4).all(|k| {
+                        self.processing_flags[index0 + k * width] == 0
+                            && self.neighbors_significance[index0 + k * width] == 0
+                    });
+                let mut i1 = 0;
+                let mut index = index0;
+                if all_empty {
+                    if self.read_bit(RUNLENGTH_CONTEXT) == 0 {
+                        for k in 0..4 {
+                            self.bits_decoded[index0 + k * width] += 1;
+                        }
+                        continue;
+                    }
+                    i1 = ((self.read_bit(UNIFORM_CONTEXT) << 1) | self.read_bit(UNIFORM_CONTEXT))
+                        as usize;
+                    let i = i0 + i1;
+                    index += i1 * width;
+                    self.set_significant(i, j, index);
+
+                    index = index0;
+                    for _ in i0..=i {
+                        self.bits_decoded[index] += 1;
+                        index += width;
+                    }
+                    i1 += 1;
+                }
+                for i in i0 + i1..i_next {
+                    if self.coefficients_magnitude[index] == 0
+                        && self.processing_flags[index] & PROCESSED == 0
+                    {
+                        let label = self.labels[self.neighbors_significance[index] as usize];
+                        if self.read_bit(label as usize) == 1 {
+                            self.set_significant(i, j, index);
+                        }
+                        self.bits_decoded[index] += 1;
+                    }
+                    index += width;
+                }
+            }
+            i0 = i_next;
+        }
+    }
+
+    fn check_segmentation_symbol(&mut self) -> Result<(), JpxError> {
+        let mut symbol = 0;
+        for _ in 0..4 {
+            symbol = (symbol << 1) | self.read_bit(UNIFORM_CONTEXT);
+        }
+        if symbol != 0xa {
+            return Err(JpxError::InvalidSegmentationSymbol);
+        }
+        Ok(())
+    }
+}
+
+/// Table C-2: (Qe, NMPS, NLPS, SWITCH)
+const QE_TABLE: [(u32, u8, u8, bool); 47] = [
+    (0x5601, 1, 1, true),
+    (0x3401, 2, 6, false),
+    (0x1801, 3, 9, false),
+    (0x0ac1, 4, 12, false),
+    (0x0521, 5, 29, false),
+    (0x0221, 38, 33, false),
+    (0x5601, 7, 6, true),
+    (0x5401, 8, 14, false),
+    (0x4801, 9, 14, false),
+    (0x3801, 10, 14, false),
+    (0x3001, 11, 17, false),
+    (0x2401, 12, 18, false),
+    (0x1c01, 13, 20, false),
+    (0x1601, 29, 21, false),
+    (0x5601, 15, 14, true),
+    (0x5401, 16, 14, false),
+    (0x5101, 17, 15, false),
+    (0x4801, 18, 16, false),
+    (0x3801, 19, 17, false),
+    (0x3401, 20, 18, false),
+    (0x3001, 21, 19, false),
+    (0x2801, 22, 19, false),
+    (0x2401, 23, 20, false),
+    (0x2201, 24, 21, false),
+    (0x1c01, 25, 22, false),
+    (0x1801, 26, 23, false),
+    (0x1601, 27, 24, false),
+    (0x1401, 28, 25, false),
+    (0x1201, 29, 26, false),
+    (0x1101, 30, 27, false),
+    (0x0ac1, 31, 28, false),
+    (0x09c1, 32, 29, false),
+    (0x08a1, 33, 30, false),
+    (0x0521, 34, 31, false),
+    (0x0441, 35, 32, false),
+    (0x02a1, 36, 33, false),
+    (0x0221, 37, 34, false),
+    (0x0141, 38, 35, false),
+    (0x0111, 39, 36, false),
+    (0x0085, 40, 37, false),
+    (0x0049, 41, 38, false),
+    (0x0025, 42, 39, false),
+    (0x0015, 43, 40, false),
+    (0x0009, 44, 41, false),
+    (0x0005, 45, 42, false),
+    (0x0001, 45, 43, false),
+    (0x5601, 46, 46, false),
+];
+
+/// Annex C.3 Arithmetic (MQ) decoding procedure
+struct ArithmeticDecoder<'a> {
+    data: &'a [u8],
+    position: usize,
+    a: u32,
+    chigh: u32,
+    clow: u32,
+    ct: i32,
+}
+impl<'a> ArithmeticDecoder<'a> {
+    /// C.3.5 Initialisation of the decoder (INITDEC)
+    fn new(data: &'a [u8]) -> Self {
+        let mut decoder = ArithmeticDecoder {
+            data,
+            position: 0,
+            a: 0x8000,
+            chigh: data.first().copied().unwrap_or(0) as u32,
+            clow: 0,
+            ct: 0,
+        };
+        decoder.byte_in();
+        decoder.chigh = ((decoder.chigh << 7) & 0xffff) | ((decoder.clow >> 9) & 0x7f);
+        decoder.clow = (decoder.clow << 7) & 0xffff;
+        decoder.ct -= 7;
+        decoder
+    }
+
+    fn at(&self, position: usize) -> u32 {
+        self.data.get(position).copied().unwrap_or(0) as u32
+    }
+
+    /// C.3.4 Compressed data input (BYTEIN)
+    fn byte_in(&mut self) {
+        let bp = self.position;
+        if self.at(bp) == 0xff && bp < self.data.len() {
+            if self.at(bp + 1) > 0x8f {
+                self.clow += 0xff00;
+                self.ct = 8;
+            } else {
+                self.position += 1;
+                self.clow += self.at(self.position) << 9;
+                self.ct = 7;
+            }
+        } else {
+            self.position += 1;
+            self.clow +=
+                if self.position < self.data.len() { self.at(self.position) << 8 } else { 0xff00 };
+            self.ct = 8;
+        }
+        if self.clow > 0xffff {
+            self.chigh += self.clow >> 16;
+            self.clow &= 0xffff;
+        }
+    }
+
+    /// C.3.2 Decoding a decision (DECODE)
+    fn read_bit(&mut self, contexts: &mut [u8], pos: usize) -> u32 {
+        let mut index = (contexts[pos] >> 1) as usize;
+        let mut mps = (contexts[pos] & 1) as u32;
+        let (qe, nmps, nlps, switch) = QE_TABLE[index];
+        let mut a = self.a.wrapping_sub(qe);
+        let d;
+        if self.chigh < qe {
+            // exchange LPS
+            if a < qe {
+                a = qe;
+                d = mps;
+                index = nmps as usize;
+            } else {
+                a = qe;
+                d = 1 ^ mps;
+                if switch {
+                    mps = d;
+                }
+                index = nlps as usize;
+            }
+        } else {
+            self.chigh -= qe;
+            if a & 0x8000 != 0 {
+                self.a = a;
+                return mps;
+            }
+            // exchange MPS
+            if a < qe {
+                d = 1 ^ mps;
+                if switch {
+                    mps = d;
+                }
+                index = nlps as usize;
+            } else {
+                d = mps;
+                index = nmps as usize;
+            }
+        }
+        // C.3.3 RENORMD
+        loop {
+            if self.ct == 0 {
+                self.byte_in();
+            }
+            a <<= 1;
+            self.chigh = ((self.chigh << 1) & 0xffff) | ((self.clow >> 15) & 1);
+            self.clow = (self.clow << 1) & 0xffff;
+            self.ct -= 1;
+            if a & 0x8000 != 0 {
+                break;
+            }
+        }
+        self.a = a;
+        contexts[pos] = ((index as u8) << 1) | mps as u8;
+        d
+    }
+}
+
+/// Decode the codeblocks of a subband into the coefficients of its resolution level. The HL, LH
+/// and HH bands are interleaved in place (Section F.3.3), the LL band is copied as is.
+fn copy_coefficients(
+    coefficients: &mut [f32],
+    level_width: usize,
+    subband: &SubBand,
+    delta: f64,
+    mb: i32,
+    reversible: bool,
+    segmentation_symbol_used: bool,
+) -> Result<(), JpxError> {
+    let (x0, y0) = (subband.tbx0, subband.tby0);
+    let width = subband.tbx1 - subband.tbx0;
+    let right = matches!(subband.kind, SubBandType::HL | SubBandType::HH) as usize;
+    let bottom =
+        if matches!(subband.kind, SubBandType::LH | SubBandType::HH) { level_width } else { 0 };
+    let interleave = subband.kind != SubBandType::LL;
+    let magnitude_correction = if reversible { 0. } else { 0.5 };
+
+    for codeblock in subband.codeblocks.iter() {
+        let block_width = codeblock.tbx1 - codeblock.tbx0;
+        let block_height = codeblock.tby1 - codeblock.tby0;
+        if block_width == 0 || block_height == 0 || !codeblock.included {
+            continue;
+        }
+        // the first bit plane has a single cleanup pass, every other one three passes. The
+        // magnitudes are 32-bit and the decoded bit counts 8-bit
+        let bit_planes = codeblock.coding_passes.div_ceil(3);
+        if bit_planes > 31 || codeblock.zero_bit_planes as usize + bit_planes > u8::MAX as usize {
+            return Err(JpxError::TooManyBitPlanes);
+        }
+        let decoder = ArithmeticDecoder::new(&codeblock.data);
+        let mut model = BitModel::new(
+            block_width,
+            block_height,
+            subband.kind,
+            codeblock.zero_bit_planes,
+            decoder,
+        );
+        // the first bit plane starts with a cleanup pass
+        let mut pass = 2;
+        for _ in 0..codeblock.coding_passes {
+            match pass {
+                0 => model.run_significance_propagation_pass(),
+                1 => model.run_magnitude_refinement_pass(),
+                _ => {
+                    model.run_cleanup_pass();
+                    if segmentation_symbol_used {
+                        model.check_segmentation_symbol()?;
+                    }
+                }
+            }
+            pass = (pass + 1) % 3;
+        }
+
+        let mut offset = codeblock.tbx0 - x0 + (codeblock.tby0 - y0) * width;
+        let mut position = 0;
+        for _ in 0..block_height {
+            // the row in the non-interleaved subband
+            let row = offset / width;
+            let level_offset = 2 * row * (level_width - width) + right + bottom;
+            for _ in 0..block_width {
+                let n = model.coefficients_magnitude[position];
+                if n != 0 {
+                    let mut n = (n as f64 + magnitude_correction) * delta;
+                    if model.coefficients_sign[position] != 0 {
+                        n = -n;
+                    }
+                    let nb = model.bits_decoded[position] as i32;
+                    let pos = if interleave { level_offset + (offset << 1) } else { offset };
+                    let value =
+                        if reversible && nb >= mb { n } else { n * pow(2., (mb - nb) as f64) };
+                    if let Some(coefficient) = coefficients.get_mut(pos) {
+                        *coefficient = value as f32;
+                    }
+                }
+                offset += 1;
+                position += 1;
+            }
+            offset += width - block_width;
+        }
+    }
+    Ok(())
+}
+
+/// A resolution level's coefficients
+struct Level {
+    width: usize,
+    height: usize,
+    items: Vec<f32>,
+}
+
+const BUFFER_PADDING: usize = 4;
+
+/// Section F.3.7 Periodic symmetric extension, by at most 4 samples
+fn extend(buffer: &mut [f32], offset: usize, size: usize) {
+    let (mut i1, mut j1) = (offset - 1, offset + 1);
+    let (mut i2, mut j2) = (offset + size - 2, offset + size);
+    for _ in 0..3 {
+        buffer[i1] = buffer[j1];
+        buffer[j2] = buffer[i2];
+        i1 -= 1;
+        j1 += 1;
+        j2 += 1;
+        i2 = i2.wrapping_sub(1);
+    }
+    buffer[i1] = buffer[j1];
+    buffer[j2] = buffer[i2];
+}
+
+/// Section F.3.8.2 Irreversible 9-7 filter
+fn irreversible_filter(x: &mut [f32], offset: usize, length: usize) {
+    const ALPHA: f64 = -1.586134342059924;
+    const BETA: f64 = -0.052980118572961;
+    const GAMMA: f64 = 0.882911075530934;
+    const DELTA: f64 = 0.443506852043971;
+    const K: f64 = 1.230174104914001;
+    const K_: f64 = 1. / K;
+    let len = length >> 1;
+
+    // step 2
+    for n in 0..len + 4 {
+        let j = offset - 3 + 2 * n;
+        x[j] = (x[j] as f64 * K_) as f32;
+    }
+    // step 1 & 3, the neighbours are subtracted in alternating order like the reference decoder
+    for n in 0..len + 3 {
+        let j = offset - 2 + 2 * n;
+        let (left, right) = (DELTA * x[j - 1] as f64, DELTA * x[j + 1] as f64);
+        let (first, second) = if n % 2 == 0 { (left, right) } else { (right, left) };
+        x[j] = (K * x[j] as f64 - first - second) as f32;
+    }
+    // steps 4, 5 and 6
+    for (start, count, factor) in
+        [(offset - 1, len + 2, GAMMA), (offset, len + 1, BETA), (offset + 1, len, ALPHA)]
+    {
+        for n in 0..count {
+            let j = start + 2 * n;
+            x[j] = (x[j] as f64 - (factor * x[j - 1] as f64 + factor * x[j + 1] as f64)) as f32;
+        }
+    }
+}
+
+/// Section F.3.8.1 Reversible 5-3 filter
+fn reversible_filter(x: &mut [f32], offset: usize, length: usize) {
+    let len = length >> 1;
+    for n in 0..len + 1 {
+        let j = offset + 2 * n;
+        x[j] = (x[j] as f64 - ((x[j - 1] as f64 + x[j + 1] as f64 + 2.) as i32 >> 2) as f64) as f32;
+    }
+    for n in 0..len {
+        let j = offset + 1 + 2 * n;
+        x[j] = (x[j] as f64 + ((x[j - 1] as f64 + x[j + 1] as f64) as i32 >> 1) as f64) as f32;
+    }
+}
+
+/// Section F.3.2 Inverse discrete wavelet transform of one level
+fn iterate(ll: Level, mut level: Level, u0: usize, v0: usize, reversible: bool) -> Level {
+    let filter = if reversible { reversible_filter } else { irreversible_filter };
+    let (width, height) = (level.width, level.height);
+    let items = &mut level.items;
+
+    // interleave LL according to Section F.3.3
+    let mut k = 0;
+    for i in 0..ll.height {
+        let mut l = i * 2 * width;
+        for _ in 0..ll.width {
+            if let (Some(item), Some(value)) = (items.get_mut(l), ll.items.get(k)) {
+                *item = *value;
+            }
+            k += 1;
+            l += 2;
+        }
+    }
+
+    // Section F.3.4 HOR_SR
+    if width == 1 {
+        // when u0 is even keep the items as is, when odd divide them by 2
+        if u0 & 1 != 0 {
+            items[..height].iter_mut().for_each(|item| *item *= 0.5);
+        }
+    } else if width > 1 {
+        let mut buffer = vec![0.; width + 2 * BUFFER_PADDING];
+        for row in items.chunks_exact_mut(width) {
+            buffer[BUFFER_PADDING..BUFFER_PADDING + width].copy_from_slice(row);
+            extend(&mut buffer, BUFFER_PADDING, width);
+            filter(&mut buffer, BUFFER_PADDING, width);
+            row.copy_from_slice(&buffer[BUFFER_PADDING..BUFFER_PADDING + width]);
+        }
+    }
+
+    // Section F.3.5 VER_SR
+    if height == 1 {
+        // when v0 is even keep the items as is, when odd divide them by 2
+        if v0 & 1 != 0 {
+            items[..width].iter_mut().for_each(|item| *item *= 0.5);
+        }
+    } else if height > 1 {
+        let mut buffer = vec![0.; height + 2 * BUFFER_PADDING];
+        for u in 0..width {
+            for v in 0..height {
+                buffer[BUFFER_PADDING + v] = items[u + v * width];
+            }
+            extend(&mut buffer, BUFFER_PADDING, height);
+            filter(&mut buffer, BUFFER_PADDING, height);
+            for v in 0..height {
+                items[u + v * width] = buffer[BUFFER_PADDING + v];
+            }
+        }
+    }
+
+    level
+}
+
+/// Decode the coefficients of a tile's component and apply the inverse wavelet transform
+fn transform_tile(component: &TileComponent, precision: u32) -> Result<Level, JpxError> {
+    let coding_style = &component.coding_style;
+    let Quantization { scalar_expounded, guard_bits, spqcds } = &component.quantization;
+    let reversible = coding_style.reversible_transformation;
+
+    let mut result: Option<Level> = None;
+    let mut b = 0;
+    for (i, resolution) in component.resolutions.iter().enumerate() {
+        let width = resolution.trx1 - resolution.trx0;
+        let height = resolution.try1 - resolution.try0;
+        // allocate space for the whole sublevel
+        let mut coefficients = vec![0.; width * height];
+        for subband in resolution.subbands.iter() {
+            let (epsilon, mu) = if !scalar_expounded {
+                // formula E-5
+                let (epsilon, mu) = spqcds.first().copied().unwrap_or_default();
+                (epsilon as i32 + if i > 0 { 1 - i as i32 } else { 0 }, mu)
+            } else {
+                let (epsilon, mu) = spqcds.get(b).copied().unwrap_or_default();
+                b += 1;
+                (epsilon as i32, mu)
+            };
+            // calculate the quantization coefficient (Section E.1.1.1)
+            let delta = if reversible {
+                1.
+            } else {
+                pow(2., (precision as i32 + subband.kind.gain_log2() - epsilon) as f64)
+                    * (1. + mu as f64 / 2048.)
+            };
+            let mb = *guard_bits as i32 + epsilon - 1;
+            copy_coefficients(
+                &mut coefficients,
+                width,
+                subband,
+                delta,
+                mb,
+                reversible,
+                coding_style.segmentation_symbol_used,
+            )?;
+        }
+        let level = Level { width, height, items: coefficients };
+        result = Some(match result {
+            None => level,
+            Some(ll) => iterate(ll, level, component.tcx0, component.tcy0, reversible),
+        });
+    }
+    Ok(result.unwrap_or(Level { width: 0, height: 0, items: vec![] }))
+}
+
+/// Section G.2 Inverse multiple component transform, then level shift and clamp each sample
+fn transform_components(context: &Context) -> Result<Vec<JpxTile>, JpxError> {
+    let components_count = context.siz.csiz;
+    let mut result = vec![];
+    for tile in context.tiles.iter() {
+        if tile.components.iter().any(|component| component.resolutions.is_empty()) {
+            continue;
+        }
+        let mut transformed = vec![];
+        for (c, component) in tile.components.iter().enumerate() {
+            transformed.push(transform_tile(component, context.components[c].precision)?);
+        }
+        let Some(first) = tile.components.first() else { continue };
+        let length = transformed[0].items.len();
+        let mut items = vec![0; length * components_count];
+        // (offset, min, max) per component
+        let ranges: Vec<(f64, f64, f64)> = context
+            .components
+            .iter()
+            .map(|component| {
+                let max = pow(2., component.precision as f64);
+                if component.is_signed {
+                    (0.5, -max / 2., max / 2. - 1.)
+                } else {
+                    (max / 2. + 0.5, 0., max - 1.)
+                }
+            })
+            .collect();
+        let clamp = |value: f64, c: usize| {
+            let (_, min, max) = ranges[c];
+            floor(value).clamp(min, max) as i32
+        };
+
+        if tile.cod.multiple_component_transform && components_count >= 3 {
+            let offset = ranges[0].0;
+            let (y0s, y1s, y2s) =
+                (&transformed[0].items, &transformed[1].items, &transformed[2].items);
+            for j in 0..length {
+                let y0 = y0s[j] as f64 + offset;
+                let y1 = y1s[j] as f64;
+                let y2 = y2s[j] as f64;
+                let pos = j * components_count;
+                if first.coding_style.reversible_transformation {
+                    // reversible component transform
+                    let g = y0 - (((y2 + y1) as i32) >> 2) as f64;
+                    items[pos] = clamp(g + y2, 0);
+                    items[pos + 1] = clamp(g, 1);
+                    items[pos + 2] = clamp(g + y1, 2);
+                } else {
+                    // irreversible component transform
+                    items[pos] = clamp(y0 + 1.402 * y2, 0);
+                    items[pos + 1] = clamp(y0 - 0.34413 * y1 - 0.71414 * y2, 1);
+                    items[pos + 2] = clamp(y0 + 1.772 * y1, 2);
+                }
+                for c in 3..components_count {
+                    items[pos + c] = clamp(transformed[c].items[j] as f64 + ranges[c].0, c);
+                }
+            }
+        } else {
+            for (c, level) in transformed.iter().enumerate() {
+                for (j, value) in level.items.iter().enumerate() {
+                    items[j * components_count + c] = clamp(*value as f64 + ranges[c].0, c);
+                }
+            }
+        }
+
+        result.push(JpxTile {
+            left: first.tcx0,
+            top: first.tcy0,
+            width: transformed[0].width,
+            height: transformed[0].height,
+            items,
+        });
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/image/jpeg2000/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn test_codestream() {
+        let image = JpxImage::new(&fixture("input.j2k")).unwrap();
+        assert_eq!(image.width, 63677);
+        assert_eq!(image.height, 1);
+        assert_eq!(image.components_count, 1);
+        assert_eq!(image.tiles.len(), 1);
+
+        let tile = &image.tiles[0];
+        assert_eq!((tile.left, tile.top, tile.width, tile.height), (0, 0, 63677, 1));
+        let expected: Vec<i32> = fixture("expected.raw").into_iter().map(i32::from).collect();
+        assert_eq!(tile.items, expected);
+    }
+
+    #[test]
+    fn test_jp2_box() {
+        // wrap the codestream in a signature and a contiguous codestream box
+        let codestream = fixture("input.j2k");
+        let mut data = vec![0, 0, 0, 12, b'j', b'P', b' ', b' ', 0x0d, 0x0a, 0x87, 0x0a];
+        data.extend(((codestream.len() + 8) as u32).to_be_bytes());
+        data.extend(b"jp2c");
+        data.extend(&codestream);
+        assert_eq!(JpxImage::new(&data), JpxImage::new(&codestream));
+
+        assert_eq!(JpxImage::new(&[0, 0, 0, 4, 0, 0, 0, 0]), Err(JpxError::InvalidBox));
+    }
+
+    #[test]
+    fn test_malformed_codestream() {
+        // more decomposition levels than allowed
+        let mut codestream = fixture("input.j2k");
+        let cod = codestream.windows(2).position(|w| w == [0xff, 0x52]).unwrap();
+        codestream[cod + 9] = 200;
+        assert_eq!(JpxImage::new(&codestream), Err(JpxError::InvalidDecompositionLevels(200)));
+
+        // more coding passes than 32-bit magnitudes can hold
+        let codeblock = CodeBlock {
+            tbx1: 4,
+            tby1: 4,
+            included: true,
+            data: vec![0xff; 64],
+            coding_passes: 100,
+            ..Default::default()
+        };
+        let subband =
+            SubBand { tbx1: 4, tby1: 4, codeblocks: vec![codeblock], ..Default::default() };
+        let mut coefficients = vec![0.; 16];
+        assert_eq!(
+            copy_coefficients(&mut coefficients, 4, &subband, 1., 8, true, false),
+            Err(JpxError::TooManyBitPlanes)
+        );
+    }
+}
@@ -0,0 +1,4 @@
+/// JPEG 2000 decoder
+pub mod jpeg2000;
+
+pub use jpeg2000::*;
@@ -0,0 +1,541 @@
+use crate::{
+    geometry::{VectorFeature, VectorGeometry, VectorPoint},
+    readers::{
+        wkt::geometry::{
+            line_string_geometry, multi_line_string_geometry, multi_point_geometry,
+            multi_polygon_geometry, point_geometry, polygon_geometry,
+        },
+        FeatureIterator, Reader,
+    },
+};
+use alloc::{string::String, vec::Vec};
+use s2json::{PrimitiveValue, Properties, ValuePrimitiveType, ValueType};
+use serde_json::{Map, Value};
+
+/// The number of bytes read from the reader at a time
+const CHUNK_SIZE: usize = 65_536;
+
+/// Errors that can occur while streaming (Geo|S2)JSON. Each carries the byte offset where the
+/// error was found
+#[derive(Debug, Clone, PartialEq)]
+pub enum JSONError {
+    /// The data ended inside a top level object or a `features` array
+    UnexpectedEof(usize),
+    /// A member of a top level object isn't a `"key": value` pair
+    InvalidMember(usize),
+}
+
+/// # GeoJSON Reader
+///
+/// ## Description
+/// Streams (Geo|S2)JSON through the [`Reader`] trait, reading the data in chunks so multi-GB
+/// files can be read from a `FileReader` or `MMapReader`.
+/// Only one feature is held in memory at a time.
+///
+/// The input may be a `FeatureCollection` (or `S2FeatureCollection`) whose `features` array is
+/// parsed incrementally, a single feature, or a sequence of features delimited by newlines
+/// (GeoJSON-seq, `.geojsonld`) or record separators (RFC 8142 text sequences). Features may be
+/// GeoJSON `Feature`s or S2JSON `VectorFeature`s and `S2Feature`s.
+///
+/// Implements the [`FeatureIterator`].
+///
+/// If the data ends early or a top level object is malformed, iteration stops and the error is
+/// stored in `error`.
+///
+/// NOTE: Features without a geometry, with a `GeometryCollection` or that aren't valid JSON are
+/// skipped. String ids are not kept.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, FeatureIterator, JSONReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/json/fixtures/points.geojson");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = JSONReader::new(BufferReader::from(&data[..]));
+/// let feature = reader.next_feature().unwrap();
+/// assert_eq!(reader.count(), 2);
+/// ```
+///
+/// ## Links
+/// - <https://datatracker.ietf.org/doc/html/rfc7946>
+/// - <https://datatracker.ietf.org/doc/html/rfc8142>
+/// - <https://github.com/Open-S2/s2json>
+#[derive(Debug)]
+pub struct JSONReader<T: Reader> {
+    reader: T,
+    buffer: Vec<u8>,
+    /// The position of the next byte in the buffer
+    pos: usize,
+    /// The offset of the next chunk in the reader
+    offset: usize,
+    chunk_size: usize,
+    /// The buffer position of the top level object being scanned
+    start: Option<usize>,
+    /// True while reading the members of a top level object
+    in_object: bool,
+    /// True while reading the elements of a `features` array
+    in_features: bool,
+    /// The error that stopped iteration if the data is malformed
+    pub error: Option<JSONError>,
+}
+impl<T: Reader> JSONReader<T> {
+    /// Create a new (Geo|S2)JSON reader
+    pub fn new(reader: T) -> Self {
+        JSONReader {
+            reader,
+            buffer: Vec::new(),
+            pos: 0,
+            offset: 0,
+            chunk_size: CHUNK_SIZE,
+            start: None,
+            in_object: false,
+            in_features: false,
+            error: None,
+        }
+    }
+
+    /// Read the next raw feature object, storing the error if the data is malformed
+    fn next_object(&mut self) -> Option<Value> {
+        let value = self.scan_object();
+        // running out of data is only expected between top level objects
+        if value.is_none() && self.error.is_none() && (self.in_object || self.in_features) {
+            self.error = Some(JSONError::UnexpectedEof(self.byte_offset()));
+        }
+        value
+    }
+
+    /// Scan for the next raw feature object. Returns None at the end of the data or if a top
+    /// level object is malformed
+    fn scan_object(&mut self) -> Option<Value> {
+        loop {
+            self.compact();
+            if self.in_features {
+                match self.skip_whitespace()? {
+                    b']' => {
+                        self.pos += 1;
+                        self.in_features = false;
+                    }
+                    b',' => self.pos += 1,
+                    b'{' => {
+                        let start = self.pos;
+                        self.skip_value()?;
+                        if let Ok(value) = serde_json::from_slice(&self.buffer[start..self.pos]) {
+                            return Some(value);
+                        }
+                    }
+                    _ => self.skip_value()?,
+                }
+            } else if self.in_object {
+                match self.skip_whitespace()? {
+                    b'}' => {
+                        self.pos += 1;
+                        self.in_object = false;
+                        // a top level object without a `features` array is a feature
+                        if let Some(start) = self.start.take() {
+                            if let Ok(value) = serde_json::from_slice(&self.buffer[start..self.pos])
+                            {
+                                return Some(value);
+                            }
+                        }
+                    }
+                    b',' => self.pos += 1,
+                    b'"' => {
+                        let key = self.pos;
+                        self.skip_string()?;
+                        let is_features = &self.buffer[key..self.pos] == b"\"features\"";
+                        if self.skip_whitespace()? != b':' {
+                            self.error = Some(JSONError::InvalidMember(self.byte_offset()));
+                            return None;
+                        }
+                        self.pos += 1;
+                        if is_features && self.skip_whitespace()? == b'[' {
+                            self.pos += 1;
+                            self.start = None;
+                            self.in_features = true;
+                        } else {
+                            self.skip_value()?;
+                        }
+                    }
+                    _ => {
+                        self.error = Some(JSONError::InvalidMember(self.byte_offset()));
+                        return None;
+                    }
+                }
+            } else {
+                // skip anything between top level objects, e.g. newlines or record separators
+                while self.byte(self.pos)? != b'{' {
+                    self.pos += 1;
+                }
+                self.start = Some(self.pos);
+                self.pos += 1;
+                self.in_object = true;
+            }
+        }
+    }
+
+    /// The byte offset of the buffer position in the reader
+    fn byte_offset(&self) -> usize {
+        self.offset - self.buffer.len() + self.pos
+    }
+
+    /// Drop the bytes that have been read and aren't part of the top level object
+    fn compact(&mut self) {
+        let keep = self.start.unwrap_or(self.pos).min(self.pos);
+        if keep > 0 {
+            self.buffer.drain(..keep);
+            self.pos -= keep;
+            self.start = self.start.map(|start| start - keep);
+        }
+    }
+
+    /// Get the byte at the buffer position, reading chunks as needed
+    fn byte(&mut self, pos: usize) -> Option<u8> {
+        while pos >= self.buffer.len() {
+            if self.offset >= self.reader.len() {
+                return None;
+            }
+            let end = (self.offset + self.chunk_size).min(self.reader.len());
+            self.buffer.extend(self.reader.slice(Some(self.offset), Some(end)));
+            self.offset = end;
+        }
+        Some(self.buffer[pos])
+    }
+
+    /// Skip whitespace, returning the next byte
+    fn skip_whitespace(&mut self) -> Option<u8> {
+        loop {
+            let byte = self.byte(self.pos)?;
+            if !byte.is_ascii_whitespace() {
+                return Some(byte);
+            }
+            self.pos += 1;
+        }
+    }
+
+    /// Skip the next value (string, object, array or scalar). Returns None at the end of the
+    /// data or if there is no value.
+    fn skip_value(&mut self) -> Option<()> {
+        self.skip_whitespace()?;
+        let start = self.pos;
+        let mut depth = 0;
+        loop {
+            match self.byte(self.pos)? {
+                b'"' => self.skip_string()?,
+                b'{' | b'[' => {
+                    depth += 1;
+                    self.pos += 1;
+                }
+                b'}' | b']' if depth > 0 => {
+                    depth -= 1;
+                    self.pos += 1;
+                }
+                b',' | b'}' | b']' if depth == 0 => break,
+                byte if byte.is_ascii_whitespace() && depth == 0 => break,
+                _ => self.pos += 1,
+            }
+            if depth == 0 && matches!(self.buffer[self.pos - 1], b'"' | b'}' | b']') {
+                break;
+            }
+        }
+        (self.pos > start).then_some(())
+    }
+
+    /// Skip a string including its quotes
+    fn skip_string(&mut self) -> Option<()> {
+        self.pos += 1;
+        loop {
+            match self.byte(self.pos)? {
+                b'\\' => self.pos += 2,
+                b'"' => {
+                    self.pos += 1;
+                    return Some(());
+                }
+                _ => self.pos += 1,
+            }
+        }
+    }
+}
+impl<T: Reader> Iterator for JSONReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.error.is_none() {
+            if let Some(feature) = json_feature(&self.next_object()?) {
+                return Some(feature);
+            }
+        }
+        None
+    }
+}
+/// A feature iterator for (Geo|S2)JSON
+impl<T: Reader> FeatureIterator for JSONReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Convert a GeoJSON `Feature` or S2JSON `VectorFeature`/`S2Feature` object
+pub fn json_feature(value: &Value) -> Option<VectorFeature> {
+    let geometry = json_geometry(value.get("geometry")?)?;
+    let id = value.get("id").and_then(Value::as_u64);
+    let properties =
+        value.get("properties").and_then(Value::as_object).map(json_properties).unwrap_or_default();
+    match value.get("type")?.as_str()? {
+        "Feature" | "VectorFeature" => Some(VectorFeature::new_wm(id, properties, geometry, None)),
+        "S2Feature" => {
+            let face = value.get("face").and_then(Value::as_u64).filter(|face| *face < 6)? as u8;
+            Some(VectorFeature::new_s2(id, face.into(), properties, geometry, None))
+        }
+        _ => None,
+    }
+}
+
+/// Convert a GeoJSON geometry or S2JSON vector geometry object
+pub fn json_geometry(value: &Value) -> Option<VectorGeometry> {
+    let coordinates = value.get("coordinates")?;
+    Some(match value.get("type")?.as_str()? {
+        "Point" => point_geometry(json_point(coordinates)?),
+        "MultiPoint" => multi_point_geometry(json_points(coordinates)?),
+        "LineString" => line_string_geometry(json_points(coordinates)?),
+        "MultiLineString" => multi_line_string_geometry(json_lines(coordinates)?),
+        "Polygon" => polygon_geometry(json_lines(coordinates)?),
+        "MultiPolygon" => multi_polygon_geometry(
+            coordinates.as_array()?.iter().map(json_lines).collect::<Option<_>>()?,
+        ),
+        _ => return None,
+    })
+}
+
+/// Convert a `[x, y, z?]` position or an `{ x, y, z?, m? }` vector point
+fn json_point(value: &Value) -> Option<VectorPoint> {
+    match value {
+        Value::Array(position) => Some(VectorPoint::new(
+            position.first()?.as_f64()?,
+            position.get(1)?.as_f64()?,
+            position.get(2).and_then(Value::as_f64),
+            None,
+        )),
+        Value::Object(point) => Some(VectorPoint::new(
+            point.get("x")?.as_f64()?,
+            point.get("y")?.as_f64()?,
+            point.get("z").and_then(Value::as_f64),
+            point.get("m").and_then(Value::as_object).map(json_properties),
+        )),
+        _ => None,
+    }
+}
+
+fn json_points(value: &Value) -> Option<Vec<VectorPoint>> {
+    value.as_array()?.iter().map(json_point).collect()
+}
+
+fn json_lines(value: &Value) -> Option<Vec<Vec<VectorPoint>>> {
+    value.as_array()?.iter().map(json_points).collect()
+}
+
+/// Convert a JSON object to properties
+pub fn json_properties(object: &Map<String, Value>) -> Properties {
+    object.iter().map(|(key, value)| (key.clone(), json_value(value))).collect()
+}
+
+fn json_value(value: &Value) -> ValueType {
+    match value {
+        Value::Array(values) => ValueType::Array(
+            values
+                .iter()
+                .filter_map(|value| match value {
+                    Value::Object(object) => Some(ValuePrimitiveType::NestedPrimitive(
+                        object
+                            .iter()
+                            .filter_map(|(key, value)| Some((key.clone(), json_primitive(value)?)))
+                            .collect(),
+                    )),
+                    value => json_primitive(value).map(ValuePrimitiveType::Primitive),
+                })
+                .collect(),
+        ),
+        Value::Object(object) => ValueType::Nested(json_properties(object)),
+        value => ValueType::Primitive(json_primitive(value).unwrap_or(PrimitiveValue::Null)),
+    }
+}
+
+/// Convert a scalar, preferring unsigned then signed integers like the s2json deserializer
+fn json_primitive(value: &Value) -> Option<PrimitiveValue> {
+    match value {
+        Value::Null => Some(PrimitiveValue::Null),
+        Value::Bool(value) => Some(PrimitiveValue::Bool(*value)),
+        Value::String(value) => Some(PrimitiveValue::String(value.clone())),
+        Value::Number(number) => Some(match (number.as_u64(), number.as_i64()) {
+            (Some(value), _) => PrimitiveValue::U64(value),
+            (_, Some(value)) => PrimitiveValue::I64(value),
+            _ => PrimitiveValue::F64(number.as_f64()?),
+        }),
+        Value::Array(_) | Value::Object(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::BufferReader;
+    use alloc::{format, vec};
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/json/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn read(data: &[u8], chunk_size: usize) -> Vec<VectorFeature> {
+        let mut reader = JSONReader::new(BufferReader::from(data));
+        reader.chunk_size = chunk_size;
+        reader.collect()
+    }
+
+    fn names(features: &[VectorFeature]) -> Vec<&str> {
+        features
+            .iter()
+            .map(|feature| match feature.properties.get("name") {
+                Some(ValueType::Primitive(PrimitiveValue::String(name))) => name.as_str(),
+                _ => panic!("missing name"),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_feature_collection() {
+        let data = fixture("points.geojson");
+        // read in small chunks so values cross the chunk boundaries
+        for chunk_size in [1, 2, 7, CHUNK_SIZE] {
+            let features = read(&data, chunk_size);
+            assert_eq!(names(&features), vec!["Melbourne", "Canberra", "Sydney"]);
+            assert_eq!(
+                features[0].geometry,
+                point_geometry(VectorPoint::new(144.9584, -37.8173, None, None))
+            );
+        }
+
+        let features = read(&fixture("point-feature.geojson"), CHUNK_SIZE);
+        assert_eq!(names(&features), vec!["Melbourne"]);
+    }
+
+    #[test]
+    fn test_sequences() {
+        for chunk_size in [3, CHUNK_SIZE] {
+            let features = read(&fixture("points.geojsonld"), chunk_size);
+            assert_eq!(names(&features), vec!["Melbourne", "Canberra", "Sydney"]);
+
+            let features = read(&fixture("features.geojsonseq"), chunk_size);
+            assert_eq!(features.len(), 3);
+            assert!(matches!(features[1].geometry, VectorGeometry::LineString(_)));
+            assert_eq!(
+                features[1].properties.get("prop1"),
+                Some(&ValueType::Primitive(PrimitiveValue::F64(0.)))
+            );
+            let VectorGeometry::Polygon(polygon) = &features[2].geometry else { panic!() };
+            assert_eq!(polygon.coordinates[0].len(), 5);
+            let Some(ValueType::Nested(nested)) = features[2].properties.get("prop1") else {
+                panic!()
+            };
+            assert_eq!(
+                nested.get("this"),
+                Some(&ValueType::Primitive(PrimitiveValue::String("that".into())))
+            );
+        }
+
+        // record separators
+        let data = b"\x1e{\"type\":\"Feature\",\"properties\":{\"name\":\"a\"},\"geometry\":{\"type\":\"Point\",\"coordinates\":[1,2,3]}}\n\x1e{\"type\":\"Feature\",\"properties\":{\"name\":\"b\"},\"geometry\":{\"type\":\"Point\",\"coordinates\":[4,5]}}\n";
+        let features = read(data, CHUNK_SIZE);
+        assert_eq!(names(&features), vec!["a", "b"]);
+        assert_eq!(features[0].geometry, point_geometry(VectorPoint::new(1., 2., Some(3.), None)));
+    }
+
+    #[test]
+    fn test_values() {
+        let data = br#"{
+            "type": "FeatureCollection",
+            "name": "features ] } \" [",
+            "features": [
+                { "type": "Feature", "id": 7, "properties": { "features": [1, -2, {"a": true}], "b": null }, "geometry": { "type": "MultiPolygon", "coordinates": [[[[0, 0], [1, 0], [1, 1], [0, 0]]]] } },
+                { "type": "Feature", "properties": {}, "geometry": null },
+                { "type": "Feature", "properties": {}, "geometry": { "type": "GeometryCollection", "geometries": [] } },
+                { "type": "S2Feature", "face": 3, "properties": {}, "geometry": { "type": "Point", "is3D": false, "coordinates": { "x": 0.5, "y": 0.25, "m": { "m": 1 } } } },
+                { "type": "VectorFeature", "properties": {}, "geometry": { "type": "MultiPoint", "is3D": true, "coordinates": [{ "x": 1, "y": 2, "z": 3 }] } }
+            ],
+            "bbox": [0, 0, 1, 1]
+        }"#;
+        for chunk_size in [1, CHUNK_SIZE] {
+            let features = read(data, chunk_size);
+            assert_eq!(features.len(), 3);
+
+            assert_eq!(features[0].id, Some(7));
+            assert_eq!(
+                features[0].properties.get("features"),
+                Some(&ValueType::Array(vec![
+                    ValuePrimitiveType::Primitive(PrimitiveValue::U64(1)),
+                    ValuePrimitiveType::Primitive(PrimitiveValue::I64(-2)),
+                    ValuePrimitiveType::NestedPrimitive(
+                        [("a".into(), PrimitiveValue::Bool(true))].into()
+                    ),
+                ]))
+            );
+            assert_eq!(
+                features[0].properties.get("b"),
+                Some(&ValueType::Primitive(PrimitiveValue::Null))
+            );
+            assert!(matches!(features[0].geometry, VectorGeometry::MultiPolygon(_)));
+
+            assert_eq!(features[1]._type, "S2Feature");
+            assert_eq!(features[1].face, 3.into());
+            let VectorGeometry::Point(point) = &features[1].geometry else { panic!() };
+            assert_eq!((point.coordinates.x, point.coordinates.y), (0.5, 0.25));
+            assert!(point.coordinates.m.is_some());
+
+            assert_eq!(
+                features[2].geometry,
+                multi_point_geometry(vec![VectorPoint::new(1., 2., Some(3.), None)])
+            );
+        }
+
+        assert_eq!(read(b"", CHUNK_SIZE).len(), 0);
+        assert_eq!(read(b"{\"type\":\"FeatureCollection\",\"features\":[{\"type\":", 4).len(), 0);
+    }
+
+    #[test]
+    fn test_malformed() {
+        let feature = r#"{"type":"Feature","properties":{"name":"a"},"geometry":{"type":"Point","coordinates":[1,2]}}"#;
+        for chunk_size in [1, CHUNK_SIZE] {
+            // a member without a colon after the first feature
+            let data = format!(r#"{{"features":[{feature}],"bbox" [0,0,1,1]}}{feature}"#);
+            let mut reader = JSONReader::new(BufferReader::from(data.as_bytes()));
+            reader.chunk_size = chunk_size;
+            assert_eq!(names(&reader.by_ref().collect::<Vec<_>>()), vec!["a"]);
+            assert_eq!(reader.error, Some(JSONError::InvalidMember(feature.len() + 22)));
+            assert_eq!(reader.next(), None);
+
+            // a top level object that isn't made of members
+            let data = format!("{feature}\n{{[1]}}\n{feature}");
+            let mut reader = JSONReader::new(BufferReader::from(data.as_bytes()));
+            reader.chunk_size = chunk_size;
+            assert_eq!(reader.by_ref().count(), 1);
+            assert_eq!(reader.error, Some(JSONError::InvalidMember(feature.len() + 2)));
+
+            // the data ends inside the features array
+            let data = format!(r#"{{"features":[{feature},{{"type":"#);
+            let mut reader = JSONReader::new(BufferReader::from(data.as_bytes()));
+            reader.chunk_size = chunk_size;
+            assert_eq!(reader.by_ref().count(), 1);
+            assert_eq!(reader.error, Some(JSONError::UnexpectedEof(data.len())));
+        }
+
+        // sequences may end with whitespace or separators
+        let mut reader = JSONReader::new(BufferReader::from(b"\n\x1e\n" as &[u8]));
+        assert_eq!(reader.by_ref().count(), 0);
+        assert_eq!(reader.error, None);
+    }
+}
@@ -0,0 +1,651 @@
+/// KML styles
+pub mod style;
+
+pub use style::*;
+
+use crate::{
+    geometry::{
+        VectorFeature, VectorGeometry, VectorGeometryType, VectorLineStringGeometry,
+        VectorMultiLineStringGeometry, VectorMultiPointGeometry, VectorMultiPolygonGeometry,
+        VectorPoint, VectorPointGeometry, VectorPolygonGeometry,
+    },
+    readers::{FeatureIterator, OwnedBufferReader, Reader, XMLError, XMLEvent, XMLNode, XMLReader},
+    util::{iter_items, CompressError},
+};
+use alloc::{
+    collections::{BTreeMap, VecDeque},
+    string::String,
+    vec,
+    vec::Vec,
+};
+use s2json::{MValue, PrimitiveValue, Properties, ValueType};
+
+/// Errors that can occur while reading a KML or KMZ file
+#[derive(Debug, PartialEq)]
+pub enum KMLError {
+    /// The document isn't well-formed XML
+    XML(XMLError),
+    /// The root element isn't `<kml>`
+    MissingRoot,
+    /// The KMZ archive doesn't contain a `.kml` document
+    MissingDocument,
+    /// The KMZ archive could not be read
+    Compression(CompressError),
+}
+impl From<XMLError> for KMLError {
+    fn from(err: XMLError) -> Self {
+        KMLError::XML(err)
+    }
+}
+impl From<CompressError> for KMLError {
+    fn from(err: CompressError) -> Self {
+        KMLError::Compression(err)
+    }
+}
+
+/// The geometries of a Placemark before they're grouped by type
+#[derive(Debug)]
+pub(crate) enum Shape {
+    Point(VectorPoint),
+    Line(Vec<VectorPoint>),
+    Polygon(Vec<Vec<VectorPoint>>),
+}
+
+/// # KML Reader
+///
+/// ## Description
+/// Reads KML 2.2 documents (Keyhole Markup Language) through the [`Reader`] trait, streamed with
+/// the [`XMLReader`]. Use [`kml_from_kmz`] to open a KMZ archive.
+///
+/// Implements the [`FeatureIterator`], yielding each `<Placemark>` in document order. Documents
+/// and Folders are descended into; overlays, network links and other elements are skipped.
+/// - `<Point>` becomes a Point, `<LineString>` and `<LinearRing>` a LineString, `<Polygon>` a
+///   Polygon with its inner boundaries as holes
+/// - `<gx:Track>` becomes a LineString whose vertices store their `<when>` as `time` and the
+///   `<gx:SimpleArrayData>` values in their M-value. `<gx:MultiTrack>` is a MultiLineString
+/// - `<MultiGeometry>` becomes a MultiPoint, MultiLineString or MultiPolygon (or the single
+///   geometry). If it mixes types, one feature per type is yielded with the same properties
+///
+/// The properties are the Placemark's `name`, `description`, `address`, `styleUrl`,
+/// `visibility`, `timestamp` or `begin`/`end`, and the values of its `<ExtendedData>`. Typed
+/// `<SimpleData>` is converted using its `<Schema>`, other data is kept as strings. The
+/// Placemark's style (shared through `styleUrl`, including the normal pair of a `<StyleMap>`,
+/// and merged with any inline `<Style>`) is stored with the simplestyle keys, see [`KMLStyle`].
+///
+/// If the document is malformed, iteration stops and the error is stored in `error`.
+///
+/// NOTE: Shared styles and schemas must be declared before the Placemarks using them. Altitude
+/// becomes `z`; altitude modes, regions and levels of detail are ignored.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, FeatureIterator, KMLReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/kml/fixtures/example.kml");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = KMLReader::new(BufferReader::from(&data[..])).unwrap();
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://developers.google.com/kml/documentation/kmlreference>
+/// - <https://www.ogc.org/standard/kml/>
+/// - <https://github.com/mapbox/simplestyle-spec>
+#[derive(Debug)]
+pub struct KMLReader<T: Reader> {
+    xml: XMLReader<T>,
+    /// The shared styles by ID
+    pub styles: BTreeMap<String, KMLStyle>,
+    /// The `styleUrl` of the normal style of each `<StyleMap>` by ID
+    pub style_maps: BTreeMap<String, String>,
+    /// The field types of each `<Schema>` by ID
+    pub schemas: BTreeMap<String, BTreeMap<String, String>>,
+    /// The files embedded in a KMZ archive (e.g. icons) by path
+    pub resources: BTreeMap<String, Vec<u8>>,
+    /// The error that stopped iteration if the document is malformed
+    pub error: Option<KMLError>,
+    features: VecDeque<VectorFeature>,
+}
+impl<T: Reader> KMLReader<T> {
+    /// Create a new KML reader, checking the root element
+    pub fn new(reader: T) -> Result<Self, KMLError> {
+        let mut xml = XMLReader::new(reader);
+        loop {
+            match xml.next_event()? {
+                XMLEvent::Start(root) if root.local_name() == "kml" => break,
+                XMLEvent::Start(_) | XMLEvent::Eof => return Err(KMLError::MissingRoot),
+                _ => {}
+            }
+        }
+
+        Ok(KMLReader {
+            xml,
+            styles: BTreeMap::new(),
+            style_maps: BTreeMap::new(),
+            schemas: BTreeMap::new(),
+            resources: BTreeMap::new(),
+            error: None,
+            features: VecDeque::new(),
+        })
+    }
+
+    /// Read the next Placemark into the feature queue, storing any styles and schemas on the way.
+    /// Returns false at the end of the document
+    fn read_placemark(&mut self) -> Result<bool, XMLError> {
+        loop {
+            let (mut node, name) = match self.xml.next_event()? {
+                XMLEvent::Start(start) => (start.to_node(), start.local_name()),
+                XMLEvent::Eof => return Ok(false),
+                _ => continue,
+            };
+            match name {
+                "Document" | "Folder" => continue,
+                "Placemark" | "Style" | "StyleMap" | "Schema" => {}
+                _ => {
+                    self.xml.skip_element()?;
+                    continue;
+                }
+            }
+            self.xml.read_children(&mut node)?;
+            let id = node.attribute("id").map(String::from);
+            match (node.local_name(), id) {
+                ("Placemark", _) => {
+                    self.add_placemark(&node);
+                    return Ok(true);
+                }
+                ("Style", Some(id)) => {
+                    self.styles.insert(id, KMLStyle::new(&node));
+                }
+                ("StyleMap", Some(id)) => {
+                    let normal = node
+                        .children("Pair")
+                        .find(|pair| pair.child_text("key") == Some("normal"))
+                        .and_then(|pair| pair.child_text("styleUrl"));
+                    if let Some(normal) = normal {
+                        self.style_maps.insert(id, normal.into());
+                    }
+                }
+                ("Schema", Some(id)) => {
+                    let fields = node
+                        .children
+                        .iter()
+                        .filter(|f| matches!(f.local_name(), "SimpleField" | "SimpleArrayField"))
+                        .filter_map(|f| {
+                            Some((f.attribute("name")?.into(), f.attribute("type")?.into()))
+                        })
+                        .collect();
+                    self.schemas.insert(id, fields);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Resolve a `styleUrl` to a shared style, following a `<StyleMap>` to its normal style
+    fn resolve_style(&self, url: &str) -> Option<&KMLStyle> {
+        let id = url.strip_prefix('#')?;
+        match self.style_maps.get(id) {
+            Some(normal) => self.styles.get(normal.strip_prefix('#')?),
+            None => self.styles.get(id),
+        }
+    }
+
+    /// Build the features of a Placemark
+    fn add_placemark(&mut self, node: &XMLNode) {
+        let mut properties = Properties::new();
+        for key in ["name", "description", "address", "styleUrl"] {
+            if let Some(value) = node.child_text(key) {
+                properties.insert(key.into(), string_value(value));
+            }
+        }
+        if let Some(visibility) = node.child_text("visibility") {
+            let visible = PrimitiveValue::Bool(parse_bool(visibility));
+            properties.insert("visibility".into(), ValueType::Primitive(visible));
+        }
+        if let Some(when) = node.child("TimeStamp").and_then(|t| t.child_text("when")) {
+            properties.insert("timestamp".into(), string_value(when));
+        }
+        if let Some(span) = node.child("TimeSpan") {
+            for key in ["begin", "end"] {
+                if let Some(value) = span.child_text(key) {
+                    properties.insert(key.into(), string_value(value));
+                }
+            }
+        }
+        if let Some(data) = node.child("ExtendedData") {
+            self.add_extended_data(data, &mut properties);
+        }
+        let mut style = node
+            .child_text("styleUrl")
+            .and_then(|url| self.resolve_style(url))
+            .cloned()
+            .unwrap_or_default();
+        if let Some(inline) = node.child("Style") {
+            style.merge(&KMLStyle::new(inline));
+        }
+        style.to_properties(&mut properties);
+
+        let mut shapes = vec![];
+        for child in &node.children {
+            self.add_shapes(child, &mut shapes);
+        }
+        for geometry in group_shapes(shapes) {
+            let feature = VectorFeature::new_wm(None, properties.clone(), geometry, None);
+            self.features.push_back(feature);
+        }
+    }
+
+    /// Store the `<Data>` and `<SchemaData>` values of an `<ExtendedData>` element
+    fn add_extended_data(&self, data: &XMLNode, properties: &mut Properties) {
+        for value in data.children("Data") {
+            if let (Some(name), Some(text)) = (value.attribute("name"), value.child_text("value")) {
+                properties.insert(name.into(), string_value(text));
+            }
+        }
+        for schema_data in data.children("SchemaData") {
+            let fields = self.schema_fields(schema_data);
+            for value in schema_data.children("SimpleData") {
+                if let Some(name) = value.attribute("name") {
+                    let field_type = fields.and_then(|f| f.get(name)).map(String::as_str);
+                    properties.insert(name.into(), typed_value(value.text.trim(), field_type));
+                }
+            }
+        }
+    }
+
+    /// The field types of the schema referenced by a `<SchemaData>` element
+    fn schema_fields(&self, schema_data: &XMLNode) -> Option<&BTreeMap<String, String>> {
+        let url = schema_data.attribute("schemaUrl")?;
+        self.schemas.get(url.strip_prefix('#').unwrap_or(url))
+    }
+
+    /// Collect the geometries of a geometry element, descending into `<MultiGeometry>`
+    fn add_shapes(&self, node: &XMLNode, shapes: &mut Vec<Shape>) {
+        match node.local_name() {
+            "Point" => {
+                if let Some(point) = coordinates(node).into_iter().next() {
+                    shapes.push(Shape::Point(point));
+                }
+            }
+            "LineString" | "LinearRing" => {
+                let line = coordinates(node);
+                if !line.is_empty() {
+                    shapes.push(Shape::Line(line));
+                }
+            }
+            "Polygon" => {
+                let ring = |boundary: &XMLNode| {
+                    boundary.child("LinearRing").map(coordinates).filter(|r| !r.is_empty())
+                };
+                let Some(outer) = node.child("outerBoundaryIs").and_then(ring) else {
+                    return;
+                };
+                let mut polygon = vec![outer];
+                polygon.extend(node.children("innerBoundaryIs").filter_map(ring));
+                shapes.push(Shape::Polygon(polygon));
+            }
+            "Track" => {
+                let line = self.track(node);
+                if !line.is_empty() {
+                    shapes.push(Shape::Line(line));
+                }
+            }
+            "MultiGeometry" | "MultiTrack" => {
+                for child in &node.children {
+                    self.add_shapes(child, shapes);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Parse a `<gx:Track>` into a line whose vertices store their time and array data
+    fn track(&self, node: &XMLNode) -> Vec<VectorPoint> {
+        let times: Vec<&str> = node.children("when").map(|w| w.text.trim()).collect();
+        let mut arrays = vec![];
+        for schema_data in node.child("ExtendedData").iter().flat_map(|e| e.children("SchemaData"))
+        {
+            let fields = self.schema_fields(schema_data);
+            for array in schema_data.children("SimpleArrayData") {
+                let Some(name) = array.attribute("name") else { continue };
+                let field_type = fields.and_then(|f| f.get(name)).map(String::as_str);
+                let values: Vec<ValueType> = array
+                    .children("value")
+                    .map(|v| typed_value(v.text.trim(), field_type))
+                    .collect();
+                arrays.push((name, values));
+            }
+        }
+        node.children("coord")
+            .enumerate()
+            .filter_map(|(i, coord)| {
+                let mut values = coord.text.split_whitespace().map(|v| v.parse::<f64>().ok());
+                let (x, y, z) = (values.next()??, values.next()??, values.next().flatten());
+                let mut m = MValue::new();
+                if let Some(time) = times.get(i) {
+                    m.insert("time".into(), string_value(time));
+                }
+                for (name, values) in &arrays {
+                    if let Some(value) = values.get(i) {
+                        m.insert((*name).into(), value.clone());
+                    }
+                }
+                Some(VectorPoint::new(x, y, z, Some(m)))
+            })
+            .collect()
+    }
+}
+
+impl<T: Reader> Iterator for KMLReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.features.is_empty() {
+            if self.error.is_some() {
+                return None;
+            }
+            match self.read_placemark() {
+                Ok(true) => {}
+                Ok(false) => return None,
+                Err(err) => self.error = Some(err.into()),
+            }
+        }
+        self.features.pop_front()
+    }
+}
+impl<T: Reader> FeatureIterator for KMLReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// # Read a KMZ archive
+///
+/// ## Description
+/// Finds the KML document inside the archive (`doc.kml`, or else the first `.kml` file) and
+/// builds a [`KMLReader`]. The other files of the archive (e.g. icons and overlay images) are
+/// stored in [`KMLReader::resources`] by their path.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::kml_from_kmz;
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/kml/fixtures/example.kmz");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let reader = kml_from_kmz(&data).unwrap();
+/// assert!(reader.resources.contains_key("files/icon.png"));
+/// ```
+pub fn kml_from_kmz(data: &[u8]) -> Result<KMLReader<OwnedBufferReader>, KMLError> {
+    let mut document: Option<(String, Vec<u8>)> = None;
+    let mut resources = BTreeMap::new();
+    for item in iter_items(data)? {
+        let name = item.filename.clone();
+        if name.ends_with('/') {
+            continue;
+        }
+        let data = (item.read)()?;
+        let is_kml = name.to_lowercase().ends_with(".kml");
+        if is_kml && (document.is_none() || name == "doc.kml") {
+            if let Some((previous, data)) = document.replace((name, data)) {
+                resources.insert(previous, data);
+            }
+        } else {
+            resources.insert(name, data);
+        }
+    }
+    let (_, document) = document.ok_or(KMLError::MissingDocument)?;
+    let mut reader = KMLReader::new(OwnedBufferReader::from(document))?;
+    reader.resources = resources;
+
+    Ok(reader)
+}
+
+/// Parse the `<coordinates>` of a geometry: whitespace separated `lon,lat[,alt]` tuples
+fn coordinates(node: &XMLNode) -> Vec<VectorPoint> {
+    let Some(text) = node.child_text("coordinates") else {
+        return vec![];
+    };
+    // some writers put spaces after the commas of a tuple
+    let mut tuples: Vec<String> = vec![];
+    for token in text.split_whitespace() {
+        match tuples.last_mut() {
+            Some(last) if last.ends_with(',') || token.starts_with(',') => last.push_str(token),
+            _ => tuples.push(token.into()),
+        }
+    }
+    tuples
+        .iter()
+        .filter_map(|tuple| {
+            let mut values = tuple.split(',').map(|v| v.parse::<f64>().ok());
+            let (x, y, z) = (values.next()??, values.next()??, values.next().flatten());
+            Some(VectorPoint::new(x, y, z, None))
+        })
+        .collect()
+}
+
+/// Group the shapes of a Placemark into one geometry per type
+pub(crate) fn group_shapes(shapes: Vec<Shape>) -> Vec<VectorGeometry> {
+    let (mut points, mut lines, mut polygons) = (vec![], vec![], vec![]);
+    for shape in shapes {
+        match shape {
+            Shape::Point(point) => points.push(point),
+            Shape::Line(line) => lines.push(line),
+            Shape::Polygon(polygon) => polygons.push(polygon),
+        }
+    }
+    let mut geometries = vec![];
+    if points.len() == 1 {
+        geometries.push(VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            is_3d: points[0].z.is_some(),
+            coordinates: points.remove(0),
+            ..Default::default()
+        }));
+    } else if !points.is_empty() {
+        geometries.push(VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+            _type: VectorGeometryType::MultiPoint,
+            is_3d: is_3d(&points),
+            coordinates: points,
+            ..Default::default()
+        }));
+    }
+    if lines.len() == 1 {
+        geometries.push(VectorGeometry::LineString(VectorLineStringGeometry {
+            _type: VectorGeometryType::LineString,
+            is_3d: is_3d(&lines[0]),
+            coordinates: lines.remove(0),
+            ..Default::default()
+        }));
+    } else if !lines.is_empty() {
+        geometries.push(VectorGeometry::MultiLineString(VectorMultiLineStringGeometry {
+            _type: VectorGeometryType::MultiLineString,
+            is_3d: is_3d(lines.iter().flatten()),
+            coordinates: lines,
+            ..Default::default()
+        }));
+    }
+    if polygons.len() == 1 {
+        geometries.push(VectorGeometry::Polygon(VectorPolygonGeometry {
+            _type: VectorGeometryType::Polygon,
+            is_3d: is_3d(polygons[0].iter().flatten()),
+            coordinates: polygons.remove(0),
+            ..Default::default()
+        }));
+    } else if !polygons.is_empty() {
+        geometries.push(VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+            _type: VectorGeometryType::MultiPolygon,
+            is_3d: is_3d(polygons.iter().flatten().flatten()),
+            coordinates: polygons,
+            ..Default::default()
+        }));
+    }
+    geometries
+}
+
+/// True if any of the points has an altitude
+fn is_3d<'a>(points: impl IntoIterator<Item = &'a VectorPoint>) -> bool {
+    points.into_iter().any(|p| p.z.is_some())
+}
+
+/// Convert a value using its `<SimpleField>` type, falling back to a string
+fn typed_value(value: &str, field_type: Option<&str>) -> ValueType {
+    let primitive = match field_type {
+        Some("int" | "short") => value.parse().ok().map(PrimitiveValue::I64),
+        Some("uint" | "ushort") => value.parse().ok().map(PrimitiveValue::U64),
+        Some("float" | "double") => value.parse().ok().map(PrimitiveValue::F64),
+        Some("bool") => Some(PrimitiveValue::Bool(parse_bool(value))),
+        _ => None,
+    };
+    match primitive {
+        Some(primitive) => ValueType::Primitive(primitive),
+        None => string_value(value),
+    }
+}
+
+/// A string primitive value
+fn string_value(value: &str) -> ValueType {
+    ValueType::Primitive(PrimitiveValue::String(value.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::BufferReader;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/kml/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn primitive(value: &MValue, key: &str) -> PrimitiveValue {
+        match value.get(key) {
+            Some(ValueType::Primitive(value)) => value.clone(),
+            _ => panic!("missing {key}"),
+        }
+    }
+
+    fn string(value: &str) -> PrimitiveValue {
+        PrimitiveValue::String(value.into())
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let reader = BufferReader::from(&b"<gpx></gpx>"[..]);
+        assert_eq!(KMLReader::new(reader).err(), Some(KMLError::MissingRoot));
+        let reader = BufferReader::from(&b"<kml><Placemark></kml>"[..]);
+        let mut reader = KMLReader::new(reader).unwrap();
+        assert!(reader.next().is_none());
+        assert_eq!(reader.error, Some(KMLError::XML(XMLError::MismatchedEndTag(16))));
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_parse_color() {
+        assert_eq!(parse_color("ff0000ff"), Some(("#ff0000".into(), 1.)));
+        assert_eq!(parse_color("00ffcc88"), Some(("#88ccff".into(), 0.)));
+        assert_eq!(parse_color("red"), None);
+    }
+
+    #[test]
+    fn test_kml() {
+        let data = fixture("example.kml");
+        let reader = KMLReader::new(BufferReader::from(&data[..])).unwrap();
+        let features: Vec<VectorFeature> = reader.collect();
+        assert_eq!(features.len(), 6);
+
+        let summit = &features[0];
+        let props = &summit.properties;
+        assert_eq!(primitive(props, "name"), string("Summit"));
+        assert_eq!(primitive(props, "description"), string("<b>Top</b> of the hill"));
+        assert_eq!(primitive(props, "timestamp"), string("2020-05-01T10:00:00Z"));
+        assert_eq!(primitive(props, "elevation"), string("1200"));
+        assert_eq!(primitive(props, "rank"), PrimitiveValue::I64(3));
+        assert_eq!(primitive(props, "open"), PrimitiveValue::Bool(true));
+        assert_eq!(primitive(props, "icon"), string("files/icon.png"));
+        assert_eq!(primitive(props, "icon-scale"), PrimitiveValue::F64(1.5));
+        assert!(!props.contains_key("stroke"));
+        assert_eq!(
+            summit.geometry,
+            VectorGeometry::Point(VectorPointGeometry {
+                _type: VectorGeometryType::Point,
+                is_3d: true,
+                coordinates: VectorPoint::new(-122.5, 37.5, Some(1200.), None),
+                ..Default::default()
+            })
+        );
+
+        let path = &features[1];
+        assert_eq!(primitive(&path.properties, "stroke"), string("#ff0000"));
+        assert_eq!(primitive(&path.properties, "stroke-width"), PrimitiveValue::F64(2.));
+        assert_eq!(
+            path.geometry,
+            VectorGeometry::LineString(VectorLineStringGeometry {
+                _type: VectorGeometryType::LineString,
+                is_3d: false,
+                coordinates: vec![
+                    VectorPoint::new(-122.0, 37.0, None, None),
+                    VectorPoint::new(-122.1, 37.1, None, None),
+                    VectorPoint::new(-122.2, 37.2, None, None),
+                ],
+                ..Default::default()
+            })
+        );
+
+        let field = &features[2];
+        let props = &field.properties;
+        assert_eq!(primitive(props, "visibility"), PrimitiveValue::Bool(false));
+        assert_eq!(primitive(props, "stroke-width"), PrimitiveValue::F64(4.));
+        assert_eq!(primitive(props, "stroke-opacity"), PrimitiveValue::F64(0.));
+        assert_eq!(primitive(props, "fill"), string("#00ff00"));
+        assert_eq!(primitive(props, "fill-opacity"), PrimitiveValue::F64(127. / 255.));
+        let VectorGeometry::Polygon(polygon) = &field.geometry else { panic!("not a polygon") };
+        assert_eq!(polygon.coordinates.len(), 2);
+        assert_eq!(polygon.coordinates[0].len(), 5);
+        assert_eq!(polygon.coordinates[1][1], VectorPoint::new(4., 2., None, None));
+
+        // the mixed MultiGeometry is split by type
+        assert_eq!(primitive(&features[3].properties, "begin"), string("2020"));
+        assert_eq!(features[3].properties, features[4].properties);
+        let VectorGeometry::MultiPoint(points) = &features[3].geometry else {
+            panic!("not a multipoint")
+        };
+        assert_eq!(points.coordinates.len(), 2);
+        assert!(matches!(features[4].geometry, VectorGeometry::LineString(_)));
+
+        let VectorGeometry::LineString(track) = &features[5].geometry else {
+            panic!("not a track")
+        };
+        assert!(track.is_3d);
+        assert_eq!(track.coordinates.len(), 2);
+        let second = &track.coordinates[1];
+        assert_eq!((second.x, second.y, second.z), (-122.3, 37.5, Some(155.)));
+        let m = second.m.as_ref().unwrap();
+        assert_eq!(primitive(m, "time"), string("2020-05-01T10:00:05Z"));
+        assert_eq!(primitive(m, "heartrate"), PrimitiveValue::F64(124.5));
+    }
+
+    #[test]
+    fn test_kmz() {
+        let data = fixture("example.kmz");
+        let mut reader = kml_from_kmz(&data).unwrap();
+        assert_eq!(reader.resources.len(), 1);
+        assert_eq!(reader.resources["files/icon.png"][..4], b"\x89PNG"[..]);
+        // shared styles are stored as they're streamed past
+        assert!(reader.styles.is_empty());
+        assert!(reader.next().is_some());
+        assert_eq!(reader.styles.len(), 2);
+        assert_eq!(reader.style_maps["pinMap"], "#pin");
+        assert_eq!(reader.count(), 5);
+
+        assert!(kml_from_kmz(&fixture("example.kml")).is_err());
+    }
+}
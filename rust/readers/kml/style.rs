@@ -0,0 +1,147 @@
+use crate::readers::XMLNode;
+use alloc::{format, string::String};
+use s2json::{PrimitiveValue, Properties, ValueType};
+
+/// The drawing style of a `<Style>` element. Colors are CSS hex strings (`#rrggbb`) with the
+/// KML alpha channel split into a separate opacity between 0 and 1
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct KMLStyle {
+    /// `<LineStyle><color>` as `#rrggbb`
+    pub stroke: Option<String>,
+    /// The alpha of `<LineStyle><color>`
+    pub stroke_opacity: Option<f64>,
+    /// `<LineStyle><width>`
+    pub stroke_width: Option<f64>,
+    /// `<PolyStyle><color>` as `#rrggbb`
+    pub fill: Option<String>,
+    /// The alpha of `<PolyStyle><color>`, 0 if `<fill>` is disabled
+    pub fill_opacity: Option<f64>,
+    /// False if `<PolyStyle><outline>` is disabled
+    pub outline: Option<bool>,
+    /// `<IconStyle><Icon><href>`
+    pub icon: Option<String>,
+    /// `<IconStyle><scale>`
+    pub icon_scale: Option<f64>,
+    /// `<IconStyle><color>` as `#rrggbb`
+    pub icon_color: Option<String>,
+    /// The alpha of `<IconStyle><color>`
+    pub icon_opacity: Option<f64>,
+    /// `<IconStyle><heading>`
+    pub icon_heading: Option<f64>,
+    /// `<LabelStyle><scale>`
+    pub label_scale: Option<f64>,
+    /// `<LabelStyle><color>` as `#rrggbb`
+    pub label_color: Option<String>,
+    /// The alpha of `<LabelStyle><color>`
+    pub label_opacity: Option<f64>,
+}
+impl KMLStyle {
+    /// Parse a `<Style>` element
+    pub fn new(node: &XMLNode) -> Self {
+        let mut style = KMLStyle::default();
+        let number = |node: &XMLNode, name| node.child_text(name).and_then(|v| v.parse().ok());
+        let color = |node: &XMLNode| node.child_text("color").and_then(parse_color).unzip();
+        if let Some(line) = node.child("LineStyle") {
+            (style.stroke, style.stroke_opacity) = color(line);
+            style.stroke_width = number(line, "width");
+        }
+        if let Some(poly) = node.child("PolyStyle") {
+            (style.fill, style.fill_opacity) = color(poly);
+            if poly.child_text("fill").is_some_and(|fill| !parse_bool(fill)) {
+                style.fill_opacity = Some(0.);
+            }
+            style.outline = poly.child_text("outline").map(parse_bool);
+        }
+        if let Some(icon) = node.child("IconStyle") {
+            (style.icon_color, style.icon_opacity) = color(icon);
+            style.icon = icon.child("Icon").and_then(|i| i.child_text("href")).map(String::from);
+            style.icon_scale = number(icon, "scale");
+            style.icon_heading = number(icon, "heading");
+        }
+        if let Some(label) = node.child("LabelStyle") {
+            (style.label_color, style.label_opacity) = color(label);
+            style.label_scale = number(label, "scale");
+        }
+        style
+    }
+
+    /// Merge another style over this one, e.g. an inline style over a shared one
+    pub fn merge(&mut self, other: &KMLStyle) {
+        macro_rules! merge {
+            ($($field:ident),*) => {
+                $(if other.$field.is_some() { self.$field = other.$field.clone(); })*
+            };
+        }
+        merge!(
+            stroke,
+            stroke_opacity,
+            stroke_width,
+            fill,
+            fill_opacity,
+            outline,
+            icon,
+            icon_scale,
+            icon_color,
+            icon_opacity,
+            icon_heading,
+            label_scale,
+            label_color,
+            label_opacity
+        );
+    }
+
+    /// Store the style in the properties using the simplestyle keys (`stroke`, `fill-opacity`,
+    /// etc.). A disabled outline sets `stroke-opacity` to 0
+    pub fn to_properties(&self, properties: &mut Properties) {
+        let mut insert = |key: &str, value: PrimitiveValue| {
+            properties.insert(key.into(), ValueType::Primitive(value));
+        };
+        let strings = [
+            ("stroke", &self.stroke),
+            ("fill", &self.fill),
+            ("icon", &self.icon),
+            ("icon-color", &self.icon_color),
+            ("label-color", &self.label_color),
+        ];
+        for (key, value) in strings {
+            if let Some(value) = value {
+                insert(key, PrimitiveValue::String(value.clone()));
+            }
+        }
+        let stroke_opacity = match self.outline {
+            Some(false) => Some(0.),
+            _ => self.stroke_opacity,
+        };
+        let numbers = [
+            ("stroke-opacity", stroke_opacity),
+            ("stroke-width", self.stroke_width),
+            ("fill-opacity", self.fill_opacity),
+            ("icon-scale", self.icon_scale),
+            ("icon-opacity", self.icon_opacity),
+            ("icon-heading", self.icon_heading),
+            ("label-scale", self.label_scale),
+            ("label-opacity", self.label_opacity),
+        ];
+        for (key, value) in numbers {
+            if let Some(value) = value {
+                insert(key, PrimitiveValue::F64(value));
+            }
+        }
+    }
+}
+
+/// Convert a KML `aabbggrr` color to a CSS `#rrggbb` color and an opacity
+pub fn parse_color(color: &str) -> Option<(String, f64)> {
+    let color = color.trim().trim_start_matches('#');
+    if color.len() != 8 || !color.is_ascii() {
+        return None;
+    }
+    let value = u32::from_str_radix(color, 16).ok()?;
+    let [a, b, g, r] = value.to_be_bytes();
+    Some((format!("#{r:02x}{g:02x}{b:02x}"), a as f64 / 255.))
+}
+
+/// KML booleans are `1`/`0` or `true`/`false`
+pub(crate) fn parse_bool(value: &str) -> bool {
+    matches!(value.trim(), "1" | "true")
+}
@@ -0,0 +1,392 @@
+use alloc::{vec, vec::Vec};
+
+/// Threshold for renormalization
+const AC_MIN_LENGTH: u32 = 0x0100_0000;
+/// Maximum interval length
+const AC_MAX_LENGTH: u32 = 0xffff_ffff;
+/// Length bits discarded before multiplication of a bit model
+const BM_LENGTH_SHIFT: u32 = 13;
+/// Maximum count of an adaptive bit model
+const BM_MAX_COUNT: u32 = 1 << BM_LENGTH_SHIFT;
+/// Length bits discarded before multiplication of a symbol model
+const DM_LENGTH_SHIFT: u32 = 15;
+/// Maximum count of an adaptive symbol model
+const DM_MAX_COUNT: u32 = 1 << DM_LENGTH_SHIFT;
+
+/// The arithmetic decoder of LASzip, reading from an owned byte stream. Reading past the end of
+/// the stream yields zeros
+#[derive(Debug, Default, Clone)]
+pub struct ArithmeticDecoder {
+    data: Vec<u8>,
+    pos: usize,
+    value: u32,
+    length: u32,
+}
+impl ArithmeticDecoder {
+    /// Create a decoder over a stream. [`ArithmeticDecoder::init`] must be called before decoding
+    pub fn new(data: Vec<u8>) -> Self {
+        Self { data, pos: 0, value: 0, length: AC_MAX_LENGTH }
+    }
+
+    /// Create a decoder over a stream and read its first four bytes
+    pub fn new_init(data: Vec<u8>) -> Self {
+        let mut dec = Self::new(data);
+        dec.init();
+        dec
+    }
+
+    /// Read the initial value
+    pub fn init(&mut self) {
+        self.length = AC_MAX_LENGTH;
+        self.value = 0;
+        for _ in 0..4 {
+            self.value = (self.value << 8) | self.byte() as u32;
+        }
+    }
+
+    /// The position in the stream
+    pub fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Read a raw byte of the stream
+    fn byte(&mut self) -> u8 {
+        let byte = self.data.get(self.pos).copied().unwrap_or(0);
+        self.pos += 1;
+        byte
+    }
+
+    /// Decode a bit with an adaptive bit model
+    pub fn decode_bit(&mut self, m: &mut ArithmeticBitModel) -> u32 {
+        let x = m.bit_0_prob.wrapping_mul(self.length >> BM_LENGTH_SHIFT);
+        let sym = if self.value < x {
+            self.length = x;
+            m.bit_0_count += 1;
+            0
+        } else {
+            self.value -= x;
+            self.length -= x;
+            1
+        };
+        if self.length < AC_MIN_LENGTH {
+            self.renorm();
+        }
+        m.bits_until_update -= 1;
+        if m.bits_until_update == 0 {
+            m.update();
+        }
+        sym
+    }
+
+    /// Decode a symbol with an adaptive symbol model
+    pub fn decode_symbol(&mut self, m: &mut ArithmeticModel) -> u32 {
+        let mut y = self.length;
+        let (sym, x);
+        self.length >>= DM_LENGTH_SHIFT;
+        if !m.decoder_table.is_empty() {
+            let dv = self.value / self.length;
+            let t = (dv >> m.table_shift) as usize;
+            // initial decision based on the table look-up, then a bisection
+            let mut s = m.decoder_table[t];
+            let mut n = m.decoder_table[t + 1] + 1;
+            while n > s + 1 {
+                let k = (s + n) >> 1;
+                if m.distribution[k as usize] > dv {
+                    n = k;
+                } else {
+                    s = k;
+                }
+            }
+            sym = s;
+            x = m.distribution[s as usize].wrapping_mul(self.length);
+            if s != m.last_symbol {
+                y = m.distribution[s as usize + 1].wrapping_mul(self.length);
+            }
+        } else {
+            let (mut s, mut lo) = (0, 0);
+            let mut n = m.symbols;
+            let mut k = n >> 1;
+            loop {
+                let z = self.length.wrapping_mul(m.distribution[k as usize]);
+                if z > self.value {
+                    n = k;
+                    y = z;
+                } else {
+                    s = k;
+                    lo = z;
+                }
+                k = (s + n) >> 1;
+                if k == s {
+                    break;
+                }
+            }
+            sym = s;
+            x = lo;
+        }
+        self.value = self.value.wrapping_sub(x);
+        self.length = y.wrapping_sub(x);
+        if self.length < AC_MIN_LENGTH {
+            self.renorm();
+        }
+        m.symbol_count[sym as usize] += 1;
+        m.symbols_until_update -= 1;
+        if m.symbols_until_update == 0 {
+            m.update();
+        }
+        sym
+    }
+
+    /// Read raw bits without a model
+    pub fn read_bits(&mut self, bits: u32) -> u32 {
+        if bits > 19 {
+            let lower = self.read_short() as u32;
+            let upper = self.read_bits(bits - 16);
+            return (upper << 16) | lower;
+        }
+        self.length >>= bits;
+        let sym = self.value / self.length;
+        self.value -= self.length * sym;
+        if self.length < AC_MIN_LENGTH {
+            self.renorm();
+        }
+        sym
+    }
+
+    /// Read a raw 16 bit value without a model
+    pub fn read_short(&mut self) -> u16 {
+        self.length >>= 16;
+        let sym = self.value / self.length;
+        self.value -= self.length * sym;
+        if self.length < AC_MIN_LENGTH {
+            self.renorm();
+        }
+        sym as u16
+    }
+
+    /// Read a raw 32 bit value without a model
+    pub fn read_int(&mut self) -> u32 {
+        let lower = self.read_short() as u32;
+        let upper = self.read_short() as u32;
+        (upper << 16) | lower
+    }
+
+    /// Read a raw 64 bit value without a model
+    pub fn read_int64(&mut self) -> u64 {
+        let lower = self.read_int() as u64;
+        let upper = self.read_int() as u64;
+        (upper << 32) | lower
+    }
+
+    /// Renormalize the interval
+    fn renorm(&mut self) {
+        loop {
+            self.value = (self.value << 8) | self.byte() as u32;
+            self.length <<= 8;
+            if self.length >= AC_MIN_LENGTH {
+                break;
+            }
+        }
+    }
+}
+
+/// An adaptive model of a multi-symbol alphabet
+#[derive(Debug, Clone)]
+pub struct ArithmeticModel {
+    symbols: u32,
+    distribution: Vec<u32>,
+    symbol_count: Vec<u32>,
+    decoder_table: Vec<u32>,
+    total_count: u32,
+    update_cycle: u32,
+    symbols_until_update: u32,
+    last_symbol: u32,
+    table_size: u32,
+    table_shift: u32,
+}
+impl ArithmeticModel {
+    /// Create an initialized model of `symbols` symbols (2 to 2048)
+    pub fn new(symbols: u32) -> Self {
+        let mut model = Self {
+            symbols,
+            distribution: vec![0; symbols as usize],
+            symbol_count: vec![1; symbols as usize],
+            decoder_table: vec![],
+            total_count: 0,
+            update_cycle: symbols,
+            symbols_until_update: 0,
+            last_symbol: symbols - 1,
+            table_size: 0,
+            table_shift: 0,
+        };
+        if symbols > 16 {
+            let mut table_bits = 3;
+            while symbols > 1 << (table_bits + 2) {
+                table_bits += 1;
+            }
+            model.table_size = 1 << table_bits;
+            model.table_shift = DM_LENGTH_SHIFT - table_bits;
+            model.decoder_table = vec![0; model.table_size as usize + 2];
+        }
+        model.update();
+        model.update_cycle = (symbols + 6) >> 1;
+        model.symbols_until_update = model.update_cycle;
+        model
+    }
+
+    /// Rebuild the distribution from the symbol counts
+    fn update(&mut self) {
+        self.total_count += self.update_cycle;
+        if self.total_count > DM_MAX_COUNT {
+            self.total_count = 0;
+            for count in self.symbol_count.iter_mut() {
+                *count = (*count + 1) >> 1;
+                self.total_count += *count;
+            }
+        }
+        let scale = 0x8000_0000 / self.total_count;
+        let mut sum: u32 = 0;
+        let mut s = 0;
+        for k in 0..self.symbols as usize {
+            self.distribution[k] = scale.wrapping_mul(sum) >> (31 - DM_LENGTH_SHIFT);
+            sum += self.symbol_count[k];
+            if self.table_size != 0 {
+                let w = self.distribution[k] >> self.table_shift;
+                while s < w {
+                    s += 1;
+                    self.decoder_table[s as usize] = k as u32 - 1;
+                }
+            }
+        }
+        if self.table_size != 0 {
+            self.decoder_table[0] = 0;
+            while s <= self.table_size {
+                s += 1;
+                self.decoder_table[s as usize] = self.symbols - 1;
+            }
+        }
+        self.update_cycle = (5 * self.update_cycle) >> 2;
+        let max_cycle = (self.symbols + 6) << 3;
+        if self.update_cycle > max_cycle {
+            self.update_cycle = max_cycle;
+        }
+        self.symbols_until_update = self.update_cycle;
+    }
+}
+
+/// An adaptive model of a single bit
+#[derive(Debug, Clone)]
+pub struct ArithmeticBitModel {
+    update_cycle: u32,
+    bits_until_update: u32,
+    bit_0_prob: u32,
+    bit_0_count: u32,
+    bit_count: u32,
+}
+impl Default for ArithmeticBitModel {
+    fn default() -> Self {
+        Self {
+            update_cycle: 4,
+            bits_until_update: 4,
+            bit_0_prob: 1 << (BM_LENGTH_SHIFT - 1),
+            bit_0_count: 1,
+            bit_count: 2,
+        }
+    }
+}
+impl ArithmeticBitModel {
+    /// Rebuild the probability from the bit counts
+    fn update(&mut self) {
+        self.bit_count += self.update_cycle;
+        if self.bit_count > BM_MAX_COUNT {
+            self.bit_count = (self.bit_count + 1) >> 1;
+            self.bit_0_count = (self.bit_0_count + 1) >> 1;
+            if self.bit_0_count == self.bit_count {
+                self.bit_count += 1;
+            }
+        }
+        let scale = 0x8000_0000 / self.bit_count;
+        self.bit_0_prob = (self.bit_0_count * scale) >> (31 - BM_LENGTH_SHIFT);
+        self.update_cycle = ((5 * self.update_cycle) >> 2).min(64);
+        self.bits_until_update = self.update_cycle;
+    }
+}
+
+/// Decompresses integers as a correction of a prediction. The number of corrector bits `k` of
+/// the last value is often used as a context for the next one
+#[derive(Debug, Clone)]
+pub struct IntegerCompressor {
+    k: u32,
+    corr_range: u32,
+    corr_min: i32,
+    bits_high: u32,
+    m_bits: Vec<ArithmeticModel>,
+    m_corrector_0: ArithmeticBitModel,
+    m_corrector: Vec<ArithmeticModel>,
+}
+impl IntegerCompressor {
+    /// Create a compressor of `bits` bit integers with `contexts` contexts
+    pub fn new(bits: u32, contexts: u32) -> Self {
+        let bits_high = 8;
+        let (corr_bits, corr_range, corr_min) = if bits != 0 && bits < 32 {
+            let range = 1u32 << bits;
+            (bits, range, -((range / 2) as i32))
+        } else {
+            (32, 0, i32::MIN)
+        };
+        Self {
+            k: 0,
+            corr_range,
+            corr_min,
+            bits_high,
+            m_bits: (0..contexts).map(|_| ArithmeticModel::new(corr_bits + 1)).collect(),
+            m_corrector_0: ArithmeticBitModel::default(),
+            m_corrector: (1..=corr_bits)
+                .map(|i| ArithmeticModel::new(1 << i.min(bits_high)))
+                .collect(),
+        }
+    }
+
+    /// The number of corrector bits of the last decompressed value
+    pub fn k(&self) -> u32 {
+        self.k
+    }
+
+    /// Decompress a value given its prediction
+    pub fn decompress(&mut self, dec: &mut ArithmeticDecoder, pred: i32, context: usize) -> i32 {
+        let mut real = pred.wrapping_add(self.read_corrector(dec, context));
+        if real < 0 {
+            real = real.wrapping_add(self.corr_range as i32);
+        } else if real as u32 >= self.corr_range {
+            real = real.wrapping_sub(self.corr_range as i32);
+        }
+        real
+    }
+
+    /// Read the corrector of a prediction
+    fn read_corrector(&mut self, dec: &mut ArithmeticDecoder, context: usize) -> i32 {
+        self.k = dec.decode_symbol(&mut self.m_bits[context]);
+        let k = self.k;
+        if k == 0 {
+            return dec.decode_bit(&mut self.m_corrector_0) as i32;
+        }
+        if k >= 32 {
+            return self.corr_min;
+        }
+        let model = &mut self.m_corrector[k as usize - 1];
+        let mut c = if k <= self.bits_high {
+            dec.decode_symbol(model) as i32
+        } else {
+            let k1 = k - self.bits_high;
+            let c = dec.decode_symbol(model) as i32;
+            let c1 = dec.read_bits(k1) as i32;
+            (c << k1) | c1
+        };
+        // translate c back into its correct interval
+        if c >= 1 << (k - 1) {
+            c += 1;
+        } else {
+            c = c.wrapping_sub(((1u32 << k) - 1) as i32);
+        }
+        c
+    }
+}
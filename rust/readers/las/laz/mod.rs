@@ -0,0 +1,412 @@
+/// The arithmetic decoder, its models and the integer compressor
+pub mod arithmetic;
+/// Version 1 item readers (LASzip 1.x)
+pub mod v1;
+/// Version 2 item readers (LASzip 2.x)
+pub mod v2;
+/// Version 3 and 4 layered item readers of the LAS 1.4 point types (LASzip 3.x)
+pub mod v3;
+
+pub use arithmetic::*;
+
+use super::LASError;
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// The record ID of the LASzip VLR
+pub const LAZ_RECORD_ID: u16 = 22204;
+
+/// How the points of a LAZ file are compressed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LAZCompressor {
+    /// The points aren't compressed
+    #[default]
+    None = 0,
+    /// Point by point, all points in a single chunk
+    Pointwise = 1,
+    /// Point by point, in chunks of points
+    PointwiseChunked = 2,
+    /// Attribute layers, in chunks of points (LAS 1.4 point types)
+    LayeredChunked = 3,
+}
+impl TryFrom<u16> for LAZCompressor {
+    type Error = LASError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(LAZCompressor::None),
+            1 => Ok(LAZCompressor::Pointwise),
+            2 => Ok(LAZCompressor::PointwiseChunked),
+            3 => Ok(LAZCompressor::LayeredChunked),
+            _ => Err(LASError::UnsupportedCompressor(value)),
+        }
+    }
+}
+
+/// The type of an item: a group of attributes of a point compressed together
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LAZItemType {
+    /// Extra bytes
+    #[default]
+    Byte = 0,
+    /// Unused legacy type
+    Short = 1,
+    /// Unused legacy type
+    Int = 2,
+    /// Unused legacy type
+    Long = 3,
+    /// Unused legacy type
+    Float = 4,
+    /// Unused legacy type
+    Double = 5,
+    /// The core of point formats 0 to 5
+    Point10 = 6,
+    /// The GPS time of point formats 1, 3, 4 and 5
+    GpsTime11 = 7,
+    /// The colors of point formats 2, 3 and 5
+    Rgb12 = 8,
+    /// The wave packet of point formats 4 and 5
+    Wavepacket13 = 9,
+    /// The core of point formats 6 to 10
+    Point14 = 10,
+    /// The colors of point format 7
+    Rgb14 = 11,
+    /// The colors and near infrared of point formats 8 and 10
+    RgbNir14 = 12,
+    /// The wave packet of point formats 9 and 10
+    Wavepacket14 = 13,
+    /// Extra bytes of point formats 6 to 10
+    Byte14 = 14,
+}
+impl TryFrom<u16> for LAZItemType {
+    type Error = LASError;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(LAZItemType::Byte),
+            1 => Ok(LAZItemType::Short),
+            2 => Ok(LAZItemType::Int),
+            3 => Ok(LAZItemType::Long),
+            4 => Ok(LAZItemType::Float),
+            5 => Ok(LAZItemType::Double),
+            6 => Ok(LAZItemType::Point10),
+            7 => Ok(LAZItemType::GpsTime11),
+            8 => Ok(LAZItemType::Rgb12),
+            9 => Ok(LAZItemType::Wavepacket13),
+            10 => Ok(LAZItemType::Point14),
+            11 => Ok(LAZItemType::Rgb14),
+            12 => Ok(LAZItemType::RgbNir14),
+            13 => Ok(LAZItemType::Wavepacket14),
+            14 => Ok(LAZItemType::Byte14),
+            _ => Err(LASError::UnsupportedItem(value, 0)),
+        }
+    }
+}
+
+/// An item of the point record and how it's compressed
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LAZItem {
+    /// The type of the item
+    pub item_type: LAZItemType,
+    /// The size of the item in bytes
+    pub size: u16,
+    /// The version of the compression
+    pub version: u16,
+}
+impl LAZItem {
+    /// The number of layers of the item in a layered chunk
+    fn num_layers(&self) -> usize {
+        match self.item_type {
+            LAZItemType::Point14 => 9,
+            LAZItemType::RgbNir14 => 2,
+            LAZItemType::Byte14 => self.size as usize,
+            _ => 1,
+        }
+    }
+}
+
+/// The LASzip VLR describing how the points are compressed
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LAZHeader {
+    /// The compressor
+    pub compressor: LAZCompressor,
+    /// The coder (0 is the only arithmetic coder)
+    pub coder: u16,
+    /// Major version of LASzip
+    pub version_major: u8,
+    /// Minor version of LASzip
+    pub version_minor: u8,
+    /// Revision of LASzip
+    pub version_revision: u16,
+    /// Option bits
+    pub options: u32,
+    /// The number of points per chunk. `u32::MAX` if the chunks have variable sizes
+    pub chunk_size: u32,
+    /// The number of special EVLRs (-1 if unused)
+    pub num_special_evlrs: i64,
+    /// The offset of the special EVLRs (-1 if unused)
+    pub offset_special_evlrs: i64,
+    /// The items of a point record in order
+    pub items: Vec<LAZItem>,
+}
+impl LAZHeader {
+    /// Parse the data of the LASzip VLR
+    pub fn new(data: &[u8]) -> Result<Self, LASError> {
+        let u16_at = |o: usize| data.get(o..o + 2).map(|b| u16::from_le_bytes([b[0], b[1]]));
+        let mut bytes = [0; 34];
+        bytes.copy_from_slice(data.get(..34).ok_or(LASError::InvalidLAZRecord)?);
+        let num_items = u16_at(32).ok_or(LASError::InvalidLAZRecord)? as usize;
+        let items = (0..num_items)
+            .map(|i| {
+                let offset = 34 + i * 6;
+                let (Some(item_type), Some(size), Some(version)) =
+                    (u16_at(offset), u16_at(offset + 2), u16_at(offset + 4))
+                else {
+                    return Err(LASError::InvalidLAZRecord);
+                };
+                let item_type = LAZItemType::try_from(item_type)
+                    .map_err(|_| LASError::UnsupportedItem(item_type, version))?;
+                Ok(LAZItem { item_type, size, version })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self {
+            compressor: LAZCompressor::try_from(u16::from_le_bytes([bytes[0], bytes[1]]))?,
+            coder: u16::from_le_bytes([bytes[2], bytes[3]]),
+            version_major: bytes[4],
+            version_minor: bytes[5],
+            version_revision: u16::from_le_bytes([bytes[6], bytes[7]]),
+            options: u32::from_le_bytes([bytes[8], bytes[9], bytes[10], bytes[11]]),
+            chunk_size: u32::from_le_bytes([bytes[12], bytes[13], bytes[14], bytes[15]]),
+            num_special_evlrs: i64::from_le_bytes(bytes[16..24].try_into().unwrap()),
+            offset_special_evlrs: i64::from_le_bytes(bytes[24..32].try_into().unwrap()),
+            items,
+        })
+    }
+
+    /// The size of a point record in bytes
+    pub fn point_size(&self) -> usize {
+        self.items.iter().map(|i| i.size as usize).sum()
+    }
+}
+
+/// A chunk of compressed points
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LAZChunk {
+    /// The offset of the chunk in the file
+    pub offset: u64,
+    /// The size of the chunk in bytes
+    pub size: u64,
+    /// The number of points in the chunk
+    pub points: u64,
+}
+
+/// Parse the chunk table from `data` starting at the table. `chunks_start` is the offset of the first
+/// chunk and `num_points` the number of points in the file
+pub fn parse_chunk_table(
+    data: &[u8],
+    chunk_size: u32,
+    chunks_start: u64,
+    num_points: u64,
+) -> Result<Vec<LAZChunk>, LASError> {
+    let version = data.get(..4).ok_or(LASError::InvalidChunkTable)?;
+    let number = data.get(4..8).ok_or(LASError::InvalidChunkTable)?;
+    if version != [0, 0, 0, 0] {
+        return Err(LASError::InvalidChunkTable);
+    }
+    let number = u32::from_le_bytes(number.try_into().unwrap()) as usize;
+    let variable = chunk_size == u32::MAX;
+    let mut dec = ArithmeticDecoder::new_init(data[8..].to_vec());
+    let mut ic = IntegerCompressor::new(32, 2);
+    // the count is untrusted, every entry takes at least a byte of the table
+    let mut chunks = Vec::with_capacity(number.min(data.len() - 8));
+    let (mut last_points, mut last_size) = (0, 0);
+    let (mut offset, mut total) = (chunks_start, 0);
+    for _ in 0..number {
+        let points = if variable {
+            last_points = ic.decompress(&mut dec, last_points, 0);
+            last_points as u32 as u64
+        } else {
+            (chunk_size as u64).min(num_points.saturating_sub(total))
+        };
+        last_size = ic.decompress(&mut dec, last_size, 1);
+        let size = last_size as u32 as u64;
+        chunks.push(LAZChunk { offset, size, points });
+        offset += size;
+        total += points;
+    }
+    Ok(chunks)
+}
+
+/// Decompresses an item of a point record
+pub(crate) trait ItemReader {
+    /// Decompress the next item into `item`. The point item of the layered items sets the
+    /// `context` (the scanner channel) for the items that follow it
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], context: &mut usize);
+}
+
+/// Decompresses the points of a chunk. The first point of a chunk is stored raw and initializes
+/// the item readers
+pub(crate) struct LAZChunkDecoder {
+    readers: Vec<Box<dyn ItemReader>>,
+    sizes: Vec<usize>,
+    dec: ArithmeticDecoder,
+    first: Option<Vec<u8>>,
+    context: usize,
+}
+impl core::fmt::Debug for LAZChunkDecoder {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LAZChunkDecoder").field("sizes", &self.sizes).finish()
+    }
+}
+impl LAZChunkDecoder {
+    /// Prepare the decompression of a chunk's data
+    pub fn new(items: &[LAZItem], layered: bool, mut data: Vec<u8>) -> Result<Self, LASError> {
+        let sizes: Vec<usize> = items.iter().map(|i| i.size as usize).collect();
+        let point_size = sizes.iter().sum();
+        if data.len() < point_size {
+            return Err(LASError::InvalidChunk);
+        }
+        let mut context = 0;
+        let mut readers = Vec::with_capacity(items.len());
+        let dec = if layered {
+            // the number of points and the byte size of every layer follow the first point
+            let mut cursor = point_size + 4;
+            let mut layer_sizes = vec![];
+            for item in items {
+                let mut sizes = vec![];
+                for _ in 0..item.num_layers() {
+                    let bytes = data.get(cursor..cursor + 4).ok_or(LASError::InvalidChunk)?;
+                    sizes.push(u32::from_le_bytes(bytes.try_into().unwrap()) as usize);
+                    cursor += 4;
+                }
+                layer_sizes.push(sizes);
+            }
+            let mut item_start = 0;
+            for (item, sizes) in items.iter().zip(layer_sizes) {
+                let mut layers = vec![];
+                for size in sizes {
+                    let layer = data.get(cursor..cursor + size).ok_or(LASError::InvalidChunk)?;
+                    layers.push(layer.to_vec());
+                    cursor += size;
+                }
+                let first = &data[item_start..item_start + item.size as usize];
+                readers.push(v3::layered_reader(item, first, layers, &mut context)?);
+                item_start += item.size as usize;
+            }
+            data.truncate(point_size);
+            ArithmeticDecoder::default()
+        } else {
+            let rest = data.split_off(point_size);
+            let mut item_start = 0;
+            for item in items {
+                let first = &data[item_start..item_start + item.size as usize];
+                let reader: Box<dyn ItemReader> = match item.version {
+                    1 => v1::pointwise_reader(item, first)?,
+                    2 => v2::pointwise_reader(item, first)?,
+                    _ => {
+                        return Err(LASError::UnsupportedItem(item.item_type as u16, item.version))
+                    }
+                };
+                readers.push(reader);
+                item_start += item.size as usize;
+            }
+            ArithmeticDecoder::new_init(rest)
+        };
+        Ok(Self { readers, sizes, dec, first: Some(data), context })
+    }
+
+    /// Decompress the next point record into `record`
+    pub fn next_point(&mut self, record: &mut [u8]) {
+        if let Some(first) = self.first.take() {
+            record[..first.len()].copy_from_slice(&first);
+            return;
+        }
+        let mut start = 0;
+        for (reader, size) in self.readers.iter_mut().zip(&self.sizes) {
+            reader.read(&mut self.dec, &mut record[start..start + size], &mut self.context);
+            start += size;
+        }
+    }
+}
+
+/// Add a correction to a byte, wrapping around
+pub(crate) fn u8_fold(n: i32) -> u8 {
+    n as u8
+}
+
+/// Clamp a value to the range of a byte
+pub(crate) fn u8_clamp(n: i32) -> i32 {
+    n.clamp(0, 255)
+}
+
+/// Read a little-endian array of bytes at an offset of an item
+pub(crate) fn le<const N: usize>(item: &[u8], offset: usize) -> [u8; N] {
+    item[offset..offset + N].try_into().unwrap()
+}
+
+/// The median of the last five values
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StreamingMedian5 {
+    values: [i32; 5],
+    high: bool,
+}
+impl Default for StreamingMedian5 {
+    fn default() -> Self {
+        Self { values: [0; 5], high: true }
+    }
+}
+impl StreamingMedian5 {
+    /// Add a value
+    pub fn add(&mut self, v: i32) {
+        let values = &mut self.values;
+        if self.high {
+            if v < values[2] {
+                values[4] = values[3];
+                values[3] = values[2];
+                if v < values[0] {
+                    values[2] = values[1];
+                    values[1] = values[0];
+                    values[0] = v;
+                } else if v < values[1] {
+                    values[2] = values[1];
+                    values[1] = v;
+                } else {
+                    values[2] = v;
+                }
+            } else {
+                if v < values[3] {
+                    values[4] = values[3];
+                    values[3] = v;
+                } else {
+                    values[4] = v;
+                }
+                self.high = false;
+            }
+        } else if values[2] < v {
+            values[0] = values[1];
+            values[1] = values[2];
+            if values[4] < v {
+                values[2] = values[3];
+                values[3] = values[4];
+                values[4] = v;
+            } else if values[3] < v {
+                values[2] = values[3];
+                values[3] = v;
+            } else {
+                values[2] = v;
+            }
+        } else {
+            if values[1] < v {
+                values[0] = values[1];
+                values[1] = v;
+            } else {
+                values[0] = v;
+            }
+            self.high = true;
+        }
+    }
+
+    /// The current median
+    pub fn get(&self) -> i32 {
+        self.values[2]
+    }
+}
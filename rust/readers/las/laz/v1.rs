@@ -0,0 +1,430 @@
+use super::{
+    le, ArithmeticDecoder, ArithmeticModel, IntegerCompressor, ItemReader, LAZItem, LAZItemType,
+};
+use crate::readers::las::LASError;
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// The number of GPS time multipliers
+const GPSTIME_MULTI_MAX: u32 = 512;
+
+/// Create the reader of a version 1 item
+pub(crate) fn pointwise_reader(
+    item: &LAZItem,
+    first: &[u8],
+) -> Result<Box<dyn ItemReader>, LASError> {
+    Ok(match item.item_type {
+        LAZItemType::Point10 => Box::new(Point10Reader::new(first)),
+        LAZItemType::GpsTime11 => Box::new(GpsTime11Reader::new(first)),
+        LAZItemType::Rgb12 => Box::new(Rgb12Reader::new(first)),
+        LAZItemType::Wavepacket13 => Box::new(Wavepacket13Reader::new(first)),
+        LAZItemType::Byte => Box::new(ByteReader::new(first)),
+        _ => return Err(LASError::UnsupportedItem(item.item_type as u16, item.version)),
+    })
+}
+
+/// The core attributes of point formats 0 to 5
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Point10 {
+    pub x: i32,
+    pub y: i32,
+    pub z: i32,
+    pub intensity: u16,
+    /// Return number, number of returns, scan direction and edge of flight line bits
+    pub flags: u8,
+    /// Classification, synthetic, key-point and withheld bits
+    pub class: u8,
+    pub scan_angle_rank: i8,
+    pub user_data: u8,
+    pub point_source_id: u16,
+}
+impl Point10 {
+    /// Read the item
+    pub fn new(item: &[u8]) -> Self {
+        Self {
+            x: i32::from_le_bytes(le(item, 0)),
+            y: i32::from_le_bytes(le(item, 4)),
+            z: i32::from_le_bytes(le(item, 8)),
+            intensity: u16::from_le_bytes(le(item, 12)),
+            flags: item[14],
+            class: item[15],
+            scan_angle_rank: item[16] as i8,
+            user_data: item[17],
+            point_source_id: u16::from_le_bytes(le(item, 18)),
+        }
+    }
+
+    /// Write the item
+    pub fn write(&self, item: &mut [u8]) {
+        item[0..4].copy_from_slice(&self.x.to_le_bytes());
+        item[4..8].copy_from_slice(&self.y.to_le_bytes());
+        item[8..12].copy_from_slice(&self.z.to_le_bytes());
+        item[12..14].copy_from_slice(&self.intensity.to_le_bytes());
+        item[14] = self.flags;
+        item[15] = self.class;
+        item[16] = self.scan_angle_rank as u8;
+        item[17] = self.user_data;
+        item[18..20].copy_from_slice(&self.point_source_id.to_le_bytes());
+    }
+
+    /// The return number
+    pub fn return_number(&self) -> usize {
+        (self.flags & 0b111) as usize
+    }
+
+    /// The number of returns of the pulse
+    pub fn number_of_returns(&self) -> usize {
+        ((self.flags >> 3) & 0b111) as usize
+    }
+
+    /// The scan direction flag
+    pub fn scan_direction(&self) -> usize {
+        ((self.flags >> 6) & 1) as usize
+    }
+}
+
+/// Lazily created models indexed by a byte of the last item
+pub(crate) fn lazy_model(
+    models: &mut [Option<ArithmeticModel>],
+    index: usize,
+    symbols: u32,
+) -> &mut ArithmeticModel {
+    models[index].get_or_insert_with(|| ArithmeticModel::new(symbols))
+}
+
+/// The median of three values
+fn median3(v: &[i32; 3]) -> i32 {
+    if v[0] < v[1] {
+        if v[1] < v[2] {
+            v[1]
+        } else if v[0] < v[2] {
+            v[2]
+        } else {
+            v[0]
+        }
+    } else if v[0] < v[2] {
+        v[0]
+    } else if v[1] < v[2] {
+        v[2]
+    } else {
+        v[1]
+    }
+}
+
+/// Point10 version 1 reader
+struct Point10Reader {
+    last: Point10,
+    last_x_diff: [i32; 3],
+    last_y_diff: [i32; 3],
+    last_incr: usize,
+    ic_dx: IntegerCompressor,
+    ic_dy: IntegerCompressor,
+    ic_z: IntegerCompressor,
+    ic_intensity: IntegerCompressor,
+    ic_scan_angle_rank: IntegerCompressor,
+    ic_point_source_id: IntegerCompressor,
+    m_changed_values: ArithmeticModel,
+    m_bit_byte: Vec<Option<ArithmeticModel>>,
+    m_classification: Vec<Option<ArithmeticModel>>,
+    m_user_data: Vec<Option<ArithmeticModel>>,
+}
+impl Point10Reader {
+    fn new(first: &[u8]) -> Self {
+        Self {
+            last: Point10::new(first),
+            last_x_diff: [0; 3],
+            last_y_diff: [0; 3],
+            last_incr: 0,
+            ic_dx: IntegerCompressor::new(32, 1),
+            ic_dy: IntegerCompressor::new(32, 20),
+            ic_z: IntegerCompressor::new(32, 20),
+            ic_intensity: IntegerCompressor::new(16, 1),
+            ic_scan_angle_rank: IntegerCompressor::new(8, 2),
+            ic_point_source_id: IntegerCompressor::new(16, 1),
+            m_changed_values: ArithmeticModel::new(64),
+            m_bit_byte: vec![None; 256],
+            m_classification: vec![None; 256],
+            m_user_data: vec![None; 256],
+        }
+    }
+}
+impl ItemReader for Point10Reader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        let last = &mut self.last;
+        // the differences are predicted by the median of the last three
+        let x_diff = self.ic_dx.decompress(dec, median3(&self.last_x_diff), 0);
+        last.x = last.x.wrapping_add(x_diff);
+        // the number of corrector bits of x are the context of y
+        let k_bits = self.ic_dx.k();
+        let y_diff =
+            self.ic_dy.decompress(dec, median3(&self.last_y_diff), k_bits.min(19) as usize);
+        last.y = last.y.wrapping_add(y_diff);
+        let k_bits = (k_bits + self.ic_dy.k()) / 2;
+        last.z = self.ic_z.decompress(dec, last.z, k_bits.min(19) as usize);
+
+        let changed_values = dec.decode_symbol(&mut self.m_changed_values);
+        if changed_values & 32 != 0 {
+            last.intensity = self.ic_intensity.decompress(dec, last.intensity as i32, 0) as u16;
+        }
+        if changed_values & 16 != 0 {
+            let model = lazy_model(&mut self.m_bit_byte, last.flags as usize, 256);
+            last.flags = dec.decode_symbol(model) as u8;
+        }
+        if changed_values & 8 != 0 {
+            let model = lazy_model(&mut self.m_classification, last.class as usize, 256);
+            last.class = dec.decode_symbol(model) as u8;
+        }
+        if changed_values & 4 != 0 {
+            let context = (k_bits < 3) as usize;
+            let rank = last.scan_angle_rank as u8 as i32;
+            last.scan_angle_rank =
+                self.ic_scan_angle_rank.decompress(dec, rank, context) as u8 as i8;
+        }
+        if changed_values & 2 != 0 {
+            let model = lazy_model(&mut self.m_user_data, last.user_data as usize, 256);
+            last.user_data = dec.decode_symbol(model) as u8;
+        }
+        if changed_values & 1 != 0 {
+            let id = last.point_source_id as i32;
+            last.point_source_id = self.ic_point_source_id.decompress(dec, id, 0) as u16;
+        }
+
+        self.last_x_diff[self.last_incr] = x_diff;
+        self.last_y_diff[self.last_incr] = y_diff;
+        self.last_incr = (self.last_incr + 1) % 3;
+        last.write(item);
+    }
+}
+
+/// GPS time version 1 reader
+struct GpsTime11Reader {
+    last: u64,
+    last_diff: i32,
+    multi_extreme_counter: i32,
+    m_gpstime_multi: ArithmeticModel,
+    m_gpstime_0diff: ArithmeticModel,
+    ic_gpstime: IntegerCompressor,
+}
+impl GpsTime11Reader {
+    fn new(first: &[u8]) -> Self {
+        Self {
+            last: u64::from_le_bytes(le(first, 0)),
+            last_diff: 0,
+            multi_extreme_counter: 0,
+            m_gpstime_multi: ArithmeticModel::new(GPSTIME_MULTI_MAX),
+            m_gpstime_0diff: ArithmeticModel::new(3),
+            ic_gpstime: IntegerCompressor::new(32, 6),
+        }
+    }
+}
+impl ItemReader for GpsTime11Reader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        if self.last_diff == 0 {
+            match dec.decode_symbol(&mut self.m_gpstime_0diff) {
+                // the difference fits in 32 bits
+                1 => {
+                    self.last_diff = self.ic_gpstime.decompress(dec, 0, 0);
+                    self.last = self.last.wrapping_add(self.last_diff as u64);
+                    self.multi_extreme_counter = 0;
+                }
+                // the difference is huge
+                2 => self.last = dec.read_int64(),
+                _ => {}
+            }
+        } else {
+            let multi = dec.decode_symbol(&mut self.m_gpstime_multi);
+            if multi < GPSTIME_MULTI_MAX - 2 {
+                let last_diff = self.last_diff;
+                let diff = if multi == 1 {
+                    let diff = self.ic_gpstime.decompress(dec, last_diff, 1);
+                    self.last_diff = diff;
+                    self.multi_extreme_counter = 0;
+                    diff
+                } else if multi == 0 {
+                    let diff = self.ic_gpstime.decompress(dec, last_diff / 4, 2);
+                    self.multi_extreme_counter += 1;
+                    if self.multi_extreme_counter > 3 {
+                        self.last_diff = diff;
+                        self.multi_extreme_counter = 0;
+                    }
+                    diff
+                } else {
+                    let context = if multi < 10 {
+                        3
+                    } else if multi < 50 {
+                        4
+                    } else {
+                        5
+                    };
+                    let pred = (multi as i32).wrapping_mul(last_diff);
+                    let diff = self.ic_gpstime.decompress(dec, pred, context);
+                    if multi == GPSTIME_MULTI_MAX - 3 {
+                        self.multi_extreme_counter += 1;
+                        if self.multi_extreme_counter > 3 {
+                            self.last_diff = diff;
+                            self.multi_extreme_counter = 0;
+                        }
+                    }
+                    diff
+                };
+                self.last = self.last.wrapping_add(diff as u64);
+            } else if multi < GPSTIME_MULTI_MAX - 1 {
+                self.last = dec.read_int64();
+            }
+        }
+        item[..8].copy_from_slice(&self.last.to_le_bytes());
+    }
+}
+
+/// RGB version 1 reader
+struct Rgb12Reader {
+    last: [u16; 3],
+    m_byte_used: ArithmeticModel,
+    ic_rgb: IntegerCompressor,
+}
+impl Rgb12Reader {
+    fn new(first: &[u8]) -> Self {
+        let last = [0, 2, 4].map(|o| u16::from_le_bytes(le(first, o)));
+        Self { last, m_byte_used: ArithmeticModel::new(64), ic_rgb: IntegerCompressor::new(8, 6) }
+    }
+}
+impl ItemReader for Rgb12Reader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        let sym = dec.decode_symbol(&mut self.m_byte_used);
+        for (i, last) in self.last.iter_mut().enumerate() {
+            let low = if sym & (1 << (2 * i)) != 0 {
+                self.ic_rgb.decompress(dec, (*last & 0xff) as i32, 2 * i) as u16 & 0xff
+            } else {
+                *last & 0xff
+            };
+            let high = if sym & (1 << (2 * i + 1)) != 0 {
+                (self.ic_rgb.decompress(dec, (*last >> 8) as i32, 2 * i + 1) as u16 & 0xff) << 8
+            } else {
+                *last & 0xff00
+            };
+            *last = low | high;
+            item[2 * i..2 * i + 2].copy_from_slice(&last.to_le_bytes());
+        }
+    }
+}
+
+/// The wave packet attributes of point formats 4, 5, 9 and 10
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct Wavepacket {
+    pub index: u8,
+    pub offset: u64,
+    pub packet_size: u32,
+    /// The bits of the return point waveform location (an f32)
+    pub return_point: i32,
+    /// The bits of the parametric line (f32s)
+    pub xyz: [i32; 3],
+}
+impl Wavepacket {
+    /// Read the item
+    pub fn new(item: &[u8]) -> Self {
+        Self {
+            index: item[0],
+            offset: u64::from_le_bytes(le(item, 1)),
+            packet_size: u32::from_le_bytes(le(item, 9)),
+            return_point: i32::from_le_bytes(le(item, 13)),
+            xyz: [17, 21, 25].map(|o| i32::from_le_bytes(le(item, o))),
+        }
+    }
+
+    /// Write the item
+    pub fn write(&self, item: &mut [u8]) {
+        item[0] = self.index;
+        item[1..9].copy_from_slice(&self.offset.to_le_bytes());
+        item[9..13].copy_from_slice(&self.packet_size.to_le_bytes());
+        item[13..17].copy_from_slice(&self.return_point.to_le_bytes());
+        for (i, v) in self.xyz.iter().enumerate() {
+            item[17 + 4 * i..21 + 4 * i].copy_from_slice(&v.to_le_bytes());
+        }
+    }
+}
+
+/// The models of a wave packet, shared by the version 1 and 3 readers
+#[derive(Debug, Clone)]
+pub(crate) struct WavepacketModels {
+    last_diff_32: i32,
+    sym_last_offset_diff: u32,
+    m_packet_index: ArithmeticModel,
+    m_offset_diff: [ArithmeticModel; 4],
+    ic_offset_diff: IntegerCompressor,
+    ic_packet_size: IntegerCompressor,
+    ic_return_point: IntegerCompressor,
+    ic_xyz: IntegerCompressor,
+}
+impl Default for WavepacketModels {
+    fn default() -> Self {
+        Self {
+            last_diff_32: 0,
+            sym_last_offset_diff: 0,
+            m_packet_index: ArithmeticModel::new(256),
+            m_offset_diff: core::array::from_fn(|_| ArithmeticModel::new(4)),
+            ic_offset_diff: IntegerCompressor::new(32, 1),
+            ic_packet_size: IntegerCompressor::new(32, 1),
+            ic_return_point: IntegerCompressor::new(32, 1),
+            ic_xyz: IntegerCompressor::new(32, 3),
+        }
+    }
+}
+impl WavepacketModels {
+    /// Decompress the wave packet following `last`
+    pub fn read(&mut self, dec: &mut ArithmeticDecoder, last: &Wavepacket) -> Wavepacket {
+        let index = dec.decode_symbol(&mut self.m_packet_index) as u8;
+        self.sym_last_offset_diff =
+            dec.decode_symbol(&mut self.m_offset_diff[self.sym_last_offset_diff as usize]);
+        let offset = match self.sym_last_offset_diff {
+            0 => last.offset,
+            1 => last.offset.wrapping_add(last.packet_size as u64),
+            2 => {
+                self.last_diff_32 = self.ic_offset_diff.decompress(dec, self.last_diff_32, 0);
+                last.offset.wrapping_add(self.last_diff_32 as i64 as u64)
+            }
+            _ => dec.read_int64(),
+        };
+        let packet_size = self.ic_packet_size.decompress(dec, last.packet_size as i32, 0) as u32;
+        let return_point = self.ic_return_point.decompress(dec, last.return_point, 0);
+        let mut xyz = [0; 3];
+        for (i, v) in xyz.iter_mut().enumerate() {
+            *v = self.ic_xyz.decompress(dec, last.xyz[i], i);
+        }
+        Wavepacket { index, offset, packet_size, return_point, xyz }
+    }
+}
+
+/// Wave packet version 1 reader. The packet index is the first byte of the item
+struct Wavepacket13Reader {
+    last: Wavepacket,
+    models: WavepacketModels,
+}
+impl Wavepacket13Reader {
+    fn new(first: &[u8]) -> Self {
+        Self { last: Wavepacket::new(first), models: WavepacketModels::default() }
+    }
+}
+impl ItemReader for Wavepacket13Reader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        self.last = self.models.read(dec, &self.last);
+        self.last.write(item);
+    }
+}
+
+/// Extra bytes version 1 reader
+struct ByteReader {
+    last: Vec<u8>,
+    ic_byte: IntegerCompressor,
+}
+impl ByteReader {
+    fn new(first: &[u8]) -> Self {
+        Self { last: first.to_vec(), ic_byte: IntegerCompressor::new(8, first.len() as u32) }
+    }
+}
+impl ItemReader for ByteReader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        for (i, last) in self.last.iter_mut().enumerate() {
+            *last = self.ic_byte.decompress(dec, *last as i32, i) as u8;
+        }
+        item.copy_from_slice(&self.last);
+    }
+}
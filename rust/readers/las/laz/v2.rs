@@ -0,0 +1,406 @@
+use super::{
+    le, u8_clamp, u8_fold,
+    v1::{lazy_model, Point10},
+    ArithmeticDecoder, ArithmeticModel, IntegerCompressor, ItemReader, LAZItem, LAZItemType,
+    StreamingMedian5,
+};
+use crate::readers::las::LASError;
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// The largest GPS time multiplier
+const GPSTIME_MULTI: i32 = 500;
+/// The smallest GPS time multiplier
+const GPSTIME_MULTI_MINUS: i32 = -10;
+/// The GPS time is unchanged
+const GPSTIME_MULTI_UNCHANGED: u32 = (GPSTIME_MULTI - GPSTIME_MULTI_MINUS + 1) as u32;
+/// The GPS time is stored in full
+const GPSTIME_MULTI_CODE_FULL: u32 = (GPSTIME_MULTI - GPSTIME_MULTI_MINUS + 2) as u32;
+/// The number of GPS time symbols
+const GPSTIME_MULTI_TOTAL: u32 = (GPSTIME_MULTI - GPSTIME_MULTI_MINUS + 6) as u32;
+
+/// Maps the number of returns and return number to a context
+pub(crate) const NUMBER_RETURN_MAP: [[u8; 8]; 8] = [
+    [15, 14, 13, 12, 11, 10, 9, 8],
+    [14, 0, 1, 3, 6, 10, 10, 9],
+    [13, 1, 2, 4, 7, 11, 11, 10],
+    [12, 3, 4, 5, 8, 12, 12, 11],
+    [11, 6, 7, 8, 9, 13, 13, 12],
+    [10, 10, 11, 12, 13, 14, 14, 13],
+    [9, 10, 11, 12, 13, 14, 15, 14],
+    [8, 9, 10, 11, 12, 13, 14, 15],
+];
+
+/// Maps the number of returns and return number to the level of the return
+pub(crate) const NUMBER_RETURN_LEVEL: [[u8; 8]; 8] = [
+    [0, 1, 2, 3, 4, 5, 6, 7],
+    [1, 0, 1, 2, 3, 4, 5, 6],
+    [2, 1, 0, 1, 2, 3, 4, 5],
+    [3, 2, 1, 0, 1, 2, 3, 4],
+    [4, 3, 2, 1, 0, 1, 2, 3],
+    [5, 4, 3, 2, 1, 0, 1, 2],
+    [6, 5, 4, 3, 2, 1, 0, 1],
+    [7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// Create the reader of a version 2 item
+pub(crate) fn pointwise_reader(
+    item: &LAZItem,
+    first: &[u8],
+) -> Result<Box<dyn ItemReader>, LASError> {
+    Ok(match item.item_type {
+        LAZItemType::Point10 => Box::new(Point10Reader::new(first)),
+        LAZItemType::GpsTime11 => Box::new(GpsTime11Reader::new(first)),
+        LAZItemType::Rgb12 => Box::new(Rgb12Reader::new(first)),
+        LAZItemType::Byte => Box::new(ByteReader::new(first)),
+        // wave packets never got a second version
+        LAZItemType::Wavepacket13 => super::v1::pointwise_reader(item, first)?,
+        _ => return Err(LASError::UnsupportedItem(item.item_type as u16, item.version)),
+    })
+}
+
+/// Point10 version 2 reader
+struct Point10Reader {
+    last: Point10,
+    last_intensity: [u16; 16],
+    last_x_diff_median5: [StreamingMedian5; 16],
+    last_y_diff_median5: [StreamingMedian5; 16],
+    last_height: [i32; 8],
+    m_changed_values: ArithmeticModel,
+    ic_intensity: IntegerCompressor,
+    m_scan_angle_rank: [ArithmeticModel; 2],
+    ic_point_source_id: IntegerCompressor,
+    m_bit_byte: Vec<Option<ArithmeticModel>>,
+    m_classification: Vec<Option<ArithmeticModel>>,
+    m_user_data: Vec<Option<ArithmeticModel>>,
+    ic_dx: IntegerCompressor,
+    ic_dy: IntegerCompressor,
+    ic_z: IntegerCompressor,
+}
+impl Point10Reader {
+    fn new(first: &[u8]) -> Self {
+        let mut last = Point10::new(first);
+        last.intensity = 0;
+        Self {
+            last,
+            last_intensity: [0; 16],
+            last_x_diff_median5: Default::default(),
+            last_y_diff_median5: Default::default(),
+            last_height: [0; 8],
+            m_changed_values: ArithmeticModel::new(64),
+            ic_intensity: IntegerCompressor::new(16, 4),
+            m_scan_angle_rank: [ArithmeticModel::new(256), ArithmeticModel::new(256)],
+            ic_point_source_id: IntegerCompressor::new(16, 1),
+            m_bit_byte: vec![None; 256],
+            m_classification: vec![None; 256],
+            m_user_data: vec![None; 256],
+            ic_dx: IntegerCompressor::new(32, 2),
+            ic_dy: IntegerCompressor::new(32, 22),
+            ic_z: IntegerCompressor::new(32, 20),
+        }
+    }
+}
+impl ItemReader for Point10Reader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        let last = &mut self.last;
+        let changed_values = dec.decode_symbol(&mut self.m_changed_values);
+        if changed_values & 32 != 0 {
+            let model = lazy_model(&mut self.m_bit_byte, last.flags as usize, 256);
+            last.flags = dec.decode_symbol(model) as u8;
+        }
+        let (r, n) = (last.return_number(), last.number_of_returns());
+        let m = NUMBER_RETURN_MAP[n][r] as usize;
+        let l = NUMBER_RETURN_LEVEL[n][r] as usize;
+        if changed_values & 16 != 0 {
+            let pred = self.last_intensity[m] as i32;
+            last.intensity = self.ic_intensity.decompress(dec, pred, m.min(3)) as u16;
+            self.last_intensity[m] = last.intensity;
+        } else {
+            last.intensity = self.last_intensity[m];
+        }
+        if changed_values & 8 != 0 {
+            let model = lazy_model(&mut self.m_classification, last.class as usize, 256);
+            last.class = dec.decode_symbol(model) as u8;
+        }
+        if changed_values & 4 != 0 {
+            let model = &mut self.m_scan_angle_rank[last.scan_direction()];
+            let val = dec.decode_symbol(model) as i32;
+            last.scan_angle_rank = u8_fold(val + last.scan_angle_rank as u8 as i32) as i8;
+        }
+        if changed_values & 2 != 0 {
+            let model = lazy_model(&mut self.m_user_data, last.user_data as usize, 256);
+            last.user_data = dec.decode_symbol(model) as u8;
+        }
+        if changed_values & 1 != 0 {
+            let id = last.point_source_id as i32;
+            last.point_source_id = self.ic_point_source_id.decompress(dec, id, 0) as u16;
+        }
+
+        let single = (n == 1) as usize;
+        let median = self.last_x_diff_median5[m].get();
+        let diff = self.ic_dx.decompress(dec, median, single);
+        last.x = last.x.wrapping_add(diff);
+        self.last_x_diff_median5[m].add(diff);
+
+        let median = self.last_y_diff_median5[m].get();
+        let k_bits = self.ic_dx.k();
+        let context = single + if k_bits < 20 { (k_bits & !1) as usize } else { 20 };
+        let diff = self.ic_dy.decompress(dec, median, context);
+        last.y = last.y.wrapping_add(diff);
+        self.last_y_diff_median5[m].add(diff);
+
+        let k_bits = (self.ic_dx.k() + self.ic_dy.k()) / 2;
+        let context = single + if k_bits < 18 { (k_bits & !1) as usize } else { 18 };
+        last.z = self.ic_z.decompress(dec, self.last_height[l], context);
+        self.last_height[l] = last.z;
+
+        last.write(item);
+    }
+}
+
+/// The GPS time models, shared by the version 2 and 3 readers
+#[derive(Debug, Clone)]
+pub(crate) struct GpsTimeModels {
+    last: usize,
+    next: usize,
+    last_gpstime: [u64; 4],
+    last_gpstime_diff: [i32; 4],
+    multi_extreme_counter: [i32; 4],
+    m_gpstime_multi: ArithmeticModel,
+    m_gpstime_0diff: ArithmeticModel,
+    ic_gpstime: IntegerCompressor,
+    /// Version 3 has no symbols for an unchanged GPS time
+    v3: bool,
+}
+impl GpsTimeModels {
+    /// Create the models following the GPS time `first`. Version 3 drops the unused symbols
+    pub fn new(first: u64, v3: bool) -> Self {
+        let dropped = v3 as u32;
+        Self {
+            last: 0,
+            next: 0,
+            last_gpstime: [first, 0, 0, 0],
+            last_gpstime_diff: [0; 4],
+            multi_extreme_counter: [0; 4],
+            m_gpstime_multi: ArithmeticModel::new(GPSTIME_MULTI_TOTAL - dropped),
+            m_gpstime_0diff: ArithmeticModel::new(6 - dropped),
+            ic_gpstime: IntegerCompressor::new(32, 9),
+            v3,
+        }
+    }
+
+    /// Read a GPS time stored in full
+    fn read_full(&mut self, dec: &mut ArithmeticDecoder) {
+        self.next = (self.next + 1) & 3;
+        let pred = (self.last_gpstime[self.last] >> 32) as i32;
+        let high = self.ic_gpstime.decompress(dec, pred, 8) as u32 as u64;
+        self.last_gpstime[self.next] = (high << 32) | dec.read_int() as u64;
+        self.last = self.next;
+        self.last_gpstime_diff[self.last] = 0;
+        self.multi_extreme_counter[self.last] = 0;
+    }
+
+    /// Count an extreme multiplier, adopting its difference when they keep coming
+    fn extreme(&mut self, diff: i32) {
+        let last = self.last;
+        self.multi_extreme_counter[last] += 1;
+        if self.multi_extreme_counter[last] > 3 {
+            self.last_gpstime_diff[last] = diff;
+            self.multi_extreme_counter[last] = 0;
+        }
+    }
+
+    /// Decompress the next GPS time (as its bits)
+    pub fn read(&mut self, dec: &mut ArithmeticDecoder) -> u64 {
+        let code_full = GPSTIME_MULTI_CODE_FULL - self.v3 as u32;
+        loop {
+            let last = self.last;
+            let last_diff = self.last_gpstime_diff[last];
+            if last_diff == 0 {
+                let multi = dec.decode_symbol(&mut self.m_gpstime_0diff) + self.v3 as u32;
+                if multi == 1 {
+                    // the difference fits in 32 bits
+                    let diff = self.ic_gpstime.decompress(dec, 0, 0);
+                    self.last_gpstime_diff[last] = diff;
+                    self.last_gpstime[last] = self.last_gpstime[last].wrapping_add(diff as u64);
+                    self.multi_extreme_counter[last] = 0;
+                } else if multi == 2 {
+                    self.read_full(dec);
+                } else if multi > 2 {
+                    // switch to another sequence
+                    self.last = (last + multi as usize - 2) & 3;
+                    continue;
+                }
+            } else {
+                let multi = dec.decode_symbol(&mut self.m_gpstime_multi);
+                if multi == 1 {
+                    let diff = self.ic_gpstime.decompress(dec, last_diff, 1);
+                    self.last_gpstime[last] = self.last_gpstime[last].wrapping_add(diff as u64);
+                    self.multi_extreme_counter[last] = 0;
+                } else if multi < GPSTIME_MULTI_UNCHANGED {
+                    let diff = if multi == 0 {
+                        let diff = self.ic_gpstime.decompress(dec, 0, 7);
+                        self.extreme(diff);
+                        diff
+                    } else if (multi as i32) < GPSTIME_MULTI {
+                        let context = if multi < 10 { 2 } else { 3 };
+                        let pred = (multi as i32).wrapping_mul(last_diff);
+                        self.ic_gpstime.decompress(dec, pred, context)
+                    } else if multi as i32 == GPSTIME_MULTI {
+                        let pred = GPSTIME_MULTI.wrapping_mul(last_diff);
+                        let diff = self.ic_gpstime.decompress(dec, pred, 4);
+                        self.extreme(diff);
+                        diff
+                    } else {
+                        let multi = GPSTIME_MULTI - multi as i32;
+                        if multi > GPSTIME_MULTI_MINUS {
+                            self.ic_gpstime.decompress(dec, multi.wrapping_mul(last_diff), 5)
+                        } else {
+                            let pred = GPSTIME_MULTI_MINUS.wrapping_mul(last_diff);
+                            let diff = self.ic_gpstime.decompress(dec, pred, 6);
+                            self.extreme(diff);
+                            diff
+                        }
+                    };
+                    self.last_gpstime[last] = self.last_gpstime[last].wrapping_add(diff as u64);
+                } else if multi == code_full {
+                    self.read_full(dec);
+                } else if multi > code_full {
+                    // switch to another sequence
+                    self.last = (last + (multi - code_full) as usize) & 3;
+                    continue;
+                }
+            }
+            return self.last_gpstime[self.last];
+        }
+    }
+}
+
+/// GPS time version 2 reader
+struct GpsTime11Reader {
+    models: GpsTimeModels,
+}
+impl GpsTime11Reader {
+    fn new(first: &[u8]) -> Self {
+        Self { models: GpsTimeModels::new(u64::from_le_bytes(le(first, 0)), false) }
+    }
+}
+impl ItemReader for GpsTime11Reader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        item[..8].copy_from_slice(&self.models.read(dec).to_le_bytes());
+    }
+}
+
+/// The RGB models, shared by the version 2 and 3 readers
+#[derive(Debug, Clone)]
+pub(crate) struct RgbModels {
+    m_byte_used: ArithmeticModel,
+    m_rgb_diff: [ArithmeticModel; 6],
+}
+impl Default for RgbModels {
+    fn default() -> Self {
+        Self {
+            m_byte_used: ArithmeticModel::new(128),
+            m_rgb_diff: core::array::from_fn(|_| ArithmeticModel::new(256)),
+        }
+    }
+}
+impl RgbModels {
+    /// Decompress the color following `last`. Green and blue are predicted by the change of red
+    pub fn read(&mut self, dec: &mut ArithmeticDecoder, last: &[u16; 3]) -> [u16; 3] {
+        let sym = dec.decode_symbol(&mut self.m_byte_used);
+        let mut corr = |dec: &mut ArithmeticDecoder, i: usize| {
+            dec.decode_symbol(&mut self.m_rgb_diff[i]) as i32
+        };
+        let low = |v: u16| (v & 0xff) as i32;
+        let high = |v: u16| (v >> 8) as i32;
+        let mut rgb = [0u16; 3];
+        rgb[0] =
+            if sym & 1 != 0 { u8_fold(corr(dec, 0) + low(last[0])) as u16 } else { last[0] & 0xff };
+        rgb[0] |= if sym & (1 << 1) != 0 {
+            (u8_fold(corr(dec, 1) + high(last[0])) as u16) << 8
+        } else {
+            last[0] & 0xff00
+        };
+        if sym & (1 << 6) != 0 {
+            let mut diff = low(rgb[0]) - low(last[0]);
+            rgb[1] = if sym & (1 << 2) != 0 {
+                u8_fold(corr(dec, 2) + u8_clamp(diff + low(last[1]))) as u16
+            } else {
+                last[1] & 0xff
+            };
+            rgb[2] = if sym & (1 << 4) != 0 {
+                diff = (diff + low(rgb[1]) - low(last[1])) / 2;
+                u8_fold(corr(dec, 4) + u8_clamp(diff + low(last[2]))) as u16
+            } else {
+                last[2] & 0xff
+            };
+            let mut diff = high(rgb[0]) - high(last[0]);
+            rgb[1] |= if sym & (1 << 3) != 0 {
+                (u8_fold(corr(dec, 3) + u8_clamp(diff + high(last[1]))) as u16) << 8
+            } else {
+                last[1] & 0xff00
+            };
+            rgb[2] |= if sym & (1 << 5) != 0 {
+                diff = (diff + high(rgb[1]) - high(last[1])) / 2;
+                (u8_fold(corr(dec, 5) + u8_clamp(diff + high(last[2]))) as u16) << 8
+            } else {
+                last[2] & 0xff00
+            };
+        } else {
+            rgb[1] = rgb[0];
+            rgb[2] = rgb[0];
+        }
+        rgb
+    }
+}
+
+/// Read the colors of an RGB item
+pub(crate) fn read_rgb(item: &[u8]) -> [u16; 3] {
+    [0, 2, 4].map(|o| u16::from_le_bytes(le(item, o)))
+}
+
+/// Write the colors of an RGB item
+pub(crate) fn write_rgb(rgb: &[u16; 3], item: &mut [u8]) {
+    for (i, c) in rgb.iter().enumerate() {
+        item[2 * i..2 * i + 2].copy_from_slice(&c.to_le_bytes());
+    }
+}
+
+/// RGB version 2 reader
+struct Rgb12Reader {
+    last: [u16; 3],
+    models: RgbModels,
+}
+impl Rgb12Reader {
+    fn new(first: &[u8]) -> Self {
+        Self { last: read_rgb(first), models: RgbModels::default() }
+    }
+}
+impl ItemReader for Rgb12Reader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        self.last = self.models.read(dec, &self.last);
+        write_rgb(&self.last, item);
+    }
+}
+
+/// Extra bytes version 2 reader
+struct ByteReader {
+    last: Vec<u8>,
+    m_byte: Vec<ArithmeticModel>,
+}
+impl ByteReader {
+    fn new(first: &[u8]) -> Self {
+        Self {
+            last: first.to_vec(),
+            m_byte: first.iter().map(|_| ArithmeticModel::new(256)).collect(),
+        }
+    }
+}
+impl ItemReader for ByteReader {
+    fn read(&mut self, dec: &mut ArithmeticDecoder, item: &mut [u8], _context: &mut usize) {
+        for (last, model) in self.last.iter_mut().zip(&mut self.m_byte) {
+            *last = u8_fold(*last as i32 + dec.decode_symbol(model) as i32);
+        }
+        item.copy_from_slice(&self.last);
+    }
+}
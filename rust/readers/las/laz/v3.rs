@@ -0,0 +1,536 @@
+use super::{
+    le, u8_fold,
+    v1::{lazy_model, Wavepacket, WavepacketModels},
+    v2::{read_rgb, write_rgb, GpsTimeModels, RgbModels},
+    ArithmeticDecoder, ArithmeticModel, IntegerCompressor, ItemReader, LAZItem, LAZItemType,
+    StreamingMedian5,
+};
+use crate::readers::las::LASError;
+use alloc::{boxed::Box, vec, vec::Vec};
+
+/// Maps the number of returns and return number to one of 6 contexts
+const NUMBER_RETURN_MAP_6CTX: [[u8; 16]; 16] = [
+    [0, 1, 2, 3, 4, 5, 3, 4, 4, 5, 5, 5, 5, 5, 5, 5],
+    [1, 0, 1, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3],
+    [2, 1, 2, 4, 4, 4, 4, 4, 4, 4, 4, 3, 3, 3, 3, 3],
+    [3, 3, 4, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4],
+    [4, 3, 4, 4, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4],
+    [5, 3, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4],
+    [3, 3, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4, 4, 4, 4],
+    [4, 3, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4, 4, 4],
+    [4, 3, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4, 4],
+    [5, 3, 4, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4, 4],
+    [5, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 4, 4, 4, 4, 4],
+    [5, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 4, 4, 4],
+    [5, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 4, 4],
+    [5, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5, 4],
+    [5, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5, 5],
+    [5, 3, 3, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 5],
+];
+
+/// Maps the number of returns and return number to one of 8 levels
+const NUMBER_RETURN_LEVEL_8CTX: [[u8; 16]; 16] = [
+    [0, 1, 2, 3, 4, 5, 6, 7, 7, 7, 7, 7, 7, 7, 7, 7],
+    [1, 0, 1, 2, 3, 4, 5, 6, 7, 7, 7, 7, 7, 7, 7, 7],
+    [2, 1, 0, 1, 2, 3, 4, 5, 6, 7, 7, 7, 7, 7, 7, 7],
+    [3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 7, 7, 7, 7, 7, 7],
+    [4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 7, 7, 7, 7, 7],
+    [5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 7, 7, 7, 7],
+    [6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 7, 7, 7],
+    [7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 7, 7],
+    [7, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6, 7],
+    [7, 7, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5, 6],
+    [7, 7, 7, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4, 5],
+    [7, 7, 7, 7, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3, 4],
+    [7, 7, 7, 7, 7, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2, 3],
+    [7, 7, 7, 7, 7, 7, 7, 6, 5, 4, 3, 2, 1, 0, 1, 2],
+    [7, 7, 7, 7, 7, 7, 7, 7, 6, 5, 4, 3, 2, 1, 0, 1],
+    [7, 7, 7, 7, 7, 7, 7, 7, 7, 6, 5, 4, 3, 2, 1, 0],
+];
+
+/// Create the reader of a version 3 (or 4) layered item. The point item sets the `context` for
+/// the items that follow it
+pub(crate) fn layered_reader(
+    item: &LAZItem,
+    first: &[u8],
+    layers: Vec<Vec<u8>>,
+    context: &mut usize,
+) -> Result<Box<dyn ItemReader>, LASError> {
+    let v4 = item.version == 4;
+    if item.version != 3 && !v4 {
+        return Err(LASError::UnsupportedItem(item.item_type as u16, item.version));
+    }
+    let mut layers = layers.into_iter().map(layer_decoder);
+    let mut next = || layers.next().flatten();
+    Ok(match item.item_type {
+        LAZItemType::Point14 => {
+            let first = Point14::new(first);
+            *context = first.scanner_channel as usize;
+            let xy = next().unwrap_or_default();
+            let layers = core::array::from_fn(|_| next());
+            Box::new(Point14Reader {
+                xy,
+                layers,
+                contexts: Contexts::new(*context, (first, Point14Models::new(&first)), true),
+            })
+        }
+        LAZItemType::Rgb14 => Box::new(Rgb14Reader {
+            dec: next(),
+            contexts: Contexts::new(*context, (read_rgb(first), RgbModels::default()), v4),
+        }),
+        LAZItemType::RgbNir14 => {
+            let last = (read_rgb(first), u16::from_le_bytes(le(first, 6)));
+            Box::new(RgbNir14Reader {
+                rgb: next(),
+                nir: next(),
+                contexts: Contexts::new(*context, (last, RgbNirModels::default()), v4),
+            })
+        }
+        LAZItemType::Wavepacket14 => Box::new(Wavepacket14Reader {
+            dec: next(),
+            contexts: Contexts::new(
+                *context,
+                (Wavepacket::new(first), WavepacketModels::default()),
+                v4,
+            ),
+        }),
+        LAZItemType::Byte14 => {
+            let models = byte_models(first.len());
+            Box::new(Byte14Reader {
+                decs: (0..first.len()).map(|_| next()).collect(),
+                contexts: Contexts::new(*context, (first.to_vec(), models), v4),
+            })
+        }
+        _ => return Err(LASError::UnsupportedItem(item.item_type as u16, item.version)),
+    })
+}
+
+/// Create the decoder of a layer. An empty layer never changes within the chunk
+fn layer_decoder(bytes: Vec<u8>) -> Option<ArithmeticDecoder> {
+    if bytes.is_empty() {
+        None
+    } else {
+        Some(ArithmeticDecoder::new_init(bytes))
+    }
+}
+
+/// The four scanner channel contexts of an item, each holding its last item `L` and models `M`
+struct Contexts<L, M> {
+    contexts: [Option<(L, M)>; 4],
+    current: usize,
+    /// Version 3 keeps predicting from the previous context when switching to a used one
+    v4: bool,
+}
+impl<L, M> Contexts<L, M> {
+    fn new(context: usize, first: (L, M), v4: bool) -> Self {
+        let mut contexts = [None, None, None, None];
+        contexts[context] = Some(first);
+        Self { contexts, current: context, v4 }
+    }
+
+    /// Switch to the scanner channel `context`, creating it from the last item of the current
+    /// context if unused. Returns the index of the context holding the last item
+    fn switch(&mut self, context: usize, create: impl FnOnce(&L) -> (L, M)) -> usize {
+        let previous = self.current;
+        if previous == context {
+            return context;
+        }
+        self.current = context;
+        if self.contexts[context].is_none() {
+            self.contexts[context] = Some(create(self.last(previous)));
+            context
+        } else if self.v4 {
+            context
+        } else {
+            previous
+        }
+    }
+
+    /// The last item of a context
+    fn last(&self, index: usize) -> &L {
+        &self.contexts[index].as_ref().unwrap().0
+    }
+
+    /// Store the last item of a context
+    fn set_last(&mut self, index: usize, last: L) {
+        self.contexts[index].as_mut().unwrap().0 = last;
+    }
+
+    /// The last item and models of the current context
+    fn get(&mut self) -> (&mut L, &mut M) {
+        let (last, models) = self.contexts[self.current].as_mut().unwrap();
+        (last, models)
+    }
+}
+
+/// The attributes of point formats 6 to 10
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+struct Point14 {
+    x: i32,
+    y: i32,
+    z: i32,
+    intensity: u16,
+    return_number: u8,
+    number_of_returns: u8,
+    classification_flags: u8,
+    scanner_channel: u8,
+    scan_direction_flag: u8,
+    edge_of_flight_line: u8,
+    classification: u8,
+    user_data: u8,
+    scan_angle: i16,
+    point_source_id: u16,
+    /// The bits of the GPS time
+    gps_time: u64,
+    /// Whether the GPS time changed at this point (not part of the record)
+    gps_time_change: bool,
+}
+impl Point14 {
+    fn new(item: &[u8]) -> Self {
+        Self {
+            x: i32::from_le_bytes(le(item, 0)),
+            y: i32::from_le_bytes(le(item, 4)),
+            z: i32::from_le_bytes(le(item, 8)),
+            intensity: u16::from_le_bytes(le(item, 12)),
+            return_number: item[14] & 0xf,
+            number_of_returns: item[14] >> 4,
+            classification_flags: item[15] & 0xf,
+            scanner_channel: (item[15] >> 4) & 0b11,
+            scan_direction_flag: (item[15] >> 6) & 1,
+            edge_of_flight_line: item[15] >> 7,
+            classification: item[16],
+            user_data: item[17],
+            scan_angle: i16::from_le_bytes(le(item, 18)),
+            point_source_id: u16::from_le_bytes(le(item, 20)),
+            gps_time: u64::from_le_bytes(le(item, 22)),
+            gps_time_change: false,
+        }
+    }
+
+    fn write(&self, item: &mut [u8]) {
+        item[0..4].copy_from_slice(&self.x.to_le_bytes());
+        item[4..8].copy_from_slice(&self.y.to_le_bytes());
+        item[8..12].copy_from_slice(&self.z.to_le_bytes());
+        item[12..14].copy_from_slice(&self.intensity.to_le_bytes());
+        item[14] = (self.number_of_returns << 4) | self.return_number;
+        item[15] = (self.edge_of_flight_line << 7)
+            | (self.scan_direction_flag << 6)
+            | (self.scanner_channel << 4)
+            | self.classification_flags;
+        item[16] = self.classification;
+        item[17] = self.user_data;
+        item[18..20].copy_from_slice(&self.scan_angle.to_le_bytes());
+        item[20..22].copy_from_slice(&self.point_source_id.to_le_bytes());
+        item[22..30].copy_from_slice(&self.gps_time.to_le_bytes());
+    }
+}
+
+/// The models of a scanner channel of the point reader
+struct Point14Models {
+    m_changed_values: [ArithmeticModel; 8],
+    m_scanner_channel: ArithmeticModel,
+    m_number_of_returns: Vec<Option<ArithmeticModel>>,
+    m_return_number: Vec<Option<ArithmeticModel>>,
+    m_return_number_gps_same: ArithmeticModel,
+    ic_dx: IntegerCompressor,
+    ic_dy: IntegerCompressor,
+    ic_z: IntegerCompressor,
+    m_classification: Vec<Option<ArithmeticModel>>,
+    m_flags: Vec<Option<ArithmeticModel>>,
+    m_user_data: Vec<Option<ArithmeticModel>>,
+    ic_intensity: IntegerCompressor,
+    ic_scan_angle: IntegerCompressor,
+    ic_point_source_id: IntegerCompressor,
+    gps_time: GpsTimeModels,
+    last_x_diff_median5: [StreamingMedian5; 12],
+    last_y_diff_median5: [StreamingMedian5; 12],
+    last_z: [i32; 8],
+    last_intensity: [u16; 8],
+}
+impl Point14Models {
+    fn new(last: &Point14) -> Self {
+        Self {
+            m_changed_values: core::array::from_fn(|_| ArithmeticModel::new(128)),
+            m_scanner_channel: ArithmeticModel::new(3),
+            m_number_of_returns: vec![None; 16],
+            m_return_number: vec![None; 16],
+            m_return_number_gps_same: ArithmeticModel::new(13),
+            ic_dx: IntegerCompressor::new(32, 2),
+            ic_dy: IntegerCompressor::new(32, 22),
+            ic_z: IntegerCompressor::new(32, 20),
+            m_classification: vec![None; 64],
+            m_flags: vec![None; 64],
+            m_user_data: vec![None; 64],
+            ic_intensity: IntegerCompressor::new(16, 4),
+            ic_scan_angle: IntegerCompressor::new(16, 2),
+            ic_point_source_id: IntegerCompressor::new(16, 1),
+            gps_time: GpsTimeModels::new(last.gps_time, true),
+            last_x_diff_median5: Default::default(),
+            last_y_diff_median5: Default::default(),
+            last_z: [last.z; 8],
+            last_intensity: [last.intensity; 8],
+        }
+    }
+}
+
+/// The layers of a point following the channel, returns and XY layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Point14Layer {
+    Z = 0,
+    Classification = 1,
+    Flags = 2,
+    Intensity = 3,
+    ScanAngle = 4,
+    UserData = 5,
+    PointSource = 6,
+    GpsTime = 7,
+}
+
+/// Point14 version 3 reader
+struct Point14Reader {
+    xy: ArithmeticDecoder,
+    layers: [Option<ArithmeticDecoder>; 8],
+    contexts: Contexts<Point14, Point14Models>,
+}
+impl ItemReader for Point14Reader {
+    fn read(&mut self, _dec: &mut ArithmeticDecoder, item: &mut [u8], context: &mut usize) {
+        let dec = &mut self.xy;
+        let current = self.contexts.current;
+        let (last, models) = self.contexts.get();
+        // single, first, last or intermediate return of the last point
+        let mut lpr = (last.return_number == 1) as usize;
+        lpr += 2 * (last.return_number >= last.number_of_returns) as usize;
+        lpr += 4 * last.gps_time_change as usize;
+        let changed_values = dec.decode_symbol(&mut models.m_changed_values[lpr]);
+        if changed_values & (1 << 6) != 0 {
+            let diff = dec.decode_symbol(&mut models.m_scanner_channel) as usize;
+            let scanner_channel = (current + diff + 1) % 4;
+            self.contexts.switch(scanner_channel, |last| {
+                let last = Point14 { gps_time_change: false, ..*last };
+                (last, Point14Models::new(&last))
+            });
+            *context = scanner_channel;
+        }
+        let channel = self.contexts.current as u8;
+        let (last, models) = self.contexts.get();
+        last.scanner_channel = channel;
+
+        let point_source_change = changed_values & (1 << 5) != 0;
+        let gps_time_change = changed_values & (1 << 4) != 0;
+        let scan_angle_change = changed_values & (1 << 3) != 0;
+
+        let (last_n, last_r) = (last.number_of_returns as usize, last.return_number as usize);
+        let n = if changed_values & (1 << 2) != 0 {
+            let model = lazy_model(&mut models.m_number_of_returns, last_n, 16);
+            dec.decode_symbol(model) as usize
+        } else {
+            last_n
+        };
+        let r = match changed_values & 3 {
+            0 => last_r,
+            1 => (last_r + 1) % 16,
+            2 => (last_r + 15) % 16,
+            _ if gps_time_change => {
+                let model = lazy_model(&mut models.m_return_number, last_r, 16);
+                dec.decode_symbol(model) as usize
+            }
+            _ => {
+                let sym = dec.decode_symbol(&mut models.m_return_number_gps_same) as usize;
+                (last_r + sym + 2) % 16
+            }
+        };
+        last.number_of_returns = n as u8;
+        last.return_number = r as u8;
+
+        let m = NUMBER_RETURN_MAP_6CTX[n][r] as usize;
+        let l = NUMBER_RETURN_LEVEL_8CTX[n][r] as usize;
+        // single, first, last or intermediate return of this point
+        let cpr = 2 * (r == 1) as usize + (r >= n) as usize;
+        let single = (n == 1) as usize;
+        let index = (m << 1) | gps_time_change as usize;
+
+        let median = models.last_x_diff_median5[index].get();
+        let diff = models.ic_dx.decompress(dec, median, single);
+        last.x = last.x.wrapping_add(diff);
+        models.last_x_diff_median5[index].add(diff);
+
+        let median = models.last_y_diff_median5[index].get();
+        let k_bits = models.ic_dx.k();
+        let context_y = single + if k_bits < 20 { (k_bits & !1) as usize } else { 20 };
+        let diff = models.ic_dy.decompress(dec, median, context_y);
+        last.y = last.y.wrapping_add(diff);
+        models.last_y_diff_median5[index].add(diff);
+
+        let layers = &mut self.layers;
+        if let Some(dec) = &mut layers[Point14Layer::Z as usize] {
+            let k_bits = (models.ic_dx.k() + models.ic_dy.k()) / 2;
+            let context_z = single + if k_bits < 18 { (k_bits & !1) as usize } else { 18 };
+            last.z = models.ic_z.decompress(dec, models.last_z[l], context_z);
+            models.last_z[l] = last.z;
+        }
+        if let Some(dec) = &mut layers[Point14Layer::Classification as usize] {
+            let ccc = (((last.classification & 0x1f) as usize) << 1) + (cpr == 3) as usize;
+            let model = lazy_model(&mut models.m_classification, ccc, 256);
+            last.classification = dec.decode_symbol(model) as u8;
+        }
+        if let Some(dec) = &mut layers[Point14Layer::Flags as usize] {
+            let last_flags = (last.edge_of_flight_line << 5)
+                | (last.scan_direction_flag << 4)
+                | last.classification_flags;
+            let model = lazy_model(&mut models.m_flags, last_flags as usize, 64);
+            let flags = dec.decode_symbol(model) as u8;
+            last.edge_of_flight_line = (flags >> 5) & 1;
+            last.scan_direction_flag = (flags >> 4) & 1;
+            last.classification_flags = flags & 0x0f;
+        }
+        if let Some(dec) = &mut layers[Point14Layer::Intensity as usize] {
+            let index = (cpr << 1) | gps_time_change as usize;
+            let pred = models.last_intensity[index] as i32;
+            last.intensity = models.ic_intensity.decompress(dec, pred, cpr) as u16;
+            models.last_intensity[index] = last.intensity;
+        }
+        if let Some(dec) = &mut layers[Point14Layer::ScanAngle as usize] {
+            if scan_angle_change {
+                let pred = last.scan_angle as i32;
+                let context = gps_time_change as usize;
+                last.scan_angle = models.ic_scan_angle.decompress(dec, pred, context) as i16;
+            }
+        }
+        if let Some(dec) = &mut layers[Point14Layer::UserData as usize] {
+            let model = lazy_model(&mut models.m_user_data, last.user_data as usize / 4, 256);
+            last.user_data = dec.decode_symbol(model) as u8;
+        }
+        if let Some(dec) = &mut layers[Point14Layer::PointSource as usize] {
+            if point_source_change {
+                let pred = last.point_source_id as i32;
+                last.point_source_id = models.ic_point_source_id.decompress(dec, pred, 0) as u16;
+            }
+        }
+        if let Some(dec) = &mut layers[Point14Layer::GpsTime as usize] {
+            if gps_time_change {
+                last.gps_time = models.gps_time.read(dec);
+            }
+        }
+
+        last.write(item);
+        last.gps_time_change = gps_time_change;
+    }
+}
+
+/// The RGB and NIR models of a scanner channel
+#[derive(Debug, Clone)]
+struct RgbNirModels {
+    rgb: RgbModels,
+    m_nir_bytes_used: ArithmeticModel,
+    m_nir_diff: [ArithmeticModel; 2],
+}
+impl Default for RgbNirModels {
+    fn default() -> Self {
+        Self {
+            rgb: RgbModels::default(),
+            m_nir_bytes_used: ArithmeticModel::new(4),
+            m_nir_diff: [ArithmeticModel::new(256), ArithmeticModel::new(256)],
+        }
+    }
+}
+impl RgbNirModels {
+    /// Decompress the near infrared following `last`
+    fn read_nir(&mut self, dec: &mut ArithmeticDecoder, last: u16) -> u16 {
+        let sym = dec.decode_symbol(&mut self.m_nir_bytes_used);
+        let low = if sym & 1 != 0 {
+            let corr = dec.decode_symbol(&mut self.m_nir_diff[0]) as i32;
+            u8_fold(corr + (last & 0xff) as i32) as u16
+        } else {
+            last & 0xff
+        };
+        let high = if sym & (1 << 1) != 0 {
+            let corr = dec.decode_symbol(&mut self.m_nir_diff[1]) as i32;
+            (u8_fold(corr + (last >> 8) as i32) as u16) << 8
+        } else {
+            last & 0xff00
+        };
+        low | high
+    }
+}
+
+/// RGB version 3 reader
+struct Rgb14Reader {
+    dec: Option<ArithmeticDecoder>,
+    contexts: Contexts<[u16; 3], RgbModels>,
+}
+impl ItemReader for Rgb14Reader {
+    fn read(&mut self, _dec: &mut ArithmeticDecoder, item: &mut [u8], context: &mut usize) {
+        let index = self.contexts.switch(*context, |last| (*last, RgbModels::default()));
+        let mut rgb = *self.contexts.last(index);
+        if let Some(dec) = &mut self.dec {
+            rgb = self.contexts.get().1.read(dec, &rgb);
+            self.contexts.set_last(index, rgb);
+        }
+        write_rgb(&rgb, item);
+    }
+}
+
+/// RGB and NIR version 3 reader
+struct RgbNir14Reader {
+    rgb: Option<ArithmeticDecoder>,
+    nir: Option<ArithmeticDecoder>,
+    contexts: Contexts<([u16; 3], u16), RgbNirModels>,
+}
+impl ItemReader for RgbNir14Reader {
+    fn read(&mut self, _dec: &mut ArithmeticDecoder, item: &mut [u8], context: &mut usize) {
+        let index = self.contexts.switch(*context, |last| (*last, RgbNirModels::default()));
+        let (mut rgb, mut nir) = *self.contexts.last(index);
+        let models = self.contexts.get().1;
+        if let Some(dec) = &mut self.rgb {
+            rgb = models.rgb.read(dec, &rgb);
+        }
+        if let Some(dec) = &mut self.nir {
+            nir = models.read_nir(dec, nir);
+        }
+        self.contexts.set_last(index, (rgb, nir));
+        write_rgb(&rgb, item);
+        item[6..8].copy_from_slice(&nir.to_le_bytes());
+    }
+}
+
+/// Wave packet version 3 reader
+struct Wavepacket14Reader {
+    dec: Option<ArithmeticDecoder>,
+    contexts: Contexts<Wavepacket, WavepacketModels>,
+}
+impl ItemReader for Wavepacket14Reader {
+    fn read(&mut self, _dec: &mut ArithmeticDecoder, item: &mut [u8], context: &mut usize) {
+        let index = self.contexts.switch(*context, |last| (*last, WavepacketModels::default()));
+        let mut wavepacket = *self.contexts.last(index);
+        if let Some(dec) = &mut self.dec {
+            wavepacket = self.contexts.get().1.read(dec, &wavepacket);
+            self.contexts.set_last(index, wavepacket);
+        }
+        wavepacket.write(item);
+    }
+}
+
+/// The models of `n` extra bytes
+fn byte_models(n: usize) -> Vec<ArithmeticModel> {
+    (0..n).map(|_| ArithmeticModel::new(256)).collect()
+}
+
+/// Extra bytes version 3 reader, each byte in its own layer
+struct Byte14Reader {
+    decs: Vec<Option<ArithmeticDecoder>>,
+    contexts: Contexts<Vec<u8>, Vec<ArithmeticModel>>,
+}
+impl ItemReader for Byte14Reader {
+    fn read(&mut self, _dec: &mut ArithmeticDecoder, item: &mut [u8], context: &mut usize) {
+        let index = self.contexts.switch(*context, |last| (last.clone(), byte_models(last.len())));
+        let mut bytes = self.contexts.last(index).clone();
+        let models = self.contexts.get().1;
+        for ((byte, dec), model) in bytes.iter_mut().zip(&mut self.decs).zip(models) {
+            if let Some(dec) = dec {
+                *byte = u8_fold(*byte as i32 + dec.decode_symbol(model) as i32);
+            }
+        }
+        item.copy_from_slice(&bytes);
+        self.contexts.set_last(index, bytes);
+    }
+}
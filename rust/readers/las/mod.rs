@@ -0,0 +1,709 @@
+/// LAZ decompression
+pub mod laz;
+/// Point record parsing
+pub mod point;
+
+pub use laz::*;
+pub use point::*;
+
+use crate::{
+    geometry::{VectorFeature, VectorGeometry, VectorGeometryType, VectorPointGeometry},
+    readers::{
+        geotiff::{
+            header::parse_geo_key_directory, GeoKeyDirectory, TAG_GEO_ASCII_PARAMS,
+            TAG_GEO_DOUBLE_PARAMS, TAG_GEO_KEY_DIRECTORY,
+        },
+        FeatureIterator, Reader,
+    },
+};
+use alloc::{
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+
+/// The record ID of the OGC coordinate system WKT VLR
+pub const LAS_WKT_RECORD_ID: u16 = 2112;
+
+/// Errors that can occur while reading a LAS or LAZ file
+#[derive(Debug, PartialEq)]
+pub enum LASError {
+    /// The file doesn't start with "LASF"
+    InvalidSignature,
+    /// The header or a variable length record points outside the file
+    InvalidHeader,
+    /// Only the point data formats 0 to 10 exist
+    UnsupportedPointFormat(u8),
+    /// The points are compressed but the LASzip VLR is missing
+    MissingLAZRecord,
+    /// The LASzip VLR is malformed or doesn't match the point format
+    InvalidLAZRecord,
+    /// The compressor of the LASzip VLR is unknown
+    UnsupportedCompressor(u16),
+    /// Only the arithmetic coder (0) is supported
+    UnsupportedCoder(u16),
+    /// The item type and version of the LASzip VLR isn't supported
+    UnsupportedItem(u16, u16),
+    /// The chunk table is malformed
+    InvalidChunkTable,
+    /// A chunk is truncated
+    InvalidChunk,
+}
+
+/// The public header block of a LAS file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LASHeader {
+    /// File signature, always "LASF"
+    pub signature: String,
+    /// File source ID, e.g. the flight line
+    pub source_id: u16,
+    /// Global encoding bits
+    pub encoding: u16,
+    /// Project ID GUID data 1
+    pub project_id_1: u32,
+    /// Project ID GUID data 2
+    pub project_id_2: u16,
+    /// Project ID GUID data 3
+    pub project_id_3: u16,
+    /// Project ID GUID data 4
+    pub project_id_4: [u8; 8],
+    /// Major version of the LAS specification
+    pub major_version: u8,
+    /// Minor version of the LAS specification
+    pub minor_version: u8,
+    /// The hardware or process that generated the data
+    pub system_identifier: String,
+    /// The software that generated the data
+    pub generating_software: String,
+    /// Day of the year the file was created
+    pub file_creation_day: u16,
+    /// Year the file was created
+    pub file_creation_year: u16,
+    /// Size of the public header block
+    pub header_size: u16,
+    /// Offset to the first point record
+    pub offset_to_points: u32,
+    /// Number of variable length records
+    pub num_variable_length_records: u32,
+    /// The point data format (0 to 10)
+    pub point_data_format: u8,
+    /// True if the points are LAZ compressed
+    pub is_compressed: bool,
+    /// The size of a point record in bytes
+    pub point_data_record_length: u16,
+    /// The number of point records
+    pub num_points: u64,
+    /// The number of points by return number
+    pub num_points_by_return: Vec<u64>,
+    /// X scale factor
+    pub x_scale_factor: f64,
+    /// Y scale factor
+    pub y_scale_factor: f64,
+    /// Z scale factor
+    pub z_scale_factor: f64,
+    /// X offset
+    pub x_offset: f64,
+    /// Y offset
+    pub y_offset: f64,
+    /// Z offset
+    pub z_offset: f64,
+    /// Largest X
+    pub max_x: f64,
+    /// Smallest X
+    pub min_x: f64,
+    /// Largest Y
+    pub max_y: f64,
+    /// Smallest Y
+    pub min_y: f64,
+    /// Largest Z
+    pub max_z: f64,
+    /// Smallest Z
+    pub min_z: f64,
+    /// Offset to the waveform data packet record (1.3+)
+    pub waveform_data_packet_offset: u64,
+    /// Offset to the first extended variable length record (1.4)
+    pub extended_variable_length_record_offset: u64,
+    /// Number of extended variable length records (1.4)
+    pub num_extended_variable_length_records: u32,
+}
+impl LASHeader {
+    /// Parse the public header block
+    pub fn new<T: Reader>(reader: &mut T) -> Result<Self, LASError> {
+        if reader.len() < 227 || reader.slice(Some(0), Some(4)) != b"LASF" {
+            return Err(LASError::InvalidSignature);
+        }
+        let mut text = |begin: usize, end: usize| {
+            let bytes = reader.slice(Some(begin), Some(end));
+            String::from_utf8_lossy(&bytes).trim_end_matches('\0').trim().to_string()
+        };
+        let system_identifier = text(26, 58);
+        let generating_software = text(58, 90);
+        let mut project_id_4 = [0; 8];
+        project_id_4.copy_from_slice(&reader.slice(Some(16), Some(24)));
+        let point_data_format_id = reader.uint8(Some(104));
+        let mut header = LASHeader {
+            signature: "LASF".into(),
+            source_id: reader.uint16_le(Some(4)),
+            encoding: reader.uint16_le(Some(6)),
+            project_id_1: reader.uint32_le(Some(8)),
+            project_id_2: reader.uint16_le(Some(12)),
+            project_id_3: reader.uint16_le(Some(14)),
+            project_id_4,
+            major_version: reader.uint8(Some(24)),
+            minor_version: reader.uint8(Some(25)),
+            system_identifier,
+            generating_software,
+            file_creation_day: reader.uint16_le(Some(90)),
+            file_creation_year: reader.uint16_le(Some(92)),
+            header_size: reader.uint16_le(Some(94)),
+            offset_to_points: reader.uint32_le(Some(96)),
+            num_variable_length_records: reader.uint32_le(Some(100)),
+            // the two high bits flag compressed points
+            point_data_format: point_data_format_id & 0x3f,
+            is_compressed: point_data_format_id & 0xc0 != 0,
+            point_data_record_length: reader.uint16_le(Some(105)),
+            num_points: reader.uint32_le(Some(107)) as u64,
+            num_points_by_return: (0..5)
+                .map(|i| reader.uint32_le(Some(111 + i * 4)) as u64)
+                .collect(),
+            x_scale_factor: reader.f64_le(Some(131)),
+            y_scale_factor: reader.f64_le(Some(139)),
+            z_scale_factor: reader.f64_le(Some(147)),
+            x_offset: reader.f64_le(Some(155)),
+            y_offset: reader.f64_le(Some(163)),
+            z_offset: reader.f64_le(Some(171)),
+            max_x: reader.f64_le(Some(179)),
+            min_x: reader.f64_le(Some(187)),
+            max_y: reader.f64_le(Some(195)),
+            min_y: reader.f64_le(Some(203)),
+            max_z: reader.f64_le(Some(211)),
+            min_z: reader.f64_le(Some(219)),
+            ..Default::default()
+        };
+        let header_size = header.header_size as usize;
+        if header_size > reader.len() || header.offset_to_points as usize > reader.len() {
+            return Err(LASError::InvalidHeader);
+        }
+        if header_size >= 235 {
+            header.waveform_data_packet_offset = reader.uint64_le(Some(227));
+        }
+        if header_size >= 375 {
+            header.extended_variable_length_record_offset = reader.uint64_le(Some(235));
+            header.num_extended_variable_length_records = reader.uint32_le(Some(243));
+            // the 64-bit point counts replace the legacy ones
+            header.num_points = reader.uint64_le(Some(247));
+            header.num_points_by_return =
+                (0..15).map(|i| reader.uint64_le(Some(255 + i * 8))).collect();
+        }
+        if header.point_data_format > 10 {
+            return Err(LASError::UnsupportedPointFormat(header.point_data_format));
+        }
+        let record_length = header.point_data_record_length as usize;
+        if record_length < LAS_POINT_SIZES[header.point_data_format as usize] {
+            return Err(LASError::InvalidHeader);
+        }
+
+        Ok(header)
+    }
+}
+
+/// A variable length record, or an extended variable length record of LAS 1.4
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LASVariableLengthRecord {
+    /// Reserved
+    pub reserved: u16,
+    /// The user ID, e.g. "LASF_Projection"
+    pub user_id: String,
+    /// The record ID
+    pub record_id: u16,
+    /// The length of the data after the header
+    pub record_length: u64,
+    /// Description of the record
+    pub description: String,
+    /// The data of the record
+    pub data: Vec<u8>,
+    /// True if the record is an extended variable length record
+    pub extended: bool,
+}
+
+/// # LAS Reader
+///
+/// ## Description
+/// Reads LAS 1.0 to 1.4 point clouds (point data formats 0 to 10) through the [`Reader`] trait,
+/// including LAZ compressed point clouds. The header and (extended) variable length records are
+/// parsed up front and the points are read in order.
+///
+/// Implements the [`FeatureIterator`]: each point record is a 3D Point feature with the header's
+/// scale and offset applied. The attributes of the record (intensity, return numbers,
+/// classification, GPS time, RGB, etc.) are stored in the point's M-value with the same camelCase
+/// keys as the TypeScript reader.
+///
+/// The coordinate reference system is available through [`LASReader::wkt`] or
+/// [`LASReader::geo_key_directory`].
+///
+/// NOTE: The points are not reprojected.
+///
+/// NOTE: LAZ decompression supports the arithmetic coded items of LASzip 1 through 4, pointwise
+/// and chunked.
+///
+/// If a compressed chunk is malformed, iteration stops and the error is stored in `error`.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, FeatureIterator, LASReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/las/fixtures/1.2-with-color.laz");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+/// assert_eq!(reader.len(), 1065);
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://www.asprs.org/wp-content/uploads/2019/07/LAS_1_4_r15.pdf>
+/// - <https://downloads.rapidlasso.de/doc/LAZ_Specification_1.4_R1.pdf>
+/// - <https://github.com/LASzip/LASzip>
+#[derive(Debug)]
+pub struct LASReader<T: Reader> {
+    reader: T,
+    /// The public header block
+    pub header: LASHeader,
+    /// The variable length records followed by the extended variable length records
+    pub variable_length_records: Vec<LASVariableLengthRecord>,
+    /// The LASzip VLR if the points are compressed
+    pub laz: Option<LAZHeader>,
+    /// The error that stopped iteration if a compressed chunk is malformed
+    pub error: Option<LASError>,
+    chunks: Vec<LAZChunk>,
+    index: u64,
+    chunk: usize,
+    decoder: Option<LAZChunkDecoder>,
+    remaining: u64,
+    record: Vec<u8>,
+}
+impl<T: Reader> LASReader<T> {
+    /// Create a new LAS reader, parsing the header and variable length records
+    pub fn new(mut reader: T) -> Result<Self, LASError> {
+        let header = LASHeader::new(&mut reader)?;
+        let mut variable_length_records = vec![];
+        let mut position = header.header_size as usize;
+        for _ in 0..header.num_variable_length_records {
+            let record = parse_record(&mut reader, position, false)?;
+            position += 54 + record.data.len();
+            variable_length_records.push(record);
+        }
+        let mut position = header.extended_variable_length_record_offset as usize;
+        if position != 0 {
+            for _ in 0..header.num_extended_variable_length_records {
+                let record = parse_record(&mut reader, position, true)?;
+                position += 60 + record.data.len();
+                variable_length_records.push(record);
+            }
+        }
+
+        let mut las = LASReader {
+            reader,
+            header,
+            variable_length_records,
+            laz: None,
+            error: None,
+            chunks: vec![],
+            index: 0,
+            chunk: 0,
+            decoder: None,
+            remaining: 0,
+            record: vec![],
+        };
+        las.record = vec![0; las.header.point_data_record_length as usize];
+        if las.header.is_compressed {
+            las.init_laz()?;
+        }
+
+        Ok(las)
+    }
+
+    /// Parse the LASzip VLR and the chunk table
+    fn init_laz(&mut self) -> Result<(), LASError> {
+        let record = self.get_vlr(LAZ_RECORD_ID).ok_or(LASError::MissingLAZRecord)?;
+        let laz = LAZHeader::new(&record.data)?;
+        if laz.coder != 0 {
+            return Err(LASError::UnsupportedCoder(laz.coder));
+        }
+        if laz.point_size() != self.record.len() {
+            return Err(LASError::InvalidLAZRecord);
+        }
+        let len = self.reader.len() as u64;
+        let offset_to_points = self.header.offset_to_points as u64;
+        self.chunks = match laz.compressor {
+            LAZCompressor::None => return Err(LASError::UnsupportedCompressor(0)),
+            LAZCompressor::Pointwise => vec![LAZChunk {
+                offset: offset_to_points,
+                size: len - offset_to_points,
+                points: self.header.num_points,
+            }],
+            LAZCompressor::PointwiseChunked | LAZCompressor::LayeredChunked => {
+                if offset_to_points + 8 > len {
+                    return Err(LASError::InvalidChunkTable);
+                }
+                let mut table_offset = self.reader.int64_le(Some(offset_to_points as usize));
+                // the table offset is stored at the end of the file when written last
+                if table_offset == -1 {
+                    table_offset = self.reader.int64_le(Some(len as usize - 8));
+                }
+                if table_offset < 0 || table_offset as u64 >= len {
+                    return Err(LASError::InvalidChunkTable);
+                }
+                let table = self.reader.slice(Some(table_offset as usize), None);
+                let chunks_start = offset_to_points + 8;
+                let chunks = parse_chunk_table(
+                    &table,
+                    laz.chunk_size,
+                    chunks_start,
+                    self.header.num_points,
+                )?;
+                if chunks.iter().any(|c| c.offset + c.size > len) {
+                    return Err(LASError::InvalidChunkTable);
+                }
+                chunks
+            }
+        };
+        self.laz = Some(laz);
+
+        Ok(())
+    }
+
+    /// The number of points
+    pub fn len(&self) -> u64 {
+        self.header.num_points
+    }
+
+    /// Returns true if there are no points
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the first (extended) variable length record with the record ID
+    pub fn get_vlr(&self, record_id: u16) -> Option<&LASVariableLengthRecord> {
+        self.variable_length_records.iter().find(|r| r.record_id == record_id)
+    }
+
+    /// The OGC coordinate system WKT if stored
+    pub fn wkt(&self) -> Option<String> {
+        let record = self.get_vlr(LAS_WKT_RECORD_ID)?;
+        let wkt = String::from_utf8_lossy(&record.data);
+        Some(wkt.trim_end_matches('\0').into())
+    }
+
+    /// The GeoTIFF keys describing the coordinate reference system if stored
+    pub fn geo_key_directory(&self) -> Option<GeoKeyDirectory> {
+        let raw: Vec<f64> = self
+            .get_vlr(TAG_GEO_KEY_DIRECTORY)?
+            .data
+            .as_chunks::<2>()
+            .0
+            .iter()
+            .map(|b| u16::from_le_bytes(*b) as f64)
+            .collect();
+        let doubles: Option<Vec<f64>> = self
+            .get_vlr(TAG_GEO_DOUBLE_PARAMS)
+            .map(|r| r.data.as_chunks::<8>().0.iter().map(|b| f64::from_le_bytes(*b)).collect());
+        let ascii = self.get_vlr(TAG_GEO_ASCII_PARAMS).map(|r| String::from_utf8_lossy(&r.data));
+        parse_geo_key_directory(&raw, doubles.as_deref(), ascii.as_deref(), |_, _| None)
+    }
+
+    /// Read the next point record into the record buffer
+    fn next_record(&mut self) -> Option<()> {
+        let Some(laz) = &self.laz else {
+            let length = self.record.len();
+            let offset = self.header.offset_to_points as usize + self.index as usize * length;
+            if offset + length > self.reader.len() {
+                return None;
+            }
+            self.record = self.reader.slice(Some(offset), Some(offset + length));
+            return Some(());
+        };
+        if self.remaining == 0 {
+            let chunk = self.chunks.get(self.chunk)?;
+            let data = self
+                .reader
+                .slice(Some(chunk.offset as usize), Some((chunk.offset + chunk.size) as usize));
+            let layered = laz.compressor == LAZCompressor::LayeredChunked;
+            match LAZChunkDecoder::new(&laz.items, layered, data) {
+                Ok(decoder) => self.decoder = Some(decoder),
+                Err(err) => {
+                    self.error = Some(err);
+                    return None;
+                }
+            }
+            self.remaining = chunk.points;
+            self.chunk += 1;
+            if self.remaining == 0 {
+                return self.next_record();
+            }
+        }
+        self.decoder.as_mut()?.next_point(&mut self.record);
+        self.remaining -= 1;
+        Some(())
+    }
+}
+
+/// Parse a variable length record (54 byte header) or an extended one (60 byte header)
+fn parse_record<T: Reader>(
+    reader: &mut T,
+    position: usize,
+    extended: bool,
+) -> Result<LASVariableLengthRecord, LASError> {
+    let header_size = if extended { 60 } else { 54 };
+    if position + header_size > reader.len() {
+        return Err(LASError::InvalidHeader);
+    }
+    let record_length = if extended {
+        reader.uint64_le(Some(position + 20))
+    } else {
+        reader.uint16_le(Some(position + 20)) as u64
+    };
+    let start = position + header_size;
+    let end = start + record_length as usize;
+    if end > reader.len() {
+        return Err(LASError::InvalidHeader);
+    }
+    let mut text = |begin: usize, end: usize| {
+        let bytes = reader.slice(Some(position + begin), Some(position + end));
+        String::from_utf8_lossy(&bytes).trim_end_matches('\0').to_string()
+    };
+    let user_id = text(2, 18);
+    let description = text(header_size - 32, header_size);
+    Ok(LASVariableLengthRecord {
+        reserved: reader.uint16_le(Some(position)),
+        user_id,
+        record_id: reader.uint16_le(Some(position + 18)),
+        record_length,
+        description,
+        data: reader.slice(Some(start), Some(end)),
+        extended,
+    })
+}
+
+impl<T: Reader> Iterator for LASReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.error.is_some() || self.index >= self.header.num_points {
+            return None;
+        }
+        self.next_record()?;
+        self.index += 1;
+        let geometry = VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            is_3d: true,
+            coordinates: parse_point(&self.record, &self.header),
+            ..Default::default()
+        });
+        Some(VectorFeature::new_wm(None, Default::default(), geometry, None))
+    }
+}
+impl<T: Reader> FeatureIterator for LASReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        geometry::VectorPoint,
+        readers::{BufferReader, PROJECTED_CS_TYPE_GEO_KEY},
+    };
+    use s2json::{MValue, PrimitiveValue, ValueType};
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/las/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    fn points(name: &str) -> Vec<VectorPoint> {
+        let data = fixture(name);
+        let reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+        reader
+            .map(|feature| match feature.geometry {
+                VectorGeometry::Point(point) => point.coordinates,
+                _ => panic!("expected a point"),
+            })
+            .collect()
+    }
+
+    fn m_value<'a>(point: &'a VectorPoint, key: &str) -> &'a PrimitiveValue {
+        match point.m.as_ref().unwrap().get(key) {
+            Some(ValueType::Primitive(value)) => value,
+            _ => panic!("missing {key}"),
+        }
+    }
+
+    fn rgb(point: &VectorPoint) -> MValue {
+        match point.m.as_ref().unwrap().get("rgba") {
+            Some(ValueType::Nested(rgba)) => rgba.clone(),
+            _ => panic!("missing rgba"),
+        }
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let data = fixture("simple.jsonld");
+        assert_eq!(
+            LASReader::new(BufferReader::from(&data[..])).err(),
+            Some(LASError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn test_point_formats() {
+        for format in 0..=10 {
+            let data = fixture(&alloc::format!("1.2_{format}.las"));
+            let reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+            assert_eq!(reader.header.point_data_format, format);
+            assert!(!reader.header.is_compressed);
+            assert_eq!(reader.len(), 1);
+
+            let points = points(&alloc::format!("1.2_{format}.las"));
+            assert_eq!(points.len(), 1);
+            let point = &points[0];
+            assert!((point.x - 470692.44).abs() < 1e-6);
+            assert!((point.y - 4602888.90).abs() < 1e-6);
+            assert_eq!(point.z, Some(16.));
+            assert_eq!(m_value(point, "classification"), &PrimitiveValue::String("Ground".into()));
+            assert_eq!(m_value(point, "returnNumber"), &PrimitiveValue::U64(2));
+        }
+
+        let point = &points("1.2_10.las")[0];
+        assert_eq!(m_value(point, "scanAngle"), &PrimitiveValue::I64(-2167));
+        assert_eq!(m_value(point, "gpsTime"), &PrimitiveValue::F64(1205902800.));
+        let rgba = rgb(point);
+        assert_eq!(rgba.get("r"), Some(&ValueType::Primitive(PrimitiveValue::U64(255))));
+        assert_eq!(rgba.get("g"), Some(&ValueType::Primitive(PrimitiveValue::U64(12))));
+        assert_eq!(rgba.get("b"), Some(&ValueType::Primitive(PrimitiveValue::U64(234))));
+    }
+
+    #[test]
+    fn test_laz_header_and_wkt() {
+        let data = fixture("1.2_0.laz");
+        let reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+        assert!(reader.header.is_compressed);
+        assert_eq!(reader.header.point_data_format, 7);
+        let laz = reader.laz.as_ref().unwrap();
+        assert_eq!(laz.compressor, LAZCompressor::LayeredChunked);
+        assert_eq!(laz.chunk_size, 50_000);
+        assert_eq!(
+            laz.items,
+            vec![
+                LAZItem { item_type: LAZItemType::Point14, size: 30, version: 3 },
+                LAZItem { item_type: LAZItemType::Rgb14, size: 6, version: 3 }
+            ]
+        );
+        assert!(reader.wkt().unwrap().starts_with("PROJCS[\"NAD83 / UTM zone 15N\""));
+
+        let points = points("1.2_0.laz");
+        assert_eq!(points.len(), 1);
+        assert_eq!(points[0].z, Some(16.));
+        assert_eq!(m_value(&points[0], "returnNumber"), &PrimitiveValue::U64(2));
+        assert_eq!(m_value(&points[0], "scanAngle"), &PrimitiveValue::I64(-2167));
+    }
+
+    #[test]
+    fn test_geo_keys() {
+        let data = fixture("1.2_0.las");
+        let reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+        let keys = reader.geo_key_directory().unwrap();
+        assert_eq!(keys.get_short(PROJECTED_CS_TYPE_GEO_KEY), Some(26915));
+    }
+
+    #[test]
+    fn test_las_14() {
+        let data = fixture("5points_14.las");
+        let reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.header.minor_version, 4);
+        assert_eq!(reader.len(), 5);
+        assert_eq!(points("5points_14.las").len(), 5);
+    }
+
+    #[test]
+    fn test_laz_matches_las() {
+        let las = points("1.2-with-color.las");
+        assert_eq!(las.len(), 1065);
+        assert_eq!(las, points("1.2-with-color.laz"));
+    }
+
+    #[test]
+    fn test_laz_versions() {
+        let compare: Vec<serde_json::Value> = fixture("simple.jsonld")
+            .split(|b| *b == b'\n')
+            .filter(|line| !line.is_empty())
+            .map(|line| serde_json::from_slice(line).unwrap())
+            .collect();
+        assert_eq!(compare.len(), 1065);
+        for name in ["simple.laz", "simpleV3.laz"] {
+            let points = points(name);
+            assert_eq!(points.len(), 1065);
+            for (point, compare) in points.iter().zip(&compare) {
+                let coordinates = &compare["geometry"]["coordinates"];
+                assert!((point.x - coordinates["x"].as_f64().unwrap()).abs() < 1e-6);
+                assert!((point.y - coordinates["y"].as_f64().unwrap()).abs() < 1e-6);
+                assert!((point.z.unwrap() - coordinates["z"].as_f64().unwrap()).abs() < 1e-6);
+                let m = &coordinates["m"];
+                let intensity = m["intensity"].as_u64().unwrap();
+                assert_eq!(m_value(point, "intensity"), &PrimitiveValue::U64(intensity));
+                let PrimitiveValue::F64(gps_time) = m_value(point, "gpsTime") else { panic!() };
+                assert!((gps_time - m["gps_time"].as_f64().unwrap()).abs() < 1e-4);
+                let rgba = rgb(point);
+                for key in ["r", "g", "b"] {
+                    let value = PrimitiveValue::U64(m["rgba"][key].as_u64().unwrap());
+                    assert_eq!(rgba.get(key), Some(&ValueType::Primitive(value)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_laz_chunked_and_layered() {
+        let data = fixture("autzen_trim.laz");
+        let reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.laz.as_ref().unwrap().compressor, LAZCompressor::PointwiseChunked);
+        assert!(reader.geo_key_directory().is_some());
+        let data = fixture("autzen_trim_v3.laz");
+        let reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.laz.as_ref().unwrap().compressor, LAZCompressor::LayeredChunked);
+
+        let v2 = points("autzen_trim.laz");
+        assert_eq!(v2.len(), 110_000);
+        let v3 = points("autzen_trim_v3.laz");
+        assert_eq!(v3.len(), 110_000);
+        for (a, b) in v2.iter().zip(&v3) {
+            assert_eq!((a.x, a.y, a.z), (b.x, b.y, b.z));
+            for key in ["intensity", "returnNumber", "numberOfReturns", "gpsTime", "pointSourceID"]
+            {
+                assert_eq!(m_value(a, key), m_value(b, key));
+            }
+            assert_eq!(rgb(a), rgb(b));
+        }
+    }
+
+    #[test]
+    fn test_laz_truncated_chunk() {
+        let data = fixture("autzen_trim.laz");
+        let mut reader = LASReader::new(BufferReader::from(&data[..])).unwrap();
+        assert_eq!(reader.next().map(|_| ()), Some(()));
+        assert_eq!(reader.error, None);
+        // the second chunk is too short to hold even its first point
+        reader.chunks[1].size = 4;
+        let count = reader.by_ref().count();
+        assert!(count < 110_000);
+        assert_eq!(reader.error, Some(LASError::InvalidChunk));
+        assert_eq!(reader.next(), None);
+    }
+}
@@ -0,0 +1,180 @@
+use super::LASHeader;
+use crate::geometry::VectorPoint;
+use alloc::{collections::BTreeMap, string::String};
+use s2json::{MValue, PrimitiveValue, ValueType};
+
+/// The size of a point record of each point data format
+pub const LAS_POINT_SIZES: [usize; 11] = [20, 28, 26, 34, 57, 63, 30, 36, 38, 59, 67];
+
+/// Convert the classification of point formats 0 to 5 to its name. Only the first 5 bits are used
+pub fn las_classification(classification: u8) -> &'static str {
+    match classification & 0b11111 {
+        0 => "Created, Never Classified",
+        1 => "Unclassified",
+        2 => "Ground",
+        3 => "Low Vegetation",
+        4 => "Medium Vegetation",
+        5 => "High Vegetation",
+        6 => "Building",
+        7 => "Low Point (Noise)",
+        8 => "Model Key-point (mass point)",
+        9 => "Water",
+        12 => "Overlap Points",
+        _ => "Reserved",
+    }
+}
+
+/// Convert the classification of point formats 6 to 10 to its name
+pub fn las_classification_14(classification: u8) -> &'static str {
+    match classification {
+        0 => "Created, Never Classified",
+        1 => "Unclassified",
+        2 => "Ground",
+        3 => "Low Vegetation",
+        4 => "Medium Vegetation",
+        5 => "High Vegetation",
+        6 => "Building",
+        7 => "Low Point (Noise)",
+        9 => "Water",
+        10 => "Rail",
+        11 => "Road Surface",
+        13 => "Wire – Guard (Shield)",
+        14 => "Wire – Conductor (Phase)",
+        15 => "Transmission Tower",
+        16 => "Wire-structure Connector (e.g. Insulator)",
+        17 => "Bridge Deck",
+        18 => "High Noise",
+        64.. => "User Definable",
+        _ => "Reserved",
+    }
+}
+
+/// Builds the M-value of a point record
+struct Record<'a> {
+    data: &'a [u8],
+    m: MValue,
+}
+impl Record<'_> {
+    fn u8(&self, offset: usize) -> u8 {
+        self.data[offset]
+    }
+    fn u16(&self, offset: usize) -> u16 {
+        u16::from_le_bytes([self.data[offset], self.data[offset + 1]])
+    }
+    fn bytes<const N: usize>(&self, offset: usize) -> [u8; N] {
+        self.data[offset..offset + N].try_into().unwrap()
+    }
+    fn set(&mut self, key: &str, value: PrimitiveValue) {
+        self.m.insert(key.into(), ValueType::Primitive(value));
+    }
+    fn set_u64(&mut self, key: &str, value: u64) {
+        self.set(key, PrimitiveValue::U64(value));
+    }
+    fn set_bool(&mut self, key: &str, value: bool) {
+        self.set(key, PrimitiveValue::Bool(value));
+    }
+    fn set_f64(&mut self, key: &str, value: f64) {
+        self.set(key, PrimitiveValue::F64(value));
+    }
+    fn gps_time(&mut self, offset: usize) {
+        self.set_f64("gpsTime", f64::from_le_bytes(self.bytes(offset)));
+    }
+    fn rgba(&mut self, offset: usize) {
+        let mut rgba = BTreeMap::new();
+        for (i, key) in ["r", "g", "b"].iter().enumerate() {
+            let value = PrimitiveValue::U64(self.u16(offset + 2 * i) as u64);
+            rgba.insert(String::from(*key), ValueType::Primitive(value));
+        }
+        rgba.insert("a".into(), ValueType::Primitive(PrimitiveValue::U64(255)));
+        self.m.insert("rgba".into(), ValueType::Nested(rgba));
+    }
+    fn wave_packet(&mut self, offset: usize) {
+        self.set_u64("wavePacketDescriptorIndex", self.u8(offset) as u64);
+        self.set_u64("wavePacketOffset", u64::from_le_bytes(self.bytes(offset + 1)));
+        self.set_u64("wavePacketLength", u32::from_le_bytes(self.bytes(offset + 9)) as u64);
+        for (i, key) in ["waveformLocationReturnPoint", "xT", "yT", "zT"].iter().enumerate() {
+            let value = f32::from_le_bytes(self.bytes(offset + 13 + 4 * i));
+            self.set(key, PrimitiveValue::F32(value));
+        }
+    }
+}
+
+/// Parse a point record of the header's point data format as a 3D point. The coordinates are
+/// scaled and offset, the attributes of the record are stored in the M-value
+pub fn parse_point(data: &[u8], header: &LASHeader) -> VectorPoint {
+    let x = i32::from_le_bytes(data[0..4].try_into().unwrap());
+    let y = i32::from_le_bytes(data[4..8].try_into().unwrap());
+    let z = i32::from_le_bytes(data[8..12].try_into().unwrap());
+    let mut record = Record { data, m: MValue::new() };
+    record.set_u64("intensity", record.u16(12) as u64);
+    let format = header.point_data_format;
+    if format <= 5 {
+        let bits = record.u8(14);
+        let class = record.u8(15);
+        record.set_u64("returnNumber", (bits & 0b111) as u64);
+        record.set_u64("numberOfReturns", ((bits >> 3) & 0b111) as u64);
+        record.set_u64("scanDirectionFlag", ((bits >> 6) & 1) as u64);
+        record.set_u64("edgeOfFlightLine", (bits >> 7) as u64);
+        record.set("classification", PrimitiveValue::String(las_classification(class).into()));
+        record.set_bool("isSynthetic", class & (1 << 5) != 0);
+        record.set_bool("isKeyPoint", class & (1 << 6) != 0);
+        record.set_bool("isWithheld", class & (1 << 7) != 0);
+        record.set("scanAngleRank", PrimitiveValue::I64(record.u8(16) as i8 as i64));
+        record.set_u64("userData", record.u8(17) as u64);
+        record.set_u64("pointSourceID", record.u16(18) as u64);
+        match format {
+            1 => record.gps_time(20),
+            2 => record.rgba(20),
+            3 => {
+                record.gps_time(20);
+                record.rgba(28);
+            }
+            4 => {
+                record.gps_time(20);
+                record.wave_packet(28);
+            }
+            5 => {
+                record.gps_time(20);
+                record.rgba(28);
+                record.wave_packet(34);
+            }
+            _ => {}
+        }
+    } else {
+        let bits = record.u8(14);
+        let flags = record.u8(15);
+        let class = record.u8(16);
+        record.set_u64("returnNumber", (bits & 0b1111) as u64);
+        record.set_u64("numberOfReturns", (bits >> 4) as u64);
+        record.set_bool("isSynthetic", flags & 1 != 0);
+        record.set_bool("isKeyPoint", flags & (1 << 1) != 0);
+        record.set_bool("isWithheld", flags & (1 << 2) != 0);
+        record.set_bool("isOverlap", flags & (1 << 3) != 0);
+        record.set_u64("scannerChannel", ((flags >> 4) & 0b11) as u64);
+        record.set_u64("scanDirectionFlag", ((flags >> 6) & 1) as u64);
+        record.set_u64("edgeOfFlightLine", (flags >> 7) as u64);
+        record.set("classification", PrimitiveValue::String(las_classification_14(class).into()));
+        record.set_u64("userData", record.u8(17) as u64);
+        let scan_angle = i16::from_le_bytes(record.bytes(18));
+        record.set("scanAngle", PrimitiveValue::I64(scan_angle as i64));
+        record.set_u64("pointSourceID", record.u16(20) as u64);
+        record.gps_time(22);
+        if matches!(format, 7 | 8 | 10) {
+            record.rgba(30);
+        }
+        if matches!(format, 8 | 10) {
+            record.set_u64("nir", record.u16(36) as u64);
+        }
+        match format {
+            9 => record.wave_packet(30),
+            10 => record.wave_packet(38),
+            _ => {}
+        }
+    }
+    VectorPoint::new(
+        x as f64 * header.x_scale_factor + header.x_offset,
+        y as f64 * header.y_scale_factor + header.y_offset,
+        Some(z as f64 * header.z_scale_factor + header.z_offset),
+        Some(record.m),
+    )
+}
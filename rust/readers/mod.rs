@@ -2,18 +2,77 @@ use crate::geometry::VectorFeature;
 
 /// Buffer Reader for reading data from a buffer
 pub mod buffer;
+/// CSV Reader
+pub mod csv;
 /// File Reader for reading data from a file
 #[cfg(feature = "std")]
 pub mod file;
+/// FlatGeobuf Reader
+pub mod flatgeobuf;
+/// GBFS Reader
+pub mod gbfs;
+/// GeoTIFF Reader
+pub mod geotiff;
+/// GML Reader
+pub mod gml;
+/// GPX Reader
+pub mod gpx;
+/// GRIB2 Reader
+pub mod grib2;
+/// GTFS Reader
+pub mod gtfs;
+/// Image decoders
+pub mod image;
+/// GeoJSON and GeoJSON-seq Reader
+pub mod json;
+/// KML and KMZ Reader
+pub mod kml;
+/// LAS and LAZ Reader
+pub mod las;
 /// Memory Mapped Reader for reading data from a file
 #[cfg(feature = "std")]
 pub mod mmap;
+/// NetCDF Reader
+pub mod netcdf;
+/// OpenStreetMap PBF Reader
+pub mod osm;
+/// PMTiles and S2-PMTiles Readers
+pub mod pmtiles;
+/// Shapefile Reader
+pub mod shapefile;
+/// Mapbox Vector Tile Reader
+pub mod vector_tile;
+/// Protobuf wire format checks of untrusted messages
+mod wire;
+/// WKT and WKB geometry Reader and Writer
+pub mod wkt;
+/// Streaming XML pull parser
+pub mod xml;
 
 pub use buffer::*;
+pub use csv::*;
 #[cfg(feature = "std")]
 pub use file::*;
+pub use flatgeobuf::*;
+pub use gbfs::*;
+pub use geotiff::*;
+pub use gml::*;
+pub use gpx::*;
+pub use grib2::*;
+pub use gtfs::*;
+pub use image::*;
+pub use json::*;
+pub use kml::*;
+pub use las::*;
 #[cfg(feature = "std")]
 pub use mmap::*;
+pub use netcdf::*;
+pub use osm::*;
+pub use pmtiles::*;
+pub use shapefile::*;
+pub use vector_tile::*;
+pub use wkt::*;
+pub use xml::*;
 
 use alloc::{string::String, vec::Vec};
 
@@ -85,3 +144,13 @@ pub trait FeatureIterator<M = ()>: Iterator<Item = VectorFeature<M>> {
     /// Get the next feature
     fn next_feature(&mut self) -> Option<VectorFeature<M>>;
 }
+
+/// Tile source interface. Implemented by archives that store tiles addressed by zoom, x and y
+pub trait TileSource {
+    /// The error returned when the archive can't be read
+    type Error;
+    /// Check if a tile exists in the archive
+    fn has_tile(&mut self, zoom: u8, x: u32, y: u32) -> Result<bool, Self::Error>;
+    /// Get the (decompressed) bytes of the tile if it exists in the archive
+    fn get_tile(&mut self, zoom: u8, x: u32, y: u32) -> Result<Option<Vec<u8>>, Self::Error>;
+}
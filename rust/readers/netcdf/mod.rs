@@ -0,0 +1,942 @@
+use crate::{
+    geometry::{
+        VectorFeature, VectorGeometry, VectorGeometryType, VectorPoint, VectorPointGeometry,
+    },
+    readers::{FeatureIterator, Reader},
+};
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use s2json::{MValue, PrimitiveValue, ValueType};
+
+/// Tag of the dimension list
+const NC_DIMENSION: u32 = 10;
+/// Tag of the variable list
+const NC_VARIABLE: u32 = 11;
+/// Tag of an attribute list
+const NC_ATTRIBUTE: u32 = 12;
+/// The number of records of a file that is still being written
+const NC_STREAMING: u32 = u32::MAX;
+
+/// Errors that can occur while reading a NetCDF file
+#[derive(Debug, PartialEq)]
+pub enum NetCDFError {
+    /// The file doesn't start with "CDF"
+    InvalidMagic,
+    /// Only the classic (1) and 64-bit offset (2) formats are supported
+    UnsupportedVersion(u8),
+    /// A dimension, attribute or variable list has an unexpected tag
+    InvalidTag(u32),
+    /// The data type isn't one of the six classic types
+    InvalidDataType(u32),
+    /// The header points outside the file
+    InvalidHeader,
+}
+
+/// The external data types of the classic NetCDF format
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CDFDataType {
+    /// 8-bit signed integer
+    #[default]
+    Byte = 1,
+    /// 8-bit character
+    Char = 2,
+    /// 16-bit signed integer
+    Short = 3,
+    /// 32-bit signed integer
+    Int = 4,
+    /// 32-bit IEEE float
+    Float = 5,
+    /// 64-bit IEEE float
+    Double = 6,
+}
+impl TryFrom<u32> for CDFDataType {
+    type Error = NetCDFError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(CDFDataType::Byte),
+            2 => Ok(CDFDataType::Char),
+            3 => Ok(CDFDataType::Short),
+            4 => Ok(CDFDataType::Int),
+            5 => Ok(CDFDataType::Float),
+            6 => Ok(CDFDataType::Double),
+            _ => Err(NetCDFError::InvalidDataType(value)),
+        }
+    }
+}
+impl CDFDataType {
+    /// The number of bytes of a single value
+    pub fn size(&self) -> usize {
+        match self {
+            CDFDataType::Byte | CDFDataType::Char => 1,
+            CDFDataType::Short => 2,
+            CDFDataType::Int | CDFDataType::Float => 4,
+            CDFDataType::Double => 8,
+        }
+    }
+}
+
+/// Typed values of an attribute or variable
+#[derive(Debug, Clone, PartialEq)]
+pub enum CDFValue {
+    /// 8-bit signed integers
+    Byte(Vec<i8>),
+    /// Text. Attributes are trimmed of trailing null and whitespace characters
+    Char(String),
+    /// 16-bit signed integers
+    Short(Vec<i16>),
+    /// 32-bit signed integers
+    Int(Vec<i32>),
+    /// 32-bit floats
+    Float(Vec<f32>),
+    /// 64-bit floats
+    Double(Vec<f64>),
+}
+impl CDFValue {
+    /// Decode `data` as big-endian values of `data_type`
+    pub fn from_bytes(data_type: CDFDataType, data: &[u8]) -> Self {
+        match data_type {
+            CDFDataType::Byte => CDFValue::Byte(data.iter().map(|&b| b as i8).collect()),
+            CDFDataType::Char => CDFValue::Char(String::from_utf8_lossy(data).into()),
+            CDFDataType::Short => CDFValue::Short(
+                data.as_chunks::<2>().0.iter().map(|b| i16::from_be_bytes(*b)).collect(),
+            ),
+            CDFDataType::Int => CDFValue::Int(
+                data.as_chunks::<4>().0.iter().map(|b| i32::from_be_bytes(*b)).collect(),
+            ),
+            CDFDataType::Float => CDFValue::Float(
+                data.as_chunks::<4>().0.iter().map(|b| f32::from_be_bytes(*b)).collect(),
+            ),
+            CDFDataType::Double => CDFValue::Double(
+                data.as_chunks::<8>().0.iter().map(|b| f64::from_be_bytes(*b)).collect(),
+            ),
+        }
+    }
+
+    /// The number of values (bytes for text)
+    pub fn len(&self) -> usize {
+        match self {
+            CDFValue::Byte(v) => v.len(),
+            CDFValue::Char(v) => v.len(),
+            CDFValue::Short(v) => v.len(),
+            CDFValue::Int(v) => v.len(),
+            CDFValue::Float(v) => v.len(),
+            CDFValue::Double(v) => v.len(),
+        }
+    }
+
+    /// Returns true if there are no values
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the numeric value at `index`. Text returns `None`
+    pub fn get(&self, index: usize) -> Option<f64> {
+        match self {
+            CDFValue::Byte(v) => v.get(index).map(|&v| v as f64),
+            CDFValue::Char(_) => None,
+            CDFValue::Short(v) => v.get(index).map(|&v| v as f64),
+            CDFValue::Int(v) => v.get(index).map(|&v| v as f64),
+            CDFValue::Float(v) => v.get(index).map(|&v| v as f64),
+            CDFValue::Double(v) => v.get(index).copied(),
+        }
+    }
+
+    /// All the values as 64-bit floats. Text returns an empty list
+    pub fn to_f64(&self) -> Vec<f64> {
+        (0..self.len()).map_while(|i| self.get(i)).collect()
+    }
+
+    /// The text if the values are characters
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            CDFValue::Char(v) => Some(v),
+            _ => None,
+        }
+    }
+}
+
+/// The attributes of a file or variable
+pub type CDFAttributes = BTreeMap<String, CDFValue>;
+
+/// A named dimension
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CDFDimension {
+    /// Index of the dimension
+    pub index: usize,
+    /// Name of the dimension
+    pub name: String,
+    /// Size of the dimension. 0 for the unlimited (record) dimension
+    pub size: usize,
+}
+
+/// The unlimited dimension that record variables grow along
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CDFRecordDimension {
+    /// The number of records
+    pub size: usize,
+    /// Index of the unlimited dimension if there is one
+    pub id: Option<usize>,
+    /// Name of the unlimited dimension if there is one
+    pub name: Option<String>,
+    /// The number of bytes of a record: the data of every record variable for one step
+    pub record_step: usize,
+}
+
+/// A variable of a NetCDF file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CDFVariable {
+    /// Name of the variable
+    pub name: String,
+    /// The dimensions of the variable in order, the record dimension first for record variables
+    pub dimensions: Vec<CDFDimension>,
+    /// The attributes of the variable
+    pub attributes: CDFAttributes,
+    /// The type of the variable's values
+    pub data_type: CDFDataType,
+    /// The padded size of the variable in bytes (of one record for record variables)
+    pub size: usize,
+    /// The offset of the variable's data (of the first record for record variables)
+    pub offset: usize,
+    /// True if the variable is a record variable
+    pub record: bool,
+}
+impl CDFVariable {
+    /// The number of values in one record, or of the whole variable for non-record variables
+    pub fn record_len(&self) -> usize {
+        let skip = if self.record { 1 } else { 0 };
+        self.dimensions.iter().skip(skip).map(|d| d.size).product()
+    }
+
+    /// The first numeric value of the `_FillValue` attribute
+    pub fn fill_value(&self) -> Option<f64> {
+        self.attributes.get("_FillValue").and_then(|v| v.get(0))
+    }
+}
+
+/// User defined options on how to read a NetCDF file
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct NetCDFReaderOptions {
+    /// The name of the longitude variable [Default: "lon"]
+    pub lon_key: Option<String>,
+    /// The name of the latitude variable [Default: "lat"]
+    pub lat_key: Option<String>,
+    /// The name of the height variable stored as each point's z value [Default: None]
+    pub height_key: Option<String>,
+    /// The variables stored in each point's M-value [Default: every numeric variable that shares
+    /// the points' dimensions]
+    pub fields: Option<Vec<String>>,
+}
+
+/// The values of a variable stored in the M-values of the points
+#[derive(Debug)]
+struct PointField {
+    name: String,
+    values: Vec<f64>,
+    /// The number of values per point from the variable's leading dimensions
+    layers: usize,
+    fill_value: Option<f64>,
+}
+
+/// The data of every point, loaded before the first feature is read
+#[derive(Debug)]
+struct PointData {
+    lon: Vec<f64>,
+    lat: Vec<f64>,
+    height: Option<Vec<f64>>,
+    /// True if the longitudes and latitudes are the axes of a grid
+    grid: bool,
+    fields: Vec<PointField>,
+    cursor: usize,
+}
+impl PointData {
+    fn len(&self) -> usize {
+        if self.grid {
+            self.lon.len() * self.lat.len()
+        } else {
+            self.lon.len().min(self.lat.len())
+        }
+    }
+}
+
+/// # NetCDF Reader
+///
+/// ## Description
+/// Reads the classic (CDF-1) and 64-bit offset (CDF-2) NetCDF formats through the [`Reader`]
+/// trait. The header's dimensions, global attributes and variables are parsed up front and the
+/// data of a variable, including record variables, is read with
+/// [`NetCDFReader::get_data_variable`].
+///
+/// Implements the [`FeatureIterator`] when latitude and longitude variables are present: each
+/// point is a Point feature. If the latitude and longitude share their dimensions they're paired
+/// value by value, otherwise two 1-D coordinate variables are the axes of a grid. The values of
+/// the fields (every numeric variable whose trailing dimensions are the points' dimensions by
+/// default) are stored in each point's M-value, keyed by the variable's name. Variables with
+/// extra leading dimensions (e.g. time) are stored as `name_0`, `name_1`, etc. Fill values are
+/// skipped.
+///
+/// NOTE: The CDF-5 (64-bit data) format is not supported.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, CDFValue, FeatureIterator, NetCDFReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/netcdf/fixtures/ichthyop.nc");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut reader = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+/// let time = reader.get_data_variable("time").unwrap();
+/// assert_eq!(time, CDFValue::Double(time.to_f64()));
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://docs.unidata.ucar.edu/netcdf-c/current/file_format_specifications.html>
+/// - <https://cfconventions.org/>
+#[derive(Debug)]
+pub struct NetCDFReader<T: Reader> {
+    reader: T,
+    /// The unlimited dimension and the number of records
+    pub record_dimension: CDFRecordDimension,
+    /// The dimensions
+    pub dimensions: Vec<CDFDimension>,
+    /// The global attributes
+    pub global_attributes: CDFAttributes,
+    /// The variables
+    pub variables: Vec<CDFVariable>,
+    /// True if the file uses 64-bit offsets
+    pub is_64: bool,
+    options: NetCDFReaderOptions,
+    points: Option<PointData>,
+}
+impl<T: Reader> NetCDFReader<T> {
+    /// Create a new NetCDF reader, parsing the header
+    pub fn new(mut reader: T, options: Option<NetCDFReaderOptions>) -> Result<Self, NetCDFError> {
+        if reader.len() < 8 || reader.slice(Some(0), Some(3)) != b"CDF" {
+            return Err(NetCDFError::InvalidMagic);
+        }
+        let version = reader.uint8(Some(3));
+        if version != 1 && version != 2 {
+            return Err(NetCDFError::UnsupportedVersion(version));
+        }
+        let mut header = Header { reader: &mut reader, cursor: 4, is_64: version == 2 };
+        let records = header.u32()?;
+        let dimensions = header.dimensions()?;
+        let global_attributes = header.attributes()?;
+        let record_id = dimensions.iter().position(|d| d.size == 0);
+        let variables = header.variables(&dimensions, record_id)?;
+
+        let record_variables: Vec<&CDFVariable> = variables.iter().filter(|v| v.record).collect();
+        let record_step = match record_variables[..] {
+            // a single record variable isn't padded between records
+            [variable] => variable.record_len() * variable.data_type.size(),
+            _ => record_variables
+                .iter()
+                .map(|v| (v.record_len() * v.data_type.size()).next_multiple_of(4))
+                .try_fold(0_usize, |step, length| step.checked_add(length))
+                .ok_or(NetCDFError::InvalidHeader)?,
+        };
+        let size = if records == NC_STREAMING {
+            match record_variables.iter().map(|v| v.offset).min() {
+                Some(begin) if record_step > 0 => reader.len().saturating_sub(begin) / record_step,
+                _ => 0,
+            }
+        } else {
+            records as usize
+        };
+        let record_dimension = CDFRecordDimension {
+            size,
+            id: record_id,
+            name: record_id.map(|id| dimensions[id].name.clone()),
+            record_step,
+        };
+
+        Ok(Self {
+            reader,
+            record_dimension,
+            dimensions,
+            global_attributes,
+            variables,
+            is_64: version == 2,
+            options: options.unwrap_or_default(),
+            points: None,
+        })
+    }
+
+    /// Get a variable by name
+    pub fn get_variable(&self, name: &str) -> Option<&CDFVariable> {
+        self.variables.iter().find(|v| v.name == name)
+    }
+
+    /// Read all the values of a variable in row-major order, every record one after the other for
+    /// record variables. Returns `None` if the variable doesn't exist or its data is out of bounds
+    pub fn get_data_variable(&mut self, name: &str) -> Option<CDFValue> {
+        let variable = self.get_variable(name)?;
+        let (data_type, offset) = (variable.data_type, variable.offset);
+        let length = variable.record_len() * data_type.size();
+        if !variable.record {
+            return self.read_value(data_type, offset, length);
+        }
+        let total = length.checked_mul(self.record_dimension.size)?;
+        if total > self.reader.len() {
+            return None;
+        }
+        let mut data = Vec::with_capacity(total);
+        for record in 0..self.record_dimension.size {
+            let begin = record
+                .checked_mul(self.record_dimension.record_step)
+                .and_then(|step| step.checked_add(offset))?;
+            if begin.checked_add(length)? > self.reader.len() {
+                return None;
+            }
+            data.extend(self.reader.slice(Some(begin), Some(begin + length)));
+        }
+        Some(CDFValue::from_bytes(data_type, &data))
+    }
+
+    /// Read the values of one record of a record variable
+    pub fn get_record(&mut self, name: &str, record: usize) -> Option<CDFValue> {
+        let variable = self.get_variable(name)?;
+        if !variable.record || record >= self.record_dimension.size {
+            return None;
+        }
+        let (data_type, offset) = (variable.data_type, variable.offset);
+        let length = variable.record_len() * data_type.size();
+        let begin = record
+            .checked_mul(self.record_dimension.record_step)
+            .and_then(|step| step.checked_add(offset))?;
+        self.read_value(data_type, begin, length)
+    }
+
+    fn read_value(
+        &mut self,
+        data_type: CDFDataType,
+        offset: usize,
+        length: usize,
+    ) -> Option<CDFValue> {
+        if offset.checked_add(length)? > self.reader.len() {
+            return None;
+        }
+        Some(CDFValue::from_bytes(
+            data_type,
+            &self.reader.slice(Some(offset), Some(offset + length)),
+        ))
+    }
+
+    /// The dimension indexes of a variable
+    fn dimension_ids(&self, name: &str) -> Option<Vec<usize>> {
+        self.get_variable(name).map(|v| v.dimensions.iter().map(|d| d.index).collect())
+    }
+
+    /// Load the coordinates and fields of the points
+    fn load_points(&mut self) -> Option<PointData> {
+        let lon_key = self.options.lon_key.clone().unwrap_or("lon".into());
+        let lat_key = self.options.lat_key.clone().unwrap_or("lat".into());
+        let lon_dims = self.dimension_ids(&lon_key)?;
+        let lat_dims = self.dimension_ids(&lat_key)?;
+        let grid = lon_dims.len() == 1 && lat_dims.len() == 1 && lon_dims != lat_dims;
+        if !grid && lon_dims != lat_dims {
+            return None;
+        }
+        let point_dims = if grid { vec![lat_dims[0], lon_dims[0]] } else { lat_dims };
+        let lon = self.get_data_variable(&lon_key)?.to_f64();
+        let lat = self.get_data_variable(&lat_key)?.to_f64();
+        let count = if grid { lon.len() * lat.len() } else { lon.len().min(lat.len()) };
+        let height_key = self.options.height_key.clone();
+        let height = height_key
+            .as_deref()
+            .and_then(|key| self.get_data_variable(key))
+            .map(|h| h.to_f64())
+            .filter(|h| h.len() == count);
+
+        let names: Vec<String> = match &self.options.fields {
+            Some(fields) => fields.clone(),
+            None => self
+                .variables
+                .iter()
+                .filter(|v| v.data_type != CDFDataType::Char)
+                .map(|v| v.name.clone())
+                .filter(|n| *n != lon_key && *n != lat_key && Some(n) != height_key.as_ref())
+                .collect(),
+        };
+        let mut fields = vec![];
+        for name in names {
+            let Some(dims) = self.dimension_ids(&name) else { continue };
+            if dims.len() < point_dims.len() || !dims.ends_with(&point_dims) {
+                continue;
+            }
+            let fill_value = self.get_variable(&name).and_then(|v| v.fill_value());
+            let Some(values) = self.get_data_variable(&name).map(|v| v.to_f64()) else { continue };
+            if count == 0 || values.is_empty() || values.len() % count != 0 {
+                continue;
+            }
+            fields.push(PointField { name, layers: values.len() / count, values, fill_value });
+        }
+
+        Some(PointData { lon, lat, height, grid, fields, cursor: 0 })
+    }
+}
+impl<T: Reader> Iterator for NetCDFReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.points.is_none() {
+            self.points = Some(self.load_points().unwrap_or(PointData {
+                lon: vec![],
+                lat: vec![],
+                height: None,
+                grid: false,
+                fields: vec![],
+                cursor: 0,
+            }));
+        }
+        let points = self.points.as_mut()?;
+        let count = points.len();
+        let index = points.cursor;
+        if index >= count {
+            return None;
+        }
+        points.cursor += 1;
+
+        let (x, y) = if points.grid {
+            (points.lon[index % points.lon.len()], points.lat[index / points.lon.len()])
+        } else {
+            (points.lon[index], points.lat[index])
+        };
+        let z = points.height.as_ref().map(|h| h[index]);
+        let mut m = MValue::new();
+        for field in &points.fields {
+            for layer in 0..field.layers {
+                let value = field.values[layer * count + index];
+                if value.is_nan() || Some(value) == field.fill_value {
+                    continue;
+                }
+                let key = if field.layers == 1 {
+                    field.name.clone()
+                } else {
+                    format!("{}_{layer}", field.name)
+                };
+                m.insert(key, ValueType::Primitive(PrimitiveValue::F64(value)));
+            }
+        }
+        let geometry = VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            is_3d: z.is_some(),
+            coordinates: VectorPoint::new(x, y, z, Some(m)),
+            ..Default::default()
+        });
+        Some(VectorFeature::new_wm(Some(index as u64), Default::default(), geometry, None))
+    }
+}
+impl<T: Reader> FeatureIterator for NetCDFReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Walks the header with a cursor. Every name and value block is padded to 4 bytes
+struct Header<'a, T: Reader> {
+    reader: &'a mut T,
+    cursor: usize,
+    is_64: bool,
+}
+impl<T: Reader> Header<'_, T> {
+    fn u32(&mut self) -> Result<u32, NetCDFError> {
+        if self.cursor + 4 > self.reader.len() {
+            return Err(NetCDFError::InvalidHeader);
+        }
+        let value = self.reader.uint32_be(Some(self.cursor));
+        self.cursor += 4;
+        Ok(value)
+    }
+
+    fn offset(&mut self) -> Result<usize, NetCDFError> {
+        if !self.is_64 {
+            return Ok(self.u32()? as usize);
+        }
+        if self.cursor + 8 > self.reader.len() {
+            return Err(NetCDFError::InvalidHeader);
+        }
+        let value = self.reader.uint64_be(Some(self.cursor));
+        self.cursor += 8;
+        Ok(value as usize)
+    }
+
+    fn bytes(&mut self, length: usize) -> Result<Vec<u8>, NetCDFError> {
+        if self.cursor + length > self.reader.len() {
+            return Err(NetCDFError::InvalidHeader);
+        }
+        let data = self.reader.slice(Some(self.cursor), Some(self.cursor + length));
+        self.cursor = (self.cursor + length).next_multiple_of(4);
+        Ok(data)
+    }
+
+    fn name(&mut self) -> Result<String, NetCDFError> {
+        let length = self.u32()? as usize;
+        Ok(String::from_utf8_lossy(&self.bytes(length)?).into())
+    }
+
+    /// Check that `count` elements of at least `size` bytes each fit in the rest of the file
+    fn check_count(&self, count: usize, size: usize) -> Result<usize, NetCDFError> {
+        match count.checked_mul(size) {
+            Some(bytes) if bytes <= self.reader.len().saturating_sub(self.cursor) => Ok(count),
+            _ => Err(NetCDFError::InvalidHeader),
+        }
+    }
+
+    /// Read the tag and number of elements of a list, every element taking at least `size` bytes.
+    /// ABSENT lists are two zeros
+    fn list(&mut self, tag: u32, size: usize) -> Result<usize, NetCDFError> {
+        let list_tag = self.u32()?;
+        let count = self.u32()? as usize;
+        if list_tag == 0 && count == 0 {
+            Ok(0)
+        } else if list_tag == tag {
+            self.check_count(count, size)
+        } else {
+            Err(NetCDFError::InvalidTag(list_tag))
+        }
+    }
+
+    fn dimensions(&mut self) -> Result<Vec<CDFDimension>, NetCDFError> {
+        // name length and size
+        let count = self.list(NC_DIMENSION, 8)?;
+        let mut dimensions = Vec::with_capacity(count);
+        for index in 0..count {
+            let name = self.name()?;
+            dimensions.push(CDFDimension { index, name, size: self.u32()? as usize });
+        }
+        Ok(dimensions)
+    }
+
+    fn attributes(&mut self) -> Result<CDFAttributes, NetCDFError> {
+        // name length, type and number of values
+        let count = self.list(NC_ATTRIBUTE, 12)?;
+        let mut attributes = CDFAttributes::new();
+        for _ in 0..count {
+            let name = self.name()?;
+            let data_type = CDFDataType::try_from(self.u32()?)?;
+            let length = self.u32()? as usize * data_type.size();
+            let mut value = CDFValue::from_bytes(data_type, &self.bytes(length)?);
+            if let CDFValue::Char(text) = &value {
+                value = CDFValue::Char(
+                    text.trim_end_matches(|c: char| c == '\0' || c.is_whitespace()).to_string(),
+                );
+            }
+            attributes.insert(name, value);
+        }
+        Ok(attributes)
+    }
+
+    fn variables(
+        &mut self,
+        dimensions: &[CDFDimension],
+        record_id: Option<usize>,
+    ) -> Result<Vec<CDFVariable>, NetCDFError> {
+        // name length, rank, attribute list, type, size and offset
+        let count = self.list(NC_VARIABLE, 28)?;
+        let mut variables = Vec::with_capacity(count);
+        for _ in 0..count {
+            let name = self.name()?;
+            let rank = self.u32()? as usize;
+            self.check_count(rank, 4)?;
+            let mut variable_dimensions = Vec::with_capacity(rank);
+            for _ in 0..rank {
+                let id = self.u32()? as usize;
+                variable_dimensions
+                    .push(dimensions.get(id).ok_or(NetCDFError::InvalidHeader)?.clone());
+            }
+            let attributes = self.attributes()?;
+            let data_type = CDFDataType::try_from(self.u32()?)?;
+            let size = self.u32()? as usize;
+            let offset = self.offset()?;
+            let record =
+                record_id.is_some() && variable_dimensions.first().map(|d| d.index) == record_id;
+            // the values of a variable (of one record for record variables) have to fit in the file
+            let length = variable_dimensions
+                .iter()
+                .skip(if record { 1 } else { 0 })
+                .try_fold(data_type.size(), |length, d| length.checked_mul(d.size));
+            if length.is_none_or(|length| length > self.reader.len()) {
+                return Err(NetCDFError::InvalidHeader);
+            }
+            variables.push(CDFVariable {
+                name,
+                dimensions: variable_dimensions,
+                attributes,
+                data_type,
+                size,
+                offset,
+                record,
+            });
+        }
+        Ok(variables)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::BufferReader;
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/netcdf/fixtures");
+        path.push(name);
+        std::fs::read(path).unwrap()
+    }
+
+    #[test]
+    fn test_invalid_file() {
+        let data = fixture("not_nc.txt");
+        assert_eq!(
+            NetCDFReader::new(BufferReader::from(&data[..]), None).err(),
+            Some(NetCDFError::InvalidMagic)
+        );
+    }
+
+    #[test]
+    fn test_header_and_record_variables() {
+        let data = fixture("madis-sao.nc");
+        let mut netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+        assert!(!netcdf.is_64);
+        assert_eq!(
+            netcdf.record_dimension,
+            CDFRecordDimension {
+                size: 178,
+                id: Some(21),
+                name: Some("recNum".into()),
+                record_step: 1220
+            }
+        );
+        assert_eq!(netcdf.dimensions.len(), 22);
+        assert_eq!(
+            netcdf.dimensions[7],
+            CDFDimension { index: 7, name: "maxSAOLen".into(), size: 256 }
+        );
+        assert_eq!(netcdf.global_attributes.len(), 83);
+        assert_eq!(
+            netcdf.global_attributes.get("DD_reference"),
+            Some(&CDFValue::Char("AWIPS Technique Specification Package (TSP) 88-21-R2".into()))
+        );
+        assert_eq!(netcdf.global_attributes.get("filePeriod"), Some(&CDFValue::Int(vec![3600])));
+
+        assert_eq!(
+            netcdf.variables[0],
+            CDFVariable {
+                name: "nStaticIds".into(),
+                attributes: BTreeMap::from([("_FillValue".into(), CDFValue::Int(vec![0]))]),
+                data_type: CDFDataType::Int,
+                size: 4,
+                offset: 39208,
+                ..Default::default()
+            }
+        );
+        let wmo_id = &netcdf.variables[11];
+        assert_eq!(wmo_id.name, "wmoId");
+        assert!(wmo_id.record);
+        assert_eq!((wmo_id.size, wmo_id.offset), (4, 48884));
+        assert_eq!(wmo_id.fill_value(), Some(-2147483647.));
+        assert_eq!(wmo_id.attributes.get("valid_range"), Some(&CDFValue::Int(vec![1, 89999])));
+
+        assert_eq!(netcdf.get_data_variable("nStaticIds"), Some(CDFValue::Int(vec![145])));
+        assert_eq!(netcdf.get_data_variable("n'importe quoi"), None);
+        let wmo_id = netcdf.get_data_variable("wmoId").unwrap();
+        assert_eq!(wmo_id.len(), 178);
+        assert_eq!(wmo_id.to_f64()[..3], [71419., 71415., 71408.]);
+        assert_eq!(netcdf.get_record("wmoId", 1), Some(CDFValue::Int(vec![71415])));
+        assert_eq!(netcdf.get_record("nStaticIds", 0), None);
+
+        let static_ids = netcdf.get_data_variable("staticIds").unwrap();
+        assert!(static_ids.as_str().unwrap().starts_with("W"));
+        // no lat/lon variables
+        assert_eq!(netcdf.next_feature(), None);
+    }
+
+    #[test]
+    fn test_two_dimensional_variable() {
+        let data = fixture("ichthyop.nc");
+        let mut netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+        let time = netcdf.get_data_variable("time").unwrap();
+        assert_eq!(time.len(), 49);
+        assert_eq!(time.get(0), Some(1547070300.));
+        let lat = netcdf.get_data_variable("lat").unwrap();
+        assert_eq!(lat.len(), 49 * 1000);
+        assert_eq!(lat.get(0), Some(53.26256561279297));
+    }
+
+    #[test]
+    fn test_64_bit_offset() {
+        let data = fixture("model1_md2.nc");
+        let mut netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+        assert!(netcdf.is_64);
+        let cell_angular = netcdf.get_data_variable("cell_angular").unwrap();
+        assert!(cell_angular.as_str().unwrap().starts_with('a'));
+        let cell_spatial = netcdf.get_data_variable("cell_spatial").unwrap();
+        assert!(cell_spatial.as_str().unwrap().starts_with('a'));
+    }
+
+    #[test]
+    fn test_single_record_variable() {
+        let data = fixture("agilent_hplc.cdf");
+        let mut netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+        assert_eq!(netcdf.variables.len(), 24);
+        let name = netcdf.variables[3].name.clone();
+        assert_eq!(netcdf.get_data_variable(&name), Some(CDFValue::Float(vec![0.012])));
+        assert_eq!(netcdf.get_data_variable("ordinate_values").map(|v| v.len()), Some(4651));
+    }
+
+    #[test]
+    fn test_malformed_header() {
+        let u32s = |data: &mut Vec<u8>, values: &[u32]| {
+            values.iter().for_each(|v| data.extend(v.to_be_bytes()));
+        };
+        let header = |records: u32, dims: &[u32], var_dims: &[u32]| {
+            let mut data = b"CDF\x01".to_vec();
+            u32s(&mut data, &[records, NC_DIMENSION, dims.len() as u32]);
+            for size in dims {
+                u32s(&mut data, &[1, u32::from_be_bytes(*b"d\0\0\0"), *size]);
+            }
+            u32s(&mut data, &[0, 0, NC_VARIABLE, 1, 1, u32::from_be_bytes(*b"v\0\0\0")]);
+            u32s(&mut data, &[var_dims.len() as u32]);
+            u32s(&mut data, var_dims);
+            let offset = data.len() as u32 + 20;
+            u32s(&mut data, &[0, 0, CDFDataType::Byte as u32, 4, offset]);
+            data.extend([0; 4]);
+            data
+        };
+
+        // more dimensions than the file can hold
+        let mut data = b"CDF\x01".to_vec();
+        u32s(&mut data, &[0, NC_DIMENSION, u32::MAX]);
+        let netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(netcdf.err(), Some(NetCDFError::InvalidHeader));
+        // a variable whose values overflow
+        let data = header(0, &[u32::MAX; 3], &[0, 1, 2]);
+        let netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(netcdf.err(), Some(NetCDFError::InvalidHeader));
+        // a variable whose values are larger than the file
+        let data = header(0, &[4, 1 << 16], &[0, 1]);
+        let netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(netcdf.err(), Some(NetCDFError::InvalidHeader));
+        // a record count larger than the file
+        let data = header(u32::MAX - 1, &[0, 4], &[0, 1]);
+        let mut netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+        assert_eq!(netcdf.get_record("v", 0), Some(CDFValue::Byte(vec![0; 4])));
+        assert_eq!(netcdf.get_record("v", 1), None);
+        assert_eq!(netcdf.get_data_variable("v"), None);
+    }
+
+    #[test]
+    fn test_points() {
+        let data = fixture("ichthyop.nc");
+        let options = NetCDFReaderOptions {
+            height_key: Some("depth".into()),
+            fields: Some(vec!["mortality".into()]),
+            ..Default::default()
+        };
+        let netcdf = NetCDFReader::new(BufferReader::from(&data[..]), Some(options)).unwrap();
+        let features: Vec<VectorFeature> = netcdf.collect();
+        assert_eq!(features.len(), 49000);
+        let VectorGeometry::Point(point) = &features[0].geometry else { panic!() };
+        assert!(point.is_3d);
+        assert_eq!(
+            point.coordinates,
+            VectorPoint::new(
+                -9.00235366821289,
+                53.26256561279297,
+                Some(-0.2654986083507538),
+                Some(MValue::from([(
+                    "mortality".into(),
+                    ValueType::Primitive(PrimitiveValue::F64(0.))
+                )]))
+            )
+        );
+
+        // every variable sharing the points' dimensions by default
+        let mut netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+        let VectorGeometry::Point(point) = netcdf.next_feature().unwrap().geometry else {
+            panic!()
+        };
+        let m = point.coordinates.m.unwrap();
+        assert!(m.contains_key("mortality") && m.contains_key("depth"));
+        assert!(!m.contains_key("lat") && !m.contains_key("time"));
+    }
+
+    #[test]
+    fn test_grid_points() {
+        // a 2x3 lat/lon grid with two time steps of a temperature variable
+        let mut data = b"CDF\x01".to_vec();
+        let u32s = |data: &mut Vec<u8>, values: &[u32]| {
+            values.iter().for_each(|v| data.extend(v.to_be_bytes()));
+        };
+        let name = |data: &mut Vec<u8>, name: &str| {
+            data.extend((name.len() as u32).to_be_bytes());
+            data.extend(name.as_bytes());
+            data.resize(data.len().next_multiple_of(4), 0);
+        };
+        u32s(&mut data, &[2, NC_DIMENSION, 3]);
+        for (dim, size) in [("time", 0), ("lat", 2), ("lon", 3)] {
+            name(&mut data, dim);
+            u32s(&mut data, &[size]);
+        }
+        u32s(&mut data, &[0, 0, NC_VARIABLE, 3]);
+        let header_len = 212;
+        let variables: [(&str, &[u32], u32, u32); 3] = [
+            ("lat", &[1], 2 * 4, header_len as u32),
+            ("lon", &[2], 3 * 4, header_len as u32 + 8),
+            ("temp", &[0, 1, 2], 6 * 4, header_len as u32 + 20),
+        ];
+        for (var, dims, size, offset) in variables {
+            name(&mut data, var);
+            u32s(&mut data, &[dims.len() as u32]);
+            u32s(&mut data, dims);
+            if var == "temp" {
+                u32s(&mut data, &[NC_ATTRIBUTE, 1]);
+                name(&mut data, "_FillValue");
+                u32s(&mut data, &[CDFDataType::Float as u32, 1]);
+                data.extend((-999_f32).to_be_bytes());
+            } else {
+                u32s(&mut data, &[0, 0]);
+            }
+            u32s(&mut data, &[CDFDataType::Float as u32, size, offset]);
+        }
+        assert_eq!(data.len(), header_len);
+        for v in [10., 20., 0., 1., 2., 1., 2., 3., 4., 5., -999., 11., 12., 13., 14., 15., 16.] {
+            data.extend((v as f32).to_be_bytes());
+        }
+        let mut netcdf = NetCDFReader::new(BufferReader::from(&data[..]), None).unwrap();
+        assert_eq!(netcdf.record_dimension.size, 2);
+        assert_eq!(netcdf.record_dimension.record_step, 24);
+        assert_eq!(
+            netcdf.get_record("temp", 1).unwrap().to_f64(),
+            vec![11., 12., 13., 14., 15., 16.]
+        );
+
+        let features: Vec<VectorFeature> = netcdf.collect();
+        assert_eq!(features.len(), 6);
+        let VectorGeometry::Point(point) = &features[5].geometry else { panic!() };
+        let m = |pairs: &[(&str, f64)]| {
+            MValue::from_iter(
+                pairs
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), ValueType::Primitive(PrimitiveValue::F64(*v)))),
+            )
+        };
+        assert_eq!(point.coordinates, VectorPoint::new(2., 20., None, Some(m(&[("temp_1", 16.)]))));
+        let VectorGeometry::Point(point) = &features[1].geometry else { panic!() };
+        assert_eq!(
+            point.coordinates,
+            VectorPoint::new(1., 10., None, Some(m(&[("temp_0", 2.), ("temp_1", 12.)])))
+        );
+    }
+}
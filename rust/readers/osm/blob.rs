@@ -1,71 +1,108 @@
-// import { decompressStream } from '../../util';
+use crate::util::{decompress_data, CompressError, CompressionFormat};
 
-// import type { PbfReader } from 'pbf-ts';
+use pbf::{ProtoRead, Protobuf};
 
-// // headers have a max size of 64KB
-// export const OSM_MAX_HEADER_SIZE = 65_536; // 64 * 1024;
-// // blobs have a max size of 32MB
-// export const OSM_MAX_BLOB_SIZE = 33_554_432; // 32 * 1024 * 1024;
+use alloc::{string::String, vec::Vec};
 
-// /**
-//  * A file contains an sequence of fileblock headers, each prefixed by
-//  * their length in network byte order, followed by a data block
-//  * containing the actual data. Types starting with a "_" are reserved.
-//  * example: { type: 'OSMHeader', indexdata: null, datasize: 173 }
-//  */
-// export class BlobHeader {
-//   type: string = '';
-//   indexdata: Uint8Array = new Uint8Array(0);
-//   datasize = 0;
+/// headers have a max size of 64KB
+pub const OSM_MAX_HEADER_SIZE: usize = 65_536; // 64 * 1024;
+/// blobs have a max size of 32MB
+pub const OSM_MAX_BLOB_SIZE: usize = 33_554_432; // 32 * 1024 * 1024;
 
-//   /** @param pbf - the Protobuf object to read from */
-//   constructor(pbf: PbfReader) {
-//     pbf.readFields(this.#readLayer, this, 0);
-//   }
+/// A file contains an sequence of fileblock headers, each prefixed by
+/// their length in network byte order, followed by a data block
+/// containing the actual data. Types starting with a "_" are reserved.
+/// example: `{ type: 'OSMHeader', indexdata: null, datasize: 173 }`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BlobHeader {
+    /// The type of the blob. Either "OSMHeader" or "OSMData"
+    pub _type: String,
+    /// Optional index data
+    pub indexdata: Vec<u8>,
+    /// The size of the blob that follows the header
+    pub datasize: i32,
+}
+impl ProtoRead for BlobHeader {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self._type = pb.read_string(),
+            2 => self.indexdata = pb.read_bytes(),
+            3 => self.datasize = pb.read_varint::<i32>(),
+            _ => {}
+        }
+    }
+}
 
-//   /**
-//    * @param tag - the tag of the layer
-//    * @param blobHeader - the blobHeader to modify
-//    * @param pbf - the Protobuf object to read from
-//    */
-//   #readLayer(tag: number, blobHeader: BlobHeader, pbf: PbfReader): void {
-//     if (tag === 1) blobHeader.type = pbf.readString();
-//     else if (tag === 2) blobHeader.indexdata = pbf.readBytes();
-//     else if (tag === 3) blobHeader.datasize = pbf.readVarint();
-//     else throw new Error('unknown tag ' + tag);
-//   }
-// }
+/// The compression used by a blob's data
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum BlobCompression {
+    /// No compression
+    #[default]
+    Raw,
+    /// ZLIB compressed
+    Zlib,
+    /// LZMA compressed (optional)
+    Lzma,
+    /// Formerly used for bzip2 compressed data. Deprecated in 2010.
+    Bzip2,
+    /// LZ4 compressed (optional)
+    Lz4,
+    /// ZSTD compressed (optional)
+    Zstd,
+}
 
-// ///  STORAGE LAYER: Storing primitives.
-// /** A Blob is a data block containing the actual data. */
-// export class Blob {
-//   raw_size = 0;
-//   data: Uint8Array | Promise<Uint8Array> = new Uint8Array(0);
-
-//   /** @param pbf - the Protobuf object to read from */
-//   constructor(pbf: PbfReader) {
-//     pbf.readFields(this.#readLayer, this, 0);
-//   }
-
-//   /**
-//    * @param tag - the tag of the layer
-//    * @param blob - the blob to modify
-//    * @param pbf - the Protobuf object to read from
-//    */
-//   #readLayer(tag: number, blob: Blob, pbf: PbfReader): void {
-//     // no compression
-//     if (tag === 1) blob.data = pbf.readBytes();
-//     else if (tag === 2) blob.raw_size = pbf.readVarint();
-//     // ZLIB:
-//     else if (tag === 3) blob.data = decompressStream(pbf.readBytes());
-//     // For LZMA compressed data (optional)
-//     else if (tag === 4) blob.data = pbf.readBytes();
-//     // Formerly used for bzip2 compressed data. Deprecated in 2010.
-//     else if (tag === 5) throw new Error('bzip2 not supported');
-//     // For LZ4 compressed data (optional)
-//     else if (tag === 6) blob.data = pbf.readBytes();
-//     // For ZSTD compressed data (optional)
-//     else if (tag === 7) blob.data = pbf.readBytes();
-//     else throw new Error('unknown tag ' + tag);
-//   }
-// }
+/// STORAGE LAYER: Storing primitives.
+/// A Blob is a data block containing the actual data.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Blob {
+    /// When compressed, the uncompressed size
+    pub raw_size: i32,
+    /// How the data is compressed
+    pub compression: BlobCompression,
+    /// The (possibly compressed) data
+    pub data: Vec<u8>,
+}
+impl Blob {
+    /// Get the uncompressed data of the blob
+    pub fn data(&self) -> Result<Vec<u8>, CompressError> {
+        match self.compression {
+            BlobCompression::Raw => Ok(self.data.clone()),
+            // NOTE: `DeflateRaw` is the zlib-wrapped format
+            BlobCompression::Zlib => decompress_data(&self.data, CompressionFormat::DeflateRaw),
+            BlobCompression::Zstd => decompress_data(&self.data, CompressionFormat::Zstd),
+            _ => Err(CompressError::InvalidCompressionMethod),
+        }
+    }
+}
+impl ProtoRead for Blob {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => {
+                self.compression = BlobCompression::Raw;
+                self.data = pb.read_bytes();
+            }
+            2 => self.raw_size = pb.read_varint::<i32>(),
+            3 => {
+                self.compression = BlobCompression::Zlib;
+                self.data = pb.read_bytes();
+            }
+            4 => {
+                self.compression = BlobCompression::Lzma;
+                self.data = pb.read_bytes();
+            }
+            5 => {
+                self.compression = BlobCompression::Bzip2;
+                self.data = pb.read_bytes();
+            }
+            6 => {
+                self.compression = BlobCompression::Lz4;
+                self.data = pb.read_bytes();
+            }
+            7 => {
+                self.compression = BlobCompression::Zstd;
+                self.data = pb.read_bytes();
+            }
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,122 @@
+use crate::geometry::BBox;
+
+use pbf::{ProtoRead, Protobuf};
+
+use alloc::{string::String, vec::Vec};
+
+/// OSM Header Block
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OSMHeader {
+    /// The bounding box of the data in lon-lat (if provided)
+    pub bbox: Option<BBox>,
+    /// Features a parser must support to properly read the data
+    pub required_features: Vec<String>,
+    /// Features that are optional to support
+    pub optional_features: Vec<String>,
+    /// The program that wrote the file
+    pub writingprogram: Option<String>,
+    /// From the bbox field
+    pub source: Option<String>,
+    /// Replication timestamp, expressed in seconds since the epoch,
+    /// otherwise the same value as in the "timestamp=..." field
+    /// in the state.txt file used by Osmosis.
+    pub osmosis_replication_timestamp: Option<i64>,
+    /// Replication sequence number (sequenceNumber in state.txt).
+    pub osmosis_replication_sequence_number: Option<i64>,
+    /// Replication base URL (from Osmosis' configuration.txt file).
+    pub osmosis_replication_base_url: Option<String>,
+}
+
+/// The OSM Header Block
+/// A block containing OSM header information that helps guide the parser
+/// of the OSM data how to interpret the data.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct HeaderBlock {
+    /// The bounding box of the data
+    pub bbox: Option<HeaderBBox>,
+    /// Additional tags to aid in parsing this dataset
+    pub required_features: Vec<String>,
+    /// Optional features
+    pub optional_features: Vec<String>,
+    /// The program that wrote the file
+    pub writingprogram: Option<String>,
+    /// From the bbox field
+    pub source: Option<String>,
+    /// Replication timestamp
+    pub osmosis_replication_timestamp: Option<i64>,
+    /// Replication sequence number
+    pub osmosis_replication_sequence_number: Option<i64>,
+    /// Replication base URL
+    pub osmosis_replication_base_url: Option<String>,
+}
+impl HeaderBlock {
+    /// Read the header block's contents into a user friendly header
+    pub fn to_header(&self) -> OSMHeader {
+        OSMHeader {
+            bbox: self.bbox.as_ref().map(|b| b.to_bbox()),
+            required_features: self.required_features.clone(),
+            optional_features: self.optional_features.clone(),
+            writingprogram: self.writingprogram.clone(),
+            source: self.source.clone(),
+            osmosis_replication_timestamp: self.osmosis_replication_timestamp,
+            osmosis_replication_sequence_number: self.osmosis_replication_sequence_number,
+            osmosis_replication_base_url: self.osmosis_replication_base_url.clone(),
+        }
+    }
+}
+impl ProtoRead for HeaderBlock {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => {
+                let mut bbox = HeaderBBox::default();
+                pb.read_message(&mut bbox);
+                self.bbox = Some(bbox);
+            }
+            4 => self.required_features.push(pb.read_string()),
+            5 => self.optional_features.push(pb.read_string()),
+            16 => self.writingprogram = Some(pb.read_string()),
+            17 => self.source = Some(pb.read_string()),
+            32 => self.osmosis_replication_timestamp = Some(pb.read_varint::<i64>()),
+            33 => self.osmosis_replication_sequence_number = Some(pb.read_varint::<i64>()),
+            34 => self.osmosis_replication_base_url = Some(pb.read_string()),
+            _ => {}
+        }
+    }
+}
+
+/// The bounding box field in the OSM header. BBOX, as used in the OSM
+/// header. Units are always in nanodegrees -- they do not obey
+/// granularity rules.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct HeaderBBox {
+    /// left (min longitude) in nanodegrees
+    pub left: i64,
+    /// right (max longitude) in nanodegrees
+    pub right: i64,
+    /// top (max latitude) in nanodegrees
+    pub top: i64,
+    /// bottom (min latitude) in nanodegrees
+    pub bottom: i64,
+}
+impl HeaderBBox {
+    /// Returns the bounding box in degrees
+    pub fn to_bbox(&self) -> BBox {
+        BBox::new(
+            self.left as f64 * 1e-9,
+            self.bottom as f64 * 1e-9,
+            self.right as f64 * 1e-9,
+            self.top as f64 * 1e-9,
+        )
+    }
+}
+impl ProtoRead for HeaderBBox {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.left = pb.read_s_varint::<i64>(),
+            2 => self.right = pb.read_s_varint::<i64>(),
+            3 => self.top = pb.read_s_varint::<i64>(),
+            4 => self.bottom = pb.read_s_varint::<i64>(),
+            _ => {}
+        }
+    }
+}
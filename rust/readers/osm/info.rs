@@ -0,0 +1,135 @@
+use super::PrimitiveBlock;
+
+use pbf::{ProtoRead, Protobuf};
+use serde::{Deserialize, Serialize};
+
+use alloc::{string::String, vec::Vec};
+
+/// Info Block - decoded into an object
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct InfoBlock {
+    /// The version of the object
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+    /// The timestamp in milliseconds since the epoch
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+    /// The changeset id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changeset: Option<i64>,
+    /// The user id
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uid: Option<i32>,
+    /// The user name
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+    /// The visibility flag
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visible: Option<bool>,
+}
+
+/// Optional metadata that may be included into each primitive.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Info {
+    /// The version of the object
+    pub version: Option<i32>,
+    /// (millisec_stamp = timestamp*dateGranularity.)
+    pub timestamp: Option<i64>,
+    /// The changeset id
+    pub changeset: Option<i64>,
+    /// The user id
+    pub uid: Option<i32>,
+    /// String IDs for usernames.
+    pub user_sid: Option<u32>,
+    /// The visible flag is used to store history information. It indicates that
+    /// the current object version has been created by a delete operation on the
+    /// OSM API.
+    /// When a writer sets this flag, it MUST add a required_features tag with
+    /// value "HistoricalInformation" to the HeaderBlock.
+    /// If this flag is not available for some object it MUST be assumed to be
+    /// true if the file has the required_features tag "HistoricalInformation"
+    /// set.
+    pub visible: Option<bool>,
+}
+impl Info {
+    /// Access the info block's data as a user friendly struct
+    pub fn to_block(&self, pb: &PrimitiveBlock) -> InfoBlock {
+        InfoBlock {
+            version: self.version,
+            timestamp: self.timestamp.map(|t| t * pb.date_granularity as i64),
+            changeset: self.changeset,
+            uid: self.uid,
+            user: self.user_sid.map(|sid| pb.get_string(sid as usize)),
+            visible: Some(self.visible.unwrap_or(true)),
+        }
+    }
+}
+impl ProtoRead for Info {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.version = Some(pb.read_varint::<i32>()),
+            2 => self.timestamp = Some(pb.read_varint::<i64>()),
+            3 => self.changeset = Some(pb.read_varint::<i64>()),
+            4 => self.uid = Some(pb.read_varint::<i32>()),
+            5 => self.user_sid = Some(pb.read_varint::<u32>()),
+            6 => self.visible = Some(pb.read_varint::<bool>()),
+            _ => {}
+        }
+    }
+}
+
+/// Optional metadata that may be included into each primitive. Special dense format used in
+/// DenseNodes.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DenseInfo {
+    /// The versions
+    pub version: Vec<i32>,
+    /// DELTA coded (millisec_stamp = timestamp*dateGranularity.)
+    pub timestamp: Vec<i64>,
+    /// DELTA coded
+    pub changeset: Vec<i64>,
+    /// DELTA coded
+    pub uid: Vec<i32>,
+    /// String IDs for usernames. DELTA coded
+    pub user_sid: Vec<i32>,
+    /// See [`Info::visible`]
+    pub visible: Vec<bool>,
+}
+impl DenseInfo {
+    /// Decode the delta coded columns into individual Info objects
+    pub fn infos(&self) -> Vec<Info> {
+        let mut res = Vec::with_capacity(self.version.len());
+        let mut timestamp: i64 = 0;
+        let mut changeset: i64 = 0;
+        let mut uid: i32 = 0;
+        let mut user_sid: i32 = 0;
+        for (i, version) in self.version.iter().enumerate() {
+            timestamp = timestamp.wrapping_add(self.timestamp.get(i).copied().unwrap_or_default());
+            changeset = changeset.wrapping_add(self.changeset.get(i).copied().unwrap_or_default());
+            uid = uid.wrapping_add(self.uid.get(i).copied().unwrap_or_default());
+            user_sid = user_sid.wrapping_add(self.user_sid.get(i).copied().unwrap_or_default());
+            res.push(Info {
+                version: Some(*version),
+                timestamp: Some(timestamp),
+                changeset: Some(changeset),
+                uid: Some(uid),
+                user_sid: Some(user_sid as u32),
+                visible: self.visible.get(i).copied(),
+            });
+        }
+        res
+    }
+}
+impl ProtoRead for DenseInfo {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.version = pb.read_packed::<i32>(),
+            2 => self.timestamp = pb.read_s_packed::<i64>(),
+            3 => self.changeset = pb.read_s_packed::<i64>(),
+            4 => self.uid = pb.read_s_packed::<i32>(),
+            5 => self.user_sid = pb.read_s_packed::<i32>(),
+            6 => self.visible = pb.read_packed::<bool>(),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,958 @@
+/// Blob and BlobHeader parsing
+pub mod blob;
+/// OSM Header Block
+pub mod header_block;
+/// Info and DenseInfo blocks
+pub mod info;
+/// Node and DenseNodes parsing
+pub mod node;
+/// OSM XML and osmChange parsing
+pub mod osm_xml;
+/// Primitive Block, Groups and String Tables
+pub mod primitive;
+/// Relation parsing
+pub mod relation;
+/// Way parsing
+pub mod way;
+/// Protobuf wire format schemas of the PBF blocks
+mod wire;
+
+pub use blob::*;
+pub use header_block::*;
+pub use info::*;
+pub use node::*;
+pub use osm_xml::*;
+pub use primitive::*;
+pub use relation::*;
+pub use way::*;
+use wire::*;
+
+use crate::geometry::{PrimitiveValue, Properties, ValueType, VectorFeature, VectorPoint};
+use crate::readers::{FeatureIterator, Reader, XMLError};
+use crate::util::CompressError;
+
+use pbf::Protobuf;
+
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::ops::Bound::{Excluded, Unbounded};
+
+/// All OSM properties are key-value pairs where both are strings
+pub type OSMProperties = Properties;
+
+/// Errors that can occur while parsing OSM data
+#[derive(Debug, Clone, PartialEq)]
+pub enum OSMError {
+    /// A PBF blob failed to decompress
+    Compress(CompressError),
+    /// The OSM XML document isn't well-formed
+    XML(XMLError),
+    /// A PBF blob header, blob or the block it stores is truncated or malformed, or its size runs
+    /// past the end of the data. Stores the byte offset of the blob
+    InvalidBlob(usize),
+    /// An OSM XML primitive or member is missing its `id` or `ref`, or it isn't an integer
+    InvalidReference,
+}
+
+/// Filter types
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FilterType {
+    /// Applies to all types
+    #[default]
+    All,
+    /// Node
+    Node,
+    /// Way
+    Way,
+    /// Relation
+    Relation,
+}
+
+/// Filter map. Used internally by TagFilter. A `None` value matches any value of the key.
+pub type FilterMap = BTreeMap<String, Option<String>>;
+
+/// # Tag Filter
+///
+/// ## Description
+/// Builds a filter for the tags when parsing data.
+/// Can parse tags from nodes, ways and relations.
+/// Also allows the ability to add tags that apply to all object types.
+/// Can filter by key, but also both key and value.
+///
+/// ## Usage
+///
+/// Note that if you don't add a filter for a specific type (or for "All"), then that type will
+/// pass all key-value pairs through.
+/// ```rust
+/// use gistools::readers::{FilterType, TagFilter};
+///
+/// let mut filter = TagFilter::default();
+/// // add a node filter
+/// filter.add_filter(FilterType::Node, "foo", Some("bar"));
+/// // add a way filter
+/// filter.add_filter(FilterType::Way, "foo", Some("bar"));
+/// // add a relation filter that accepts any value
+/// filter.add_filter(FilterType::Relation, "foo", None);
+/// // add a filter that effects all types
+/// filter.add_filter(FilterType::All, "foo", Some("bar"));
+///
+/// assert!(filter.match_found(FilterType::Relation, "foo", "baz"));
+/// assert!(!filter.match_found(FilterType::Node, "foo", "baz"));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct TagFilter {
+    filters: FilterMap,
+    node_filters: FilterMap,
+    way_filters: FilterMap,
+    relation_filters: FilterMap,
+}
+impl TagFilter {
+    fn get_filter(&self, filter_type: FilterType) -> &FilterMap {
+        match filter_type {
+            FilterType::All => &self.filters,
+            FilterType::Node => &self.node_filters,
+            FilterType::Way => &self.way_filters,
+            FilterType::Relation => &self.relation_filters,
+        }
+    }
+
+    /// Add a filter. If no value is provided, any value of the key will match
+    pub fn add_filter(&mut self, filter_type: FilterType, key: &str, value: Option<&str>) {
+        let filter = match filter_type {
+            FilterType::All => &mut self.filters,
+            FilterType::Node => &mut self.node_filters,
+            FilterType::Way => &mut self.way_filters,
+            FilterType::Relation => &mut self.relation_filters,
+        };
+        filter.insert(key.to_string(), value.map(|v| v.to_string()));
+    }
+
+    /// Returns true if filters exist that apply to the filter type
+    pub fn has_filters(&self, filter_type: FilterType) -> bool {
+        !self.filters.is_empty() || !self.get_filter(filter_type).is_empty()
+    }
+
+    /// Check if a key-value pair matches a filter
+    pub fn match_found(&self, filter_type: FilterType, key: &str, value: &str) -> bool {
+        let is_match = |filter: &FilterMap| match filter.get(key) {
+            Some(Some(filter_value)) => filter_value == value,
+            Some(None) => true,
+            None => false,
+        };
+        // check all filters first then type-specific filters
+        is_match(&self.filters)
+            || (filter_type != FilterType::All && is_match(self.get_filter(filter_type)))
+    }
+
+    /// Returns true if any of the properties match a filter
+    pub fn match_properties(&self, filter_type: FilterType, properties: &OSMProperties) -> bool {
+        properties.iter().any(|(key, value)| match value {
+            ValueType::Primitive(PrimitiveValue::String(v)) => {
+                self.match_found(filter_type, key, v)
+            }
+            _ => false,
+        })
+    }
+}
+
+/// OSM Reader options
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct OSMReaderOptions {
+    /// if true, remove nodes that have no tags [Default = false]
+    pub remove_empty_nodes: Option<bool>,
+    /// If provided, filters the nodes, ways and relations by their tags
+    pub tag_filter: Option<TagFilter>,
+    /// If set to true, nodes will be skipped. [Default = false]
+    pub skip_nodes: Option<bool>,
+    /// If set to true, ways will be skipped. [Default = false]
+    pub skip_ways: Option<bool>,
+    /// If set to true, relations will be skipped. [Default = false]
+    pub skip_relations: Option<bool>,
+    /// If set to true, ways will be converted to areas if they are closed.
+    /// NOTE: They are upgraded anyways if the tag "area" is set to "yes".
+    /// [Default = false]
+    pub upgrade_ways_to_areas: Option<bool>,
+    /// If set to true, add a bbox property to each feature [Default = false]
+    pub add_bbox: Option<bool>,
+}
+
+/// Which primitives the iterator is currently yielding
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum IterPhase {
+    #[default]
+    Nodes,
+    Ways,
+    Relations,
+    Done,
+}
+
+/// # OSM Reader
+///
+/// ## Description
+/// Parses OSM PBF files as well as OSM XML (`.osm`) and osmChange (`.osc`) files. The format is
+/// detected from the first bytes of the input and all three produce the same nodes, ways,
+/// relations and features.
+/// Implements the [`FeatureIterator`] trait. If the data fails to parse, iteration stops and the
+/// error is stored in [`OSMReader::error`].
+/// PBF blocks are checked before they're decoded, so corrupted data is an error rather than a
+/// panic.
+///
+/// Relations tagged `type=multipolygon` or `type=boundary` are assembled into multipolygons.
+/// Relations that can't be assembled (missing members, unclosed rings or rings with fewer than 4
+/// nodes) are skipped as a whole and reported in [`OSMReader::broken_relations`].
+///
+/// When reading osmChange, each feature's metadata stores the `create`, `modify` or `delete`
+/// action it was listed under and every primitive's action is recorded in order in
+/// [`OSMReader::changes`], including deletions that carry no geometry.
+///
+/// NOTE: osmChange files only contain the primitives that changed, so ways and relations whose
+/// nodes weren't modified can't be resolved into features. Their changes are still recorded.
+///
+/// ## Usage
+/// ```rust,no_run
+/// use gistools::readers::{FileReader, OSMReader, OSMReaderOptions};
+/// use std::path::PathBuf;
+///
+/// let file_reader = FileReader::new(PathBuf::from("./data.osm.pbf")).unwrap();
+/// let mut reader = OSMReader::new(file_reader, OSMReaderOptions::default());
+///
+/// // pull out the header
+/// let header = reader.get_header().unwrap().unwrap();
+///
+/// // read the features
+/// for feature in reader {
+///     println!("{:?}", feature);
+/// }
+/// ```
+///
+/// ## Links
+/// - <https://wiki.openstreetmap.org/wiki/PBF_Format>
+/// - <https://github.com/openstreetmap/pbf/blob/master/OSM-binary.md>
+/// - <https://wiki.openstreetmap.org/wiki/OSM_XML>
+/// - <https://wiki.openstreetmap.org/wiki/OsmChange>
+#[derive(Debug)]
+pub struct OSMReader<T: Reader> {
+    reader: T,
+    /// if true, remove nodes that have no tags
+    pub remove_empty_nodes: bool,
+    /// If provided, filters the nodes, ways and relations by their tags
+    pub tag_filter: Option<TagFilter>,
+    /// If set to true, nodes will be skipped
+    pub skip_nodes: bool,
+    /// If set to true, ways will be skipped
+    pub skip_ways: bool,
+    /// If set to true, relations will be skipped
+    pub skip_relations: bool,
+    /// If set to true, ways will be converted to areas if they are closed.
+    pub upgrade_ways_to_areas: bool,
+    /// If set to true, add a bbox property to each feature
+    pub add_bbox: bool,
+    /// All node geometry (used to build ways and relations)
+    pub node_geometry: BTreeMap<u64, VectorPoint>,
+    /// All nodes that passed the filters
+    pub nodes: BTreeMap<u64, VectorFeature<OSMMetadata>>,
+    /// All way node references (used to build relations)
+    pub way_geometry: BTreeMap<u64, WayNodes>,
+    /// All ways that passed the filters
+    pub ways: BTreeMap<u64, IntermediateWay>,
+    /// All relations that passed the filters
+    pub relations: BTreeMap<u64, IntermediateRelation>,
+    /// Nodes that are a `label` or `admin_centre` of a relation
+    pub node_relation_pairs: BTreeMap<u64, IntermediateNodeMember>,
+    /// Relations that could not be assembled while iterating (e.g. unclosed rings)
+    pub broken_relations: BTreeMap<u64, RelationError>,
+    /// The osmChange actions in document order (empty unless reading osmChange)
+    pub changes: Vec<OSMChange>,
+    /// The error that stopped iteration if the data failed to parse
+    pub error: Option<OSMError>,
+    offset: usize,
+    parsed: bool,
+    phase: IterPhase,
+    cursor: Option<u64>,
+}
+impl<T: Reader> OSMReader<T> {
+    /// Create a new OSM Reader given an input reader and user defined options
+    pub fn new(reader: T, options: OSMReaderOptions) -> Self {
+        Self {
+            reader,
+            remove_empty_nodes: options.remove_empty_nodes.unwrap_or(false),
+            tag_filter: options.tag_filter,
+            skip_nodes: options.skip_nodes.unwrap_or(false),
+            skip_ways: options.skip_ways.unwrap_or(false),
+            skip_relations: options.skip_relations.unwrap_or(false),
+            upgrade_ways_to_areas: options.upgrade_ways_to_areas.unwrap_or(false),
+            add_bbox: options.add_bbox.unwrap_or(false),
+            node_geometry: BTreeMap::new(),
+            nodes: BTreeMap::new(),
+            way_geometry: BTreeMap::new(),
+            ways: BTreeMap::new(),
+            relations: BTreeMap::new(),
+            node_relation_pairs: BTreeMap::new(),
+            broken_relations: BTreeMap::new(),
+            changes: Vec::new(),
+            error: None,
+            offset: 0,
+            parsed: false,
+            phase: IterPhase::default(),
+            cursor: None,
+        }
+    }
+
+    /// Get the header of the OSM file if it exists
+    pub fn get_header(&mut self) -> Result<Option<OSMHeader>, OSMError> {
+        if self.is_xml() {
+            return self.read_xml_header();
+        }
+        self.offset = 0;
+        let Some((blob_header, blob)) = self.next_blob()? else {
+            return Ok(None);
+        };
+        if blob_header._type != "OSMHeader" {
+            return Ok(None);
+        }
+        let data = blob.data().map_err(OSMError::Compress)?;
+        if !is_valid_message(&data, header_block_schema) {
+            return Err(OSMError::InvalidBlob(0));
+        }
+        let mut pbf = Protobuf::from(data);
+        let mut header_block = HeaderBlock::default();
+        pbf.read_fields(&mut header_block, None);
+
+        Ok(Some(header_block.to_header()))
+    }
+
+    /// Get a node by its id
+    pub fn get_node(&self, id: u64) -> Option<&VectorFeature<OSMMetadata>> {
+        self.nodes.get(&id)
+    }
+
+    /// Get a way by its id
+    pub fn get_way(&self, id: u64) -> Option<&IntermediateWay> {
+        self.ways.get(&id)
+    }
+
+    /// Get a relation by its id
+    pub fn get_relation(&self, id: u64) -> Option<&IntermediateRelation> {
+        self.relations.get(&id)
+    }
+
+    /// Parse all the blocks of the file, storing the nodes, ways and relations that pass the
+    /// filters. This is called automatically when iterating if it hasn't been called yet.
+    /// A failure is also stored in `error`.
+    pub fn parse(&mut self) -> Result<(), OSMError> {
+        let result = self.read_all();
+        if let Err(err) = &result {
+            self.error = Some(err.clone());
+        }
+        result
+    }
+
+    /// Read the XML document or every PBF blob
+    fn read_all(&mut self) -> Result<(), OSMError> {
+        if self.is_xml() {
+            self.read_xml()?;
+        } else {
+            self.offset = 0;
+            loop {
+                let start = self.offset;
+                let Some((blob_header, blob)) = self.next_blob()? else { break };
+                if blob_header._type != "OSMData" {
+                    continue;
+                }
+                let data = blob.data().map_err(OSMError::Compress)?;
+                if !is_valid_message(&data, primitive_block_schema) {
+                    return Err(OSMError::InvalidBlob(start));
+                }
+                self.read_block(data);
+            }
+        }
+        self.parsed = true;
+        self.phase = IterPhase::default();
+        self.cursor = None;
+
+        Ok(())
+    }
+
+    /// Read the next blob header and blob if they exist. Sizes that run past the end of the data
+    /// are an error.
+    fn next_blob(&mut self) -> Result<Option<(BlobHeader, Blob)>, OSMError> {
+        let Self { reader, offset, .. } = self;
+        let start = *offset;
+        // if we've already read all the data, return None
+        if start == reader.len() {
+            return Ok(None);
+        }
+        let invalid = || OSMError::InvalidBlob(start);
+        // STEP 1: Get blob header
+        let header_start =
+            start.checked_add(4).filter(|s| *s <= reader.len()).ok_or_else(invalid)?;
+        let length = reader.uint32_be(Some(start)) as usize;
+        let header_end = checked_end(header_start, length, reader.len()).ok_or_else(invalid)?;
+        let data = reader.slice(Some(header_start), Some(header_end));
+        if !is_valid_message(&data, blob_header_schema) {
+            return Err(invalid());
+        }
+        let mut pbf = Protobuf::from(data);
+        let mut blob_header = BlobHeader::default();
+        pbf.read_fields(&mut blob_header, None);
+        // STEP 2: Get blob data
+        let datasize = usize::try_from(blob_header.datasize).map_err(|_| invalid())?;
+        let blob_end = checked_end(header_end, datasize, reader.len()).ok_or_else(invalid)?;
+        let data = reader.slice(Some(header_end), Some(blob_end));
+        if !is_valid_message(&data, blob_schema) {
+            return Err(invalid());
+        }
+        let mut pbf = Protobuf::from(data);
+        let mut blob = Blob::default();
+        pbf.read_fields(&mut blob, None);
+        *offset = blob_end;
+
+        Ok(Some((blob_header, blob)))
+    }
+
+    /// Check if a primitive should be filtered out given its properties
+    fn is_filtered(&self, filter_type: FilterType, properties: &OSMProperties) -> bool {
+        match &self.tag_filter {
+            Some(filter) if filter.has_filters(filter_type) => {
+                !filter.match_properties(filter_type, properties)
+            }
+            _ => false,
+        }
+    }
+
+    /// Parse a decompressed primitive block and store its contents
+    fn read_block(&mut self, data: Vec<u8>) {
+        let mut pbf = Protobuf::from(data);
+        let mut pb = PrimitiveBlock::default();
+        pbf.read_fields(&mut pb, None);
+        let skip_wr = self.skip_ways && self.skip_relations;
+
+        for group in pb.groups() {
+            let dense_nodes = group.dense.as_ref().map(|d| d.nodes()).unwrap_or_default();
+            for node in group.nodes.iter().chain(dense_nodes.iter()) {
+                let properties = node.properties(&pb);
+                let coordinates = node.to_vector_point(&pb, &properties);
+                self.add_node(node.id as u64, coordinates, properties, node.metadata(&pb));
+            }
+            if skip_wr {
+                continue;
+            }
+            for way in &group.ways {
+                let properties = way.properties(&pb);
+                if let Some(iw) =
+                    way.to_intermediate_feature(&pb, properties, self.upgrade_ways_to_areas)
+                {
+                    self.add_way(iw);
+                }
+            }
+            if self.skip_relations {
+                continue;
+            }
+            for relation in &group.relations {
+                let properties = relation.properties(&pb);
+                if let Some(ir) = relation.to_intermediate_feature(&pb, properties) {
+                    self.add_relation(ir);
+                }
+            }
+        }
+    }
+
+    /// Store a node's geometry (used to build ways and relations) and its feature if it passes
+    /// the filters
+    fn add_node(
+        &mut self,
+        id: u64,
+        coordinates: VectorPoint,
+        properties: OSMProperties,
+        metadata: OSMMetadata,
+    ) {
+        if !self.skip_ways || !self.skip_relations {
+            self.node_geometry.insert(id, coordinates.clone());
+        }
+        let filtered = self.skip_nodes
+            || (self.remove_empty_nodes && properties.is_empty())
+            || self.is_filtered(FilterType::Node, &properties);
+        if !filtered {
+            let feature =
+                node_to_vector_feature(id, coordinates, properties, metadata, self.add_bbox);
+            self.nodes.insert(id, feature);
+        }
+    }
+
+    /// Store a way's node references (used to build relations) and the way if it passes the
+    /// filters
+    fn add_way(&mut self, way: IntermediateWay) {
+        if !self.skip_relations {
+            self.way_geometry.insert(way.id, way.way_nodes.clone());
+        }
+        if !self.skip_ways && !self.is_filtered(FilterType::Way, &way.properties) {
+            self.ways.insert(way.id, way);
+        }
+    }
+
+    /// Store a relation's labelled nodes and the relation if it passes the filters
+    fn add_relation(&mut self, relation: IntermediateRelation) {
+        if self.skip_relations {
+            return;
+        }
+        for member in get_node_relation_pairs(&relation.members) {
+            self.node_relation_pairs.insert(member.node, member);
+        }
+        if !self.is_filtered(FilterType::Relation, &relation.properties) {
+            self.relations.insert(relation.id, relation);
+        }
+    }
+
+    /// Merge an associated relation's role and properties into a node if it exists
+    fn merge_relation_if_exists(&self, feature: &mut VectorFeature<OSMMetadata>) {
+        let Some(pair) = feature.id.and_then(|id| self.node_relation_pairs.get(&id)) else {
+            return;
+        };
+        let Some(relation) = self.relations.get(&pair.relation_id) else {
+            return;
+        };
+        if let Some(metadata) = feature.metadata.as_mut() {
+            metadata.relation = Some(OSMRelationMetadata {
+                role: pair.role.clone(),
+                properties: relation.properties.clone(),
+            });
+        }
+    }
+}
+
+/// The end of `length` bytes starting at `start` if it's within `len`
+fn checked_end(start: usize, length: usize, len: usize) -> Option<usize> {
+    start.checked_add(length).filter(|end| *end <= len)
+}
+
+/// Find the next key in the map after the cursor
+fn next_key<V>(map: &BTreeMap<u64, V>, cursor: Option<u64>) -> Option<u64> {
+    match cursor {
+        Some(cursor) => map.range((Excluded(cursor), Unbounded)).next().map(|(k, _)| *k),
+        None => map.keys().next().copied(),
+    }
+}
+
+impl<T: Reader> Iterator for OSMReader<T> {
+    type Item = VectorFeature<OSMMetadata>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.parsed && self.parse().is_err() {
+            self.parsed = true;
+            self.phase = IterPhase::Done;
+        }
+        loop {
+            match self.phase {
+                IterPhase::Nodes => {
+                    let key =
+                        if self.skip_nodes { None } else { next_key(&self.nodes, self.cursor) };
+                    let Some(key) = key else {
+                        self.phase = IterPhase::Ways;
+                        self.cursor = None;
+                        continue;
+                    };
+                    self.cursor = Some(key);
+                    let mut node = self.nodes.get(&key).cloned()?;
+                    self.merge_relation_if_exists(&mut node);
+                    return Some(node);
+                }
+                IterPhase::Ways => {
+                    let key = if self.skip_ways { None } else { next_key(&self.ways, self.cursor) };
+                    let Some(key) = key else {
+                        self.phase = IterPhase::Relations;
+                        self.cursor = None;
+                        continue;
+                    };
+                    self.cursor = Some(key);
+                    let way = intermediate_way_to_vector_feature(
+                        self.ways.get(&key)?,
+                        &self.node_geometry,
+                        self.add_bbox,
+                    );
+                    if way.is_some() {
+                        return way;
+                    }
+                }
+                IterPhase::Relations => {
+                    let key = if self.skip_relations {
+                        None
+                    } else {
+                        next_key(&self.relations, self.cursor)
+                    };
+                    let Some(key) = key else {
+                        self.phase = IterPhase::Done;
+                        self.cursor = None;
+                        continue;
+                    };
+                    self.cursor = Some(key);
+                    let relation = intermediate_relation_to_vector_feature(
+                        self.relations.get(&key)?,
+                        &self.node_geometry,
+                        &self.way_geometry,
+                        self.add_bbox,
+                    );
+                    match relation {
+                        Ok(relation) => return Some(relation),
+                        Err(RelationError::NoGeometry) => {}
+                        Err(err) => {
+                            self.broken_relations.insert(key, err);
+                        }
+                    }
+                }
+                IterPhase::Done => return None,
+            }
+        }
+    }
+}
+
+impl<T: Reader> FeatureIterator<OSMMetadata> for OSMReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature<OSMMetadata>> {
+        self.next()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{
+        BBox, PrimitiveValue, ValueType, VectorGeometry, VectorGeometryType, VectorPoint,
+        VectorPointGeometry,
+    };
+    use crate::readers::BufferReader;
+    use alloc::{vec, vec::Vec};
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn read_fixture(name: &str) -> Vec<u8> {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/osm/fixtures");
+        path.push(name);
+        fs::read(&path).expect("Failed to read file expected")
+    }
+
+    #[test]
+    fn parse_basic_case() {
+        let data = read_fixture("test.pbf");
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+
+        let header = reader.get_header().unwrap().unwrap();
+        assert_eq!(
+            header,
+            OSMHeader {
+                required_features: vec!["OsmSchema-V0.6".into(), "DenseNodes".into()],
+                writingprogram: Some("0.40.1".into()),
+                ..Default::default()
+            }
+        );
+
+        let features: Vec<VectorFeature<OSMMetadata>> = reader.collect();
+        assert_eq!(features.len(), 8);
+        let ids: Vec<u64> = features.iter().map(|f| f.id.unwrap()).collect();
+        assert_eq!(
+            ids,
+            vec![275452090, 304994979, 304994980, 304994981, 319408586, 319408587, 27776903, 56688]
+        );
+
+        // node
+        let cafe = &features[0];
+        assert_eq!(
+            cafe.geometry,
+            VectorGeometry::Point(VectorPointGeometry {
+                _type: VectorGeometryType::Point,
+                coordinates: VectorPoint::new(-0.10761860000000001, 51.5075933, None, None),
+                ..Default::default()
+            })
+        );
+        let mut properties = OSMProperties::new();
+        properties
+            .insert("amenity".into(), ValueType::Primitive(PrimitiveValue::String("cafe".into())));
+        properties.insert(
+            "name".into(),
+            ValueType::Primitive(PrimitiveValue::String("Jam's Sandwich Bar".into())),
+        );
+        assert_eq!(cafe.properties, properties);
+        assert_eq!(
+            cafe.metadata,
+            Some(OSMMetadata {
+                _type: OSMType::Node,
+                info: InfoBlock {
+                    version: Some(3),
+                    timestamp: Some(1256818475000),
+                    changeset: Some(2980587),
+                    uid: Some(1697),
+                    user: Some("nickb".into()),
+                    visible: Some(true),
+                },
+                ..Default::default()
+            })
+        );
+
+        // way
+        let way = &features[6];
+        let VectorGeometry::LineString(line) = &way.geometry else {
+            panic!("expected a linestring");
+        };
+        assert_eq!(line.coordinates.len(), 5);
+        assert_eq!(
+            line.coordinates[0],
+            VectorPoint::new(-0.10833480000000001, 51.507406, None, None)
+        );
+        assert_eq!(
+            way.properties.get("name"),
+            Some(&ValueType::Primitive(PrimitiveValue::String("üßé€".into())))
+        );
+        assert_eq!(way.metadata.as_ref().unwrap()._type, OSMType::Way);
+
+        // relation
+        let relation = &features[7];
+        assert_eq!(relation.geometry, way.geometry);
+        assert_eq!(
+            relation.metadata.as_ref().unwrap().nodes,
+            Some(vec![IntermediateNodeMember {
+                relation_id: 56688,
+                role: "".into(),
+                node: 319408586
+            }])
+        );
+    }
+
+    #[test]
+    fn parse_with_tag_filter() {
+        let data = read_fixture("test.pbf");
+        let mut tag_filter = TagFilter::default();
+        tag_filter.add_filter(FilterType::All, "amenity", Some("cafe"));
+        let reader = OSMReader::new(
+            BufferReader::from(&data[..]),
+            OSMReaderOptions { tag_filter: Some(tag_filter), ..Default::default() },
+        );
+
+        let features: Vec<VectorFeature<OSMMetadata>> = reader.collect();
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].id, Some(275452090));
+    }
+
+    #[test]
+    fn parse_only_nodes() {
+        let data = read_fixture("test.pbf");
+        let reader = OSMReader::new(
+            BufferReader::from(&data[..]),
+            OSMReaderOptions {
+                remove_empty_nodes: Some(true),
+                skip_ways: Some(true),
+                skip_relations: Some(true),
+                ..Default::default()
+            },
+        );
+
+        let features: Vec<VectorFeature<OSMMetadata>> = reader.collect();
+        let ids: Vec<u64> = features.iter().map(|f| f.id.unwrap()).collect();
+        assert_eq!(ids, vec![275452090, 304994980]);
+    }
+
+    #[test]
+    fn parse_truncated() {
+        let data = read_fixture("test.pbf");
+        let mut reader = OSMReader::new(
+            BufferReader::from(&data[..data.len() - 10]),
+            OSMReaderOptions::default(),
+        );
+        assert!(reader.next().is_none());
+        assert!(matches!(reader.error, Some(OSMError::InvalidBlob(_))));
+        assert!(reader.next().is_none());
+
+        // a blob header size larger than the data
+        let mut reader = OSMReader::new(
+            BufferReader::from(&[0xff, 0xff, 0xff, 0xff, 0x0a][..]),
+            OSMReaderOptions::default(),
+        );
+        assert_eq!(reader.parse(), Err(OSMError::InvalidBlob(0)));
+        assert_eq!(reader.get_header(), Err(OSMError::InvalidBlob(0)));
+    }
+
+    /// Frame raw (uncompressed) blocks as a PBF file
+    fn raw_file(blocks: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut data = vec![];
+        for (_type, block) in blocks {
+            let mut blob = Protobuf::new();
+            blob.write_bytes_field(1, block);
+            let blob = blob.take();
+            let mut header = Protobuf::new();
+            header.write_string_field(1, _type);
+            header.write_varint_field(3, blob.len());
+            let header = header.take();
+            data.extend((header.len() as u32).to_be_bytes());
+            data.extend(header);
+            data.extend(blob);
+        }
+        data
+    }
+
+    #[test]
+    fn parse_corrupted_blocks() {
+        // a bbox with an invalid UTF-8 writing program
+        let data = raw_file(&[("OSMHeader", &[0x82, 0x01, 0x02, 0xff, 0xfe])]);
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        assert_eq!(reader.get_header(), Err(OSMError::InvalidBlob(0)));
+
+        let header = raw_file(&[("OSMHeader", &[])]);
+        let corrupted: [&[u8]; 5] = [
+            // a string table that runs past the end of the block
+            &[0x0a, 0x05, 0x0a],
+            // a truncated granularity varint
+            &[0x88, 0x01, 0x80],
+            // a node id with the wire type of a group
+            &[0x12, 0x03, 0x0a, 0x01, 0x0b],
+            // a dense node uid out of the i32 range
+            &[
+                0x12, 0x0f, 0x12, 0x0d, 0x2a, 0x0b, 0x22, 0x09, 0xfe, 0xff, 0xff, 0xff, 0xff, 0xff,
+                0xff, 0xff, 0x01,
+            ],
+            // a way with a truncated packed refs field
+            &[0x12, 0x06, 0x1a, 0x04, 0x42, 0x02, 0x02, 0x80],
+        ];
+        for block in corrupted {
+            let data = [&header[..], &raw_file(&[("OSMData", block)])].concat();
+            let mut reader =
+                OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+            assert_eq!(reader.get_header(), Ok(Some(OSMHeader::default())));
+            assert_eq!(reader.next(), None);
+            assert_eq!(reader.error, Some(OSMError::InvalidBlob(header.len())));
+        }
+
+        // a malformed blob header
+        let mut data = raw_file(&[("OSMHeader", &[])]);
+        data[4] = 0x0b;
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        assert_eq!(reader.parse(), Err(OSMError::InvalidBlob(0)));
+    }
+
+    #[test]
+    fn parse_bounds() {
+        let data = read_fixture("bounds.osm.pbf");
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        let header = reader.get_header().unwrap().unwrap();
+        assert!(header.bbox.is_some());
+    }
+
+    #[test]
+    fn parse_xml_matches_pbf() {
+        let xml = read_fixture("bounds.xml");
+        let mut xml_reader =
+            OSMReader::new(BufferReader::from(&xml[..]), OSMReaderOptions::default());
+        let pbf = read_fixture("bounds.osm.pbf");
+        let pbf_reader = OSMReader::new(BufferReader::from(&pbf[..]), OSMReaderOptions::default());
+
+        let header = xml_reader.get_header().unwrap().unwrap();
+        assert_eq!(header.bbox, Some(BBox::new(0., 0., 15., 15.)));
+        assert_eq!(header.writingprogram, Some("test".into()));
+
+        let xml_features: Vec<VectorFeature<OSMMetadata>> = xml_reader.collect();
+        let pbf_features: Vec<VectorFeature<OSMMetadata>> = pbf_reader.collect();
+        assert_eq!(xml_features.len(), 7);
+        assert_eq!(xml_features, pbf_features);
+
+        // changeset documents have no primitives
+        let data = read_fixture("changeset.xml");
+        let reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        assert_eq!(reader.count(), 0);
+    }
+
+    #[test]
+    fn parse_xml_malformed() {
+        let data = br#"<osm generator="test"><node id="1" lat="1" lon="2"/><way id="2"><nd ref="x"/></way></osm>"#;
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        assert_eq!(reader.parse(), Err(OSMError::InvalidReference));
+        assert_eq!(reader.error, Some(OSMError::InvalidReference));
+
+        let data = br#"<osm><relation id="3"><member type="way" role="outer"/></relation></osm>"#;
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        assert_eq!(reader.next(), None);
+        assert_eq!(reader.error, Some(OSMError::InvalidReference));
+
+        let data = br#"<osm generator="test"><node id="1" lat="1" lon="2"></osm>"#;
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        assert_eq!(reader.next(), None);
+        assert!(matches!(reader.error, Some(OSMError::XML(XMLError::MismatchedEndTag(_)))));
+    }
+
+    #[test]
+    fn parse_osm_change() {
+        let data = br#"<?xml version="1.0" encoding="UTF-8"?>
+<osmChange version="0.6" generator="editor">
+  <create>
+    <node id="1" version="1" timestamp="2024-01-01T00:00:00Z" uid="5" user="me" changeset="9" lat="1" lon="2">
+      <tag k="amenity" v="cafe"/>
+    </node>
+    <node id="2" version="1" changeset="9" lat="2" lon="2"/>
+    <way id="10" version="1" changeset="9">
+      <nd ref="1"/>
+      <nd ref="2"/>
+      <tag k="highway" v="path"/>
+    </way>
+  </create>
+  <modify>
+    <node id="3" version="4" changeset="9" lat="3" lon="3"/>
+  </modify>
+  <delete>
+    <way id="11" version="3" changeset="9" visible="false"/>
+    <node id="4" version="2" changeset="9" visible="false"/>
+  </delete>
+</osmChange>"#;
+        let mut reader = OSMReader::new(BufferReader::from(&data[..]), OSMReaderOptions::default());
+        assert_eq!(reader.get_header().unwrap().unwrap().writingprogram, Some("editor".into()));
+
+        let features: Vec<VectorFeature<OSMMetadata>> = reader.by_ref().collect();
+        let actions: Vec<(u64, Option<OSMAction>)> =
+            features.iter().map(|f| (f.id.unwrap(), f.metadata.as_ref().unwrap().action)).collect();
+        assert_eq!(
+            actions,
+            vec![
+                (1, Some(OSMAction::Create)),
+                (2, Some(OSMAction::Create)),
+                (3, Some(OSMAction::Modify)),
+                (10, Some(OSMAction::Create)),
+            ]
+        );
+        assert_eq!(
+            features[0].metadata.as_ref().unwrap().info,
+            InfoBlock {
+                version: Some(1),
+                timestamp: Some(1_704_067_200_000),
+                changeset: Some(9),
+                uid: Some(5),
+                user: Some("me".into()),
+                visible: Some(true),
+            }
+        );
+
+        let changes: Vec<(OSMType, u64, OSMAction)> =
+            reader.changes.iter().map(|c| (c._type, c.id, c.action)).collect();
+        assert_eq!(
+            changes,
+            vec![
+                (OSMType::Node, 1, OSMAction::Create),
+                (OSMType::Node, 2, OSMAction::Create),
+                (OSMType::Way, 10, OSMAction::Create),
+                (OSMType::Node, 3, OSMAction::Modify),
+                (OSMType::Way, 11, OSMAction::Delete),
+                (OSMType::Node, 4, OSMAction::Delete),
+            ]
+        );
+        assert_eq!(reader.changes[4].info.visible, Some(false));
+    }
+
+    #[test]
+    fn tag_filter() {
+        let mut filter = TagFilter::default();
+        assert!(!filter.has_filters(FilterType::Way));
+        filter.add_filter(FilterType::Way, "highway", None);
+        assert!(filter.has_filters(FilterType::Way));
+        assert!(!filter.has_filters(FilterType::Node));
+        assert!(filter.match_found(FilterType::Way, "highway", "service"));
+        assert!(!filter.match_found(FilterType::Node, "highway", "service"));
+        assert!(!filter.match_found(FilterType::All, "highway", "service"));
+    }
+}
@@ -0,0 +1,200 @@
+use super::{DenseInfo, Info, OSMMetadata, OSMProperties, OSMType, PrimitiveBlock};
+use crate::geometry::{
+    BBox3D, PrimitiveValue, ValueType, VectorFeature, VectorGeometry, VectorGeometryType,
+    VectorPoint, VectorPointGeometry,
+};
+
+use pbf::{ProtoRead, Protobuf};
+
+use alloc::{string::String, vec::Vec};
+
+/// Node class
+/// contains a single node.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Node {
+    /// The node id
+    pub id: i64,
+    /// Optional info block
+    pub info: Option<Info>,
+    /// The latitude in granularity units
+    pub lat: i64,
+    /// The longitude in granularity units
+    pub lon: i64,
+    /// String IDs for the keys
+    pub keys: Vec<u32>,
+    /// String IDs for the values
+    pub vals: Vec<u32>,
+}
+impl Node {
+    /// Get the properties of the node
+    pub fn properties(&self, pb: &PrimitiveBlock) -> OSMProperties {
+        pb.tags(&self.keys, &self.vals)
+    }
+
+    /// Gain access to the nodes geometry
+    pub fn to_vector_point(&self, pb: &PrimitiveBlock, properties: &OSMProperties) -> VectorPoint {
+        VectorPoint::new(pb.lon(self.lon), pb.lat(self.lat), parse_z(properties), None)
+    }
+
+    /// Build the node's feature metadata
+    pub fn metadata(&self, pb: &PrimitiveBlock) -> OSMMetadata {
+        OSMMetadata {
+            _type: OSMType::Node,
+            info: self.info.as_ref().map(|i| i.to_block(pb)).unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+
+    /// Convert the node to a vector feature
+    pub fn to_vector_feature(
+        &self,
+        pb: &PrimitiveBlock,
+        properties: OSMProperties,
+        add_bbox: bool,
+    ) -> VectorFeature<OSMMetadata> {
+        let coordinates = self.to_vector_point(pb, &properties);
+        let metadata = self.metadata(pb);
+
+        node_to_vector_feature(self.id as u64, coordinates, properties, metadata, add_bbox)
+    }
+}
+
+/// Build a node's point feature given its resolved coordinates
+pub fn node_to_vector_feature(
+    id: u64,
+    coordinates: VectorPoint,
+    properties: OSMProperties,
+    metadata: OSMMetadata,
+    add_bbox: bool,
+) -> VectorFeature<OSMMetadata> {
+    let bbox = if add_bbox { Some(BBox3D::from_point(&coordinates)) } else { None };
+    let geometry = VectorGeometry::Point(VectorPointGeometry {
+        _type: VectorGeometryType::Point,
+        is_3d: coordinates.z.is_some(),
+        coordinates,
+        bbox,
+        ..Default::default()
+    });
+
+    VectorFeature::new_wm(Some(id), properties, geometry, Some(metadata))
+}
+impl ProtoRead for Node {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.id = pb.read_s_varint::<i64>(),
+            2 => self.keys = pb.read_packed::<u32>(),
+            3 => self.vals = pb.read_packed::<u32>(),
+            4 => {
+                let mut info = Info::default();
+                pb.read_message(&mut info);
+                self.info = Some(info);
+            }
+            8 => self.lat = pb.read_s_varint::<i64>(),
+            9 => self.lon = pb.read_s_varint::<i64>(),
+            _ => {}
+        }
+    }
+}
+
+/// Used to densly represent a sequence of nodes that do not have any tags.
+/// We represent these nodes columnwise as five columns: ID's, lats, and
+/// lons, all delta coded. When metadata is not omitted,
+/// We encode keys & vals for all nodes as a single array of integers
+/// containing key-stringid and val-stringid, using a stringid of 0 as a
+/// delimiter between nodes.
+///    `( (<keyid> <valid>)* '0' )*`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct DenseNodes {
+    /// DELTA coded
+    pub ids: Vec<i64>,
+    /// Optional dense info
+    pub denseinfo: Option<DenseInfo>,
+    /// DELTA coded
+    pub lats: Vec<i64>,
+    /// DELTA coded
+    pub lons: Vec<i64>,
+    /// Special packing of keys and vals into one array. May be empty if all nodes in this block
+    /// are tagless.
+    pub keys_vals: Vec<i32>,
+}
+impl DenseNodes {
+    /// Access the nodes in this block
+    pub fn nodes(&self) -> Vec<Node> {
+        let mut res = Vec::with_capacity(self.ids.len());
+        let mut infos = self.denseinfo.as_ref().map(|di| di.infos()).unwrap_or_default();
+        infos.reverse();
+        let mut j = 0;
+        let mut cur_id: i64 = 0;
+        let mut cur_lat: i64 = 0;
+        let mut cur_lon: i64 = 0;
+        for i in 0..self.ids.len() {
+            cur_id = cur_id.wrapping_add(self.ids[i]);
+            cur_lat = cur_lat.wrapping_add(self.lats.get(i).copied().unwrap_or_default());
+            cur_lon = cur_lon.wrapping_add(self.lons.get(i).copied().unwrap_or_default());
+            let mut keys = Vec::new();
+            let mut vals = Vec::new();
+            if !self.keys_vals.is_empty() {
+                while j < self.keys_vals.len() && self.keys_vals[j] != 0 {
+                    keys.push(self.keys_vals[j] as u32);
+                    vals.push(self.keys_vals.get(j + 1).copied().unwrap_or_default() as u32);
+                    j += 2;
+                }
+                j += 1;
+            }
+            res.push(Node {
+                id: cur_id,
+                info: infos.pop(),
+                lat: cur_lat,
+                lon: cur_lon,
+                keys,
+                vals,
+            });
+        }
+
+        res
+    }
+}
+impl ProtoRead for DenseNodes {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.ids = pb.read_s_packed::<i64>(),
+            5 => {
+                let mut denseinfo = DenseInfo::default();
+                pb.read_message(&mut denseinfo);
+                self.denseinfo = Some(denseinfo);
+            }
+            8 => self.lats = pb.read_s_packed::<i64>(),
+            9 => self.lons = pb.read_s_packed::<i64>(),
+            10 => self.keys_vals = pb.read_packed::<i32>(),
+            _ => {}
+        }
+    }
+}
+
+/// If the node has altitude or something defining its z position, find it.
+/// Checks `ele`, `height`, `altitude`, `elevation` and `depth` (in that order).
+pub(crate) fn parse_z(properties: &OSMProperties) -> Option<f64> {
+    for key in ["ele", "height", "altitude", "elevation", "depth"] {
+        if let Some(ValueType::Primitive(PrimitiveValue::String(value))) = properties.get(key) {
+            if let Some(z) = parse_altitude(value) {
+                return Some(if key == "depth" { -z } else { z });
+            }
+        }
+    }
+    None
+}
+
+/// Parse an altitude string assuming it is in meters.
+/// Common inputs: `246`, `246.62`, `246,62 m`, `-12m`
+fn parse_altitude(alt: &str) -> Option<f64> {
+    let mut num = String::new();
+    for c in alt.trim().chars() {
+        match c {
+            '0'..='9' | '.' => num.push(c),
+            ',' => num.push('.'),
+            '-' | '+' if num.is_empty() => num.push(c),
+            _ => break,
+        }
+    }
+    num.parse::<f64>().ok()
+}
@@ -0,0 +1,255 @@
+use super::{
+    node::parse_z, InfoBlock, IntermediateMember, IntermediateNodeMember, IntermediateRelation,
+    IntermediateWay, IntermediateWayMember, OSMError, OSMHeader, OSMMetadata, OSMProperties,
+    OSMReader, OSMType,
+};
+use crate::geometry::{BBox, PrimitiveValue, ValueType, VectorPoint};
+use crate::readers::{
+    gbfs::parse_rfc3339, OwnedBufferReader, Reader, XMLEvent, XMLNode, XMLReader,
+};
+
+use serde::{Deserialize, Serialize};
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The action of an osmChange block
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OSMAction {
+    /// The primitive was created
+    #[serde(rename = "create")]
+    Create,
+    /// The primitive was modified
+    #[serde(rename = "modify")]
+    Modify,
+    /// The primitive was deleted
+    #[serde(rename = "delete")]
+    Delete,
+}
+impl OSMAction {
+    /// Get the action of an osmChange block given its element name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "create" => Some(OSMAction::Create),
+            "modify" => Some(OSMAction::Modify),
+            "delete" => Some(OSMAction::Delete),
+            _ => None,
+        }
+    }
+}
+
+/// A primitive listed in an osmChange file
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct OSMChange {
+    /// The type of primitive
+    #[serde(rename = "type")]
+    pub _type: OSMType,
+    /// The primitive's id
+    pub id: u64,
+    /// The action to apply
+    pub action: OSMAction,
+    /// The info block of the primitive after the change
+    pub info: InfoBlock,
+}
+
+impl<T: Reader> OSMReader<T> {
+    /// Check if the input is an OSM XML document. PBF files start with the big-endian length of
+    /// their first blob header so the first byte is never `<`
+    pub(crate) fn is_xml(&mut self) -> bool {
+        for i in 0..self.reader.len() {
+            match self.reader.uint8(Some(i)) {
+                // whitespace and the UTF-8 byte order mark
+                b' ' | b'\t' | b'\r' | b'\n' | 0xEF | 0xBB | 0xBF => {}
+                byte => return byte == b'<',
+            }
+        }
+        false
+    }
+
+    /// Build the header from the root's `generator` and the `<bounds>` element if present
+    pub(crate) fn read_xml_header(&mut self) -> Result<Option<OSMHeader>, OSMError> {
+        let mut xml = self.xml_document();
+        let mut header = loop {
+            match xml.next_event().map_err(OSMError::XML)? {
+                XMLEvent::Start(root) => {
+                    let writingprogram = root.attribute("generator").map(String::from);
+                    break OSMHeader { writingprogram, ..Default::default() };
+                }
+                XMLEvent::Eof => return Ok(None),
+                _ => {}
+            }
+        };
+        loop {
+            match xml.next_event().map_err(OSMError::XML)? {
+                XMLEvent::Start(bounds) if bounds.local_name() == "bounds" => {
+                    let coord = |name| bounds.attribute(name).and_then(|v| v.parse::<f64>().ok());
+                    if let (Some(minlon), Some(minlat), Some(maxlon), Some(maxlat)) =
+                        (coord("minlon"), coord("minlat"), coord("maxlon"), coord("maxlat"))
+                    {
+                        header.bbox = Some(BBox::new(minlon, minlat, maxlon, maxlat));
+                    }
+                    break;
+                }
+                XMLEvent::Start(_) | XMLEvent::End(_) | XMLEvent::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(Some(header))
+    }
+
+    /// Parse an OSM XML or osmChange document, storing its nodes, ways and relations
+    pub(crate) fn read_xml(&mut self) -> Result<(), OSMError> {
+        self.changes.clear();
+        let mut xml = self.xml_document();
+        let mut action = None;
+        loop {
+            match xml.next_event().map_err(OSMError::XML)? {
+                XMLEvent::Start(start) => {
+                    if let Some(block) = OSMAction::from_name(start.local_name()) {
+                        action = Some(block);
+                        continue;
+                    }
+                    let mut node = start.to_node();
+                    let _type = match node.local_name() {
+                        "node" => OSMType::Node,
+                        "way" => OSMType::Way,
+                        "relation" => OSMType::Relation,
+                        // keep reading the root's children
+                        _ if xml.depth() == 1 => continue,
+                        // bounds, changesets, notes, etc.
+                        _ => {
+                            xml.skip_element().map_err(OSMError::XML)?;
+                            continue;
+                        }
+                    };
+                    xml.read_children(&mut node).map_err(OSMError::XML)?;
+                    self.read_xml_primitive(_type, &node, action)?;
+                }
+                XMLEvent::End(end) if OSMAction::from_name(end.local_name()).is_some() => {
+                    action = None;
+                }
+                XMLEvent::Eof => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Store a `<node>`, `<way>` or `<relation>` element
+    fn read_xml_primitive(
+        &mut self,
+        _type: OSMType,
+        node: &XMLNode,
+        action: Option<OSMAction>,
+    ) -> Result<(), OSMError> {
+        let id = xml_reference(node, "id")?;
+        let info = xml_info(node);
+        let properties = xml_tags(node);
+        if let Some(action) = action {
+            self.changes.push(OSMChange { _type, id, action, info: info.clone() });
+        }
+        match _type {
+            OSMType::Node => {
+                let coord = |name| node.attribute(name).and_then(|v| v.parse::<f64>().ok());
+                // deleted nodes may not store their location
+                let (Some(lon), Some(lat)) = (coord("lon"), coord("lat")) else {
+                    return Ok(());
+                };
+                let coordinates = VectorPoint::new(lon, lat, parse_z(&properties), None);
+                let metadata = OSMMetadata { _type, info, action, ..Default::default() };
+                self.add_node(id, coordinates, properties, metadata);
+            }
+            OSMType::Way => {
+                if self.skip_ways && self.skip_relations {
+                    return Ok(());
+                }
+                let way_nodes = node
+                    .children("nd")
+                    .map(|nd| xml_reference(nd, "ref"))
+                    .collect::<Result<_, _>>()?;
+                let way = IntermediateWay::new(
+                    id,
+                    properties,
+                    info,
+                    way_nodes,
+                    self.upgrade_ways_to_areas,
+                );
+                if let Some(way) = way {
+                    self.add_way(IntermediateWay { action, ..way });
+                }
+            }
+            OSMType::Relation => {
+                let mut members: Vec<IntermediateMember> = Vec::new();
+                for member in node.children("member") {
+                    let member_ref = xml_reference(member, "ref")?;
+                    let role = member.attribute("role").unwrap_or_default().to_string();
+                    match member.attribute("type") {
+                        Some("node") => {
+                            members.push(IntermediateMember::Node(IntermediateNodeMember {
+                                relation_id: id,
+                                role,
+                                node: member_ref,
+                            }))
+                        }
+                        Some("way") => {
+                            members.push(IntermediateMember::Way(IntermediateWayMember {
+                                role,
+                                way: member_ref,
+                            }))
+                        }
+                        // relation members are not supported
+                        _ => {}
+                    }
+                }
+                if !members.is_empty() {
+                    self.add_relation(IntermediateRelation {
+                        id,
+                        properties,
+                        members,
+                        info,
+                        action,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stream the input as an XML document
+    fn xml_document(&mut self) -> XMLReader<OwnedBufferReader> {
+        let data = self.reader.slice(Some(0), Some(self.reader.len()));
+        XMLReader::new(OwnedBufferReader::from(data))
+    }
+}
+
+/// Read a primitive's `id` or a member's `ref`. Negative ids of unsaved primitives are kept as
+/// their two's complement
+fn xml_reference(node: &XMLNode, name: &str) -> Result<u64, OSMError> {
+    let id = node.attribute(name).and_then(|id| id.parse::<i64>().ok());
+    id.map(|id| id as u64).ok_or(OSMError::InvalidReference)
+}
+
+/// Read the info block from a primitive's attributes
+fn xml_info(node: &XMLNode) -> InfoBlock {
+    InfoBlock {
+        version: node.attribute("version").and_then(|v| v.parse().ok()),
+        timestamp: node.attribute("timestamp").and_then(parse_rfc3339).map(|t| t * 1_000),
+        changeset: node.attribute("changeset").and_then(|v| v.parse().ok()),
+        uid: node.attribute("uid").and_then(|v| v.parse().ok()),
+        user: node.attribute("user").map(String::from),
+        visible: Some(node.attribute("visible") != Some("false")),
+    }
+}
+
+/// Read the `<tag k="" v=""/>` children of a primitive
+fn xml_tags(node: &XMLNode) -> OSMProperties {
+    node.children("tag")
+        .filter_map(|tag| {
+            let value = PrimitiveValue::String(tag.attribute("v")?.into());
+            Some((tag.attribute("k")?.into(), ValueType::Primitive(value)))
+        })
+        .collect()
+}
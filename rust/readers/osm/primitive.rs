@@ -0,0 +1,204 @@
+use super::{
+    DenseNodes, InfoBlock, IntermediateNodeMember, Node, OSMAction, OSMProperties, Relation, Way,
+};
+use crate::geometry::{PrimitiveValue, ValueType};
+
+use pbf::{ProtoRead, Protobuf};
+use serde::{Deserialize, Serialize};
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The type of OSM primitive a feature was built from
+#[derive(Serialize, Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum OSMType {
+    /// Node
+    #[default]
+    #[serde(rename = "node")]
+    Node,
+    /// Way
+    #[serde(rename = "way")]
+    Way,
+    /// Relation
+    #[serde(rename = "relation")]
+    Relation,
+}
+
+/// The relation a node is a member of (e.g. a `label` or `admin_centre` of a boundary)
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct OSMRelationMetadata {
+    /// The role of the node in the relation
+    pub role: String,
+    /// The relation's properties
+    pub properties: OSMProperties,
+}
+
+/// The expected metadata in the VectorFeature for all types (node, way, relation)
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct OSMMetadata {
+    /// The type of primitive
+    #[serde(rename = "type")]
+    pub _type: OSMType,
+    /// The info block of the primitive
+    pub info: InfoBlock,
+    /// Relations store their node members
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nodes: Option<Vec<IntermediateNodeMember>>,
+    /// Nodes that are members of a relation store the relation's role and properties
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relation: Option<OSMRelationMetadata>,
+    /// The osmChange action the primitive was listed under (only set when reading osmChange)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub action: Option<OSMAction>,
+}
+
+/// A block of primitives. Each primitive block is independently parsable.
+///
+/// NOTE: The primitive groups are stored as raw bytes and parsed after the block is read since
+/// the `granularity` and offsets are stored *after* the groups in the protobuf message.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PrimitiveBlock {
+    /// The block's string table
+    pub stringtable: StringTable,
+    /// The raw primitive groups
+    pub primitive_groups: Vec<Vec<u8>>,
+    /// Granularity, units of nanodegrees, used to store coordinates in this block.
+    pub granularity: i32,
+    /// Offset value between the output coordinates and the granularity grid in units of nanodegrees.
+    pub lat_offset: i64,
+    /// Offset value between the output coordinates and the granularity grid in units of nanodegrees.
+    pub lon_offset: i64,
+    /// Granularity of dates, normally represented in units of milliseconds since the 1970 epoch.
+    pub date_granularity: i32,
+}
+impl Default for PrimitiveBlock {
+    fn default() -> Self {
+        Self {
+            stringtable: StringTable::default(),
+            primitive_groups: Vec::new(),
+            granularity: 100,
+            lat_offset: 0,
+            lon_offset: 0,
+            date_granularity: 1_000,
+        }
+    }
+}
+impl PrimitiveBlock {
+    /// Get a string from the string table at the given index
+    pub fn get_string(&self, index: usize) -> String {
+        self.stringtable.get(index)
+    }
+
+    /// Get a record of strings from the string table
+    pub fn tags(&self, keys: &[u32], values: &[u32]) -> OSMProperties {
+        let mut res = OSMProperties::new();
+        for (key, value) in keys.iter().zip(values) {
+            res.insert(
+                self.get_string(*key as usize),
+                ValueType::Primitive(PrimitiveValue::String(self.get_string(*value as usize))),
+            );
+        }
+        res
+    }
+
+    /// Convert a latitude in granularity units to degrees
+    pub fn lat(&self, lat: i64) -> f64 {
+        0.000_000_001 * (self.lat_offset as f64 + self.granularity as f64 * lat as f64)
+    }
+
+    /// Convert a longitude in granularity units to degrees
+    pub fn lon(&self, lon: i64) -> f64 {
+        0.000_000_001 * (self.lon_offset as f64 + self.granularity as f64 * lon as f64)
+    }
+
+    /// Parse out the primitive groups
+    pub fn groups(&self) -> Vec<PrimitiveGroup> {
+        self.primitive_groups
+            .iter()
+            .map(|bytes| {
+                let mut pbf = Protobuf::from(bytes.clone());
+                let mut group = PrimitiveGroup::default();
+                pbf.read_fields(&mut group, None);
+                group
+            })
+            .collect()
+    }
+}
+impl ProtoRead for PrimitiveBlock {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => pb.read_message(&mut self.stringtable),
+            2 => self.primitive_groups.push(pb.read_bytes()),
+            17 => self.granularity = pb.read_varint::<i32>(),
+            18 => self.date_granularity = pb.read_varint::<i32>(),
+            19 => self.lat_offset = pb.read_varint::<i64>(),
+            20 => self.lon_offset = pb.read_varint::<i64>(),
+            _ => {}
+        }
+    }
+}
+
+/// Group of OSMPrimitives. All primitives in a group must be the same type.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PrimitiveGroup {
+    /// Nodes
+    pub nodes: Vec<Node>,
+    /// Dense nodes
+    pub dense: Option<DenseNodes>,
+    /// Ways
+    pub ways: Vec<Way>,
+    /// Relations
+    pub relations: Vec<Relation>,
+}
+impl ProtoRead for PrimitiveGroup {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => {
+                let mut node = Node::default();
+                pb.read_message(&mut node);
+                self.nodes.push(node);
+            }
+            2 => {
+                let mut dense = DenseNodes::default();
+                pb.read_message(&mut dense);
+                self.dense = Some(dense);
+            }
+            3 => {
+                let mut way = Way::default();
+                pb.read_message(&mut way);
+                self.ways.push(way);
+            }
+            4 => {
+                let mut relation = Relation::default();
+                pb.read_message(&mut relation);
+                self.relations.push(relation);
+            }
+            // 5 => changesets are kept for backwards compatibility but not used anywhere.
+            _ => {}
+        }
+    }
+}
+
+/// String table, contains the common strings in each block.
+/// Note that we reserve index '0' as a delimiter, so the entry at that
+/// index in the table is ALWAYS blank and unused.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct StringTable {
+    /// The strings
+    pub strings: Vec<String>,
+}
+impl StringTable {
+    /// Get the string at the given index
+    pub fn get(&self, index: usize) -> String {
+        self.strings.get(index).cloned().unwrap_or_default()
+    }
+}
+impl ProtoRead for StringTable {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        if tag == 1 {
+            self.strings.push(String::from_utf8_lossy(&pb.read_bytes()).to_string());
+        }
+    }
+}
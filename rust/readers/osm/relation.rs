@@ -0,0 +1,559 @@
+use super::{
+    Info, InfoBlock, OSMAction, OSMMetadata, OSMProperties, OSMType, PrimitiveBlock, WayNodes,
+};
+use crate::geometry::{
+    tools::{point_in_ring, rewind},
+    BBox3D, PrimitiveValue, ValueType, VectorFeature, VectorGeometry, VectorGeometryType,
+    VectorLineString, VectorLineStringGeometry, VectorMultiLineString,
+    VectorMultiLineStringGeometry, VectorMultiPolygon, VectorMultiPolygonGeometry, VectorPoint,
+};
+
+use pbf::{ProtoRead, Protobuf};
+use serde::{Deserialize, Serialize};
+
+use alloc::{collections::BTreeMap, string::String, vec, vec::Vec};
+
+/// An intermediate vector feature where the ways and nodes haven't been resolved yet.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IntermediateRelation {
+    /// The relation id
+    pub id: u64,
+    /// The relation's properties
+    pub properties: OSMProperties,
+    /// The relation's members
+    pub members: Vec<IntermediateMember>,
+    /// The relation's info block
+    pub info: InfoBlock,
+    /// The osmChange action if read from an osmChange file
+    pub action: Option<OSMAction>,
+}
+
+/// An intermediate member where the way nodes haven't been resolved yet.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IntermediateMember {
+    /// Node member
+    Node(IntermediateNodeMember),
+    /// Way member
+    Way(IntermediateWayMember),
+}
+
+/// An intermediate node member
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+pub struct IntermediateNodeMember {
+    /// The relation the node is a member of
+    #[serde(rename = "relationID")]
+    pub relation_id: u64,
+    /// The role of the node
+    pub role: String,
+    /// The node id
+    pub node: u64,
+}
+
+/// An intermediate way member where the way nodes haven't been resolved yet.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IntermediateWayMember {
+    /// The role of the way
+    pub role: String,
+    /// The way id
+    pub way: u64,
+}
+
+/// Member Type can be Node (0), Way (1) or Relation (2).
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum MemberType {
+    /// Node Member
+    #[default]
+    Node = 0,
+    /// Way Member
+    Way = 1,
+    /// Relation Member
+    Relation = 2,
+}
+impl From<u8> for MemberType {
+    fn from(t: u8) -> Self {
+        match t {
+            1 => MemberType::Way,
+            2 => MemberType::Relation,
+            _ => MemberType::Node,
+        }
+    }
+}
+
+/// A way member whose geometry has been resolved
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WayMember {
+    /// The way id
+    pub id: u64,
+    /// The role of the way
+    pub role: String,
+    /// The way's geometry
+    pub way: VectorLineString,
+}
+
+/// Relation class contains a collection of nodes, ways and relations as members.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Relation {
+    /// The relation id
+    pub id: i64,
+    /// Optional info block
+    pub info: Option<Info>,
+    /// String IDs for the keys
+    pub keys: Vec<u32>,
+    /// String IDs for the values
+    pub vals: Vec<u32>,
+    /// This should have been defined as uint32 for consistency, but it is now too late to change it
+    pub roles_sid: Vec<i32>,
+    /// DELTA encoded
+    pub memids: Vec<i64>,
+    /// The member types
+    pub types: Vec<MemberType>,
+}
+impl Relation {
+    /// Access the relation's properties
+    pub fn properties(&self, pb: &PrimitiveBlock) -> OSMProperties {
+        pb.tags(&self.keys, &self.vals)
+    }
+
+    /// Each member can be node, way or relation. Relations are skipped as they are not supported.
+    pub fn members(&self, pb: &PrimitiveBlock) -> Vec<IntermediateMember> {
+        let mut res = Vec::new();
+        let mut memid: i64 = 0;
+        for (i, delta) in self.memids.iter().enumerate() {
+            memid = memid.wrapping_add(*delta);
+            let role = pb.get_string(self.roles_sid.get(i).copied().unwrap_or_default() as usize);
+            match self.types.get(i).copied().unwrap_or_default() {
+                MemberType::Node => res.push(IntermediateMember::Node(IntermediateNodeMember {
+                    relation_id: self.id as u64,
+                    role,
+                    node: memid as u64,
+                })),
+                MemberType::Way => res.push(IntermediateMember::Way(IntermediateWayMember {
+                    role,
+                    way: memid as u64,
+                })),
+                // Relation -> no-op
+                MemberType::Relation => {}
+            }
+        }
+        res
+    }
+
+    /// Converts the relation to a feature in intermediate format to build later
+    pub fn to_intermediate_feature(
+        &self,
+        pb: &PrimitiveBlock,
+        properties: OSMProperties,
+    ) -> Option<IntermediateRelation> {
+        let members = self.members(pb);
+        if members.is_empty() {
+            return None;
+        }
+        Some(IntermediateRelation {
+            id: self.id as u64,
+            properties,
+            members,
+            info: self.info.as_ref().map(|i| i.to_block(pb)).unwrap_or_default(),
+            action: None,
+        })
+    }
+}
+impl ProtoRead for Relation {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.id = pb.read_varint::<i64>(),
+            2 => self.keys = pb.read_packed::<u32>(),
+            3 => self.vals = pb.read_packed::<u32>(),
+            4 => {
+                let mut info = Info::default();
+                pb.read_message(&mut info);
+                self.info = Some(info);
+            }
+            8 => self.roles_sid = pb.read_packed::<i32>(),
+            9 => self.memids = pb.read_s_packed::<i64>(),
+            10 => self.types = pb.read_packed::<u8>().into_iter().map(MemberType::from).collect(),
+            _ => {}
+        }
+    }
+}
+
+/// Find the node members that have a 'label' or 'admin_centre' role
+pub fn get_node_relation_pairs(members: &[IntermediateMember]) -> Vec<IntermediateNodeMember> {
+    members
+        .iter()
+        .filter_map(|member| match member {
+            IntermediateMember::Node(node)
+                if node.role == "label" || node.role == "admin_centre" =>
+            {
+                Some(node.clone())
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+/// Reasons a relation could not be assembled into a vector feature
+#[derive(Debug, Clone, PartialEq)]
+pub enum RelationError {
+    /// The relation has no way members to build a geometry from
+    NoGeometry,
+    /// A member way is missing from the data (common with extracts)
+    MissingWay(u64),
+    /// A node referenced by a member way is missing from the data
+    MissingNode(u64),
+    /// The member ways could not be stitched into a closed ring. Contains the ids of the ways
+    /// that make up the open ring in the order they were stitched.
+    UnclosedRing(Vec<u64>),
+    /// The member ways were stitched into a closed ring with fewer than 4 nodes. Contains the
+    /// ids of the ways that make up the ring in the order they were stitched.
+    DegenerateRing(Vec<u64>),
+}
+
+/// Returns true if the relation describes an area (`type=multipolygon` or `type=boundary`)
+pub fn is_area_relation(properties: &OSMProperties) -> bool {
+    matches!(
+        properties.get("type"),
+        Some(ValueType::Primitive(PrimitiveValue::String(t))) if t == "multipolygon" || t == "boundary"
+    )
+}
+
+/// Resolve the way members of a relation into their geometry. If any way or node is missing,
+/// the relation can't be built and an error is returned.
+pub fn resolve_way_members(
+    members: &[IntermediateMember],
+    node_geometry: &BTreeMap<u64, VectorPoint>,
+    way_geometry: &BTreeMap<u64, WayNodes>,
+) -> Result<Vec<WayMember>, RelationError> {
+    let mut ways = Vec::new();
+    for member in members {
+        if let IntermediateMember::Way(IntermediateWayMember { role, way }) = member {
+            let refs = way_geometry.get(way).ok_or(RelationError::MissingWay(*way))?;
+            ways.push(WayMember {
+                id: *way,
+                role: role.clone(),
+                way: resolve_nodes(refs, node_geometry)?,
+            });
+        }
+    }
+    Ok(ways)
+}
+
+/// Resolve a list of node ids into their geometry
+fn resolve_nodes(
+    refs: &[u64],
+    node_geometry: &BTreeMap<u64, VectorPoint>,
+) -> Result<VectorLineString, RelationError> {
+    refs.iter()
+        .map(|id| node_geometry.get(id).cloned().ok_or(RelationError::MissingNode(*id)))
+        .collect()
+}
+
+/// Stitch ways (given as a way id and its node ids) into closed rings. Ways are joined end to
+/// end, reversing them where necessary. If any ring can't be closed or has fewer than 4 nodes,
+/// the whole relation is rejected rather than assembled from the remaining rings.
+pub fn stitch_rings(ways: &[(u64, &WayNodes)]) -> Result<Vec<WayNodes>, RelationError> {
+    let mut remaining: Vec<(u64, &WayNodes)> = ways.to_vec();
+    let mut rings = Vec::new();
+    while !remaining.is_empty() {
+        let (id, nodes) = remaining.remove(0);
+        let mut ring_ways = vec![id];
+        let mut ring = nodes.clone();
+        while ring.len() < 2 || ring.first() != ring.last() {
+            let last = ring.last().copied();
+            let Some(pos) = remaining
+                .iter()
+                .position(|(_, n)| n.first().copied() == last || n.last().copied() == last)
+            else {
+                return Err(RelationError::UnclosedRing(ring_ways));
+            };
+            let (id, nodes) = remaining.remove(pos);
+            ring_ways.push(id);
+            if nodes.first().copied() == last {
+                ring.extend(nodes.iter().skip(1));
+            } else {
+                ring.extend(nodes.iter().rev().skip(1));
+            }
+        }
+        if ring.len() < 4 {
+            return Err(RelationError::DegenerateRing(ring_ways));
+        }
+        rings.push(ring);
+    }
+    Ok(rings)
+}
+
+/// Assemble the `outer` and `inner` way members of an area relation into polygons.
+/// Member roles are treated as hints: rings are nested by containment, so a ring inside an
+/// outer ring becomes its hole and a ring inside a hole becomes a new outer ring.
+/// Outer rings are wound counter-clockwise and inner rings clockwise (RFC 7946).
+pub fn assemble_multipolygon(
+    members: &[IntermediateMember],
+    node_geometry: &BTreeMap<u64, VectorPoint>,
+    way_geometry: &BTreeMap<u64, WayNodes>,
+) -> Result<VectorMultiPolygon, RelationError> {
+    let mut ways = Vec::new();
+    for member in members {
+        if let IntermediateMember::Way(IntermediateWayMember { role, way }) = member {
+            if role != "outer" && role != "inner" && !role.is_empty() {
+                continue;
+            }
+            ways.push((*way, way_geometry.get(way).ok_or(RelationError::MissingWay(*way))?));
+        }
+    }
+    let node_rings = stitch_rings(&ways)?;
+    if node_rings.is_empty() {
+        return Err(RelationError::NoGeometry);
+    }
+    let rings = node_rings
+        .iter()
+        .map(|ring| resolve_nodes(ring, node_geometry))
+        .collect::<Result<Vec<VectorLineString>, RelationError>>()?;
+
+    // place the largest rings first so that containers are always placed before their children
+    let mut order: Vec<usize> = (0..rings.len()).collect();
+    let areas: Vec<f64> = rings.iter().map(ring_area).collect();
+    order.sort_by(|a, b| areas[*b].total_cmp(&areas[*a]));
+
+    let mut depths = vec![0; rings.len()];
+    let mut polygon_index = vec![0; rings.len()];
+    let mut polygons: VectorMultiPolygon = Vec::new();
+    for (k, &r) in order.iter().enumerate() {
+        // the smallest placed ring that contains this ring is its parent
+        let parent = order[..k]
+            .iter()
+            .rev()
+            .copied()
+            .find(|&p| ring_in_ring(&node_rings[r], &rings[r], &node_rings[p], &rings[p]));
+        let mut ring = rings[r].clone();
+        match parent {
+            Some(p) if depths[p] % 2 == 0 => {
+                depths[r] = depths[p] + 1;
+                rewind(&mut ring, false);
+                polygons[polygon_index[p]].push(ring);
+            }
+            _ => {
+                depths[r] = parent.map(|p| depths[p] + 1).unwrap_or(0);
+                rewind(&mut ring, true);
+                polygon_index[r] = polygons.len();
+                polygons.push(vec![ring]);
+            }
+        }
+    }
+
+    Ok(polygons)
+}
+
+/// Absolute area of a ring
+fn ring_area(ring: &VectorLineString) -> f64 {
+    let mut area = 0.;
+    let mut j = ring.len() - 1;
+    for i in 0..ring.len() {
+        area += (ring[j].x - ring[i].x) * (ring[j].y + ring[i].y);
+        j = i;
+    }
+    (area / 2.).abs()
+}
+
+/// Check if ring `a` is inside ring `b`. Nodes shared by both rings are ignored so that rings
+/// touching at a vertex are handled correctly.
+fn ring_in_ring(
+    a_nodes: &[u64],
+    a: &VectorLineString,
+    b_nodes: &[u64],
+    b: &VectorLineString,
+) -> bool {
+    a_nodes
+        .iter()
+        .zip(a)
+        .find(|(id, _)| !b_nodes.contains(id))
+        .is_some_and(|(_, point)| point_in_ring(point, b))
+}
+
+/// Convert an intermediate relation to a vector feature. Area relations (`type=multipolygon`
+/// and `type=boundary`) are assembled into a multipolygon, otherwise each way member is stored
+/// as its own line.
+pub fn intermediate_relation_to_vector_feature(
+    relation: &IntermediateRelation,
+    node_geometry: &BTreeMap<u64, VectorPoint>,
+    way_geometry: &BTreeMap<u64, WayNodes>,
+    add_bbox: bool,
+) -> Result<VectorFeature<OSMMetadata>, RelationError> {
+    let IntermediateRelation { id, members, properties, info, action } = relation;
+    let geometry = if is_area_relation(properties) {
+        let polygons = assemble_multipolygon(members, node_geometry, way_geometry)?;
+        let bbox = if add_bbox { Some(BBox3D::from_multi_polygon(&polygons)) } else { None };
+        VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+            _type: VectorGeometryType::MultiPolygon,
+            coordinates: polygons,
+            bbox,
+            ..Default::default()
+        })
+    } else {
+        let ways = resolve_way_members(members, node_geometry, way_geometry)?;
+        if ways.is_empty() {
+            return Err(RelationError::NoGeometry);
+        }
+        let mut lines: VectorMultiLineString = ways.into_iter().map(|w| w.way).collect();
+        let bbox = if add_bbox { Some(BBox3D::from_multi_linestring(&lines)) } else { None };
+        if lines.len() == 1 {
+            VectorGeometry::LineString(VectorLineStringGeometry {
+                _type: VectorGeometryType::LineString,
+                coordinates: lines.remove(0),
+                bbox,
+                ..Default::default()
+            })
+        } else {
+            VectorGeometry::MultiLineString(VectorMultiLineStringGeometry {
+                _type: VectorGeometryType::MultiLineString,
+                coordinates: lines,
+                bbox,
+                ..Default::default()
+            })
+        }
+    };
+
+    Ok(VectorFeature::new_wm(
+        Some(*id),
+        properties.clone(),
+        geometry,
+        Some(OSMMetadata { action: *action, ..relation_metadata(members, info) }),
+    ))
+}
+
+/// Build the metadata for a relation feature
+pub fn relation_metadata(members: &[IntermediateMember], info: &InfoBlock) -> OSMMetadata {
+    let nodes = members
+        .iter()
+        .filter_map(|m| match m {
+            IntermediateMember::Node(node) => Some(node.clone()),
+            _ => None,
+        })
+        .collect();
+    OSMMetadata {
+        _type: OSMType::Relation,
+        info: info.clone(),
+        nodes: Some(nodes),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::VectorPolygon;
+    use alloc::string::ToString;
+
+    /// Build a relation from `(way id, role)` pairs
+    fn area_relation(ways: &[(u64, &str)]) -> IntermediateRelation {
+        let mut properties = OSMProperties::new();
+        properties.insert(
+            "type".into(),
+            ValueType::Primitive(PrimitiveValue::String("multipolygon".into())),
+        );
+        IntermediateRelation {
+            id: 1,
+            properties,
+            members: ways
+                .iter()
+                .map(|(way, role)| {
+                    IntermediateMember::Way(IntermediateWayMember {
+                        role: role.to_string(),
+                        way: *way,
+                    })
+                })
+                .collect(),
+            info: InfoBlock::default(),
+            action: None,
+        }
+    }
+
+    fn geometry() -> (BTreeMap<u64, VectorPoint>, BTreeMap<u64, WayNodes>) {
+        let mut nodes = BTreeMap::new();
+        // outer square 0-10
+        nodes.insert(1, VectorPoint::new(0., 0., None, None));
+        nodes.insert(2, VectorPoint::new(10., 0., None, None));
+        nodes.insert(3, VectorPoint::new(10., 10., None, None));
+        nodes.insert(4, VectorPoint::new(0., 10., None, None));
+        // inner square 2-4
+        nodes.insert(5, VectorPoint::new(2., 2., None, None));
+        nodes.insert(6, VectorPoint::new(2., 4., None, None));
+        nodes.insert(7, VectorPoint::new(4., 4., None, None));
+        nodes.insert(8, VectorPoint::new(4., 2., None, None));
+        let mut ways = BTreeMap::new();
+        // outer split into two ways, the second drawn in the opposite direction
+        ways.insert(10, vec![1, 2, 3]);
+        ways.insert(11, vec![1, 4, 3]);
+        // clockwise inner
+        ways.insert(12, vec![5, 6, 7, 8, 5]);
+        // continues the outer from node 3 but stops at node 4
+        ways.insert(14, vec![3, 4]);
+        // closed but only 3 nodes
+        ways.insert(15, vec![1, 2, 1]);
+        (nodes, ways)
+    }
+
+    #[test]
+    fn stitch_rings_reverses_ways() {
+        let (_, ways) = geometry();
+        let rings = stitch_rings(&[(10, &ways[&10]), (11, &ways[&11])]).unwrap();
+        assert_eq!(rings, vec![vec![1, 2, 3, 4, 1]]);
+    }
+
+    #[test]
+    fn assemble_polygon_with_hole() {
+        let (nodes, ways) = geometry();
+        let relation = area_relation(&[(12, "inner"), (10, "outer"), (11, "outer")]);
+        let feature =
+            intermediate_relation_to_vector_feature(&relation, &nodes, &ways, false).unwrap();
+
+        let p = |x: f64, y: f64| VectorPoint::new(x, y, None, None);
+        let expected: VectorPolygon = vec![
+            // counter-clockwise outer
+            vec![p(0., 0.), p(10., 0.), p(10., 10.), p(0., 10.), p(0., 0.)],
+            // clockwise inner
+            vec![p(2., 2.), p(2., 4.), p(4., 4.), p(4., 2.), p(2., 2.)],
+        ];
+        assert_eq!(
+            feature.geometry,
+            VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+                _type: VectorGeometryType::MultiPolygon,
+                coordinates: vec![expected],
+                ..Default::default()
+            })
+        );
+    }
+
+    #[test]
+    fn assemble_broken_relations() {
+        let (nodes, ways) = geometry();
+
+        let relation = area_relation(&[(10, "outer"), (12, "inner")]);
+        assert_eq!(
+            intermediate_relation_to_vector_feature(&relation, &nodes, &ways, false),
+            Err(RelationError::UnclosedRing(vec![10]))
+        );
+
+        let relation = area_relation(&[(10, "outer"), (11, "outer"), (13, "inner")]);
+        assert_eq!(
+            intermediate_relation_to_vector_feature(&relation, &nodes, &ways, false),
+            Err(RelationError::MissingWay(13))
+        );
+    }
+
+    #[test]
+    fn reject_incomplete_rings() {
+        let (nodes, ways) = geometry();
+
+        // the unclosed outer isn't dropped in favour of the valid inner ring
+        let relation = area_relation(&[(12, "inner"), (10, "outer"), (14, "outer")]);
+        assert_eq!(
+            intermediate_relation_to_vector_feature(&relation, &nodes, &ways, false),
+            Err(RelationError::UnclosedRing(vec![10, 14]))
+        );
+
+        let relation = area_relation(&[(10, "outer"), (11, "outer"), (15, "inner")]);
+        assert_eq!(
+            intermediate_relation_to_vector_feature(&relation, &nodes, &ways, false),
+            Err(RelationError::DegenerateRing(vec![15]))
+        );
+    }
+}
@@ -0,0 +1,157 @@
+use super::{Info, InfoBlock, OSMAction, OSMMetadata, OSMProperties, OSMType, PrimitiveBlock};
+use crate::geometry::{
+    BBox3D, PrimitiveValue, ValueType, VectorFeature, VectorGeometry, VectorGeometryType,
+    VectorLineString, VectorLineStringGeometry, VectorPoint, VectorPolygonGeometry,
+};
+
+use pbf::{ProtoRead, Protobuf};
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+/// Linebased node reference store
+pub type WayNodes = Vec<u64>;
+
+/// An intermediate vector feature where the way nodes haven't been resolved yet.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IntermediateWay {
+    /// The way id
+    pub id: u64,
+    /// The way's properties
+    pub properties: OSMProperties,
+    /// The way's info block
+    pub info: InfoBlock,
+    /// The node ids that make up the way
+    pub way_nodes: WayNodes,
+    /// True if the way should be treated as an area
+    pub is_area: bool,
+    /// The osmChange action if read from an osmChange file
+    pub action: Option<OSMAction>,
+}
+impl IntermediateWay {
+    /// Create an intermediate way given its node references. Returns `None` if the way has less
+    /// than two nodes.
+    pub fn new(
+        id: u64,
+        properties: OSMProperties,
+        info: InfoBlock,
+        way_nodes: WayNodes,
+        upgrade_ways_to_areas: bool,
+    ) -> Option<Self> {
+        if way_nodes.len() < 2 {
+            return None;
+        }
+        let is_closed = way_nodes.len() >= 4 && way_nodes.first() == way_nodes.last();
+        let is_area = (upgrade_ways_to_areas && is_closed)
+            || matches!(
+                properties.get("area"),
+                Some(ValueType::Primitive(PrimitiveValue::String(v))) if v == "yes"
+            );
+
+        Some(Self { id, properties, info, way_nodes, is_area, action: None })
+    }
+}
+
+/// Convert an intermediate way to a vector feature. If any of the way's nodes are missing, the
+/// way can't be built and `None` is returned.
+pub fn intermediate_way_to_vector_feature(
+    way: &IntermediateWay,
+    node_geometry: &BTreeMap<u64, VectorPoint>,
+    add_bbox: bool,
+) -> Option<VectorFeature<OSMMetadata>> {
+    let IntermediateWay { id, properties, info, way_nodes, is_area, action } = way;
+    // build line
+    let mut line: VectorLineString = Vec::with_capacity(way_nodes.len());
+    for node_id in way_nodes {
+        line.push(node_geometry.get(node_id)?.clone());
+    }
+    let bbox = if add_bbox { Some(BBox3D::from_linestring(&line)) } else { None };
+    // build geometry
+    let geometry = if *is_area {
+        VectorGeometry::Polygon(VectorPolygonGeometry {
+            _type: VectorGeometryType::Polygon,
+            coordinates: vec![line],
+            bbox,
+            ..Default::default()
+        })
+    } else {
+        VectorGeometry::LineString(VectorLineStringGeometry {
+            _type: VectorGeometryType::LineString,
+            coordinates: line,
+            bbox,
+            ..Default::default()
+        })
+    };
+    let metadata = OSMMetadata {
+        _type: OSMType::Way,
+        info: info.clone(),
+        action: *action,
+        ..Default::default()
+    };
+
+    Some(VectorFeature::new_wm(Some(*id), properties.clone(), geometry, Some(metadata)))
+}
+
+/// Way Class
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Way {
+    /// The way id
+    pub id: i64,
+    /// Optional info block
+    pub info: Option<Info>,
+    /// String IDs for the keys
+    pub keys: Vec<u32>,
+    /// String IDs for the values
+    pub vals: Vec<u32>,
+    /// DELTA coded node references
+    pub refs: Vec<i64>,
+}
+impl Way {
+    /// Access the way's properties
+    pub fn properties(&self, pb: &PrimitiveBlock) -> OSMProperties {
+        pb.tags(&self.keys, &self.vals)
+    }
+
+    /// Access the way's node IDs associated with this way
+    pub fn node_refs(&self) -> WayNodes {
+        let mut res = Vec::with_capacity(self.refs.len());
+        let mut cur: i64 = 0;
+        for r in &self.refs {
+            cur = cur.wrapping_add(*r);
+            res.push(cur as u64);
+        }
+        res
+    }
+
+    /// Converts the way to an intermediate vector feature (way's nodes have not been parsed)
+    pub fn to_intermediate_feature(
+        &self,
+        pb: &PrimitiveBlock,
+        properties: OSMProperties,
+        upgrade_ways_to_areas: bool,
+    ) -> Option<IntermediateWay> {
+        IntermediateWay::new(
+            self.id as u64,
+            properties,
+            self.info.as_ref().map(|i| i.to_block(pb)).unwrap_or_default(),
+            self.node_refs(),
+            upgrade_ways_to_areas,
+        )
+    }
+}
+impl ProtoRead for Way {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.id = pb.read_varint::<i64>(),
+            2 => self.keys = pb.read_packed::<u32>(),
+            3 => self.vals = pb.read_packed::<u32>(),
+            4 => {
+                let mut info = Info::default();
+                pb.read_message(&mut info);
+                self.info = Some(info);
+            }
+            8 => self.refs = pb.read_s_packed::<i64>(),
+            // 9 & 10 are the optional lats and lons. Skipped, I've never seen them used.
+            _ => {}
+        }
+    }
+}
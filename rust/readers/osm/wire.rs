@@ -0,0 +1,115 @@
+pub use crate::readers::wire::*;
+
+/// BlobHeader: type, indexdata and datasize
+pub fn blob_header_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::String),
+        2 => Some(WireField::Bytes),
+        3 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+/// Blob: the raw size and the data of one of the compressions
+pub fn blob_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        2 => Some(WireField::Varint),
+        1 | 3..=7 => Some(WireField::Bytes),
+        _ => None,
+    }
+}
+
+/// HeaderBlock: the bbox, features, writing program, source and replication fields
+pub fn header_block_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(header_bbox_schema)),
+        4 | 5 | 16 | 17 | 34 => Some(WireField::String),
+        32 | 33 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn header_bbox_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=4 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+/// PrimitiveBlock: the string table, the groups and the granularity and offsets
+pub fn primitive_block_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(string_table_schema)),
+        2 => Some(WireField::Message(primitive_group_schema)),
+        17..=20 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn string_table_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        // the strings are decoded lossily
+        1 => Some(WireField::Bytes),
+        _ => None,
+    }
+}
+
+fn primitive_group_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Message(node_schema)),
+        2 => Some(WireField::Message(dense_nodes_schema)),
+        3 => Some(WireField::Message(way_schema)),
+        4 => Some(WireField::Message(relation_schema)),
+        _ => None,
+    }
+}
+
+fn node_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 8 | 9 => Some(WireField::Varint),
+        2 | 3 => Some(WireField::Packed),
+        4 => Some(WireField::Message(info_schema)),
+        _ => None,
+    }
+}
+
+fn info_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=6 => Some(WireField::Varint),
+        _ => None,
+    }
+}
+
+fn dense_nodes_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 | 8..=10 => Some(WireField::Packed),
+        5 => Some(WireField::Message(dense_info_schema)),
+        _ => None,
+    }
+}
+
+fn dense_info_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1..=3 | 6 => Some(WireField::Packed),
+        4 | 5 => Some(WireField::SPacked32),
+        _ => None,
+    }
+}
+
+fn way_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Varint),
+        2 | 3 | 8 => Some(WireField::Packed),
+        4 => Some(WireField::Message(info_schema)),
+        _ => None,
+    }
+}
+
+fn relation_schema(tag: u64) -> Option<WireField> {
+    match tag {
+        1 => Some(WireField::Varint),
+        2 | 3 | 8..=10 => Some(WireField::Packed),
+        4 => Some(WireField::Message(info_schema)),
+        _ => None,
+    }
+}
@@ -0,0 +1,10 @@
+/// PMTiles and S2-PMTiles archive readers
+pub mod reader;
+/// S2-PMTiles header
+pub mod s2pmtiles;
+/// PMTiles header, directory and tile ID utilities
+pub mod spec;
+
+pub use reader::*;
+pub use s2pmtiles::*;
+pub use spec::*;
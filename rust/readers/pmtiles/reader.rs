@@ -0,0 +1,593 @@
+use super::{
+    deserialize_dir, find_tile, zxy_to_tile_id, Compression, Entry, Header, PMTilesError, S2Header,
+    HEADER_SIZE_BYTES, MAX_ZOOM, ROOT_SIZE, S2_HEADER_SIZE_BYTES,
+};
+use crate::data_structures::Cache;
+use crate::geometry::{Face, S2CellId};
+use crate::readers::{Reader, TileSource};
+use crate::util::decompress_data;
+
+use serde_json::Value;
+
+use alloc::vec::Vec;
+
+/// Leaf directory cache keyed by the directory's offset in the archive
+pub(crate) type DirCache = Cache<u64, Vec<Entry>, fn(&u64, &Vec<Entry>)>;
+
+/// A description of where a tile can be found in the archive. Both offset and length are in bytes
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PMTilesTileEntry {
+    /// The offset of the tile from the start of the archive
+    pub offset: u64,
+    /// The length of the tile in bytes
+    pub length: u64,
+}
+
+/// # PMTiles Reader
+///
+/// ## Description
+/// A V3.0 PMTiles reader for reading standard WebMercator Tile data.
+/// Works with any [`Reader`] ([`crate::readers::BufferReader`], `FileReader` or `MMapReader`).
+/// Implements the [`TileSource`] trait
+///
+/// ## Usage
+/// ```rust,no_run
+/// use gistools::readers::{FileReader, PMTilesReader, TileSource};
+/// use std::path::PathBuf;
+///
+/// let file_reader = FileReader::new(PathBuf::from("./data.pmtiles")).unwrap();
+/// let mut reader = PMTilesReader::new(file_reader, None).unwrap();
+///
+/// // pull out the header
+/// let header = reader.get_header();
+/// // get the metadata
+/// let metadata = reader.get_metadata();
+///
+/// let has_tile = reader.has_tile(0, 0, 0).unwrap();
+/// let tile: Option<Vec<u8>> = reader.get_tile(0, 0, 0).unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://github.com/protomaps/PMTiles>
+/// - <https://github.com/protomaps/PMTiles/blob/main/spec/v3/spec.md>
+pub struct PMTilesReader<T: Reader> {
+    reader: T,
+    header: Header,
+    root_dir: Vec<Entry>,
+    metadata: Value,
+    dir_cache: DirCache,
+}
+impl<T: Reader> PMTilesReader<T> {
+    /// Create a new PMTiles reader, parsing the header, metadata and root directory.
+    /// `max_cache_size` is the number of leaf directories to keep in memory [Default = 20]
+    pub fn new(mut reader: T, max_cache_size: Option<usize>) -> Result<Self, PMTilesError> {
+        if reader.len() < HEADER_SIZE_BYTES {
+            return Err(PMTilesError::InvalidHeader);
+        }
+        let root_size = ROOT_SIZE.min(reader.len());
+        let data = reader.slice(Some(0), Some(root_size));
+        let header = Header::from_bytes(&data)?;
+        let metadata = read_metadata(
+            &mut reader,
+            header.internal_compression,
+            header.json_metadata_offset,
+            header.json_metadata_length,
+        )?;
+        let root_dir = read_directory(
+            &mut reader,
+            header.internal_compression,
+            header.root_directory_offset,
+            header.root_directory_length,
+        )?;
+
+        Ok(Self {
+            reader,
+            header,
+            root_dir,
+            metadata,
+            dir_cache: Cache::new(max_cache_size.unwrap_or(20), None),
+        })
+    }
+
+    /// Get the header of the archive
+    pub fn get_header(&self) -> Header {
+        self.header
+    }
+
+    /// Get the metadata of the archive
+    pub fn get_metadata(&self) -> &Value {
+        &self.metadata
+    }
+
+    /// Find where the tile at the given (zoom, x, y) coordinates is stored in the archive
+    pub fn get_tile_entry(
+        &mut self,
+        zoom: u8,
+        x: u32,
+        y: u32,
+    ) -> Result<Option<PMTilesTileEntry>, PMTilesError> {
+        let Header { min_zoom, max_zoom, .. } = self.header;
+        if zoom < min_zoom || zoom > max_zoom {
+            return Ok(None);
+        }
+        find_tile_entry(
+            &mut self.reader,
+            &mut self.dir_cache,
+            &self.header,
+            &self.root_dir,
+            self.header.leaf_directory_offset,
+            zoom,
+            x,
+            y,
+        )
+    }
+}
+impl<T: Reader> TileSource for PMTilesReader<T> {
+    type Error = PMTilesError;
+
+    fn has_tile(&mut self, zoom: u8, x: u32, y: u32) -> Result<bool, PMTilesError> {
+        Ok(self.get_tile_entry(zoom, x, y)?.is_some())
+    }
+
+    fn get_tile(&mut self, zoom: u8, x: u32, y: u32) -> Result<Option<Vec<u8>>, PMTilesError> {
+        let entry = self.get_tile_entry(zoom, x, y)?;
+        read_tile(&mut self.reader, &self.header, entry)
+    }
+}
+
+/// # S2-PMTiles Reader
+///
+/// ## Description
+/// A V1.0 S2-PMTiles reader for reading S2 Tile data. A modified PMTiles archive that stores a
+/// root directory (and leaf directories) for each of the 6 faces of the S2 projection.
+/// Works with any [`Reader`] ([`crate::readers::BufferReader`], `FileReader` or `MMapReader`).
+///
+/// ## Usage
+/// ```rust,no_run
+/// use gistools::geometry::{Face, S2CellId};
+/// use gistools::readers::{FileReader, S2PMTilesReader};
+/// use std::path::PathBuf;
+///
+/// let file_reader = FileReader::new(PathBuf::from("./data.s2pmtiles")).unwrap();
+/// let mut reader = S2PMTilesReader::new(file_reader, None).unwrap();
+///
+/// // pull out the header
+/// let header = reader.get_header();
+/// // get the metadata
+/// let metadata = reader.get_metadata();
+///
+/// // by face, zoom, i, j
+/// let has_tile = reader.has_tile_s2(Face::Face0, 0, 0, 0).unwrap();
+/// let tile: Option<Vec<u8>> = reader.get_tile_s2(Face::Face0, 0, 0, 0).unwrap();
+/// // by cell id
+/// let tile: Option<Vec<u8>> = reader.get_tile_from_cell(S2CellId::from_face(1)).unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://github.com/Open-S2/s2-pmtiles>
+/// - <https://github.com/Open-S2/s2-pmtiles/blob/master/s2-pmtiles-spec/1.0.0/README.md>
+pub struct S2PMTilesReader<T: Reader> {
+    reader: T,
+    header: S2Header,
+    root_dirs: [Vec<Entry>; 6],
+    metadata: Value,
+    dir_cache: DirCache,
+}
+impl<T: Reader> S2PMTilesReader<T> {
+    /// Create a new S2-PMTiles reader, parsing the header, metadata and the root directories of
+    /// all 6 faces.
+    /// `max_cache_size` is the number of leaf directories to keep in memory [Default = 20]
+    pub fn new(mut reader: T, max_cache_size: Option<usize>) -> Result<Self, PMTilesError> {
+        if reader.len() < S2_HEADER_SIZE_BYTES {
+            return Err(PMTilesError::InvalidHeader);
+        }
+        let data = reader.slice(Some(0), Some(S2_HEADER_SIZE_BYTES));
+        let header = S2Header::from_bytes(&data)?;
+        // every face's directories have to lie inside the archive, even if they're never read
+        for face in 0..6 {
+            check_range(
+                &reader,
+                header.root_directory_offsets[face],
+                header.root_directory_lengths[face],
+            )?;
+            check_range(
+                &reader,
+                header.leaf_directory_offsets[face],
+                header.leaf_directory_lengths[face],
+            )?;
+        }
+        let compression = header.header.internal_compression;
+        let metadata = read_metadata(
+            &mut reader,
+            compression,
+            header.header.json_metadata_offset,
+            header.header.json_metadata_length,
+        )?;
+        let mut root_dirs: [Vec<Entry>; 6] = Default::default();
+        for (face, root_dir) in root_dirs.iter_mut().enumerate() {
+            *root_dir = read_directory(
+                &mut reader,
+                compression,
+                header.root_directory_offsets[face],
+                header.root_directory_lengths[face],
+            )?;
+        }
+
+        Ok(Self {
+            reader,
+            header,
+            root_dirs,
+            metadata,
+            dir_cache: Cache::new(max_cache_size.unwrap_or(20), None),
+        })
+    }
+
+    /// Get the header of the archive
+    pub fn get_header(&self) -> S2Header {
+        self.header
+    }
+
+    /// Get the metadata of the archive
+    pub fn get_metadata(&self) -> &Value {
+        &self.metadata
+    }
+
+    /// Find where the tile at the given (face, zoom, i, j) coordinates is stored in the archive
+    pub fn get_tile_entry_s2(
+        &mut self,
+        face: Face,
+        zoom: u8,
+        i: u32,
+        j: u32,
+    ) -> Result<Option<PMTilesTileEntry>, PMTilesError> {
+        let Header { min_zoom, max_zoom, .. } = self.header.header;
+        if zoom < min_zoom || zoom > max_zoom {
+            return Ok(None);
+        }
+        let face = u8::from(face) as usize;
+        find_tile_entry(
+            &mut self.reader,
+            &mut self.dir_cache,
+            &self.header.header,
+            &self.root_dirs[face],
+            self.header.leaf_directory_offsets[face],
+            zoom,
+            i,
+            j,
+        )
+    }
+
+    /// Check if the tile at the given (face, zoom, i, j) coordinates exists in the archive
+    pub fn has_tile_s2(
+        &mut self,
+        face: Face,
+        zoom: u8,
+        i: u32,
+        j: u32,
+    ) -> Result<bool, PMTilesError> {
+        Ok(self.get_tile_entry_s2(face, zoom, i, j)?.is_some())
+    }
+
+    /// Get the (decompressed) bytes of the tile at the given (face, zoom, i, j) coordinates
+    pub fn get_tile_s2(
+        &mut self,
+        face: Face,
+        zoom: u8,
+        i: u32,
+        j: u32,
+    ) -> Result<Option<Vec<u8>>, PMTilesError> {
+        let entry = self.get_tile_entry_s2(face, zoom, i, j)?;
+        read_tile(&mut self.reader, &self.header.header, entry)
+    }
+
+    /// Check if the tile described by the cell id exists in the archive
+    pub fn has_tile_from_cell(&mut self, id: S2CellId) -> Result<bool, PMTilesError> {
+        let (face, zoom, i, j) = cell_to_face_zoom_ij(id);
+        self.has_tile_s2(face, zoom, i, j)
+    }
+
+    /// Get the (decompressed) bytes of the tile described by the cell id
+    pub fn get_tile_from_cell(&mut self, id: S2CellId) -> Result<Option<Vec<u8>>, PMTilesError> {
+        let (face, zoom, i, j) = cell_to_face_zoom_ij(id);
+        self.get_tile_s2(face, zoom, i, j)
+    }
+}
+
+/// Convert a cell id to its tile's (face, zoom, i, j) coordinates
+fn cell_to_face_zoom_ij(id: S2CellId) -> (Face, u8, u32, u32) {
+    let zoom = id.level();
+    let (face, i, j) = id.to_zoom_ij(Some(zoom));
+    (face.into(), zoom, i, j)
+}
+
+/// Walk the directories starting at the root directory to find where the tile at the given
+/// (zoom, x, y) coordinates is stored in the archive
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn find_tile_entry<T: Reader>(
+    reader: &mut T,
+    dir_cache: &mut DirCache,
+    header: &Header,
+    root_dir: &[Entry],
+    leaf_directory_offset: u64,
+    zoom: u8,
+    x: u32,
+    y: u32,
+) -> Result<Option<PMTilesTileEntry>, PMTilesError> {
+    let size = 1u64 << zoom.min(MAX_ZOOM);
+    if zoom > MAX_ZOOM || x as u64 >= size || y as u64 >= size {
+        return Ok(None);
+    }
+    let tile_id = zxy_to_tile_id(zoom, x, y);
+
+    let mut entry = find_tile(root_dir, tile_id).copied();
+    for _ in 0..=3 {
+        let Some(Entry { offset, length, run_length, .. }) = entry else {
+            return Ok(None);
+        };
+        if run_length > 0 {
+            let offset =
+                header.tile_data_offset.checked_add(offset).ok_or(PMTilesError::OutOfBounds)?;
+            return Ok(Some(PMTilesTileEntry { offset, length: length as u64 }));
+        }
+        let dir_offset =
+            leaf_directory_offset.checked_add(offset).ok_or(PMTilesError::OutOfBounds)?;
+        if dir_cache.get(&dir_offset).is_none() {
+            let directory =
+                read_directory(reader, header.internal_compression, dir_offset, length as u64)?;
+            if directory.is_empty() {
+                return Err(PMTilesError::EmptyDirectory);
+            }
+            dir_cache.set(dir_offset, directory);
+        }
+        entry = dir_cache.get(&dir_offset).and_then(|dir| find_tile(dir, tile_id)).copied();
+    }
+
+    Err(PMTilesError::MaxDepthExceeded)
+}
+
+/// Read and decompress the tile data described by the entry
+pub(crate) fn read_tile<T: Reader>(
+    reader: &mut T,
+    header: &Header,
+    entry: Option<PMTilesTileEntry>,
+) -> Result<Option<Vec<u8>>, PMTilesError> {
+    let Some(PMTilesTileEntry { offset, length }) = entry else {
+        return Ok(None);
+    };
+    let data = read_range(reader, offset, length)?;
+    Ok(Some(decompress_data(&data, header.tile_compression.into())?))
+}
+
+/// Read and decompress a directory from the archive
+pub(crate) fn read_directory<T: Reader>(
+    reader: &mut T,
+    compression: Compression,
+    offset: u64,
+    length: u64,
+) -> Result<Vec<Entry>, PMTilesError> {
+    if length == 0 {
+        return Ok(Vec::new());
+    }
+    let data = read_range(reader, offset, length)?;
+    let data = decompress_data(&data, compression.into())?;
+    Ok(deserialize_dir(data))
+}
+
+/// Read, decompress and parse the JSON metadata from the archive
+pub(crate) fn read_metadata<T: Reader>(
+    reader: &mut T,
+    compression: Compression,
+    offset: u64,
+    length: u64,
+) -> Result<Value, PMTilesError> {
+    if length == 0 {
+        return Ok(Value::Object(Default::default()));
+    }
+    let data = read_range(reader, offset, length)?;
+    let data = decompress_data(&data, compression.into())?;
+    serde_json::from_slice(&data).map_err(|_| PMTilesError::InvalidMetadata)
+}
+
+/// Read `length` bytes starting at `offset`, checking that the range lies inside the archive
+pub(crate) fn read_range<T: Reader>(
+    reader: &mut T,
+    offset: u64,
+    length: u64,
+) -> Result<Vec<u8>, PMTilesError> {
+    let end = check_range(reader, offset, length)?;
+    Ok(reader.slice(Some(offset as usize), Some(end as usize)))
+}
+
+/// Check that `length` bytes starting at `offset` lie inside the archive and return the end
+fn check_range<T: Reader>(reader: &T, offset: u64, length: u64) -> Result<u64, PMTilesError> {
+    let end = offset.checked_add(length).ok_or(PMTilesError::OutOfBounds)?;
+    if end > reader.len() as u64 {
+        return Err(PMTilesError::OutOfBounds);
+    }
+    Ok(end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::{BufferReader, FileReader, MMapReader};
+    use crate::readers::{Compression, TileType};
+    use alloc::vec;
+    use serde_json::json;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn fixture_path(name: &str) -> PathBuf {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/pmtiles/fixtures");
+        path.push(name);
+        path
+    }
+
+    fn fixture_header(tile_data_length: u64) -> Header {
+        Header {
+            spec_version: 3,
+            root_directory_offset: 127,
+            root_directory_length: 25,
+            json_metadata_offset: 152,
+            json_metadata_length: 247,
+            leaf_directory_offset: 0,
+            leaf_directory_length: 0,
+            tile_data_offset: 399,
+            tile_data_length,
+            n_addressed_tiles: 1,
+            n_tile_entries: 1,
+            n_tile_contents: 1,
+            clustered: false,
+            internal_compression: Compression::Gzip,
+            tile_compression: Compression::Gzip,
+            tile_type: TileType::Pbf,
+            min_zoom: 0,
+            max_zoom: 0,
+            min_longitude_e7: 0,
+            min_latitude_e7: 0,
+            max_longitude_e7: 9_999_999,
+            max_latitude_e7: 10_000_000,
+            center_zoom: 0,
+            center_longitude_e7: 0,
+            center_latitude_e7: 0,
+        }
+    }
+
+    #[test]
+    fn test_fixture_1() {
+        let data = fs::read(fixture_path("test_fixture_1.pmtiles")).unwrap();
+        let mut reader = PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+
+        assert_eq!(reader.get_header(), fixture_header(69));
+        let metadata = reader.get_metadata();
+        assert_eq!(metadata["name"], json!("test_fixture_1.pmtiles"));
+        assert_eq!(metadata["generator"], json!("tippecanoe v2.5.0"));
+        assert_eq!(metadata["vector_layers"][0]["id"], json!("test_fixture_1pmtiles"));
+
+        assert!(reader.has_tile(0, 0, 0).unwrap());
+        assert!(!reader.has_tile(1, 0, 0).unwrap());
+        assert_eq!(reader.get_tile(1, 0, 0).unwrap(), None);
+        assert_eq!(
+            reader.get_tile(0, 0, 0).unwrap(),
+            Some(vec![
+                26, 47, 120, 2, 10, 21, 116, 101, 115, 116, 95, 102, 105, 120, 116, 117, 114, 101,
+                95, 49, 112, 109, 116, 105, 108, 101, 115, 40, 128, 32, 18, 17, 24, 3, 34, 13, 9,
+                150, 32, 232, 31, 26, 0, 24, 21, 0, 0, 23, 15,
+            ])
+        );
+    }
+
+    #[test]
+    fn test_fixture_2() {
+        let expected_tile = vec![
+            26, 45, 120, 2, 10, 21, 116, 101, 115, 116, 95, 102, 105, 120, 116, 117, 114, 101, 95,
+            50, 112, 109, 116, 105, 108, 101, 115, 40, 128, 32, 18, 15, 24, 3, 34, 11, 9, 128, 32,
+            232, 31, 18, 22, 24, 21, 0, 15,
+        ];
+
+        let file_reader = FileReader::new(fixture_path("test_fixture_2.pmtiles")).unwrap();
+        let mut reader = PMTilesReader::new(file_reader, Some(5)).unwrap();
+        assert_eq!(reader.get_header(), fixture_header(67));
+        assert_eq!(reader.get_metadata()["name"], json!("test_fixture_2.pmtiles"));
+        assert_eq!(reader.get_tile(0, 0, 0).unwrap(), Some(expected_tile.clone()));
+
+        let mmap_reader = MMapReader::new(fixture_path("test_fixture_2.pmtiles")).unwrap();
+        let mut reader = PMTilesReader::new(mmap_reader, None).unwrap();
+        assert_eq!(reader.get_tile(0, 0, 0).unwrap(), Some(expected_tile));
+    }
+
+    #[test]
+    fn test_invalid_archives() {
+        let data = fs::read(fixture_path("empty.pmtiles")).unwrap();
+        let reader = PMTilesReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(reader.err(), Some(PMTilesError::InvalidHeader));
+
+        let data = fs::read(fixture_path("invalid.pmtiles")).unwrap();
+        let reader = PMTilesReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(reader.err(), Some(PMTilesError::InvalidMagicNumber));
+    }
+
+    #[test]
+    fn test_truncated_archives() {
+        for name in ["test_fixture_1.pmtiles", "test_fixture_2.pmtiles"] {
+            let data = fs::read(fixture_path(name)).unwrap();
+            // only the header is left, the metadata and root directory are missing
+            let reader = PMTilesReader::new(BufferReader::from(&data[..127]), None);
+            assert_eq!(reader.err(), Some(PMTilesError::OutOfBounds));
+
+            // the directories are intact but the tile data is cut short
+            let mut reader = PMTilesReader::new(BufferReader::from(&data[..400]), None).unwrap();
+            assert!(reader.has_tile(0, 0, 0).unwrap());
+            assert_eq!(reader.get_tile(0, 0, 0), Err(PMTilesError::OutOfBounds));
+        }
+    }
+
+    #[test]
+    fn test_s2_archive() {
+        let data = fs::read(fixture_path("s2.s2pmtiles")).unwrap();
+        let mut reader = S2PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+
+        assert_eq!(
+            reader.get_header(),
+            S2Header {
+                header: Header {
+                    spec_version: 1,
+                    root_directory_offset: 262,
+                    root_directory_length: 5,
+                    json_metadata_offset: 280,
+                    json_metadata_length: 17,
+                    leaf_directory_offset: 98_339,
+                    leaf_directory_length: 0,
+                    tile_data_offset: 98_304,
+                    tile_data_length: 35,
+                    n_addressed_tiles: 3,
+                    n_tile_entries: 1,
+                    n_tile_contents: 1,
+                    clustered: true,
+                    internal_compression: Compression::None,
+                    tile_compression: Compression::None,
+                    tile_type: TileType::Pbf,
+                    min_zoom: 0,
+                    max_zoom: 0,
+                    ..Default::default()
+                },
+                root_directory_offsets: [262, 267, 272, 273, 278, 279],
+                root_directory_lengths: [5, 5, 1, 5, 1, 1],
+                leaf_directory_offsets: [98_339; 6],
+                leaf_directory_lengths: [0; 6],
+            }
+        );
+        assert_eq!(reader.get_metadata(), &json!({ "metadata": true }));
+
+        let hello_world = Some(b"hello world".to_vec());
+        assert_eq!(reader.get_tile_s2(Face::Face0, 0, 0, 0).unwrap(), hello_world);
+        assert_eq!(reader.get_tile_s2(Face::Face1, 0, 0, 0).unwrap(), hello_world);
+        assert!(!reader.has_tile_s2(Face::Face2, 0, 0, 0).unwrap());
+        assert!(!reader.has_tile_s2(Face::Face0, 1, 0, 0).unwrap());
+        assert_eq!(reader.get_tile_from_cell(S2CellId::from_face(1)).unwrap(), hello_world);
+        assert_eq!(reader.get_tile_from_cell(S2CellId::from_face(4)).unwrap(), None);
+
+        // the WM reader rejects S2 archives
+        let reader = PMTilesReader::new(BufferReader::from(&data[..]), None);
+        assert_eq!(reader.err(), Some(PMTilesError::InvalidMagicNumber));
+    }
+
+    #[test]
+    fn test_truncated_s2_archive() {
+        let data = fs::read(fixture_path("s2.s2pmtiles")).unwrap();
+        // the root directories and metadata are intact, the leaf directories and tiles are not
+        let reader = S2PMTilesReader::new(BufferReader::from(&data[..327]), None);
+        assert_eq!(reader.err(), Some(PMTilesError::OutOfBounds));
+        // a root directory is cut short
+        let reader = S2PMTilesReader::new(BufferReader::from(&data[..270]), None);
+        assert_eq!(reader.err(), Some(PMTilesError::OutOfBounds));
+
+        // a tile that points past the end of the archive is an error rather than a panic
+        let mut reader = S2PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+        reader.header.header.tile_data_offset = u64::MAX;
+        assert_eq!(reader.get_tile_s2(Face::Face0, 0, 0, 0), Err(PMTilesError::OutOfBounds));
+        reader.header.header.tile_data_offset = data.len() as u64;
+        assert_eq!(reader.get_tile_s2(Face::Face0, 0, 0, 0), Err(PMTilesError::OutOfBounds));
+    }
+}
@@ -0,0 +1,88 @@
+use super::{Header, PMTilesError};
+use crate::readers::{BufferReader, Reader};
+
+use alloc::vec::Vec;
+
+/// The size of an S2-PMTiles header in bytes
+pub const S2_HEADER_SIZE_BYTES: usize = 262;
+
+/// The header and the root directories of all 6 faces must fit within the first 98,304 bytes
+pub const S2_ROOT_SIZE: usize = 98_304;
+
+/// S2-PMTiles v1 header storing basic archive-level information. Each of the 6 faces of the S2
+/// projection has its own root and leaf directories.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct S2Header {
+    /// The shared PMTiles header fields. The root and leaf directory fields describe face 0.
+    /// The bounds and center fields are not used by the S2 variant.
+    pub header: Header,
+    /// The offset of the root directory of each face in bytes
+    pub root_directory_offsets: [u64; 6],
+    /// The length of the root directory of each face in bytes
+    pub root_directory_lengths: [u64; 6],
+    /// The offset of the leaf directories of each face in bytes
+    pub leaf_directory_offsets: [u64; 6],
+    /// The length of the leaf directories of each face in bytes
+    pub leaf_directory_lengths: [u64; 6],
+}
+impl S2Header {
+    /// Parse the raw header bytes. Only the first [`S2_HEADER_SIZE_BYTES`] are used.
+    pub fn from_bytes(bytes: &[u8]) -> Result<S2Header, PMTilesError> {
+        if bytes.len() < S2_HEADER_SIZE_BYTES {
+            return Err(PMTilesError::InvalidHeader);
+        }
+        if !is_s2_archive(bytes) {
+            return Err(PMTilesError::InvalidMagicNumber);
+        }
+        let header = Header::from_bytes_unchecked(bytes);
+        if header.spec_version != 1 {
+            return Err(PMTilesError::UnsupportedVersion(header.spec_version));
+        }
+        let mut s2_header = S2Header { header, ..Default::default() };
+        s2_header.root_directory_offsets[0] = header.root_directory_offset;
+        s2_header.root_directory_lengths[0] = header.root_directory_length;
+        s2_header.leaf_directory_offsets[0] = header.leaf_directory_offset;
+        s2_header.leaf_directory_lengths[0] = header.leaf_directory_length;
+        let mut reader = BufferReader::new(bytes);
+        for face in 1..6 {
+            let root = 102 + (face - 1) * 16;
+            let leaf = 182 + (face - 1) * 16;
+            s2_header.root_directory_offsets[face] = reader.uint64_le(Some(root));
+            s2_header.root_directory_lengths[face] = reader.uint64_le(Some(root + 8));
+            s2_header.leaf_directory_offsets[face] = reader.uint64_le(Some(leaf));
+            s2_header.leaf_directory_lengths[face] = reader.uint64_le(Some(leaf + 8));
+        }
+
+        Ok(s2_header)
+    }
+
+    /// Serialize the header into its raw [`S2_HEADER_SIZE_BYTES`] bytes. The root and leaf
+    /// directory fields of the shared header are taken from face 0.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut header = self.header;
+        header.root_directory_offset = self.root_directory_offsets[0];
+        header.root_directory_length = self.root_directory_lengths[0];
+        header.leaf_directory_offset = self.leaf_directory_offsets[0];
+        header.leaf_directory_length = self.leaf_directory_lengths[0];
+
+        let mut bytes = Vec::with_capacity(S2_HEADER_SIZE_BYTES);
+        bytes.extend_from_slice(b"S2\0\0\0\0\0");
+        bytes.push(header.spec_version);
+        header.write_shared_bytes(&mut bytes);
+        for face in 1..6 {
+            bytes.extend_from_slice(&self.root_directory_offsets[face].to_le_bytes());
+            bytes.extend_from_slice(&self.root_directory_lengths[face].to_le_bytes());
+        }
+        for face in 1..6 {
+            bytes.extend_from_slice(&self.leaf_directory_offsets[face].to_le_bytes());
+            bytes.extend_from_slice(&self.leaf_directory_lengths[face].to_le_bytes());
+        }
+
+        bytes
+    }
+}
+
+/// Check if the raw bytes start with the S2-PMTiles magic number (`S2`)
+pub fn is_s2_archive(bytes: &[u8]) -> bool {
+    bytes.starts_with(b"S2")
+}
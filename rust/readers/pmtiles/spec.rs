@@ -0,0 +1,521 @@
+use crate::readers::{BufferReader, Reader};
+use crate::util::{CompressError, CompressionFormat};
+use crate::writers::WriterError;
+
+use pbf::Protobuf;
+
+use alloc::vec::Vec;
+
+/// The size of a PMTiles v3 header in bytes
+pub const HEADER_SIZE_BYTES: usize = 127;
+
+/// The header and root directory must fit within the first 16,384 bytes of the archive
+pub const ROOT_SIZE: usize = 16_384;
+
+/// The maximum zoom level a Hilbert tile ID can be encoded with
+pub const MAX_ZOOM: u8 = 26;
+
+/// Errors that can occur while reading or writing a PMTiles archive
+#[derive(Debug, PartialEq)]
+pub enum PMTilesError {
+    /// The archive is too small to contain a header
+    InvalidHeader,
+    /// The magic number at the start of the archive is not `PM`
+    InvalidMagicNumber,
+    /// The archive's spec version is not supported
+    UnsupportedVersion(u8),
+    /// The metadata is not valid JSON
+    InvalidMetadata,
+    /// A directory, the metadata or a tile extends past the end of the archive
+    OutOfBounds,
+    /// A leaf directory was found to be empty
+    EmptyDirectory,
+    /// More than 3 levels of leaf directories were found
+    MaxDepthExceeded,
+    /// Errors from decompressing data
+    Compression(CompressError),
+    /// WM and S2 tiles were written to the same archive
+    MixedProjections,
+    /// The archive could not be written
+    Writer(WriterError),
+}
+impl From<CompressError> for PMTilesError {
+    fn from(err: CompressError) -> Self {
+        PMTilesError::Compression(err)
+    }
+}
+impl From<WriterError> for PMTilesError {
+    fn from(err: WriterError) -> Self {
+        PMTilesError::Writer(err)
+    }
+}
+
+/// PMTiles v3 directory entry.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// The Hilbert tile ID (see [`zxy_to_tile_id`])
+    pub tile_id: u64,
+    /// The offset of the tile (or leaf directory) relative to the start of the tile data (or
+    /// leaf directories) section
+    pub offset: u64,
+    /// The length of the tile (or leaf directory) in bytes
+    pub length: u32,
+    /// Number of consecutive tile IDs that share the same data. 0 means the entry points to a
+    /// leaf directory
+    pub run_length: u32,
+}
+
+/// Describe the type of tiles stored in the archive.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TileType {
+    /// unknown/other
+    #[default]
+    Unknown = 0,
+    /// Vector tiles
+    Pbf = 1,
+    /// PNG image tiles
+    Png = 2,
+    /// JPEG image tiles
+    Jpeg = 3,
+    /// WEBP image tiles
+    Webp = 4,
+    /// AVIF image tiles
+    Avif = 5,
+}
+impl From<u8> for TileType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => TileType::Pbf,
+            2 => TileType::Png,
+            3 => TileType::Jpeg,
+            4 => TileType::Webp,
+            5 => TileType::Avif,
+            _ => TileType::Unknown,
+        }
+    }
+}
+
+/// The compression used for the internal data (directories and metadata) or the tiles
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    /// unknown compression, for if you must use a different or unspecified algorithm
+    #[default]
+    Unknown = 0,
+    /// no compression
+    None = 1,
+    /// gzip
+    Gzip = 2,
+    /// brotli
+    Brotli = 3,
+    /// zstd
+    Zstd = 4,
+}
+impl From<u8> for Compression {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Compression::None,
+            2 => Compression::Gzip,
+            3 => Compression::Brotli,
+            4 => Compression::Zstd,
+            _ => Compression::Unknown,
+        }
+    }
+}
+impl From<Compression> for CompressionFormat {
+    fn from(compression: Compression) -> Self {
+        match compression {
+            Compression::Unknown | Compression::None => CompressionFormat::None,
+            Compression::Gzip => CompressionFormat::Gzip,
+            Compression::Brotli => CompressionFormat::Brotli,
+            Compression::Zstd => CompressionFormat::Zstd,
+        }
+    }
+}
+
+/// PMTiles v3 header storing basic archive-level information.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct Header {
+    /// The version of the spec
+    pub spec_version: u8,
+    /// The offset of the root directory in bytes
+    pub root_directory_offset: u64,
+    /// The length of the root directory in bytes
+    pub root_directory_length: u64,
+    /// The offset of the JSON metadata in bytes
+    pub json_metadata_offset: u64,
+    /// The length of the JSON metadata in bytes
+    pub json_metadata_length: u64,
+    /// The offset of the leaf directories in bytes
+    pub leaf_directory_offset: u64,
+    /// The length of the leaf directories in bytes
+    pub leaf_directory_length: u64,
+    /// The offset of the tile data in bytes
+    pub tile_data_offset: u64,
+    /// The length of the tile data in bytes
+    pub tile_data_length: u64,
+    /// The number of tiles addressed by the directories
+    pub n_addressed_tiles: u64,
+    /// The number of tile entries in the directories
+    pub n_tile_entries: u64,
+    /// The number of distinct tile contents
+    pub n_tile_contents: u64,
+    /// True if the tile data is ordered by tile ID
+    pub clustered: bool,
+    /// The compression used by the directories and metadata
+    pub internal_compression: Compression,
+    /// The compression used by the tiles
+    pub tile_compression: Compression,
+    /// The type of tiles stored in the archive
+    pub tile_type: TileType,
+    /// The minimum zoom level
+    pub min_zoom: u8,
+    /// The maximum zoom level
+    pub max_zoom: u8,
+    /// The minimum longitude of the bounds (multiplied by 10,000,000)
+    pub min_longitude_e7: i32,
+    /// The minimum latitude of the bounds (multiplied by 10,000,000)
+    pub min_latitude_e7: i32,
+    /// The maximum longitude of the bounds (multiplied by 10,000,000)
+    pub max_longitude_e7: i32,
+    /// The maximum latitude of the bounds (multiplied by 10,000,000)
+    pub max_latitude_e7: i32,
+    /// The zoom level to initially display the archive at
+    pub center_zoom: u8,
+    /// The longitude of the center (multiplied by 10,000,000)
+    pub center_longitude_e7: i32,
+    /// The latitude of the center (multiplied by 10,000,000)
+    pub center_latitude_e7: i32,
+}
+impl Header {
+    /// Parse the raw header bytes. Only the first [`HEADER_SIZE_BYTES`] are used.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Header, PMTilesError> {
+        if bytes.len() < HEADER_SIZE_BYTES {
+            return Err(PMTilesError::InvalidHeader);
+        }
+        if &bytes[0..2] != b"PM" {
+            return Err(PMTilesError::InvalidMagicNumber);
+        }
+        let spec_version = bytes[7];
+        if spec_version != 3 {
+            return Err(PMTilesError::UnsupportedVersion(spec_version));
+        }
+        let mut header = Header::from_bytes_unchecked(bytes);
+        let mut reader = BufferReader::new(bytes);
+        header.min_longitude_e7 = reader.int32_le(Some(102));
+        header.min_latitude_e7 = reader.int32_le(Some(106));
+        header.max_longitude_e7 = reader.int32_le(Some(110));
+        header.max_latitude_e7 = reader.int32_le(Some(114));
+        header.center_zoom = reader.uint8(Some(118));
+        header.center_longitude_e7 = reader.int32_le(Some(119));
+        header.center_latitude_e7 = reader.int32_le(Some(123));
+
+        Ok(header)
+    }
+
+    /// Serialize the header into its raw [`HEADER_SIZE_BYTES`] bytes
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(HEADER_SIZE_BYTES);
+        bytes.extend_from_slice(b"PMTiles");
+        bytes.push(self.spec_version);
+        self.write_shared_bytes(&mut bytes);
+        bytes.extend_from_slice(&self.min_longitude_e7.to_le_bytes());
+        bytes.extend_from_slice(&self.min_latitude_e7.to_le_bytes());
+        bytes.extend_from_slice(&self.max_longitude_e7.to_le_bytes());
+        bytes.extend_from_slice(&self.max_latitude_e7.to_le_bytes());
+        bytes.push(self.center_zoom);
+        bytes.extend_from_slice(&self.center_longitude_e7.to_le_bytes());
+        bytes.extend_from_slice(&self.center_latitude_e7.to_le_bytes());
+
+        bytes
+    }
+
+    /// Serialize bytes 8 to 102 of the header that are shared with the S2 variant
+    pub(crate) fn write_shared_bytes(&self, bytes: &mut Vec<u8>) {
+        for value in [
+            self.root_directory_offset,
+            self.root_directory_length,
+            self.json_metadata_offset,
+            self.json_metadata_length,
+            self.leaf_directory_offset,
+            self.leaf_directory_length,
+            self.tile_data_offset,
+            self.tile_data_length,
+            self.n_addressed_tiles,
+            self.n_tile_entries,
+            self.n_tile_contents,
+        ] {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+        bytes.push(self.clustered as u8);
+        bytes.push(self.internal_compression as u8);
+        bytes.push(self.tile_compression as u8);
+        bytes.push(self.tile_type as u8);
+        bytes.push(self.min_zoom);
+        bytes.push(self.max_zoom);
+    }
+
+    /// Parse the first 102 bytes of the header that are shared with the S2 variant without
+    /// validating the magic number or version.
+    pub(crate) fn from_bytes_unchecked(bytes: &[u8]) -> Header {
+        let mut reader = BufferReader::new(bytes);
+        Header {
+            spec_version: reader.uint8(Some(7)),
+            root_directory_offset: reader.uint64_le(Some(8)),
+            root_directory_length: reader.uint64_le(Some(16)),
+            json_metadata_offset: reader.uint64_le(Some(24)),
+            json_metadata_length: reader.uint64_le(Some(32)),
+            leaf_directory_offset: reader.uint64_le(Some(40)),
+            leaf_directory_length: reader.uint64_le(Some(48)),
+            tile_data_offset: reader.uint64_le(Some(56)),
+            tile_data_length: reader.uint64_le(Some(64)),
+            n_addressed_tiles: reader.uint64_le(Some(72)),
+            n_tile_entries: reader.uint64_le(Some(80)),
+            n_tile_contents: reader.uint64_le(Some(88)),
+            clustered: reader.uint8(Some(96)) == 1,
+            internal_compression: reader.uint8(Some(97)).into(),
+            tile_compression: reader.uint8(Some(98)).into(),
+            tile_type: reader.uint8(Some(99)).into(),
+            min_zoom: reader.uint8(Some(100)),
+            max_zoom: reader.uint8(Some(101)),
+            ..Default::default()
+        }
+    }
+}
+
+/// Rotate a point within a Hilbert curve quadrant
+fn rotate(n: u64, xy: &mut [u64; 2], rx: u64, ry: u64) {
+    if ry == 0 {
+        if rx == 1 {
+            xy[0] = n - 1 - xy[0];
+            xy[1] = n - 1 - xy[1];
+        }
+        xy.swap(0, 1);
+    }
+}
+
+/// Number of tiles in all zoom levels below `zoom`
+fn zoom_acc(zoom: u8) -> u64 {
+    ((1u64 << (2 * zoom as u64)) - 1) / 3
+}
+
+/// Convert Z,X,Y to a Hilbert TileID.
+/// The caller must ensure the zoom is at most [`MAX_ZOOM`] and x and y are within the zoom's
+/// bounds.
+pub fn zxy_to_tile_id(zoom: u8, x: u32, y: u32) -> u64 {
+    let n = 1u64 << zoom;
+    let mut xy = [x as u64, y as u64];
+    let mut d = 0;
+    let mut s = n / 2;
+    while s > 0 {
+        let rx = if (xy[0] & s) > 0 { 1 } else { 0 };
+        let ry = if (xy[1] & s) > 0 { 1 } else { 0 };
+        d += s * s * ((3 * rx) ^ ry);
+        rotate(n, &mut xy, rx, ry);
+        s /= 2;
+    }
+    zoom_acc(zoom) + d
+}
+
+/// Convert a Hilbert TileID to Z,X,Y.
+pub fn tile_id_to_zxy(tile_id: u64) -> (u8, u32, u32) {
+    let mut zoom = 0;
+    while zoom < MAX_ZOOM && zoom_acc(zoom + 1) <= tile_id {
+        zoom += 1;
+    }
+    let pos = tile_id - zoom_acc(zoom);
+    let n = 1u64 << zoom;
+    let mut t = pos;
+    let mut xy = [0, 0];
+    let mut s = 1;
+    while s < n {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+        rotate(s, &mut xy, rx, ry);
+        xy[0] += s * rx;
+        xy[1] += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+    (zoom, xy[0] as u32, xy[1] as u32)
+}
+
+/// Low-level function for looking up a TileID or leaf directory inside a directory.
+pub fn find_tile(entries: &[Entry], tile_id: u64) -> Option<&Entry> {
+    let n = entries.partition_point(|e| e.tile_id <= tile_id);
+    let entry = entries.get(n.checked_sub(1)?)?;
+    if entry.tile_id == tile_id
+        || entry.run_length == 0
+        || tile_id - entry.tile_id < entry.run_length as u64
+    {
+        Some(entry)
+    } else {
+        None
+    }
+}
+
+/// Serialize directory entries (sorted by tile ID) into their raw (uncompressed) form.
+pub fn serialize_dir(entries: &[Entry]) -> Vec<u8> {
+    let mut pbf = Protobuf::new();
+    pbf.write_varint(entries.len() as u64);
+
+    let mut last_id = 0;
+    for entry in entries {
+        pbf.write_varint(entry.tile_id - last_id);
+        last_id = entry.tile_id;
+    }
+    for entry in entries {
+        pbf.write_varint(entry.run_length);
+    }
+    for entry in entries {
+        pbf.write_varint(entry.length);
+    }
+    for (i, entry) in entries.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|i| &entries[i]);
+        match prev {
+            Some(prev) if entry.offset == prev.offset + prev.length as u64 => pbf.write_varint(0),
+            _ => pbf.write_varint(entry.offset + 1),
+        }
+    }
+
+    pbf.take()
+}
+
+/// Deserialize a (decompressed) directory into its entries.
+pub fn deserialize_dir(buffer: Vec<u8>) -> Vec<Entry> {
+    let mut pbf = Protobuf::from(buffer);
+    let n_entries = pbf.decode_varint() as usize;
+    let mut entries: Vec<Entry> = Vec::with_capacity(n_entries);
+
+    let mut last_id = 0;
+    for _ in 0..n_entries {
+        last_id += pbf.decode_varint();
+        entries.push(Entry { tile_id: last_id, offset: 0, length: 0, run_length: 1 });
+    }
+    // run lengths, lengths, and offsets
+    for entry in entries.iter_mut() {
+        entry.run_length = pbf.decode_varint() as u32;
+    }
+    for entry in entries.iter_mut() {
+        entry.length = pbf.decode_varint() as u32;
+    }
+    for i in 0..n_entries {
+        let v = pbf.decode_varint();
+        entries[i].offset = if v == 0 && i > 0 {
+            entries[i - 1].offset + entries[i - 1].length as u64
+        } else {
+            v.saturating_sub(1)
+        };
+    }
+
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_zxy_to_tile_id() {
+        assert_eq!(zxy_to_tile_id(0, 0, 0), 0);
+        assert_eq!(zxy_to_tile_id(1, 0, 1), 2);
+        assert_eq!(zxy_to_tile_id(1, 1, 0), 4);
+        assert_eq!(zxy_to_tile_id(1, 1, 1), 3);
+        assert_eq!(zxy_to_tile_id(2, 0, 0), 5);
+        assert_eq!(zxy_to_tile_id(2, 0, 1), 8);
+        assert_eq!(zxy_to_tile_id(2, 1, 0), 6);
+        assert_eq!(zxy_to_tile_id(2, 1, 1), 7);
+        assert_eq!(zxy_to_tile_id(20, 1_002, 6_969), 366_567_509_724);
+    }
+
+    #[test]
+    fn test_tile_id_to_zxy() {
+        assert_eq!(tile_id_to_zxy(0), (0, 0, 0));
+        assert_eq!(tile_id_to_zxy(2), (1, 0, 1));
+        assert_eq!(tile_id_to_zxy(4), (1, 1, 0));
+        assert_eq!(tile_id_to_zxy(3), (1, 1, 1));
+        assert_eq!(tile_id_to_zxy(5), (2, 0, 0));
+        assert_eq!(tile_id_to_zxy(8), (2, 0, 1));
+        assert_eq!(tile_id_to_zxy(6), (2, 1, 0));
+        assert_eq!(tile_id_to_zxy(7), (2, 1, 1));
+        assert_eq!(tile_id_to_zxy(366_567_509_724), (20, 1_002, 6_969));
+    }
+
+    #[test]
+    fn test_find_tile() {
+        let entries: Vec<Entry> = (0..10)
+            .map(|i| Entry { tile_id: i, offset: i * 2, length: 100, run_length: 1 })
+            .collect();
+        assert_eq!(find_tile(&entries, 0), Some(&entries[0]));
+        assert_eq!(find_tile(&entries, 2), Some(&entries[2]));
+        assert_eq!(find_tile(&entries, 11), None);
+
+        // run lengths and leaf directories
+        let entries = vec![
+            Entry { tile_id: 5, offset: 0, length: 10, run_length: 3 },
+            Entry { tile_id: 20, offset: 10, length: 10, run_length: 0 },
+        ];
+        assert_eq!(find_tile(&entries, 4), None);
+        assert_eq!(find_tile(&entries, 7), Some(&entries[0]));
+        assert_eq!(find_tile(&entries, 8), None);
+        assert_eq!(find_tile(&entries, 50), Some(&entries[1]));
+    }
+
+    #[test]
+    fn test_serialize_dir() {
+        let entries: Vec<Entry> = (0..10)
+            .map(|i| Entry { tile_id: i * 3, offset: i * 100, length: 100, run_length: 1 })
+            .chain([
+                Entry { tile_id: 50, offset: 0, length: 100, run_length: 5 },
+                Entry { tile_id: 60, offset: 5_000, length: 20, run_length: 0 },
+            ])
+            .collect();
+        let bytes = serialize_dir(&entries);
+        assert_eq!(deserialize_dir(bytes), entries);
+    }
+
+    #[test]
+    fn test_header_to_bytes() {
+        let header = Header {
+            spec_version: 3,
+            root_directory_offset: 127,
+            root_directory_length: 634,
+            json_metadata_offset: 720,
+            json_metadata_length: 7,
+            leaf_directory_offset: 6,
+            leaf_directory_length: 100,
+            tile_data_offset: 5,
+            tile_data_length: 4,
+            n_addressed_tiles: 3,
+            n_tile_entries: 2,
+            n_tile_contents: 1,
+            clustered: true,
+            internal_compression: Compression::None,
+            tile_compression: Compression::Zstd,
+            tile_type: TileType::Pbf,
+            min_zoom: 1,
+            max_zoom: 10,
+            min_longitude_e7: -1_800_000_000,
+            min_latitude_e7: -850_000_000,
+            max_longitude_e7: 1_800_000_000,
+            max_latitude_e7: 850_000_000,
+            center_zoom: 4,
+            center_longitude_e7: 10,
+            center_latitude_e7: -10,
+        };
+        let bytes = header.to_bytes();
+        assert_eq!(bytes.len(), HEADER_SIZE_BYTES);
+        assert_eq!(&bytes[0..8], b"PMTiles\x03");
+        assert_eq!(Header::from_bytes(&bytes), Ok(header));
+    }
+
+    #[test]
+    fn test_header_errors() {
+        assert_eq!(Header::from_bytes(&[0; 20]), Err(PMTilesError::InvalidHeader));
+        assert_eq!(Header::from_bytes(&[0; 127]), Err(PMTilesError::InvalidMagicNumber));
+        let mut bytes = [0; 127];
+        bytes[0..2].copy_from_slice(b"PM");
+        bytes[7] = 2;
+        assert_eq!(Header::from_bytes(&bytes), Err(PMTilesError::UnsupportedVersion(2)));
+    }
+}
@@ -0,0 +1,194 @@
+use crate::geometry::{PrimitiveValue, Properties, ValueType};
+use crate::readers::Reader;
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+/// The text encoding of the DBF strings. Usually described by the `.cpg` file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum DBFEncoding {
+    /// UTF-8 encoded strings. Invalid sequences are replaced
+    #[default]
+    Utf8,
+    /// Single byte Latin-1 (ISO-8859-1) encoded strings
+    Latin1,
+}
+impl DBFEncoding {
+    /// Parse the contents of a `.cpg` file. Unknown code pages fall back to UTF-8
+    pub fn from_cpg(cpg: &str) -> Self {
+        let cpg = cpg.trim().to_lowercase();
+        if cpg.contains("8859-1") || cpg.contains("latin1") || cpg.contains("1252") {
+            DBFEncoding::Latin1
+        } else {
+            DBFEncoding::Utf8
+        }
+    }
+
+    /// Decode the raw bytes into a string
+    pub fn decode(&self, bytes: &[u8]) -> String {
+        match self {
+            DBFEncoding::Utf8 => String::from_utf8_lossy(bytes).to_string(),
+            DBFEncoding::Latin1 => bytes.iter().map(|b| *b as char).collect(),
+        }
+    }
+}
+
+/// The Header data explaining the contents of the DBF file
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DBFHeader {
+    /// The year the file was last updated
+    pub year: u16,
+    /// The month the file was last updated (1-12)
+    pub month: u8,
+    /// The day the file was last updated (1-31)
+    pub day: u8,
+    /// The number of records
+    pub records: u32,
+    /// The length of the header data
+    pub header_len: u16,
+    /// The length of each record
+    pub rec_len: u16,
+}
+
+/// Each field describes a column of the records
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DBFField {
+    /// The name of the field
+    pub name: String,
+    /// The data type of the field, e.g. `C` (character), `N` (numeric), `L` (logical), `D` (date)
+    pub data_type: char,
+    /// The length of the field in bytes
+    pub len: u8,
+    /// The number of decimal places of the field
+    pub decimal: u8,
+}
+
+/// # DataBase File Reader
+///
+/// ## Description
+/// Reads the attributes (`.dbf`) paired with a shapefile.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, DataBaseFile};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/shapefile/fixtures/utf.dbf");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let mut dbf = DataBaseFile::new(BufferReader::from(&data[..]), None);
+/// assert_eq!(dbf.get_header().records, 2);
+/// let properties = dbf.get_properties(0).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DataBaseFile<T: Reader> {
+    reader: T,
+    header: DBFHeader,
+    fields: Vec<DBFField>,
+    encoding: DBFEncoding,
+}
+impl<T: Reader> DataBaseFile<T> {
+    /// Create a new DataBaseFile reader. `encoding` defaults to UTF-8
+    pub fn new(mut reader: T, encoding: Option<DBFEncoding>) -> Self {
+        let encoding = encoding.unwrap_or_default();
+        let header = DBFHeader {
+            year: reader.uint8(Some(1)) as u16 + 1900,
+            month: reader.uint8(Some(2)),
+            day: reader.uint8(Some(3)),
+            records: reader.uint32_le(Some(4)),
+            header_len: reader.uint16_le(Some(8)),
+            rec_len: reader.uint16_le(Some(10)),
+        };
+
+        // field descriptors are 32 bytes each and are terminated by 0x0D
+        let mut fields = Vec::new();
+        let mut offset = 32;
+        let end = (header.header_len as usize).min(reader.len());
+        while offset + 32 <= end && reader.uint8(Some(offset)) != 0x0D {
+            let name = reader.slice(Some(offset), Some(offset + 11));
+            let name_end = name.iter().position(|b| *b == 0).unwrap_or(name.len());
+            fields.push(DBFField {
+                name: encoding.decode(&name[..name_end]).trim().to_string(),
+                data_type: reader.uint8(Some(offset + 11)) as char,
+                len: reader.uint8(Some(offset + 16)),
+                decimal: reader.uint8(Some(offset + 17)),
+            });
+            offset += 32;
+        }
+
+        Self { reader, header, fields, encoding }
+    }
+
+    /// Get the header
+    pub fn get_header(&self) -> DBFHeader {
+        self.header
+    }
+
+    /// Get the field descriptors
+    pub fn fields(&self) -> &[DBFField] {
+        &self.fields
+    }
+
+    /// Get the properties of the record at `index`. Returns `None` if the record doesn't exist
+    pub fn get_properties(&mut self, index: usize) -> Option<Properties> {
+        let DBFHeader { records, header_len, rec_len, .. } = self.header;
+        if index >= records as usize {
+            return None;
+        }
+        // skip the deletion flag
+        let mut offset = header_len as usize + index * rec_len as usize + 1;
+        let mut properties = Properties::new();
+        for field in &self.fields {
+            let end = offset + field.len as usize;
+            if end > self.reader.len() {
+                break;
+            }
+            let raw = self.reader.slice(Some(offset), Some(end));
+            let text = self.encoding.decode(&raw);
+            if let Some(value) =
+                parse_value(text.trim_matches(|c: char| c == '\0' || c == ' '), field)
+            {
+                properties.insert(field.name.clone(), ValueType::Primitive(value));
+            }
+            offset = end;
+        }
+        Some(properties)
+    }
+
+    /// Get the properties of every record
+    pub fn get_all_properties(&mut self) -> Vec<Properties> {
+        (0..self.header.records as usize).filter_map(|i| self.get_properties(i)).collect()
+    }
+}
+
+/// Parse a field's value. Empty numbers, dates and logicals are skipped
+fn parse_value(text: &str, field: &DBFField) -> Option<PrimitiveValue> {
+    match field.data_type {
+        'N' | 'F' | 'O' => {
+            if field.decimal == 0 {
+                if let Ok(int) = text.parse::<i64>() {
+                    return Some(PrimitiveValue::I64(int));
+                }
+            }
+            text.parse::<f64>().ok().map(PrimitiveValue::F64)
+        }
+        'D' => {
+            // YYYYMMDD -> YYYY-MM-DD
+            if text.len() != 8 || !text.bytes().all(|b| b.is_ascii_digit()) {
+                return None;
+            }
+            Some(PrimitiveValue::String(
+                text[0..4].to_string() + "-" + &text[4..6] + "-" + &text[6..8],
+            ))
+        }
+        'L' => match text.to_lowercase().as_str() {
+            "y" | "t" => Some(PrimitiveValue::Bool(true)),
+            "n" | "f" => Some(PrimitiveValue::Bool(false)),
+            _ => None,
+        },
+        _ => Some(PrimitiveValue::String(text.to_string())),
+    }
+}
@@ -0,0 +1,377 @@
+/// DataBase File (.dbf) Reader
+pub mod dbf;
+/// Shapefile (.shp) Reader
+pub mod shp;
+
+pub use dbf::*;
+pub use shp::*;
+
+#[cfg(feature = "std")]
+use crate::readers::FileReader;
+use crate::{
+    readers::OwnedBufferReader,
+    util::{iter_items, CompressError},
+};
+
+use alloc::string::String;
+
+/// Errors that can occur while reading a shapefile
+#[derive(Debug, PartialEq)]
+pub enum ShapeFileError {
+    /// The `.shp` or `.shx` header is invalid
+    InvalidHeader,
+    /// The `.shp` file could not be found
+    MissingShp,
+    /// The zip archive could not be read
+    Compression(CompressError),
+    /// A file could not be read
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+impl From<CompressError> for ShapeFileError {
+    fn from(err: CompressError) -> Self {
+        ShapeFileError::Compression(err)
+    }
+}
+#[cfg(feature = "std")]
+impl From<std::io::Error> for ShapeFileError {
+    fn from(err: std::io::Error) -> Self {
+        ShapeFileError::Io(err.kind())
+    }
+}
+
+/// # Read a Shapefile from a zip archive
+///
+/// ## Description
+/// Finds the `.shp`, `.shx`, `.dbf`, `.prj` and `.cpg` files inside the archive (the first of
+/// each is used) and builds a [`ShapeFileReader`].
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::shapefile_from_zip;
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/shapefile/fixtures/utf.zip");
+/// let data = std::fs::read(path).unwrap();
+///
+/// let reader = shapefile_from_zip(&data).unwrap();
+/// assert_eq!(reader.count(), 2);
+/// ```
+pub fn shapefile_from_zip(
+    data: &[u8],
+) -> Result<ShapeFileReader<OwnedBufferReader>, ShapeFileError> {
+    let mut shp = None;
+    let mut shx = None;
+    let mut dbf = None;
+    let mut prj = None;
+    let mut cpg = None;
+    for item in iter_items(data)? {
+        let name = item.filename.to_lowercase();
+        // skip folders and macOS resource forks
+        if name.ends_with('/') || name.starts_with("__macosx") {
+            continue;
+        }
+        let slot = match name.rsplit('.').next() {
+            Some("shp") => &mut shp,
+            Some("shx") => &mut shx,
+            Some("dbf") => &mut dbf,
+            Some("prj") => &mut prj,
+            Some("cpg") => &mut cpg,
+            _ => continue,
+        };
+        if slot.is_none() {
+            *slot = Some((item.read)()?);
+        }
+    }
+    let shp = shp.ok_or(ShapeFileError::MissingShp)?;
+    let encoding = cpg.map(|cpg| DBFEncoding::from_cpg(&String::from_utf8_lossy(&cpg)));
+    let dbf = dbf.map(|dbf| DataBaseFile::new(OwnedBufferReader::from(dbf), encoding));
+    let prj = prj.map(|prj| String::from_utf8_lossy(&prj).into_owned());
+
+    ShapeFileReader::new(OwnedBufferReader::from(shp), shx.map(OwnedBufferReader::from), dbf, prj)
+}
+
+/// # Read a Shapefile from the filesystem
+///
+/// ## Description
+/// Given the path to the `.shp` file (or the path without the extension), the paired `.shx`,
+/// `.dbf`, `.prj` and `.cpg` files are loaded if they exist.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::shapefile_from_path;
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/shapefile/fixtures/utf.shp");
+///
+/// let reader = shapefile_from_path(path).unwrap();
+/// assert_eq!(reader.count(), 2);
+/// ```
+#[cfg(feature = "std")]
+pub fn shapefile_from_path(
+    path: std::path::PathBuf,
+) -> Result<ShapeFileReader<FileReader>, ShapeFileError> {
+    let shp_path = path.with_extension("shp");
+    if !shp_path.exists() {
+        return Err(ShapeFileError::MissingShp);
+    }
+    let existing = |ext: &str| Some(path.with_extension(ext)).filter(|p| p.exists());
+    let encoding = match existing("cpg") {
+        Some(cpg) => Some(DBFEncoding::from_cpg(&std::fs::read_to_string(cpg)?)),
+        None => None,
+    };
+    let shx = existing("shx").map(FileReader::new).transpose()?;
+    let dbf = match existing("dbf") {
+        Some(dbf) => Some(DataBaseFile::new(FileReader::new(dbf)?, encoding)),
+        None => None,
+    };
+    let prj = existing("prj").map(std::fs::read_to_string).transpose()?;
+
+    ShapeFileReader::new(FileReader::new(shp_path)?, shx, dbf, prj)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::geometry::{
+        BBox3D, MValue, PrimitiveValue, Properties, ValueType, VectorGeometry, VectorPoint,
+    };
+    use crate::readers::{BufferReader, FeatureIterator};
+    use alloc::{string::ToString, vec, vec::Vec};
+    use std::path::PathBuf;
+
+    fn fixture(name: &str) -> PathBuf {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/readers/shapefile/fixtures");
+        path.push(name);
+        path
+    }
+
+    fn string_prop(key: &str, value: &str) -> (String, ValueType) {
+        (key.to_string(), ValueType::Primitive(PrimitiveValue::String(value.into())))
+    }
+
+    #[test]
+    fn test_utf_shp() {
+        let data = std::fs::read(fixture("utf.shp")).unwrap();
+        let reader = ShapeFileReader::new(BufferReader::from(&data[..]), None, None, None).unwrap();
+        assert_eq!(
+            reader.get_header(),
+            SHPHeader {
+                length: 156,
+                version: 1000,
+                shape_type: ShapeType::Point,
+                bbox: BBox3D::new(
+                    -108.97956848144531,
+                    41.244772343082076,
+                    -108.6328125,
+                    41.253032440653186,
+                    0.,
+                    0.
+                ),
+            }
+        );
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[0].id, Some(1));
+        assert_eq!(features[0].properties, Properties::new());
+        let VectorGeometry::Point(point) = &features[0].geometry else { panic!() };
+        assert_eq!(
+            point.coordinates,
+            VectorPoint::new(-108.6328125, 41.244772343082076, None, None)
+        );
+        assert_eq!(features[1].id, Some(2));
+    }
+
+    #[test]
+    fn test_dbf() {
+        let data = std::fs::read(fixture("utf.dbf")).unwrap();
+        let mut dbf = DataBaseFile::new(BufferReader::from(&data[..]), None);
+        assert_eq!(
+            dbf.get_header(),
+            DBFHeader { year: 1995, month: 7, day: 26, records: 2, header_len: 65, rec_len: 255 }
+        );
+        assert_eq!(dbf.get_properties(0), Some([string_prop("field", "💩")].into()));
+        assert_eq!(dbf.get_properties(1), Some([string_prop("field", "Hněvošický háj")].into()));
+        assert_eq!(dbf.get_properties(2), None);
+
+        let data = std::fs::read(fixture("empty.dbf")).unwrap();
+        let mut dbf = DataBaseFile::new(BufferReader::from(&data[..]), None);
+        assert_eq!(dbf.get_all_properties(), vec![Properties::new(), Properties::new()]);
+
+        let data = std::fs::read(fixture("watershed.dbf")).unwrap();
+        let mut dbf = DataBaseFile::new(BufferReader::from(&data[..]), None);
+        assert_eq!(dbf.get_header().records, 33);
+        let properties = dbf.get_all_properties();
+        assert_eq!(properties.len(), 33);
+        assert_eq!(
+            properties[0],
+            [
+                string_prop("DWM_NAME", "BUZZARDS BAY"),
+                string_prop("DWM_CODE", "95"),
+                string_prop("DRAINAGE", "coastal"),
+                (
+                    "SHAPE_AREA".to_string(),
+                    ValueType::Primitive(PrimitiveValue::F64(1100426424.93))
+                ),
+                ("SHAPE_LEN".to_string(), ValueType::Primitive(PrimitiveValue::F64(680071.913919))),
+            ]
+            .into()
+        );
+    }
+
+    #[test]
+    fn test_multipointz_shp() {
+        let reader = shapefile_from_path(fixture("export_multipointz.shp")).unwrap();
+        assert_eq!(reader.get_header().shape_type, ShapeType::MultiPointZ);
+        assert!(reader.prj.is_some());
+        assert_eq!(reader.crs().unwrap().name, "GCS_WGS_1984");
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 1);
+        let VectorGeometry::MultiPoint(points) = &features[0].geometry else { panic!() };
+        assert!(points.is_3d);
+        assert_eq!(points.bbox, Some(BBox3D::new(-123., 46., -121., 48., 1_200., 3_600.)));
+        let xyz: Vec<_> = points.coordinates.iter().map(|p| (p.x, p.y, p.z)).collect();
+        assert_eq!(
+            xyz,
+            vec![
+                (-123., 48., Some(1_200.)),
+                (-122., 47., Some(2_500.)),
+                (-121., 46., Some(3_600.))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_polylinez_shp() {
+        let reader = shapefile_from_path(fixture("export_polylinez")).unwrap();
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 1);
+        let VectorGeometry::MultiLineString(lines) = &features[0].geometry else { panic!() };
+        assert!(lines.is_3d);
+        assert_eq!(lines.coordinates.len(), 2);
+        let xyz: Vec<_> = lines.coordinates[0].iter().map(|p| (p.x, p.y, p.z)).collect();
+        assert_eq!(
+            xyz,
+            vec![(-120., 45., Some(800.)), (-119., 44., Some(1_100.)), (-118., 43., Some(2_300.))]
+        );
+    }
+
+    #[test]
+    fn test_polygons_shp() {
+        let mut reader = shapefile_from_path(fixture("counties.shp")).unwrap();
+        assert_eq!(reader.get_header().shape_type, ShapeType::Polygon);
+        let total = reader.len();
+        assert!(total > 0);
+        let mut count = 0;
+        while let Some(feature) = reader.next_feature() {
+            count += 1;
+            assert!(!feature.properties.is_empty());
+            let polygons = match &feature.geometry {
+                VectorGeometry::Polygon(p) => vec![p.coordinates.clone()],
+                VectorGeometry::MultiPolygon(p) => p.coordinates.clone(),
+                _ => panic!("expected polygons"),
+            };
+            for polygon in polygons {
+                // outer rings are counter-clockwise and holes clockwise
+                assert!(polygon_area(&polygon[0]) > 0.);
+                for hole in &polygon[1..] {
+                    assert!(polygon_area(hole) < 0.);
+                }
+            }
+        }
+        assert_eq!(count, total);
+    }
+
+    fn polygon_area(ring: &[VectorPoint]) -> f64 {
+        ring.windows(2).map(|w| w[0].x * w[1].y - w[1].x * w[0].y).sum()
+    }
+
+    #[test]
+    fn test_shapefile_from_zip() {
+        let data = std::fs::read(fixture("utf.zip")).unwrap();
+        let reader = shapefile_from_zip(&data).unwrap();
+        assert!(reader.prj.is_some());
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 2);
+        assert_eq!(features[1].properties, [string_prop("field", "Hněvošický háj")].into());
+
+        let data = std::fs::read(fixture("noshp.zip")).unwrap();
+        assert_eq!(shapefile_from_zip(&data).err(), Some(ShapeFileError::MissingShp));
+    }
+
+    #[test]
+    fn test_shx_index() {
+        let shp = std::fs::read(fixture("senate.shp")).unwrap();
+        let shx = std::fs::read(fixture("senate.shx")).unwrap();
+        let with_index = ShapeFileReader::new(
+            BufferReader::from(&shp[..]),
+            Some(BufferReader::from(&shx[..])),
+            None,
+            None,
+        )
+        .unwrap();
+        let without_index =
+            ShapeFileReader::new(BufferReader::from(&shp[..]), None, None, None).unwrap();
+        assert_eq!(with_index.len(), without_index.len());
+        assert!(with_index.zip(without_index).all(|(a, b)| a == b));
+
+        let bad = std::fs::read(fixture("bad.shp")).unwrap();
+        assert_eq!(
+            ShapeFileReader::new(BufferReader::from(&bad[..]), None, None, None).err(),
+            Some(ShapeFileError::InvalidHeader)
+        );
+    }
+
+    #[test]
+    fn test_m_values() {
+        // a single PolygonM record with a hole wound the wrong way
+        let mut shp = vec![0_u8; 100];
+        shp[0..4].copy_from_slice(&SHP_FILE_CODE.to_be_bytes());
+        shp[28..32].copy_from_slice(&1000_i32.to_le_bytes());
+        shp[32..36].copy_from_slice(&(ShapeType::PolygonM as i32).to_le_bytes());
+        let outer = [(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)];
+        let hole = [(2., 2.), (4., 2.), (4., 4.), (2., 2.)];
+        let mut content = vec![];
+        content.extend((ShapeType::PolygonM as i32).to_le_bytes());
+        for v in [0., 0., 10., 10.] {
+            content.extend(f64::to_le_bytes(v));
+        }
+        content.extend(2_i32.to_le_bytes());
+        content.extend(9_i32.to_le_bytes());
+        content.extend(0_i32.to_le_bytes());
+        content.extend(5_i32.to_le_bytes());
+        for (x, y) in outer.iter().chain(hole.iter()) {
+            content.extend(f64::to_le_bytes(*x));
+            content.extend(f64::to_le_bytes(*y));
+        }
+        content.extend(f64::to_le_bytes(0.));
+        content.extend(f64::to_le_bytes(8.));
+        for m in 0..9 {
+            content.extend(f64::to_le_bytes(if m == 1 { -1e39 } else { m as f64 }));
+        }
+        shp.extend(1_i32.to_be_bytes());
+        shp.extend(((content.len() / 2) as i32).to_be_bytes());
+        shp.extend(content);
+        let len = (shp.len() / 2) as i32;
+        shp[24..28].copy_from_slice(&len.to_be_bytes());
+
+        let mut reader =
+            ShapeFileReader::new(BufferReader::from(&shp[..]), None, None, None).unwrap();
+        let feature = reader.next().unwrap();
+        let VectorGeometry::Polygon(polygon) = &feature.geometry else { panic!() };
+        assert!(!polygon.is_3d);
+        assert_eq!(polygon.coordinates.len(), 2);
+        assert!(polygon_area(&polygon.coordinates[0]) > 0.);
+        assert!(polygon_area(&polygon.coordinates[1]) < 0.);
+        let m = |v: f64| -> Option<MValue> {
+            Some([("m".to_string(), ValueType::Primitive(PrimitiveValue::F64(v)))].into())
+        };
+        // the outer ring was reversed, so the "no data" M value is now second to last
+        let ms: Vec<_> = polygon.coordinates[0].iter().map(|p| p.m.clone()).collect();
+        assert_eq!(ms, vec![m(4.), m(3.), m(2.), None, m(0.)]);
+        assert!(reader.next().is_none());
+    }
+}
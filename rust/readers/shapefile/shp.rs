@@ -0,0 +1,529 @@
+use super::{DataBaseFile, ShapeFileError};
+use crate::geometry::{
+    point_in_ring, rewind, BBox3D, MValue, PrimitiveValue, ValueType, VectorFeature,
+    VectorGeometry, VectorGeometryType, VectorLineString, VectorLineStringGeometry,
+    VectorMultiLineStringGeometry, VectorMultiPointGeometry, VectorMultiPolygonGeometry,
+    VectorPoint, VectorPointGeometry, VectorPolygon, VectorPolygonGeometry,
+};
+use crate::readers::{parse_wkt_crs, FeatureIterator, Reader, WKTCRS};
+
+use alloc::{string::String, vec, vec::Vec};
+
+/// The magic file code found at the start of every `.shp` and `.shx` file
+pub const SHP_FILE_CODE: i32 = 9994;
+/// M values less than this are considered "no data"
+const NO_DATA_M: f64 = -1e38;
+
+/// The shape types a shapefile may store
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeType {
+    /// Empty shape
+    #[default]
+    Null = 0,
+    /// Point
+    Point = 1,
+    /// PolyLine (one or more lines)
+    PolyLine = 3,
+    /// Polygon (one or more rings)
+    Polygon = 5,
+    /// MultiPoint
+    MultiPoint = 8,
+    /// Point with Z (and optional M)
+    PointZ = 11,
+    /// PolyLine with Z (and optional M)
+    PolyLineZ = 13,
+    /// Polygon with Z (and optional M)
+    PolygonZ = 15,
+    /// MultiPoint with Z (and optional M)
+    MultiPointZ = 18,
+    /// Point with M
+    PointM = 21,
+    /// PolyLine with M
+    PolyLineM = 23,
+    /// Polygon with M
+    PolygonM = 25,
+    /// MultiPoint with M
+    MultiPointM = 28,
+    /// MultiPatch (not supported)
+    MultiPatch = 31,
+}
+impl From<i32> for ShapeType {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => ShapeType::Point,
+            3 => ShapeType::PolyLine,
+            5 => ShapeType::Polygon,
+            8 => ShapeType::MultiPoint,
+            11 => ShapeType::PointZ,
+            13 => ShapeType::PolyLineZ,
+            15 => ShapeType::PolygonZ,
+            18 => ShapeType::MultiPointZ,
+            21 => ShapeType::PointM,
+            23 => ShapeType::PolyLineM,
+            25 => ShapeType::PolygonM,
+            28 => ShapeType::MultiPointM,
+            31 => ShapeType::MultiPatch,
+            _ => ShapeType::Null,
+        }
+    }
+}
+impl ShapeType {
+    /// True if the shape stores Z values
+    pub fn has_z(&self) -> bool {
+        matches!(
+            self,
+            ShapeType::PointZ | ShapeType::PolyLineZ | ShapeType::PolygonZ | ShapeType::MultiPointZ
+        )
+    }
+
+    /// True if the shape stores M values (optional for Z shapes)
+    pub fn has_m(&self) -> bool {
+        self.has_z()
+            || matches!(
+                self,
+                ShapeType::PointM
+                    | ShapeType::PolyLineM
+                    | ShapeType::PolygonM
+                    | ShapeType::MultiPointM
+            )
+    }
+}
+
+/// A Shapefile Header describing the internal data
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct SHPHeader {
+    /// The length of the file in bytes
+    pub length: u32,
+    /// The version of the file (1000)
+    pub version: i32,
+    /// The shape type of all the records
+    pub shape_type: ShapeType,
+    /// The bounding box of all the shapes (x, y and z ranges)
+    pub bbox: BBox3D,
+}
+
+/// # The Shapefile Reader
+///
+/// ## Description
+/// Reads the geometry of a shapefile (`.shp`), optionally pairing it with its index (`.shx`),
+/// attributes (`.dbf`) and projection (`.prj`). Implements the [`FeatureIterator`].
+///
+/// It's easier to use [`crate::readers::shapefile_from_zip`] or
+/// [`crate::readers::shapefile_from_path`] which find and load the paired files.
+///
+/// Polygon rings are grouped by their orientation: clockwise rings are outer rings and
+/// counter-clockwise rings are holes of the outer ring that contains them. Outer rings are
+/// rewound to be counter-clockwise and holes clockwise. M values are stored in each point's
+/// M-value as `{ "m": value }`.
+///
+/// NOTE: Coordinates are not reprojected. The `.prj` contents are exposed as [`ShapeFileReader::prj`]
+/// and parsed by [`ShapeFileReader::crs`].
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, DataBaseFile, FeatureIterator, ShapeFileReader};
+/// use std::path::PathBuf;
+///
+/// let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+/// path.push("tests/readers/shapefile/fixtures/utf");
+/// let shp = std::fs::read(path.with_extension("shp")).unwrap();
+/// let dbf = std::fs::read(path.with_extension("dbf")).unwrap();
+///
+/// let dbf = DataBaseFile::new(BufferReader::from(&dbf[..]), None);
+/// let mut reader = ShapeFileReader::new(BufferReader::from(&shp[..]), None, Some(dbf), None)
+///     .unwrap();
+/// while let Some(feature) = reader.next_feature() {
+///     println!("{:?}", feature.properties);
+/// }
+/// ```
+///
+/// ## Links
+/// - <https://www.esri.com/content/dam/esrisites/sitecore-archive/Files/Pdfs/library/whitepapers/pdfs/shapefile.pdf>
+/// - <https://en.wikipedia.org/wiki/Shapefile>
+#[derive(Debug)]
+pub struct ShapeFileReader<T: Reader> {
+    reader: T,
+    header: SHPHeader,
+    /// (byte offset, record index) of every non-empty record
+    rows: Vec<(usize, usize)>,
+    cursor: usize,
+    /// The attributes paired with the shapes
+    pub dbf: Option<DataBaseFile<T>>,
+    /// The well-known text of the projection if it was provided
+    pub prj: Option<String>,
+}
+impl<T: Reader> ShapeFileReader<T> {
+    /// Create a new shapefile reader. If the `.shx` index is provided, it's used to locate the
+    /// records, otherwise the records are found by walking the `.shp` file.
+    pub fn new(
+        mut reader: T,
+        shx: Option<T>,
+        dbf: Option<DataBaseFile<T>>,
+        prj: Option<String>,
+    ) -> Result<Self, ShapeFileError> {
+        if reader.len() < 100 || reader.int32_be(Some(0)) != SHP_FILE_CODE {
+            return Err(ShapeFileError::InvalidHeader);
+        }
+        let header = SHPHeader {
+            length: (reader.int32_be(Some(24)) as u32) << 1,
+            version: reader.int32_le(Some(28)),
+            shape_type: reader.int32_le(Some(32)).into(),
+            bbox: BBox3D::new(
+                reader.f64_le(Some(36)),
+                reader.f64_le(Some(44)),
+                reader.f64_le(Some(52)),
+                reader.f64_le(Some(60)),
+                reader.f64_le(Some(68)),
+                reader.f64_le(Some(76)),
+            ),
+        };
+        let offsets = match shx {
+            Some(shx) => read_index(shx)?,
+            None => walk_records(&mut reader),
+        };
+        let mut rows = Vec::with_capacity(offsets.len());
+        for (index, offset) in offsets.into_iter().enumerate() {
+            if offset + 12 <= reader.len() && reader.int32_le(Some(offset + 8)) != 0 {
+                rows.push((offset, index));
+            }
+        }
+
+        Ok(Self { reader, header, rows, cursor: 0, dbf, prj })
+    }
+
+    /// Get the header
+    pub fn get_header(&self) -> SHPHeader {
+        self.header
+    }
+
+    /// Parse the projection of the `.prj` if it was provided and is valid WKT
+    pub fn crs(&self) -> Option<WKTCRS> {
+        parse_wkt_crs(self.prj.as_ref()?).ok()
+    }
+
+    /// The number of non-empty shapes
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns true if there are no shapes
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    /// Read the feature of the `index`th non-empty shape
+    pub fn get_feature(&mut self, index: usize) -> Option<VectorFeature> {
+        let (offset, record_index) = *self.rows.get(index)?;
+        let reader = &mut self.reader;
+        let id = reader.int32_be(Some(offset)) as u64;
+        let length = (reader.int32_be(Some(offset + 4)) as usize) << 1;
+        if length < 4 || offset + 8 + length > reader.len() {
+            return None;
+        }
+        let shape_type: ShapeType = reader.int32_le(Some(offset + 8)).into();
+        let mut shape = Shape { reader, offset: offset + 12, end: offset + 8 + length, shape_type };
+        let geometry = shape.parse()?;
+        let properties =
+            self.dbf.as_mut().and_then(|dbf| dbf.get_properties(record_index)).unwrap_or_default();
+
+        Some(VectorFeature::new_wm(Some(id), properties, geometry, None))
+    }
+}
+impl<T: Reader> Iterator for ShapeFileReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.cursor < self.rows.len() {
+            let feature = self.get_feature(self.cursor);
+            self.cursor += 1;
+            if feature.is_some() {
+                return feature;
+            }
+        }
+        None
+    }
+}
+impl<T: Reader> FeatureIterator for ShapeFileReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Read the record offsets from a `.shx` index
+fn read_index<T: Reader>(mut shx: T) -> Result<Vec<usize>, ShapeFileError> {
+    if shx.len() < 100 || shx.int32_be(Some(0)) != SHP_FILE_CODE {
+        return Err(ShapeFileError::InvalidHeader);
+    }
+    let count = (shx.len() - 100) / 8;
+    Ok((0..count).map(|i| (shx.int32_be(Some(100 + i * 8)) as usize) << 1).collect())
+}
+
+/// Find the record offsets by walking the `.shp` records
+fn walk_records<T: Reader>(reader: &mut T) -> Vec<usize> {
+    let mut offsets = vec![];
+    let mut offset = 100;
+    while offset + 12 <= reader.len() {
+        let length = (reader.int32_be(Some(offset + 4)) as usize) << 1;
+        if length == 0 {
+            break;
+        }
+        offsets.push(offset);
+        offset += 8 + length;
+    }
+    offsets
+}
+
+/// A single shape record being parsed
+struct Shape<'a, T: Reader> {
+    reader: &'a mut T,
+    /// start of the shape's content after the shape type
+    offset: usize,
+    /// end of the record
+    end: usize,
+    shape_type: ShapeType,
+}
+impl<T: Reader> Shape<'_, T> {
+    fn parse(&mut self) -> Option<VectorGeometry> {
+        match self.shape_type {
+            ShapeType::Point | ShapeType::PointZ | ShapeType::PointM => self.parse_point(),
+            ShapeType::MultiPoint | ShapeType::MultiPointZ | ShapeType::MultiPointM => {
+                self.parse_multi_point()
+            }
+            ShapeType::PolyLine | ShapeType::PolyLineZ | ShapeType::PolyLineM => {
+                self.parse_poly(false)
+            }
+            ShapeType::Polygon | ShapeType::PolygonZ | ShapeType::PolygonM => self.parse_poly(true),
+            ShapeType::Null | ShapeType::MultiPatch => None,
+        }
+    }
+
+    /// Read a f64 if it's within the record
+    fn f64(&mut self, offset: usize) -> Option<f64> {
+        if offset + 8 <= self.end {
+            Some(self.reader.f64_le(Some(offset)))
+        } else {
+            None
+        }
+    }
+
+    fn m_value(&mut self, offset: usize) -> Option<MValue> {
+        let m = self.f64(offset)?;
+        if m < NO_DATA_M {
+            return None;
+        }
+        Some(MValue::from([("m".into(), ValueType::Primitive(PrimitiveValue::F64(m)))]))
+    }
+
+    fn parse_point(&mut self) -> Option<VectorGeometry> {
+        let is_3d = self.shape_type.has_z();
+        let x = self.f64(self.offset)?;
+        let y = self.f64(self.offset + 8)?;
+        let (z, m) = match self.shape_type {
+            ShapeType::PointZ => (self.f64(self.offset + 16), self.m_value(self.offset + 24)),
+            ShapeType::PointM => (None, self.m_value(self.offset + 16)),
+            _ => (None, None),
+        };
+        Some(VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            is_3d,
+            coordinates: VectorPoint::new(x, y, z, m),
+            ..Default::default()
+        }))
+    }
+
+    /// Read `count` points starting at `offset` with their z and m values (if present)
+    fn read_points(&mut self, offset: usize, count: usize) -> (Vec<VectorPoint>, BBox3D) {
+        let is_3d = self.shape_type.has_z();
+        let mut bbox = BBox3D::new(
+            self.f64(self.offset).unwrap_or_default(),
+            self.f64(self.offset + 8).unwrap_or_default(),
+            self.f64(self.offset + 16).unwrap_or_default(),
+            self.f64(self.offset + 24).unwrap_or_default(),
+            0.,
+            0.,
+        );
+        // z range + z values then m range + m values follow the points
+        let mut extra = offset + 16 * count;
+        let z_offset = if is_3d {
+            bbox.near = self.f64(extra).unwrap_or_default();
+            bbox.far = self.f64(extra + 8).unwrap_or_default();
+            extra += 16 + 8 * count;
+            Some(extra - 8 * count)
+        } else {
+            None
+        };
+        let m_offset = if self.shape_type.has_m() { Some(extra + 16) } else { None };
+
+        let mut points = Vec::with_capacity(count);
+        for i in 0..count {
+            let x = self.f64(offset + i * 16).unwrap_or_default();
+            let y = self.f64(offset + i * 16 + 8).unwrap_or_default();
+            let z = z_offset.and_then(|z| self.f64(z + i * 8));
+            let m = m_offset.and_then(|m| self.m_value(m + i * 8));
+            points.push(VectorPoint::new(x, y, z, m));
+        }
+        (points, bbox)
+    }
+
+    fn parse_multi_point(&mut self) -> Option<VectorGeometry> {
+        let is_3d = self.shape_type.has_z();
+        let count = self.reader.int32_le(Some(self.offset + 32)).max(0) as usize;
+        if count == 0 || self.offset + 36 + count * 16 > self.end {
+            return None;
+        }
+        let (mut points, bbox) = self.read_points(self.offset + 36, count);
+        if points.len() == 1 {
+            Some(VectorGeometry::Point(VectorPointGeometry {
+                _type: VectorGeometryType::Point,
+                is_3d,
+                coordinates: points.remove(0),
+                ..Default::default()
+            }))
+        } else {
+            Some(VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+                _type: VectorGeometryType::MultiPoint,
+                is_3d,
+                coordinates: points,
+                bbox: Some(bbox),
+                ..Default::default()
+            }))
+        }
+    }
+
+    fn parse_poly(&mut self, is_polygon: bool) -> Option<VectorGeometry> {
+        let is_3d = self.shape_type.has_z();
+        let num_parts = self.reader.int32_le(Some(self.offset + 32)).max(0) as usize;
+        let num_points = self.reader.int32_le(Some(self.offset + 36)).max(0) as usize;
+        let points_offset = self.offset + 40 + 4 * num_parts;
+        if num_parts == 0 || num_points == 0 || points_offset + num_points * 16 > self.end {
+            return None;
+        }
+        let mut parts: Vec<usize> = (0..num_parts)
+            .map(|i| self.reader.int32_le(Some(self.offset + 40 + i * 4)).max(0) as usize)
+            .collect();
+        parts.push(num_points);
+        let (points, bbox) = self.read_points(points_offset, num_points);
+        let mut lines: Vec<VectorLineString> = parts
+            .windows(2)
+            .filter(|w| w[0] < w[1] && w[1] <= num_points)
+            .map(|w| points[w[0]..w[1]].to_vec())
+            .collect();
+
+        if is_polygon {
+            let mut polygons = group_rings(lines);
+            if polygons.is_empty() {
+                return None;
+            }
+            if polygons.len() == 1 {
+                Some(VectorGeometry::Polygon(VectorPolygonGeometry {
+                    _type: VectorGeometryType::Polygon,
+                    is_3d,
+                    coordinates: polygons.remove(0),
+                    bbox: Some(bbox),
+                    ..Default::default()
+                }))
+            } else {
+                Some(VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+                    _type: VectorGeometryType::MultiPolygon,
+                    is_3d,
+                    coordinates: polygons,
+                    bbox: Some(bbox),
+                    ..Default::default()
+                }))
+            }
+        } else if lines.len() == 1 {
+            Some(VectorGeometry::LineString(VectorLineStringGeometry {
+                _type: VectorGeometryType::LineString,
+                is_3d,
+                coordinates: lines.remove(0),
+                bbox: Some(bbox),
+                ..Default::default()
+            }))
+        } else if lines.is_empty() {
+            None
+        } else {
+            Some(VectorGeometry::MultiLineString(VectorMultiLineStringGeometry {
+                _type: VectorGeometryType::MultiLineString,
+                is_3d,
+                coordinates: lines,
+                bbox: Some(bbox),
+                ..Default::default()
+            }))
+        }
+    }
+}
+
+/// Group the rings into polygons. Clockwise rings are outer rings and counter-clockwise rings
+/// are holes that belong to the smallest outer ring containing them, so holes of an island inside
+/// a lake go to the island. Holes without a containing outer ring are treated as outer rings. The
+/// result is rewound so outer rings are counter-clockwise.
+fn group_rings(rings: Vec<VectorLineString>) -> Vec<VectorPolygon> {
+    let mut polygons: Vec<VectorPolygon> = vec![];
+    let mut holes: Vec<VectorLineString> = vec![];
+    for ring in rings {
+        if ring.len() < 3 {
+            continue;
+        }
+        if signed_area(&ring) <= 0. {
+            polygons.push(vec![ring]);
+        } else {
+            holes.push(ring);
+        }
+    }
+    let areas: Vec<f64> = polygons.iter().map(|polygon| signed_area(&polygon[0]).abs()).collect();
+    for hole in holes {
+        let outer = areas
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| hole.iter().any(|p| point_in_ring(p, &polygons[*i][0])))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(i, _)| i);
+        match outer {
+            Some(i) => polygons[i].push(hole),
+            None => polygons.push(vec![hole]),
+        }
+    }
+    for polygon in polygons.iter_mut() {
+        for (i, ring) in polygon.iter_mut().enumerate() {
+            rewind(ring, i == 0);
+        }
+    }
+    polygons
+}
+
+/// Shoelace signed area. Positive for counter-clockwise rings (y up)
+fn signed_area(ring: &VectorLineString) -> f64 {
+    let mut area = 0.;
+    let mut j = ring.len() - 1;
+    for i in 0..ring.len() {
+        area += ring[j].x * ring[i].y - ring[i].x * ring[j].y;
+        j = i;
+    }
+    area / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ring(points: &[(f64, f64)]) -> VectorLineString {
+        points.iter().map(|(x, y)| VectorPoint::new(*x, *y, None, None)).collect()
+    }
+
+    #[test]
+    fn test_group_rings_smallest_outer() {
+        // a lake in a large outer ring with an island that has a pond of its own. Outer rings are
+        // clockwise and holes counter-clockwise
+        let outer = ring(&[(0., 0.), (0., 10.), (10., 10.), (10., 0.), (0., 0.)]);
+        let lake = ring(&[(1., 1.), (9., 1.), (9., 9.), (1., 9.), (1., 1.)]);
+        let island = ring(&[(3., 3.), (3., 7.), (7., 7.), (7., 3.), (3., 3.)]);
+        let pond = ring(&[(4., 4.), (6., 4.), (6., 6.), (4., 6.), (4., 4.)]);
+        let polygons = group_rings(vec![outer, lake, island, pond]);
+        assert_eq!(polygons.len(), 2);
+        assert_eq!(polygons[0].len(), 2);
+        assert_eq!(polygons[0][1][0], VectorPoint::new(1., 1., None, None));
+        assert_eq!(polygons[1].len(), 2);
+        assert_eq!(polygons[1][0][0], VectorPoint::new(3., 3., None, None));
+        assert_eq!(polygons[1][1][0], VectorPoint::new(4., 4., None, None));
+    }
+}
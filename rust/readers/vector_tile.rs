@@ -0,0 +1,1116 @@
+use crate::data_structures::{
+    decode_offset, unweave_point, unweave_point_3d, HasLayer, OColumnCacheReader, OFeatureType,
+    OShape, VectorTileGeomType, COMMAND_CLOSE_PATH, COMMAND_LINE_TO, COMMAND_MOVE_TO,
+    DEFAULT_EXTENT, OFLAG_BBOX, OFLAG_ID, OFLAG_INDICES, OFLAG_M_VALUES, OFLAG_OFFSETS,
+    OFLAG_SINGLE, OFLAG_TESSELLATION, OVT_EXTENTS,
+};
+use crate::geometry::{
+    CellId, ConvertVectorFeatureS2, ConvertVectorFeatureWM, PrimitiveValue, Projection, Properties,
+    ValueType, VectorFeature, VectorGeometry, VectorGeometryType, VectorLineStringGeometry,
+    VectorMultiLineStringGeometry, VectorMultiPointGeometry, VectorMultiPolygonGeometry,
+    VectorPoint, VectorPointGeometry, VectorPolygon, VectorPolygonGeometry,
+};
+use crate::readers::FeatureIterator;
+
+use pbf::{zagzig, ProtoRead, Protobuf};
+
+use alloc::{string::String, vec, vec::Vec};
+
+/// The coordinate system decoded features are returned in
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum TileCoordinates {
+    /// Integer coordinates local to the tile, ranging from 0 to the layer's extent
+    #[default]
+    Local,
+    /// 0->1 coordinates relative to the whole projection. For S2 tiles these are the S-T
+    /// coordinates of the tile's face.
+    Unit,
+    /// Longitude-latitude coordinates
+    LonLat,
+}
+
+/// Vector Tile Reader Options
+#[derive(Debug, Default, Clone, Copy)]
+pub struct VectorTileReaderOptions {
+    /// The tile's cell. WM tiles use face 0, e.g. `CellId::from_face_ij(0, x, y, Some(zoom))`
+    /// [Default = `CellId::from_face(0)`]
+    pub id: Option<CellId>,
+    /// The tile's projection [Default = WG]
+    pub projection: Option<Projection>,
+    /// The coordinate system to decode the features into [Default = Local]
+    pub coordinates: Option<TileCoordinates>,
+}
+
+/// Metadata attached to every decoded feature
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct VectorTileMetadata {
+    /// The name of the layer the feature was stored in
+    pub layer: String,
+    /// The extent of the layer the feature was stored in
+    pub extent: u32,
+}
+impl HasLayer for VectorTileMetadata {
+    fn get_layer(&self) -> Option<String> {
+        Some(self.layer.clone())
+    }
+}
+
+/// # Vector Tile Reader
+///
+/// ## Description
+/// Decodes [Mapbox Vector Tile](https://github.com/mapbox/vector-tile-spec/tree/master/2.1)
+/// and [Open Vector Tile](https://github.com/Open-S2/open-vector-tile) protobufs into
+/// [`VectorFeature`]s. A tile may hold both kinds of layers.
+///
+/// Mapbox polygon rings are grouped into polygons by winding order: clockwise rings (in tile
+/// coordinates) start a new polygon and counter-clockwise rings are its holes. Rings are closed.
+///
+/// Open Vector Tile layers keep their 3D coordinates, M-values, line offsets, polygon indices and
+/// bounding boxes. Z values are scaled by the extent like x and y. Features whose geometry or
+/// properties point outside the tile's column cache are skipped.
+///
+/// Implements the [`FeatureIterator`], yielding every feature of every Mapbox layer and then
+/// every feature of every Open Vector Tile layer, in the order they were stored. The feature's metadata tracks its layer so that features can be added back into a
+/// [`crate::data_structures::TileStore`] or [`crate::data_structures::Tile`].
+///
+/// ## Usage
+/// ```rust
+/// use gistools::data_structures::Tile;
+/// use gistools::geometry::{
+///     CellId, Properties, VectorFeature, VectorGeometry, VectorGeometryType, VectorPoint,
+///     VectorPointGeometry,
+/// };
+/// use gistools::readers::{TileCoordinates, VectorTileReader, VectorTileReaderOptions};
+///
+/// let mut tile: Tile<()> = Tile::new(CellId::from_face(0));
+/// let geometry = VectorGeometry::Point(VectorPointGeometry {
+///     _type: VectorGeometryType::Point,
+///     coordinates: VectorPoint::new(0.5, 0.5, None, None),
+///     ..Default::default()
+/// });
+/// tile.add_feature(VectorFeature::new_wm(Some(1), Properties::new(), geometry, None), None);
+/// let data = tile.encode_mvt(4_096);
+///
+/// let options = VectorTileReaderOptions {
+///     coordinates: Some(TileCoordinates::LonLat),
+///     ..Default::default()
+/// };
+/// let reader = VectorTileReader::new(data, Some(options));
+/// let features: Vec<_> = reader.collect();
+/// assert_eq!(features.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct VectorTileReader {
+    /// The tile's Mapbox layers in the order they were stored
+    pub layers: Vec<VectorTileLayer>,
+    /// The tile's Open Vector Tile layers in the order they were stored
+    pub open_layers: Vec<OpenVectorTileLayer>,
+    id: CellId,
+    projection: Projection,
+    coordinates: TileCoordinates,
+    layer_cursor: usize,
+    feature_cursor: usize,
+}
+impl VectorTileReader {
+    /// Parse a vector tile
+    pub fn new(data: Vec<u8>, options: Option<VectorTileReaderOptions>) -> Self {
+        let options = options.unwrap_or_default();
+        let mut pbf = Protobuf::from(data);
+        let mut reader = VectorTileReader {
+            id: options.id.unwrap_or(CellId::from_face(0)),
+            projection: options.projection.unwrap_or(Projection::WG),
+            coordinates: options.coordinates.unwrap_or_default(),
+            layers: vec![],
+            open_layers: vec![],
+            layer_cursor: 0,
+            feature_cursor: 0,
+        };
+        let mut tile = RawTile::default();
+        pbf.read_fields(&mut tile, None);
+        reader.layers = tile.layers;
+        // open layers index into the column cache, which is stored after them
+        reader.open_layers = tile
+            .open_layers
+            .into_iter()
+            .map(|data| OpenVectorTileLayer::new(data, &tile.cache))
+            .collect();
+        reader
+    }
+
+    /// Get a Mapbox layer by name
+    pub fn layer(&self, name: &str) -> Option<&VectorTileLayer> {
+        self.layers.iter().find(|l| l.name == name)
+    }
+
+    /// Get an Open Vector Tile layer by name
+    pub fn open_layer(&self, name: &str) -> Option<&OpenVectorTileLayer> {
+        self.open_layers.iter().find(|l| l.name == name)
+    }
+
+    /// Decode the feature at `index` of the layer at `layer_index`. Mapbox layers come first,
+    /// so Open Vector Tile layers start at `layers.len()`.
+    pub fn feature(
+        &self,
+        layer_index: usize,
+        index: usize,
+    ) -> Option<VectorFeature<VectorTileMetadata>> {
+        let (id, properties, geometry, name, extent) = match self.layers.get(layer_index) {
+            Some(layer) => {
+                let feature = layer.features.get(index)?;
+                let properties = layer.properties(&feature.tags);
+                (feature.id, properties, feature.geometry()?, &layer.name, layer.extent)
+            }
+            None => {
+                let layer = self.open_layers.get(layer_index - self.layers.len())?;
+                let feature = layer.features.get(index)?;
+                let geometry = feature.geometry.clone();
+                (feature.id, feature.properties.clone(), geometry, &layer.name, layer.extent)
+            }
+        };
+        let metadata = VectorTileMetadata { layer: name.clone(), extent };
+        let mut vector_feature = match self.projection {
+            Projection::WG => VectorFeature::new_wm(id, properties, geometry, None),
+            Projection::S2 => {
+                VectorFeature::new_s2(id, self.id.face().into(), properties, geometry, None)
+            }
+        };
+        vector_feature.metadata = Some(metadata);
+        if self.coordinates == TileCoordinates::Local {
+            return Some(vector_feature);
+        }
+
+        // move the local coordinates into the 0->1 coordinate system of the projection
+        let zoom = self.id.level();
+        let (_face, ti, tj) = self.id.to_zoom_ij(Some(zoom));
+        let scale = (1_u64 << zoom) as f64;
+        let extent = extent as f64;
+        for_each_point(&mut vector_feature.geometry, &mut |p| {
+            p.x = (ti as f64 + p.x / extent) / scale;
+            p.y = (tj as f64 + p.y / extent) / scale;
+            p.z = p.z.map(|z| z / extent);
+        });
+        if self.coordinates == TileCoordinates::LonLat {
+            match self.projection {
+                Projection::WG => vector_feature.to_ll(),
+                Projection::S2 => vector_feature = vector_feature.to_wm(),
+            }
+        }
+
+        Some(vector_feature)
+    }
+}
+impl Iterator for VectorTileReader {
+    type Item = VectorFeature<VectorTileMetadata>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.layer_cursor < self.layers.len() + self.open_layers.len() {
+            let len = match self.layers.get(self.layer_cursor) {
+                Some(layer) => layer.len(),
+                None => self.open_layers[self.layer_cursor - self.layers.len()].len(),
+            };
+            if self.feature_cursor >= len {
+                self.layer_cursor += 1;
+                self.feature_cursor = 0;
+                continue;
+            }
+            let feature = self.feature(self.layer_cursor, self.feature_cursor);
+            self.feature_cursor += 1;
+            if feature.is_some() {
+                return feature;
+            }
+        }
+        None
+    }
+}
+impl FeatureIterator<VectorTileMetadata> for VectorTileReader {
+    fn next_feature(&mut self) -> Option<VectorFeature<VectorTileMetadata>> {
+        self.next()
+    }
+}
+
+/// The layers of a tile before the Open Vector Tile layers are decoded
+#[derive(Debug, Default)]
+struct RawTile {
+    layers: Vec<VectorTileLayer>,
+    open_layers: Vec<Vec<u8>>,
+    cache: OColumnCacheReader,
+}
+impl ProtoRead for RawTile {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            3 => {
+                let mut layer = VectorTileLayer::default();
+                pb.read_message(&mut layer);
+                self.layers.push(layer);
+            }
+            4 => self.open_layers.push(pb.read_bytes()),
+            5 => pb.read_message(&mut self.cache),
+            _ => {}
+        }
+    }
+}
+
+/// A vector tile layer
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorTileLayer {
+    /// The layer's version, 1 or 2
+    pub version: u32,
+    /// The layer's name
+    pub name: String,
+    /// The layer's extent
+    pub extent: u32,
+    /// The property keys shared by the features
+    pub keys: Vec<String>,
+    /// The property values shared by the features
+    pub values: Vec<PrimitiveValue>,
+    /// The raw features
+    pub features: Vec<VectorTileFeature>,
+}
+impl Default for VectorTileLayer {
+    fn default() -> Self {
+        Self {
+            version: 1,
+            name: String::new(),
+            extent: DEFAULT_EXTENT,
+            keys: vec![],
+            values: vec![],
+            features: vec![],
+        }
+    }
+}
+impl VectorTileLayer {
+    /// Get the number of features in the layer
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Returns true if the layer has no features
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+
+    /// Build properties from key/value index pairs. Invalid indexes are skipped.
+    fn properties(&self, tags: &[u32]) -> Properties {
+        let mut properties = Properties::new();
+        for [key, value] in tags.as_chunks::<2>().0 {
+            let (Some(key), Some(value)) =
+                (self.keys.get(*key as usize), self.values.get(*value as usize))
+            else {
+                continue;
+            };
+            properties.insert(key.clone(), ValueType::Primitive(value.clone()));
+        }
+        properties
+    }
+}
+impl ProtoRead for VectorTileLayer {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            15 => self.version = pb.read_varint(),
+            1 => self.name = pb.read_string(),
+            2 => {
+                let mut feature = VectorTileFeature::default();
+                pb.read_message(&mut feature);
+                self.features.push(feature);
+            }
+            3 => self.keys.push(pb.read_string()),
+            4 => {
+                let mut value = VectorTileValue::default();
+                pb.read_message(&mut value);
+                self.values.push(value.0);
+            }
+            5 => self.extent = pb.read_varint(),
+            _ => {}
+        }
+    }
+}
+
+/// A vector tile value message
+#[derive(Debug)]
+struct VectorTileValue(PrimitiveValue);
+impl Default for VectorTileValue {
+    fn default() -> Self {
+        Self(PrimitiveValue::Null)
+    }
+}
+impl ProtoRead for VectorTileValue {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        self.0 = match tag {
+            1 => PrimitiveValue::String(pb.read_string()),
+            2 => PrimitiveValue::F32(pb.read_fixed()),
+            3 => PrimitiveValue::F64(pb.read_fixed()),
+            4 => PrimitiveValue::I64(pb.read_varint()),
+            5 => PrimitiveValue::U64(pb.read_varint()),
+            6 => PrimitiveValue::I64(pb.read_s_varint()),
+            7 => PrimitiveValue::Bool(pb.read_varint()),
+            _ => return,
+        };
+    }
+}
+
+/// A raw vector tile feature
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct VectorTileFeature {
+    /// The feature's id
+    pub id: Option<u64>,
+    /// key/value index pairs into the layer's keys and values
+    pub tags: Vec<u32>,
+    /// The geometry type
+    pub _type: VectorTileGeomType,
+    /// The zigzag delta encoded geometry commands
+    pub geometry: Vec<u32>,
+}
+impl VectorTileFeature {
+    /// Decode the feature's geometry in tile local coordinates. Returns `None` if the geometry
+    /// is empty or its type is unknown.
+    fn geometry(&self) -> Option<VectorGeometry> {
+        let paths = decode_paths(&self.geometry);
+        if paths.is_empty() {
+            return None;
+        }
+        match self._type {
+            VectorTileGeomType::Points => {
+                let mut points: Vec<VectorPoint> = paths.into_iter().flatten().collect();
+                if points.len() == 1 {
+                    Some(VectorGeometry::Point(VectorPointGeometry {
+                        _type: VectorGeometryType::Point,
+                        coordinates: points.remove(0),
+                        ..Default::default()
+                    }))
+                } else {
+                    Some(VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+                        _type: VectorGeometryType::MultiPoint,
+                        coordinates: points,
+                        ..Default::default()
+                    }))
+                }
+            }
+            VectorTileGeomType::Lines => {
+                let mut lines = paths;
+                if lines.len() == 1 {
+                    Some(VectorGeometry::LineString(VectorLineStringGeometry {
+                        _type: VectorGeometryType::LineString,
+                        coordinates: lines.remove(0),
+                        ..Default::default()
+                    }))
+                } else {
+                    Some(VectorGeometry::MultiLineString(VectorMultiLineStringGeometry {
+                        _type: VectorGeometryType::MultiLineString,
+                        coordinates: lines,
+                        ..Default::default()
+                    }))
+                }
+            }
+            VectorTileGeomType::Polygons => {
+                let mut polygons: Vec<VectorPolygon> = vec![];
+                for ring in paths {
+                    let area = ring_area(&ring);
+                    match polygons.last_mut() {
+                        Some(polygon) if area <= 0. => polygon.push(ring),
+                        _ => polygons.push(vec![ring]),
+                    }
+                }
+                if polygons.len() == 1 {
+                    Some(VectorGeometry::Polygon(VectorPolygonGeometry {
+                        _type: VectorGeometryType::Polygon,
+                        coordinates: polygons.remove(0),
+                        ..Default::default()
+                    }))
+                } else {
+                    Some(VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+                        _type: VectorGeometryType::MultiPolygon,
+                        coordinates: polygons,
+                        ..Default::default()
+                    }))
+                }
+            }
+            _ => None,
+        }
+    }
+}
+impl ProtoRead for VectorTileFeature {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.id = Some(pb.read_varint()),
+            2 => self.tags = pb.read_packed(),
+            3 => self._type = pb.read_varint::<u8>().into(),
+            4 => self.geometry = pb.read_packed(),
+            _ => {}
+        }
+    }
+}
+
+/// An Open Vector Tile layer
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenVectorTileLayer {
+    /// The layer's version
+    pub version: u32,
+    /// The layer's name
+    pub name: String,
+    /// The layer's extent
+    pub extent: u32,
+    /// The decoded features in tile local coordinates
+    pub features: Vec<OpenVectorTileFeature>,
+}
+impl OpenVectorTileLayer {
+    /// Decode a layer against the tile's column cache
+    fn new(data: Vec<u8>, cache: &OColumnCacheReader) -> Self {
+        let mut raw = RawOpenLayer::default();
+        Protobuf::from(data).read_fields(&mut raw, None);
+        let shape = raw.shape.and_then(|i| cache.get_shape(i)).unwrap_or_default();
+        let m_shape = raw.m_shape.and_then(|i| cache.get_shape(i)).unwrap_or_default();
+        let features = raw
+            .features
+            .iter()
+            .filter_map(|data| OpenVectorTileFeature::new(data, cache, &shape, &m_shape))
+            .collect();
+        Self {
+            version: raw.version,
+            name: cache.get_string(raw.name).cloned().unwrap_or_default(),
+            // unknown extents fall back to the smallest one
+            extent: *OVT_EXTENTS.get(raw.extent).unwrap_or(&OVT_EXTENTS[0]),
+            features,
+        }
+    }
+
+    /// Get the number of features in the layer
+    pub fn len(&self) -> usize {
+        self.features.len()
+    }
+
+    /// Returns true if the layer has no features
+    pub fn is_empty(&self) -> bool {
+        self.features.is_empty()
+    }
+}
+
+/// An Open Vector Tile layer whose features haven't been decoded
+#[derive(Debug, Default)]
+struct RawOpenLayer {
+    version: u32,
+    name: usize,
+    extent: usize,
+    features: Vec<Vec<u8>>,
+    shape: Option<usize>,
+    m_shape: Option<usize>,
+}
+impl ProtoRead for RawOpenLayer {
+    fn read(&mut self, tag: u64, pb: &mut Protobuf) {
+        match tag {
+            1 => self.version = pb.read_varint(),
+            2 => self.name = pb.read_varint(),
+            3 => self.extent = pb.read_varint(),
+            4 => self.features.push(pb.read_bytes()),
+            5 => self.shape = Some(pb.read_varint()),
+            6 => self.m_shape = Some(pb.read_varint()),
+            _ => {}
+        }
+    }
+}
+
+/// A decoded Open Vector Tile feature
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenVectorTileFeature {
+    /// The feature's id
+    pub id: Option<u64>,
+    /// The feature's properties
+    pub properties: Properties,
+    /// The feature's geometry in tile local coordinates
+    pub geometry: VectorGeometry,
+}
+impl OpenVectorTileFeature {
+    /// Decode a feature. Returns `None` if the feature is truncated or points outside the cache
+    fn new(
+        data: &[u8],
+        cache: &OColumnCacheReader,
+        shape: &OShape,
+        m_shape: &OShape,
+    ) -> Option<Self> {
+        let mut varints = Varints { data, pos: 0 };
+        let _type = OFeatureType::from_u64(varints.next()?)?;
+        let flags = varints.next()? as u8;
+        let id = if flags & OFLAG_ID != 0 { Some(varints.next()?) } else { None };
+        let properties = cache.get_value(varints.next()? as usize, shape)?;
+        let geometry = varints.next()?;
+        let is_polygon = matches!(_type, OFeatureType::Polygons | OFeatureType::Polygons3D);
+        let indices = match is_polygon && flags & OFLAG_INDICES != 0 {
+            true => Some(cache.get_indices(varints.next()? as usize)?),
+            false => None,
+        };
+        // tessellation can't be stored on the geometry, so it's skipped
+        if is_polygon && flags & OFLAG_TESSELLATION != 0 {
+            varints.next()?;
+        }
+        let bbox = match flags & OFLAG_BBOX != 0 {
+            true => Some(cache.get_bbox(varints.next()? as usize)?),
+            false => None,
+        };
+
+        let decoder = GeometryDecoder {
+            cache,
+            m_shape,
+            is_3d: _type.is_3d(),
+            has_offsets: flags & OFLAG_OFFSETS != 0,
+            has_m_values: flags & OFLAG_M_VALUES != 0,
+        };
+        let single = flags & OFLAG_SINGLE != 0;
+        let is_3d = _type.is_3d();
+        let geometry = match _type {
+            OFeatureType::Points | OFeatureType::Points3D => {
+                let mut points = if single {
+                    let (x, y, z) = match is_3d {
+                        true => unweave_point_3d(geometry),
+                        false => with_z(unweave_point(geometry as u32)),
+                    };
+                    vec![decoder.point((x, y, z), None)]
+                } else {
+                    let indices = cache.get_indices(geometry as usize)?;
+                    decoder.line(&indices, &mut 0)?.1
+                };
+                if points.len() == 1 {
+                    VectorGeometry::Point(VectorPointGeometry {
+                        _type: VectorGeometryType::Point,
+                        is_3d,
+                        coordinates: points.remove(0),
+                        bbox,
+                        ..Default::default()
+                    })
+                } else {
+                    VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+                        _type: VectorGeometryType::MultiPoint,
+                        is_3d,
+                        coordinates: points,
+                        bbox,
+                        ..Default::default()
+                    })
+                }
+            }
+            OFeatureType::Lines | OFeatureType::Lines3D => {
+                let indices = cache.get_indices(geometry as usize)?;
+                let mut cursor = 0;
+                let count = if single { 1 } else { next_index(&indices, &mut cursor)? };
+                let (offsets, mut lines) = decoder.lines(&indices, &mut cursor, count)?;
+                if single {
+                    VectorGeometry::LineString(VectorLineStringGeometry {
+                        _type: VectorGeometryType::LineString,
+                        is_3d,
+                        coordinates: lines.remove(0),
+                        offset: offsets.map(|o| o[0]),
+                        bbox,
+                        ..Default::default()
+                    })
+                } else {
+                    VectorGeometry::MultiLineString(VectorMultiLineStringGeometry {
+                        _type: VectorGeometryType::MultiLineString,
+                        is_3d,
+                        coordinates: lines,
+                        offset: offsets,
+                        bbox,
+                        ..Default::default()
+                    })
+                }
+            }
+            OFeatureType::Polygons | OFeatureType::Polygons3D => {
+                let indices_store = cache.get_indices(geometry as usize)?;
+                let mut cursor = 0;
+                let count = if single { 1 } else { next_index(&indices_store, &mut cursor)? };
+                let mut polygons = vec![];
+                let mut polygon_offsets = vec![];
+                for _ in 0..count {
+                    let ring_count = next_index(&indices_store, &mut cursor)?;
+                    let (offsets, rings) =
+                        decoder.lines(&indices_store, &mut cursor, ring_count)?;
+                    polygons.push(rings);
+                    polygon_offsets.push(offsets);
+                }
+                let offsets = decoder.has_offsets.then_some(polygon_offsets);
+                if single {
+                    VectorGeometry::Polygon(VectorPolygonGeometry {
+                        _type: VectorGeometryType::Polygon,
+                        is_3d,
+                        coordinates: polygons.remove(0),
+                        offset: offsets.and_then(|mut o| o.remove(0)),
+                        bbox,
+                        indices,
+                        ..Default::default()
+                    })
+                } else {
+                    VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+                        _type: VectorGeometryType::MultiPolygon,
+                        is_3d,
+                        coordinates: polygons,
+                        offset: offsets
+                            .map(|o| o.into_iter().map(Option::unwrap_or_default).collect()),
+                        bbox,
+                        indices,
+                        ..Default::default()
+                    })
+                }
+            }
+        };
+
+        Some(Self { id, properties, geometry })
+    }
+}
+
+/// Reads the raw varints of an Open Vector Tile feature
+struct Varints<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+impl Varints<'_> {
+    /// Read the next varint. Returns `None` if the data ends or the varint is too long
+    fn next(&mut self) -> Option<u64> {
+        let mut value = 0_u64;
+        for shift in (0..64).step_by(7) {
+            let byte = *self.data.get(self.pos)?;
+            self.pos += 1;
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte < 0x80 {
+                return Some(value);
+            }
+        }
+        None
+    }
+}
+
+/// Decoded lines and their offsets if the geometry stores them
+type OpenLines = (Option<Vec<f64>>, Vec<Vec<VectorPoint>>);
+
+/// Decodes the lines and M-values an Open Vector Tile geometry stores in the indices column
+struct GeometryDecoder<'a> {
+    cache: &'a OColumnCacheReader,
+    m_shape: &'a OShape,
+    is_3d: bool,
+    has_offsets: bool,
+    has_m_values: bool,
+}
+impl GeometryDecoder<'_> {
+    fn point(&self, (x, y, z): (i32, i32, i32), m: Option<Properties>) -> VectorPoint {
+        let z = if self.is_3d { Some(z as f64) } else { None };
+        VectorPoint::new(x as f64, y as f64, z, m)
+    }
+
+    /// Decode `[offset], points, [M-value per point]` starting at `cursor`
+    fn line(&self, indices: &[u32], cursor: &mut usize) -> Option<(f64, Vec<VectorPoint>)> {
+        let offset = match self.has_offsets {
+            true => decode_offset(next_index(indices, cursor)? as u32),
+            false => 0.,
+        };
+        let points = self.cache.get_points(next_index(indices, cursor)?, self.is_3d)?;
+        let mut line = Vec::with_capacity(points.len());
+        for point in points {
+            let m = match self.has_m_values {
+                true => Some(self.cache.get_value(next_index(indices, cursor)?, self.m_shape)?),
+                false => None,
+            };
+            line.push(self.point(point, m));
+        }
+        Some((offset, line))
+    }
+
+    /// Decode `count` lines, returning their offsets if the geometry stores them
+    fn lines(&self, indices: &[u32], cursor: &mut usize, count: usize) -> Option<OpenLines> {
+        // every line takes at least one index
+        if count > indices.len() {
+            return None;
+        }
+        let mut offsets = Vec::with_capacity(count);
+        let mut lines = Vec::with_capacity(count);
+        for _ in 0..count {
+            let (offset, line) = self.line(indices, cursor)?;
+            offsets.push(offset);
+            lines.push(line);
+        }
+        if lines.is_empty() {
+            return None;
+        }
+        Some((self.has_offsets.then_some(offsets), lines))
+    }
+}
+
+/// Read the next entry of an indices column
+fn next_index(indices: &[u32], cursor: &mut usize) -> Option<usize> {
+    let index = *indices.get(*cursor)?;
+    *cursor += 1;
+    Some(index as usize)
+}
+
+fn with_z((x, y): (i32, i32)) -> (i32, i32, i32) {
+    (x, y, 0)
+}
+
+/// Decode geometry commands into paths. Every MoveTo starts a new path and ClosePath closes the
+/// current path by repeating its first point.
+fn decode_paths(geometry: &[u32]) -> Vec<Vec<VectorPoint>> {
+    let mut paths: Vec<Vec<VectorPoint>> = vec![];
+    let (mut x, mut y) = (0_i64, 0_i64);
+    let mut i = 0;
+    while i < geometry.len() {
+        let cmd = geometry[i] & 0x7;
+        let count = geometry[i] >> 3;
+        i += 1;
+        match cmd {
+            COMMAND_MOVE_TO | COMMAND_LINE_TO => {
+                for _ in 0..count {
+                    if i + 2 > geometry.len() {
+                        return paths;
+                    }
+                    x += zagzig(geometry[i] as u64);
+                    y += zagzig(geometry[i + 1] as u64);
+                    i += 2;
+                    if cmd == COMMAND_MOVE_TO || paths.is_empty() {
+                        paths.push(vec![]);
+                    }
+                    paths
+                        .last_mut()
+                        .unwrap()
+                        .push(VectorPoint::new(x as f64, y as f64, None, None));
+                }
+            }
+            COMMAND_CLOSE_PATH => {
+                if let Some(path) = paths.last_mut() {
+                    if let Some(first) = path.first().cloned() {
+                        path.push(first);
+                    }
+                }
+            }
+            _ => return paths,
+        }
+    }
+    paths
+}
+
+/// Twice the signed area of a closed ring. Positive areas are clockwise in tile coordinates
+fn ring_area(ring: &[VectorPoint]) -> f64 {
+    ring.windows(2).map(|w| w[0].x * w[1].y - w[1].x * w[0].y).sum()
+}
+
+/// Mutate every point of the geometry
+fn for_each_point(geometry: &mut VectorGeometry, f: &mut impl FnMut(&mut VectorPoint)) {
+    match geometry {
+        VectorGeometry::Point(g) => f(&mut g.coordinates),
+        VectorGeometry::MultiPoint(g) | VectorGeometry::LineString(g) => {
+            g.coordinates.iter_mut().for_each(f)
+        }
+        VectorGeometry::MultiLineString(g) | VectorGeometry::Polygon(g) => {
+            g.coordinates.iter_mut().flatten().for_each(f)
+        }
+        VectorGeometry::MultiPolygon(g) => g.coordinates.iter_mut().flatten().flatten().for_each(f),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_structures::{Tile, TileStore};
+    use crate::geometry::{BBox3D, MValue};
+    use alloc::string::ToString;
+
+    fn point(x: f64, y: f64, z: Option<f64>, m: Option<MValue>) -> VectorPoint {
+        VectorPoint::new(x, y, z, m)
+    }
+
+    fn source_tile(id: CellId) -> Tile<()> {
+        let mut tile: Tile<()> = Tile::new(id);
+        let (_, ti, tj) = id.to_zoom_ij(Some(id.level()));
+        let scale = (1_u64 << id.level()) as f64;
+        // tile relative coordinates to the 0->1 coordinates of the projection
+        let p =
+            |x: f64, y: f64| point((ti as f64 + x) / scale, (tj as f64 + y) / scale, None, None);
+        let properties: Properties = [
+            ("name".to_string(), ValueType::Primitive(PrimitiveValue::String("a".into()))),
+            ("rank".to_string(), ValueType::Primitive(PrimitiveValue::I64(-4))),
+        ]
+        .into();
+        let points = VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+            _type: VectorGeometryType::MultiPoint,
+            coordinates: vec![p(0.25, 0.25), p(0.75, 0.5)],
+            ..Default::default()
+        });
+        tile.add_feature(VectorFeature::new_wm(Some(1), properties, points, None), None);
+        let multi_polygon = VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+            _type: VectorGeometryType::MultiPolygon,
+            coordinates: vec![
+                vec![
+                    vec![p(0., 0.), p(0.5, 0.), p(0.5, 0.5), p(0., 0.5), p(0., 0.)],
+                    vec![p(0.1, 0.1), p(0.1, 0.2), p(0.2, 0.2), p(0.1, 0.1)],
+                ],
+                vec![vec![p(0.75, 0.75), p(1., 0.75), p(1., 1.), p(0.75, 0.75)]],
+            ],
+            ..Default::default()
+        });
+        tile.add_feature(
+            VectorFeature::new_wm(Some(2), Properties::new(), multi_polygon, None),
+            Some("water".into()),
+        );
+        tile
+    }
+
+    #[test]
+    fn test_decode_local() {
+        let id = CellId::from_face(0);
+        let reader = VectorTileReader::new(source_tile(id).encode_mvt(4_096), None);
+        assert_eq!(reader.layers.len(), 2);
+        let layer = reader.layer("default").unwrap();
+        assert_eq!((layer.version, layer.extent, layer.len()), (2, 4_096, 1));
+
+        let features: Vec<_> = reader.clone().collect();
+        assert_eq!(features.len(), 2);
+        let feature = &features[0];
+        assert_eq!(feature.id, Some(1));
+        assert_eq!(
+            feature.properties,
+            [
+                ("name".to_string(), ValueType::Primitive(PrimitiveValue::String("a".into()))),
+                ("rank".to_string(), ValueType::Primitive(PrimitiveValue::I64(-4))),
+            ]
+            .into()
+        );
+        assert_eq!(
+            feature.metadata,
+            Some(VectorTileMetadata { layer: "default".into(), extent: 4_096 })
+        );
+        let VectorGeometry::MultiPoint(points) = &feature.geometry else { panic!() };
+        assert_eq!(
+            points.coordinates,
+            vec![point(1_024., 1_024., None, None), point(3_072., 2_048., None, None)]
+        );
+
+        let feature = &features[1];
+        assert_eq!(feature.metadata.as_ref().unwrap().layer, "water");
+        let VectorGeometry::MultiPolygon(polygons) = &feature.geometry else { panic!() };
+        assert_eq!(polygons.coordinates.len(), 2);
+        assert_eq!(polygons.coordinates[0].len(), 2);
+        assert_eq!(polygons.coordinates[1].len(), 1);
+        for ring in polygons.coordinates.iter().flatten() {
+            assert_eq!(ring.first(), ring.last());
+        }
+        assert_eq!(polygons.coordinates[0][0].len(), 5);
+        assert_eq!(polygons.coordinates[0][1].len(), 4);
+    }
+
+    #[test]
+    fn test_decode_wm_lon_lat() {
+        // the tile (zoom 1, x 1, y 0) covers lon 0->180 and lat 0->85.05
+        let id = CellId::from_face_ij(0, 1, 0, Some(1));
+        let options = VectorTileReaderOptions {
+            id: Some(id),
+            coordinates: Some(TileCoordinates::LonLat),
+            ..Default::default()
+        };
+        let mut reader = VectorTileReader::new(source_tile(id).encode_mvt(4_096), Some(options));
+        let feature = reader.next_feature().unwrap();
+        assert_eq!(feature._type, "VectorFeature");
+        let VectorGeometry::MultiPoint(points) = &feature.geometry else { panic!() };
+        assert_eq!(points.coordinates[0].x, 45.);
+        assert!((points.coordinates[0].y - 79.17133464081945).abs() < 1e-9);
+        assert_eq!(points.coordinates[1].x, 135.);
+        assert!((points.coordinates[1].y - 66.51326044311186).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_decode_s2() {
+        let id = CellId::from_face_ij(2, 1, 1, Some(1));
+        let options = VectorTileReaderOptions {
+            id: Some(id),
+            projection: Some(Projection::S2),
+            coordinates: Some(TileCoordinates::Unit),
+        };
+        let data = source_tile(id).encode_mvt(4_096);
+        let reader = VectorTileReader::new(data.clone(), Some(options));
+        let features: Vec<_> = reader.collect();
+        let feature = &features[0];
+        assert_eq!(feature._type, "S2Feature");
+        assert_eq!(u8::from(feature.face), 2);
+        let VectorGeometry::MultiPoint(points) = &feature.geometry else { panic!() };
+        assert_eq!(points.coordinates[0], point(0.625, 0.625, None, None));
+
+        let options =
+            VectorTileReaderOptions { coordinates: Some(TileCoordinates::LonLat), ..options };
+        let feature = VectorTileReader::new(data, Some(options)).next().unwrap();
+        assert_eq!(feature._type, "VectorFeature");
+        let VectorGeometry::MultiPoint(points) = &feature.geometry else { panic!() };
+        assert!(points.coordinates[0].y > 45.);
+    }
+
+    #[test]
+    fn test_decode_into_tile_store() {
+        let reader = VectorTileReader::new(
+            source_tile(CellId::from_face(0)).encode_mvt(4_096),
+            Some(VectorTileReaderOptions {
+                coordinates: Some(TileCoordinates::Unit),
+                ..Default::default()
+            }),
+        );
+        let mut store: TileStore<VectorTileMetadata> = TileStore::default();
+        reader.for_each(|feature| store.add_feature(feature));
+        let tile = store.get_tile(CellId::from_face(0)).unwrap();
+        assert_eq!(tile.layers.keys().collect::<Vec<_>>(), vec!["default", "water"]);
+    }
+
+    fn open_tile() -> Tile<()> {
+        let mut tile: Tile<()> = Tile::new(CellId::from_face(0));
+        let m = |speed: u64| {
+            let m: MValue =
+                [("speed".to_string(), ValueType::Primitive(PrimitiveValue::U64(speed)))].into();
+            Some(m)
+        };
+        let properties: Properties =
+            [("name".to_string(), ValueType::Primitive(PrimitiveValue::String("a".into())))].into();
+        let line = VectorGeometry::LineString(VectorLineStringGeometry {
+            _type: VectorGeometryType::LineString,
+            is_3d: true,
+            offset: Some(1.5),
+            coordinates: vec![point(0.25, 0.5, Some(0.5), m(1)), point(0.75, 1., Some(0.25), m(2))],
+            ..Default::default()
+        });
+        tile.add_feature(VectorFeature::new_wm(Some(3), properties, line, None), None);
+        let point_geometry = VectorGeometry::Point(VectorPointGeometry {
+            _type: VectorGeometryType::Point,
+            coordinates: point(0.5, 0.25, None, None),
+            bbox: Some(BBox3D::new(-180., -90., 180., 90., 0., 0.)),
+            ..Default::default()
+        });
+        tile.add_feature(
+            VectorFeature::new_wm(None, Properties::new(), point_geometry, None),
+            None,
+        );
+        let polygons = VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+            _type: VectorGeometryType::MultiPolygon,
+            offset: Some(vec![vec![0.25], vec![0.]]),
+            indices: Some(vec![0, 1, 2, 3, 4, 5]),
+            coordinates: vec![
+                vec![vec![
+                    point(0., 0., None, None),
+                    point(0.5, 0., None, None),
+                    point(0., 0., None, None),
+                ]],
+                vec![vec![
+                    point(1., 1., None, None),
+                    point(0.5, 1., None, None),
+                    point(1., 1., None, None),
+                ]],
+            ],
+            ..Default::default()
+        });
+        tile.add_feature(
+            VectorFeature::new_wm(Some(4), Properties::new(), polygons, None),
+            Some("water".into()),
+        );
+        tile
+    }
+
+    #[test]
+    fn test_decode_open() {
+        let reader = VectorTileReader::new(open_tile().encode_ovt(4_096), None);
+        assert!(reader.layers.is_empty());
+        assert_eq!(reader.open_layers.len(), 2);
+        let layer = reader.open_layer("default").unwrap();
+        assert_eq!((layer.version, layer.extent, layer.len()), (1, 4_096, 2));
+
+        let features: Vec<_> = reader.collect();
+        assert_eq!(features.len(), 3);
+        // features are sorted by type, so the 2D point comes first
+        let feature = &features[0];
+        assert_eq!(feature.id, None);
+        assert_eq!(
+            feature.metadata,
+            Some(VectorTileMetadata { layer: "default".into(), extent: 4_096 })
+        );
+        let VectorGeometry::Point(point_geometry) = &feature.geometry else { panic!() };
+        assert_eq!(point_geometry.coordinates, point(2_048., 1_024., None, None));
+        let bbox = point_geometry.bbox.unwrap();
+        assert!((bbox.left + 180.).abs() < 1e-9 && (bbox.top - 90.).abs() < 1e-9);
+
+        let feature = &features[1];
+        assert_eq!(feature.id, Some(3));
+        assert_eq!(
+            feature.properties,
+            [("name".to_string(), ValueType::Primitive(PrimitiveValue::String("a".into())))].into()
+        );
+        let VectorGeometry::LineString(line) = &feature.geometry else { panic!() };
+        assert!(line.is_3d);
+        assert_eq!(line.offset, Some(1.5));
+        let m = |speed: u64| {
+            let m: MValue =
+                [("speed".to_string(), ValueType::Primitive(PrimitiveValue::U64(speed)))].into();
+            Some(m)
+        };
+        assert_eq!(
+            line.coordinates,
+            vec![
+                point(1_024., 2_048., Some(2_048.), m(1)),
+                point(3_072., 4_096., Some(1_024.), m(2))
+            ]
+        );
+
+        let feature = &features[2];
+        assert_eq!(feature.metadata.as_ref().unwrap().layer, "water");
+        let VectorGeometry::MultiPolygon(polygons) = &feature.geometry else { panic!() };
+        assert_eq!(polygons.offset, Some(vec![vec![0.25], vec![0.]]));
+        assert_eq!(polygons.indices, Some(vec![0, 1, 2, 3, 4, 5]));
+        assert_eq!(
+            polygons.coordinates[1][0],
+            vec![
+                point(4_096., 4_096., None, None),
+                point(2_048., 4_096., None, None),
+                point(4_096., 4_096., None, None)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_open_unit() {
+        let id = CellId::from_face_ij(0, 1, 0, Some(1));
+        // treat the features as relative to the zoom 1 tile
+        let mut tile = open_tile();
+        tile.id = id;
+        tile.transformed = true;
+        let options = VectorTileReaderOptions {
+            id: Some(id),
+            coordinates: Some(TileCoordinates::Unit),
+            ..Default::default()
+        };
+        let mut reader = VectorTileReader::new(tile.encode_ovt(4_096), Some(options));
+        let feature = reader.nth(1).unwrap();
+        let VectorGeometry::LineString(line) = &feature.geometry else { panic!() };
+        // x moves into the right half of the projection and z is scaled back down
+        assert_eq!(line.coordinates[0].x, 0.625);
+        assert_eq!(line.coordinates[0].y, 0.25);
+        assert_eq!(line.coordinates[0].z, Some(0.5));
+    }
+
+    #[test]
+    fn test_decode_mapbox_and_open() {
+        let mut data = source_tile(CellId::from_face(0)).encode_mvt(4_096);
+        data.extend(open_tile().encode_ovt(4_096));
+        let reader = VectorTileReader::new(data, None);
+        assert_eq!((reader.layers.len(), reader.open_layers.len()), (2, 2));
+        let layers: Vec<_> = reader.map(|f| f.metadata.unwrap().layer).collect::<Vec<_>>();
+        assert_eq!(layers, vec!["default", "water", "default", "default", "water"]);
+    }
+
+    #[test]
+    fn test_decode_open_malformed() {
+        let mut tile = RawTile::default();
+        Protobuf::from(open_tile().encode_ovt(4_096)).read_fields(&mut tile, None);
+        let mut layer = RawOpenLayer::default();
+        Protobuf::from(tile.open_layers[0].clone()).read_fields(&mut layer, None);
+        let shape = tile.cache.get_shape(layer.shape.unwrap()).unwrap();
+        let m_shape = tile.cache.get_shape(layer.m_shape.unwrap()).unwrap();
+        for data in &layer.features {
+            assert!(OpenVectorTileFeature::new(data, &tile.cache, &shape, &m_shape).is_some());
+            // truncated features are skipped instead of panicking
+            for end in 0..data.len() {
+                let feature = &data[..end];
+                assert!(
+                    OpenVectorTileFeature::new(feature, &tile.cache, &shape, &m_shape).is_none()
+                );
+            }
+            // as are features that point past the end of the column cache
+            let empty = OColumnCacheReader::default();
+            assert!(OpenVectorTileFeature::new(data, &empty, &shape, &m_shape).is_none());
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use pbf::zagzig;
+
+/// How a field of a protobuf message is decoded
+#[derive(Debug, Clone, Copy)]
+pub enum WireField {
+    /// A single varint, plain or zigzag encoded
+    Varint,
+    /// A 64-bit fixed width value
+    Fixed64,
+    /// A 32-bit fixed width value
+    Fixed32,
+    /// A length delimited byte array
+    Bytes,
+    /// A length delimited UTF-8 string
+    String,
+    /// Packed varints, plain or zigzag encoded as 64-bit values
+    Packed,
+    /// Packed zigzag varints that are decoded as 32-bit values
+    SPacked32,
+    /// A nested message
+    Message(WireSchema),
+}
+
+/// Maps a field number of a message to how it's decoded. Fields that map to `None` are skipped
+pub type WireSchema = fn(u64) -> Option<WireField>;
+
+/// Check that `data` decodes as a message of the schema: every field is complete, uses the wire
+/// type its reader expects, and strings are valid UTF-8. The protobuf reader panics on data that
+/// fails these checks, so untrusted messages are checked first.
+pub fn is_valid_message(data: &[u8], schema: WireSchema) -> bool {
+    let mut pos = 0;
+    while pos < data.len() {
+        let Some(key) = varint(data, &mut pos) else { return false };
+        let valid = match (schema(key >> 3), key & 0x7) {
+            (Some(WireField::Varint) | None, 0) => varint(data, &mut pos).is_some(),
+            (Some(WireField::Fixed64) | None, 1) => skip(data, &mut pos, 8),
+            (Some(WireField::Fixed32) | None, 5) => skip(data, &mut pos, 4),
+            (Some(WireField::Bytes) | None, 2) => length_delimited(data, &mut pos).is_some(),
+            (Some(WireField::String), 2) => length_delimited(data, &mut pos)
+                .is_some_and(|bytes| core::str::from_utf8(bytes).is_ok()),
+            (Some(WireField::Packed), 2) => {
+                length_delimited(data, &mut pos).is_some_and(|bytes| is_packed(bytes, |_| true))
+            }
+            (Some(WireField::SPacked32), 2) => {
+                length_delimited(data, &mut pos).is_some_and(|bytes| {
+                    is_packed(bytes, |value| i32::try_from(zagzig(value)).is_ok())
+                })
+            }
+            (Some(WireField::Message(schema)), 2) => length_delimited(data, &mut pos)
+                .is_some_and(|bytes| is_valid_message(bytes, schema)),
+            // an empty field
+            (None, 7) => true,
+            // mismatched wire types, groups and unknown wire types
+            _ => false,
+        };
+        if !valid {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Read a varint of at most 10 bytes like the protobuf reader does
+fn varint(data: &[u8], pos: &mut usize) -> Option<u64> {
+    let mut value = 0;
+    for shift in (0..70).step_by(7) {
+        let byte = *data.get(*pos)?;
+        *pos += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte < 0x80 {
+            break;
+        }
+    }
+    Some(value)
+}
+
+fn length_delimited<'a>(data: &'a [u8], pos: &mut usize) -> Option<&'a [u8]> {
+    let length = usize::try_from(varint(data, pos)?).ok()?;
+    let end = pos.checked_add(length)?;
+    let bytes = data.get(*pos..end)?;
+    *pos = end;
+    Some(bytes)
+}
+
+fn skip(data: &[u8], pos: &mut usize, length: usize) -> bool {
+    match pos.checked_add(length).filter(|end| *end <= data.len()) {
+        Some(end) => {
+            *pos = end;
+            true
+        }
+        None => false,
+    }
+}
+
+fn is_packed(data: &[u8], valid: impl Fn(u64) -> bool) -> bool {
+    let mut pos = 0;
+    while pos < data.len() {
+        match varint(data, &mut pos) {
+            Some(value) if valid(value) => {}
+            _ => return false,
+        }
+    }
+    true
+}
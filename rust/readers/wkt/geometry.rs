@@ -0,0 +1,667 @@
+use crate::{
+    geometry::{
+        VectorFeature, VectorGeometry, VectorGeometryType, VectorLineStringGeometry,
+        VectorMultiLineStringGeometry, VectorMultiPointGeometry, VectorMultiPolygonGeometry,
+        VectorPoint, VectorPointGeometry, VectorPolygonGeometry,
+    },
+    readers::FeatureIterator,
+};
+use alloc::{
+    boxed::Box,
+    collections::VecDeque,
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use s2json::{MValue, PrimitiveValue, ValueType};
+
+/// Errors that can occur while parsing WKT
+#[derive(Debug, PartialEq)]
+pub enum WKTError {
+    /// The text ended in the middle of a geometry
+    UnexpectedEnd,
+    /// An unexpected character at the byte offset
+    UnexpectedToken(usize),
+    /// An invalid number at the byte offset
+    InvalidNumber(usize),
+    /// The geometry type isn't supported (e.g. `CIRCULARSTRING`)
+    UnknownGeometry(String),
+    /// A `GEOMETRYCOLLECTION` can't be a single geometry, use the [`WKTGeometryReader`]
+    Collection,
+    /// The CRS keyword isn't supported (e.g. `TIMECRS`)
+    UnknownCRS(String),
+}
+
+/// The geometry types that can be parsed
+const GEOMETRY_NAMES: [&str; 11] = [
+    "POINT",
+    "MULTIPOINT",
+    "LINESTRING",
+    "LINEARRING",
+    "MULTILINESTRING",
+    "POLYGON",
+    "TRIANGLE",
+    "MULTIPOLYGON",
+    "TIN",
+    "POLYHEDRALSURFACE",
+    "GEOMETRYCOLLECTION",
+];
+
+/// The key of a point's M-value storing the measure of a WKT or WKB `M` coordinate
+pub const WKT_M_KEY: &str = "m";
+
+/// A parsed geometry before collections are flattened
+#[derive(Debug)]
+pub(crate) enum WKTShape {
+    Geometry(Box<VectorGeometry>),
+    Empty,
+    Collection(Vec<WKTShape>),
+}
+impl WKTShape {
+    /// Append the non-empty geometries, flattening collections
+    pub(crate) fn flatten(self, geometries: &mut Vec<VectorGeometry>) {
+        match self {
+            WKTShape::Geometry(geometry) => geometries.push(*geometry),
+            WKTShape::Empty => {}
+            WKTShape::Collection(shapes) => shapes.into_iter().for_each(|s| s.flatten(geometries)),
+        }
+    }
+
+    /// Convert to a single geometry
+    pub(crate) fn single(self) -> Result<Option<VectorGeometry>, WKTError> {
+        match self {
+            WKTShape::Geometry(geometry) => Ok(Some(*geometry)),
+            WKTShape::Empty => Ok(None),
+            WKTShape::Collection(_) => Err(WKTError::Collection),
+        }
+    }
+}
+
+/// Split the `SRID=<srid>;` prefix of an EWKT string from its geometry
+pub fn split_ewkt_srid(wkt: &str) -> (Option<u32>, &str) {
+    let trimmed = wkt.trim_start();
+    let Some((prefix, geometry)) = trimmed.split_once(';') else {
+        return (None, wkt);
+    };
+    let srid = prefix.trim().strip_prefix("SRID=").or(prefix.trim().strip_prefix("srid="));
+    match srid.and_then(|srid| srid.trim().parse().ok()) {
+        Some(srid) => (Some(srid), geometry),
+        None => (None, wkt),
+    }
+}
+
+/// # WKT Geometry Parser
+///
+/// ## Description
+/// Parse a single WKT or EWKT geometry into a [`VectorGeometry`]. The `SRID=<srid>;` prefix of
+/// EWKT is ignored, see [`split_ewkt_srid`]. `Z`, `M` and `ZM` coordinates are supported: the
+/// measure is stored in the point's M-value under [`WKT_M_KEY`]. `TRIANGLE` is parsed as a
+/// Polygon, `TIN` and `POLYHEDRALSURFACE` as a MultiPolygon.
+///
+/// `POINT EMPTY` returns `None`, other empty geometries have no coordinates.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::parse_wkt_geometry;
+///
+/// let geometry = parse_wkt_geometry("POINT Z (1 2 3)").unwrap().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry>
+/// - <https://www.ogc.org/standard/sfa/>
+pub fn parse_wkt_geometry(wkt: &str) -> Result<Option<VectorGeometry>, WKTError> {
+    let mut parser = WKTParser::new(split_ewkt_srid(wkt).1);
+    let shape = parser.geometry()?;
+    parser.end()?;
+    shape.single()
+}
+
+/// # WKT Geometry Reader
+///
+/// ## Description
+/// Parse a sequence of WKT or EWKT geometries (separated by whitespace, commas or semicolons)
+/// into features. Geometry collections are flattened and empty geometries are skipped. See
+/// [`parse_wkt_geometry`] for the supported geometries.
+///
+/// Implements the [`FeatureIterator`]
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{FeatureIterator, WKTGeometryReader};
+///
+/// let wkt = "POINT(4 6) GEOMETRYCOLLECTION(POINT(1 2), LINESTRING(3 4,5 6))";
+/// let mut reader = WKTGeometryReader::new(wkt).unwrap();
+/// let feature = reader.next_feature().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://en.wikipedia.org/wiki/Well-known_text_representation_of_geometry>
+#[derive(Debug, Default)]
+pub struct WKTGeometryReader {
+    /// The SRID of the first EWKT geometry if any
+    pub srid: Option<u32>,
+    features: VecDeque<VectorFeature>,
+}
+impl WKTGeometryReader {
+    /// Parse the WKT geometries
+    pub fn new(data: &str) -> Result<Self, WKTError> {
+        let mut reader = WKTGeometryReader::default();
+        let mut parser = WKTParser::new(data);
+        let mut geometries = vec![];
+        loop {
+            parser.skip(|b| b.is_ascii_whitespace() || b == b',' || b == b';');
+            if parser.peek().is_none() {
+                break;
+            }
+            if parser.rest().get(..5).is_some_and(|s| s.eq_ignore_ascii_case("SRID=")) {
+                let (srid, rest) = split_ewkt_srid(parser.rest());
+                reader.srid = reader.srid.or(srid);
+                parser.pos = data.len() - rest.len();
+            }
+            parser.geometry()?.flatten(&mut geometries);
+        }
+        reader.features = geometries
+            .into_iter()
+            .map(|geometry| VectorFeature::new_wm(None, Default::default(), geometry, None))
+            .collect();
+
+        Ok(reader)
+    }
+}
+
+impl Iterator for WKTGeometryReader {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.features.pop_front()
+    }
+}
+impl FeatureIterator for WKTGeometryReader {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// The ordinates present in each point
+#[derive(Debug, Default, Clone, Copy)]
+struct Dimensions {
+    /// Whether a third ordinate is Z rather than M. None if it's not specified
+    z: Option<bool>,
+}
+
+/// A recursive descent WKT geometry parser
+#[derive(Debug)]
+struct WKTParser<'a> {
+    wkt: &'a str,
+    pos: usize,
+}
+impl<'a> WKTParser<'a> {
+    fn new(wkt: &'a str) -> Self {
+        WKTParser { wkt, pos: 0 }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.wkt[self.pos..]
+    }
+
+    fn skip(&mut self, skip: impl Fn(u8) -> bool) {
+        while self.wkt.as_bytes().get(self.pos).is_some_and(|b| skip(*b)) {
+            self.pos += 1;
+        }
+    }
+
+    /// The next non-whitespace byte
+    fn peek(&mut self) -> Option<u8> {
+        self.skip(|b| b.is_ascii_whitespace());
+        self.wkt.as_bytes().get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), WKTError> {
+        match self.peek() {
+            Some(b) if b == byte => {
+                self.pos += 1;
+                Ok(())
+            }
+            Some(_) => Err(WKTError::UnexpectedToken(self.pos)),
+            None => Err(WKTError::UnexpectedEnd),
+        }
+    }
+
+    /// Check that only whitespace is left
+    fn end(&mut self) -> Result<(), WKTError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(WKTError::UnexpectedToken(self.pos)),
+        }
+    }
+
+    /// Read a keyword, upper cased
+    fn word(&mut self) -> Option<String> {
+        self.peek()?;
+        let start = self.pos;
+        self.skip(|b| b.is_ascii_alphabetic());
+        (self.pos > start).then(|| self.wkt[start..self.pos].to_ascii_uppercase())
+    }
+
+    /// Read `EMPTY` if it's next
+    fn empty(&mut self) -> bool {
+        let is_empty = self.empty_next();
+        if is_empty {
+            self.word();
+        }
+        is_empty
+    }
+
+    fn number(&mut self) -> Result<f64, WKTError> {
+        self.peek().ok_or(WKTError::UnexpectedEnd)?;
+        let start = self.pos;
+        self.skip(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'+'));
+        self.wkt[start..self.pos].parse().map_err(|_| WKTError::InvalidNumber(start))
+    }
+
+    /// Parse a tagged geometry
+    fn geometry(&mut self) -> Result<WKTShape, WKTError> {
+        let start = self.pos;
+        let word = self.word().ok_or(WKTError::UnexpectedToken(self.pos))?;
+        // the dimensions may be written apart (`POINT Z`) or appended (`POINTZ`)
+        let (name, mut suffix) = ["", "ZM", "Z", "M"]
+            .into_iter()
+            .find_map(|suffix| {
+                let name = word.strip_suffix(suffix)?;
+                GEOMETRY_NAMES.contains(&name).then(|| (String::from(name), String::from(suffix)))
+            })
+            .ok_or(WKTError::UnknownGeometry(word.clone()))?;
+        if suffix.is_empty() && !self.empty_next() && self.peek() != Some(b'(') {
+            suffix = self.word().unwrap_or_default();
+        }
+        let dims = match suffix.as_str() {
+            "" => Dimensions::default(),
+            "Z" | "ZM" => Dimensions { z: Some(true) },
+            "M" => Dimensions { z: Some(false) },
+            _ => return Err(WKTError::UnexpectedToken(start)),
+        };
+        let is_empty = self.empty();
+        let geometry = match name.as_str() {
+            "GEOMETRYCOLLECTION" => {
+                let shapes = self.list(is_empty, |p| p.geometry())?;
+                return Ok(WKTShape::Collection(shapes));
+            }
+            "POINT" if is_empty => return Ok(WKTShape::Empty),
+            "POINT" => {
+                self.expect(b'(')?;
+                let point = self.point(dims)?;
+                self.expect(b')')?;
+                point_geometry(point)
+            }
+            "MULTIPOINT" => {
+                let points = self.list(is_empty, |p| match p.peek() {
+                    // points may be wrapped in their own brackets
+                    Some(b'(') => {
+                        p.expect(b'(')?;
+                        let point = p.point(dims)?;
+                        p.expect(b')')?;
+                        Ok(Some(point))
+                    }
+                    _ if p.empty() => Ok(None),
+                    _ => p.point(dims).map(Some),
+                })?;
+                multi_point_geometry(points.into_iter().flatten().collect())
+            }
+            "LINESTRING" | "LINEARRING" => line_string_geometry(self.line(is_empty, dims)?),
+            "MULTILINESTRING" => multi_line_string_geometry(self.list(is_empty, |p| {
+                let is_empty = p.empty();
+                p.line(is_empty, dims)
+            })?),
+            "POLYGON" | "TRIANGLE" => polygon_geometry(self.polygon(is_empty, dims)?),
+            "MULTIPOLYGON" | "TIN" | "POLYHEDRALSURFACE" => {
+                multi_polygon_geometry(self.list(is_empty, |p| {
+                    let is_empty = p.empty();
+                    p.polygon(is_empty, dims)
+                })?)
+            }
+            _ => unreachable!(),
+        };
+        Ok(WKTShape::Geometry(Box::new(geometry)))
+    }
+
+    /// Whether `EMPTY` is next, without reading it
+    fn empty_next(&self) -> bool {
+        self.rest().trim_start().get(..5).is_some_and(|s| s.eq_ignore_ascii_case("EMPTY"))
+    }
+
+    /// Parse a bracketed, comma separated list
+    fn list<V>(
+        &mut self,
+        is_empty: bool,
+        mut item: impl FnMut(&mut Self) -> Result<V, WKTError>,
+    ) -> Result<Vec<V>, WKTError> {
+        let mut items = vec![];
+        if is_empty {
+            return Ok(items);
+        }
+        self.expect(b'(')?;
+        loop {
+            items.push(item(self)?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                _ => break,
+            }
+        }
+        self.expect(b')')?;
+        Ok(items)
+    }
+
+    fn line(&mut self, is_empty: bool, dims: Dimensions) -> Result<Vec<VectorPoint>, WKTError> {
+        self.list(is_empty, |p| p.point(dims))
+    }
+
+    fn polygon(
+        &mut self,
+        is_empty: bool,
+        dims: Dimensions,
+    ) -> Result<Vec<Vec<VectorPoint>>, WKTError> {
+        self.list(is_empty, |p| {
+            let is_empty = p.empty();
+            p.line(is_empty, dims)
+        })
+    }
+
+    /// Parse the ordinates of a point
+    fn point(&mut self, dims: Dimensions) -> Result<VectorPoint, WKTError> {
+        let mut ordinates = vec![];
+        while self.peek().is_some_and(|b| !matches!(b, b',' | b')')) {
+            ordinates.push(self.number()?);
+        }
+        let (has_z, has_m) = match (ordinates.len(), dims.z) {
+            (2, _) => (false, false),
+            (3, Some(z)) => (z, !z),
+            (3, None) => (true, false),
+            (4, _) => (true, true),
+            _ => return Err(WKTError::UnexpectedToken(self.pos)),
+        };
+        let m = has_m.then(|| m_value(ordinates[ordinates.len() - 1]));
+        Ok(VectorPoint::new(ordinates[0], ordinates[1], has_z.then(|| ordinates[2]), m))
+    }
+}
+
+/// Store a measure in an M-value
+pub(crate) fn m_value(m: f64) -> MValue {
+    MValue::from([(WKT_M_KEY.into(), ValueType::Primitive(PrimitiveValue::F64(m)))])
+}
+
+/// The measure of a point stored under [`WKT_M_KEY`]
+pub(crate) fn measure(point: &VectorPoint) -> Option<f64> {
+    match point.m.as_ref()?.get(WKT_M_KEY)? {
+        ValueType::Primitive(PrimitiveValue::F64(m)) => Some(*m),
+        ValueType::Primitive(PrimitiveValue::F32(m)) => Some(*m as f64),
+        ValueType::Primitive(PrimitiveValue::I64(m)) => Some(*m as f64),
+        ValueType::Primitive(PrimitiveValue::U64(m)) => Some(*m as f64),
+        _ => None,
+    }
+}
+
+pub(crate) fn point_geometry(point: VectorPoint) -> VectorGeometry {
+    VectorGeometry::Point(VectorPointGeometry {
+        _type: VectorGeometryType::Point,
+        is_3d: point.z.is_some(),
+        coordinates: point,
+        ..Default::default()
+    })
+}
+
+pub(crate) fn multi_point_geometry(points: Vec<VectorPoint>) -> VectorGeometry {
+    VectorGeometry::MultiPoint(VectorMultiPointGeometry {
+        _type: VectorGeometryType::MultiPoint,
+        is_3d: points.iter().any(|p| p.z.is_some()),
+        coordinates: points,
+        ..Default::default()
+    })
+}
+
+pub(crate) fn line_string_geometry(line: Vec<VectorPoint>) -> VectorGeometry {
+    VectorGeometry::LineString(VectorLineStringGeometry {
+        _type: VectorGeometryType::LineString,
+        is_3d: line.iter().any(|p| p.z.is_some()),
+        coordinates: line,
+        ..Default::default()
+    })
+}
+
+pub(crate) fn multi_line_string_geometry(lines: Vec<Vec<VectorPoint>>) -> VectorGeometry {
+    VectorGeometry::MultiLineString(VectorMultiLineStringGeometry {
+        _type: VectorGeometryType::MultiLineString,
+        is_3d: lines.iter().flatten().any(|p| p.z.is_some()),
+        coordinates: lines,
+        ..Default::default()
+    })
+}
+
+pub(crate) fn polygon_geometry(polygon: Vec<Vec<VectorPoint>>) -> VectorGeometry {
+    VectorGeometry::Polygon(VectorPolygonGeometry {
+        _type: VectorGeometryType::Polygon,
+        is_3d: polygon.iter().flatten().any(|p| p.z.is_some()),
+        coordinates: polygon,
+        ..Default::default()
+    })
+}
+
+pub(crate) fn multi_polygon_geometry(polygons: Vec<Vec<Vec<VectorPoint>>>) -> VectorGeometry {
+    VectorGeometry::MultiPolygon(VectorMultiPolygonGeometry {
+        _type: VectorGeometryType::MultiPolygon,
+        is_3d: polygons.iter().flatten().flatten().any(|p| p.z.is_some()),
+        coordinates: polygons,
+        ..Default::default()
+    })
+}
+
+/// # WKT Geometry Writer
+///
+/// ## Description
+/// Write a [`VectorGeometry`] as WKT, or as EWKT if a SRID is given. 3D geometries are written
+/// with `Z`, and the measures stored under [`WKT_M_KEY`] in the first point's M-value are
+/// written with `M`. Geometries without coordinates are written as `EMPTY`.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{parse_wkt_geometry, to_wkt};
+///
+/// let geometry = parse_wkt_geometry("POINT Z (1 2 3)").unwrap().unwrap();
+/// assert_eq!(to_wkt(&geometry, Some(4326)), "SRID=4326;POINT Z (1 2 3)");
+/// ```
+pub fn to_wkt(geometry: &VectorGeometry, srid: Option<u32>) -> String {
+    let mut wkt = srid.map(|srid| format!("SRID={srid};")).unwrap_or_default();
+    let first = geometry_points(geometry).next();
+    let has_m = first.is_some_and(|p| measure(p).is_some());
+    let (name, is_3d, is_empty) = match geometry {
+        VectorGeometry::Point(g) => ("POINT", g.is_3d, false),
+        VectorGeometry::MultiPoint(g) => ("MULTIPOINT", g.is_3d, g.coordinates.is_empty()),
+        VectorGeometry::LineString(g) => ("LINESTRING", g.is_3d, g.coordinates.is_empty()),
+        VectorGeometry::MultiLineString(g) => {
+            ("MULTILINESTRING", g.is_3d, g.coordinates.is_empty())
+        }
+        VectorGeometry::Polygon(g) => ("POLYGON", g.is_3d, g.coordinates.is_empty()),
+        VectorGeometry::MultiPolygon(g) => ("MULTIPOLYGON", g.is_3d, g.coordinates.is_empty()),
+    };
+    wkt.push_str(name);
+    wkt.push_str(match (is_3d, has_m) {
+        (true, true) => " ZM ",
+        (true, false) => " Z ",
+        (false, true) => " M ",
+        (false, false) => " ",
+    });
+    if is_empty {
+        wkt.push_str("EMPTY");
+        return wkt;
+    }
+    let point = |p: &VectorPoint| -> String {
+        let mut ordinates = vec![p.x.to_string(), p.y.to_string()];
+        if is_3d {
+            ordinates.push(p.z.unwrap_or(0.).to_string());
+        }
+        if has_m {
+            ordinates.push(measure(p).unwrap_or(0.).to_string());
+        }
+        ordinates.join(" ")
+    };
+    let line =
+        |l: &Vec<VectorPoint>| format!("({})", l.iter().map(point).collect::<Vec<_>>().join(", "));
+    let polygon = |p: &Vec<Vec<VectorPoint>>| {
+        format!("({})", p.iter().map(line).collect::<Vec<_>>().join(", "))
+    };
+    let body = match geometry {
+        VectorGeometry::Point(g) => format!("({})", point(&g.coordinates)),
+        VectorGeometry::MultiPoint(g) => line(&g.coordinates),
+        VectorGeometry::LineString(g) => line(&g.coordinates),
+        VectorGeometry::MultiLineString(g) => polygon(&g.coordinates),
+        VectorGeometry::Polygon(g) => polygon(&g.coordinates),
+        VectorGeometry::MultiPolygon(g) => {
+            format!("({})", g.coordinates.iter().map(polygon).collect::<Vec<_>>().join(", "))
+        }
+    };
+    wkt.push_str(&body);
+    wkt
+}
+
+/// Iterate over every point of a geometry
+pub(crate) fn geometry_points(geometry: &VectorGeometry) -> impl Iterator<Item = &VectorPoint> {
+    let points: Vec<&VectorPoint> = match geometry {
+        VectorGeometry::Point(g) => vec![&g.coordinates],
+        VectorGeometry::MultiPoint(g) => g.coordinates.iter().collect(),
+        VectorGeometry::LineString(g) => g.coordinates.iter().collect(),
+        VectorGeometry::MultiLineString(g) => g.coordinates.iter().flatten().collect(),
+        VectorGeometry::Polygon(g) => g.coordinates.iter().flatten().collect(),
+        VectorGeometry::MultiPolygon(g) => g.coordinates.iter().flatten().flatten().collect(),
+    };
+    points.into_iter()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(x: f64, y: f64) -> VectorPoint {
+        VectorPoint::new(x, y, None, None)
+    }
+
+    #[test]
+    fn test_parse_points() {
+        assert_eq!(parse_wkt_geometry("POINT(0 0)"), Ok(Some(point_geometry(point(0., 0.)))));
+        assert_eq!(
+            parse_wkt_geometry("point z (1 2 3)"),
+            Ok(Some(point_geometry(VectorPoint::new(1., 2., Some(3.), None))))
+        );
+        assert_eq!(
+            parse_wkt_geometry("SRID=4326;POINTM(1 2 3)"),
+            Ok(Some(point_geometry(VectorPoint::new(1., 2., None, Some(m_value(3.))))))
+        );
+        assert_eq!(
+            parse_wkt_geometry("POINT ZM (1 2 3 4)"),
+            Ok(Some(point_geometry(VectorPoint::new(1., 2., Some(3.), Some(m_value(4.))))))
+        );
+        assert_eq!(parse_wkt_geometry("POINT EMPTY"), Ok(None));
+        assert_eq!(
+            parse_wkt_geometry("MULTIPOINT ((10 40), (40 30), EMPTY)"),
+            parse_wkt_geometry("MULTIPOINT (10 40, 40 30)")
+        );
+        assert_eq!(
+            parse_wkt_geometry("MULTIPOINT (10 40, 40 30)"),
+            Ok(Some(multi_point_geometry(vec![point(10., 40.), point(40., 30.)])))
+        );
+        assert_eq!(split_ewkt_srid("SRID=3857;POINT(1 2)"), (Some(3857), "POINT(1 2)"));
+        assert_eq!(split_ewkt_srid("POINT(1 2)"), (None, "POINT(1 2)"));
+    }
+
+    #[test]
+    fn test_parse_lines_and_polygons() {
+        assert_eq!(
+            parse_wkt_geometry("LINESTRING (30 10, 10 30, 40 40)"),
+            Ok(Some(line_string_geometry(vec![point(30., 10.), point(10., 30.), point(40., 40.)])))
+        );
+        assert_eq!(
+            parse_wkt_geometry("MULTILINESTRING ((10 10, 20 20), (40 40, 30 30))"),
+            Ok(Some(multi_line_string_geometry(vec![
+                vec![point(10., 10.), point(20., 20.)],
+                vec![point(40., 40.), point(30., 30.)],
+            ])))
+        );
+        let polygon = "POLYGON ((35 10, 45 45, 15 40, 35 10), (20 30, 35 35, 30 20, 20 30))";
+        let Some(VectorGeometry::Polygon(polygon)) = parse_wkt_geometry(polygon).unwrap() else {
+            panic!("not a polygon")
+        };
+        assert_eq!(polygon.coordinates.len(), 2);
+        assert_eq!(polygon.coordinates[1][2], point(30., 20.));
+
+        let multi = "MULTIPOLYGON Z (((40 40 1, 20 45 2, 45 30 3, 40 40 1)), EMPTY, ((20 35 0, 10 \
+                     30 0, 10 10 0, 20 35 0)))";
+        let Some(VectorGeometry::MultiPolygon(multi)) = parse_wkt_geometry(multi).unwrap() else {
+            panic!("not a multipolygon")
+        };
+        assert!(multi.is_3d);
+        assert_eq!(multi.coordinates.len(), 3);
+        assert!(multi.coordinates[1].is_empty());
+        assert_eq!(multi.coordinates[0][0][1], VectorPoint::new(20., 45., Some(2.), None));
+
+        assert_eq!(
+            parse_wkt_geometry("MULTIPOLYGON EMPTY"),
+            Ok(Some(multi_polygon_geometry(vec![])))
+        );
+        let triangle = parse_wkt_geometry("TRIANGLE((0 0 0,0 1 0,1 1 0,0 0 0))").unwrap();
+        assert!(matches!(triangle, Some(VectorGeometry::Polygon(_))));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_wkt_geometry("POINT (1 2"), Err(WKTError::UnexpectedEnd));
+        assert_eq!(parse_wkt_geometry("POINT (1 a)"), Err(WKTError::InvalidNumber(9)));
+        assert_eq!(parse_wkt_geometry("POINT (1 2) x"), Err(WKTError::UnexpectedToken(12)));
+        assert_eq!(
+            parse_wkt_geometry("CIRCULARSTRING (1 2, 3 4, 5 6)"),
+            Err(WKTError::UnknownGeometry("CIRCULARSTRING".into()))
+        );
+        assert_eq!(
+            parse_wkt_geometry("GEOMETRYCOLLECTION (POINT (1 2))"),
+            Err(WKTError::Collection)
+        );
+    }
+
+    #[test]
+    fn test_reader() {
+        let wkt = "POINT(4 6)
+            GEOMETRYCOLLECTION(POINT(1 2), LINESTRING(3 4,5 6))
+            MULTIPOLYGON EMPTY
+            SRID=4326;POINT EMPTY
+            TRIANGLE((0 0 0,0 1 0,1 1 0,0 0 0))";
+        let mut reader = WKTGeometryReader::new(wkt).unwrap();
+        assert_eq!(reader.srid, Some(4326));
+        let features: Vec<VectorGeometry> = reader.by_ref().map(|f| f.geometry).collect();
+        assert_eq!(features.len(), 5);
+        assert_eq!(features[1], point_geometry(point(1., 2.)));
+        assert_eq!(features[2], line_string_geometry(vec![point(3., 4.), point(5., 6.)]));
+        assert_eq!(features[3], multi_polygon_geometry(vec![]));
+        assert!(matches!(features[4], VectorGeometry::Polygon(_)));
+    }
+
+    #[test]
+    fn test_to_wkt() {
+        let wkts = [
+            "POINT (1.5 -2)",
+            "POINT Z (1 2 3)",
+            "POINT M (1 2 3)",
+            "SRID=4326;POINT ZM (1 2 3 4)",
+            "MULTIPOINT (10 40, 40 30)",
+            "LINESTRING EMPTY",
+            "LINESTRING (30 10, 10 30, 40 40)",
+            "MULTILINESTRING ((10 10, 20 20), (40 40, 30 30))",
+            "POLYGON ((35 10, 45 45, 15 40, 35 10), (20 30, 35 35, 30 20, 20 30))",
+            "MULTIPOLYGON Z (((40 40 1, 20 45 2, 45 30 3, 40 40 1)), ((20 35 0, 10 30 0, 20 35 \
+             0)))",
+        ];
+        for wkt in wkts {
+            let (srid, _) = split_ewkt_srid(wkt);
+            let geometry = parse_wkt_geometry(wkt).unwrap().unwrap();
+            assert_eq!(to_wkt(&geometry, srid), wkt);
+        }
+    }
+}
@@ -0,0 +1,10 @@
+/// WKT and EWKT geometry parsing and writing
+pub mod geometry;
+/// WKT1 and WKT2 coordinate reference system parsing
+pub mod projection;
+/// WKB and EWKB geometry reading and writing
+pub mod wkb;
+
+pub use geometry::*;
+pub use projection::*;
+pub use wkb::*;
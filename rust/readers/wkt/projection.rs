@@ -0,0 +1,868 @@
+use super::WKTError;
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::f64::consts::PI;
+
+/// The keywords of every CRS object that can be parsed
+const CRS_KEYWORDS: [&str; 23] = [
+    "GEOGCS",
+    "GEOCCS",
+    "PROJCS",
+    "VERT_CS",
+    "VERTCS",
+    "LOCAL_CS",
+    "COMPD_CS",
+    "GEOGCRS",
+    "GEOGRAPHICCRS",
+    "BASEGEOGCRS",
+    "GEODCRS",
+    "GEODETICCRS",
+    "BASEGEODCRS",
+    "PROJCRS",
+    "PROJECTEDCRS",
+    "VERTCRS",
+    "VERTICALCRS",
+    "BASEVERTCRS",
+    "ENGCRS",
+    "ENGINEERINGCRS",
+    "BASEENGCRS",
+    "COMPOUNDCRS",
+    "BOUNDCRS",
+];
+/// The keywords of the base CRS of a projected CRS
+const BASE_CRS_KEYWORDS: [&str; 3] = ["GEOGCS", "BASEGEOGCRS", "BASEGEODCRS"];
+/// The keywords of datums and datum ensembles
+const DATUM_KEYWORDS: [&str; 11] = [
+    "DATUM",
+    "GEODETICDATUM",
+    "TRF",
+    "ENSEMBLE",
+    "VERT_DATUM",
+    "VDATUM",
+    "VERTICALDATUM",
+    "VRF",
+    "LOCAL_DATUM",
+    "EDATUM",
+    "ENGINEERINGDATUM",
+];
+/// The keywords of units
+const UNIT_KEYWORDS: [&str; 6] =
+    ["UNIT", "LENGTHUNIT", "ANGLEUNIT", "SCALEUNIT", "TIMEUNIT", "PARAMETRICUNIT"];
+/// The keywords of identifiers
+const ID_KEYWORDS: [&str; 2] = ["AUTHORITY", "ID"];
+/// The conversion factor of a degree to radians
+const DEGREE: f64 = PI / 180.;
+
+/// A value inside the brackets of a WKT object
+#[derive(Debug, Clone, PartialEq)]
+pub enum WKTValue {
+    /// A quoted string
+    String(String),
+    /// A number
+    Number(f64),
+    /// An unquoted enumeration value (e.g. the `NORTH` of `AXIS["Lat",NORTH]`)
+    Keyword(String),
+    /// A nested object
+    Node(WKTNode),
+}
+
+/// A WKT object, e.g. `SPHEROID["WGS 84",6378137,298.257223563]`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WKTNode {
+    /// The upper cased keyword
+    pub keyword: String,
+    /// The values inside the brackets in order
+    pub values: Vec<WKTValue>,
+}
+impl WKTNode {
+    /// The first child object with one of the keywords
+    pub fn child(&self, keywords: &[&str]) -> Option<&WKTNode> {
+        self.values.iter().find_map(|value| match value {
+            WKTValue::Node(node) if keywords.contains(&node.keyword.as_str()) => Some(node),
+            _ => None,
+        })
+    }
+
+    /// All child objects with one of the keywords
+    pub fn children<'a>(&'a self, keywords: &'a [&'a str]) -> impl Iterator<Item = &'a WKTNode> {
+        self.values.iter().filter_map(move |value| match value {
+            WKTValue::Node(node) if keywords.contains(&node.keyword.as_str()) => Some(node),
+            _ => None,
+        })
+    }
+
+    /// The `index`th value if it's a string or an enumeration value
+    pub fn string(&self, index: usize) -> Option<&str> {
+        match self.values.get(index)? {
+            WKTValue::String(value) | WKTValue::Keyword(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// The `index`th value if it's a number
+    pub fn number(&self, index: usize) -> Option<f64> {
+        match self.values.get(index)? {
+            WKTValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// The name of the object, its first value
+    pub fn name(&self) -> String {
+        self.string(0).unwrap_or_default().to_string()
+    }
+}
+
+/// Parse a WKT object tree. Both `[]` and `()` brackets are accepted.
+pub fn parse_wkt_object(wkt: &str) -> Result<WKTNode, WKTError> {
+    let mut parser = WKTObjectParser { wkt, pos: 0 };
+    let keyword = parser.word()?;
+    let node = parser.node(keyword)?;
+    parser.end()?;
+    Ok(node)
+}
+
+/// The kind of a coordinate reference system
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CRSKind {
+    /// Latitude and longitude on an ellipsoid (`GEOGCS`, `GEOGCRS`)
+    #[default]
+    Geographic,
+    /// Earth-centered cartesian coordinates (`GEOCCS`, or a `GEODCRS` with a cartesian CS)
+    Geocentric,
+    /// A projection of a geographic CRS (`PROJCS`, `PROJCRS`)
+    Projected,
+    /// Heights or depths (`VERT_CS`, `VERTCRS`)
+    Vertical,
+    /// A local CRS (`LOCAL_CS`, `ENGCRS`)
+    Engineering,
+    /// A horizontal CRS paired with a vertical CRS (`COMPD_CS`, `COMPOUNDCRS`)
+    Compound,
+}
+
+/// An authority identifier, e.g. `AUTHORITY["EPSG","4326"]` or `ID["EPSG",4326]`
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSId {
+    /// The authority, e.g. `EPSG`
+    pub authority: String,
+    /// The code in the authority
+    pub code: String,
+}
+
+/// The quantity a unit measures
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CRSUnitKind {
+    /// A length, converted to metres
+    Linear,
+    /// An angle, converted to radians
+    Angular,
+    /// A scale, converted to unity
+    Scale,
+    /// A time, converted to seconds
+    Time,
+    /// A parametric or unknown quantity
+    #[default]
+    Unknown,
+}
+
+/// A unit of measure
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSUnit {
+    /// The name of the unit, e.g. `metre`
+    pub name: String,
+    /// The quantity the unit measures
+    pub kind: CRSUnitKind,
+    /// The factor converting a value to metres, radians, unity or seconds
+    pub conversion_factor: f64,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+
+/// The reference ellipsoid of a datum
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSEllipsoid {
+    /// The name of the ellipsoid, e.g. `WGS 84`
+    pub name: String,
+    /// The semi-major axis in metres
+    pub semi_major_axis: f64,
+    /// The inverse flattening (0 for a sphere)
+    pub inverse_flattening: f64,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+
+/// The meridian longitudes are measured from
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSPrimeMeridian {
+    /// The name of the meridian, e.g. `Greenwich`
+    pub name: String,
+    /// The longitude from Greenwich in degrees
+    pub longitude: f64,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+
+/// A datum (or datum ensemble) anchoring a CRS to the earth
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSDatum {
+    /// The name of the datum, e.g. `WGS_1984` (ESRI prefixes the name with `D_`)
+    pub name: String,
+    /// The ellipsoid of a geodetic datum
+    pub ellipsoid: Option<CRSEllipsoid>,
+    /// The prime meridian of a geodetic datum
+    pub prime_meridian: Option<CRSPrimeMeridian>,
+    /// The 3 or 7 Helmert parameters to WGS 84 (`TOWGS84` or the transformation of a `BOUNDCRS`).
+    /// Translations in metres, position vector rotations in arc-seconds and scale in ppm.
+    pub to_wgs84: Option<Vec<f64>>,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+
+/// A coordinate system axis
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSAxis {
+    /// The name of the axis, e.g. `Easting` or `geodetic latitude`
+    pub name: String,
+    /// The abbreviation of a WKT2 axis, e.g. the `Lat` of `geodetic latitude (Lat)`
+    pub abbreviation: Option<String>,
+    /// The lower cased direction, e.g. `north` or `east`
+    pub direction: String,
+    /// The unit of the axis if given
+    pub unit: Option<CRSUnit>,
+}
+
+/// A projection method
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSMethod {
+    /// The name of the method, e.g. `Transverse_Mercator` or `Transverse Mercator`
+    pub name: String,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+
+/// A parameter of a projection method
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSParameter {
+    /// The name of the parameter, e.g. `false_easting` or `False easting`
+    pub name: String,
+    /// The value. WKT1 values are in the unit of the CRS (lengths) or the base CRS (angles).
+    pub value: f64,
+    /// The unit of a WKT2 parameter
+    pub unit: Option<CRSUnit>,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+
+/// The conversion from the base CRS of a projected CRS
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CRSConversion {
+    /// The name of the conversion (the method name for WKT1)
+    pub name: String,
+    /// The projection method
+    pub method: CRSMethod,
+    /// The parameters of the method
+    pub parameters: Vec<CRSParameter>,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+
+/// # WKT CRS
+///
+/// ## Description
+/// A coordinate reference system parsed from WKT1 (OGC and ESRI flavors) or WKT2, shaped after
+/// PROJJSON. Parse one with [`parse_wkt_crs`].
+///
+/// NOTE: A WKT2 `BOUNDCRS` is parsed as its source CRS with the transformation stored as the
+/// [`CRSDatum::to_wgs84`] parameters.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{parse_wkt_crs, CRSKind};
+///
+/// let crs = parse_wkt_crs(r#"PROJCS["WGS_1984_UTM_Zone_31N",GEOGCS["GCS_WGS_1984",DATUM["D_WGS_1984",SPHEROID["WGS_1984",6378137.0,298.257223563]],PRIMEM["Greenwich",0.0],UNIT["Degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],PARAMETER["False_Easting",500000.0],PARAMETER["False_Northing",0.0],PARAMETER["Central_Meridian",3.0],PARAMETER["Scale_Factor",0.9996],PARAMETER["Latitude_Of_Origin",0.0],UNIT["Meter",1.0]]"#).unwrap();
+/// assert_eq!(crs.kind, CRSKind::Projected);
+/// assert_eq!(crs.parameter("false easting"), Some(500000.));
+/// assert_eq!(crs.ellipsoid().unwrap().semi_major_axis, 6378137.);
+/// ```
+///
+/// ## Links
+/// - <https://docs.ogc.org/is/18-010r11/18-010r11.pdf>
+/// - <https://docs.ogc.org/is/12-063r5/12-063r5.html#appendix-C>
+/// - <https://proj.org/en/latest/specifications/projjson.html>
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct WKTCRS {
+    /// The kind of CRS
+    pub kind: CRSKind,
+    /// The name of the CRS
+    pub name: String,
+    /// The datum of a geographic, geocentric, vertical or engineering CRS
+    pub datum: Option<CRSDatum>,
+    /// The geographic CRS a projected CRS is based on
+    pub base_crs: Option<Box<WKTCRS>>,
+    /// The projection of a projected CRS
+    pub conversion: Option<CRSConversion>,
+    /// The horizontal and vertical CRSs of a compound CRS
+    pub components: Vec<WKTCRS>,
+    /// The lower cased WKT2 coordinate system type, e.g. `ellipsoidal` or `cartesian`
+    pub coordinate_system: Option<String>,
+    /// The unit of the coordinates
+    pub unit: Option<CRSUnit>,
+    /// The axes in coordinate order. WKT1 axes are often omitted.
+    pub axes: Vec<CRSAxis>,
+    /// The authority identifier
+    pub id: Option<CRSId>,
+}
+impl WKTCRS {
+    /// The EPSG code of the CRS if it has one
+    pub fn epsg(&self) -> Option<u32> {
+        self.id
+            .as_ref()
+            .filter(|id| id.authority.eq_ignore_ascii_case("EPSG"))
+            .and_then(|id| id.code.parse().ok())
+    }
+
+    /// The geodetic datum of the CRS, its base CRS or its horizontal component
+    pub fn geodetic_datum(&self) -> Option<&CRSDatum> {
+        match self.kind {
+            CRSKind::Geographic | CRSKind::Geocentric => self.datum.as_ref(),
+            CRSKind::Projected => self.base_crs.as_ref()?.geodetic_datum(),
+            CRSKind::Compound => self.components.iter().find_map(|crs| crs.geodetic_datum()),
+            CRSKind::Vertical | CRSKind::Engineering => None,
+        }
+    }
+
+    /// The ellipsoid of the geodetic datum
+    pub fn ellipsoid(&self) -> Option<&CRSEllipsoid> {
+        self.geodetic_datum()?.ellipsoid.as_ref()
+    }
+
+    /// Find a projection parameter value. Names are compared ignoring case, spaces and
+    /// underscores so `False_Easting`, `false_easting` and `False easting` all match.
+    pub fn parameter(&self, name: &str) -> Option<f64> {
+        let name = normalize_name(name);
+        match self.kind {
+            CRSKind::Compound => self.components.iter().find_map(|crs| crs.parameter(&name)),
+            _ => self
+                .conversion
+                .as_ref()?
+                .parameters
+                .iter()
+                .find(|parameter| normalize_name(&parameter.name) == name)
+                .map(|parameter| parameter.value),
+        }
+    }
+
+    /// Returns true if the first axis points north or south (e.g. latitude before longitude)
+    pub fn is_northing_first(&self) -> bool {
+        match self.kind {
+            CRSKind::Compound => self.components.first().is_some_and(|crs| crs.is_northing_first()),
+            _ => self
+                .axes
+                .first()
+                .is_some_and(|axis| matches!(axis.direction.as_str(), "north" | "south")),
+        }
+    }
+
+    fn geodetic_datum_mut(&mut self) -> Option<&mut CRSDatum> {
+        match self.kind {
+            CRSKind::Geographic | CRSKind::Geocentric => self.datum.as_mut(),
+            CRSKind::Projected => self.base_crs.as_mut()?.geodetic_datum_mut(),
+            CRSKind::Compound => {
+                self.components.iter_mut().find_map(|crs| crs.geodetic_datum_mut())
+            }
+            CRSKind::Vertical | CRSKind::Engineering => None,
+        }
+    }
+}
+
+/// Parse a WKT1 (OGC or ESRI) or WKT2 coordinate reference system, e.g. the contents of a
+/// shapefile `.prj`
+pub fn parse_wkt_crs(wkt: &str) -> Result<WKTCRS, WKTError> {
+    build_crs(&parse_wkt_object(wkt.trim())?)
+}
+
+/// Build a CRS from its object
+fn build_crs(node: &WKTNode) -> Result<WKTCRS, WKTError> {
+    let coordinate_system =
+        node.child(&["CS"]).and_then(|cs| cs.string(0)).map(|cs| cs.to_ascii_lowercase());
+    let kind = match node.keyword.as_str() {
+        "GEOGCS" | "GEOGCRS" | "GEOGRAPHICCRS" | "BASEGEOGCRS" => CRSKind::Geographic,
+        "GEODCRS" | "GEODETICCRS" | "BASEGEODCRS" => {
+            if coordinate_system.as_deref() == Some("cartesian") {
+                CRSKind::Geocentric
+            } else {
+                CRSKind::Geographic
+            }
+        }
+        "GEOCCS" => CRSKind::Geocentric,
+        "PROJCS" | "PROJCRS" | "PROJECTEDCRS" => CRSKind::Projected,
+        "VERT_CS" | "VERTCS" | "VERTCRS" | "VERTICALCRS" | "BASEVERTCRS" => CRSKind::Vertical,
+        "LOCAL_CS" | "ENGCRS" | "ENGINEERINGCRS" | "BASEENGCRS" => CRSKind::Engineering,
+        "COMPD_CS" | "COMPOUNDCRS" => CRSKind::Compound,
+        "BOUNDCRS" => return build_bound_crs(node),
+        keyword => return Err(WKTError::UnknownCRS(keyword.into())),
+    };
+    let unit_kind = match kind {
+        CRSKind::Geographic => CRSUnitKind::Angular,
+        CRSKind::Geocentric | CRSKind::Projected | CRSKind::Vertical => CRSUnitKind::Linear,
+        CRSKind::Engineering | CRSKind::Compound => CRSUnitKind::Unknown,
+    };
+    let unit = node.child(&UNIT_KEYWORDS).map(|unit| build_unit(unit, unit_kind));
+    let axes: Vec<CRSAxis> =
+        node.children(&["AXIS"]).map(|axis| build_axis(axis, unit_kind)).collect();
+
+    let mut crs = WKTCRS {
+        kind,
+        name: node.name(),
+        datum: node.child(&DATUM_KEYWORDS).map(|datum| build_datum(datum, node, unit.as_ref())),
+        coordinate_system,
+        unit: unit.or_else(|| axes.iter().find_map(|axis| axis.unit.clone())),
+        axes,
+        id: build_id(node),
+        ..Default::default()
+    };
+    if kind == CRSKind::Projected {
+        if let Some(base) = node.child(&BASE_CRS_KEYWORDS) {
+            crs.base_crs = Some(Box::new(build_crs(base)?));
+        }
+        crs.conversion = match node.child(&["CONVERSION"]) {
+            Some(conversion) => Some(build_conversion(conversion, conversion.name())),
+            None => node
+                .child(&["PROJECTION"])
+                .map(|projection| build_conversion(node, projection.name())),
+        };
+    } else if kind == CRSKind::Compound {
+        crs.components = node
+            .values
+            .iter()
+            .filter_map(|value| match value {
+                WKTValue::Node(node) if CRS_KEYWORDS.contains(&node.keyword.as_str()) => {
+                    Some(build_crs(node))
+                }
+                _ => None,
+            })
+            .collect::<Result<_, _>>()?;
+    }
+
+    Ok(crs)
+}
+
+/// Build the source CRS of a `BOUNDCRS`, storing a Helmert transformation as `to_wgs84`
+fn build_bound_crs(node: &WKTNode) -> Result<WKTCRS, WKTError> {
+    let source = node
+        .child(&["SOURCECRS"])
+        .and_then(|source| source.child(&CRS_KEYWORDS))
+        .ok_or_else(|| WKTError::UnknownCRS(node.keyword.clone()))?;
+    let mut crs = build_crs(source)?;
+    if let Some(transformation) = node.child(&["ABRIDGEDTRANSFORMATION"]) {
+        let mut parameters: Vec<f64> = transformation
+            .children(&["PARAMETER"])
+            .filter_map(|parameter| parameter.number(1))
+            .collect();
+        let method = transformation.child(&["METHOD"]).map(|method| method.name());
+        // coordinate frame rotations are the opposite sign of position vector rotations
+        if parameters.len() == 7
+            && method.is_some_and(|method| method.to_ascii_lowercase().contains("coordinate frame"))
+        {
+            parameters[3..6].iter_mut().for_each(|rotation| *rotation = -*rotation);
+        }
+        if matches!(parameters.len(), 3 | 7) {
+            if let Some(datum) = crs.geodetic_datum_mut() {
+                datum.to_wgs84 = Some(parameters);
+            }
+        }
+    }
+
+    Ok(crs)
+}
+
+/// Build a datum. The prime meridian may be a sibling of the datum in the CRS object.
+fn build_datum(node: &WKTNode, crs: &WKTNode, unit: Option<&CRSUnit>) -> CRSDatum {
+    let prime_meridian =
+        node.child(&["PRIMEM", "PRIMEMERIDIAN"]).or(crs.child(&["PRIMEM", "PRIMEMERIDIAN"]));
+    CRSDatum {
+        name: node.name(),
+        ellipsoid: node.child(&["SPHEROID", "ELLIPSOID"]).map(|ellipsoid| CRSEllipsoid {
+            name: ellipsoid.name(),
+            semi_major_axis: ellipsoid.number(1).unwrap_or_default()
+                * ellipsoid.child(&UNIT_KEYWORDS).and_then(|unit| unit.number(1)).unwrap_or(1.),
+            inverse_flattening: ellipsoid.number(2).unwrap_or_default(),
+            id: build_id(ellipsoid),
+        }),
+        prime_meridian: prime_meridian.map(|meridian| {
+            let longitude = meridian.number(1).unwrap_or_default();
+            let factor = match meridian.child(&UNIT_KEYWORDS) {
+                Some(unit) => unit.number(1),
+                None => unit
+                    .filter(|unit| unit.kind == CRSUnitKind::Angular)
+                    .map(|unit| unit.conversion_factor),
+            }
+            .unwrap_or(DEGREE);
+            CRSPrimeMeridian {
+                name: meridian.name(),
+                longitude: if (factor - DEGREE).abs() < 1e-12 {
+                    longitude
+                } else {
+                    longitude * factor / DEGREE
+                },
+                id: build_id(meridian),
+            }
+        }),
+        to_wgs84: node
+            .child(&["TOWGS84"])
+            .map(|towgs84| (0..towgs84.values.len()).filter_map(|i| towgs84.number(i)).collect()),
+        id: build_id(node),
+    }
+}
+
+/// Build a conversion from the `METHOD` or `PROJECTION` and the `PARAMETER`s of the object
+fn build_conversion(node: &WKTNode, name: String) -> CRSConversion {
+    let method = node.child(&["METHOD", "PROJECTION"]);
+    CRSConversion {
+        name,
+        method: CRSMethod {
+            name: method.map(|method| method.name()).unwrap_or_default(),
+            id: method.and_then(build_id),
+        },
+        parameters: node
+            .children(&["PARAMETER"])
+            .map(|parameter| CRSParameter {
+                name: parameter.name(),
+                value: parameter.number(1).unwrap_or_default(),
+                unit: parameter
+                    .child(&UNIT_KEYWORDS)
+                    .map(|unit| build_unit(unit, CRSUnitKind::Unknown)),
+                id: build_id(parameter),
+            })
+            .collect(),
+        id: build_id(node).filter(|_| node.keyword == "CONVERSION"),
+    }
+}
+
+/// Build a unit. A plain WKT1 `UNIT` measures the `default` quantity of its CRS.
+fn build_unit(node: &WKTNode, default: CRSUnitKind) -> CRSUnit {
+    CRSUnit {
+        name: node.name(),
+        kind: match node.keyword.as_str() {
+            "LENGTHUNIT" => CRSUnitKind::Linear,
+            "ANGLEUNIT" => CRSUnitKind::Angular,
+            "SCALEUNIT" => CRSUnitKind::Scale,
+            "TIMEUNIT" => CRSUnitKind::Time,
+            "PARAMETRICUNIT" => CRSUnitKind::Unknown,
+            _ => default,
+        },
+        conversion_factor: node.number(1).unwrap_or(1.),
+        id: build_id(node),
+    }
+}
+
+/// Build an axis, splitting the abbreviation from a WKT2 name like `geodetic latitude (Lat)`
+fn build_axis(node: &WKTNode, unit_kind: CRSUnitKind) -> CRSAxis {
+    let name = node.name();
+    let (name, abbreviation) = match name.strip_suffix(')').and_then(|name| name.rsplit_once('(')) {
+        Some((name, abbreviation)) => {
+            (name.trim().to_string(), Some(abbreviation.trim().to_string()))
+        }
+        None => (name, None),
+    };
+    CRSAxis {
+        name,
+        abbreviation,
+        direction: node.string(1).unwrap_or_default().to_ascii_lowercase(),
+        unit: node.child(&UNIT_KEYWORDS).map(|unit| build_unit(unit, unit_kind)),
+    }
+}
+
+/// Build the `AUTHORITY` or `ID` of an object
+fn build_id(node: &WKTNode) -> Option<CRSId> {
+    let id = node.child(&ID_KEYWORDS)?;
+    let code = match id.values.get(1)? {
+        WKTValue::String(code) | WKTValue::Keyword(code) => code.clone(),
+        WKTValue::Number(code) => code.to_string(),
+        WKTValue::Node(_) => return None,
+    };
+    Some(CRSId { authority: id.name(), code })
+}
+
+/// Lower case a name and remove its spaces and underscores
+fn normalize_name(name: &str) -> String {
+    name.chars().filter(|c| !matches!(c, ' ' | '_')).flat_map(char::to_lowercase).collect()
+}
+
+/// A recursive descent WKT object parser
+#[derive(Debug)]
+struct WKTObjectParser<'a> {
+    wkt: &'a str,
+    pos: usize,
+}
+impl WKTObjectParser<'_> {
+    fn skip(&mut self, skip: impl Fn(u8) -> bool) {
+        while self.wkt.as_bytes().get(self.pos).is_some_and(|b| skip(*b)) {
+            self.pos += 1;
+        }
+    }
+
+    /// The next non-whitespace byte
+    fn peek(&mut self) -> Option<u8> {
+        self.skip(|b| b.is_ascii_whitespace());
+        self.wkt.as_bytes().get(self.pos).copied()
+    }
+
+    /// Check that only whitespace is left
+    fn end(&mut self) -> Result<(), WKTError> {
+        match self.peek() {
+            None => Ok(()),
+            Some(_) => Err(WKTError::UnexpectedToken(self.pos)),
+        }
+    }
+
+    /// Read a keyword or an enumeration value
+    fn word(&mut self) -> Result<String, WKTError> {
+        self.peek().ok_or(WKTError::UnexpectedEnd)?;
+        let start = self.pos;
+        self.skip(|b| b.is_ascii_alphanumeric() || b == b'_');
+        if self.pos == start {
+            return Err(WKTError::UnexpectedToken(start));
+        }
+        Ok(self.wkt[start..self.pos].to_string())
+    }
+
+    /// Read the bracketed values of an object
+    fn node(&mut self, keyword: String) -> Result<WKTNode, WKTError> {
+        match self.peek() {
+            Some(b'[' | b'(') => self.pos += 1,
+            Some(_) => return Err(WKTError::UnexpectedToken(self.pos)),
+            None => return Err(WKTError::UnexpectedEnd),
+        }
+        let mut node = WKTNode { keyword: keyword.to_ascii_uppercase(), values: Vec::new() };
+        if matches!(self.peek(), Some(b']' | b')')) {
+            self.pos += 1;
+            return Ok(node);
+        }
+        loop {
+            node.values.push(self.value()?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b']' | b')') => {
+                    self.pos += 1;
+                    return Ok(node);
+                }
+                Some(_) => return Err(WKTError::UnexpectedToken(self.pos)),
+                None => return Err(WKTError::UnexpectedEnd),
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<WKTValue, WKTError> {
+        match self.peek() {
+            None => Err(WKTError::UnexpectedEnd),
+            Some(b'"') => self.string().map(WKTValue::String),
+            Some(b) if b.is_ascii_digit() || matches!(b, b'-' | b'+' | b'.') => {
+                self.number().map(WKTValue::Number)
+            }
+            Some(_) => {
+                let word = self.word()?;
+                if matches!(self.peek(), Some(b'[' | b'(')) {
+                    self.node(word).map(WKTValue::Node)
+                } else {
+                    Ok(WKTValue::Keyword(word))
+                }
+            }
+        }
+    }
+
+    /// Read a quoted string where `""` escapes a quote
+    fn string(&mut self) -> Result<String, WKTError> {
+        self.pos += 1;
+        let mut value = String::new();
+        loop {
+            let end = self.wkt[self.pos..].find('"').ok_or(WKTError::UnexpectedEnd)? + self.pos;
+            value.push_str(&self.wkt[self.pos..end]);
+            self.pos = end + 1;
+            if self.wkt.as_bytes().get(self.pos) != Some(&b'"') {
+                return Ok(value);
+            }
+            value.push('"');
+            self.pos += 1;
+        }
+    }
+
+    fn number(&mut self) -> Result<f64, WKTError> {
+        let start = self.pos;
+        self.skip(|b| b.is_ascii_alphanumeric() || matches!(b, b'.' | b'-' | b'+'));
+        self.wkt[start..self.pos].parse().map_err(|_| WKTError::InvalidNumber(start))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_parse_object() {
+        let node = parse_wkt_object(r#"AXIS["Lat",NORTH, ORDER(1)]"#).unwrap();
+        assert_eq!(node.keyword, "AXIS");
+        assert_eq!(node.string(0), Some("Lat"));
+        assert_eq!(node.values[1], WKTValue::Keyword("NORTH".into()));
+        assert_eq!(node.child(&["ORDER"]).and_then(|order| order.number(0)), Some(1.));
+
+        let node = parse_wkt_object(r#"REMARK["a ""quoted"" word"]"#).unwrap();
+        assert_eq!(node.string(0), Some(r#"a "quoted" word"#));
+
+        assert_eq!(parse_wkt_object("GEOGCS[\"a\""), Err(WKTError::UnexpectedEnd));
+        assert_eq!(parse_wkt_object("GEOGCS[\"a\"] x"), Err(WKTError::UnexpectedToken(12)));
+        assert_eq!(parse_wkt_object("UNIT[\"a\",1x]"), Err(WKTError::InvalidNumber(9)));
+        assert_eq!(parse_wkt_crs("TIMECRS[\"t\"]"), Err(WKTError::UnknownCRS("TIMECRS".into())));
+    }
+
+    #[test]
+    fn test_wkt1_ogc() {
+        let crs = parse_wkt_crs(r#"PROJCS["NZGD49 / New Zealand Map Grid",GEOGCS["NZGD49",DATUM["New_Zealand_Geodetic_Datum_1949",SPHEROID["International 1924",6378388,297,AUTHORITY["EPSG","7022"]],TOWGS84[59.47,-5.04,187.44,0.47,-0.1,1.024,-4.5993],AUTHORITY["EPSG","6272"]],PRIMEM["Greenwich",0,AUTHORITY["EPSG","8901"]],UNIT["degree",0.01745329251994328,AUTHORITY["EPSG","9122"]],AUTHORITY["EPSG","4272"]],UNIT["metre",1,AUTHORITY["EPSG","9001"]],PROJECTION["New_Zealand_Map_Grid"],PARAMETER["latitude_of_origin",-41],PARAMETER["central_meridian",173],PARAMETER["false_easting",2510000],PARAMETER["false_northing",6023150],AUTHORITY["EPSG","27200"],AXIS["Easting",EAST],AXIS["Northing",NORTH]]"#).unwrap();
+        assert_eq!(crs.kind, CRSKind::Projected);
+        assert_eq!(crs.name, "NZGD49 / New Zealand Map Grid");
+        assert_eq!(crs.epsg(), Some(27200));
+        assert!(!crs.is_northing_first());
+        assert_eq!(
+            crs.unit,
+            Some(CRSUnit {
+                name: "metre".into(),
+                kind: CRSUnitKind::Linear,
+                conversion_factor: 1.,
+                id: Some(CRSId { authority: "EPSG".into(), code: "9001".into() }),
+            })
+        );
+        assert_eq!(
+            crs.axes,
+            vec![
+                CRSAxis { name: "Easting".into(), direction: "east".into(), ..Default::default() },
+                CRSAxis {
+                    name: "Northing".into(),
+                    direction: "north".into(),
+                    ..Default::default()
+                },
+            ]
+        );
+        let conversion = crs.conversion.as_ref().unwrap();
+        assert_eq!(conversion.method.name, "New_Zealand_Map_Grid");
+        assert_eq!(conversion.parameters.len(), 4);
+        assert_eq!(crs.parameter("False Northing"), Some(6023150.));
+
+        let base = crs.base_crs.as_ref().unwrap();
+        assert_eq!(base.kind, CRSKind::Geographic);
+        assert_eq!(base.epsg(), Some(4272));
+        assert_eq!(base.unit.as_ref().unwrap().kind, CRSUnitKind::Angular);
+        assert_eq!(
+            crs.geodetic_datum(),
+            Some(&CRSDatum {
+                name: "New_Zealand_Geodetic_Datum_1949".into(),
+                ellipsoid: Some(CRSEllipsoid {
+                    name: "International 1924".into(),
+                    semi_major_axis: 6378388.,
+                    inverse_flattening: 297.,
+                    id: Some(CRSId { authority: "EPSG".into(), code: "7022".into() }),
+                }),
+                prime_meridian: Some(CRSPrimeMeridian {
+                    name: "Greenwich".into(),
+                    longitude: 0.,
+                    id: Some(CRSId { authority: "EPSG".into(), code: "8901".into() }),
+                }),
+                to_wgs84: Some(vec![59.47, -5.04, 187.44, 0.47, -0.1, 1.024, -4.5993]),
+                id: Some(CRSId { authority: "EPSG".into(), code: "6272".into() }),
+            })
+        );
+
+        let crs = parse_wkt_crs(r#"COMPD_CS["OSGB36 / British National Grid + ODN",PROJCS["OSGB 1936 / British National Grid",GEOGCS["OSGB 1936",DATUM["OSGB_1936",SPHEROID["Airy 1830",6377563.396,299.3249646]],PRIMEM["Greenwich",0],UNIT["degree",0.0174532925199433]],PROJECTION["Transverse_Mercator"],PARAMETER["scale_factor",0.9996012717],UNIT["metre",1]],VERT_CS["Newlyn",VERT_DATUM["Ordnance Datum Newlyn",2005],UNIT["metre",1]]]"#).unwrap();
+        assert_eq!(crs.kind, CRSKind::Compound);
+        assert_eq!(crs.components.len(), 2);
+        assert_eq!(crs.components[1].kind, CRSKind::Vertical);
+        assert_eq!(crs.components[1].datum.as_ref().unwrap().name, "Ordnance Datum Newlyn");
+        assert_eq!(crs.parameter("scale_factor"), Some(0.9996012717));
+        assert_eq!(crs.ellipsoid().unwrap().name, "Airy 1830");
+    }
+
+    #[test]
+    fn test_wkt1_esri() {
+        let prj = std::fs::read_to_string(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/tests/readers/shapefile/fixtures/senate.prj"
+        ))
+        .unwrap();
+        let crs = parse_wkt_crs(&prj).unwrap();
+        assert_eq!(crs.kind, CRSKind::Projected);
+        assert_eq!(crs.name, "NAD_1983_StatePlane_Massachusetts_Mainland_FIPS_2001");
+        assert_eq!(crs.epsg(), None);
+        assert_eq!(crs.conversion.as_ref().unwrap().method.name, "Lambert_Conformal_Conic");
+        assert_eq!(crs.parameter("standard parallel 1"), Some(41.71666666666667));
+        assert_eq!(crs.parameter("latitude_of_origin"), Some(41.));
+        assert_eq!(crs.parameter("scale_factor"), None);
+        assert_eq!(crs.unit.as_ref().unwrap().name, "Meter");
+        let datum = crs.geodetic_datum().unwrap();
+        assert_eq!(datum.name, "D_North_American_1983");
+        assert_eq!(datum.ellipsoid.as_ref().unwrap().inverse_flattening, 298.257222101);
+
+        let crs = parse_wkt_crs(r#"GEOGCS["GCS_Paris",DATUM["D_Paris",SPHEROID["Clarke_1880_IGN",6378249.2,293.46602]],PRIMEM["Paris",2.5969213],UNIT["Grad",0.01570796326794897]]"#).unwrap();
+        let meridian = crs.datum.unwrap().prime_meridian.unwrap();
+        assert!((meridian.longitude - 2.33722917).abs() < 1e-8);
+    }
+
+    #[test]
+    fn test_wkt2() {
+        let crs = parse_wkt_crs(r#"PROJCRS["WGS 84 / UTM zone 31N",
+    BASEGEOGCRS["WGS 84",
+        ENSEMBLE["World Geodetic System 1984 ensemble",
+            MEMBER["World Geodetic System 1984 (G2139)"],
+            ELLIPSOID["WGS 84",6378137,298.257223563,LENGTHUNIT["metre",1]],
+            ENSEMBLEACCURACY[2.0]],
+        PRIMEM["Greenwich",0,ANGLEUNIT["degree",0.0174532925199433]],
+        ID["EPSG",4326]],
+    CONVERSION["UTM zone 31N",
+        METHOD["Transverse Mercator",ID["EPSG",9807]],
+        PARAMETER["Latitude of natural origin",0,ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8801]],
+        PARAMETER["Longitude of natural origin",3,ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",8802]],
+        PARAMETER["Scale factor at natural origin",0.9996,SCALEUNIT["unity",1],ID["EPSG",8805]],
+        PARAMETER["False easting",500000,LENGTHUNIT["metre",1],ID["EPSG",8806]],
+        PARAMETER["False northing",0,LENGTHUNIT["metre",1],ID["EPSG",8807]]],
+    CS[Cartesian,2],
+        AXIS["(E)",east,ORDER[1],LENGTHUNIT["metre",1]],
+        AXIS["(N)",north,ORDER[2],LENGTHUNIT["metre",1]],
+    USAGE[SCOPE["Navigation and medium accuracy spatial referencing."],AREA["Between 0°E and 6°E, northern hemisphere."],BBOX[0,0,84,6]],
+    ID["EPSG",32631]]"#).unwrap();
+        assert_eq!(crs.kind, CRSKind::Projected);
+        assert_eq!(crs.epsg(), Some(32631));
+        assert_eq!(crs.coordinate_system.as_deref(), Some("cartesian"));
+        assert_eq!(crs.axes[1].abbreviation.as_deref(), Some("N"));
+        assert_eq!(crs.unit.as_ref().unwrap().kind, CRSUnitKind::Linear);
+        let conversion = crs.conversion.as_ref().unwrap();
+        assert_eq!(conversion.name, "UTM zone 31N");
+        assert_eq!(
+            conversion.method.id,
+            Some(CRSId { authority: "EPSG".into(), code: "9807".into() })
+        );
+        assert_eq!(conversion.parameters[2].unit.as_ref().unwrap().kind, CRSUnitKind::Scale);
+        assert_eq!(crs.parameter("false_easting"), Some(500000.));
+        assert_eq!(crs.base_crs.as_ref().unwrap().epsg(), Some(4326));
+        assert_eq!(crs.geodetic_datum().unwrap().name, "World Geodetic System 1984 ensemble");
+        assert_eq!(crs.ellipsoid().unwrap().semi_major_axis, 6378137.);
+
+        let crs = parse_wkt_crs(r#"GEOGCRS["WGS 84",DATUM["World Geodetic System 1984",ELLIPSOID["WGS 84",6378137,298.257223563]],CS[ellipsoidal,2],AXIS["geodetic latitude (Lat)",north],AXIS["geodetic longitude (Lon)",east],ANGLEUNIT["degree",0.0174532925199433],ID["EPSG",4326]]"#).unwrap();
+        assert_eq!(crs.kind, CRSKind::Geographic);
+        assert!(crs.is_northing_first());
+        assert_eq!(crs.axes[0].name, "geodetic latitude");
+        assert_eq!(crs.unit.as_ref().unwrap().kind, CRSUnitKind::Angular);
+
+        let crs = parse_wkt_crs(r#"BOUNDCRS[SOURCECRS[GEOGCRS["NZGD49",DATUM["New Zealand Geodetic Datum 1949",ELLIPSOID["International 1924",6378388,297]],CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],ANGLEUNIT["degree",0.0174532925199433]]],TARGETCRS[GEOGCRS["WGS 84",DATUM["World Geodetic System 1984",ELLIPSOID["WGS 84",6378137,298.257223563]],CS[ellipsoidal,2],AXIS["latitude",north],AXIS["longitude",east],ANGLEUNIT["degree",0.0174532925199433]]],ABRIDGEDTRANSFORMATION["NZGD49 to WGS 84",METHOD["Coordinate Frame rotation (geog2D domain)"],PARAMETER["X-axis translation",59.47],PARAMETER["Y-axis translation",-5.04],PARAMETER["Z-axis translation",187.44],PARAMETER["X-axis rotation",-0.47],PARAMETER["Y-axis rotation",0.1],PARAMETER["Z-axis rotation",-1.024],PARAMETER["Scale difference",-4.5993]]]"#).unwrap();
+        assert_eq!(crs.name, "NZGD49");
+        assert_eq!(
+            crs.datum.unwrap().to_wgs84,
+            Some(vec![59.47, -5.04, 187.44, 0.47, -0.1, 1.024, -4.5993])
+        );
+
+        let crs = parse_wkt_crs(r#"GEODCRS["WGS 84",DATUM["World Geodetic System 1984",ELLIPSOID["WGS 84",6378137,298.257223563]],CS[Cartesian,3],AXIS["(X)",geocentricX],AXIS["(Y)",geocentricY],AXIS["(Z)",geocentricZ],LENGTHUNIT["metre",1]]"#).unwrap();
+        assert_eq!(crs.kind, CRSKind::Geocentric);
+        assert_eq!(crs.axes[2].direction, "geocentricz");
+    }
+}
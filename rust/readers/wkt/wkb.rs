@@ -0,0 +1,486 @@
+use super::{
+    geometry_points, line_string_geometry, m_value, measure, multi_line_string_geometry,
+    multi_point_geometry, multi_polygon_geometry, point_geometry, polygon_geometry, WKTShape,
+};
+use crate::{
+    geometry::{VectorFeature, VectorGeometry, VectorPoint},
+    readers::{FeatureIterator, Reader},
+    writers::{Writer, WriterError},
+};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+
+/// The EWKB flag of geometries with Z coordinates
+const EWKB_Z: u32 = 0x8000_0000;
+/// The EWKB flag of geometries with M coordinates
+const EWKB_M: u32 = 0x4000_0000;
+/// The EWKB flag of geometries with a SRID
+const EWKB_SRID: u32 = 0x2000_0000;
+
+/// Errors that can occur while reading WKB
+#[derive(Debug, PartialEq)]
+pub enum WKBError {
+    /// The data ended in the middle of a geometry
+    UnexpectedEnd,
+    /// The byte order at the byte offset isn't 0 (big-endian) or 1 (little-endian)
+    InvalidByteOrder(usize),
+    /// The geometry type isn't supported (e.g. 8 for a CircularString)
+    UnknownGeometry(u32),
+    /// A member of a multi geometry isn't of the member type
+    InvalidMember(u32),
+    /// A GeometryCollection can't be a single geometry, use the [`WKBGeometryReader`]
+    Collection,
+    /// The hex string has an odd length or non-hex characters
+    InvalidHex,
+}
+
+/// Decode hex encoded WKB, as found in PostGIS dumps
+pub fn decode_wkb_hex(hex: &str) -> Result<Vec<u8>, WKBError> {
+    let hex = hex.trim().trim_start_matches("\\x");
+    if hex.len() % 2 != 0 {
+        return Err(WKBError::InvalidHex);
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect::<Option<_>>()
+        .ok_or(WKBError::InvalidHex)
+}
+
+/// Encode WKB as upper case hex, as written by PostGIS
+pub fn encode_wkb_hex(wkb: &[u8]) -> String {
+    const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+    wkb.iter()
+        .flat_map(|b| [DIGITS[(b >> 4) as usize] as char, DIGITS[(b & 15) as usize] as char])
+        .collect()
+}
+
+/// # WKB Geometry Parser
+///
+/// ## Description
+/// Read a single WKB or EWKB geometry at the reader's position, advancing past it. Both byte
+/// orders, the ISO (e.g. 1001 for a Point Z) and EWKB (PostGIS flags) type codes and the EWKB
+/// SRID are supported, see [`WKBGeometryReader::srid`]. Measures are stored in the point's
+/// M-value under [`super::WKT_M_KEY`]. Triangles are read as Polygons, TINs and polyhedral
+/// surfaces as MultiPolygons.
+///
+/// An empty Point (NaN coordinates) returns `None`, other empty geometries have no coordinates.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{decode_wkb_hex, parse_wkb_geometry, BufferReader};
+///
+/// let wkb = decode_wkb_hex("0101000000000000000000F03F0000000000000040").unwrap();
+/// let geometry = parse_wkb_geometry(&mut BufferReader::from(&wkb[..])).unwrap().unwrap();
+/// ```
+///
+/// ## Links
+/// - <https://libgeos.org/specifications/wkb/>
+/// - <https://www.ogc.org/standard/sfa/>
+pub fn parse_wkb_geometry<T: Reader>(reader: &mut T) -> Result<Option<VectorGeometry>, WKBError> {
+    let shape = WKBParser { reader, little: true, srid: None }.geometry(None)?;
+    shape.single().map_err(|_| WKBError::Collection)
+}
+
+/// # WKB Geometry Reader
+///
+/// ## Description
+/// Read the consecutive WKB or EWKB geometries of a reader into features. Geometry collections
+/// are flattened and empty geometries are skipped. See [`parse_wkb_geometry`] for the supported
+/// geometries.
+///
+/// Implements the [`FeatureIterator`]. Reading stops at the first invalid geometry.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{decode_wkb_hex, BufferReader, FeatureIterator, WKBGeometryReader};
+///
+/// let ewkb = decode_wkb_hex("0101000020E6100000000000000000F03F0000000000000040").unwrap();
+/// let mut reader = WKBGeometryReader::new(BufferReader::from(&ewkb[..]));
+/// let feature = reader.next_feature().unwrap();
+/// assert_eq!(reader.srid, Some(4326));
+/// ```
+///
+/// ## Links
+/// - <https://libgeos.org/specifications/wkb/>
+#[derive(Debug)]
+pub struct WKBGeometryReader<T: Reader> {
+    reader: T,
+    /// The SRID of the last EWKB geometry read with one
+    pub srid: Option<u32>,
+    geometries: Vec<VectorGeometry>,
+}
+impl<T: Reader> WKBGeometryReader<T> {
+    /// Create a new WKB geometry reader
+    pub fn new(reader: T) -> Self {
+        WKBGeometryReader { reader, srid: None, geometries: vec![] }
+    }
+}
+
+impl<T: Reader> Iterator for WKBGeometryReader<T> {
+    type Item = VectorFeature;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.geometries.is_empty() {
+            if self.reader.tell() >= self.reader.len() {
+                return None;
+            }
+            let mut parser = WKBParser { reader: &mut self.reader, little: true, srid: None };
+            let shape = parser.geometry(None).ok()?;
+            self.srid = parser.srid.or(self.srid);
+            shape.flatten(&mut self.geometries);
+            self.geometries.reverse();
+        }
+        let geometry = self.geometries.pop()?;
+        Some(VectorFeature::new_wm(None, Default::default(), geometry, None))
+    }
+}
+impl<T: Reader> FeatureIterator for WKBGeometryReader<T> {
+    fn next_feature(&mut self) -> Option<VectorFeature> {
+        self.next()
+    }
+}
+
+/// Reads WKB through a reader
+#[derive(Debug)]
+struct WKBParser<'a, T: Reader> {
+    reader: &'a mut T,
+    little: bool,
+    srid: Option<u32>,
+}
+impl<T: Reader> WKBParser<'_, T> {
+    /// Check that the next bytes exist
+    fn check(&mut self, size: usize) -> Result<(), WKBError> {
+        if self.reader.tell() + size <= self.reader.len() {
+            Ok(())
+        } else {
+            Err(WKBError::UnexpectedEnd)
+        }
+    }
+
+    fn u32(&mut self) -> Result<u32, WKBError> {
+        self.check(4)?;
+        Ok(if self.little { self.reader.uint32_le(None) } else { self.reader.uint32_be(None) })
+    }
+
+    fn f64(&mut self) -> Result<f64, WKBError> {
+        self.check(8)?;
+        Ok(if self.little { self.reader.f64_le(None) } else { self.reader.f64_be(None) })
+    }
+
+    /// Read a geometry. Members of a multi geometry must be of the member type
+    fn geometry(&mut self, member: Option<u32>) -> Result<WKTShape, WKBError> {
+        self.check(1)?;
+        let offset = self.reader.tell();
+        self.little = match self.reader.uint8(None) {
+            0 => false,
+            1 => true,
+            _ => return Err(WKBError::InvalidByteOrder(offset)),
+        };
+        let code = self.u32()?;
+        if code & EWKB_SRID != 0 {
+            self.srid = Some(self.u32()?);
+        }
+        let iso = code & 0x0FFF_FFFF;
+        let (kind, iso_z, iso_m) = (iso % 1000, matches!(iso / 1000, 1 | 3), iso / 1000 >= 2);
+        let (has_z, has_m) = (code & EWKB_Z != 0 || iso_z, code & EWKB_M != 0 || iso_m);
+        if member.is_some_and(|member| member != kind) {
+            return Err(WKBError::InvalidMember(kind));
+        }
+        let geometry = match kind {
+            1 => match self.point(has_z, has_m)? {
+                Some(point) => point_geometry(point),
+                None => return Ok(WKTShape::Empty),
+            },
+            2 => line_string_geometry(self.line(has_z, has_m)?),
+            3 | 17 => polygon_geometry(self.polygon(has_z, has_m)?),
+            4 => multi_point_geometry(
+                self.members(1)?
+                    .into_iter()
+                    .filter_map(|g| match g.single().ok()?? {
+                        VectorGeometry::Point(point) => Some(point.coordinates),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            5 => multi_line_string_geometry(
+                self.members(2)?
+                    .into_iter()
+                    .filter_map(|g| match g.single().ok()?? {
+                        VectorGeometry::LineString(line) => Some(line.coordinates),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            6 | 15 | 16 => multi_polygon_geometry(
+                self.members(if kind == 16 { 17 } else { 3 })?
+                    .into_iter()
+                    .filter_map(|g| match g.single().ok()?? {
+                        VectorGeometry::Polygon(polygon) => Some(polygon.coordinates),
+                        _ => None,
+                    })
+                    .collect(),
+            ),
+            7 => {
+                let count = self.u32()?;
+                let shapes = (0..count).map(|_| self.geometry(None)).collect::<Result<_, _>>()?;
+                return Ok(WKTShape::Collection(shapes));
+            }
+            _ => return Err(WKBError::UnknownGeometry(kind)),
+        };
+        Ok(WKTShape::Geometry(Box::new(geometry)))
+    }
+
+    /// Read the members of a multi geometry
+    fn members(&mut self, member: u32) -> Result<Vec<WKTShape>, WKBError> {
+        let count = self.u32()?;
+        (0..count).map(|_| self.geometry(Some(member))).collect()
+    }
+
+    /// Read a point, `None` if all its ordinates are NaN
+    fn point(&mut self, has_z: bool, has_m: bool) -> Result<Option<VectorPoint>, WKBError> {
+        let (x, y) = (self.f64()?, self.f64()?);
+        let z = if has_z { Some(self.f64()?) } else { None };
+        let m = if has_m { Some(self.f64()?) } else { None };
+        if x.is_nan() && y.is_nan() {
+            return Ok(None);
+        }
+        Ok(Some(VectorPoint::new(x, y, z, m.map(m_value))))
+    }
+
+    fn line(&mut self, has_z: bool, has_m: bool) -> Result<Vec<VectorPoint>, WKBError> {
+        let count = self.u32()?;
+        // a point takes at least 16 bytes
+        self.check(count as usize * 16)?;
+        let mut line = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            line.extend(self.point(has_z, has_m)?);
+        }
+        Ok(line)
+    }
+
+    fn polygon(&mut self, has_z: bool, has_m: bool) -> Result<Vec<Vec<VectorPoint>>, WKBError> {
+        let count = self.u32()?;
+        self.check(count as usize * 4)?;
+        (0..count).map(|_| self.line(has_z, has_m)).collect()
+    }
+}
+
+/// # WKB Geometry Writer
+///
+/// ## Description
+/// Append a [`VectorGeometry`] to the writer as little-endian WKB using the ISO type codes, or
+/// as EWKB (the PostGIS flags) if a SRID is given. 3D geometries are written with Z, and the
+/// measures stored under [`super::WKT_M_KEY`] in the first point's M-value are written with M.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{encode_wkb_hex, parse_wkt_geometry, write_wkb_geometry};
+/// use gistools::writers::BufferWriter;
+///
+/// let geometry = parse_wkt_geometry("POINT (1 2)").unwrap().unwrap();
+/// let mut writer = BufferWriter::new();
+/// write_wkb_geometry(&mut writer, &geometry, Some(4326)).unwrap();
+/// let hex = encode_wkb_hex(&writer.take());
+/// assert_eq!(hex, "0101000020E6100000000000000000F03F0000000000000040");
+/// ```
+pub fn write_wkb_geometry<W: Writer>(
+    writer: &mut W,
+    geometry: &VectorGeometry,
+    srid: Option<u32>,
+) -> Result<(), WriterError> {
+    let is_3d = match geometry {
+        VectorGeometry::Point(g) => g.is_3d,
+        VectorGeometry::MultiPoint(g) => g.is_3d,
+        VectorGeometry::LineString(g) => g.is_3d,
+        VectorGeometry::MultiLineString(g) => g.is_3d,
+        VectorGeometry::Polygon(g) => g.is_3d,
+        VectorGeometry::MultiPolygon(g) => g.is_3d,
+    };
+    let has_m = geometry_points(geometry).next().is_some_and(|p| measure(p).is_some());
+    let mut wkb = WKBWriter { data: vec![], is_3d, has_m, srid };
+    match geometry {
+        VectorGeometry::Point(g) => {
+            wkb.header(1);
+            wkb.point(&g.coordinates);
+        }
+        VectorGeometry::MultiPoint(g) => {
+            wkb.header(4);
+            wkb.u32(g.coordinates.len());
+            for point in &g.coordinates {
+                wkb.header(1);
+                wkb.point(point);
+            }
+        }
+        VectorGeometry::LineString(g) => {
+            wkb.header(2);
+            wkb.line(&g.coordinates);
+        }
+        VectorGeometry::MultiLineString(g) => {
+            wkb.header(5);
+            wkb.u32(g.coordinates.len());
+            for line in &g.coordinates {
+                wkb.header(2);
+                wkb.line(line);
+            }
+        }
+        VectorGeometry::Polygon(g) => {
+            wkb.header(3);
+            wkb.polygon(&g.coordinates);
+        }
+        VectorGeometry::MultiPolygon(g) => {
+            wkb.header(6);
+            wkb.u32(g.coordinates.len());
+            for polygon in &g.coordinates {
+                wkb.header(3);
+                wkb.polygon(polygon);
+            }
+        }
+    }
+    writer.append(&wkb.data)
+}
+
+/// Encodes a geometry as WKB
+struct WKBWriter {
+    data: Vec<u8>,
+    is_3d: bool,
+    has_m: bool,
+    /// Written with the first header only
+    srid: Option<u32>,
+}
+impl WKBWriter {
+    fn u32(&mut self, value: usize) {
+        self.data.extend((value as u32).to_le_bytes());
+    }
+
+    fn header(&mut self, kind: u32) {
+        self.data.push(1);
+        let code = match self.srid {
+            Some(_) => {
+                let z = if self.is_3d { EWKB_Z } else { 0 };
+                let m = if self.has_m { EWKB_M } else { 0 };
+                let srid = if self.data.len() == 1 { EWKB_SRID } else { 0 };
+                kind | z | m | srid
+            }
+            None => kind + 1000 * (self.is_3d as u32 + 2 * self.has_m as u32),
+        };
+        self.data.extend(code.to_le_bytes());
+        if let Some(srid) = self.srid.filter(|_| self.data.len() == 5) {
+            self.data.extend(srid.to_le_bytes());
+        }
+    }
+
+    fn point(&mut self, point: &VectorPoint) {
+        self.data.extend(point.x.to_le_bytes());
+        self.data.extend(point.y.to_le_bytes());
+        if self.is_3d {
+            self.data.extend(point.z.unwrap_or(0.).to_le_bytes());
+        }
+        if self.has_m {
+            self.data.extend(measure(point).unwrap_or(0.).to_le_bytes());
+        }
+    }
+
+    fn line(&mut self, line: &[VectorPoint]) {
+        self.u32(line.len());
+        line.iter().for_each(|point| self.point(point));
+    }
+
+    fn polygon(&mut self, polygon: &[Vec<VectorPoint>]) {
+        self.u32(polygon.len());
+        polygon.iter().for_each(|ring| self.line(ring));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        readers::{parse_wkt_geometry, to_wkt, BufferReader, OwnedBufferReader},
+        writers::BufferWriter,
+    };
+
+    fn wkb_to_wkt(hex: &str) -> String {
+        let wkb = decode_wkb_hex(hex).unwrap();
+        let geometry = parse_wkb_geometry(&mut BufferReader::from(&wkb[..])).unwrap().unwrap();
+        to_wkt(&geometry, None)
+    }
+
+    #[test]
+    fn test_parse_wkb() {
+        // little-endian ISO point
+        assert_eq!(wkb_to_wkt("0101000000000000000000F03F0000000000000040"), "POINT (1 2)");
+        // big-endian ISO point Z
+        assert_eq!(
+            wkb_to_wkt("00000003E93FF000000000000040000000000000004008000000000000"),
+            "POINT Z (1 2 3)"
+        );
+        // EWKB line string Z with a SRID
+        let ewkb = "01020000A0E610000002000000000000000000F03F00000000000000400000000000000840\
+                    000000000000104000000000000014400000000000001840";
+        assert_eq!(wkb_to_wkt(ewkb), "LINESTRING Z (1 2 3, 4 5 6)");
+        // empty point
+        let wkb = decode_wkb_hex("0101000000000000000000F87F000000000000F87F").unwrap();
+        assert_eq!(parse_wkb_geometry(&mut BufferReader::from(&wkb[..])), Ok(None));
+
+        let wkb = decode_wkb_hex("01010000000000").unwrap();
+        assert_eq!(
+            parse_wkb_geometry(&mut BufferReader::from(&wkb[..])),
+            Err(WKBError::UnexpectedEnd)
+        );
+        let wkb = decode_wkb_hex("0208000000").unwrap();
+        assert_eq!(
+            parse_wkb_geometry(&mut BufferReader::from(&wkb[..])),
+            Err(WKBError::InvalidByteOrder(0))
+        );
+        let wkb = decode_wkb_hex("010800000000000000").unwrap();
+        assert_eq!(
+            parse_wkb_geometry(&mut BufferReader::from(&wkb[..])),
+            Err(WKBError::UnknownGeometry(8))
+        );
+        assert_eq!(decode_wkb_hex("0x"), Err(WKBError::InvalidHex));
+        assert_eq!(decode_wkb_hex("\\x0101"), Ok(vec![1, 1]));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let wkts = [
+            "POINT (1.5 -2)",
+            "POINT ZM (1 2 3 4)",
+            "MULTIPOINT Z (10 40 1, 40 30 2)",
+            "LINESTRING M (30 10 1, 10 30 2, 40 40 3)",
+            "MULTILINESTRING ((10 10, 20 20), (40 40, 30 30))",
+            "POLYGON ((35 10, 45 45, 15 40, 35 10), (20 30, 35 35, 30 20, 20 30))",
+            "MULTIPOLYGON (((40 40, 20 45, 45 30, 40 40)), ((20 35, 10 30, 10 10, 20 35)))",
+            "MULTIPOLYGON EMPTY",
+        ];
+        for srid in [None, Some(4326)] {
+            let mut writer = BufferWriter::new();
+            for wkt in wkts {
+                let geometry = parse_wkt_geometry(wkt).unwrap().unwrap();
+                write_wkb_geometry(&mut writer, &geometry, srid).unwrap();
+            }
+            let mut reader = WKBGeometryReader::new(OwnedBufferReader::from(writer.take()));
+            // the empty multi polygon is kept, it isn't a collection
+            let read: Vec<String> = reader.by_ref().map(|f| to_wkt(&f.geometry, None)).collect();
+            assert_eq!(read, wkts);
+            assert_eq!(reader.srid, srid);
+        }
+    }
+
+    #[test]
+    fn test_collection() {
+        // GEOMETRYCOLLECTION (POINT (1 2), POINT EMPTY, LINESTRING (3 4, 5 6))
+        let hex = "010700000003000000\
+                   0101000000000000000000F03F0000000000000040\
+                   0101000000000000000000F87F000000000000F87F\
+                   0102000000020000000000000000000840000000000000104000000000000014400000000000001840";
+        let wkb = decode_wkb_hex(hex).unwrap();
+        assert_eq!(
+            parse_wkb_geometry(&mut BufferReader::from(&wkb[..])),
+            Err(WKBError::Collection)
+        );
+        let reader = WKBGeometryReader::new(BufferReader::from(&wkb[..]));
+        let read: Vec<String> = reader.map(|f| to_wkt(&f.geometry, None)).collect();
+        assert_eq!(read, vec!["POINT (1 2)", "LINESTRING (3 4, 5 6)"]);
+    }
+}
@@ -0,0 +1,902 @@
+/// Owned XML element trees
+pub mod tree;
+
+pub use tree::*;
+
+use crate::readers::Reader;
+use alloc::{borrow::Cow, string::String, vec, vec::Vec};
+
+/// The number of bytes read from the underlying reader at a time
+const CHUNK_SIZE: usize = 64 * 1024;
+/// The namespace bound to the `xml` prefix
+pub const XML_NAMESPACE: &str = "http://www.w3.org/XML/1998/namespace";
+/// The namespace bound to the `xmlns` prefix
+pub const XMLNS_NAMESPACE: &str = "http://www.w3.org/2000/xmlns/";
+
+/// Errors that can occur while parsing an XML document. Each carries the byte offset of the
+/// offending token
+#[derive(Debug, Clone, PartialEq)]
+pub enum XMLError {
+    /// The document ended inside a token or with unclosed elements
+    UnexpectedEof(usize),
+    /// A token isn't valid UTF-8
+    InvalidUtf8(usize),
+    /// A start or end tag is malformed
+    InvalidTag(usize),
+    /// An attribute is malformed or not quoted
+    InvalidAttribute(usize),
+    /// An end tag doesn't match the open element
+    MismatchedEndTag(usize),
+    /// Content was found outside of the root element
+    ContentOutsideRoot(usize),
+}
+
+/// A namespace binding declared by an `xmlns` or `xmlns:prefix` attribute
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct XMLNamespace {
+    /// The prefix, empty for the default namespace
+    pub prefix: String,
+    /// The namespace URI, empty to undeclare the default namespace
+    pub uri: String,
+    /// The depth of the element that declared the binding
+    depth: usize,
+}
+
+/// Split a qualified name into its prefix and local name
+pub fn xml_split_name(name: &str) -> (Option<&str>, &str) {
+    match name.split_once(':') {
+        Some((prefix, local)) => (Some(prefix), local),
+        None => (None, name),
+    }
+}
+
+/// Resolve a prefix (`None` for the default namespace) against the bindings in scope
+fn resolve<'a>(namespaces: &'a [XMLNamespace], prefix: Option<&str>) -> Option<&'a str> {
+    let prefix = prefix.unwrap_or_default();
+    match prefix {
+        "xml" => return Some(XML_NAMESPACE),
+        "xmlns" => return Some(XMLNS_NAMESPACE),
+        _ => {}
+    }
+    let binding = namespaces.iter().rev().find(|n| n.prefix == prefix)?;
+    (!binding.uri.is_empty()).then_some(binding.uri.as_str())
+}
+
+/// Replace the predefined (`&lt;` `&gt;` `&amp;` `&quot;` `&apos;`) and numeric character
+/// references. Unknown entities are kept as is. Only allocates if the text contains a reference
+pub fn xml_unescape(text: &str) -> Cow<'_, str> {
+    if !text.contains('&') {
+        return Cow::Borrowed(text);
+    }
+    let mut res = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        res.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let decoded = rest.find(';').and_then(|end| {
+            let c = match &rest[1..end] {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                entity => {
+                    let code = match entity.strip_prefix("#x") {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                        None => entity.strip_prefix('#')?.parse().ok()?,
+                    };
+                    char::from_u32(code)?
+                }
+            };
+            Some((c, end))
+        });
+        match decoded {
+            Some((c, end)) => {
+                res.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                res.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    res.push_str(rest);
+    Cow::Owned(res)
+}
+
+/// An attribute of a start tag. The value is stored raw, see [`XMLAttribute::value`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XMLAttribute<'a> {
+    /// The qualified name
+    pub name: &'a str,
+    /// The value without its quotes and before entities are replaced
+    pub raw_value: &'a str,
+}
+impl<'a> XMLAttribute<'a> {
+    /// The prefix of the name if any
+    pub fn prefix(&self) -> Option<&'a str> {
+        xml_split_name(self.name).0
+    }
+
+    /// The name without its prefix
+    pub fn local_name(&self) -> &'a str {
+        xml_split_name(self.name).1
+    }
+
+    /// The value with its entities replaced
+    pub fn value(&self) -> Cow<'a, str> {
+        xml_unescape(self.raw_value)
+    }
+}
+
+/// Iterates over the attributes of a start tag
+#[derive(Debug, Clone)]
+pub struct XMLAttributes<'a> {
+    rest: &'a str,
+}
+impl<'a> Iterator for XMLAttributes<'a> {
+    type Item = XMLAttribute<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // the attributes were validated when the start tag was read
+        let (attribute, rest) = parse_attribute(self.rest).ok()??;
+        self.rest = rest;
+        Some(attribute)
+    }
+}
+
+/// Parse the next attribute of a start tag, returning it and the remaining text
+fn parse_attribute(text: &str) -> Result<Option<(XMLAttribute<'_>, &str)>, ()> {
+    let text = text.trim_start();
+    if text.is_empty() {
+        return Ok(None);
+    }
+    let eq = text.find('=').ok_or(())?;
+    let name = text[..eq].trim_end();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(());
+    }
+    let value = text[eq + 1..].trim_start();
+    let quote = value.chars().next().ok_or(())?;
+    if quote != '"' && quote != '\'' {
+        return Err(());
+    }
+    let end = value[1..].find(quote).ok_or(())? + 1;
+    let rest = &value[end + 1..];
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return Err(());
+    }
+    Ok(Some((XMLAttribute { name, raw_value: &value[1..end] }, rest)))
+}
+
+/// A start tag, or an empty element tag (`<name/>`) which is followed by its [`XMLEvent::End`]
+#[derive(Debug, Clone, Copy)]
+pub struct XMLStartTag<'a> {
+    name: &'a str,
+    attributes: &'a str,
+    namespaces: &'a [XMLNamespace],
+    empty: bool,
+}
+impl<'a> XMLStartTag<'a> {
+    /// The qualified name
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// The prefix of the name if any
+    pub fn prefix(&self) -> Option<&'a str> {
+        xml_split_name(self.name).0
+    }
+
+    /// The name without its prefix
+    pub fn local_name(&self) -> &'a str {
+        xml_split_name(self.name).1
+    }
+
+    /// The namespace URI of the element if its prefix or the default namespace is bound
+    pub fn namespace(&self) -> Option<&'a str> {
+        resolve(self.namespaces, self.prefix())
+    }
+
+    /// Resolve a prefix (`None` for the default namespace) against the bindings in scope
+    pub fn resolve(&self, prefix: Option<&str>) -> Option<&'a str> {
+        resolve(self.namespaces, prefix)
+    }
+
+    /// True if the tag is an empty element tag
+    pub fn is_empty(&self) -> bool {
+        self.empty
+    }
+
+    /// Iterate over the attributes, including namespace declarations
+    pub fn attributes(&self) -> XMLAttributes<'a> {
+        XMLAttributes { rest: self.attributes }
+    }
+
+    /// Get the value of the attribute with the qualified name
+    pub fn attribute(&self, name: &str) -> Option<Cow<'a, str>> {
+        self.attributes().find(|a| a.name == name).map(|a| a.value())
+    }
+
+    /// Convert to an owned node without children
+    pub fn to_node(&self) -> XMLNode {
+        XMLNode {
+            name: self.name.into(),
+            namespace: self.namespace().map(String::from),
+            attributes: self.attributes().map(|a| (a.name.into(), a.value().into())).collect(),
+            children: vec![],
+            text: String::new(),
+        }
+    }
+}
+
+/// An end tag
+#[derive(Debug, Clone, Copy)]
+pub struct XMLEndTag<'a> {
+    name: &'a str,
+    namespace: Option<&'a str>,
+}
+impl<'a> XMLEndTag<'a> {
+    /// The qualified name
+    pub fn name(&self) -> &'a str {
+        self.name
+    }
+
+    /// The name without its prefix
+    pub fn local_name(&self) -> &'a str {
+        xml_split_name(self.name).1
+    }
+
+    /// The namespace URI of the element if bound
+    pub fn namespace(&self) -> Option<&'a str> {
+        self.namespace
+    }
+}
+
+/// Character data between tags. The text is stored raw, see [`XMLText::unescape`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XMLText<'a>(pub &'a str);
+impl<'a> XMLText<'a> {
+    /// The text with its entities replaced
+    pub fn unescape(&self) -> Cow<'a, str> {
+        xml_unescape(self.0)
+    }
+
+    /// True if the text is only whitespace
+    pub fn is_whitespace(&self) -> bool {
+        self.0.trim().is_empty()
+    }
+}
+
+/// An event of the XML pull parser. Borrows from the parser until the next event is read
+#[derive(Debug, Clone, Copy)]
+pub enum XMLEvent<'a> {
+    /// The XML declaration or a processing instruction, without `<?` and `?>`
+    ProcessingInstruction(&'a str),
+    /// A document type declaration, without `<!DOCTYPE` and `>`
+    DocType(&'a str),
+    /// A comment, without `<!--` and `-->`
+    Comment(&'a str),
+    /// A start tag
+    Start(XMLStartTag<'a>),
+    /// An end tag
+    End(XMLEndTag<'a>),
+    /// Character data
+    Text(XMLText<'a>),
+    /// A CDATA section, without `<![CDATA[` and `]]>`
+    CData(&'a str),
+    /// The end of the document
+    Eof,
+}
+
+/// The byte ranges of a token in the buffer
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Token {
+    ProcessingInstruction(usize, usize),
+    DocType(usize, usize),
+    Comment(usize, usize),
+    /// The name and attribute ranges and whether the tag is empty
+    Start(usize, usize, usize, bool),
+    End(usize, usize),
+    Text(usize, usize),
+    CData(usize, usize),
+    Eof,
+}
+
+/// # XML Reader
+///
+/// ## Description
+/// A streaming XML pull parser reading from any [`Reader`]. The document is read in chunks and
+/// each call to [`XMLReader::next_event`] returns the next [`XMLEvent`], borrowing its names and
+/// text from the internal buffer so that no allocation is made unless text contains entities.
+///
+/// Tags are checked to be balanced, namespace bindings are tracked per element so that element
+/// names (and attribute prefixes) can be resolved to their namespace URI, CDATA sections are
+/// returned verbatim and [`XMLText::unescape`] replaces the predefined and numeric entities.
+/// Empty element tags (`<name/>`) produce a [`XMLEvent::Start`] followed by an [`XMLEvent::End`].
+///
+/// Uninteresting subtrees can be skipped with [`XMLReader::skip_element`], which only scans for
+/// tag boundaries. Small subtrees can be read as an owned [`XMLNode`] with
+/// [`XMLReader::read_node`] or [`XMLReader::read_children`].
+///
+/// NOTE: Entities declared in a DTD are not expanded and the document is assumed to be UTF-8.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, XMLEvent, XMLReader};
+///
+/// let xml = br#"<gpx xmlns="http://www.topografix.com/GPX/1/1"><name>A &amp; B</name></gpx>"#;
+/// let mut reader = XMLReader::new(BufferReader::from(&xml[..]));
+///
+/// let XMLEvent::Start(root) = reader.next_event().unwrap() else { panic!() };
+/// assert_eq!(root.namespace(), Some("http://www.topografix.com/GPX/1/1"));
+/// let name = reader.read_node().unwrap().unwrap();
+/// assert_eq!(name.text, "A & B");
+/// ```
+///
+/// ## Links
+/// - <https://www.w3.org/TR/xml/>
+/// - <https://www.w3.org/TR/xml-names/>
+#[derive(Debug)]
+pub struct XMLReader<T: Reader> {
+    reader: T,
+    chunk_size: usize,
+    /// The offset of the next chunk in the reader
+    read_offset: usize,
+    /// The unconsumed bytes start at `pos`; `buffer[0]` is at `buffer_offset` in the reader
+    buffer: Vec<u8>,
+    buffer_offset: usize,
+    pos: usize,
+    /// The names of the open elements, concatenated, and where each one ends
+    names: String,
+    name_ends: Vec<usize>,
+    namespaces: Vec<XMLNamespace>,
+    /// The last event was an end tag whose element is popped on the next call
+    close_pending: bool,
+    /// The last event was an empty element tag whose end is returned on the next call
+    end_pending: Option<(usize, usize)>,
+    seen_root: bool,
+}
+impl<T: Reader> XMLReader<T> {
+    /// Create a new XML pull parser
+    pub fn new(reader: T) -> Self {
+        XMLReader {
+            reader,
+            chunk_size: CHUNK_SIZE,
+            read_offset: 0,
+            buffer: vec![],
+            buffer_offset: 0,
+            pos: 0,
+            names: String::new(),
+            name_ends: vec![],
+            namespaces: vec![],
+            close_pending: false,
+            end_pending: None,
+            seen_root: false,
+        }
+    }
+
+    /// The number of open elements. For start and end tags this includes the element itself
+    pub fn depth(&self) -> usize {
+        self.name_ends.len()
+    }
+
+    /// The byte offset of the parser in the document
+    pub fn offset(&self) -> usize {
+        self.buffer_offset + self.pos
+    }
+
+    /// Read the next event
+    pub fn next_event(&mut self) -> Result<XMLEvent<'_>, XMLError> {
+        self.close();
+        if let Some((begin, end)) = self.end_pending.take() {
+            self.close_pending = true;
+            let name = self.str(begin, end)?;
+            let namespace = resolve(&self.namespaces, xml_split_name(name).0);
+            return Ok(XMLEvent::End(XMLEndTag { name, namespace }));
+        }
+        let offset = self.offset();
+        let token = self.next_token()?;
+        match token {
+            Token::Start(name_begin, name_end, attributes_end, empty) => {
+                if self.depth() == 0 && self.seen_root {
+                    return Err(XMLError::ContentOutsideRoot(offset));
+                }
+                self.seen_root = true;
+                self.open(name_begin, name_end, attributes_end, offset)?;
+                if empty {
+                    self.end_pending = Some((name_begin, name_end));
+                }
+            }
+            Token::End(begin, end) => {
+                let name = self.str(begin, end)?;
+                let open = match self.name_ends.len() {
+                    0 => return Err(XMLError::MismatchedEndTag(offset)),
+                    n => &self.names[if n > 1 { self.name_ends[n - 2] } else { 0 }..],
+                };
+                if name != open {
+                    return Err(XMLError::MismatchedEndTag(offset));
+                }
+                self.close_pending = true;
+            }
+            Token::Text(begin, end) if self.depth() == 0 => {
+                if !self.buffer[begin..end].iter().all(u8::is_ascii_whitespace) {
+                    return Err(XMLError::ContentOutsideRoot(offset));
+                }
+            }
+            Token::CData(..) if self.depth() == 0 => {
+                return Err(XMLError::ContentOutsideRoot(offset));
+            }
+            Token::Eof if self.depth() != 0 => return Err(XMLError::UnexpectedEof(offset)),
+            _ => {}
+        }
+        self.event(token, offset)
+    }
+
+    /// Skip the content of the element whose start tag was just read, up to and including its end
+    /// tag. The skipped content is only scanned for tag boundaries
+    pub fn skip_element(&mut self) -> Result<(), XMLError> {
+        if self.end_pending.is_some() {
+            self.next_event()?;
+            return Ok(());
+        }
+        let mut level = 1;
+        loop {
+            let offset = self.offset();
+            match self.next_token()? {
+                Token::Start(.., false) => level += 1,
+                Token::End(begin, end) => {
+                    level -= 1;
+                    if level == 0 {
+                        let start = self.name_ends.len().checked_sub(2);
+                        let open = &self.names[start.map(|i| self.name_ends[i]).unwrap_or(0)..];
+                        if &self.buffer[begin..end] != open.as_bytes() {
+                            return Err(XMLError::MismatchedEndTag(offset));
+                        }
+                        self.close_pending = true;
+                        return Ok(());
+                    }
+                }
+                Token::Eof => return Err(XMLError::UnexpectedEof(offset)),
+                _ => {}
+            }
+        }
+    }
+
+    /// Read the text and CDATA of the element whose start tag was just read, up to and
+    /// including its end tag. The text of child elements is skipped
+    pub fn read_text(&mut self) -> Result<String, XMLError> {
+        let mut text = String::new();
+        loop {
+            match self.next_event()? {
+                XMLEvent::Text(t) => text.push_str(&t.unescape()),
+                XMLEvent::CData(t) => text.push_str(t),
+                XMLEvent::Start(_) => self.skip_element()?,
+                XMLEvent::End(_) => return Ok(text),
+                _ => {}
+            }
+        }
+    }
+
+    /// Read the content of the element whose start tag was just read into the node, up to and
+    /// including its end tag
+    pub fn read_children(&mut self, node: &mut XMLNode) -> Result<(), XMLError> {
+        loop {
+            match self.next_event()? {
+                XMLEvent::Text(t) => node.text.push_str(&t.unescape()),
+                XMLEvent::CData(t) => node.text.push_str(t),
+                XMLEvent::Start(start) => {
+                    let mut child = start.to_node();
+                    self.read_children(&mut child)?;
+                    node.children.push(child);
+                }
+                XMLEvent::End(_) => return Ok(()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Read the next element at the current depth as a node. Returns `None` (consuming the end
+    /// tag) when the parent element ends, or at the end of the document
+    pub fn read_node(&mut self) -> Result<Option<XMLNode>, XMLError> {
+        loop {
+            match self.next_event()? {
+                XMLEvent::Start(start) => {
+                    let mut node = start.to_node();
+                    self.read_children(&mut node)?;
+                    return Ok(Some(node));
+                }
+                XMLEvent::End(_) | XMLEvent::Eof => return Ok(None),
+                _ => {}
+            }
+        }
+    }
+
+    /// Pop the element closed by the last event
+    fn close(&mut self) {
+        if !self.close_pending {
+            return;
+        }
+        self.close_pending = false;
+        let depth = self.depth();
+        self.name_ends.pop();
+        self.names.truncate(self.name_ends.last().copied().unwrap_or(0));
+        while self.namespaces.last().is_some_and(|n| n.depth >= depth) {
+            self.namespaces.pop();
+        }
+    }
+
+    /// Push an element, validating its attributes and binding its namespace declarations
+    fn open(
+        &mut self,
+        name_begin: usize,
+        name_end: usize,
+        attributes_end: usize,
+        offset: usize,
+    ) -> Result<(), XMLError> {
+        let invalid_utf8 = |_| XMLError::InvalidUtf8(offset);
+        let name =
+            core::str::from_utf8(&self.buffer[name_begin..name_end]).map_err(invalid_utf8)?;
+        let mut attributes =
+            core::str::from_utf8(&self.buffer[name_end..attributes_end]).map_err(invalid_utf8)?;
+        let depth = self.name_ends.len() + 1;
+        while let Some((attribute, rest)) =
+            parse_attribute(attributes).map_err(|_| XMLError::InvalidAttribute(offset))?
+        {
+            if attribute.name == "xmlns" || attribute.prefix() == Some("xmlns") {
+                let prefix = if attribute.name == "xmlns" { "" } else { attribute.local_name() };
+                self.namespaces.push(XMLNamespace {
+                    prefix: prefix.into(),
+                    uri: attribute.value().into(),
+                    depth,
+                });
+            }
+            attributes = rest;
+        }
+        self.names.push_str(name);
+        self.name_ends.push(self.names.len());
+        Ok(())
+    }
+
+    /// Build the event of a token
+    fn event(&self, token: Token, offset: usize) -> Result<XMLEvent<'_>, XMLError> {
+        Ok(match token {
+            Token::ProcessingInstruction(b, e) => XMLEvent::ProcessingInstruction(self.str(b, e)?),
+            Token::DocType(b, e) => XMLEvent::DocType(self.str(b, e)?),
+            Token::Comment(b, e) => XMLEvent::Comment(self.str(b, e)?),
+            Token::Start(name_begin, name_end, attributes_end, empty) => {
+                XMLEvent::Start(XMLStartTag {
+                    name: self.str(name_begin, name_end)?,
+                    attributes: self.str(name_end, attributes_end)?,
+                    namespaces: &self.namespaces,
+                    empty,
+                })
+            }
+            Token::End(b, e) => {
+                let name = self.str(b, e)?;
+                let namespace = resolve(&self.namespaces, xml_split_name(name).0);
+                XMLEvent::End(XMLEndTag { name, namespace })
+            }
+            Token::Text(b, e) => XMLEvent::Text(XMLText(self.str(b, e)?)),
+            Token::CData(b, e) => XMLEvent::CData(self.str(b, e)?),
+            Token::Eof => {
+                if !self.seen_root {
+                    return Err(XMLError::UnexpectedEof(offset));
+                }
+                XMLEvent::Eof
+            }
+        })
+    }
+
+    /// Get a range of the buffer as a string
+    fn str(&self, begin: usize, end: usize) -> Result<&str, XMLError> {
+        core::str::from_utf8(&self.buffer[begin..end])
+            .map_err(|_| XMLError::InvalidUtf8(self.buffer_offset + begin))
+    }
+
+    /// Read the next chunk into the buffer. Returns false at the end of the reader
+    fn fill(&mut self) -> bool {
+        let len = self.reader.len();
+        if self.read_offset >= len {
+            return false;
+        }
+        let end = (self.read_offset + self.chunk_size).min(len);
+        let chunk = self.reader.slice(Some(self.read_offset), Some(end));
+        if self.read_offset == 0 && chunk.starts_with(&[0xef, 0xbb, 0xbf]) {
+            // skip the byte order mark
+            self.buffer.extend_from_slice(&chunk[3..]);
+            self.buffer_offset = 3;
+        } else {
+            self.buffer.extend_from_slice(&chunk);
+        }
+        self.read_offset = end;
+        true
+    }
+
+    /// Find a sequence in the buffer starting at `from`, reading more chunks as needed
+    fn find(&mut self, from: usize, sequence: &[u8]) -> Option<usize> {
+        let mut searched = from;
+        loop {
+            if let Some(i) =
+                self.buffer[searched..].windows(sequence.len()).position(|w| w == sequence)
+            {
+                return Some(searched + i);
+            }
+            searched = searched.max(self.buffer.len().saturating_sub(sequence.len() - 1));
+            if !self.fill() {
+                return None;
+            }
+        }
+    }
+
+    /// Find the `>` closing a tag starting at `from`, ignoring any inside quotes or, for a
+    /// document type declaration, inside its internal subset
+    fn find_tag_end(&mut self, from: usize) -> Option<usize> {
+        let (mut quote, mut brackets, mut i) = (None, 0, from);
+        loop {
+            while i < self.buffer.len() {
+                let c = self.buffer[i];
+                match (quote, c) {
+                    (None, b'"' | b'\'') => quote = Some(c),
+                    (Some(q), _) if q == c => quote = None,
+                    (None, b'[') => brackets += 1,
+                    (None, b']') => brackets -= 1,
+                    (None, b'>') if brackets <= 0 => return Some(i),
+                    _ => {}
+                }
+                i += 1;
+            }
+            if !self.fill() {
+                return None;
+            }
+        }
+    }
+
+    /// Ensure `n` bytes are available after `pos` if the document is long enough
+    fn ensure(&mut self, n: usize) {
+        while self.buffer.len() < self.pos + n && self.fill() {}
+    }
+
+    /// Read the byte ranges of the next token and consume it
+    fn next_token(&mut self) -> Result<Token, XMLError> {
+        // drop the consumed bytes so the buffer stays around a chunk in size
+        if self.pos >= self.chunk_size {
+            self.buffer.drain(..self.pos);
+            self.buffer_offset += self.pos;
+            self.pos = 0;
+        }
+        let offset = self.offset();
+        let start = self.pos;
+        self.ensure(1);
+        if start >= self.buffer.len() {
+            return Ok(Token::Eof);
+        }
+        if self.buffer[start] != b'<' {
+            let end = self.find(start, b"<").unwrap_or(self.buffer.len());
+            self.pos = end;
+            return Ok(Token::Text(start, end));
+        }
+        self.ensure(9);
+        let rest = &self.buffer[start..];
+        let eof = XMLError::UnexpectedEof(offset);
+        let token = if rest.starts_with(b"<!--") {
+            let end = self.find(start + 4, b"-->").ok_or(eof)?;
+            self.pos = end + 3;
+            Token::Comment(start + 4, end)
+        } else if rest.starts_with(b"<![CDATA[") {
+            let end = self.find(start + 9, b"]]>").ok_or(eof)?;
+            self.pos = end + 3;
+            Token::CData(start + 9, end)
+        } else if rest.starts_with(b"<?") {
+            let end = self.find(start + 2, b"?>").ok_or(eof)?;
+            self.pos = end + 2;
+            Token::ProcessingInstruction(start + 2, end)
+        } else if rest.starts_with(b"<!DOCTYPE") {
+            let end = self.find_tag_end(start + 9).ok_or(eof)?;
+            self.pos = end + 1;
+            Token::DocType(start + 9, end)
+        } else if rest.starts_with(b"</") {
+            let end = self.find(start + 2, b">").ok_or(eof)?;
+            self.pos = end + 1;
+            let name = trim_ascii_range(&self.buffer, start + 2, end);
+            if name.0 == name.1 {
+                return Err(XMLError::InvalidTag(offset));
+            }
+            Token::End(name.0, name.1)
+        } else {
+            let end = self.find_tag_end(start + 1).ok_or(eof)?;
+            self.pos = end + 1;
+            let empty = self.buffer[end - 1] == b'/';
+            let attributes_end = if empty { end - 1 } else { end };
+            let name_end = (start + 1..attributes_end)
+                .find(|&i| self.buffer[i].is_ascii_whitespace())
+                .unwrap_or(attributes_end);
+            let name = &self.buffer[start + 1..name_end];
+            if name.is_empty() || name.starts_with(b"!") {
+                return Err(XMLError::InvalidTag(offset));
+            }
+            Token::Start(start + 1, name_end, attributes_end, empty)
+        };
+        Ok(token)
+    }
+}
+
+/// Trim the ASCII whitespace of a byte range
+fn trim_ascii_range(buffer: &[u8], mut begin: usize, mut end: usize) -> (usize, usize) {
+    while begin < end && buffer[begin].is_ascii_whitespace() {
+        begin += 1;
+    }
+    while end > begin && buffer[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+    (begin, end)
+}
+
+impl XMLNode {
+    /// Parse a whole document into its root element
+    pub fn parse<T: Reader>(reader: T) -> Result<XMLNode, XMLError> {
+        let mut xml = XMLReader::new(reader);
+        let root = xml.read_node()?.ok_or(XMLError::UnexpectedEof(0))?;
+        // validate that nothing but comments and whitespace follow the root
+        while !matches!(xml.next_event()?, XMLEvent::Eof) {}
+        Ok(root)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::BufferReader;
+    use alloc::string::ToString;
+
+    fn events(xml: &str, chunk_size: usize) -> Result<Vec<String>, XMLError> {
+        let mut reader = XMLReader::new(BufferReader::from(xml.as_bytes()));
+        reader.chunk_size = chunk_size;
+        let mut res = vec![];
+        loop {
+            let event = match reader.next_event()? {
+                XMLEvent::ProcessingInstruction(pi) => alloc::format!("?{pi}"),
+                XMLEvent::DocType(d) => alloc::format!("!{}", d.trim()),
+                XMLEvent::Comment(c) => alloc::format!("#{c}"),
+                XMLEvent::Start(s) => {
+                    let attributes: Vec<String> = s
+                        .attributes()
+                        .map(|a| alloc::format!(" {}={}", a.name, a.value()))
+                        .collect();
+                    alloc::format!("<{}{}>", s.name(), attributes.concat())
+                }
+                XMLEvent::End(e) => alloc::format!("</{}>", e.name()),
+                XMLEvent::Text(t) if t.is_whitespace() => continue,
+                XMLEvent::Text(t) => alloc::format!("'{}'", t.unescape()),
+                XMLEvent::CData(c) => alloc::format!("[{c}]"),
+                XMLEvent::Eof => break,
+            };
+            res.push(event);
+        }
+        Ok(res)
+    }
+
+    const DOCUMENT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<!DOCTYPE kml [<!ENTITY copy "&#169;">]>
+<!-- a <comment> -->
+<kml xmlns="http://www.opengis.net/kml/2.2" xmlns:gx='http://www.google.com/kml/ext/2.2'>
+  <name a="1 &gt; 0" b='"quoted"'>Tom &amp; Jerry &#x263A; &#9731; &nbsp;</name>
+  <description><![CDATA[<b>bold</b> & raw]]></description>
+  <gx:Track/>
+  <empty></empty>
+</kml>
+"#;
+
+    #[test]
+    fn test_events() {
+        let expected = vec![
+            "?xml version=\"1.0\" encoding=\"UTF-8\"",
+            "!kml [<!ENTITY copy \"&#169;\">]",
+            "# a <comment> ",
+            "<kml xmlns=http://www.opengis.net/kml/2.2 xmlns:gx=http://www.google.com/kml/ext/2.2>",
+            "<name a=1 > 0 b=\"quoted\">",
+            "'Tom & Jerry ☺ ☃ &nbsp;'",
+            "</name>",
+            "<description>",
+            "[<b>bold</b> & raw]",
+            "</description>",
+            "<gx:Track>",
+            "</gx:Track>",
+            "<empty>",
+            "</empty>",
+            "</kml>",
+        ];
+        assert_eq!(events(DOCUMENT, CHUNK_SIZE).unwrap(), expected);
+        // every token split across chunks
+        for chunk_size in 1..8 {
+            assert_eq!(events(DOCUMENT, chunk_size).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let xml =
+            r#"<a xmlns="urn:default" xmlns:p="urn:p"><p:b><c xmlns="" p:x="1"/></p:b><d/></a>"#;
+        let mut reader = XMLReader::new(BufferReader::from(xml.as_bytes()));
+        let mut resolved = vec![];
+        loop {
+            match reader.next_event().unwrap() {
+                XMLEvent::Start(s) => {
+                    let attribute = s.attributes().find(|a| a.prefix() == Some("p"));
+                    if let Some(a) = attribute {
+                        assert_eq!(s.resolve(a.prefix()), Some("urn:p"));
+                        assert_eq!(a.local_name(), "x");
+                    }
+                    resolved.push((s.local_name().to_string(), s.namespace().map(String::from)));
+                }
+                XMLEvent::End(e) => resolved
+                    .push((alloc::format!("/{}", e.local_name()), e.namespace().map(String::from))),
+                XMLEvent::Eof => break,
+                _ => {}
+            }
+        }
+        let ns = |s: &str| Some(String::from(s));
+        assert_eq!(
+            resolved,
+            vec![
+                ("a".into(), ns("urn:default")),
+                ("b".into(), ns("urn:p")),
+                ("c".into(), None),
+                ("/c".into(), None),
+                ("/b".into(), ns("urn:p")),
+                ("d".into(), ns("urn:default")),
+                ("/d".into(), ns("urn:default")),
+                ("/a".into(), ns("urn:default")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skip_and_read() {
+        let xml = "<root><skip><a><b/></a>text<c>x</c></skip><keep \
+                   id='1'>v<i>ignored</i>al</keep><node k='v'><x>1</x><y/>tail</node></root>";
+        let mut reader = XMLReader::new(BufferReader::from(xml.as_bytes()));
+        reader.chunk_size = 3;
+        assert!(matches!(reader.next_event().unwrap(), XMLEvent::Start(_)));
+        let XMLEvent::Start(skip) = reader.next_event().unwrap() else { panic!() };
+        assert_eq!(skip.name(), "skip");
+        reader.skip_element().unwrap();
+        assert_eq!(reader.depth(), 2);
+        let XMLEvent::Start(keep) = reader.next_event().unwrap() else { panic!() };
+        assert_eq!(keep.attribute("id").as_deref(), Some("1"));
+        assert_eq!(reader.read_text().unwrap(), "val");
+        let node = reader.read_node().unwrap().unwrap();
+        assert_eq!(node.name, "node");
+        assert_eq!(node.attribute("k"), Some("v"));
+        assert_eq!(node.child_text("x"), Some("1"));
+        assert!(node.child("y").is_some());
+        assert_eq!(node.text, "tail");
+        assert_eq!(reader.read_node().unwrap(), None);
+        assert!(matches!(reader.next_event().unwrap(), XMLEvent::Eof));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(events("<a><b></a>", 64), Err(XMLError::MismatchedEndTag(6)));
+        assert_eq!(events("<a><b>", 64), Err(XMLError::UnexpectedEof(6)));
+        assert_eq!(events("<a x=1/>", 64), Err(XMLError::InvalidAttribute(0)));
+        assert_eq!(events("<a/><b/>", 64), Err(XMLError::ContentOutsideRoot(4)));
+        assert_eq!(events("<a><!-- open", 64), Err(XMLError::UnexpectedEof(3)));
+        assert_eq!(events("", 64), Err(XMLError::UnexpectedEof(0)));
+        let mut reader = XMLReader::new(BufferReader::from(&b"<a>\xff</a>"[..]));
+        reader.next_event().unwrap();
+        assert_eq!(reader.next_event().err(), Some(XMLError::InvalidUtf8(3)));
+    }
+
+    #[test]
+    fn test_parse_node() {
+        let node = XMLNode::parse(BufferReader::from(DOCUMENT.as_bytes())).unwrap();
+        assert_eq!(node.local_name(), "kml");
+        assert_eq!(node.namespace.as_deref(), Some("http://www.opengis.net/kml/2.2"));
+        assert_eq!(node.child_text("description"), Some("<b>bold</b> & raw"));
+        let track = node.child("Track").unwrap();
+        assert_eq!(track.prefix(), Some("gx"));
+        assert_eq!(track.namespace.as_deref(), Some("http://www.google.com/kml/ext/2.2"));
+    }
+}
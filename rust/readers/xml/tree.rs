@@ -0,0 +1,48 @@
+use super::xml_split_name;
+use alloc::{string::String, vec::Vec};
+
+/// An owned XML element with its attributes, child elements and text content
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct XMLNode {
+    /// The qualified name
+    pub name: String,
+    /// The namespace URI of the element if bound
+    pub namespace: Option<String>,
+    /// The attributes in document order with their entities replaced
+    pub attributes: Vec<(String, String)>,
+    /// The child elements in document order
+    pub children: Vec<XMLNode>,
+    /// The text and CDATA of the element, excluding the text of its children
+    pub text: String,
+}
+impl XMLNode {
+    /// The prefix of the name if any
+    pub fn prefix(&self) -> Option<&str> {
+        xml_split_name(&self.name).0
+    }
+
+    /// The name without its prefix
+    pub fn local_name(&self) -> &str {
+        xml_split_name(&self.name).1
+    }
+
+    /// Get the value of the attribute with the qualified name
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    /// Get the first child element with the local name
+    pub fn child(&self, local_name: &str) -> Option<&XMLNode> {
+        self.children.iter().find(|c| c.local_name() == local_name)
+    }
+
+    /// Iterate over the child elements with the local name
+    pub fn children<'a>(&'a self, local_name: &'a str) -> impl Iterator<Item = &'a XMLNode> {
+        self.children.iter().filter(move |c| c.local_name() == local_name)
+    }
+
+    /// The trimmed text of the first child element with the local name
+    pub fn child_text(&self, local_name: &str) -> Option<&str> {
+        self.child(local_name).map(|c| c.text.trim())
+    }
+}
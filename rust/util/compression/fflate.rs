@@ -19,7 +19,7 @@ use alloc::vec::Vec;
 use core::result::Result;
 
 /// Handles compression errors
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum FFlateError {
     /// Unexpected EOF
     UnexpectedEof,
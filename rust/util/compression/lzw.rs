@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+
+/// Resets the dictionary
+const LZW_CLEAR_CODE: usize = 256;
+/// End of information
+const LZW_EOI_CODE: usize = 257;
+/// The first code that isn't a literal or a control code
+const LZW_FIRST_CODE: usize = 258;
+/// Codes start at 9 bits
+const LZW_MIN_BITS: usize = 9;
+/// and grow to at most 12 bits
+const LZW_MAX_BITS: usize = 12;
+/// The maximum number of dictionary entries
+const LZW_TABLE_SIZE: usize = 1 << LZW_MAX_BITS;
+
+/// Decompress LZW data as it's stored in TIFF files (MSB-first codes with "early change").
+///
+/// Decoding stops at the end-of-information code or at the end of the input, whichever comes
+/// first. Invalid codes end the decoding and the data decoded so far is returned.
+pub fn decompress_lzw(input: &[u8]) -> Vec<u8> {
+    let mut prefix = [0_u16; LZW_TABLE_SIZE];
+    let mut suffix = [0_u8; LZW_TABLE_SIZE];
+    let mut first = [0_u8; LZW_TABLE_SIZE];
+    let mut length = [0_u16; LZW_TABLE_SIZE];
+    for i in 0..256 {
+        suffix[i] = i as u8;
+        first[i] = i as u8;
+        length[i] = 1;
+    }
+
+    let mut output = Vec::with_capacity(input.len() * 2);
+    let mut next = LZW_FIRST_CODE;
+    let mut bits = LZW_MIN_BITS;
+    let mut position = 0;
+    let mut old: Option<usize> = None;
+    let total_bits = input.len() * 8;
+
+    while position + bits <= total_bits {
+        let code = read_code(input, position, bits);
+        position += bits;
+        if code == LZW_EOI_CODE {
+            break;
+        }
+        if code == LZW_CLEAR_CODE {
+            next = LZW_FIRST_CODE;
+            bits = LZW_MIN_BITS;
+            old = None;
+            continue;
+        }
+        match old {
+            None => {
+                if code >= LZW_FIRST_CODE {
+                    break;
+                }
+                output.push(code as u8);
+            }
+            Some(old) => {
+                if code > next || (code == next && next >= LZW_TABLE_SIZE) {
+                    break;
+                }
+                // the KwKwK case adds the entry before it's emitted
+                let c = if code < next { first[code] } else { first[old] };
+                if next < LZW_TABLE_SIZE {
+                    prefix[next] = old as u16;
+                    suffix[next] = c;
+                    first[next] = first[old];
+                    length[next] = length[old] + 1;
+                    next += 1;
+                }
+                // write the entry backwards by walking the prefixes
+                let start = output.len();
+                output.resize(start + length[code] as usize, 0);
+                let mut entry = code;
+                for i in (start..output.len()).rev() {
+                    output[i] = suffix[entry];
+                    entry = prefix[entry] as usize;
+                }
+            }
+        }
+        old = Some(code);
+        if next + 1 >= 1 << bits && bits < LZW_MAX_BITS {
+            bits += 1;
+        }
+    }
+
+    output
+}
+
+/// Read `bits` bits starting at the bit `position` (MSB first)
+fn read_code(input: &[u8], position: usize, bits: usize) -> usize {
+    let mut code = 0;
+    for bit in position..position + bits {
+        let byte = input[bit >> 3] as usize;
+        code = (code << 1) | ((byte >> (7 - (bit & 7))) & 1);
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    /// Pack codes MSB first using the same width changes as the decoder
+    fn encode_codes(codes: &[(usize, usize)]) -> Vec<u8> {
+        let mut out = vec![];
+        let mut acc: u64 = 0;
+        let mut acc_bits = 0;
+        for (code, bits) in codes {
+            acc = (acc << bits) | *code as u64;
+            acc_bits += bits;
+            while acc_bits >= 8 {
+                acc_bits -= 8;
+                out.push((acc >> acc_bits) as u8);
+            }
+        }
+        if acc_bits > 0 {
+            out.push((acc << (8 - acc_bits)) as u8);
+        }
+        out
+    }
+
+    #[test]
+    fn test_decompress_lzw() {
+        // "ABABABA" -> clear, A, B, AB(258), ABA(260, KwKwK), eoi
+        let data = encode_codes(&[(256, 9), (65, 9), (66, 9), (258, 9), (260, 9), (257, 9)]);
+        assert_eq!(decompress_lzw(&data), b"ABABABA".to_vec());
+    }
+
+    #[test]
+    fn test_decompress_lzw_truncated() {
+        // missing the end of information code
+        let data = encode_codes(&[(256, 9), (72, 9), (105, 9)]);
+        assert_eq!(decompress_lzw(&data), b"Hi".to_vec());
+    }
+}
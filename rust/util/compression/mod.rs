@@ -1,7 +1,10 @@
 /// Flate decompression (gzip, inflate, inflate-raw)
 pub mod fflate;
+/// LZW decompression (TIFF variant)
+pub mod lzw;
 
 pub use fflate::*;
+pub use lzw::*;
 
 #[cfg(feature = "std")]
 use flate2::{
@@ -22,10 +25,8 @@ use alloc::vec::Vec;
 
 use core::result::Result;
 
-use crate::readers::{BufferReader, Reader};
-
 /// Handles compression errors
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum CompressError {
     /// Brotli not implemented
     UnimplementedBrotli,
@@ -184,51 +185,42 @@ pub struct ZipItem<'a> {
 pub fn iter_items(raw: &[u8]) -> Result<Vec<ZipItem<'_>>, CompressError> {
     let mut at: usize = find_end_central_directory(raw)?;
     let mut items = Vec::new();
-    let mut reader: BufferReader = raw.into();
 
     // Read end central directory
-    let file_count = reader.uint16_le(Some(10 + at));
-    if file_count != reader.uint16_le(Some(8 + at)) {
+    let file_count = zip_u16(raw, at, 10)?;
+    if file_count != zip_u16(raw, at, 8)? {
         return Err(CompressError::ZipMultiDiskNotSupported);
     }
-    let central_directory_start = reader.uint32_le(Some(16 + at));
+    let central_directory_start = zip_u32(raw, at, 16)?;
     at = central_directory_start as usize;
 
     // Read central directory
     for _ in 0..file_count {
-        let compression_method = reader.uint16_le(Some(10 + at));
-        let filename_length = reader.uint16_le(Some(28 + at));
-        let extra_fields_length = reader.uint16_le(Some(30 + at));
-        let comment_length = reader.uint16_le(Some(32 + at));
-        let compressed_size = reader.uint32_le(Some(20 + at));
+        let compression_method = zip_u16(raw, at, 10)?;
+        let filename_length = zip_u16(raw, at, 28)? as usize;
+        let extra_fields_length = zip_u16(raw, at, 30)? as usize;
+        let comment_length = zip_u16(raw, at, 32)? as usize;
+        let compressed_size = zip_u32(raw, at, 20)?;
 
         // Find local entry location
-        let local_entry_at = reader.uint32_le(Some(42 + at));
+        let local_entry_at = zip_u32(raw, at, 42)?;
 
         // Read buffers, move at to after entry, and store where we were
         let filename =
-            String::from_utf8_lossy(&raw[at + 46..at + 46 + filename_length as usize]).to_string();
-        let comment = String::from_utf8_lossy(
-            &raw[at + 46 + filename_length as usize + extra_fields_length as usize
-                ..at + 46
-                    + filename_length as usize
-                    + extra_fields_length as usize
-                    + comment_length as usize],
-        )
-        .to_string();
-
-        let next_central_directory_entry = at;
+            String::from_utf8_lossy(zip_bytes(raw, at, 46, filename_length)?).to_string();
+        let comment_at = 46 + filename_length + extra_fields_length;
+        let comment =
+            String::from_utf8_lossy(zip_bytes(raw, at, comment_at, comment_length)?).to_string();
+
+        // the comment is in bounds so this can't overflow
+        let next_central_directory_entry = at + comment_at + comment_length;
 
         // >> Start reading entry
         at = local_entry_at as usize;
 
         // This is the local entry (filename + extra fields) length, which we skip
-        let bytes_start = at
-            + 30
-            + reader.uint16_le(Some(26 + at)) as usize
-            + reader.uint16_le(Some(28 + at)) as usize;
-        let bytes_end = at + compressed_size as usize + bytes_start;
-        let bytes = &raw[bytes_start..bytes_end];
+        let bytes_at = 30 + zip_u16(raw, at, 26)? as usize + zip_u16(raw, at, 28)? as usize;
+        let bytes = zip_bytes(raw, at, bytes_at, compressed_size as usize)?;
 
         let read_fn = Box::new(move || {
             if compression_method & 8 > 0 {
@@ -248,8 +240,23 @@ pub fn iter_items(raw: &[u8]) -> Result<Vec<ZipItem<'_>>, CompressError> {
     Ok(items)
 }
 
+/// Get the `length` bytes at `offset` from a record starting at `at`
+fn zip_bytes(raw: &[u8], at: usize, offset: usize, length: usize) -> Result<&[u8], CompressError> {
+    let start = at.checked_add(offset).ok_or(CompressError::BadZipFormat)?;
+    let end = start.checked_add(length).ok_or(CompressError::BadZipFormat)?;
+    raw.get(start..end).ok_or(CompressError::BadZipFormat)
+}
+
+fn zip_u16(raw: &[u8], at: usize, offset: usize) -> Result<u16, CompressError> {
+    zip_bytes(raw, at, offset, 2).map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+fn zip_u32(raw: &[u8], at: usize, offset: usize) -> Result<u32, CompressError> {
+    zip_bytes(raw, at, offset, 4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
 fn find_end_central_directory(raw: &[u8]) -> Result<usize, CompressError> {
-    let mut search = raw.len() - 20;
+    let mut search = raw.len().checked_sub(20).ok_or(CompressError::BadZipFormat)?;
     // let bounds = usize::max(search - 65516, 2); // Sub 2**256 - 20 (max comment length)
     let bounds = if search > 65516 { usize::max(search - 65516, 2) } else { 2 };
 
@@ -404,7 +411,7 @@ mod tests {
         let filenames: Vec<String> = items.iter().map(|item| item.filename.to_string()).collect();
         assert_eq!(
             filenames,
-            vec!["utf.cpg", "utf.cpg", "utf.cpg", "utf.cpg", "utf.cpg", "utf.cpg"]
+            vec!["utf.cpg", "utf.dbf", "utf.prj", "utf.qpj", "utf.shp", "utf.shx"]
         );
         let first = items.first().unwrap();
         let first_data = (first.read)().unwrap();
@@ -414,4 +421,30 @@ mod tests {
         let first_string = String::from_utf8(first_data).unwrap();
         assert_eq!(first_string, "UTF-8");
     }
+
+    #[test]
+    fn decode_zip_folder_malformed() {
+        let mut path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        path.push("tests/util/fixtures/utf.zip");
+        let data: Vec<u8> = fs::read(&path).expect("Failed to read file expected");
+
+        assert_eq!(iter_items(&[]).err(), Some(CompressError::BadZipFormat));
+        // cut off inside the local entries, the central directory still points at them
+        let eocd = find_end_central_directory(&data).unwrap();
+        let central_directory = u32::from_le_bytes(data[eocd + 16..eocd + 20].try_into().unwrap());
+        let mut truncated = data[central_directory as usize..].to_vec();
+        let eocd = eocd - central_directory as usize;
+        truncated[eocd + 16..eocd + 20].copy_from_slice(&0_u32.to_le_bytes());
+        assert_eq!(iter_items(&truncated).err(), Some(CompressError::BadZipFormat));
+        // a central directory that starts past the end of the data
+        let mut moved = data.clone();
+        moved[eocd + central_directory as usize + 16..][..4]
+            .copy_from_slice(&u32::MAX.to_le_bytes());
+        assert_eq!(iter_items(&moved).err(), Some(CompressError::BadZipFormat));
+        // a file name that runs past the end of the data
+        let mut long_name = data.clone();
+        let name_length = central_directory as usize + 28;
+        long_name[name_length..name_length + 2].copy_from_slice(&u16::MAX.to_le_bytes());
+        assert_eq!(iter_items(&long_name).err(), Some(CompressError::BadZipFormat));
+    }
 }
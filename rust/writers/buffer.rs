@@ -0,0 +1,69 @@
+use crate::writers::{Writer, WriterError};
+
+use alloc::vec::Vec;
+
+/// A buffer writer for writing data to a buffer
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct BufferWriter {
+    buffer: Vec<u8>,
+}
+impl BufferWriter {
+    /// Creates a new buffer writer
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the written data
+    pub fn take(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.buffer)
+    }
+}
+impl Writer for BufferWriter {
+    fn len(&self) -> u64 {
+        self.buffer.len() as u64
+    }
+
+    fn write(&mut self, data: &[u8], offset: u64) -> Result<(), WriterError> {
+        let offset = offset as usize;
+        let end = offset + data.len();
+        if end > self.buffer.len() {
+            self.buffer.resize(end, 0);
+        }
+        self.buffer[offset..end].copy_from_slice(data);
+        Ok(())
+    }
+
+    fn append(&mut self, data: &[u8]) -> Result<(), WriterError> {
+        self.buffer.extend_from_slice(data);
+        Ok(())
+    }
+
+    fn read(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, WriterError> {
+        let start = usize::try_from(offset).map_err(|_| WriterError::OutOfBounds)?;
+        let end = usize::try_from(length).ok().and_then(|length| start.checked_add(length));
+        end.and_then(|end| self.buffer.get(start..end))
+            .map(|data| data.to_vec())
+            .ok_or(WriterError::OutOfBounds)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn test_buffer_writer() {
+        let mut writer = BufferWriter::new();
+        assert!(writer.is_empty());
+        writer.append(&[1, 2, 3]).unwrap();
+        writer.write(&[4, 5], 2).unwrap();
+        assert_eq!(writer.len(), 4);
+        writer.write(&[6], 6).unwrap();
+        assert_eq!(writer.read(1, 3), Ok(vec![2, 4, 5]));
+        assert_eq!(writer.read(5, 3), Err(WriterError::OutOfBounds));
+        assert_eq!(writer.read(u64::MAX, 2), Err(WriterError::OutOfBounds));
+        assert_eq!(writer.take(), vec![1, 2, 4, 5, 0, 0, 6]);
+        assert!(writer.is_empty());
+    }
+}
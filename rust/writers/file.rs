@@ -0,0 +1,48 @@
+use std::fs::{File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+
+use alloc::{vec, vec::Vec};
+
+use crate::writers::{Writer, WriterError};
+
+/// A file writer for writing data to a file
+pub struct FileWriter {
+    file: File,
+    size: u64,
+}
+impl FileWriter {
+    /// Creates a new file writer from a file path. If the file already exists it is truncated.
+    pub fn new(path: PathBuf) -> io::Result<Self> {
+        let file =
+            OpenOptions::new().read(true).write(true).create(true).truncate(true).open(path)?;
+        Ok(Self { file, size: 0 })
+    }
+}
+impl Writer for FileWriter {
+    fn len(&self) -> u64 {
+        self.size
+    }
+
+    fn write(&mut self, data: &[u8], offset: u64) -> Result<(), WriterError> {
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.write_all(data)?;
+        self.size = self.size.max(offset + data.len() as u64);
+        Ok(())
+    }
+
+    fn append(&mut self, data: &[u8]) -> Result<(), WriterError> {
+        let size = self.size;
+        self.write(data, size)
+    }
+
+    fn read(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, WriterError> {
+        if offset.checked_add(length).is_none_or(|end| end > self.size) {
+            return Err(WriterError::OutOfBounds);
+        }
+        let mut data = vec![0; length as usize];
+        self.file.seek(SeekFrom::Start(offset))?;
+        self.file.read_exact(&mut data)?;
+        Ok(data)
+    }
+}
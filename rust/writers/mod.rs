@@ -0,0 +1,46 @@
+/// Buffer Writer for writing data to a buffer
+pub mod buffer;
+/// File Writer for writing data to a file
+#[cfg(feature = "std")]
+pub mod file;
+/// PMTiles and S2-PMTiles Writer
+pub mod pmtiles;
+
+pub use buffer::*;
+#[cfg(feature = "std")]
+pub use file::*;
+pub use pmtiles::*;
+
+use alloc::vec::Vec;
+
+/// Errors that can occur while writing data
+#[derive(Debug, Clone, PartialEq)]
+pub enum WriterError {
+    /// A read of written data extends past the end of the output
+    OutOfBounds,
+    /// The file could not be written to or read from
+    #[cfg(feature = "std")]
+    Io(std::io::ErrorKind),
+}
+#[cfg(feature = "std")]
+impl From<std::io::Error> for WriterError {
+    fn from(err: std::io::Error) -> Self {
+        WriterError::Io(err.kind())
+    }
+}
+
+/// Writer interface. Implemented to write data to either a buffer or a filesystem
+pub trait Writer {
+    /// Get the number of bytes written
+    fn len(&self) -> u64;
+    /// See if empty
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Write data at the given byte offset, growing the output if necessary
+    fn write(&mut self, data: &[u8], offset: u64) -> Result<(), WriterError>;
+    /// Append data to the end of the output
+    fn append(&mut self, data: &[u8]) -> Result<(), WriterError>;
+    /// Read back `length` bytes of the written data starting at the given byte offset
+    fn read(&mut self, offset: u64, length: u64) -> Result<Vec<u8>, WriterError>;
+}
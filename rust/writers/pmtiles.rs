@@ -0,0 +1,510 @@
+use crate::geometry::{Face, Projection, S2CellId};
+use crate::readers::{
+    serialize_dir, zxy_to_tile_id, Compression, Entry, Header, PMTilesError, S2Header, TileType,
+    HEADER_SIZE_BYTES, ROOT_SIZE, S2_HEADER_SIZE_BYTES, S2_ROOT_SIZE,
+};
+#[cfg(feature = "std")]
+use crate::util::compress_data;
+#[cfg(not(feature = "std"))]
+use crate::util::CompressError;
+use crate::writers::Writer;
+
+use serde::Serialize;
+
+use alloc::{collections::BTreeMap, vec, vec::Vec};
+
+/// # (S2) PMTiles Writer
+///
+/// ## Description
+/// Writes data via the [PMTiles v3 specification](https://github.com/protomaps/PMTiles/blob/main/spec/v3/spec.md)
+/// or the [S2-PMTiles specification](https://github.com/Open-S2/s2-pmtiles/blob/master/s2-pmtiles-spec/1.0.0/README.md)
+/// if S2 tiles are written. An archive holds either WM or S2 tiles, writing a tile of the other
+/// kind is an error.
+///
+/// Tiles may be written in any order. Identical tile contents are only stored once and
+/// consecutive tiles sharing the same contents are stored as a single run-length entry.
+/// The archive is flagged as clustered if the tiles were written in tile ID order.
+///
+/// ## Usage
+/// ```rust
+/// use gistools::readers::{BufferReader, PMTilesReader, TileSource, TileType};
+/// use gistools::writers::{BufferWriter, PMTilesWriter};
+/// use serde_json::json;
+///
+/// let mut writer = PMTilesWriter::new(BufferWriter::new(), TileType::Pbf, None);
+/// writer.write_tile_wm(0, 0, 0, b"hello world").unwrap();
+/// writer.write_tile_wm(1, 0, 1, b"hello world").unwrap();
+/// // finish writing the archive and get the resulting bytes
+/// let data = writer.commit(&json!({ "name": "example" }), None).unwrap().take();
+///
+/// let mut reader = PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+/// assert_eq!(reader.get_tile(1, 0, 1).unwrap(), Some(b"hello world".to_vec()));
+/// ```
+///
+/// To write to the filesystem, use a `FileWriter` instead of a [`crate::writers::BufferWriter`].
+///
+/// ## Links
+/// - <https://github.com/Open-S2/s2-pmtiles/blob/master/s2-pmtiles-spec/1.0.0/README.md>
+/// - <https://github.com/protomaps/PMTiles>
+pub struct PMTilesWriter<W: Writer> {
+    writer: W,
+    tile_type: TileType,
+    internal_compression: Compression,
+    /// The projection of the archive, set by the first tile written
+    projection: Option<Projection>,
+    tile_entries: Vec<Entry>,
+    s2_tile_entries: [Vec<Entry>; 6],
+    /// tile content hash -> (offset, length) of the contents sharing the hash
+    contents: BTreeMap<u128, Vec<(u64, u32)>>,
+    offset: u64,
+    clustered: bool,
+    min_zoom: u8,
+    max_zoom: u8,
+}
+impl<W: Writer> PMTilesWriter<W> {
+    /// Create a new PMTiles writer.
+    /// `internal_compression` is used for the directories and metadata [Default = Gzip]
+    pub fn new(writer: W, tile_type: TileType, internal_compression: Option<Compression>) -> Self {
+        Self {
+            writer,
+            tile_type,
+            internal_compression: internal_compression.unwrap_or(Compression::Gzip),
+            projection: None,
+            tile_entries: Vec::new(),
+            s2_tile_entries: Default::default(),
+            contents: BTreeMap::new(),
+            offset: 0,
+            clustered: true,
+            min_zoom: u8::MAX,
+            max_zoom: 0,
+        }
+    }
+
+    /// Write a tile given its (zoom, x, y) coordinates. Errors if S2 tiles were already written
+    pub fn write_tile_wm(
+        &mut self,
+        zoom: u8,
+        x: u32,
+        y: u32,
+        data: &[u8],
+    ) -> Result<(), PMTilesError> {
+        self.write_tile(None, zoom, x, y, data)
+    }
+
+    /// Write an S2 tile given its (face, zoom, i, j) coordinates. Errors if WM tiles were
+    /// already written
+    pub fn write_tile_s2(
+        &mut self,
+        face: Face,
+        zoom: u8,
+        i: u32,
+        j: u32,
+        data: &[u8],
+    ) -> Result<(), PMTilesError> {
+        self.write_tile(Some(face), zoom, i, j, data)
+    }
+
+    /// Write an S2 tile given its cell id. Errors if WM tiles were already written
+    pub fn write_tile_from_cell(&mut self, id: S2CellId, data: &[u8]) -> Result<(), PMTilesError> {
+        let zoom = id.level();
+        let (face, i, j) = id.to_zoom_ij(Some(zoom));
+        self.write_tile(Some(face.into()), zoom, i, j, data)
+    }
+
+    /// Store the tile data (if it's not a duplicate) and add its directory entry
+    fn write_tile(
+        &mut self,
+        face: Option<Face>,
+        zoom: u8,
+        x: u32,
+        y: u32,
+        data: &[u8],
+    ) -> Result<(), PMTilesError> {
+        let projection = if face.is_some() { Projection::S2 } else { Projection::WG };
+        self.set_projection(projection)?;
+        self.min_zoom = self.min_zoom.min(zoom);
+        self.max_zoom = self.max_zoom.max(zoom);
+        let tile_id = zxy_to_tile_id(zoom, x, y);
+        let hash = fnv1a_128(data);
+        // the hash only finds candidates, the stored bytes must match to share the contents
+        let mut stored = None;
+        for &(offset, length) in self.contents.get(&hash).into_iter().flatten() {
+            let start = root_size(projection) as u64 + offset;
+            if length as usize == data.len() && self.writer.read(start, length as u64)? == data {
+                stored = Some((offset, length));
+                break;
+            }
+        }
+        let (offset, length) = match stored {
+            Some(stored) => stored,
+            None => {
+                let stored = (self.offset, data.len() as u32);
+                self.writer.append(data)?;
+                self.offset += data.len() as u64;
+                self.contents.entry(hash).or_default().push(stored);
+                stored
+            }
+        };
+        let entries = match face {
+            Some(face) => &mut self.s2_tile_entries[u8::from(face) as usize],
+            None => &mut self.tile_entries,
+        };
+        if entries.last().is_some_and(|last| tile_id < last.tile_id) {
+            self.clustered = false;
+        }
+        entries.push(Entry { tile_id, offset, length, run_length: 1 });
+
+        Ok(())
+    }
+
+    /// Set the projection of the archive on the first write and reserve space for the header,
+    /// root directories and metadata. Errors if the projection differs from the archive's.
+    fn set_projection(&mut self, projection: Projection) -> Result<(), PMTilesError> {
+        match self.projection {
+            Some(current) if current != projection => Err(PMTilesError::MixedProjections),
+            Some(_) => Ok(()),
+            None => {
+                self.projection = Some(projection);
+                self.writer.append(&vec![0; root_size(projection)])?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Finish writing by building the header, directories and metadata. If S2 tiles were
+    /// written, an S2-PMTiles archive is created. Returns the underlying writer.
+    /// `tile_compression` describes the compression already applied to the tiles [Default = None]
+    pub fn commit<M: Serialize>(
+        mut self,
+        metadata: &M,
+        tile_compression: Option<Compression>,
+    ) -> Result<W, PMTilesError> {
+        // an empty archive is written as WM
+        let projection = self.projection.unwrap_or(Projection::WG);
+        self.set_projection(projection)?;
+        let is_s2 = projection == Projection::S2;
+        let root_size = root_size(projection);
+        let metadata = serde_json::to_vec(metadata).map_err(|_| PMTilesError::InvalidMetadata)?;
+        let metadata = compress(&metadata, self.internal_compression)?;
+        let tile_data_length = self.offset;
+        let n_tile_contents = self.contents.values().map(|c| c.len() as u64).sum();
+
+        // sort and run-length encode the entries of every directory
+        let mut directories: Vec<Vec<Entry>> = if is_s2 {
+            core::mem::take(&mut self.s2_tile_entries).into_iter().collect()
+        } else {
+            vec![core::mem::take(&mut self.tile_entries)]
+        };
+        for entries in directories.iter_mut() {
+            *entries = run_length_encode(core::mem::take(entries));
+        }
+        let n_tile_entries = directories.iter().map(|e| e.len() as u64).sum();
+        let n_addressed_tiles = directories.iter().flatten().map(|e| e.run_length as u64).sum();
+
+        // build the root and leaf directories, leaves are stored after the tile data
+        let (header_size, target_root_length) = if is_s2 {
+            (S2_HEADER_SIZE_BYTES, (S2_ROOT_SIZE - S2_HEADER_SIZE_BYTES) / 6)
+        } else {
+            (HEADER_SIZE_BYTES, ROOT_SIZE - HEADER_SIZE_BYTES)
+        };
+        let mut roots = Vec::with_capacity(directories.len());
+        let mut leaves = Vec::with_capacity(directories.len());
+        let mut root_offset = header_size as u64;
+        for entries in &directories {
+            let (root_bytes, leaves_bytes) =
+                optimize_directories(entries, target_root_length, self.internal_compression)?;
+            let leaf_offset = root_size as u64 + self.offset;
+            self.writer.append(&leaves_bytes)?;
+            self.offset += leaves_bytes.len() as u64;
+            leaves.push((leaf_offset, leaves_bytes.len() as u64));
+            self.writer.write(&root_bytes, root_offset)?;
+            roots.push((root_offset, root_bytes.len() as u64));
+            root_offset += root_bytes.len() as u64;
+        }
+
+        // store the metadata after the root directories if it fits, otherwise at the end
+        let json_metadata_offset = if root_offset as usize + metadata.len() <= root_size {
+            root_offset
+        } else {
+            root_size as u64 + self.offset
+        };
+        self.writer.write(&metadata, json_metadata_offset)?;
+
+        let header = Header {
+            spec_version: if is_s2 { 1 } else { 3 },
+            root_directory_offset: roots[0].0,
+            root_directory_length: roots[0].1,
+            json_metadata_offset,
+            json_metadata_length: metadata.len() as u64,
+            leaf_directory_offset: leaves[0].0,
+            leaf_directory_length: leaves[0].1,
+            tile_data_offset: root_size as u64,
+            tile_data_length,
+            n_addressed_tiles,
+            n_tile_entries,
+            n_tile_contents,
+            clustered: self.clustered,
+            internal_compression: self.internal_compression,
+            tile_compression: tile_compression.unwrap_or(Compression::None),
+            tile_type: self.tile_type,
+            min_zoom: if n_addressed_tiles == 0 { 0 } else { self.min_zoom },
+            max_zoom: self.max_zoom,
+            ..Default::default()
+        };
+        let header_bytes = if is_s2 {
+            let mut s2_header = S2Header { header, ..Default::default() };
+            for (face, ((root_offset, root_length), (leaf_offset, leaf_length))) in
+                roots.into_iter().zip(leaves).enumerate()
+            {
+                s2_header.root_directory_offsets[face] = root_offset;
+                s2_header.root_directory_lengths[face] = root_length;
+                s2_header.leaf_directory_offsets[face] = leaf_offset;
+                s2_header.leaf_directory_lengths[face] = leaf_length;
+            }
+            s2_header.to_bytes()
+        } else {
+            header.to_bytes()
+        };
+        self.writer.write(&header_bytes, 0)?;
+
+        Ok(self.writer)
+    }
+}
+
+/// The space reserved at the start of the archive for the header, root directories and metadata
+fn root_size(projection: Projection) -> usize {
+    match projection {
+        Projection::WG => ROOT_SIZE,
+        Projection::S2 => S2_ROOT_SIZE,
+    }
+}
+
+/// Sort the entries by tile ID and merge consecutive tiles that share the same contents into a
+/// single entry. If a tile was written more than once, the last write is kept.
+fn run_length_encode(mut entries: Vec<Entry>) -> Vec<Entry> {
+    // stable sort so that the last write of a tile ID is last among duplicates
+    entries.sort_by_key(|e| e.tile_id);
+    let mut res: Vec<Entry> = Vec::with_capacity(entries.len());
+    for entry in entries {
+        match res.last_mut() {
+            Some(last) if last.tile_id == entry.tile_id => *last = entry,
+            Some(last)
+                if last.offset == entry.offset
+                    && last.length == entry.length
+                    && last.tile_id + last.run_length as u64 == entry.tile_id =>
+            {
+                last.run_length += 1;
+            }
+            _ => res.push(entry),
+        }
+    }
+    res
+}
+
+/// Serialize the entries into a root directory that fits within `target_root_length` bytes,
+/// moving the entries into leaf directories if necessary. Returns the root and leaves bytes.
+fn optimize_directories(
+    entries: &[Entry],
+    target_root_length: usize,
+    compression: Compression,
+) -> Result<(Vec<u8>, Vec<u8>), PMTilesError> {
+    let root_bytes = compress(&serialize_dir(entries), compression)?;
+    if root_bytes.len() <= target_root_length {
+        return Ok((root_bytes, Vec::new()));
+    }
+
+    let mut leaf_size = 4_096;
+    loop {
+        let mut root_entries = Vec::new();
+        let mut leaves_bytes = Vec::new();
+        for chunk in entries.chunks(leaf_size) {
+            let leaf = compress(&serialize_dir(chunk), compression)?;
+            root_entries.push(Entry {
+                tile_id: chunk[0].tile_id,
+                offset: leaves_bytes.len() as u64,
+                length: leaf.len() as u32,
+                run_length: 0,
+            });
+            leaves_bytes.extend_from_slice(&leaf);
+        }
+        let root_bytes = compress(&serialize_dir(&root_entries), compression)?;
+        if root_bytes.len() <= target_root_length {
+            return Ok((root_bytes, leaves_bytes));
+        }
+        leaf_size *= 2;
+    }
+}
+
+/// Compress the internal data of the archive
+fn compress(input: &[u8], compression: Compression) -> Result<Vec<u8>, PMTilesError> {
+    match compression {
+        Compression::None | Compression::Unknown => Ok(input.to_vec()),
+        #[cfg(feature = "std")]
+        _ => Ok(compress_data(input, compression.into())?),
+        #[cfg(not(feature = "std"))]
+        _ => Err(PMTilesError::Compression(CompressError::InvalidCompressionMethod)),
+    }
+}
+
+/// 128-bit FNV-1a hash used to deduplicate tile contents
+fn fnv1a_128(data: &[u8]) -> u128 {
+    const OFFSET_BASIS: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+    data.iter().fold(OFFSET_BASIS, |hash, byte| (hash ^ *byte as u128).wrapping_mul(PRIME))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::readers::{BufferReader, PMTilesReader, S2PMTilesReader, TileSource};
+    use crate::writers::{BufferWriter, FileWriter};
+    use serde_json::json;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_run_length_encode() {
+        let entry = |tile_id, offset| Entry { tile_id, offset, length: 10, run_length: 1 };
+        assert_eq!(
+            run_length_encode(vec![
+                entry(3, 0),
+                entry(1, 0),
+                entry(2, 0),
+                entry(4, 10),
+                entry(4, 20)
+            ]),
+            vec![
+                Entry { tile_id: 1, offset: 0, length: 10, run_length: 3 },
+                Entry { tile_id: 4, offset: 20, length: 10, run_length: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_write_wm() {
+        let mut writer = PMTilesWriter::new(BufferWriter::new(), TileType::Pbf, None);
+        writer.write_tile_wm(5, 2, 9, b"hello world 2").unwrap();
+        writer.write_tile_wm(0, 0, 0, b"hello world").unwrap();
+        writer.write_tile_wm(1, 0, 1, b"hello world").unwrap();
+        let data = writer.commit(&json!({ "metadata": true }), None).unwrap().take();
+
+        let mut reader = PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+        let header = reader.get_header();
+        assert_eq!(header.spec_version, 3);
+        assert!(!header.clustered);
+        assert_eq!(header.internal_compression, Compression::Gzip);
+        assert_eq!(header.tile_type, TileType::Pbf);
+        assert_eq!((header.min_zoom, header.max_zoom), (0, 5));
+        assert_eq!(header.n_addressed_tiles, 3);
+        assert_eq!(header.n_tile_entries, 3);
+        assert_eq!(header.n_tile_contents, 2);
+        assert_eq!(header.tile_data_offset, ROOT_SIZE as u64);
+        assert_eq!(header.tile_data_length, 24);
+        assert_eq!(reader.get_metadata(), &json!({ "metadata": true }));
+
+        assert_eq!(reader.get_tile(0, 0, 0).unwrap(), Some(b"hello world".to_vec()));
+        assert_eq!(reader.get_tile(1, 0, 1).unwrap(), Some(b"hello world".to_vec()));
+        assert_eq!(reader.get_tile(5, 2, 9).unwrap(), Some(b"hello world 2".to_vec()));
+        assert_eq!(reader.get_tile(1, 1, 1).unwrap(), None);
+    }
+
+    #[test]
+    fn test_write_s2() {
+        let mut writer =
+            PMTilesWriter::new(BufferWriter::new(), TileType::Pbf, Some(Compression::None));
+        writer.write_tile_s2(Face::Face0, 0, 0, 0, b"hello world").unwrap();
+        writer.write_tile_s2(Face::Face1, 0, 0, 0, b"hello world").unwrap();
+        writer.write_tile_from_cell(S2CellId::from_face(3).child(2), b"hello world 2").unwrap();
+        let data = writer.commit(&json!({ "metadata": true }), None).unwrap().take();
+
+        let mut reader = S2PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+        let header = reader.get_header();
+        assert!(header.header.clustered);
+        assert_eq!(header.header.tile_data_offset, S2_ROOT_SIZE as u64);
+        assert_eq!(header.header.n_addressed_tiles, 3);
+        assert_eq!(header.header.n_tile_contents, 2);
+        assert_eq!(reader.get_metadata(), &json!({ "metadata": true }));
+
+        let hello_world = Some(b"hello world".to_vec());
+        assert_eq!(reader.get_tile_s2(Face::Face0, 0, 0, 0).unwrap(), hello_world);
+        assert_eq!(reader.get_tile_s2(Face::Face1, 0, 0, 0).unwrap(), hello_world);
+        assert_eq!(reader.get_tile_s2(Face::Face2, 0, 0, 0).unwrap(), None);
+        assert_eq!(
+            reader.get_tile_from_cell(S2CellId::from_face(3).child(2)).unwrap(),
+            Some(b"hello world 2".to_vec())
+        );
+    }
+
+    #[test]
+    fn test_write_hash_collision() {
+        let mut writer =
+            PMTilesWriter::new(BufferWriter::new(), TileType::Pbf, Some(Compression::None));
+        writer.write_tile_wm(0, 0, 0, b"hello world").unwrap();
+        // pretend the next tile's contents hash the same as the stored contents
+        let (_, stored) = writer.contents.pop_first().unwrap();
+        writer.contents.insert(fnv1a_128(b"hello worle"), stored);
+        writer.write_tile_wm(1, 0, 0, b"hello worle").unwrap();
+        writer.write_tile_wm(1, 0, 1, b"hello worle").unwrap();
+        let data = writer.commit(&json!({}), None).unwrap().take();
+
+        let mut reader = PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+        assert_eq!(reader.get_header().n_tile_contents, 2);
+        assert_eq!(reader.get_tile(0, 0, 0).unwrap(), Some(b"hello world".to_vec()));
+        assert_eq!(reader.get_tile(1, 0, 0).unwrap(), Some(b"hello worle".to_vec()));
+        assert_eq!(reader.get_tile(1, 0, 1).unwrap(), Some(b"hello worle".to_vec()));
+    }
+
+    #[test]
+    fn test_write_mixed_projections() {
+        let mut writer = PMTilesWriter::new(BufferWriter::new(), TileType::Pbf, None);
+        writer.write_tile_wm(0, 0, 0, b"hello world").unwrap();
+        assert_eq!(
+            writer.write_tile_s2(Face::Face0, 0, 0, 0, b"hello world"),
+            Err(PMTilesError::MixedProjections)
+        );
+
+        let mut writer = PMTilesWriter::new(BufferWriter::new(), TileType::Pbf, None);
+        writer.write_tile_from_cell(S2CellId::from_face(3), b"hello world").unwrap();
+        assert_eq!(
+            writer.write_tile_wm(0, 0, 0, b"hello world"),
+            Err(PMTilesError::MixedProjections)
+        );
+    }
+
+    #[test]
+    fn test_write_leaf_directories() {
+        let mut writer = PMTilesWriter::new(BufferWriter::new(), TileType::Png, None);
+        // unique contents per tile so the directory can't be run-length encoded
+        let zoom = 8;
+        for x in 0..256 {
+            for y in 0..256 {
+                writer.write_tile_wm(zoom, x, y, &(x * 256 + y).to_le_bytes()).unwrap();
+            }
+        }
+        let data = writer.commit(&json!({}), None).unwrap().take();
+
+        let mut reader = PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+        let header = reader.get_header();
+        assert!(header.leaf_directory_length > 0);
+        assert_eq!(header.n_addressed_tiles, 65_536);
+        for (x, y) in [(0, 0), (17, 250), (255, 255)] {
+            assert_eq!(
+                reader.get_tile(zoom, x, y).unwrap(),
+                Some((x * 256 + y).to_le_bytes().to_vec())
+            );
+        }
+    }
+
+    #[test]
+    fn test_write_file() {
+        let path = std::env::temp_dir().join("gistools_pmtiles_writer_test.pmtiles");
+        let file_writer = FileWriter::new(PathBuf::from(&path)).unwrap();
+        let mut writer = PMTilesWriter::new(file_writer, TileType::Pbf, None);
+        writer.write_tile_wm(0, 0, 0, b"hello world").unwrap();
+        writer.commit(&json!({ "metadata": true }), None).unwrap();
+
+        let data = std::fs::read(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let mut reader = PMTilesReader::new(BufferReader::from(&data[..]), None).unwrap();
+        assert_eq!(reader.get_tile(0, 0, 0).unwrap(), Some(b"hello world".to_vec()));
+    }
+}
@@ -0,0 +1,45 @@
+<?xml version="1.0" encoding="UTF-8"?>
+<ogr:FeatureCollection xmlns:ogr="http://ogr.maptools.org/"
+    xmlns:gml="http://www.opengis.net/gml">
+  <gml:boundedBy>
+    <gml:Box srsName="EPSG:4326">
+      <gml:coord><gml:X>0</gml:X><gml:Y>0</gml:Y></gml:coord>
+      <gml:coord><gml:X>10</gml:X><gml:Y>10</gml:Y></gml:coord>
+    </gml:Box>
+  </gml:boundedBy>
+  <gml:featureMember>
+    <ogr:parcels fid="parcels.0">
+      <ogr:geometryProperty>
+        <gml:Polygon srsName="EPSG:4326">
+          <gml:outerBoundaryIs>
+            <gml:LinearRing>
+              <gml:coordinates decimal="," cs=";" ts=" ">0;0 10;0 10;10 0,5;10 0;0</gml:coordinates>
+            </gml:LinearRing>
+          </gml:outerBoundaryIs>
+          <gml:innerBoundaryIs>
+            <gml:LinearRing>
+              <gml:coordinates>2,2 4,2 4,4 2,2</gml:coordinates>
+            </gml:LinearRing>
+          </gml:innerBoundaryIs>
+        </gml:Polygon>
+      </ogr:geometryProperty>
+      <ogr:owner>Smith</ogr:owner>
+    </ogr:parcels>
+  </gml:featureMember>
+  <gml:featureMember>
+    <ogr:parcels fid="parcels.1">
+      <ogr:geometryProperty>
+        <gml:MultiPoint>
+          <gml:pointMember><gml:Point><gml:coordinates>1,2</gml:coordinates></gml:Point></gml:pointMember>
+          <gml:pointMember><gml:Point><gml:coord><gml:X>3</gml:X><gml:Y>4</gml:Y></gml:coord></gml:Point></gml:pointMember>
+        </gml:MultiPoint>
+      </ogr:geometryProperty>
+      <ogr:owner>Jones</ogr:owner>
+    </ogr:parcels>
+  </gml:featureMember>
+  <gml:featureMember>
+    <ogr:parcels fid="parcels.2">
+      <ogr:owner>Nobody</ogr:owner>
+    </ogr:parcels>
+  </gml:featureMember>
+</ogr:FeatureCollection>
@@ -0,0 +1,83 @@
+<?xml version="1.0" encoding="UTF-8"?>
+<wfs:FeatureCollection xmlns:wfs="http://www.opengis.net/wfs/2.0"
+    xmlns:gml="http://www.opengis.net/gml/3.2"
+    xmlns:xlink="http://www.w3.org/1999/xlink"
+    xmlns:ex="http://example.com/ns"
+    numberMatched="3" numberReturned="3">
+  <wfs:boundedBy>
+    <gml:Envelope srsName="urn:ogc:def:crs:EPSG::4326">
+      <gml:lowerCorner>47.0 5.0</gml:lowerCorner>
+      <gml:upperCorner>55.0 15.0</gml:upperCorner>
+    </gml:Envelope>
+  </wfs:boundedBy>
+  <wfs:member>
+    <ex:City gml:id="city.1">
+      <gml:name>Berlin</gml:name>
+      <ex:population>3645000</ex:population>
+      <ex:area>891.7</ex:area>
+      <ex:capital>true</ex:capital>
+      <ex:postcode>01067</ex:postcode>
+      <ex:address>
+        <ex:street>Unter den Linden</ex:street>
+        <ex:number>1</ex:number>
+      </ex:address>
+      <ex:tag>museum</ex:tag>
+      <ex:tag>river</ex:tag>
+      <ex:website xlink:href="https://berlin.de"/>
+      <ex:location>
+        <gml:Point gml:id="p1" srsName="urn:ogc:def:crs:EPSG::4326">
+          <gml:pos>52.52 13.405</gml:pos>
+        </gml:Point>
+      </ex:location>
+    </ex:City>
+  </wfs:member>
+  <wfs:member>
+    <ex:Lake gml:id="lake.1">
+      <ex:name>Lake</ex:name>
+      <ex:shape>
+        <gml:MultiSurface srsName="http://www.opengis.net/def/crs/EPSG/0/4326">
+          <gml:surfaceMember>
+            <gml:Polygon>
+              <gml:exterior>
+                <gml:LinearRing>
+                  <gml:posList>50 10 50 11 51 11 51 10 50 10</gml:posList>
+                </gml:LinearRing>
+              </gml:exterior>
+              <gml:interior>
+                <gml:LinearRing>
+                  <gml:posList>50.2 10.2 50.2 10.4 50.4 10.4 50.2 10.2</gml:posList>
+                </gml:LinearRing>
+              </gml:interior>
+            </gml:Polygon>
+          </gml:surfaceMember>
+          <gml:surfaceMember>
+            <gml:Polygon>
+              <gml:exterior>
+                <gml:LinearRing>
+                  <gml:posList>52 12 52 13 53 13 52 12</gml:posList>
+                </gml:LinearRing>
+              </gml:exterior>
+            </gml:Polygon>
+          </gml:surfaceMember>
+        </gml:MultiSurface>
+      </ex:shape>
+    </ex:Lake>
+  </wfs:member>
+  <wfs:member>
+    <ex:Road gml:id="road.1">
+      <ex:name>Trail</ex:name>
+      <ex:geometry>
+        <gml:Curve srsName="urn:ogc:def:crs:EPSG::3857" srsDimension="3">
+          <gml:segments>
+            <gml:LineStringSegment>
+              <gml:posList>100 200 1 110 210 2</gml:posList>
+            </gml:LineStringSegment>
+            <gml:LineStringSegment>
+              <gml:posList>110 210 2 120 220 3</gml:posList>
+            </gml:LineStringSegment>
+          </gml:segments>
+        </gml:Curve>
+      </ex:geometry>
+    </ex:Road>
+  </wfs:member>
+</wfs:FeatureCollection>